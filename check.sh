@@ -32,6 +32,7 @@ Commands:
     klippy        validate + fix clippy
     doc           generate docs for 'godot' crate
     dok           generate docs and open in browser
+    size          compare release binary sizes with/without lazy-function-tables
 
 Options:
     -h, --help          print this help text
@@ -168,6 +169,43 @@ function cmd_dok() {
     run cargo doc --lib -p godot --no-deps "${extraCargoArgs[@]}" --open
 }
 
+# Builds the dodge-the-creeps cdylib in release mode (with LTO and stripping), once with eager and once with lazy function tables.
+# Wasm is only measured if the wasm32-unknown-emscripten target and emcc are available.
+function cmd_size() {
+    local targets=("")
+    if rustup target list --installed 2>/dev/null | grep -q "wasm32-unknown-emscripten" && command -v emcc >/dev/null; then
+        targets+=("wasm32-unknown-emscripten")
+    fi
+
+    local target
+    for target in "${targets[@]}"; do
+        local targetArgs=()
+        local outDir="target/size-check"
+        if [[ -n "$target" ]]; then
+            targetArgs=("--target" "$target")
+            outDir="$outDir/$target"
+        fi
+
+        local feature
+        for feature in "" "godot/lazy-function-tables"; do
+            # Separate target dirs, so the two builds don't overwrite each other's binary.
+            local featureArgs=()
+            local featureDir="$outDir/eager"
+            if [[ -n "$feature" ]]; then
+                featureArgs=("--features" "$feature")
+                featureDir="$outDir/lazy"
+            fi
+
+            CARGO_PROFILE_RELEASE_LTO=true CARGO_PROFILE_RELEASE_STRIP=true CARGO_PROFILE_RELEASE_CODEGEN_UNITS=1 \
+                run cargo build -p dodge-the-creeps --release "${targetArgs[@]}" "${featureArgs[@]}" --target-dir "$featureDir" || return 1
+
+            local binary
+            binary=$(find "$featureDir" -maxdepth 3 -path "*release/*" \( -name "*.so" -o -name "*.dll" -o -name "*.dylib" -o -name "*.wasm" \) | head -n1)
+            log "${CYAN}${target:-native}, ${feature:-eager tables}: $(wc -c <"$binary") bytes${END}"
+        done
+    done
+}
+
 ################################################################################
 # Argument parsing
 ################################################################################
@@ -191,7 +229,7 @@ for arg in "$@"; do
         --double)
             extraCargoArgs+=("--features" "godot/double-precision")
             ;;
-        fmt | test | itest | clippy | klippy | doc | dok)
+        fmt | test | itest | clippy | klippy | doc | dok | size)
             cmds+=("$arg")
             ;;
         -f | --filter)
@@ -9,7 +9,10 @@
 //!
 //! Do not depend on this crate directly, instead use the `godot` crate.
 //! No SemVer or other guarantees are provided.
+//!
+//! The exception is [`project_names`], which build scripts can use to generate constants from a project's `project.godot`.
 
+pub mod project_names;
 pub(crate) mod watch;
 
 use std::path::Path;
@@ -0,0 +1,308 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Constants for names defined in a Godot project's `project.godot`.
+//!
+//! Input actions, global groups and autoloads are referred to by name in Rust code, e.g. `Input::is_action_pressed("jump")`. A typo or a
+//! renamed action in the project settings only shows up at runtime. Generating constants from `project.godot` turns these into compile
+//! errors.
+//!
+//! # Usage
+//! Add `godot-bindings` as a build dependency (same version as `godot`), and call [`generate_project_names()`] from `build.rs`:
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() {
+//!     let out_dir = std::env::var("OUT_DIR").unwrap();
+//!     godot_bindings::project_names::generate_project_names(
+//!         "../godot/project.godot",
+//!         format!("{out_dir}/project_names.rs"),
+//!     )
+//!     .expect("generate project names");
+//! }
+//! ```
+//!
+//! Then include the generated file in your crate:
+//!
+//! ```ignore
+//! mod project {
+//!     include!(concat!(env!("OUT_DIR"), "/project_names.rs"));
+//! }
+//!
+//! if Input::singleton().is_action_pressed(project::actions::JUMP) { ... }
+//! ```
+//!
+//! The generated file contains the modules `actions` (section `[input]`), `groups` (section `[global_group]`, Godot 4.3+) and `autoloads`
+//! (section `[autoload]`). Each holds one `&str` constant per name, in `SCREAMING_SNAKE_CASE`, plus an `ALL` slice. A name that would
+//! itself map to `ALL` gets the constant `ALL_` instead.
+//!
+//! Constants are `&str` rather than `StringName`, since `StringName` cannot be constructed in const context. They can be passed directly
+//! to parameters of type `impl AsArg<StringName>`, where godot-rust caches the conversion, so repeated calls don't create a new
+//! `StringName` each time. Where a `StringName` value is needed (e.g. to store it), convert once with `StringName::from()` and keep it.
+//!
+//! Only names stored in `project.godot` are included. Built-in actions such as `ui_accept` appear only if they have been modified in the
+//! project settings.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+/// Sections of `project.godot` and the names of the generated modules.
+const SECTIONS: &[(&str, &str, &str)] = &[
+    (
+        "input",
+        "actions",
+        "Input actions, from the `[input]` section.",
+    ),
+    (
+        "global_group",
+        "groups",
+        "Global node groups, from the `[global_group]` section.",
+    ),
+    (
+        "autoload",
+        "autoloads",
+        "Autoload singletons, from the `[autoload]` section.",
+    ),
+];
+
+/// Reads `project_godot` and writes a Rust file with name constants to `out_file`.
+///
+/// Also instructs Cargo to re-run the build script when `project_godot` changes.
+///
+/// # Errors
+/// If reading or writing fails, or if two names in the same section map to the same Rust identifier (e.g. `move-left` and `move_left`).
+pub fn generate_project_names(
+    project_godot: impl AsRef<Path>,
+    out_file: impl AsRef<Path>,
+) -> io::Result<()> {
+    let project_godot = project_godot.as_ref();
+    println!("cargo:rerun-if-changed={}", project_godot.display());
+
+    let content = std::fs::read_to_string(project_godot)?;
+    let code = make_project_names(&content)
+        .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))?;
+
+    std::fs::write(out_file, code)
+}
+
+/// Generates the Rust code for the names in `project.godot` content.
+fn make_project_names(content: &str) -> Result<String, String> {
+    let keys = parse_section_keys(content);
+
+    let mut code =
+        String::from("// Generated by godot-bindings from project.godot. Do not edit.\n");
+    for (section, module, doc) in SECTIONS {
+        let names = keys.get(*section).map(Vec::as_slice).unwrap_or_default();
+
+        let mut constants = BTreeMap::new();
+        for name in names {
+            let ident = to_const_ident(name);
+            if let Some(previous) = constants.insert(ident.clone(), name) {
+                return Err(format!(
+                    "[{section}] names {previous:?} and {name:?} both map to constant `{ident}`"
+                ));
+            }
+        }
+
+        writeln!(code, "\n#[doc = {doc:?}]").unwrap();
+        writeln!(code, "#[allow(dead_code)]").unwrap();
+        writeln!(code, "pub mod {module} {{").unwrap();
+        for name in names {
+            writeln!(
+                code,
+                "    pub const {}: &str = {name:?};",
+                to_const_ident(name)
+            )
+            .unwrap();
+        }
+
+        let all = names
+            .iter()
+            .map(|name| to_const_ident(name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            code,
+            "\n    /// All names in this module, in `project.godot` order."
+        )
+        .unwrap();
+        writeln!(code, "    pub const ALL: &[&str] = &[{all}];").unwrap();
+        writeln!(code, "}}").unwrap();
+    }
+
+    Ok(code)
+}
+
+/// Returns the top-level keys of each section, in order of appearance.
+///
+/// Values may span multiple lines (e.g. the event dictionaries of input actions), so lines are only considered keys outside of brackets.
+fn parse_section_keys(content: &str) -> BTreeMap<String, Vec<String>> {
+    let mut sections = BTreeMap::<String, Vec<String>>::new();
+    let mut current_section = None;
+    let mut depth = 0_i32;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if depth == 0 {
+            if trimmed.is_empty() || trimmed.starts_with(';') {
+                continue;
+            }
+
+            if let Some(section) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                current_section = Some(section.to_string());
+                continue;
+            }
+
+            if let (Some(section), Some((key, _))) = (&current_section, trimmed.split_once('=')) {
+                let key = key.trim();
+                let key = key
+                    .strip_prefix('"')
+                    .and_then(|k| k.strip_suffix('"'))
+                    .unwrap_or(key);
+
+                sections
+                    .entry(section.clone())
+                    .or_default()
+                    .push(key.to_string());
+            }
+        }
+
+        depth += bracket_balance(trimmed);
+    }
+
+    sections
+}
+
+/// Net number of opened brackets in `line`, ignoring those inside string literals.
+fn bracket_balance(line: &str) -> i32 {
+    let mut balance = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '{' | '[' | '(' if !in_string => balance += 1,
+            '}' | ']' | ')' if !in_string => balance -= 1,
+            _ => {}
+        }
+    }
+
+    balance
+}
+
+/// Converts a name such as `move-left`, `ui accept` or `GameState` to a constant identifier (`MOVE_LEFT`, `UI_ACCEPT`, `GAME_STATE`).
+fn to_const_ident(name: &str) -> String {
+    let mut ident = String::with_capacity(name.len() + 4);
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            // Split camel case: `GameState` -> `GAME_STATE`.
+            if c.is_ascii_uppercase() && prev_lower {
+                ident.push('_');
+            }
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+            ident.push(c.to_ascii_uppercase());
+        } else {
+            if !ident.is_empty() && !ident.ends_with('_') {
+                ident.push('_');
+            }
+            prev_lower = false;
+        }
+    }
+
+    if ident.len() > 1 && ident.ends_with('_') {
+        ident.pop();
+    }
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+
+    // Reserved for the slice of all names.
+    if ident == "ALL" {
+        ident.push('_');
+    }
+
+    ident
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_parse_section_keys() {
+    let content = r#"
+config_version=5
+
+[application]
+
+config/name="Test"
+
+[autoload]
+
+GameState="*res://game_state.gd"
+
+[global_group]
+
+enemies="Hostile units"
+
+[input]
+
+jump={
+"deadzone": 0.5,
+"events": [Object(InputEventKey,"resource_local_to_scene":false,"keycode":0,"unicode":32,"echo":false,"script":null)
+]
+}
+"move left"={
+"deadzone": 0.5,
+"events": []
+}
+"#;
+
+    let keys = parse_section_keys(content);
+    assert_eq!(keys["input"], ["jump", "move left"]);
+    assert_eq!(keys["global_group"], ["enemies"]);
+    assert_eq!(keys["autoload"], ["GameState"]);
+    assert_eq!(keys["application"], ["config/name"]);
+}
+
+#[test]
+fn test_make_project_names() {
+    let content = "[input]\n\njump={\n\"events\": []\n}\nmove-left={\n\"events\": []\n}\n";
+
+    let code = make_project_names(content).unwrap();
+    assert!(code.contains(r#"pub const JUMP: &str = "jump";"#));
+    assert!(code.contains(r#"pub const MOVE_LEFT: &str = "move-left";"#));
+    assert!(code.contains("pub const ALL: &[&str] = &[JUMP, MOVE_LEFT];"));
+    assert!(code.contains("pub mod groups {"));
+
+    let content = "[global_group]\nall=\"\"\nenemies=\"\"\n";
+    let code = make_project_names(content).unwrap();
+    assert!(code.contains(r#"pub const ALL_: &str = "all";"#));
+    assert!(code.contains("pub const ALL: &[&str] = &[ALL_, ENEMIES];"));
+
+    let content = "[input]\nmove-left={}\nmove_left={}\n";
+    let err = make_project_names(content).unwrap_err();
+    assert!(err.contains("MOVE_LEFT"), "{err}");
+}
+
+#[test]
+fn test_to_const_ident() {
+    assert_eq!(to_const_ident("jump"), "JUMP");
+    assert_eq!(to_const_ident("ui accept"), "UI_ACCEPT");
+    assert_eq!(to_const_ident("2d/player"), "_2D_PLAYER");
+    assert_eq!(to_const_ident("GameState"), "GAME_STATE");
+    assert_eq!(to_const_ident("shoot!"), "SHOOT");
+    assert_eq!(to_const_ident("all"), "ALL_");
+    assert_eq!(to_const_ident("all units"), "ALL_UNITS");
+}
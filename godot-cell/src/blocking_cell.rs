@@ -139,6 +139,11 @@ impl<T> GdCellBlocking<T> {
         self.inner.as_ref().is_currently_bound()
     }
 
+    /// Returns the number of live shared and mutable references across all threads, including inaccessible ones.
+    pub fn borrow_count(&self) -> usize {
+        self.inner.as_ref().borrow_count()
+    }
+
     /// Blocks the current thread until all mutable and shared references have been dropped.
     fn block_mut<'a>(
         &self,
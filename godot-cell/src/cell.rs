@@ -67,6 +67,11 @@ impl<T> GdCell<T> {
     pub fn is_currently_bound(&self) -> bool {
         self.0.as_ref().is_currently_bound()
     }
+
+    /// Returns the number of live shared and mutable references, including inaccessible ones.
+    pub fn borrow_count(&self) -> usize {
+        self.0.as_ref().borrow_count()
+    }
 }
 
 /// Internals of [`GdCell`].
@@ -162,6 +167,13 @@ impl<T> GdCellInner<T> {
         state.borrow_state.shared_count() > 0 || state.borrow_state.mut_count() > 0
     }
 
+    /// Returns the number of live shared and mutable references, including inaccessible ones.
+    pub(crate) fn borrow_count(self: Pin<&Self>) -> usize {
+        let state = self.state.lock().unwrap();
+
+        state.borrow_state.shared_count() + state.borrow_state.mut_count()
+    }
+
     /// Similar to [`Self::is_currently_bound`] but only counts mutable references and ignores shared references.
     pub(crate) fn is_currently_mutably_bound(self: Pin<&Self>) -> bool {
         let state = self.state.lock().unwrap();
@@ -350,4 +362,26 @@ mod test {
         drop(guard1);
         drop(guard2);
     }
+
+    #[test]
+    fn borrow_count_includes_inaccessible() {
+        let cell = GdCell::new(0);
+        assert_eq!(cell.borrow_count(), 0);
+
+        let mut guard1 = cell.borrow_mut().unwrap();
+        assert_eq!(cell.borrow_count(), 1);
+
+        let inaccessible_guard = cell.make_inaccessible(&mut *guard1).unwrap();
+        let guard2 = cell.borrow().unwrap();
+        let guard3 = cell.borrow().unwrap();
+        assert_eq!(cell.borrow_count(), 3);
+
+        drop(guard2);
+        drop(guard3);
+        drop(inaccessible_guard);
+        assert_eq!(cell.borrow_count(), 1);
+
+        drop(guard1);
+        assert_eq!(cell.borrow_count(), 0);
+    }
 }
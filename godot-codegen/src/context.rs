@@ -21,6 +21,12 @@ use std::collections::{HashMap, HashSet};
 pub struct Context<'a> {
     builtin_types: HashSet<&'a str>,
     native_structures_types: HashSet<&'a str>,
+    /// All classes in the JSON API, including those excluded from codegen.
+    json_classes: HashSet<&'a str>,
+    /// Qualified enum and bitfield names as they appear in the JSON API, e.g. `Error` or `Node.ProcessMode`.
+    json_enums: HashSet<String>,
+    /// Warnings already emitted, to report each one only once.
+    emitted_warnings: HashSet<String>,
     singletons: HashSet<&'a str>,
     inheritance_tree: InheritanceTree,
     cached_rust_types: HashMap<GodotTy, RustTy>,
@@ -55,6 +61,9 @@ impl<'a> Context<'a> {
             ctx.native_structures_types.insert(ty_name);
         }
 
+        // Needed before the class loop below, which already checks methods for unknown types.
+        Self::populate_json_types(api, &mut ctx);
+
         let mut engine_classes = HashMap::new();
         for class in api.classes.iter() {
             let class_name = TyName::from_godot(&class.name);
@@ -136,6 +145,28 @@ impl<'a> Context<'a> {
         ctx
     }
 
+    fn populate_json_types(api: &'a JsonExtensionApi, ctx: &mut Context<'a>) {
+        for enum_ in api.global_enums.iter() {
+            ctx.json_enums.insert(enum_.name.clone());
+        }
+
+        for builtin in api.builtin_classes.iter() {
+            for enum_ in option_as_slice(&builtin.enums) {
+                ctx.json_enums
+                    .insert(format!("{}.{}", builtin.name, enum_.name));
+            }
+        }
+
+        for class in api.classes.iter() {
+            ctx.json_classes.insert(class.name.as_str());
+
+            for enum_ in option_as_slice(&class.enums) {
+                ctx.json_enums
+                    .insert(format!("{}.{}", class.name, enum_.name));
+            }
+        }
+    }
+
     fn populate_notification_constants(
         class_name: &TyName,
         constants: &[JsonClassConstant],
@@ -262,6 +293,23 @@ impl<'a> Context<'a> {
         self.native_structures_types.contains(ty_name)
     }
 
+    /// Checks if the JSON API declares this class, regardless of whether it's excluded from codegen.
+    pub fn is_json_class(&self, class_name: &str) -> bool {
+        self.json_classes.contains(class_name)
+    }
+
+    /// Checks if the JSON API declares this enum or bitfield, given as `Class.Enum` or `GlobalEnum`.
+    pub fn is_json_enum(&self, qualified_name: &str) -> bool {
+        self.json_enums.contains(qualified_name)
+    }
+
+    /// Emits a Cargo warning, unless the same message has already been emitted.
+    pub fn warn_once(&mut self, message: String) {
+        if self.emitted_warnings.insert(message.clone()) {
+            println!("cargo:warning={message}");
+        }
+    }
+
     pub fn is_singleton(&self, class_name: &str) -> bool {
         self.singletons.contains(class_name)
     }
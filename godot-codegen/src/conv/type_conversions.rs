@@ -25,6 +25,10 @@ fn to_hardcoded_rust_ident(full_ty: &GodotTy) -> Option<&str> {
     let ty = full_ty.ty.as_str();
     let meta = full_ty.meta.as_deref();
 
+    if !is_meta_supported(ty, meta) {
+        panic!("unhandled type {ty:?} with meta {meta:?}");
+    }
+
     let result = match (ty, meta) {
         // Integers
         ("int", Some("int64") | None) => "i64",
@@ -38,16 +42,13 @@ fn to_hardcoded_rust_ident(full_ty: &GodotTy) -> Option<&str> {
         // TODO handle char types as `char`?
         ("int", Some("char16")) => "u16",
         ("int", Some("char32")) => "u32",
-        ("int", Some(meta)) => panic!("unhandled type int with meta {meta:?}"),
 
         // Floats (with single precision builds)
         ("float", Some("double") | None) => "f64",
         ("float", Some("float")) => "f32",
-        ("float", Some(meta)) => panic!("unhandled type float with meta {meta:?}"),
 
         // Doubles (with double precision builds)
         ("double", None) => "f64",
-        ("double", Some(meta)) => panic!("unhandled type double with meta {meta:?}"),
 
         // Others
         ("bool", None) => "bool",
@@ -66,14 +67,58 @@ fn to_hardcoded_rust_ident(full_ty: &GodotTy) -> Option<&str> {
         ("real_t", None) => "real",
         ("void", None) => "c_void",
 
-        (ty, Some(meta)) => panic!("unhandled type {ty:?} with meta {meta:?}"),
-
         _ => return None,
     };
 
     Some(result)
 }
 
+/// Whether `ty` (without meta) is mapped to a hardcoded Rust type, e.g. `int` or `uint8_t`.
+pub(crate) fn is_hardcoded_rust_type(ty: &str) -> bool {
+    let full_ty = GodotTy {
+        ty: ty.to_string(),
+        meta: None,
+    };
+
+    to_hardcoded_rust_ident(&full_ty).is_some()
+}
+
+/// Whether the `meta` qualifier of a JSON type can be mapped to a Rust type.
+///
+/// Custom Godot builds may use qualifiers that gdext does not know; APIs with such types are skipped.
+pub(crate) fn is_meta_supported(ty: &str, meta: Option<&str>) -> bool {
+    let Some(meta) = meta else {
+        return true;
+    };
+
+    match ty {
+        "int" => matches!(
+            meta,
+            "int64"
+                | "int32"
+                | "int16"
+                | "int8"
+                | "uint64"
+                | "uint32"
+                | "uint16"
+                | "uint8"
+                | "char16"
+                | "char32"
+        ),
+        "float" => matches!(meta, "double" | "float"),
+
+        // Typed arrays pass the meta on to their element type.
+        _ => ty
+            .strip_prefix("typedarray::")
+            .is_some_and(|elem_ty| is_meta_supported(elem_ty, Some(meta))),
+    }
+}
+
+/// Whether `ty` is an enum that is mapped manually, e.g. `enum::Variant.Type`.
+pub(crate) fn is_hardcoded_rust_enum(ty: &str) -> bool {
+    to_hardcoded_rust_enum(ty).is_some()
+}
+
 fn to_hardcoded_rust_enum(ty: &str) -> Option<&str> {
     // Some types like Vector2[i].Axis may not appear in Godot's current JSON, but they are encountered
     // in custom Godot builds, e.g. when extending PhysicsServer2D.
@@ -7,11 +7,14 @@
 use crate::context::{Context, NotificationEnum};
 use crate::generator::functions_common::{FnCode, FnDefinition, FnDefinitions};
 use crate::generator::method_tables::MethodTableKey;
-use crate::generator::{constants, docs, enums, functions_common, notifications, virtual_traits};
+use crate::generator::{
+    constants, docs, enums, functions_common, notifications, signals, virtual_traits,
+};
 use crate::models::domain::{
     ApiView, Class, ClassLike, ClassMethod, ExtensionApi, FnDirection, FnQualifier, Function,
-    ModName, TyName,
+    ModName, RustTy, TyName,
 };
+use crate::special_cases;
 use crate::util::{ident, make_string_name};
 use crate::{conv, util, SubmitFn};
 use proc_macro2::{Ident, TokenStream};
@@ -76,7 +79,6 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 
     // Strings
     let godot_class_str = &class_name.godot_ty;
-    let class_name_cstr = util::c_str(godot_class_str);
     let virtual_trait_str = class_name.virtual_trait_name();
 
     // Idents and tokens
@@ -154,6 +156,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 
     // notify() and notify_reversed() are added after other methods, to list others first in docs.
     let notify_methods = notifications::make_notify_methods(class_name, ctx);
+    let await_methods = signals::make_await_methods(class);
 
     let (assoc_memory, assoc_dyn_memory, is_exportable) = make_bounds(class, ctx);
 
@@ -207,6 +210,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
                 #constructor
                 #methods
                 #notify_methods
+                #await_methods
                 #internal_methods
                 #constants
             }
@@ -216,10 +220,8 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
                 // Code duplicated in godot-macros.
                 fn class_name() -> ClassName {
                     // Optimization note: instead of lazy init, could use separate static which is manually initialized during registration.
-                    static CLASS_NAME: std::sync::OnceLock<ClassName> = std::sync::OnceLock::new();
-
-                    let name: &'static ClassName = CLASS_NAME.get_or_init(|| ClassName::alloc_next_ascii(#class_name_cstr));
-                    *name
+                    static CLASS_NAME: crate::meta::StaticClassName = crate::meta::StaticClassName::new(#godot_class_str);
+                    CLASS_NAME.get()
                 }
 
                 const INIT_LEVEL: crate::init::InitLevel = #init_level;
@@ -474,10 +476,17 @@ fn make_class_method_definition(
         return FnDefinition::none();
     };
 
-    let rust_class_name = class.name().rust_ty.to_string();
-    let rust_method_name = method.name();
+    let godot_class_name = class.name().godot_ty.as_str();
     let godot_method_name = method.godot_name();
 
+    // Call contexts (used in error messages) carry Rust names. With debug-ptrcall-checks, they carry Godot names instead (e.g. `GDScript`,
+    // not `GdScript`), because the validation looks up the method in ClassDB under the call context's names.
+    let (ctx_class_name, ctx_method_name) = if cfg!(feature = "debug-ptrcall-checks") {
+        (godot_class_name.to_string(), godot_method_name.to_string())
+    } else {
+        (class.name().rust_ty.to_string(), method.name().to_string())
+    };
+
     let receiver = functions_common::make_receiver(method.qualifier(), quote! { self.object_ptr });
 
     let table_index = ctx.get_table_index(&MethodTableKey::from_class(class, method));
@@ -489,7 +498,6 @@ fn make_class_method_definition(
     };
 
     let fptr_access = if cfg!(feature = "codegen-lazy-fptrs") {
-        let godot_class_name = &class.name().godot_ty;
         quote! {
             fptr_by_key(sys::lazy_keys::ClassMethodKey {
                 class_name: #godot_class_name,
@@ -507,8 +515,8 @@ fn make_class_method_definition(
 
         <CallSig as PtrcallSignatureTuple>::out_class_ptrcall(
             method_bind,
-            #rust_class_name,
-            #rust_method_name,
+            #ctx_class_name,
+            #ctx_method_name,
             #object_ptr,
             #maybe_instance_id,
             args,
@@ -520,8 +528,8 @@ fn make_class_method_definition(
 
         <CallSig as VarcallSignatureTuple>::out_class_varcall(
             method_bind,
-            #rust_class_name,
-            #rust_method_name,
+            #ctx_class_name,
+            #ctx_method_name,
             #object_ptr,
             #maybe_instance_id,
             args,
@@ -529,7 +537,9 @@ fn make_class_method_definition(
         )
     };
 
-    functions_common::make_function_definition(
+    let non_null_accessor = make_non_null_accessor(class, method, &receiver, cfg_attributes);
+
+    let mut definition = functions_common::make_function_definition(
         method,
         &FnCode {
             receiver,
@@ -540,5 +550,61 @@ fn make_class_method_definition(
         },
         None,
         cfg_attributes,
-    )
+    );
+
+    definition.functions.extend(non_null_accessor);
+    definition
+}
+
+/// For methods known to never return null, generates `method_expect()` returning `Gd<T>` instead of `Option<Gd<T>>`.
+fn make_non_null_accessor(
+    class: &Class,
+    method: &ClassMethod,
+    receiver: &functions_common::FnReceiver,
+    cfg_attributes: &TokenStream,
+) -> TokenStream {
+    if !special_cases::is_class_method_return_non_null(class.name(), method.godot_name()) {
+        return TokenStream::new();
+    }
+
+    let Some(RustTy::EngineClass {
+        tokens: return_ty, ..
+    }) = &method.return_value().type_
+    else {
+        panic!(
+            "non-null return declared for {}::{}, but it does not return an object",
+            class.name().godot_ty,
+            method.godot_name()
+        );
+    };
+    assert!(
+        method.params().is_empty() && !method.is_vararg(),
+        "non-null return declared for {}::{}, but only parameterless methods are supported",
+        class.name().godot_ty,
+        method.godot_name()
+    );
+
+    let method_name = method.name_ident();
+    let expect_name = format_ident!("{}_expect", method_name);
+    let receiver_param = &receiver.param;
+    let self_prefix = &receiver.self_prefix;
+
+    let doc = format!(
+        "Like [`{method_name}()`][Self::{method_name}], but returns the object directly, as Godot never returns null here.\n\n\
+        # Panics\n\
+        If Godot unexpectedly returns null."
+    );
+    let panic_msg = format!(
+        "{}::{}() unexpectedly returned null",
+        class.name().rust_ty,
+        method_name
+    );
+
+    quote! {
+        #[doc = #doc]
+        #cfg_attributes
+        pub fn #expect_name(#receiver_param) -> #return_ty {
+            #self_prefix #method_name().expect(#panic_msg)
+        }
+    }
 }
@@ -478,6 +478,19 @@ pub(crate) fn make_param_or_field_type(
             }
         }
 
+        // Integers: impl AsArg<T>, but stored and passed on by value.
+        RustTy::BuiltinIdent {
+            arg_passing: ArgPassing::ImplAsArgByValue,
+            ..
+        } => {
+            special_ty = Some(quote! { #ty });
+
+            match decl {
+                FnParamDecl::FnPublic | FnParamDecl::FnPublicLifetime => quote! { impl AsArg<#ty> },
+                FnParamDecl::FnInternal | FnParamDecl::Field => quote! { #ty },
+            }
+        }
+
         // By-ref: Array, Dictionary, Variant, Callable, ...
         RustTy::BuiltinIdent {
             arg_passing: ArgPassing::ByRef,
@@ -531,6 +544,18 @@ pub(crate) fn make_arg_expr(name: &Ident, ty: &RustTy, expr: FnArgExpr) -> Token
             FnArgExpr::StoreInDefaultField => quote! { CowArg::Owned(#name) },
         },
 
+        // Integers.
+        RustTy::BuiltinIdent {
+            arg_passing: ArgPassing::ImplAsArgByValue,
+            ..
+        } => match expr {
+            FnArgExpr::PassToFfi => quote! { #name.into_arg() },
+            FnArgExpr::PassToFfiFromEx => quote! { #name },
+            FnArgExpr::Forward => quote! { #name },
+            FnArgExpr::StoreInField => quote! { #name.into_arg() },
+            FnArgExpr::StoreInDefaultField => quote! { #name },
+        },
+
         // By-ref: Array, Dictionary, Variant, Callable, ...
         RustTy::BuiltinIdent {
             arg_passing: ArgPassing::ByRef,
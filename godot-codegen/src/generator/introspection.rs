@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Generates the tables behind `godot::meta::introspection`, describing the classes as they are generated (not as they appear in the JSON).
+
+use crate::models::domain::{
+    Class, ClassMethod, ClassSignal, FnParam, FnQualifier, Function, RustTy,
+};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+pub fn make_introspection_file(classes: &[Class]) -> TokenStream {
+    let class_infos = classes.iter().map(make_class_info);
+
+    quote! {
+        use crate::meta::introspection::*;
+
+        pub static CLASSES: &[ClassInfo] = &[
+            #( #class_infos, )*
+        ];
+    }
+}
+
+fn make_class_info(class: &Class) -> TokenStream {
+    let godot_name = &class.common.name.godot_ty;
+    let rust_name = class.common.name.rust_ty.to_string();
+    let base = match &class.inherits {
+        Some(base) => quote! { Some(#base) },
+        None => quote! { None },
+    };
+    let init_level = class.api_level.to_init_level();
+    let is_refcounted = class.is_refcounted;
+    let is_instantiable = class.is_instantiable;
+    let is_experimental = class.is_experimental;
+
+    // Private methods are only used internally (e.g. to implement wrappers), so they are not part of the public API.
+    let methods = class
+        .methods
+        .iter()
+        .filter(|method| !method.is_private())
+        .map(make_method_info);
+    let signals = class.signals.iter().map(make_signal_info);
+
+    quote! {
+        ClassInfo {
+            godot_name: #godot_name,
+            rust_name: #rust_name,
+            base: #base,
+            init_level: #init_level,
+            is_refcounted: #is_refcounted,
+            is_instantiable: #is_instantiable,
+            is_experimental: #is_experimental,
+            methods: &[ #( #methods, )* ],
+            signals: &[ #( #signals, )* ],
+        }
+    }
+}
+
+fn make_method_info(method: &ClassMethod) -> TokenStream {
+    let godot_name = method.godot_name();
+    let rust_name = method.name();
+    let params = method.params().iter().map(make_param_info);
+    let return_type = match &method.return_value().type_ {
+        Some(ty) => {
+            let ty = type_string(ty);
+            quote! { Some(#ty) }
+        }
+        None => quote! { None },
+    };
+    let qualifier = match method.qualifier() {
+        FnQualifier::Const => quote! { MethodQualifier::Const },
+        FnQualifier::Mut => quote! { MethodQualifier::Mut },
+        FnQualifier::Static | FnQualifier::Global => quote! { MethodQualifier::Static },
+    };
+    let is_virtual = method.is_virtual();
+    let is_vararg = method.is_vararg();
+
+    quote! {
+        MethodInfo {
+            godot_name: #godot_name,
+            rust_name: #rust_name,
+            params: &[ #( #params, )* ],
+            return_type: #return_type,
+            qualifier: #qualifier,
+            is_virtual: #is_virtual,
+            is_vararg: #is_vararg,
+        }
+    }
+}
+
+fn make_signal_info(signal: &ClassSignal) -> TokenStream {
+    let name = &signal.name;
+    let params = signal.params.iter().map(make_param_info);
+
+    quote! {
+        SignalInfo {
+            name: #name,
+            params: &[ #( #params, )* ],
+        }
+    }
+}
+
+fn make_param_info(param: &FnParam) -> TokenStream {
+    let name = param.name.to_string();
+    let rust_type = type_string(&param.type_);
+    let has_default = param.default_value.is_some();
+
+    quote! {
+        ParamInfo {
+            name: #name,
+            rust_type: #rust_type,
+            has_default: #has_default,
+        }
+    }
+}
+
+/// Type as seen from user code, e.g. `godot::classes::node::ProcessMode` instead of `crate::classes::node::ProcessMode`.
+///
+/// Engine classes are described as `Gd<T>`, although they are passed as `impl AsObjectArg<T>` and returned as `Option<Gd<T>>`.
+fn type_string(ty: &RustTy) -> String {
+    match ty {
+        // Built structurally, as the space after `*const`/`*mut` is significant.
+        RustTy::RawPointer { inner, is_const } => {
+            let qualifier = if *is_const { "const" } else { "mut" };
+            format!("*{qualifier} {}", type_string(inner))
+        }
+
+        // Paths and generics only: spaces between tokens are insignificant.
+        other => other
+            .to_token_stream()
+            .to_string()
+            .replace(' ', "")
+            .replace("crate::", "godot::"),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::domain::ArgPassing;
+    use crate::util::ident;
+
+    #[test]
+    fn type_string_raw_pointers() {
+        let c_void = RustTy::BuiltinIdent {
+            ty: ident("c_void"),
+            arg_passing: ArgPassing::ByValue,
+        };
+        let ptr = RustTy::RawPointer {
+            inner: Box::new(c_void),
+            is_const: false,
+        };
+        let ptr_ptr = RustTy::RawPointer {
+            inner: Box::new(ptr.clone()),
+            is_const: true,
+        };
+
+        assert_eq!(type_string(&ptr), "*mut c_void");
+        assert_eq!(type_string(&ptr_ptr), "*const *mut c_void");
+
+        let path = RustTy::RawPointer {
+            inner: Box::new(RustTy::EngineEnum {
+                tokens: quote! { crate::classes::node::ProcessMode },
+                surrounding_class: Some("Node".to_string()),
+                is_bitfield: false,
+            }),
+            is_const: true,
+        };
+        assert_eq!(
+            type_string(&path),
+            "*const godot::classes::node::ProcessMode"
+        );
+    }
+}
@@ -437,15 +437,36 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
     let unused_attr = (method_count == 0).then(|| quote! { #[allow(unused_variables)] });
     let named_method_api = make_named_accessors(&named_accessors, &fptr_type);
 
+    // The tables are global and populated on first use of each method. With experimental-threads, engine methods may be called from
+    // several threads, so two threads could insert into the same HashMap at once -- a RefCell would panic or, being !Sync, not even
+    // compile in a static. The Mutex is only emitted in that configuration; single-threaded builds keep the RefCell.
+    // The lock is held only for the HashMap lookup (and on a miss, the one-time ClassDB query), not for the engine call itself.
+    let (cell_import, cell_type, cell_ctor, cell_access) = if cfg!(feature = "experimental-threads")
+    {
+        (
+            quote! { use std::sync::{Mutex, PoisonError}; },
+            quote! { Mutex<InnerTable> },
+            quote! { Mutex::new },
+            quote! { self.inner.lock().unwrap_or_else(PoisonError::into_inner) },
+        )
+    } else {
+        (
+            quote! { use std::cell::RefCell; },
+            quote! { RefCell<InnerTable> },
+            quote! { RefCell::new },
+            quote! { self.inner.borrow_mut() },
+        )
+    };
+
     // Assumes that inits already have a trailing comma.
     // This is necessary because some generators emit multiple lines (statements) per element.
     quote! {
         #imports
         use crate::StringCache;
         use std::collections::HashMap;
-        use std::cell::RefCell;
+        #cell_import
 
-        // Exists to be stored inside RefCell.
+        // Exists to be stored inside RefCell/Mutex.
         struct InnerTable {
             // 'static because at this point, the interface and lifecycle tables are globally available.
             string_cache: StringCache<'static>,
@@ -454,7 +475,7 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
 
         // Note: get_method_bind and other function pointers could potentially be stored as fields in table, to avoid interface_fn!.
         pub struct #table_name {
-            inner: RefCell<InnerTable>,
+            inner: #cell_type,
         }
 
         impl #table_name {
@@ -472,7 +493,7 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
                 };
 
                 Self {
-                    inner: RefCell::new(InnerTable {
+                    inner: #cell_ctor(InnerTable {
                         string_cache: StringCache::new(interface, lifecycle_table),
                         function_pointers: HashMap::new(),
                     }),
@@ -481,7 +502,7 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
 
             #[inline(always)]
             pub fn fptr_by_key(&self, key: #lazy_key_type) -> #fptr_type {
-                let mut guard = self.inner.borrow_mut();
+                let mut guard = #cell_access;
                 let inner = &mut *guard;
                 *inner.function_pointers.entry(key.clone()).or_insert_with(|| {
                     #lazy_method_init
@@ -768,3 +789,56 @@ pub(crate) fn make_table_accessor_name(class_ty: &TyName, method: &dyn Function)
         method.godot_name()
     )
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::json::load_extension_api;
+
+    /// Measures the real class method tables: how many methods are loaded by `load()`, which runs at startup.
+    ///
+    /// Every method loaded at startup keeps its name, hash and loading code alive in the binary, even if the user never calls it. With lazy
+    /// tables, methods are only loaded at their call sites, so the linker can strip unused ones.
+    #[test]
+    fn class_method_table_size() {
+        let mut watch = godot_bindings::StopWatch::start();
+        let json_api = load_extension_api(&mut watch);
+        let mut ctx = Context::build_from_api(&json_api);
+        let api = ExtensionApi::from_json(&json_api, &mut ctx);
+
+        let mut total_methods = 0;
+        for api_level in ClassCodegenLevel::with_tables() {
+            let method_count = api
+                .classes
+                .iter()
+                .filter(|class| class.api_level == api_level)
+                .flat_map(|class| class.methods.iter())
+                .filter(|method| matches!(method.direction(), FnDirection::Outbound { .. }))
+                .count();
+            total_methods += method_count;
+
+            let code = make_class_method_table(&api, api_level, &mut ctx).to_string();
+            let load_sites = code.matches("load_class_method").count();
+
+            if cfg!(feature = "codegen-lazy-fptrs") {
+                // Single generic load site in fptr_by_key(), independent of API size.
+                assert_eq!(load_sites, 1, "lazy table for {}", api_level.lower());
+            } else {
+                // One load per method, all reachable from load().
+                assert_eq!(
+                    load_sites,
+                    method_count,
+                    "eager table for {}",
+                    api_level.lower()
+                );
+            }
+        }
+
+        assert!(
+            total_methods > 1000,
+            "API should contain many class methods, found {total_methods}"
+        );
+    }
+}
@@ -8,6 +8,7 @@
 use crate::context::Context;
 use crate::models::domain::{ClassCodegenLevel, ExtensionApi};
 use crate::SubmitFn;
+use proc_macro2::TokenStream;
 use quote::quote;
 use std::path::Path;
 
@@ -21,10 +22,13 @@ pub mod enums;
 pub mod extension_interface;
 pub mod functions_common;
 pub mod gdext_build_struct;
+#[cfg(feature = "api-introspection")]
+pub mod introspection;
 pub mod lifecycle_builtins;
 pub mod method_tables;
 pub mod native_structures;
 pub mod notifications;
+pub mod signals;
 pub mod utility_functions;
 pub mod virtual_traits;
 
@@ -124,12 +128,19 @@ pub fn generate_sys_builtin_lifecycle_file(
 
 pub fn generate_core_mod_file(gen_path: &Path, submit_fn: &mut SubmitFn) {
     // When invoked by another crate during unit-test (not integration test), don't run generator.
+    let introspection = if cfg!(feature = "api-introspection") {
+        quote! { pub mod introspection; }
+    } else {
+        TokenStream::new()
+    };
+
     let code = quote! {
         pub mod central;
         pub mod classes;
         pub mod builtin_classes;
         pub mod utilities;
         pub mod native;
+        #introspection
     };
 
     submit_fn(gen_path.join("mod.rs"), code);
@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::models::domain::{Class, ClassLike, ClassSignal, RustTy};
+use crate::util::ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates one `await_<signal>()` method per signal of the class, returning a `SignalFuture`.
+pub fn make_await_methods(class: &Class) -> TokenStream {
+    let methods = class
+        .signals
+        .iter()
+        .map(|signal| make_await_method(class, signal));
+
+    quote! {
+        #( #methods )*
+    }
+}
+
+fn make_await_method(class: &Class, signal: &ClassSignal) -> TokenStream {
+    let method_name = ident(&format!("await_{}", signal.name));
+    let signal_name = &signal.name;
+
+    // Objects may be null when emitted, so they are received as Option<Gd<T>>.
+    let param_types = signal.params.iter().map(|param| match &param.type_ {
+        RustTy::EngineClass { tokens, .. } => quote! { Option<#tokens> },
+        other => quote! { #other },
+    });
+
+    let param_names = signal
+        .params
+        .iter()
+        .map(|param| format!("`{}`", param.name))
+        .collect::<Vec<_>>();
+
+    let result_doc = if param_names.is_empty() {
+        "The future resolves to `()`.".to_string()
+    } else {
+        format!(
+            "The future resolves to a tuple of the signal parameters ({}).",
+            param_names.join(", ")
+        )
+    };
+
+    let doc = format!(
+        "Returns a future that resolves on the next emission of the `{signal_name}` signal.\n\n\
+        {result_doc}\n\n\
+        _Godot equivalent: `await {class}.{signal_name}`_",
+        class = class.name().godot_ty,
+    );
+
+    quote! {
+        #[doc = #doc]
+        pub fn #method_name(&self) -> crate::tools::SignalFuture<( #( #param_types, )* )> {
+            crate::tools::SignalFuture::__from_object(self.__checked_id(), #signal_name)
+        }
+    }
+}
@@ -191,6 +191,13 @@ pub fn generate_core_files(core_gen_path: &Path) {
     );
     watch.record("generate_native_structures_files");
 
+    #[cfg(feature = "api-introspection")]
+    {
+        let code = generator::introspection::make_introspection_file(&api.classes);
+        submit_fn(core_gen_path.join("introspection.rs"), code);
+        watch.record("generate_introspection_file");
+    }
+
     #[cfg(feature = "codegen-rustfmt")]
     {
         rustfmt_files();
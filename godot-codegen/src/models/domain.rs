@@ -166,6 +166,7 @@ pub struct Class {
     pub constants: Vec<ClassConstant>,
     pub enums: Vec<Enum>,
     pub methods: Vec<ClassMethod>,
+    pub signals: Vec<ClassSignal>,
 }
 
 impl ClassLike for Class {
@@ -185,6 +186,15 @@ pub struct Singleton {
     // type_: String,
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Signals
+
+/// Signal declared by an engine class.
+pub struct ClassSignal {
+    pub name: String,
+    pub params: Vec<FnParam>,
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Constants
 
@@ -517,7 +527,7 @@ impl FnParam {
 
     pub fn new(method_arg: &JsonMethodArg, ctx: &mut Context) -> FnParam {
         let name = safe_ident(&method_arg.name);
-        let type_ = conv::to_rust_type(&method_arg.type_, method_arg.meta.as_ref(), ctx);
+        let type_ = Self::param_type(method_arg, ctx);
         let default_value = method_arg
             .default_value
             .as_ref()
@@ -530,11 +540,22 @@ impl FnParam {
         }
     }
 
+    fn param_type(method_arg: &JsonMethodArg, ctx: &mut Context) -> RustTy {
+        conv::to_rust_type(&method_arg.type_, method_arg.meta.as_ref(), ctx)
+    }
+
+    /// Declares a by-value builtin parameter as `impl AsArg<T>`, so that types converting to `T` (e.g. user enums) can be passed.
+    pub fn make_impl_as_arg(&mut self) {
+        if let RustTy::BuiltinIdent { arg_passing, .. } = &mut self.type_ {
+            *arg_passing = ArgPassing::ImplAsArgByValue;
+        }
+    }
+
     /// `impl AsObjectArg<T>` for object parameters. Only set if requested and `T` is an engine class.
     pub fn new_no_defaults(method_arg: &JsonMethodArg, ctx: &mut Context) -> FnParam {
         FnParam {
             name: safe_ident(&method_arg.name),
-            type_: conv::to_rust_type(&method_arg.type_, method_arg.meta.as_ref(), ctx),
+            type_: Self::param_type(method_arg, ctx),
             //type_: to_rust_type(&method_arg.type_, &method_arg.meta, ctx),
             default_value: None,
         }
@@ -710,6 +731,9 @@ pub enum ArgPassing {
     ByValue,
     ByRef,
     ImplAsArg,
+
+    /// Like `ByValue`, but declared as `impl AsArg<T>` to also accept user types converting to `T` (e.g. enums with `Via = i64`).
+    ImplAsArgByValue,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -10,8 +10,11 @@
 // TODO make this file private and only accessed by special_cases.rs.
 
 use crate::context::Context;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::conv;
+use crate::models::domain::TyName;
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases;
+use crate::util::option_as_slice;
 
 pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
     // The `cfg` below becomes `false` for api > 4.1 so clippy would complain it's always false.
@@ -63,7 +66,11 @@ fn is_type_excluded(_ty: &str, _ctx: &mut Context) -> bool {
     false
 }
 
-pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Context) -> bool {
+pub(crate) fn is_class_method_excluded(
+    class_name: &TyName,
+    method: &JsonClassMethod,
+    ctx: &mut Context,
+) -> bool {
     let is_arg_or_return_excluded = |ty: &str, _ctx: &mut Context| {
         // First check if the type is explicitly deleted. In Godot, type names are unique without further categorization,
         // so passing in a class name while checking for any types is fine.
@@ -73,47 +80,137 @@ pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Conte
         class_deleted || is_type_excluded(ty, _ctx)
     };
 
+    let return_value = method.return_value.as_ref();
+    let arguments = option_as_slice(&method.arguments);
+
     // Exclude if return type contains an excluded type.
-    if method
-        .return_value
-        .as_ref()
-        .is_some_and(|ret| is_arg_or_return_excluded(ret.type_.as_str(), ctx))
-    {
+    if return_value.is_some_and(|ret| is_arg_or_return_excluded(ret.type_.as_str(), ctx)) {
         return true;
     }
 
     // Exclude if any argument contains an excluded type.
-    if method.arguments.as_ref().is_some_and(|args| {
-        args.iter()
-            .any(|arg| is_arg_or_return_excluded(arg.type_.as_str(), ctx))
-    }) {
+    if arguments
+        .iter()
+        .any(|arg| is_arg_or_return_excluded(arg.type_.as_str(), ctx))
+    {
         return true;
     }
 
-    false
+    let types = return_value
+        .map(|ret| (ret.type_.as_str(), ret.meta.as_deref()))
+        .into_iter()
+        .chain(
+            arguments
+                .iter()
+                .map(|arg| (arg.type_.as_str(), arg.meta.as_deref())),
+        );
+
+    let api_name = format!("{}::{}", class_name.godot_ty, method.name);
+    is_any_type_unknown(&api_name, types, ctx)
 }
 
-#[cfg(feature = "codegen-full")]
-pub(crate) fn is_utility_function_excluded(
-    _function: &JsonUtilityFunction,
-    _ctx: &mut Context,
+pub(crate) fn is_class_signal_excluded(
+    class_name: &TyName,
+    signal: &JsonSignal,
+    ctx: &mut Context,
 ) -> bool {
-    false
+    let arguments = option_as_slice(&signal.arguments);
+
+    // Same rules as for method parameters.
+    if arguments.iter().any(|arg| {
+        special_cases::is_godot_type_deleted(arg.type_.as_str())
+            || is_type_excluded(arg.type_.as_str(), ctx)
+    }) {
+        return true;
+    }
+
+    let types = arguments
+        .iter()
+        .map(|arg| (arg.type_.as_str(), arg.meta.as_deref()));
+
+    let api_name = format!("signal {}::{}", class_name.godot_ty, signal.name);
+    is_any_type_unknown(&api_name, types, ctx)
 }
 
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_utility_function_excluded(
     function: &JsonUtilityFunction,
     ctx: &mut Context,
 ) -> bool {
-    function
-        .return_type
-        .as_ref()
-        .is_some_and(|ret| is_type_excluded(ret.as_str(), ctx))
-        || function.arguments.as_ref().is_some_and(|args| {
-            args.iter()
-                .any(|arg| is_type_excluded(arg.type_.as_str(), ctx))
-        })
+    let return_type = function.return_type.as_deref();
+    let arguments = option_as_slice(&function.arguments);
+
+    let is_excluded = return_type.is_some_and(|ret| is_type_excluded(ret, ctx))
+        || arguments
+            .iter()
+            .any(|arg| is_type_excluded(arg.type_.as_str(), ctx));
+
+    if is_excluded {
+        return true;
+    }
+
+    let types = return_type.map(|ret| (ret, None)).into_iter().chain(
+        arguments
+            .iter()
+            .map(|arg| (arg.type_.as_str(), arg.meta.as_deref())),
+    );
+
+    let api_name = format!("utility function {}", function.name);
+    is_any_type_unknown(&api_name, types, ctx)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Unknown types
+
+/// Checks if any of `types` cannot be mapped to Rust, and emits a Cargo warning naming `api_name` if so.
+///
+/// Custom Godot builds (e.g. with C++ modules) may expose APIs referring to classes or enums that are not part of the JSON, or using
+/// unknown `meta` qualifiers. Generating bindings for these would fail to compile, so they are skipped instead.
+fn is_any_type_unknown<'t>(
+    api_name: &str,
+    types: impl IntoIterator<Item = (&'t str, Option<&'t str>)>,
+    ctx: &mut Context,
+) -> bool {
+    for (ty, meta) in types {
+        if is_type_unknown(ty, meta, ctx) {
+            let meta = meta.map(|m| format!(" (meta {m})")).unwrap_or_default();
+            ctx.warn_once(format!(
+                "gdext codegen: skipped {api_name}, which uses unknown type `{ty}`{meta}"
+            ));
+            return true;
+        }
+    }
+
+    false
+}
+
+fn is_type_unknown(ty: &str, meta: Option<&str>, ctx: &Context) -> bool {
+    if !conv::is_meta_supported(ty, meta) {
+        return true;
+    }
+
+    // Pointers in virtual methods, e.g. "const uint8_t*" or "AudioFrame*".
+    if let Some(pointee) = ty.strip_suffix('*') {
+        let pointee = pointee.trim().trim_start_matches("const ");
+        return is_type_unknown(pointee, None, ctx);
+    }
+
+    if let Some(elem_ty) = ty.strip_prefix("typedarray::") {
+        return is_type_unknown(elem_ty, meta, ctx);
+    }
+
+    if let Some(enum_) = ty
+        .strip_prefix("enum::")
+        .or_else(|| ty.strip_prefix("bitfield::"))
+    {
+        return !ctx.is_json_enum(enum_) && !conv::is_hardcoded_rust_enum(ty);
+    }
+
+    let is_known = conv::is_hardcoded_rust_type(ty)
+        || ctx.is_builtin(ty)
+        || ctx.is_native_structure(ty)
+        || ctx.is_json_class(ty);
+
+    !is_known
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -162,6 +259,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "PathFollow2D",
     "PhysicsBody2D",
     "PrimitiveMesh",
+    "ProjectSettings",
     "RefCounted",
     "RenderingServer",
     "Resource",
@@ -25,7 +25,7 @@
 
 use crate::conv::to_enum_type_uncached;
 use crate::models::domain::{Enum, RustTy, TyName};
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases::codegen_special_cases;
 use crate::Context;
 use proc_macro2::Ident;
@@ -33,7 +33,7 @@ use proc_macro2::Ident;
 
 #[rustfmt::skip]
 pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ctx: &mut Context) -> bool {
-    if codegen_special_cases::is_class_method_excluded(method, ctx){
+    if codegen_special_cases::is_class_method_excluded(class_name, method, ctx){
         return true;
     }
     
@@ -84,6 +84,25 @@ pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ct
     }
 }
 
+/// Whether an `await_*()` method should be generated for the given signal.
+pub fn is_class_signal_deleted(
+    class_name: &TyName,
+    signal: &JsonSignal,
+    ctx: &mut Context,
+) -> bool {
+    // Signal futures rely on `Callable::from_local_fn()`, which is only available from Godot 4.2 on.
+    if cfg!(before_api = "4.2") {
+        return true;
+    }
+
+    // `FromSignalArgs` is implemented for tuples of up to 8 elements.
+    if signal.arguments.as_ref().is_some_and(|args| args.len() > 8) {
+        return true;
+    }
+
+    codegen_special_cases::is_class_signal_excluded(class_name, signal, ctx)
+}
+
 pub fn is_class_deleted(class_name: &TyName) -> bool {
     codegen_special_cases::is_class_excluded(&class_name.godot_ty)
         || is_godot_type_deleted(&class_name.godot_ty)
@@ -523,6 +542,86 @@ pub fn is_class_method_param_required(
     }
 }
 
+/// Integer parameters that conventionally hold values of a user-defined enum: custom item IDs and layer numbers.
+///
+/// These are declared as `impl AsArg<T>`, so enums deriving `GodotConvert` with a matching `via` type can be passed directly. All other
+/// integer parameters stay by-value, as `impl AsArg` would break type inference for expressions like `x.into()`.
+#[rustfmt::skip]
+pub fn is_class_method_param_user_enum(class_name: &TyName, godot_method_name: &str, param: &str) -> bool {
+    match (class_name.godot_ty.as_str(), godot_method_name) {
+        // Item IDs.
+        | ("PopupMenu", "add_item")
+        | ("PopupMenu", "add_icon_item")
+        | ("PopupMenu", "add_check_item")
+        | ("PopupMenu", "add_icon_check_item")
+        | ("PopupMenu", "add_radio_check_item")
+        | ("PopupMenu", "add_icon_radio_check_item")
+        | ("PopupMenu", "add_multistate_item")
+        | ("PopupMenu", "add_submenu_item")
+        | ("PopupMenu", "add_submenu_node_item")
+        | ("PopupMenu", "set_item_id")
+        | ("PopupMenu", "get_item_index")
+        | ("OptionButton", "add_item")
+        | ("OptionButton", "add_icon_item")
+        | ("OptionButton", "set_item_id")
+        | ("OptionButton", "get_item_index")
+        => param == "id",
+
+        // Layer numbers.
+        | ("CollisionObject2D", "set_collision_layer_value")
+        | ("CollisionObject2D", "get_collision_layer_value")
+        | ("CollisionObject2D", "set_collision_mask_value")
+        | ("CollisionObject2D", "get_collision_mask_value")
+        | ("CollisionObject3D", "set_collision_layer_value")
+        | ("CollisionObject3D", "get_collision_layer_value")
+        | ("CollisionObject3D", "set_collision_mask_value")
+        | ("CollisionObject3D", "get_collision_mask_value")
+        | ("NavigationAgent2D", "set_navigation_layer_value")
+        | ("NavigationAgent2D", "get_navigation_layer_value")
+        | ("NavigationAgent3D", "set_navigation_layer_value")
+        | ("NavigationAgent3D", "get_navigation_layer_value")
+        | ("NavigationRegion2D", "set_navigation_layer_value")
+        | ("NavigationRegion2D", "get_navigation_layer_value")
+        | ("NavigationRegion3D", "set_navigation_layer_value")
+        | ("NavigationRegion3D", "get_navigation_layer_value")
+        | ("VisualInstance3D", "set_layer_mask_value")
+        | ("VisualInstance3D", "get_layer_mask_value")
+        | ("Camera3D", "set_cull_mask_value")
+        | ("Camera3D", "get_cull_mask_value")
+        => param == "layer_number",
+
+        _ => false,
+    }
+}
+
+/// Checks if the specified method never returns null in practice, so that an additional `*_expect()` accessor returning `Gd<T>`
+/// instead of `Option<Gd<T>>` is generated.
+///
+/// Only list parameterless methods that return an object under all circumstances in which they can be called (e.g. not
+/// `Node::get_tree()`, which is null outside the tree, or `Node::create_tween()`, which fails outside the tree). The regular method
+/// keeps its `Option` return type.
+#[rustfmt::skip]
+pub fn is_class_method_return_non_null(class_name: &TyName, method_name: &str) -> bool {
+    match (class_name.godot_ty.as_str(), method_name) {
+        // Scene tree.
+        | ("SceneTree", "get_root")
+        | ("SceneTree", "create_tween")
+
+        // Created together with their owner.
+        | ("Viewport", "get_texture")
+        | ("Viewport", "get_world_2d")
+        | ("PackedScene", "get_state")
+        | ("EditorInterface", "get_base_control")
+        | ("EditorInterface", "get_resource_filesystem")
+        | ("EditorInterface", "get_editor_settings")
+        | ("EditorInterface", "get_selection")
+
+        => true,
+
+        _ => false,
+    }
+}
+
 /// True if builtin method is excluded. Does NOT check for type exclusion; use [`is_builtin_type_deleted`] for that.
 pub fn is_builtin_method_deleted(_class_name: &TyName, method: &JsonBuiltinMethod) -> bool {
     codegen_special_cases::is_builtin_method_excluded(method)
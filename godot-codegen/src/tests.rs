@@ -8,9 +8,14 @@
 // Tests translation of certain symbols.
 // See also integration tests: itest/engine_tests/codegen_[enums_]test.rs.
 
+use crate::context::Context;
 use crate::conv;
 use crate::generator::native_structures::parse_native_structures_format;
 use crate::generator::native_structures::NativeStructuresField;
+use crate::models::domain::TyName;
+use crate::models::json::JsonClassMethod;
+use crate::special_cases;
+use nanoserde::DeJson;
 
 #[test]
 fn test_pascal_conversion() {
@@ -171,3 +176,55 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_meta_supported() {
+    assert!(conv::is_meta_supported("int", None));
+    assert!(conv::is_meta_supported("int", Some("uint16")));
+    assert!(conv::is_meta_supported("float", Some("float")));
+    assert!(conv::is_meta_supported("typedarray::int", Some("int32")));
+
+    // Qualifiers from custom engine builds.
+    assert!(!conv::is_meta_supported("int", Some("int128")));
+    assert!(!conv::is_meta_supported("float", Some("half")));
+    assert!(!conv::is_meta_supported("Vector2", Some("int32")));
+    assert!(!conv::is_meta_supported("typedarray::float", Some("half")));
+}
+
+#[test]
+fn test_unknown_types_skipped() {
+    fn method(return_and_args: &str) -> JsonClassMethod {
+        let json = format!(
+            r#"{{"name": "custom", "is_const": false, "is_vararg": false, "is_static": false, "is_virtual": false, {return_and_args}}}"#
+        );
+        JsonClassMethod::deserialize_json(&json).expect("valid method JSON")
+    }
+
+    let class_name = TyName::from_godot("Node");
+    let mut ctx = Context::default();
+    let mut is_deleted = |method: &JsonClassMethod| {
+        special_cases::is_class_method_deleted(&class_name, method, &mut ctx)
+    };
+
+    // Only hardcoded types, known to any context.
+    let known = method(
+        r#""return_value": {"type": "int", "meta": "uint8"}, "arguments": [{"name": "a", "type": "bool"}]"#,
+    );
+    assert!(!is_deleted(&known));
+
+    // Classes and enums that the (here empty) JSON API does not declare, e.g. from custom engine modules.
+    let unknown_class = method(r#""return_value": {"type": "CustomModuleClass"}, "arguments": []"#);
+    assert!(is_deleted(&unknown_class));
+
+    let unknown_arg = method(
+        r#""arguments": [{"name": "a", "type": "int"}, {"name": "b", "type": "typedarray::CustomModuleClass"}]"#,
+    );
+    assert!(is_deleted(&unknown_arg));
+
+    let unknown_enum =
+        method(r#""arguments": [{"name": "mode", "type": "enum::CustomModuleClass.Mode"}]"#);
+    assert!(is_deleted(&unknown_enum));
+
+    let unknown_meta = method(r#""arguments": [{"name": "a", "type": "int", "meta": "int128"}]"#);
+    assert!(is_deleted(&unknown_meta));
+}
@@ -89,11 +89,22 @@ impl Basis {
 
     /// Create a `Basis` from an axis and angle.
     ///
+    /// `axis` must be normalized; see [`Basis::try_from_axis_angle()`] for a fallible version.
+    ///
     /// _Godot equivalent: `Basis(Vector3 axis, float angle)`_
     pub fn from_axis_angle(axis: Vector3, angle: real) -> Self {
         RMat3::from_axis_angle(axis.to_glam(), angle).to_front()
     }
 
+    /// Fallible version of [`Basis::from_axis_angle()`], returning an error if `axis` is not normalized.
+    pub fn try_from_axis_angle(axis: Vector3, angle: real) -> Result<Self, String> {
+        if !axis.is_normalized() {
+            return Err(format!("Basis axis {axis:?} is not normalized."));
+        }
+
+        Ok(Self::from_axis_angle(axis, angle))
+    }
+
     /// Create a diagonal matrix from the given values.
     pub const fn from_diagonal(x: real, y: real, z: real) -> Self {
         Self {
@@ -357,12 +368,23 @@ impl Basis {
 
     /// Returns the inverse of the matrix.
     ///
+    /// The matrix must be invertible; see [`Basis::try_inverse()`] for a fallible version.
+    ///
     /// _Godot equivalent: `Basis.inverse()`_
     #[must_use]
     pub fn inverse(&self) -> Basis {
         self.glam(|mat| mat.inverse())
     }
 
+    /// Fallible version of [`Basis::inverse()`], returning an error if the determinant is 0.
+    pub fn try_inverse(&self) -> Result<Basis, String> {
+        if self.determinant().is_zero_approx() {
+            return Err(format!("basis {self} is not invertible (determinant 0)"));
+        }
+
+        Ok(self.inverse())
+    }
+
     /// Returns the transposed version of the matrix.
     ///
     /// _Godot equivalent: `Basis.transposed()`_
@@ -723,6 +745,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn try_constructors() {
+        let err = Basis::try_from_axis_angle(Vector3::new(1.0, 1.0, 0.0), PI).unwrap_err();
+        assert!(err.contains("not normalized"), "{err}");
+
+        let singular = Basis::from_scale(Vector3::new(1.0, 0.0, 1.0));
+        let err = singular.try_inverse().unwrap_err();
+        assert!(err.contains("not invertible"), "{err}");
+
+        let scale = Basis::from_scale(Vector3::new(2.0, 4.0, 8.0));
+        assert_eq_approx!(
+            scale.try_inverse().unwrap(),
+            Basis::from_scale(Vector3::new(0.5, 0.25, 0.125))
+        );
+    }
+
     // Translated from Godot
     #[test]
     fn basis_euler_conversions() {
@@ -64,6 +64,50 @@ impl Callable {
         }
     }
 
+    /// Create a callable for the non-static method `method_name` of the object with the given instance ID.
+    ///
+    /// Unlike [`from_object_method()`][Self::from_object_method], this doesn't need a `Gd<T>` pointer, so no reference count is modified.
+    /// Together with passing `method_name` as `&str` (which is converted through a cache of `StringName`s, see [`AsArg`][meta::AsArg]),
+    /// this makes connecting and disconnecting many signals cheap, e.g. when a UI list stores instance IDs of its items.
+    ///
+    /// # Panics
+    /// If no object with `instance_id` exists. See [`try_from_instance_id()`][Self::try_from_instance_id] for a non-panicking version.
+    ///
+    /// _Godot equivalent: `Callable(instance_from_id(id), method)`_
+    pub fn from_instance_id<S>(instance_id: InstanceId, method_name: S) -> Self
+    where
+        S: meta::AsArg<StringName>,
+    {
+        Self::try_from_instance_id(instance_id, method_name).unwrap_or_else(|| {
+            panic!("Callable::from_instance_id(): instance ID {instance_id} is not alive")
+        })
+    }
+
+    /// Like [`from_instance_id()`][Self::from_instance_id], but returns `None` if no object with `instance_id` exists.
+    pub fn try_from_instance_id<S>(instance_id: InstanceId, method_name: S) -> Option<Self>
+    where
+        S: meta::AsArg<StringName>,
+    {
+        meta::arg_into_ref!(method_name);
+
+        let object_ptr = classes::object_ptr_from_id(instance_id);
+        if object_ptr.is_null() {
+            return None;
+        }
+
+        // SAFETY: object_ptr points to a live object. Godot's Callable only stores the object's ID, so no reference is acquired.
+        let callable = unsafe {
+            Self::new_with_uninit(|self_ptr| {
+                let ctor = sys::builtin_fn!(callable_from_object_method);
+                let raw = crate::obj::object_as_arg_ptr(&object_ptr);
+                let args = [raw, method_name.sys()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        };
+
+        Some(callable)
+    }
+
     /// Create a callable for the static method `class_name::function` (single-threaded).
     ///
     /// Allows you to call static functions through `Callable`.
@@ -193,6 +237,34 @@ impl Callable {
         })
     }
 
+    /// Create callable from a **thread-safe** Rust function, which Godot may invoke from several threads _at the same time_.
+    ///
+    /// In contrast to [`from_sync_fn`][Self::from_sync_fn], the function is only ever accessed through a shared reference, hence `Fn`.
+    #[cfg(since_api = "4.2")]
+    #[cfg(feature = "experimental-threads")]
+    pub(crate) fn from_concurrent_fn<F>(name: GString, rust_function: F) -> Self
+    where
+        F: 'static + Send + Sync + Fn(&[&Variant]) -> Result<Variant, ()>,
+    {
+        let userdata = CallableUserdata {
+            inner: FnWrapper {
+                rust_function,
+                name,
+                thread_id: None,
+            },
+        };
+
+        let info = CallableCustomInfo {
+            callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            call_func: Some(rust_callable_call_shared_fn::<F>),
+            free_func: Some(rust_callable_destroy::<FnWrapper<F>>),
+            to_string_func: Some(rust_callable_to_string_named::<F>),
+            ..Self::default_callable_custom_info()
+        };
+
+        Self::from_custom_info(info)
+    }
+
     #[deprecated = "Now split into from_local_fn (single-threaded) and from_sync_fn (multi-threaded)."]
     #[cfg(since_api = "4.2")]
     pub fn from_fn<F, S>(name: S, rust_function: F) -> Self
@@ -294,6 +366,17 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Calls the method represented by this callable at the end of the current frame, with the given arguments.
+    ///
+    /// The call is queued in Godot's message queue, like [`Object::call_deferred()`][crate::classes::Object::call_deferred]. This also works
+    /// before the main loop is running, e.g. during extension initialization; the call then happens once the engine processes the queue.
+    ///
+    /// _Godot equivalent: `call_deferred`_
+    pub fn call_deferred(&self, arguments: &[Variant]) {
+        // Vararg builtin method, not covered by the generated inner API.
+        self.to_variant().call("call_deferred", arguments);
+    }
+
     /// Returns a copy of this Callable with one or more arguments bound, reading them from an array.
     ///
     /// _Godot equivalent: `bindv`_
@@ -506,6 +589,16 @@ mod custom_callable {
             let ptr = void_ptr as *mut CallableUserdata<T>;
             &mut (*ptr).inner
         }
+
+        /// # Safety
+        /// Returns an unbounded reference. `void_ptr` must be a valid pointer to a `CallableUserdata`.
+        ///
+        /// Unlike [`inner_from_raw`][Self::inner_from_raw], this never creates a `&mut`, so it may be used while other threads access
+        /// the same userdata.
+        unsafe fn inner_ref_from_raw<'a>(void_ptr: *mut std::ffi::c_void) -> &'a T {
+            let ptr = void_ptr as *const CallableUserdata<T>;
+            &(*ptr).inner
+        }
     }
 
     pub(crate) struct FnWrapper<F> {
@@ -568,7 +661,7 @@ mod custom_callable {
         let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
 
         let name = {
-            let w: &FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+            let w: &FnWrapper<F> = CallableUserdata::inner_ref_from_raw(callable_userdata);
             w.name.to_string()
         };
         let ctx = meta::CallContext::custom_callable(name.as_str());
@@ -595,6 +688,34 @@ mod custom_callable {
         });
     }
 
+    /// Like [`rust_callable_call_fn`], but only ever accesses the function through `&`, so Godot may invoke it concurrently.
+    #[cfg(feature = "experimental-threads")]
+    pub unsafe extern "C" fn rust_callable_call_shared_fn<F>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: Fn(&[&Variant]) -> Result<Variant, ()> + Sync,
+    {
+        let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
+
+        let name = {
+            let w: &FnWrapper<F> = CallableUserdata::inner_ref_from_raw(callable_userdata);
+            w.name.to_string()
+        };
+        let ctx = meta::CallContext::custom_callable(name.as_str());
+
+        crate::private::handle_varcall_panic(&ctx, &mut *r_error, move || {
+            // Get the FnWrapper again inside closure so the Fn doesn't have to be RefUnwindSafe.
+            let w: &FnWrapper<F> = CallableUserdata::inner_ref_from_raw(callable_userdata);
+            let result = (w.rust_function)(arg_refs);
+            meta::varcall_return_checked(result, r_return, r_error);
+            Ok(())
+        });
+    }
+
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
         let rust_ptr = callable_userdata as *mut CallableUserdata<T>;
         let _drop = Box::from_raw(rust_ptr);
@@ -603,7 +724,7 @@ mod custom_callable {
     pub unsafe extern "C" fn rust_callable_hash<T: Hash>(
         callable_userdata: *mut std::ffi::c_void,
     ) -> u32 {
-        let c: &T = CallableUserdata::<T>::inner_from_raw(callable_userdata);
+        let c: &T = CallableUserdata::<T>::inner_ref_from_raw(callable_userdata);
 
         // Just cut off top bits, not best-possible hash.
         sys::hash_value(c) as u32
@@ -613,8 +734,8 @@ mod custom_callable {
         callable_userdata_a: *mut std::ffi::c_void,
         callable_userdata_b: *mut std::ffi::c_void,
     ) -> sys::GDExtensionBool {
-        let a: &T = CallableUserdata::inner_from_raw(callable_userdata_a);
-        let b: &T = CallableUserdata::inner_from_raw(callable_userdata_b);
+        let a: &T = CallableUserdata::inner_ref_from_raw(callable_userdata_a);
+        let b: &T = CallableUserdata::inner_ref_from_raw(callable_userdata_b);
 
         sys::conv::bool_to_sys(a == b)
     }
@@ -624,7 +745,7 @@ mod custom_callable {
         r_is_valid: *mut sys::GDExtensionBool,
         r_out: sys::GDExtensionStringPtr,
     ) {
-        let c: &T = CallableUserdata::inner_from_raw(callable_userdata);
+        let c: &T = CallableUserdata::inner_ref_from_raw(callable_userdata);
         let s = crate::builtin::GString::from(c.to_string());
 
         s.move_into_string_ptr(r_out);
@@ -636,7 +757,7 @@ mod custom_callable {
         r_is_valid: *mut sys::GDExtensionBool,
         r_out: sys::GDExtensionStringPtr,
     ) {
-        let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+        let w: &FnWrapper<F> = CallableUserdata::inner_ref_from_raw(callable_userdata);
 
         w.name.clone().move_into_string_ptr(r_out);
         *r_is_valid = sys::conv::SYS_TRUE;
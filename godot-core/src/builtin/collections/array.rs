@@ -457,6 +457,97 @@ impl<T: ArrayElement> Array<T> {
         inner_self.append_array(other);
     }
 
+    /// Appends all elements of a Rust slice at the end of this array.
+    ///
+    /// Resizes the array once and writes elements in place, which is considerably faster than calling [`push()`][Self::push] in a loop.
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: ToGodot,
+    {
+        let original_len = self.len();
+        let added_len = slice.len();
+        if added_len == 0 {
+            return;
+        }
+
+        // SAFETY: We fill the array with `Variant::nil()`, but overwrite all new elements with values of type `T` below.
+        unsafe { self.as_inner_mut() }.resize(to_i64(original_len + added_len));
+
+        // SAFETY: the array has `original_len + added_len` elements since we just resized it, and they are all valid `Variant`s.
+        // We do not access the array while the slice exists, so the slice has unique access to the elements.
+        let elements = unsafe { Variant::borrow_slice_mut(self.ptr_mut(original_len), added_len) };
+        for (element, array_slot) in slice.iter().zip(elements.iter_mut()) {
+            // Exception safety: see resize().
+            *array_slot = element.to_variant();
+        }
+    }
+
+    /// Shortens the array to `len` elements, dropping the rest. Has no effect if `len` is greater than or equal to the current length.
+    ///
+    /// Equivalent to [`shrink()`][Self::shrink], but with `Vec`-like naming and without return value.
+    pub fn truncate(&mut self, len: usize) {
+        self.shrink(len);
+    }
+
+    /// Splits the array in two at `at`. Returns a new array with elements `[at, len)`, while `self` keeps `[0, at)`.
+    ///
+    /// # Panics
+    /// If `at > len`.
+    #[must_use = "use truncate() if the split-off elements are not needed"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(at <= len, "split_off: index {at} out of bounds (len {len})");
+
+        let tail = self.subarray_shallow(at, len, None);
+        self.shrink(at);
+
+        tail
+    }
+
+    /// Removes the elements in `range` from the array, and returns them as a new array.
+    ///
+    /// Unlike [`Vec::drain()`], the removal happens immediately, independently of whether the returned array is used. The elements after
+    /// the range are moved in place, without converting them from/to `Variant`.
+    ///
+    /// # Panics
+    /// If the range is decreasing or out of bounds.
+    pub fn drain(&mut self, range: impl std::ops::RangeBounds<usize>) -> Self {
+        use std::ops::Bound;
+
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+
+        assert!(start <= end, "drain: range start {start} > end {end}");
+        assert!(
+            end <= len,
+            "drain: range end {end} out of bounds (len {len})"
+        );
+
+        let drained = self.subarray_shallow(start, end, None);
+        if start == end {
+            return drained;
+        }
+
+        // SAFETY: the array has `len` valid elements, and we do not access the array while the slice exists. Rotating only permutes
+        // elements of type `T`, so the array stays well-typed.
+        let elements = unsafe { Variant::borrow_slice_mut(self.ptr_mut(0), len) };
+        elements[start..].rotate_left(end - start);
+
+        // The drained elements are now at the end.
+        self.shrink(len - (end - start));
+
+        drained
+    }
+
     /// Returns a shallow copy of the array. All array elements are copied, but any reference types
     /// (such as `Array`, `Dictionary` and `Object`) will still refer to the same value.
     ///
@@ -800,7 +891,7 @@ impl<T: ArrayElement> Array<T> {
         let canonical_array = unsafe { self.assume_type_ref::<Variant>() };
 
         // If any element is not convertible, this will return an error.
-        for elem in canonical_array.iter_shared() {
+        for (index, elem) in canonical_array.iter_shared().enumerate() {
             elem.try_to::<T>().map_err(|_err| {
                 FromGodotError::BadArrayTypeInt {
                     expected: self.type_info(),
@@ -809,6 +900,7 @@ impl<T: ArrayElement> Array<T> {
                         .expect("origin must be i64 compatible; this is a bug"),
                 }
                 .into_error(self.clone())
+                .with_index(index)
             })?;
         }
 
@@ -920,6 +1012,49 @@ fn correct_variant_t() {
 }
 
 impl VariantArray {
+    /// Converts all elements to `T`, if every element holds exactly `T`'s variant type.
+    ///
+    /// Equivalent to calling [`Variant::try_to::<T>()`][Variant::try_to] on each element and collecting the results, but considerably
+    /// faster for large arrays: the element types are verified in one pass, after which all values are extracted with a single engine
+    /// call into the matching packed array (e.g. [`PackedInt64Array`] for `i64`) and copied out as a contiguous slice.
+    ///
+    /// Only element types of packed arrays are supported, see [`PackedArrayElement`][meta::PackedArrayElement]. As with `try_to()`,
+    /// types must match strictly -- an `INT` element is not accepted for `f64`. Narrower integers such as `i32` are range-checked.
+    ///
+    /// # Errors
+    /// On the first element that cannot be converted, returns its index together with the conversion error.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = varray![1, 2, 3];
+    /// let ints: Vec<i32> = array.try_bulk_to().unwrap();
+    /// assert_eq!(ints, [1, 2, 3]);
+    ///
+    /// let mixed = varray![1, "two", 3];
+    /// let (index, _err) = mixed.try_bulk_to::<i64>().unwrap_err();
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn try_bulk_to<T: meta::PackedArrayElement>(
+        &self,
+    ) -> Result<Vec<T>, (usize, ConvertError)> {
+        let expected = <T::Ffi as GodotFfi>::variant_type();
+
+        for i in 0..self.len() {
+            // SAFETY: `i` is in bounds, so `ptr()` returns a live pointer to a variant.
+            let variant = unsafe { Variant::borrow_var_sys(self.ptr(i)) };
+
+            let actual = variant.get_type();
+            if actual != expected {
+                let err =
+                    FromVariantError::BadType { expected, actual }.into_error(variant.clone());
+                return Err((i, err));
+            }
+        }
+
+        T::bulk_from_variant_array(self)
+    }
+
     /// # Safety
     /// - Variant must have type `VariantType::ARRAY`.
     /// - Subsequent operations on this array must not rely on the type of the array.
@@ -1121,6 +1256,11 @@ impl<T: ArrayElement> Default for Array<T> {
 impl<T: ArrayElement> Drop for Array<T> {
     #[inline]
     fn drop(&mut self) {
+        // See impl_builtin_traits! for Drop.
+        if !sys::is_initialized() {
+            return;
+        }
+
         unsafe {
             let array_destroy = sys::builtin_fn!(array_destroy);
             array_destroy(self.sys_mut());
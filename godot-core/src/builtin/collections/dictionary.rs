@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Variant, VariantArray};
+use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, ToGodot};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -125,6 +126,29 @@ impl Dictionary {
         }
     }
 
+    /// Returns the value for the given key, converted to `V`.
+    ///
+    /// Returns `None` if the key is absent, and `Some(Err)` if the value cannot be converted to `V`. In the latter case, the error
+    /// mentions the key.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn find_enemy(enemies: &Dictionary, name: &str) -> Option<Gd<Node>> {
+    ///     enemies.get_as::<Gd<Node>>(name)?.ok()
+    /// }
+    /// ```
+    pub fn get_as<V: FromGodot>(&self, key: impl ToGodot) -> Option<Result<V, ConvertError>> {
+        let key = key.to_variant();
+        let value = self.get(key.clone())?;
+
+        Some(
+            value
+                .try_to::<V>()
+                .map_err(|err| err.with_dictionary_key(&key, "value")),
+        )
+    }
+
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
     ///
     /// This method does not let you differentiate `NIL` values stored as values from absent keys.
@@ -216,6 +240,27 @@ impl Dictionary {
         old_value
     }
 
+    /// Insert a value at the given key, returning the previous value for that key converted to `V` (if available).
+    ///
+    /// The new value is inserted even if the previous value cannot be converted to `V`; in that case `Some(Err)` is returned.
+    ///
+    /// If you don't need the previous value, use [`set()`][Self::set] instead.
+    #[must_use]
+    pub fn insert_typed<V: ToGodot + FromGodot>(
+        &mut self,
+        key: impl ToGodot,
+        value: V,
+    ) -> Option<Result<V, ConvertError>> {
+        let key = key.to_variant();
+        let old_value = self.insert(key.clone(), value)?;
+
+        Some(
+            old_value
+                .try_to::<V>()
+                .map_err(|err| err.with_dictionary_key(&key, "value")),
+        )
+    }
+
     /// Removes a key from the map, and returns the value associated with
     /// the key if the key was in the dictionary.
     ///
@@ -294,11 +339,29 @@ impl Dictionary {
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
     /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
     ///
-    /// Use `dict.iter_shared().typed::<K, V>()` to iterate over `(K, V)` pairs instead.
+    /// Use `dict.iter_shared().typed::<K, V>()` to iterate over `(K, V)` pairs instead, or [`iter_typed()`][Self::iter_typed] if
+    /// conversions may fail.
     pub fn iter_shared(&self) -> Iter<'_> {
         Iter::new(self)
     }
 
+    /// Returns an iterator over the key-value pairs, converting each pair to `(K, V)`.
+    ///
+    /// Unlike `dict.iter_shared().typed::<K, V>()`, this does not panic on conversion failure. Instead, each item is a `Result`, with
+    /// errors mentioning the key of the affected entry. The same caveats as for [`iter_shared()`][Self::iter_shared] apply.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::meta::error::ConvertError;
+    /// fn collect_nodes(dict: &Dictionary) -> Result<Vec<(GString, Gd<Node>)>, ConvertError> {
+    ///     dict.iter_typed::<GString, Gd<Node>>().collect()
+    /// }
+    /// ```
+    pub fn iter_typed<K: FromGodot, V: FromGodot>(&self) -> TryTypedIter<'_, K, V> {
+        TryTypedIter::new(self)
+    }
+
     /// Returns an iterator over the keys in a `Dictionary`.
     ///
     /// The keys are each of type `Variant`. Each key references the original `Dictionary`, but instead of a `&`-reference to keys pairs
@@ -692,6 +755,48 @@ impl<K: FromGodot> Iterator for TypedKeys<'_, K> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// [`Dictionary`] iterator that tries to convert each key-value pair into a typed `(K, V)`.
+///
+/// See [`Dictionary::iter_typed()`] for more information.
+pub struct TryTypedIter<'a, K, V> {
+    iter: DictionaryIter<'a>,
+    _k: PhantomData<K>,
+    _v: PhantomData<V>,
+}
+
+impl<'a, K, V> TryTypedIter<'a, K, V> {
+    fn new(dictionary: &'a Dictionary) -> Self {
+        Self {
+            iter: DictionaryIter::new(dictionary),
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+}
+
+impl<K: FromGodot, V: FromGodot> Iterator for TryTypedIter<'_, K, V> {
+    type Item = Result<(K, V), ConvertError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.iter.next_key_value()?;
+
+        let typed_key = key
+            .try_to::<K>()
+            .map_err(|err| err.with_dictionary_key(&key, "key"));
+        let typed_value = value
+            .try_to::<V>()
+            .map_err(|err| err.with_dictionary_key(&key, "value"));
+
+        Some(typed_key.and_then(|k| typed_value.map(|v| (k, v))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions
 
@@ -1071,3 +1071,42 @@ fn populated_or_err(array: PackedByteArray) -> Result<PackedByteArray, ()> {
         Ok(array)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Bulk transforms
+
+impl PackedVector2Array {
+    /// Applies `transform` to every point in the array, in place.
+    ///
+    /// Equivalent to `*point = *transform * *point` for each element, but operates directly on the array's memory. The loop is free
+    /// of FFI calls and branches, allowing the compiler to vectorize it. Prefer this over per-element `get()`/`set()` when processing
+    /// large meshes or point clouds.
+    ///
+    /// _Godot equivalent: `transform * array`_
+    pub fn transform_all(&mut self, transform: &Transform2D) {
+        let Transform2D { a, b, origin } = *transform;
+
+        for point in self.as_mut_slice() {
+            *point = a * point.x + b * point.y + origin;
+        }
+    }
+}
+
+impl PackedVector3Array {
+    /// Applies `transform` to every point in the array, in place.
+    ///
+    /// Equivalent to `*point = *transform * *point` for each element, but operates directly on the array's memory. The loop is free
+    /// of FFI calls and branches, allowing the compiler to vectorize it. Prefer this over per-element `get()`/`set()` when processing
+    /// large meshes or point clouds.
+    ///
+    /// _Godot equivalent: `transform * array`_
+    pub fn transform_all(&mut self, transform: &Transform3D) {
+        let [x_row, y_row, z_row] = transform.basis.rows;
+        let origin = transform.origin;
+
+        for point in self.as_mut_slice() {
+            let p = *point;
+            *point = Vector3::new(x_row.dot(p), y_row.dot(p), z_row.dot(p)) + origin;
+        }
+    }
+}
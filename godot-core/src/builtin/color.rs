@@ -57,11 +57,20 @@ impl Color {
     /// Constructs a new `Color` with the given components as bytes. 0 is mapped to 0.0, 255 is
     /// mapped to 1.0.
     ///
+    /// This is a `const fn`, so it can be used to define color constants, e.g. for a UI palette.
+    ///
     /// _Godot equivalent: the global `Color8` function_
-    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+    pub const fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self::from_rgba(from_u8(r), from_u8(g), from_u8(b), from_u8(a))
     }
 
+    /// Constructs a new `Color` with the given color components as bytes, and the alpha channel set to 1.
+    ///
+    /// See [`from_rgba8()`][Self::from_rgba8] for the mapping.
+    pub const fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self::from_rgba8(r, g, b, 255)
+    }
+
     /// Constructs a new `Color` with the given components as `u16` words. 0 is mapped to 0.0,
     /// 65535 (`0xffff`) is mapped to 1.0.
     pub fn from_rgba16(r: u16, g: u16, b: u16, a: u16) -> Self {
@@ -147,6 +156,52 @@ impl Color {
         InnerColor::from_ok_hsl(h, s, l, 1.0)
     }
 
+    /// Constructs a `Color` from an [HSL profile](https://en.wikipedia.org/wiki/HSL_and_HSV). The hue (`h`), saturation (`s`) and
+    /// lightness (`l`) are typically between 0.0 and 1.0; the hue wraps around. Alpha is set to 1; use [`Color::with_alpha`] to change it.
+    ///
+    /// Computed on the Rust side. Unlike [`from_ok_hsl()`][Self::from_ok_hsl], this is the classic HSL model used in CSS.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let hue = h.rem_euclid(1.0) * 6.0;
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = l - chroma / 2.0;
+        Self::from_rgb(r + m, g + m, b + m)
+    }
+
+    /// Constructs a `Color` from coordinates in the [OKLab color space](https://bottosson.github.io/posts/oklab/).
+    ///
+    /// Lightness `l` is typically between 0.0 and 1.0, while `a` (green-red) and `b` (blue-yellow) are roughly within `-0.4..=0.4`. The
+    /// result is sRGB-encoded like all `Color` values, with alpha set to 1. Colors outside the sRGB gamut have components outside `0.0..=1.0`;
+    /// use [`Color::normalized`] to clamp them.
+    ///
+    /// OKLab is perceptually uniform, which makes it a good space for gradients and palette generation.
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Self {
+        // Computed in f64 for accuracy; coefficients from the reference implementation.
+        let (l, a, b) = (l as f64, a as f64, b as f64);
+
+        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+        Self::from_rgb(
+            linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+        )
+    }
+
     /// Constructs a `Color` from an RGBE9995 format integer. This is a special OpenGL texture
     /// format where the three color components have 9 bits of precision and all three share a
     /// single 5-bit exponent.
@@ -322,6 +377,51 @@ impl Color {
         Ok(ColorHsv { h, s, v, a })
     }
 
+    /// Converts this color to its [HSL](https://en.wikipedia.org/wiki/HSL_and_HSV) representation, returning `(h, s, l)`.
+    ///
+    /// The hue is in range `0.0..1.0`; saturation and lightness are in `0.0..=1.0` for normalized colors. Alpha is ignored.
+    /// Inverse of [`Color::from_hsl`].
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+
+        let l = (max + min) / 2.0;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == self.r {
+            ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            (self.b - self.r) / delta + 2.0
+        } else {
+            (self.r - self.g) / delta + 4.0
+        };
+
+        (h / 6.0, s, l)
+    }
+
+    /// Converts this color to the [OKLab color space](https://bottosson.github.io/posts/oklab/), returning `(l, a, b)`.
+    ///
+    /// Alpha is ignored. Inverse of [`Color::from_oklab`].
+    pub fn to_oklab(self) -> (f32, f32, f32) {
+        let r = srgb_to_linear(self.r);
+        let g = srgb_to_linear(self.g);
+        let b = srgb_to_linear(self.b);
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        (
+            (0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s) as f32,
+            (1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s) as f32,
+            (0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s) as f32,
+        )
+    }
+
     /// Clamps all components to a usually valid range `0.0..=1.0`.
     ///
     /// Useful for transformations between different color representations.
@@ -512,8 +612,19 @@ impl ops::Neg for Color {
 }
 
 /// Converts a single channel byte to a float in the range 0 to 1.
-fn from_u8(byte: u8) -> f32 {
-    byte as f32 / 255.0
+const fn from_u8(byte: u8) -> f32 {
+    // Float arithmetic is not available in `const fn` for our MSRV, but it is in `const` initializers.
+    const TABLE: [f32; 256] = {
+        let mut table = [0.0; 256];
+        let mut i = 0;
+        while i < 256 {
+            table[i] = i as f32 / 255.0;
+            i += 1;
+        }
+        table
+    };
+
+    TABLE[byte as usize]
 }
 
 /// Converts a single channel `u16` word to a float in the range 0 to 1.
@@ -538,6 +649,27 @@ fn to_u16(v: f32) -> u16 {
     (v * MAX).round().clamp(0.0, MAX) as u16
 }
 
+/// Converts a single sRGB-encoded channel to linear light.
+fn srgb_to_linear(c: f32) -> f64 {
+    let c = c as f64;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a single linear channel to sRGB encoding.
+fn linear_to_srgb(c: f64) -> f32 {
+    let srgb = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+
+    srgb as f32
+}
+
 /// Packs four `u16` words into a `u64` in big-endian order.
 fn from_be_words(words: [u16; 4]) -> u64 {
     (words[0] as u64) << 48 | (words[1] as u64) << 32 | (words[2] as u64) << 16 | (words[3] as u64)
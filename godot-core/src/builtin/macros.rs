@@ -41,6 +41,11 @@ macro_rules! impl_builtin_traits_inner {
         impl Drop for $Type {
             #[inline]
             fn drop(&mut self) {
+                // After the extension is unloaded (e.g. thread-locals destroyed at exit), the engine has already released the memory.
+                if !::godot_ffi::is_initialized() {
+                    return;
+                }
+
                 unsafe {
                     let destructor = ::godot_ffi::builtin_fn!($gd_method @1);
                     destructor(self.sys_mut());
@@ -0,0 +1,538 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::builtin::{real, Vector2, Vector3};
+
+/// Number of fractional bits in [`Fixed`].
+const FRAC_BITS: u32 = 32;
+
+/// Signed fixed-point number with 32 integer and 32 fractional bits.
+///
+/// All arithmetic is performed on integers, so results are bit-identical on every platform, compiler and optimization level. This makes
+/// `Fixed` suitable for lockstep simulations, where floating-point differences (FMA contraction, SIMD paths, platform `sqrt`/`sin`
+/// implementations) would otherwise cause desyncs.
+///
+/// Conversion from and to floating-point is only meant for the boundary to the engine, e.g. when applying a simulated position to a node.
+///
+/// # Rounding and overflow
+/// - Conversion from float rounds to the nearest representable value (ties away from zero).
+/// - Multiplication rounds towards negative infinity, division towards zero.
+/// - [`sqrt()`][Self::sqrt] rounds towards zero.
+/// - Overflow behaves like `i64` arithmetic: it panics in debug builds and wraps in release builds. Representable range is roughly ±2.1e9,
+///   with a resolution of about 2.3e-10.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fixed {
+    raw: i64,
+}
+
+impl Fixed {
+    pub const ZERO: Self = Self::from_raw(0);
+    pub const ONE: Self = Self::from_raw(1 << FRAC_BITS);
+    pub const MIN: Self = Self::from_raw(i64::MIN);
+    pub const MAX: Self = Self::from_raw(i64::MAX);
+
+    /// Smallest positive value, `2^-32`.
+    pub const EPSILON: Self = Self::from_raw(1);
+
+    /// Creates a value from its underlying representation, which is the number multiplied by `2^32`.
+    pub const fn from_raw(raw: i64) -> Self {
+        Self { raw }
+    }
+
+    /// Underlying representation, i.e. the number multiplied by `2^32`.
+    ///
+    /// This is the value to send over the network or store in replays.
+    pub const fn raw(self) -> i64 {
+        self.raw
+    }
+
+    pub const fn from_int(value: i32) -> Self {
+        Self::from_raw((value as i64) << FRAC_BITS)
+    }
+
+    /// Converts from floating-point, rounding to the nearest representable value.
+    ///
+    /// # Panics
+    /// If `value` is NaN or outside the representable range.
+    pub fn from_f64(value: f64) -> Self {
+        let scaled = (value * (1u64 << FRAC_BITS) as f64).round();
+        assert!(
+            scaled >= i64::MIN as f64 && scaled < i64::MAX as f64,
+            "value {value} cannot be represented as Fixed"
+        );
+
+        Self::from_raw(scaled as i64)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.raw as f64 / (1u64 << FRAC_BITS) as f64
+    }
+
+    /// Converts from [`real`], see [`from_f64()`][Self::from_f64].
+    pub fn from_real(value: real) -> Self {
+        Self::from_f64(value as f64)
+    }
+
+    pub fn to_real(self) -> real {
+        self.to_f64() as real
+    }
+
+    /// Integer part, rounded towards negative infinity.
+    pub const fn floor_to_int(self) -> i32 {
+        (self.raw >> FRAC_BITS) as i32
+    }
+
+    pub const fn abs(self) -> Self {
+        Self::from_raw(self.raw.abs())
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.raw < 0
+    }
+
+    /// Square root, rounded towards zero.
+    ///
+    /// # Panics
+    /// If `self` is negative.
+    pub fn sqrt(self) -> Self {
+        assert!(!self.is_negative(), "square root of negative value {self}");
+
+        // sqrt(raw / 2^32) * 2^32 == sqrt(raw * 2^32).
+        let root = isqrt((self.raw as u128) << FRAC_BITS);
+        Self::from_raw(root as i64)
+    }
+
+    /// Linear interpolation between `self` and `to`, with `weight` usually in `[0, 1]`.
+    pub fn lerp(self, to: Self, weight: Self) -> Self {
+        self + (to - self) * weight
+    }
+}
+
+impl Fixed {
+    /// Narrows an intermediate result, with the same overflow behavior as `i64` arithmetic.
+    fn from_wide(raw: i128, operation: &str) -> Self {
+        #[cfg(debug_assertions)]
+        let raw = i64::try_from(raw).unwrap_or_else(|_| panic!("Fixed {operation} overflow"));
+
+        #[cfg(not(debug_assertions))]
+        let raw = {
+            let _ = operation;
+            raw as i64
+        };
+
+        Self::from_raw(raw)
+    }
+}
+
+/// Integer square root, rounded towards zero.
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton iteration, starting from a power of two that is guaranteed to be >= the root.
+    let bits = 128 - value.leading_zeros();
+    let mut x = 1u128 << bits.div_ceil(2);
+    loop {
+        let next = (x + value / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw + rhs.raw)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw - rhs.raw)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let product = (self.raw as i128 * rhs.raw as i128) >> FRAC_BITS;
+        Self::from_wide(product, "multiplication")
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    /// # Panics
+    /// If `rhs` is zero.
+    fn div(self, rhs: Self) -> Self {
+        let quotient = ((self.raw as i128) << FRAC_BITS) / rhs.raw as i128;
+        Self::from_wide(quotient, "division")
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_raw(-self.raw)
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Fixed {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Fixed {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl fmt::Debug for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fixed({})", self.to_f64())
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Vectors
+
+macro_rules! impl_fixed_vector {
+    (
+        $(#[$attr:meta])*
+        $Vector:ident mirrors $EngineVector:ident { $($comp:ident),+ }
+    ) => {
+        $(#[$attr])*
+        #[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $Vector {
+            $( pub $comp: Fixed, )+
+        }
+
+        impl $Vector {
+            pub const ZERO: Self = Self { $( $comp: Fixed::ZERO, )+ };
+
+            pub const fn new($( $comp: Fixed ),+) -> Self {
+                Self { $( $comp ),+ }
+            }
+
+            /// Converts from the engine vector, rounding each component to the nearest representable value.
+            ///
+            /// # Panics
+            /// If a component is NaN or outside the range of [`Fixed`].
+            pub fn from_engine(v: $EngineVector) -> Self {
+                Self { $( $comp: Fixed::from_real(v.$comp) ),+ }
+            }
+
+            /// Converts to the engine vector, for use at the boundary of a deterministic simulation.
+            pub fn to_engine(self) -> $EngineVector {
+                $EngineVector { $( $comp: self.$comp.to_real() ),+ }
+            }
+
+            pub fn dot(self, with: Self) -> Fixed {
+                Fixed::ZERO $( + self.$comp * with.$comp )+
+            }
+
+            pub fn length_squared(self) -> Fixed {
+                self.dot(self)
+            }
+
+            /// Length of the vector.
+            ///
+            /// Unlike [`length_squared()`][Self::length_squared], this does not overflow for large components: squares are summed in a
+            /// 128-bit intermediate. Only lengths beyond [`Fixed::MAX`] overflow.
+            pub fn length(self) -> Fixed {
+                // sqrt(sum(raw^2) / 2^64) * 2^32 == sqrt(sum(raw^2)).
+                let sum_squares = 0u128 $( + {
+                    let raw = self.$comp.raw.unsigned_abs() as u128;
+                    raw * raw
+                } )+;
+
+                Fixed::from_wide(isqrt(sum_squares) as i128, "length")
+            }
+
+            /// Returns the vector scaled to unit length, or zero if `self` is zero.
+            pub fn normalized_or_zero(self) -> Self {
+                let length = self.length();
+                if length == Fixed::ZERO {
+                    Self::ZERO
+                } else {
+                    self / length
+                }
+            }
+
+            pub fn distance_to(self, to: Self) -> Fixed {
+                (to - self).length()
+            }
+
+            pub fn lerp(self, to: Self, weight: Fixed) -> Self {
+                Self { $( $comp: self.$comp.lerp(to.$comp, weight) ),+ }
+            }
+        }
+
+        impl Add for $Vector {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $( $comp: self.$comp + rhs.$comp ),+ }
+            }
+        }
+
+        impl Sub for $Vector {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $( $comp: self.$comp - rhs.$comp ),+ }
+            }
+        }
+
+        impl Mul<Fixed> for $Vector {
+            type Output = Self;
+
+            fn mul(self, rhs: Fixed) -> Self {
+                Self { $( $comp: self.$comp * rhs ),+ }
+            }
+        }
+
+        impl Div<Fixed> for $Vector {
+            type Output = Self;
+
+            fn div(self, rhs: Fixed) -> Self {
+                Self { $( $comp: self.$comp / rhs ),+ }
+            }
+        }
+
+        impl Neg for $Vector {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $( $comp: -self.$comp ),+ }
+            }
+        }
+
+        impl AddAssign for $Vector {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $Vector {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<Fixed> for $Vector {
+            fn mul_assign(&mut self, rhs: Fixed) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign<Fixed> for $Vector {
+            fn div_assign(&mut self, rhs: Fixed) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl From<$EngineVector> for $Vector {
+            fn from(v: $EngineVector) -> Self {
+                Self::from_engine(v)
+            }
+        }
+
+        impl From<$Vector> for $EngineVector {
+            fn from(v: $Vector) -> Self {
+                v.to_engine()
+            }
+        }
+    };
+}
+
+impl_fixed_vector!(
+    /// Deterministic counterpart of [`Vector2`], using [`Fixed`] components.
+    ///
+    /// Keep simulation state in this type and convert to `Vector2` only when handing results to the engine.
+    FixedVector2 mirrors Vector2 { x, y }
+);
+
+impl_fixed_vector!(
+    /// Deterministic counterpart of [`Vector3`], using [`Fixed`] components.
+    ///
+    /// Keep simulation state in this type and convert to `Vector3` only when handing results to the engine.
+    FixedVector3 mirrors Vector3 { x, y, z }
+);
+
+impl FixedVector3 {
+    pub fn cross(self, with: Self) -> Self {
+        Self::new(
+            self.y * with.z - self.z * with.y,
+            self.z * with.x - self.x * with.z,
+            self.x * with.y - self.y * with.x,
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fx(value: f64) -> Fixed {
+        Fixed::from_f64(value)
+    }
+
+    #[test]
+    fn fixed_arithmetic() {
+        assert_eq!(fx(1.5) + fx(2.25), fx(3.75));
+        assert_eq!(fx(1.5) - fx(2.25), fx(-0.75));
+        assert_eq!(fx(1.5) * fx(-2.0), fx(-3.0));
+        assert_eq!(fx(3.0) / fx(4.0), fx(0.75));
+        assert_eq!(-fx(0.5), fx(-0.5));
+        assert_eq!(Fixed::from_int(-3).floor_to_int(), -3);
+        assert_eq!(fx(-2.5).floor_to_int(), -3);
+    }
+
+    #[test]
+    fn fixed_rounding() {
+        // Multiplication floors: -EPSILON * 0.5 is -0.5 ulp, rounded to -1 ulp.
+        assert_eq!(-Fixed::EPSILON * fx(0.5), -Fixed::EPSILON);
+        assert_eq!(Fixed::EPSILON * fx(0.5), Fixed::ZERO);
+
+        // Division truncates towards zero.
+        assert_eq!(
+            Fixed::from_int(-1) / Fixed::from_int(3),
+            -(Fixed::ONE / Fixed::from_int(3))
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "Fixed multiplication overflow"]
+    fn fixed_mul_overflow() {
+        let _ = Fixed::from_int(100_000) * Fixed::from_int(100_000);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "Fixed division overflow"]
+    fn fixed_div_overflow() {
+        let _ = Fixed::MAX / fx(0.5);
+    }
+
+    #[test]
+    fn fixed_mul_div_near_limits() {
+        // Intermediate results exceed i64, final ones don't.
+        let big = Fixed::from_int(1 << 30);
+        assert_eq!(big * fx(1.5), Fixed::from_int(3 << 29));
+        assert_eq!(big / fx(4.0), Fixed::from_int(1 << 28));
+    }
+
+    #[test]
+    fn fixed_sqrt() {
+        assert_eq!(Fixed::ZERO.sqrt(), Fixed::ZERO);
+        assert_eq!(fx(16.0).sqrt(), fx(4.0));
+        assert_eq!(fx(0.25).sqrt(), fx(0.5));
+
+        // sqrt(2) truncated to 32 fractional bits.
+        assert_eq!(fx(2.0).sqrt().raw(), 6_074_000_999);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fixed_sqrt_negative() {
+        fx(-1.0).sqrt();
+    }
+
+    #[test]
+    fn fixed_vector_ops() {
+        let a = FixedVector3::new(fx(1.0), fx(2.0), fx(3.0));
+        let b = FixedVector3::new(fx(4.0), fx(5.0), fx(6.0));
+
+        assert_eq!(a.dot(b), fx(32.0));
+        assert_eq!(a.cross(b), FixedVector3::new(fx(-3.0), fx(6.0), fx(-3.0)));
+        assert_eq!(b - a, FixedVector3::new(fx(3.0), fx(3.0), fx(3.0)));
+
+        let v = FixedVector2::new(fx(3.0), fx(4.0));
+        assert_eq!(v.length(), fx(5.0));
+        let n = v.normalized_or_zero();
+        assert!((n.x.to_f64() - 0.6).abs() < 1e-9);
+        assert!((n.y.to_f64() - 0.8).abs() < 1e-9);
+        assert_eq!(FixedVector2::ZERO.normalized_or_zero(), FixedVector2::ZERO);
+    }
+
+    #[test]
+    fn fixed_vector_length_near_limits() {
+        // length_squared() would overflow beyond ~46341 per component.
+        let v = FixedVector2::new(Fixed::from_int(60_000), Fixed::from_int(-80_000));
+        assert_eq!(v.length(), Fixed::from_int(100_000));
+
+        let v = FixedVector3::new(Fixed::from_int(1 << 30), Fixed::ZERO, Fixed::ZERO);
+        assert_eq!(v.length(), Fixed::from_int(1 << 30));
+        assert_eq!(
+            v.normalized_or_zero(),
+            FixedVector3::new(Fixed::ONE, Fixed::ZERO, Fixed::ZERO)
+        );
+
+        let v = FixedVector2::new(Fixed::MAX, Fixed::ZERO);
+        assert_eq!(v.length(), Fixed::MAX);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "Fixed length overflow"]
+    fn fixed_vector_length_overflow() {
+        let _ = FixedVector2::new(Fixed::MAX, Fixed::MAX).length();
+    }
+
+    #[test]
+    fn fixed_vector_engine_roundtrip() {
+        let v = Vector2::new(1.25, -7.5);
+        assert_eq!(FixedVector2::from(v).to_engine(), v);
+
+        let v = Vector3::new(0.5, 100.0, -0.125);
+        assert_eq!(Vector3::from(FixedVector3::from(v)), v);
+    }
+}
@@ -69,6 +69,19 @@ pub trait FloatExt: private::Sealed + Copy {
         post_t: Self,
     ) -> Self;
 
+    /// Cubic Hermite interpolation between `self` and `to`, using the tangents (derivatives) at both ends.
+    ///
+    /// Unlike [`cubic_interpolate`](FloatExt::cubic_interpolate), which derives the tangents from neighboring points, the curve's slope is
+    /// given explicitly. This is useful if velocities are known, e.g. when interpolating between physics snapshots. Tangents are relative to
+    /// the `0.0 ..= 1.0` range of `weight`, so velocities need to be multiplied by the time between `self` and `to`.
+    fn hermite_interpolate(
+        self,
+        to: Self,
+        self_tangent: Self,
+        to_tangent: Self,
+        weight: Self,
+    ) -> Self;
+
     /// Linearly interpolates between two angles (in radians) by a `weight` value
     /// between 0.0 and 1.0.
     ///
@@ -224,6 +237,22 @@ macro_rules! impl_float_ext {
                 Self::lerp(b1, b2, if to_t == 0.0 { 0.5 } else { t / to_t })
             }
 
+            fn hermite_interpolate(
+                self,
+                to: Self,
+                self_tangent: Self,
+                to_tangent: Self,
+                weight: Self,
+            ) -> Self {
+                let t2 = weight * weight;
+                let t3 = t2 * weight;
+
+                self * (2.0 * t3 - 3.0 * t2 + 1.0)
+                    + self_tangent * (t3 - 2.0 * t2 + weight)
+                    + to * (-2.0 * t3 + 3.0 * t2)
+                    + to_tangent * (t3 - t2)
+            }
+
             fn lerp_angle(self, to: Self, weight: Self) -> Self {
                 use $consts;
 
@@ -337,4 +366,22 @@ mod test {
             fn = is_angle_equal_approx_f64
         );
     }
+
+    #[test]
+    fn hermite_interpolate() {
+        // Endpoints are hit exactly.
+        assert_eq_approx!(f64::hermite_interpolate(2.0, 5.0, 1.0, -1.0, 0.0), 2.0);
+        assert_eq_approx!(f64::hermite_interpolate(2.0, 5.0, 1.0, -1.0, 1.0), 5.0);
+
+        // Tangents matching the slope degenerate to linear interpolation.
+        for weight in [0.25, 0.5, 0.75] {
+            assert_eq_approx!(
+                f64::hermite_interpolate(2.0, 5.0, 3.0, 3.0, weight),
+                f64::lerp(2.0, 5.0, weight)
+            );
+        }
+
+        // Zero tangents produce a smoothstep-shaped curve.
+        assert_eq_approx!(f64::hermite_interpolate(0.0, 1.0, 0.0, 0.0, 0.25), 0.15625);
+    }
 }
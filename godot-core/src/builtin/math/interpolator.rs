@@ -0,0 +1,189 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    real, Basis, Quaternion, Transform2D, Transform3D, Vector2, Vector3, Vector4,
+};
+
+/// Types that can be interpolated between two values, as used by [`TransformInterpolator`].
+///
+/// Each implementation uses the interpolation Godot uses for that type: [`Transform2D::interpolate_with()`] and
+/// [`Transform3D::interpolate_with()`] for transforms, spherical interpolation for rotations and linear interpolation for vectors.
+pub trait Interpolate: Copy {
+    /// Interpolates from `self` to `to` by `weight`, which is typically in the range `0.0 ..= 1.0`.
+    fn interpolate(&self, to: &Self, weight: real) -> Self;
+}
+
+impl Interpolate for Transform2D {
+    fn interpolate(&self, to: &Self, weight: real) -> Self {
+        self.interpolate_with(to, weight)
+    }
+}
+
+impl Interpolate for Transform3D {
+    fn interpolate(&self, to: &Self, weight: real) -> Self {
+        self.interpolate_with(to, weight)
+    }
+}
+
+impl Interpolate for Basis {
+    fn interpolate(&self, to: &Self, weight: real) -> Self {
+        self.slerp(to, weight)
+    }
+}
+
+impl Interpolate for Quaternion {
+    fn interpolate(&self, to: &Self, weight: real) -> Self {
+        self.slerp(*to, weight)
+    }
+}
+
+macro_rules! impl_interpolate_lerp {
+    ($($Ty:ty),+) => {
+        $(
+            impl Interpolate for $Ty {
+                fn interpolate(&self, to: &Self, weight: real) -> Self {
+                    self.lerp(*to, weight)
+                }
+            }
+        )+
+    };
+}
+
+impl_interpolate_lerp!(Vector2, Vector3, Vector4);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Blends between the two most recent states of a value that is updated at a fixed rate, e.g. in `physics_process()`.
+///
+/// When physics runs at a lower rate than rendering, objects moved in `physics_process()` appear to stutter. The common remedy is to keep
+/// the previous and current physics state, and to render an interpolation between them, using the fraction of the physics tick that has
+/// elapsed -- provided by [`Engine::get_physics_interpolation_fraction()`][crate::classes::Engine::get_physics_interpolation_fraction].
+/// The rendered state thus lags behind by at most one physics tick.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::math::TransformInterpolator;
+/// use godot::classes::{Engine, INode3D, Node3D};
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node3D)]
+/// struct Body {
+///     physics_transform: TransformInterpolator<Transform3D>,
+///     base: Base<Node3D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode3D for Body {
+///     fn init(base: Base<Node3D>) -> Self {
+///         Self {
+///             physics_transform: TransformInterpolator::new(Transform3D::IDENTITY),
+///             base,
+///         }
+///     }
+///
+///     fn physics_process(&mut self, delta: f64) {
+///         let next = self.physics_transform.current().translated(Vector3::FORWARD * delta as real);
+///         self.physics_transform.push(next);
+///     }
+///
+///     fn process(&mut self, _delta: f64) {
+///         let fraction = Engine::singleton().get_physics_interpolation_fraction() as real;
+///         let transform = self.physics_transform.blended(fraction);
+///         self.base_mut().set_transform(transform);
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TransformInterpolator<T> {
+    previous: T,
+    current: T,
+}
+
+impl<T: Interpolate> TransformInterpolator<T> {
+    /// Creates an interpolator at rest, with both the previous and current state set to `initial`.
+    pub fn new(initial: T) -> Self {
+        Self {
+            previous: initial,
+            current: initial,
+        }
+    }
+
+    /// Records a new state, making the current one the previous state.
+    ///
+    /// Call this once per fixed update.
+    pub fn push(&mut self, next: T) {
+        self.previous = self.current;
+        self.current = next;
+    }
+
+    /// Sets both the previous and current state to `value`, so that there is no interpolation until the next [`push()`][Self::push].
+    ///
+    /// Use this when teleporting, to avoid blending across the jump.
+    pub fn reset(&mut self, value: T) {
+        self.previous = value;
+        self.current = value;
+    }
+
+    /// Returns the state between the previous (`fraction` 0.0) and the current one (`fraction` 1.0).
+    pub fn blended(&self, fraction: real) -> T {
+        self.previous.interpolate(&self.current, fraction)
+    }
+
+    /// State before the last [`push()`][Self::push].
+    pub fn previous(&self) -> T {
+        self.previous
+    }
+
+    /// State of the last [`push()`][Self::push].
+    pub fn current(&self) -> T {
+        self.current
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+
+    #[test]
+    fn interpolator_push_and_blend() {
+        let mut interpolator = TransformInterpolator::new(Vector2::ZERO);
+        assert_eq_approx!(interpolator.blended(0.5), Vector2::ZERO);
+
+        interpolator.push(Vector2::new(2.0, 4.0));
+        assert_eq_approx!(interpolator.blended(0.0), Vector2::ZERO);
+        assert_eq_approx!(interpolator.blended(0.5), Vector2::new(1.0, 2.0));
+        assert_eq_approx!(interpolator.blended(1.0), Vector2::new(2.0, 4.0));
+
+        interpolator.push(Vector2::new(4.0, 4.0));
+        assert_eq_approx!(interpolator.previous(), Vector2::new(2.0, 4.0));
+        assert_eq_approx!(interpolator.blended(0.25), Vector2::new(2.5, 4.0));
+
+        interpolator.reset(Vector2::ONE);
+        assert_eq_approx!(interpolator.blended(0.5), Vector2::ONE);
+    }
+
+    #[test]
+    fn interpolator_transform2d() {
+        let from = Transform2D::IDENTITY;
+        let to = Transform2D::from_angle_origin(1.0, Vector2::new(10.0, 0.0));
+
+        let mut interpolator = TransformInterpolator::new(from);
+        interpolator.push(to);
+
+        assert_eq_approx!(interpolator.blended(0.0), from);
+        assert_eq_approx!(interpolator.blended(1.0), to);
+        assert_eq_approx!(
+            interpolator.blended(0.5),
+            Transform2D::from_angle_origin(0.5, Vector2::new(5.0, 0.0))
+        );
+    }
+}
@@ -6,12 +6,18 @@
  */
 
 mod approx_eq;
+#[cfg(feature = "fixed-point")]
+mod fixed;
 mod float;
 mod glam_helpers;
+mod interpolator;
 
 pub use crate::{assert_eq_approx, assert_ne_approx};
 pub use approx_eq::ApproxEq;
+#[cfg(feature = "fixed-point")]
+pub use fixed::{Fixed, FixedVector2, FixedVector3};
 pub use float::FloatExt;
+pub use interpolator::{Interpolate, TransformInterpolator};
 
 // Internal glam re-exports
 pub(crate) use glam_helpers::*;
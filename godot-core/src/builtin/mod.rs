@@ -117,6 +117,10 @@
 //! Some operations are reasonably complex to implement in Rust, and we're not a math library, nor do we want to depend on one besides `glam`.
 //! An ever-increasing maintenance burden for geometry re-implementations is also detrimental.
 //!
+//! The fixed-point types behind the `fixed-point` feature are a deliberate exception: deterministic simulations cannot use the engine's
+//! floating-point math at all, so a small set of integer-only vector operations is provided. It is kept to what such simulations need
+//! at the boundary to the engine, not extended towards a full math library.
+//!
 //! TLDR: it's a trade-off between performance, maintenance effort and correctness -- the current combination of `glam` and Godot seems to be a
 //! relatively well-working sweet spot.
 //!
@@ -191,7 +195,8 @@ pub mod iter {
 /// Specialized types related to Godot's various string implementations.
 pub mod strings {
     pub use super::string::{
-        ExGStringFind, ExGStringSplit, ExStringNameFind, ExStringNameSplit, TransientStringNameOrd,
+        ExGStringFind, ExGStringSplit, ExStringNameFind, ExStringNameSplit, GStringBuilder,
+        TransientStringNameOrd,
     };
 }
 
@@ -47,12 +47,20 @@ impl Plane {
     /// _Godot equivalent: `Plane(Vector3 normal, float d)`_
     #[inline]
     pub fn new(unit_normal: Vector3, d: real) -> Self {
-        let plane = Self {
+        Self::try_new(unit_normal, d).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Fallible version of [`Plane::new()`], returning an error if `unit_normal` does not have unit length.
+    #[inline]
+    pub fn try_new(unit_normal: Vector3, d: real) -> Result<Self, String> {
+        if !unit_normal.is_normalized() {
+            return Err(format!("normal {unit_normal:?} is not normalized"));
+        }
+
+        Ok(Self {
             normal: unit_normal,
             d,
-        };
-        plane.assert_normalized();
-        plane
+        })
     }
 
     /// Create a new `Plane` through the origin from a normal.
@@ -99,17 +107,22 @@ impl Plane {
     /// _Godot equivalent: `Plane(Vector3 point1, Vector3 point2, Vector3 point3)`_
     #[inline]
     pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        Self::try_from_points(a, b, c).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Fallible version of [`Plane::from_points()`], returning an error if all three points are colinear.
+    #[inline]
+    pub fn try_from_points(a: Vector3, b: Vector3, c: Vector3) -> Result<Self, String> {
         let normal = (a - c).cross(a - b);
-        assert_ne!(
-            normal,
-            Vector3::ZERO,
-            "points {a}, {b}, {c} are all colinear"
-        );
+        if normal == Vector3::ZERO {
+            return Err(format!("points {a}, {b}, {c} are all colinear"));
+        }
+
         let normal = normal.normalized();
-        Self {
+        Ok(Self {
             normal,
             d: normal.dot(a),
-        }
+        })
     }
 
     /// Creates a new `Plane` with default values. This new `Plane` will be invalid.
@@ -241,15 +254,6 @@ impl Plane {
     pub fn project(self, point: Vector3) -> Vector3 {
         point - self.normal * self.distance_to(point)
     }
-
-    #[inline]
-    fn assert_normalized(self) {
-        assert!(
-            self.normal.is_normalized(),
-            "normal {:?} is not normalized",
-            self.normal
-        );
-    }
 }
 
 impl Neg for Plane {
@@ -352,6 +356,18 @@ mod test {
         let _ = Plane::from_points(Vector3::ZERO, Vector3::BACK, Vector3::new(0.0, 0.0, 2.0));
     }
 
+    #[test]
+    fn try_constructors_fail() {
+        let err = Plane::try_new(Vector3::new(1.0, 2.0, 3.0), 5.0).unwrap_err();
+        assert!(err.contains("not normalized"), "{err}");
+
+        let err = Plane::try_from_points(Vector3::ZERO, Vector3::BACK, Vector3::new(0.0, 0.0, 2.0))
+            .unwrap_err();
+        assert!(err.contains("colinear"), "{err}");
+
+        assert!(Plane::try_new(Vector3::UP, 1.0).is_ok());
+    }
+
     /// Tests `distance_to()`, `center()`, `contains_point()`, and `is_point_over()`.
     #[test]
     fn test_spatial_relations() {
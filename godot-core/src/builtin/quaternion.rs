@@ -49,10 +49,15 @@ impl Quaternion {
     /// # Panics
     /// If the vector3 is not normalized.
     pub fn from_axis_angle(axis: Vector3, angle: real) -> Self {
-        assert!(
-            axis.is_normalized(),
-            "Quaternion axis {axis:?} is not normalized."
-        );
+        Self::try_from_axis_angle(axis, angle).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Fallible version of [`Quaternion::from_axis_angle()`], returning an error if `axis` is not normalized.
+    pub fn try_from_axis_angle(axis: Vector3, angle: real) -> Result<Self, String> {
+        if !axis.is_normalized() {
+            return Err(format!("Quaternion axis {axis:?} is not normalized."));
+        }
+
         let d = axis.length();
         let sin_angle = (angle * 0.5).sin();
         let cos_angle = (angle * 0.5).cos();
@@ -61,7 +66,7 @@ impl Quaternion {
         let y = axis.y * s;
         let z = axis.z * s;
         let w = cos_angle;
-        Self::new(x, y, z, w)
+        Ok(Self::new(x, y, z, w))
     }
 
     /// Constructs a Quaternion representing the shortest arc between `arc_from` and `arc_to`.
@@ -439,10 +444,24 @@ impl Neg for Quaternion {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+
+    #[test]
+    fn try_from_axis_angle() {
+        let err = Quaternion::try_from_axis_angle(Vector3::new(1.0, 1.0, 0.0), 1.0).unwrap_err();
+        assert!(err.contains("not normalized"), "{err}");
+
+        let quat = Quaternion::try_from_axis_angle(Vector3::UP, 1.0).unwrap();
+        assert_eq_approx!(quat, Quaternion::from_axis_angle(Vector3::UP, 1.0));
+        assert_eq_approx!(quat.get_axis(), Vector3::UP);
+        assert_eq_approx!(quat.get_angle(), 1.0);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
-        let quaternion = super::Quaternion::new(1.0, 1.0, 1.0, 1.0);
+        let quaternion = Quaternion::new(1.0, 1.0, 1.0, 1.0);
         let expected_json = "{\"x\":1.0,\"y\":1.0,\"z\":1.0,\"w\":1.0}";
 
         crate::builtin::test_utils::roundtrip(&quaternion, expected_json);
@@ -11,12 +11,13 @@ use std::ptr;
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::classes::object::ConnectFlags;
 use crate::classes::Object;
 use crate::global::Error;
 use crate::meta;
 use crate::meta::{FromGodot, GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
-use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
+use crate::obj::{Bounds, EngineBitfield, Gd, GodotClass, Inherits, InstanceId};
 use sys::{ffi_methods, GodotFfi};
 
 /// A `Signal` represents a signal of an Object instance in Godot.
@@ -116,6 +117,33 @@ impl Signal {
             .collect()
     }
 
+    /// Returns the connections of this signal as typed structs, in the order in which Godot invokes them on emission.
+    ///
+    /// Typed alternative to [`connections()`][Self::connections].
+    pub fn connection_list(&self) -> Vec<SignalConnection> {
+        self.connections()
+            .iter_shared()
+            .map(|dict| SignalConnection::from_dictionary(&dict))
+            .collect()
+    }
+
+    /// Returns `true` if this signal is connected to `method` on `target`.
+    ///
+    /// Unlike [`is_connected()`][Self::is_connected], this also matches callables with bound or unbound arguments, as it only compares
+    /// the target object and method name.
+    pub fn is_connected_to<T>(&self, target: &Gd<T>, method: impl meta::AsArg<StringName>) -> bool
+    where
+        T: Inherits<Object>,
+    {
+        meta::arg_into_ref!(method);
+        let target_id = target.instance_id();
+
+        self.connection_list().iter().any(|connection| {
+            connection.callable.object_id() == Some(target_id)
+                && connection.callable.method_name().as_ref() == Some(method)
+        })
+    }
+
     /// Returns the name of the signal.
     pub fn name(&self) -> StringName {
         self.as_inner().get_name()
@@ -156,6 +184,83 @@ impl Signal {
         self.as_inner().is_null()
     }
 
+    /// Returns a future that resolves with the arguments of the next emission of this signal.
+    ///
+    /// `R` is a tuple of the signal's parameter types, e.g. `(Gd<Node>,)`, or `()` for signals without parameters. See
+    /// [`SignalFuture`][crate::tools::SignalFuture] for details.
+    ///
+    /// _Godot equivalent: `await signal`_
+    #[cfg(since_api = "4.2")]
+    pub fn to_future<R>(&self) -> crate::tools::SignalFuture<R>
+    where
+        R: crate::tools::FromSignalArgs,
+    {
+        crate::tools::SignalFuture::new(self.clone())
+    }
+
+    /// Connects a Rust function with typed parameters to this signal.
+    ///
+    /// The signal's arguments are converted to the function's parameter types on each emission, so no manual `Variant` handling is
+    /// needed. The function may have fewer parameters than the signal: trailing signal arguments are then dropped, like with
+    /// [`Callable::unbind()`]. Arguments in the middle can be ignored with `_` patterns. To pass extra arguments to the function, use
+    /// [`bind_args()`][Self::bind_args].
+    ///
+    /// Returns the result of [`connect()`][Self::connect].
+    ///
+    /// # Panics
+    /// On emission, if the signal has fewer arguments than the function has parameters, or if an argument cannot be converted to the
+    /// parameter type. Signals carry no static type information, so neither can be checked at compile time or when connecting. The panic
+    /// is caught and reported by Godot, and the function is not invoked for that emission.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn on_hit(signal: &Signal) {
+    ///     // Signal "hit" has parameters (damage: i64, source: Gd<Node>, critical: bool).
+    ///     signal.connect_mapped(|damage: i64, _source: Gd<Node>, critical: bool| {
+    ///         godot_print!("damage {damage}, critical {critical}");
+    ///     });
+    ///
+    ///     // Only interested in the damage: `source` and `critical` are dropped.
+    ///     signal.connect_mapped(|damage: i64| {
+    ///         godot_print!("damage {damage}");
+    ///     });
+    /// }
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn connect_mapped<Ps, F>(&self, function: F) -> Error
+    where
+        Ps: crate::tools::FromSignalArgs,
+        F: crate::tools::SignalReceiver<Ps>,
+    {
+        crate::tools::connect_mapped(self, Vec::new(), function)
+    }
+
+    /// Binds extra arguments, which are passed after the signal's own arguments to functions connected through the returned object.
+    ///
+    /// If the function has fewer parameters than the signal's arguments plus the bound ones, trailing signal arguments are dropped, so
+    /// the bound arguments always arrive in the last parameters.
+    ///
+    /// Typed alternative to [`Callable::bind()`]. Connect with [`BoundSignal::connect_mapped()`][crate::tools::BoundSignal::connect_mapped].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn connect_button(signal: &Signal, index: i32) {
+    ///     // Signal "pressed" has no parameters.
+    ///     signal.bind_args((index, "menu")).connect_mapped(|index: i32, group: GString| {
+    ///         godot_print!("pressed button {index} in {group}");
+    ///     });
+    /// }
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn bind_args<B>(&self, args: B) -> crate::tools::BoundSignal
+    where
+        B: crate::tools::ToSignalArgs,
+    {
+        crate::tools::BoundSignal::new(self.clone(), args.to_signal_args())
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerSignal {
         inner::InnerSignal::from_outer(self)
@@ -213,3 +318,49 @@ impl fmt::Display for Signal {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A single connection of a signal to a callable.
+///
+/// Returned by [`Signal::connection_list()`] and [`Gd::signal_connections()`][crate::obj::Gd::signal_connections].
+#[derive(Clone, Debug)]
+pub struct SignalConnection {
+    /// The signal being connected.
+    pub signal: Signal,
+
+    /// The callable that is invoked on emission.
+    pub callable: Callable,
+
+    /// Flags passed when connecting.
+    pub flags: ConnectFlags,
+
+    /// Object that the callable is bound to.
+    ///
+    /// `None` for callables without an object (e.g. custom Rust callables), or if the object is no longer alive.
+    pub target: Option<Gd<Object>>,
+}
+
+impl SignalConnection {
+    /// Method name of the callable, or `None` for custom callables.
+    pub fn method(&self) -> Option<StringName> {
+        self.callable.method_name()
+    }
+
+    /// Returns whether the connection was made with `flag`, e.g. [`ConnectFlags::ONE_SHOT`].
+    pub fn has_flag(&self, flag: ConnectFlags) -> bool {
+        self.flags.is_set(flag)
+    }
+
+    fn from_dictionary(dict: &Dictionary) -> Self {
+        let callable: Callable = dict.get_or_nil("callable").to();
+        let flags: i64 = dict.get_or_nil("flags").to();
+
+        Self {
+            signal: dict.get_or_nil("signal").to(),
+            target: callable.object(),
+            callable,
+            flags: ConnectFlags::from_ord(flags as u64),
+        }
+    }
+}
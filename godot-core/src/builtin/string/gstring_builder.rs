@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::GString;
+
+/// Number of UTF-32 code points that are buffered on the stack before being appended to the Godot string.
+const CHUNK_LEN: usize = 64;
+
+/// Incrementally builds a [`GString`], appending directly into Godot's string storage.
+///
+/// Using `format!()` followed by `GString::from()` allocates twice: once for the Rust `String` (UTF-8), and once for the `GString` (UTF-32).
+/// `GStringBuilder` avoids the intermediate Rust string: text is re-encoded to UTF-32 in small stack-allocated chunks, which are then
+/// appended to the Godot string in place.
+///
+/// The builder implements [`std::fmt::Write`], so it can be used with the `write!` and `writeln!` macros.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::strings::GStringBuilder;
+/// use godot::builtin::GString;
+/// use std::fmt::Write;
+///
+/// let mut builder = GStringBuilder::new();
+/// for i in 0..3 {
+///     write!(builder, "{i},").unwrap();
+/// }
+/// builder.push_str("end");
+///
+/// let string: GString = builder.build();
+/// assert_eq!(string, "0,1,2,end".into());
+/// ```
+///
+/// # Null characters
+/// Godot strings are null-terminated, so `'\0'` characters cannot be represented. They are skipped when appended.
+#[derive(Default)]
+pub struct GStringBuilder {
+    string: GString,
+}
+
+impl GStringBuilder {
+    /// Creates a builder starting with an empty string.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder that continues appending to an existing string.
+    ///
+    /// If `string` shares its storage with other `GString` instances, the first append will cause a copy (copy-on-write).
+    pub fn from_gstring(string: GString) -> Self {
+        Self { string }
+    }
+
+    /// Appends a Rust string slice.
+    pub fn push_str(&mut self, s: &str) {
+        let mut buffer = [0 as sys::char32_t; CHUNK_LEN + 1];
+        let mut len = 0;
+
+        for ch in s.chars() {
+            if ch == '\0' {
+                continue;
+            }
+
+            buffer[len] = ch as sys::char32_t;
+            len += 1;
+
+            if len == CHUNK_LEN {
+                self.append_chunk(&mut buffer, len);
+                len = 0;
+            }
+        }
+
+        if len > 0 {
+            self.append_chunk(&mut buffer, len);
+        }
+    }
+
+    /// Appends a single character.
+    pub fn push_char(&mut self, ch: char) {
+        if ch == '\0' {
+            return;
+        }
+
+        // SAFETY: string pointer is valid; `char` is a valid Unicode code point.
+        unsafe {
+            interface_fn!(string_operator_plus_eq_char)(
+                self.string.string_sys_mut(),
+                ch as sys::char32_t,
+            );
+        }
+    }
+
+    /// Appends another Godot string, without converting it to Rust first.
+    pub fn push_gstring(&mut self, other: &GString) {
+        // SAFETY: both string pointers are valid; `other` is only read.
+        unsafe {
+            interface_fn!(string_operator_plus_eq_string)(
+                self.string.string_sys_mut(),
+                other.string_sys(),
+            );
+        }
+    }
+
+    /// Number of characters built so far.
+    pub fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    /// Returns `true` if nothing has been appended yet.
+    pub fn is_empty(&self) -> bool {
+        self.string.is_empty()
+    }
+
+    /// Returns the built string, without copying it.
+    pub fn build(self) -> GString {
+        self.string
+    }
+
+    /// Null-terminates the first `len` code points in `buffer` and appends them to the string.
+    fn append_chunk(&mut self, buffer: &mut [sys::char32_t; CHUNK_LEN + 1], len: usize) {
+        debug_assert!(len <= CHUNK_LEN);
+        buffer[len] = 0;
+
+        // SAFETY: string pointer is valid; `buffer` is null-terminated and contains only valid code points (no interior nulls).
+        unsafe {
+            interface_fn!(string_operator_plus_eq_c32str)(
+                self.string.string_sys_mut(),
+                buffer.as_ptr(),
+            );
+        }
+    }
+}
+
+impl fmt::Write for GStringBuilder {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push_char(c);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for GStringBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GStringBuilder").field(&self.string).finish()
+    }
+}
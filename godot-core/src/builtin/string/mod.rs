@@ -8,6 +8,7 @@
 //! Godot-types that are Strings.
 
 mod gstring;
+mod gstring_builder;
 mod macros;
 mod node_path;
 mod string_macros;
@@ -18,6 +19,7 @@ use crate::meta::{FromGodot, GodotConvert, ToGodot};
 use std::ops;
 
 pub use gstring::*;
+pub use gstring_builder::GStringBuilder;
 pub use node_path::NodePath;
 pub use string_name::*;
 
@@ -160,12 +160,25 @@ impl Transform2D {
     /// Returns the inverse of the transform, under the assumption that the
     /// transformation is composed of rotation, scaling and translation.
     ///
+    /// The basis must be invertible; see [`Transform2D::try_affine_inverse()`] for a fallible version.
+    ///
     /// _Godot equivalent: `Transform2D.affine_inverse()`_
     #[must_use]
     pub fn affine_inverse(&self) -> Self {
         self.glam(|aff| aff.inverse())
     }
 
+    /// Fallible version of [`Transform2D::affine_inverse()`], returning an error if the determinant of the basis is 0.
+    pub fn try_affine_inverse(&self) -> Result<Self, String> {
+        if self.determinant().is_zero_approx() {
+            return Err(format!(
+                "transform {self} is not invertible (determinant 0)"
+            ));
+        }
+
+        Ok(self.affine_inverse())
+    }
+
     /// Returns the determinant of the basis matrix.
     ///
     /// If the basis is uniformly scaled, then its determinant equals the square of the scale factor.
@@ -605,6 +618,21 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn try_affine_inverse() {
+        let singular = Transform2D::from_cols(
+            Vector2::new(1.0, 2.0),
+            Vector2::new(2.0, 4.0),
+            Vector2::ZERO,
+        );
+        let err = singular.try_affine_inverse().unwrap_err();
+        assert!(err.contains("not invertible"), "{err}");
+
+        let trans =
+            Transform2D::from_angle_origin(real!(30.0).to_radians(), Vector2::new(1.0, 2.0));
+        assert_eq_approx!(trans.try_affine_inverse().unwrap(), trans.affine_inverse());
+    }
+
     #[test]
     fn transform2d_constructors_correct() {
         let trans = Transform2D::from_angle(real!(115.0).to_radians());
@@ -8,7 +8,7 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
-use crate::builtin::math::{ApproxEq, GlamConv, GlamType};
+use crate::builtin::math::{ApproxEq, FloatExt, GlamConv, GlamType};
 use crate::builtin::{real, Aabb, Basis, Plane, Projection, RAffine3, Vector3};
 
 use std::fmt::Display;
@@ -128,11 +128,24 @@ impl Transform3D {
 
     /// Returns the inverse of the transform, under the assumption that the
     /// transformation is composed of rotation, scaling and translation.
+    ///
+    /// The basis must be invertible; see [`Transform3D::try_affine_inverse()`] for a fallible version.
     #[must_use]
     pub fn affine_inverse(&self) -> Self {
         self.glam(|aff| aff.inverse())
     }
 
+    /// Fallible version of [`Transform3D::affine_inverse()`], returning an error if the determinant of the basis is 0.
+    pub fn try_affine_inverse(&self) -> Result<Self, String> {
+        if self.basis.determinant().is_zero_approx() {
+            return Err(format!(
+                "transform {self} is not invertible (determinant 0)"
+            ));
+        }
+
+        Ok(self.affine_inverse())
+    }
+
     /// Returns a transform interpolated between this transform and another by
     /// a given weight (on the range of 0.0 to 1.0).
     #[must_use]
@@ -399,6 +412,8 @@ crate::meta::impl_godot_as_self!(Transform3D);
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_eq_approx;
+    use crate::builtin::EulerOrder;
 
     // Tests translated from Godot.
 
@@ -493,6 +508,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn try_affine_inverse() {
+        let err = DUMMY_TRANSFORM.try_affine_inverse().unwrap_err();
+        assert!(err.contains("not invertible"), "{err}");
+
+        let trans = Transform3D::new(
+            Basis::from_euler(EulerOrder::YXZ, Vector3::new(0.5, 1.0, 0.25))
+                .scaled(Vector3::new(2.0, 3.0, 4.0)),
+            Vector3::new(1.0, 2.0, 3.0),
+        );
+        assert_eq_approx!(trans.try_affine_inverse().unwrap(), trans.affine_inverse());
+        assert_eq_approx!(
+            trans * trans.try_affine_inverse().unwrap(),
+            Transform3D::IDENTITY
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
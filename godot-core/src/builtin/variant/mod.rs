@@ -444,6 +444,11 @@ impl Clone for Variant {
 
 impl Drop for Variant {
     fn drop(&mut self) {
+        // See impl_builtin_traits! for Drop.
+        if !sys::is_initialized() {
+            return;
+        }
+
         unsafe {
             interface_fn!(variant_destroy)(self.var_sys_mut());
         }
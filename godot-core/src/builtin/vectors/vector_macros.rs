@@ -598,7 +598,10 @@ macro_rules! impl_float_vector_fns {
             /// and returns the result at position `weight`.
             ///
             /// `weight` is on the range of 0.0 to 1.0, representing the amount of interpolation.
+            /// This is a uniform [Catmull-Rom spline](https://en.wikipedia.org/wiki/Centripetal_Catmull%E2%80%93Rom_spline) through
+            /// the points `pre_a`, `self`, `b` and `post_b`.
             #[inline]
+            #[doc(alias = "catmull_rom")]
             pub fn cubic_interpolate(self, b: Self, pre_a: Self, post_b: Self, weight: real) -> Self {
                 Self::new(
                     $(
@@ -633,6 +636,19 @@ macro_rules! impl_float_vector_fns {
                 )
             }
 
+            /// Cubic Hermite interpolation between `self` and `to`, with the tangents `self_tangent` and `to_tangent` at both ends.
+            ///
+            /// `weight` is on the range of 0.0 to 1.0, representing the amount of interpolation. See
+            /// [`FloatExt::hermite_interpolate()`][crate::builtin::math::FloatExt::hermite_interpolate] for details on the tangents.
+            #[inline]
+            pub fn hermite_interpolate(self, to: Self, self_tangent: Self, to_tangent: Self, weight: real) -> Self {
+                Self::new(
+                    $(
+                        self.$comp.hermite_interpolate(to.$comp, self_tangent.$comp, to_tangent.$comp, weight)
+                    ),*
+                )
+            }
+
             /// Returns the normalized vector pointing from this vector to `to` or [`None`], if `self` and `to` are equal.
             ///
             /// This is equivalent to using `(b - a).try_normalized()`. See also [`direction_to()`][Self::direction_to].
@@ -4,10 +4,24 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
+#[cfg(feature = "codegen-full")]
+use crate::builtin::{Color, GString, Variant, Vector2};
+use crate::builtin::{NodePath, PackedByteArray, StringName};
+use crate::classes::image::Format;
+#[cfg(feature = "codegen-full")]
+use crate::classes::{
+    Control, Font, ImageTexture, InstancePlaceholder, StyleBox, TextServerManager, Texture2D,
+};
+use crate::classes::{Image, Node, PackedScene, SceneTree};
+use crate::global::Error as GodotError;
+#[cfg(feature = "codegen-full")]
+use crate::meta::error::ConvertError;
+use crate::meta::error::ImageError;
 use crate::meta::{arg_into_ref, AsArg};
+#[cfg(feature = "codegen-full")]
+use crate::meta::{AsObjectArg, FromGodot};
 use crate::obj::{Gd, Inherits};
+use crate::tools::{validate_image_data, ImageMipmaps, NodeGroup};
 
 /// Manual extensions for the `Node` class.
 impl Node {
@@ -43,6 +57,109 @@ impl Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    /// Sets `owner` as the [owner][Self::set_owner] of all descendants of this node (not including this node itself).
+    ///
+    /// This is needed before [`PackedScene::pack()`] when a scene was composed in code, since only owned nodes are saved.
+    ///
+    /// Nodes instantiated from another scene (non-empty [`scene_file_path`][Self::get_scene_file_path]) receive the owner themselves,
+    /// but their own descendants are left untouched -- they already belong to that sub-scene, and taking them over would duplicate
+    /// them on save. This mirrors the editor's behavior.
+    ///
+    /// The tree is walked iteratively, so deep hierarchies do not risk a stack overflow.
+    ///
+    /// # Composing large scenes
+    /// Godot has no way to batch `NOTIFICATION_ENTER_TREE`/`NOTIFICATION_READY`: each child added to a node inside the scene tree enters the
+    /// tree immediately, together with its subtree. For procedural generation, compose the subtree on a node that is _not_ yet inside the
+    /// tree, assign the owner, and add only its root at the end. Tree notifications are then propagated in a single pass.
+    pub fn assign_owner_recursive<T>(&mut self, owner: &Gd<T>)
+    where
+        T: Inherits<Node>,
+    {
+        let owner = owner.clone().upcast::<Node>();
+
+        let mut stack: Vec<Gd<Node>> = Vec::new();
+        push_children(self, &mut stack);
+
+        while let Some(mut node) = stack.pop() {
+            if node != owner {
+                node.set_owner(&owner);
+            }
+
+            if node.get_scene_file_path().is_empty() {
+                push_children(&node, &mut stack);
+            }
+        }
+    }
+
+    /// Adds this node to the group `G`.
+    ///
+    /// Shorthand for [`add_to_group()`][Self::add_to_group] with [`G::NAME`][NodeGroup::NAME].
+    pub fn add_to_group_typed<G: NodeGroup>(&mut self) {
+        self.add_to_group(G::NAME);
+    }
+
+    /// Removes this node from the group `G`.
+    ///
+    /// Shorthand for [`remove_from_group()`][Self::remove_from_group] with [`G::NAME`][NodeGroup::NAME].
+    pub fn remove_from_group_typed<G: NodeGroup>(&mut self) {
+        self.remove_from_group(G::NAME);
+    }
+
+    /// Returns whether this node is in the group `G`.
+    ///
+    /// Shorthand for [`is_in_group()`][Self::is_in_group] with [`G::NAME`][NodeGroup::NAME].
+    pub fn is_in_group_typed<G: NodeGroup>(&self) -> bool {
+        self.is_in_group(G::NAME)
+    }
+}
+
+/// Pushes children in reverse order, so that they are popped (visited) in tree order.
+fn push_children(node: &Node, stack: &mut Vec<Gd<Node>>) {
+    for i in (0..node.get_child_count()).rev() {
+        if let Some(child) = node.get_child(i) {
+            stack.push(child);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// Returns all members of `group` that have type `T` or inherited, in tree order.
+    ///
+    /// Members of other types are skipped; use [`try_nodes_in_group_as()`][Self::try_nodes_in_group_as] if they indicate an error.
+    /// For groups declared as [`NodeGroup`] marker types, pass `G::NAME`.
+    pub fn nodes_in_group_as<T>(
+        &mut self,
+        group: impl AsArg<StringName>,
+    ) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let members: Vec<Gd<T>> = self
+            .get_nodes_in_group(group)
+            .iter_shared()
+            .filter_map(|node| node.try_cast::<T>().ok())
+            .collect();
+
+        members.into_iter()
+    }
+
+    /// Returns all members of `group` as type `T`, in tree order, or the first member that does not have type `T` or inherited.
+    pub fn try_nodes_in_group_as<T>(
+        &mut self,
+        group: impl AsArg<StringName>,
+    ) -> Result<Vec<Gd<T>>, Gd<Node>>
+    where
+        T: Inherits<Node>,
+    {
+        self.get_nodes_in_group(group)
+            .iter_shared()
+            .map(|node| node.try_cast::<T>())
+            .collect()
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -71,3 +188,418 @@ impl PackedScene {
         self.instantiate().and_then(|gd| gd.try_cast::<T>().ok())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Image` class.
+///
+/// Constructors from raw pixel data, which validate dimensions and data size up front. Godot's own
+/// [`create_from_data()`][Self::create_from_data] only prints an error and returns an empty image if the data doesn't match.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::Image;
+///
+/// // 2x2 checkerboard, black and white.
+/// let pixels = [
+///     0, 0, 0, 255,   255, 255, 255, 255,
+///     255, 255, 255, 255,   0, 0, 0, 255,
+/// ];
+///
+/// let image = Image::from_rgba8(2, 2, &pixels).expect("valid image data");
+/// assert_eq!(image.get_pixel(1, 0), Color::WHITE);
+/// ```
+impl Image {
+    /// Creates an image in [`Format::RGBA8`] from `data`, with 4 bytes per pixel in row-major order.
+    ///
+    /// Shorthand for [`from_raw()`][Self::from_raw] without mipmaps.
+    pub fn from_rgba8(width: u32, height: u32, data: &[u8]) -> Result<Gd<Image>, ImageError> {
+        Self::from_raw(width, height, Format::RGBA8, data, ImageMipmaps::None)
+    }
+
+    /// Creates an image from `data`, which holds pixels of the uncompressed `format` in row-major order.
+    ///
+    /// With [`ImageMipmaps::Included`], `data` must contain the mipmap levels after the full-size image.
+    ///
+    /// # Errors
+    /// - If `width` or `height` is zero or exceeds Godot's limits.
+    /// - If `format` is a compressed format.
+    /// - If the length of `data` doesn't match the dimensions, format and mipmaps.
+    /// - If mipmaps are to be generated, but Godot fails to do so.
+    pub fn from_raw(
+        width: u32,
+        height: u32,
+        format: Format,
+        data: &[u8],
+        mipmaps: ImageMipmaps,
+    ) -> Result<Gd<Image>, ImageError> {
+        validate_image_data(width, height, format, data.len(), mipmaps)?;
+
+        // Casts: validated to fit into i32.
+        let mut image = Image::create_from_data(
+            width as i32,
+            height as i32,
+            mipmaps == ImageMipmaps::Included,
+            format,
+            &PackedByteArray::from(data),
+        )
+        .ok_or_else(ImageError::creating)?;
+
+        if mipmaps == ImageMipmaps::Generate {
+            let error = image.generate_mipmaps();
+            if error != GodotError::OK {
+                return Err(ImageError::generating_mipmaps(error));
+            }
+        }
+
+        Ok(image)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ImageTexture` class.
+#[cfg(feature = "codegen-full")]
+impl ImageTexture {
+    /// Creates a texture from `RGBA8` pixel data, with 4 bytes per pixel in row-major order.
+    ///
+    /// See [`Image::from_rgba8()`] for details and errors.
+    pub fn from_rgba8(
+        width: u32,
+        height: u32,
+        data: &[u8],
+    ) -> Result<Gd<ImageTexture>, ImageError> {
+        Self::from_image(&Image::from_rgba8(width, height, data)?)
+    }
+
+    /// Creates a texture from pixel data of the uncompressed `format`, in row-major order.
+    ///
+    /// See [`Image::from_raw()`] for details and errors.
+    pub fn from_raw(
+        width: u32,
+        height: u32,
+        format: Format,
+        data: &[u8],
+        mipmaps: ImageMipmaps,
+    ) -> Result<Gd<ImageTexture>, ImageError> {
+        Self::from_image(&Image::from_raw(width, height, format, data, mipmaps)?)
+    }
+
+    /// Creates a texture from `image`.
+    ///
+    /// Like [`create_from_image()`][Self::create_from_image], but returns an error instead of `None`, in particular if the image is
+    /// empty.
+    pub fn from_image(image: &Gd<Image>) -> Result<Gd<ImageTexture>, ImageError> {
+        if image.is_empty() {
+            return Err(ImageError::empty_image());
+        }
+
+        ImageTexture::create_from_image(image).ok_or_else(ImageError::creating)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `InstancePlaceholder` class.
+///
+/// Placeholders are not created directly: Godot substitutes them for sub-scenes that are marked with _Load as Placeholder_ in the editor,
+/// or with [`Node::set_scene_instance_load_placeholder()`] before [`PackedScene::pack()`]. Instantiating the outer scene then yields an
+/// `InstancePlaceholder` in place of each marked sub-scene, which stores the sub-scene's path and its property overrides. This allows
+/// streaming systems to load parts of a world on demand.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::InstancePlaceholder;
+///
+/// fn stream_in(mut placeholder: Gd<InstancePlaceholder>) -> Gd<Node2D> {
+///     let above_ground = placeholder
+///         .stored_property::<Vector2>("position")
+///         .is_some_and(|pos| pos.y < 0.0);
+///
+///     placeholder
+///         .replace_with_instance_as::<Node2D>([("visible".into(), above_ground.to_variant())])
+///         .expect("placeholder of Node2D scene")
+/// }
+/// ```
+#[cfg(feature = "codegen-full")]
+impl InstancePlaceholder {
+    /// Returns the property values that the placeholder will apply to the instantiated scene.
+    ///
+    /// _Godot equivalent: `InstancePlaceholder.get_stored_values()`_
+    pub fn stored_properties(&mut self) -> Vec<(StringName, Variant)> {
+        self.get_stored_values()
+            .iter_shared()
+            .map(|(name, value)| (name.to::<StringName>(), value))
+            .collect()
+    }
+
+    /// Returns the stored value of property `name` converted to `T`, or `None` if the placeholder does not override that property.
+    ///
+    /// # Panics
+    /// If the stored value cannot be converted to `T`. Use [`try_stored_property()`][Self::try_stored_property] for a fallible version.
+    pub fn stored_property<T: FromGodot>(&mut self, name: impl AsArg<StringName>) -> Option<T> {
+        arg_into_ref!(name);
+
+        self.try_stored_property(name).map(|result| {
+            result
+                .unwrap_or_else(|err| panic!("stored property `{name}` has unexpected type: {err}"))
+        })
+    }
+
+    /// Returns the stored value of property `name` converted to `T` (fallible), or `None` if the placeholder does not override that
+    /// property.
+    pub fn try_stored_property<T: FromGodot>(
+        &mut self,
+        name: impl AsArg<StringName>,
+    ) -> Option<Result<T, ConvertError>> {
+        arg_into_ref!(name);
+
+        self.get_stored_values()
+            .get(name.clone())
+            .map(|value| value.try_to::<T>())
+    }
+
+    /// Instantiates the scene, replaces the placeholder with it, and applies `overrides` on top of the stored properties.
+    ///
+    /// The placeholder must have a parent; it is removed from it and queued for deletion. Returns `Err` with the new node
+    /// if it does not have type `T` or inherited; the node is still inserted in that case.
+    ///
+    /// # Panics
+    /// If the placeholder has no parent or the scene cannot be loaded.
+    pub fn replace_with_instance_as<T>(
+        &mut self,
+        overrides: impl IntoIterator<Item = (StringName, Variant)>,
+    ) -> Result<Gd<T>, Gd<Node>>
+    where
+        T: Inherits<Node>,
+    {
+        let mut node = self
+            .create_instance_ex()
+            .replace(true)
+            .done()
+            .unwrap_or_else(|| {
+                panic!(
+                    "failed to instantiate placeholder for `{path}`",
+                    path = self.get_instance_path()
+                )
+            });
+
+        for (property, value) in overrides {
+            node.set(&property, &value);
+        }
+
+        node.try_cast::<T>()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Font` class.
+#[cfg(feature = "codegen-full")]
+impl Font {
+    /// Size of `text` when drawn in a single line with `font_size`.
+    ///
+    /// Shorthand for [`get_string_size_ex()`][Self::get_string_size_ex] with only the font size set.
+    pub fn measure(&self, text: impl AsArg<GString>, font_size: i32) -> Vector2 {
+        self.get_string_size_ex(text).font_size(font_size).done()
+    }
+
+    /// Size of `text` when wrapped at `max_width` pixels, with `font_size`.
+    ///
+    /// The width of the result is the width of the longest line, the height covers all lines. Explicit line breaks are respected.
+    pub fn measure_wrapped(
+        &self,
+        text: impl AsArg<GString>,
+        font_size: i32,
+        max_width: f32,
+    ) -> Vector2 {
+        self.get_multiline_string_size_ex(text)
+            .font_size(font_size)
+            .width(max_width)
+            .done()
+    }
+
+    /// Splits `text` into the lines that result from wrapping it at `max_width` pixels, with `font_size`.
+    ///
+    /// Lines are broken at word boundaries and explicit line breaks; single words wider than `max_width` are not split. Trailing
+    /// whitespace is removed from each line.
+    ///
+    /// The lines are computed by the primary text server, so they match what `Label` or `TextParagraph` display for the same settings.
+    pub fn wrap_lines(
+        &self,
+        text: impl AsArg<GString>,
+        font_size: i32,
+        max_width: f32,
+    ) -> Vec<GString> {
+        arg_into_ref!(text);
+
+        let mut server = TextServerManager::singleton()
+            .get_primary_interface()
+            .expect("no primary TextServer available");
+
+        let shaped = server.create_shaped_text();
+        server.shaped_text_add_string(shaped, text, &self.get_rids(), font_size.into());
+        let breaks = server.shaped_text_get_line_breaks(shaped, max_width.into());
+        server.free_rid(shaped);
+
+        // Breaks are [start, end) pairs of character indices.
+        let chars = text.chars();
+        breaks
+            .as_slice()
+            .chunks_exact(2)
+            .map(|range| {
+                let line: String = chars[range[0] as usize..range[1] as usize].iter().collect();
+                GString::from(line.trim_end())
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Generates a typed override setter and getter for one kind of theme item.
+#[cfg(feature = "codegen-full")]
+macro_rules! impl_theme_item {
+    (
+        $kind:literal,
+        $override_fn:ident($($value:ident: $Value:ty),+) => $add_override:ident,
+        $get_fn:ident() -> Option<$Item:ty> => $get_theme:ident,
+        $has_theme:ident
+    ) => {
+        #[doc = concat!("Overrides the theme ", $kind, " `name` for this control only.")]
+        ///
+        #[doc = concat!("Shorthand for [`", stringify!($add_override), "()`][Self::", stringify!($add_override), "]. In Debug builds, warns ")]
+        #[doc = concat!("if neither the control's themes nor the default theme define a ", $kind, " `name`, which usually indicates a typo.")]
+        pub fn $override_fn(&mut self, name: impl AsArg<StringName>, $($value: $Value),+) {
+            arg_into_ref!(name);
+
+            #[cfg(debug_assertions)]
+            self.validate_theme_item(name, $kind, self.$has_theme(name));
+
+            self.$add_override(name, $($value),+);
+        }
+
+        #[doc = concat!("Returns the theme ", $kind, " `name`, as resolved for this control (overrides, themes, then the default theme).")]
+        ///
+        #[doc = concat!("Like [`", stringify!($get_theme), "()`][Self::", stringify!($get_theme), "], but returns `None` if there is no ")]
+        #[doc = concat!($kind, " `name`, instead of silently returning a fallback value.")]
+        pub fn $get_fn(&self, name: impl AsArg<StringName>) -> Option<$Item> {
+            arg_into_ref!(name);
+
+            if !self.$has_theme(name) {
+                return None;
+            }
+
+            ThemeItemValue::into_item(self.$get_theme(name))
+        }
+    };
+}
+
+/// Manual extensions for the `Control` class.
+///
+/// Typed helpers for theme items. Names passed as `&str` are converted to cached `StringName`s, so repeated calls with
+/// literals (e.g. in `draw()`) don't allocate.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::Button;
+/// let mut button = Button::new_alloc();
+/// button.override_color("font_color", Color::ORANGE_RED);
+/// button.override_font_size("font_size", 24);
+///
+/// assert_eq!(button.theme_font_size("font_size"), Some(24));
+/// assert_eq!(button.theme_font_size("no_such_size"), None);
+/// ```
+#[cfg(feature = "codegen-full")]
+impl Control {
+    impl_theme_item!(
+        "color",
+        override_color(color: Color) => add_theme_color_override,
+        theme_color() -> Option<Color> => get_theme_color,
+        has_theme_color
+    );
+
+    impl_theme_item!(
+        "constant",
+        override_constant(constant: i32) => add_theme_constant_override,
+        theme_constant() -> Option<i32> => get_theme_constant,
+        has_theme_constant
+    );
+
+    impl_theme_item!(
+        "font size",
+        override_font_size(font_size: i32) => add_theme_font_size_override,
+        theme_font_size() -> Option<i32> => get_theme_font_size,
+        has_theme_font_size
+    );
+
+    impl_theme_item!(
+        "font",
+        override_font(font: impl AsObjectArg<Font>) => add_theme_font_override,
+        theme_font() -> Option<Gd<Font>> => get_theme_font,
+        has_theme_font
+    );
+
+    impl_theme_item!(
+        "icon",
+        override_icon(icon: impl AsObjectArg<Texture2D>) => add_theme_icon_override,
+        theme_icon() -> Option<Gd<Texture2D>> => get_theme_icon,
+        has_theme_icon
+    );
+
+    impl_theme_item!(
+        "stylebox",
+        override_stylebox(stylebox: impl AsObjectArg<StyleBox>) => add_theme_stylebox_override,
+        theme_stylebox() -> Option<Gd<StyleBox>> => get_theme_stylebox,
+        has_theme_stylebox
+    );
+
+    #[cfg(debug_assertions)]
+    fn validate_theme_item(&self, name: &StringName, kind: &str, exists: bool) {
+        if !exists {
+            crate::godot_warn!(
+                "{class} has no theme {kind} \"{name}\"; check the name for typos",
+                class = self.get_class(),
+            );
+        }
+    }
+}
+
+/// Return value of a `Control::get_theme_*()` method, as returned by the typed getters.
+#[cfg(feature = "codegen-full")]
+trait ThemeItemValue {
+    type Item;
+
+    fn into_item(self) -> Option<Self::Item>;
+}
+
+#[cfg(feature = "codegen-full")]
+impl ThemeItemValue for Color {
+    type Item = Color;
+
+    fn into_item(self) -> Option<Color> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+impl ThemeItemValue for i32 {
+    type Item = i32;
+
+    fn into_item(self) -> Option<i32> {
+        Some(self)
+    }
+}
+
+// Objects are already nullable; engine getters return `None` for missing items.
+#[cfg(feature = "codegen-full")]
+impl<T: crate::obj::GodotClass> ThemeItemValue for Option<Gd<T>> {
+    type Item = Gd<T>;
+
+    fn into_item(self) -> Option<Gd<T>> {
+        self
+    }
+}
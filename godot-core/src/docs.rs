@@ -7,7 +7,9 @@
 
 use crate::meta::ClassName;
 use crate::registry::plugin::{InherentImpl, PluginItem};
+use godot_ffi::Global;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Created for documentation on
 /// ```ignore
@@ -70,9 +72,27 @@ struct DocPieces {
 /// -- inherent implementations (`methods`) and `I*` trait implementations (`virtual_method_docs`) --
 /// it is undesirable to merge them at compile time. Instead, they are being kept as a
 /// strings of not-yet-parented XML tags (or empty string if no method has been documented).
+///
+/// Amendments supplied through [`amend_class_docs()`] are merged into the result.
 #[doc(hidden)]
 pub fn gather_xml_docs() -> impl Iterator<Item = String> {
+    let (mut map, bases) = gather_doc_pieces();
+    let amendments = AMENDMENTS.lock();
+
+    // Classes which have no compile-time docs, but runtime ones.
+    for class in amendments.keys() {
+        map.entry(*class).or_default();
+    }
+
+    map.into_iter()
+        .map(|(class, pieces)| make_class_xml(class, pieces, &bases, amendments.get(&class)))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+fn gather_doc_pieces() -> (HashMap<ClassName, DocPieces>, HashMap<ClassName, ClassName>) {
     let mut map = HashMap::<ClassName, DocPieces>::new();
+    let mut bases = HashMap::<ClassName, ClassName>::new();
     crate::private::iterate_plugins(|x| {
         let class_name = x.class_name;
 
@@ -87,37 +107,75 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
             } => map.entry(class_name).or_default().virtual_methods = virtual_method_docs,
 
             PluginItem::Struct {
-                docs: Some(docs), ..
-            } => map.entry(class_name).or_default().definition = docs,
+                base_class_name,
+                docs,
+                ..
+            } => {
+                bases.insert(class_name, base_class_name);
+                if let Some(docs) = docs {
+                    map.entry(class_name).or_default().definition = docs;
+                }
+            }
 
             _ => (),
         }
     });
 
-    map.into_iter().map(|(class, pieces)| {
-            let StructDocs {
-                base,
-                description,
-                members,
-            } = pieces.definition;
-
-            let InherentImplDocs {
-                methods,
-                signals_block,
-                constants_block,
-            } = pieces.inherent;
-
-            let virtual_methods = pieces.virtual_methods;
-            let methods_block = (virtual_methods.is_empty() && methods.is_empty())
-                .then(String::new)
-                .unwrap_or_else(|| format!("<methods>{methods}{virtual_methods}</methods>"));
-
-            let brief = description
-                .split_once("[br]")
-                .map(|(x, _)| x)
-                .unwrap_or_default();
-
-            format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+    (map, bases)
+}
+
+fn make_class_xml(
+    class: ClassName,
+    pieces: DocPieces,
+    bases: &HashMap<ClassName, ClassName>,
+    amendment: Option<&ClassDocsAmendment>,
+) -> String {
+    let StructDocs {
+        base,
+        description,
+        members,
+    } = pieces.definition;
+
+    let InherentImplDocs {
+        methods,
+        signals_block,
+        constants_block,
+    } = pieces.inherent;
+
+    let virtual_methods = pieces.virtual_methods;
+
+    let mut description = description.to_string();
+    let mut methods = format!("{methods}{virtual_methods}");
+    let mut signals = strip_block(signals_block, "signals").to_string();
+    let mut members = members.to_string();
+
+    // Base is only known from struct docs; fall back to registration data if docs are added at runtime.
+    let base = match (base, amendment) {
+        ("", Some(_)) => bases.get(&class).map(|b| b.to_string()).unwrap_or_default(),
+        _ => base.to_string(),
+    };
+
+    if let Some(amendment) = amendment {
+        amendment.apply(&mut description, &mut methods, &mut signals, &mut members);
+    }
+
+    let methods_block = methods
+        .is_empty()
+        .then(String::new)
+        .unwrap_or_else(|| format!("<methods>{methods}</methods>"));
+
+    let signals_block = signals
+        .is_empty()
+        .then(String::new)
+        .unwrap_or_else(|| format!("<signals>{signals}</signals>"));
+
+    let brief = description
+        .split_once("[br]")
+        .map(|(x, _)| x)
+        .unwrap_or_default();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
 <class name="{class}" inherits="{base}" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="../class.xsd">
 <brief_description>{brief}</brief_description>
 <description>{description}</description>
@@ -125,9 +183,202 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
 {constants_block}
 {signals_block}
 <members>{members}</members>
-</class>"#)
-        },
-        )
+</class>"#
+    )
+}
+
+/// Returns the content of `<tag>...</tag>`, or `block` unchanged if it's not wrapped in `tag`.
+fn strip_block<'a>(block: &'a str, tag: &str) -> &'a str {
+    let trimmed = block.trim();
+    trimmed
+        .strip_prefix(&format!("<{tag}>"))
+        .and_then(|rest| rest.strip_suffix(&format!("</{tag}>")))
+        .unwrap_or(block)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Runtime amendments
+
+static AMENDMENTS: Global<HashMap<ClassName, ClassDocsAmendment>> = Global::default();
+
+static DOCS_SUBMITTED: AtomicBool = AtomicBool::new(false);
+
+/// Documentation supplied at runtime for a registered class, see [`amend_class_docs()`].
+///
+/// All texts use Godot's BBCode doc format (e.g. `[b]bold[/b]`, `[method other]`); XML escaping is done automatically. Entries replace
+/// the documentation of same-named items from doc comments, or add new items for members that only exist at runtime.
+#[derive(Clone, Debug, Default)]
+pub struct ClassDocsAmendment {
+    description: Option<String>,
+    appended_description: Vec<String>,
+    methods: Vec<(String, String)>,
+    signals: Vec<(String, String)>,
+    properties: Vec<(String, String)>,
+}
+
+impl ClassDocsAmendment {
+    /// Replaces the class description. The brief description is the part before the first `[br]`.
+    pub fn set_description(&mut self, bbcode: impl Into<String>) -> &mut Self {
+        self.description = Some(bbcode.into());
+        self.appended_description.clear();
+        self
+    }
+
+    /// Appends a paragraph to the class description.
+    pub fn append_description(&mut self, bbcode: impl Into<String>) -> &mut Self {
+        self.appended_description.push(bbcode.into());
+        self
+    }
+
+    /// Documents method `name`.
+    pub fn method(&mut self, name: impl Into<String>, bbcode: impl Into<String>) -> &mut Self {
+        upsert(&mut self.methods, name.into(), bbcode.into());
+        self
+    }
+
+    /// Documents signal `name`.
+    pub fn signal(&mut self, name: impl Into<String>, bbcode: impl Into<String>) -> &mut Self {
+        upsert(&mut self.signals, name.into(), bbcode.into());
+        self
+    }
+
+    /// Documents property `name`.
+    pub fn property(&mut self, name: impl Into<String>, bbcode: impl Into<String>) -> &mut Self {
+        upsert(&mut self.properties, name.into(), bbcode.into());
+        self
+    }
+
+    fn apply(
+        &self,
+        description: &mut String,
+        methods: &mut String,
+        signals: &mut String,
+        members: &mut String,
+    ) {
+        if let Some(replaced) = &self.description {
+            *description = xml_escape(replaced);
+        }
+
+        for paragraph in &self.appended_description {
+            if !description.is_empty() {
+                description.push_str("[br]");
+            }
+            description.push_str(&xml_escape(paragraph));
+        }
+
+        for (name, docs) in &self.methods {
+            set_entry_docs(methods, "method", name, docs, true);
+        }
+        for (name, docs) in &self.signals {
+            set_entry_docs(signals, "signal", name, docs, true);
+        }
+        for (name, docs) in &self.properties {
+            set_entry_docs(members, "member", name, docs, false);
+        }
+    }
+}
+
+/// Supplies or amends the documentation of class `class_name` at runtime, e.g. generated from external schema files.
+///
+/// Can be called multiple times for the same class; the changes accumulate. `amend` runs without any lock held, so it may call
+/// `amend_class_docs()` for other classes. Nested or concurrent calls for the _same_ class overwrite each other's changes. Typically called in
+/// [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] before the `Editor` level, when docs are submitted
+/// to the editor. If called later, the class's docs are submitted again right away, which must then happen on the main thread.
+///
+/// # Example
+/// ```no_run
+/// use godot::docs::amend_class_docs;
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {}
+///
+/// fn document_enemy() {
+///     amend_class_docs(Enemy::class_name(), |docs| {
+///         docs.append_description("Stats are generated from [code]enemies.json[/code].")
+///             .property("health", "Hit points, see [member max_health].");
+///     });
+/// }
+/// ```
+pub fn amend_class_docs(class_name: ClassName, amend: impl FnOnce(&mut ClassDocsAmendment)) {
+    // Run `amend` on a copy without holding the lock, so it may itself call amend_class_docs() or other engine code.
+    let mut amendment = AMENDMENTS
+        .lock()
+        .get(&class_name)
+        .cloned()
+        .unwrap_or_default();
+    amend(&mut amendment);
+    AMENDMENTS.lock().insert(class_name, amendment.clone());
+
+    if DOCS_SUBMITTED.load(Ordering::Acquire) {
+        let (mut map, bases) = gather_doc_pieces();
+        let pieces = map.remove(&class_name).unwrap_or_default();
+        let xml = make_class_xml(class_name, pieces, &bases, Some(&amendment));
+
+        // SAFETY: docs have been submitted before, so the binding is initialized. Thread requirement is documented.
+        unsafe { load_xml(&xml) };
+    }
+}
+
+fn upsert(entries: &mut Vec<(String, String)>, name: String, docs: String) {
+    match entries.iter_mut().find(|(existing, _)| *existing == name) {
+        Some(entry) => entry.1 = docs,
+        None => entries.push((name, docs)),
+    }
+}
+
+/// Replaces the docs of `<tag name="name">` in `block`, or appends a new such element.
+///
+/// `block` is XML generated by godot-macros, so it has a known shape: methods and signals nest the text in `<description>`, members don't.
+fn set_entry_docs(block: &mut String, tag: &str, name: &str, docs: &str, in_description: bool) {
+    let name = xml_escape(name);
+    let docs = xml_escape(docs);
+
+    let open = format!("<{tag} name=\"{name}\"");
+    let close = format!("</{tag}>");
+
+    if let Some(start) = block.find(&open) {
+        if let Some(end) = block[start..].find(&close).map(|i| start + i) {
+            let element = &block[start..end];
+            let content = if in_description {
+                element
+                    .find("<description>")
+                    .zip(element.find("</description>"))
+                    .map(|(a, b)| (start + a + "<description>".len(), start + b))
+            } else {
+                element.find('>').map(|a| (start + a + 1, end))
+            };
+
+            if let Some((from, to)) = content {
+                block.replace_range(from..to, &format!("\n  {docs}\n  "));
+                return;
+            }
+        }
+    }
+
+    if in_description {
+        block.push_str(&format!(
+            "\n<{tag} name=\"{name}\">\n  <description>\n  {docs}\n  </description>\n</{tag}>\n"
+        ));
+    } else {
+        block.push_str(&format!("<{tag} name=\"{name}\">{docs}</{tag}>"));
+    }
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            c => result.push(c),
+        }
+    }
+    result
 }
 
 /// # Safety
@@ -137,9 +388,22 @@ pub fn gather_xml_docs() -> impl Iterator<Item = String> {
 /// If "experimental-threads" is not enabled, then this must be called from the same thread that the bindings were initialized from.
 pub unsafe fn register() {
     for xml in gather_xml_docs() {
-        crate::sys::interface_fn!(editor_help_load_xml_from_utf8_chars_and_len)(
-            xml.as_ptr() as *const std::ffi::c_char,
-            xml.len() as i64,
-        );
+        load_xml(&xml);
     }
+
+    DOCS_SUBMITTED.store(true, Ordering::Release);
+}
+
+/// Called when the `Editor` level is unloaded. Amendments made afterward are only submitted by the next [`register()`].
+pub(crate) fn unregister() {
+    DOCS_SUBMITTED.store(false, Ordering::Release);
+}
+
+/// # Safety
+/// See [`register()`].
+unsafe fn load_xml(xml: &str) {
+    crate::sys::interface_fn!(editor_help_load_xml_from_utf8_chars_and_len)(
+        xml.as_ptr() as *const std::ffi::c_char,
+        xml.len() as i64,
+    );
 }
@@ -0,0 +1,108 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Assertions with Godot semantics.
+
+/// Asserts that a condition holds, in Debug builds only.
+///
+/// Like GDScript's `assert()`, the check is compiled out in Release builds of the calling crate, so the condition must not have side effects.
+/// An optional message with format arguments can follow the condition.
+///
+/// _Godot equivalent: [`@GDScript.assert()`](https://docs.godotengine.org/en/stable/classes/class_@gdscript.html#class-gdscript-method-assert)_.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// fn take_damage(health: &mut i32, damage: i32) {
+///     godot_assert!(damage >= 0, "negative damage {damage}");
+///     *health -= damage;
+/// }
+/// ```
+#[macro_export]
+macro_rules! godot_assert {
+    ($cond:expr $(,)?) => {
+        if cfg!(debug_assertions) && !$cond {
+            $crate::private::godot_assert_failed(stringify!($cond), None);
+        }
+    };
+    ($cond:expr, $($fmt:tt)+) => {
+        if cfg!(debug_assertions) && !$cond {
+            $crate::private::godot_assert_failed(stringify!($cond), Some(format_args!($($fmt)+)));
+        }
+    };
+}
+
+/// Asserts that two values are equal according to Godot, after converting both to `Variant`.
+///
+/// Equality follows Godot's `==` operator, e.g. `1 == 1.0` holds, as does a `StringName` compared to an equal `GString`. On failure, both
+/// values are printed in their stringified form -- as `str()` in GDScript would show them -- together with their variant types. This is
+/// often more readable than the `Debug` output used by [`assert_eq!`].
+///
+/// An optional message with format arguments can follow the two values.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// let node = Node::new_alloc();
+/// assert_gd_eq!(node.get_name(), "");
+/// assert_gd_eq!(node.get_child_count(), 0.0, "unexpected children");
+/// # node.free();
+/// ```
+#[macro_export]
+macro_rules! assert_gd_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::private::assert_gd_eq_impl(
+                $crate::meta::ToGodot::to_variant(left),
+                $crate::meta::ToGodot::to_variant(right),
+                stringify!($left),
+                stringify!($right),
+                None,
+            ),
+        }
+    };
+    ($left:expr, $right:expr, $($fmt:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::private::assert_gd_eq_impl(
+                $crate::meta::ToGodot::to_variant(left),
+                $crate::meta::ToGodot::to_variant(right),
+                stringify!($left),
+                stringify!($right),
+                Some(format_args!($($fmt)+)),
+            ),
+        }
+    };
+}
+
+/// Asserts that the object behind a [`Gd`][crate::obj::Gd] pointer has been freed.
+///
+/// Useful to verify that manually managed objects are cleaned up, e.g. after `queue_free()` has been processed. On failure, the instance ID
+/// and dynamic class of the object still alive are printed.
+///
+/// An optional message with format arguments can follow the pointer.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// let node = Node::new_alloc();
+/// let copy = node.clone();
+///
+/// node.free();
+/// assert_freed!(copy);
+/// ```
+#[macro_export]
+macro_rules! assert_freed {
+    ($gd:expr $(,)?) => {
+        $crate::private::assert_freed_impl(&$gd, stringify!($gd), None)
+    };
+    ($gd:expr, $($fmt:tt)+) => {
+        $crate::private::assert_freed_impl(&$gd, stringify!($gd), Some(format_args!($($fmt)+)))
+    };
+}
@@ -22,9 +22,13 @@
 //! - Vector: [`Vector2Axis`][crate::builtin::Vector2Axis], [`Vector3Axis`][crate::builtin::Vector3Axis], [`Vector4Axis`][crate::builtin::Vector4Axis]
 //!
 
+mod assert;
 mod print;
 
-pub use crate::{godot_error, godot_print, godot_print_rich, godot_script_error, godot_warn};
+pub use crate::{
+    assert_freed, assert_gd_eq, godot_assert, godot_error, godot_print, godot_print_rich,
+    godot_script_error, godot_warn,
+};
 
 // Some enums are directly re-exported from crate::builtin.
 pub use crate::gen::central::global_enums::*;
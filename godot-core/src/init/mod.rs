@@ -14,7 +14,7 @@ use sys::GodotFfi;
 use crate::builtin::{GString, StringName};
 use crate::out;
 
-pub use sys::GdextBuild;
+pub use sys::{CompatibilityError, CompatibilityErrorKind, GdextBuild};
 
 #[doc(hidden)]
 #[deny(unsafe_op_in_unsafe_fn)]
@@ -24,6 +24,9 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
     init: *mut sys::GDExtensionInitialization,
 ) -> sys::GDExtensionBool {
     let init_code = || {
+        #[cfg(not(wasm_nothreads))]
+        let _ = MAIN_THREAD_ID.set(std::thread::current().id());
+
         // Make sure the first thing we do is check whether hot reloading should be enabled or not. This is to ensure that if we do anything to
         // cause TLS-destructors to run then we have a setting already for how to deal with them. Otherwise, this could cause the default
         // behavior to kick in and disable hot reloading.
@@ -42,8 +45,16 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
         let config = sys::GdextConfig::new(tool_only_in_editor);
 
         // SAFETY: no custom code has run yet + no other thread is accessing global handle.
-        unsafe {
-            sys::initialize(get_proc_address, library, config);
+        let result = unsafe { sys::initialize(get_proc_address, library, config) };
+
+        if let Err(error) = result {
+            let notify = E::on_compatibility_error(&error);
+
+            // SAFETY: `get_proc_address` is passed by Godot.
+            unsafe { sys::report_load_error(get_proc_address, &error, notify) };
+
+            // Godot does not read the output parameters if the entry point reports failure.
+            return 0;
         }
 
         // Currently no way to express failure; could be exposed to E if necessary.
@@ -73,6 +84,24 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
 
 static LEVEL_SERVERS_CORE_LOADED: AtomicBool = AtomicBool::new(false);
 
+/// Thread which loaded the extension, i.e. Godot's main thread.
+#[cfg(not(wasm_nothreads))]
+static MAIN_THREAD_ID: std::sync::OnceLock<std::thread::ThreadId> = std::sync::OnceLock::new();
+
+/// Whether the current thread is Godot's main thread. Does not access the engine, so it can be called from any thread.
+pub(crate) fn is_main_thread() -> bool {
+    #[cfg(not(wasm_nothreads))]
+    {
+        MAIN_THREAD_ID.get() == Some(&std::thread::current().id())
+    }
+
+    // Wasm builds without threads cannot query thread IDs; there is only the main thread anyway.
+    #[cfg(wasm_nothreads)]
+    {
+        true
+    }
+}
+
 unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
     _userdata: *mut std::ffi::c_void,
     init_level: sys::GDExtensionInitializationLevel,
@@ -98,7 +127,7 @@ unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
 
         // SAFETY: Godot will call this from the main thread, after `__gdext_load_library` where the library is initialized,
         // and only once per level.
-        unsafe { gdext_on_level_init(level) };
+        unsafe { gdext_on_level_init::<E>(level) };
         E::on_level_init(level);
     }
 
@@ -135,7 +164,7 @@ unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
 /// - The interface must have been initialized.
 /// - Must only be called once per level.
 #[deny(unsafe_op_in_unsafe_fn)]
-unsafe fn gdext_on_level_init(level: InitLevel) {
+unsafe fn gdext_on_level_init<E: ExtensionLibrary>(level: InitLevel) {
     // TODO: in theory, a user could start a thread in one of the early levels, and run concurrent code that messes with the global state
     // (e.g. class registration). This would break the assumption that the load_class_method_table() calls are exclusive.
     // We could maybe protect globals with a mutex until initialization is complete, and then move it to a directly-accessible, read-only static.
@@ -146,7 +175,32 @@ unsafe fn gdext_on_level_init(level: InitLevel) {
     match level {
         InitLevel::Scene => {
             // SAFETY: On the main thread, api initialized, `Scene` was initialized above.
-            unsafe { ensure_godot_features_compatible() };
+            if let Err(error) = unsafe { ensure_godot_features_compatible() } {
+                if E::on_compatibility_error(&error) {
+                    show_compatibility_alert(&error);
+                }
+
+                panic!("{error}");
+            }
+
+            #[cfg(feature = "mem-stats")]
+            sys::mem_stats::set_engine_usage_source(Some(engine_static_memory_usage));
+
+            #[cfg(feature = "log")]
+            if let Some(logger) = E::logger() {
+                if let Err(error) = logger.install() {
+                    crate::godot_warn!("godot-rust: failed to install logger from ExtensionLibrary::logger(): {error}");
+                }
+            }
+
+            #[cfg(feature = "tracing")]
+            if let Some(layer) = E::tracing_layer() {
+                if let Err(error) = layer.install() {
+                    crate::godot_warn!(
+                        "godot-rust: failed to install tracing layer from ExtensionLibrary::tracing_layer(): {error}"
+                    );
+                }
+            }
         }
         InitLevel::Editor => {
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
@@ -159,10 +213,72 @@ unsafe fn gdext_on_level_init(level: InitLevel) {
     }
 
     crate::registry::class::auto_register_classes(level);
+
+    #[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+    if level == InitLevel::Editor {
+        crate::registry::editor_icons::apply_deferred();
+    }
+}
+
+#[cfg(feature = "mem-stats")]
+fn engine_static_memory_usage() -> u64 {
+    crate::classes::Os::singleton().get_static_memory_usage()
+}
+
+/// Returns whether the engine can currently be called.
+///
+/// This is `false` before the extension is loaded and after it has been fully unloaded. Most notably, values stored in thread-locals
+/// are dropped _after_ unloading, when the process exits. Godot-rust's own types (`Gd`, `Variant`, `Callable`, strings, containers, ...)
+/// detect this and skip their destructors, since the engine has already released all memory. Your own `Drop` impls that call engine
+/// APIs should do the same:
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// struct Connection {
+///     node: Gd<Node>,
+/// }
+///
+/// impl Drop for Connection {
+///     fn drop(&mut self) {
+///         // May run at process exit, e.g. if Connection lives in a thread_local!.
+///         if !godot::init::engine_available() {
+///             return;
+///         }
+///
+///         self.node.emit_signal("disconnected", &[]);
+///     }
+/// }
+/// ```
+pub fn engine_available() -> bool {
+    sys::is_initialized()
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    #[cfg(since_api = "4.2")]
+    if level == InitLevel::Scene {
+        // Pending tasks may hold objects; release them while the engine is still alive.
+        crate::tools::cleanup_local_tasks();
+    }
+
+    #[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+    if level == InitLevel::Editor {
+        // Play listeners may capture user objects.
+        crate::tools::unregister_play_listeners();
+    }
+
+    #[cfg(all(since_api = "4.3", feature = "register-docs"))]
+    if level == InitLevel::Editor {
+        // After a hot reload, docs are registered anew; amendments in between must not reach the editor help.
+        crate::docs::unregister();
+    }
+
+    #[cfg(feature = "mem-stats")]
+    if level == InitLevel::Scene {
+        sys::mem_stats::set_engine_usage_source(None);
+    }
+
     crate::registry::class::unregister_classes(level);
 
     if level == InitLevel::Core {
@@ -175,6 +291,9 @@ fn gdext_on_level_deinit(level: InitLevel) {
             crate::meta::cleanup();
         }
 
+        #[cfg(debug_assertions)]
+        crate::obj::clear_free_tracking();
+
         // SAFETY: called after all other logic, so no concurrent access.
         // TODO: multithreading must make sure other threads are joined/stopped here.
         unsafe {
@@ -275,6 +394,60 @@ pub unsafe trait ExtensionLibrary {
     fn override_hot_reload() -> Option<bool> {
         None
     }
+
+    /// Called when the extension cannot run, because it is incompatible with the Godot binary that loaded it.
+    ///
+    /// This happens if Godot is older than the API version godot-rust was compiled against, or if its floating-point precision
+    /// differs from the `double-precision` Cargo feature. The extension is not loaded in either case. Apart from the
+    /// [`Display`][std::fmt::Display] impl, the error offers structured access to the versions involved and advice for the user.
+    ///
+    /// Return `true` to let godot-rust notify the user: an editor notification for version mismatches (the binding is not yet
+    /// available at that point), or an OS alert dialog for precision mismatches. Return `false` if you handle the notification yourself.
+    /// The error is logged regardless.
+    ///
+    /// Since this may run before the Godot binding is initialized, do not call any Godot APIs in this function.
+    ///
+    /// ```no_run
+    /// # use godot::init::*;
+    /// struct MyExtension;
+    ///
+    /// #[gdextension]
+    /// unsafe impl ExtensionLibrary for MyExtension {
+    ///     fn on_compatibility_error(error: &CompatibilityError) -> bool {
+    ///         eprintln!("Requires Godot {}, found {}.", error.compiled_version(), error.runtime_version());
+    ///         true
+    ///     }
+    /// }
+    /// ```
+    #[allow(unused_variables)]
+    fn on_compatibility_error(error: &CompatibilityError) -> bool {
+        true
+    }
+
+    /// Logger for the [`log`](https://docs.rs/log) crate, which forwards records to the Godot console.
+    ///
+    /// If this returns `Some`, the logger is installed as the global `log` logger once the `Scene` level is initialized, before
+    /// [`on_level_init()`][Self::on_level_init] is invoked for that level. If another logger is already installed, a warning is printed.
+    ///
+    /// Returns `None` by default, leaving the `log` crate untouched.
+    #[cfg(feature = "log")]
+    #[cfg_attr(published_docs, doc(cfg(feature = "log")))]
+    fn logger() -> Option<crate::tools::GodotLogger> {
+        None
+    }
+
+    /// Layer for [`tracing-subscriber`](https://docs.rs/tracing-subscriber), which forwards events to the Godot console.
+    ///
+    /// If this returns `Some`, a subscriber with this layer is installed as the global default once the `Scene` level is initialized,
+    /// before [`on_level_init()`][Self::on_level_init] is invoked for that level. If another global subscriber is already installed,
+    /// a warning is printed.
+    ///
+    /// Returns `None` by default, leaving `tracing` untouched.
+    #[cfg(feature = "tracing")]
+    #[cfg_attr(published_docs, doc(cfg(feature = "tracing")))]
+    fn tracing_layer() -> Option<crate::tools::GodotTracingLayer> {
+        None
+    }
 }
 
 /// Determines if and how an extension's code is run in the editor.
@@ -323,7 +496,7 @@ pub type InitLevel = sys::InitLevel;
 /// - The interface must be initialized.
 /// - The `Scene` api level must have been initialized.
 #[deny(unsafe_op_in_unsafe_fn)]
-unsafe fn ensure_godot_features_compatible() {
+unsafe fn ensure_godot_features_compatible() -> Result<(), CompatibilityError> {
     // The reason why we don't simply call Os::has_feature() here is that we might move the high-level engine classes out of godot-core
     // later, and godot-core would only depend on godot-sys. This makes future migrations easier. We still have access to builtins though.
 
@@ -363,10 +536,19 @@ unsafe fn ensure_godot_features_compatible() {
     );
 
     if godot_is_double != gdext_is_double {
-        panic!(
-            "Godot runs with {} precision, but gdext was compiled with {} precision.\n\
-            Cargo feature `double-precision` must be used if and only if Godot is compiled with `precision=double`.\n",
-            s(godot_is_double), s(gdext_is_double),
-        );
+        // SAFETY: binding is initialized, main thread.
+        let error =
+            unsafe { CompatibilityError::precision_mismatch(godot_is_double, gdext_is_double) };
+        return Err(error);
     }
+
+    Ok(())
+}
+
+/// Shows a native alert dialog with the error, blocking until the user dismisses it.
+fn show_compatibility_alert(error: &CompatibilityError) {
+    crate::classes::Os::singleton()
+        .alert_ex(&error.to_string())
+        .title("Incompatible Rust extension")
+        .done();
 }
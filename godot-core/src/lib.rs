@@ -27,6 +27,8 @@ pub mod init;
 pub mod meta;
 pub mod obj;
 pub mod registry;
+#[cfg(feature = "serde")]
+pub mod serde_variant;
 pub mod tools;
 
 mod storage;
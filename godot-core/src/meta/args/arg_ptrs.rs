@@ -0,0 +1,175 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::mem::MaybeUninit;
+
+/// Number of argument pointers stored without heap allocation.
+///
+/// Covers all engine methods with fixed parameters and typical `emit_signal()` / `call()` invocations.
+const INLINE_CAPACITY: usize = 8;
+
+/// Contiguous buffer of argument pointers, passed to Godot as `(ptr, len)` pair.
+///
+/// Outbound calls combine explicit parameters and varargs into one pointer array. This happens on every call (e.g. each `emit_signal()`),
+/// so small argument lists are kept on the stack; only calls with more than [`INLINE_CAPACITY`] arguments allocate.
+///
+/// Allocations are verified by the unit tests below, which install a counting global allocator.
+pub struct ArgPtrs<T: Copy> {
+    inline: [MaybeUninit<T>; INLINE_CAPACITY],
+    heap: Vec<T>,
+    len: usize,
+}
+
+impl<T: Copy> ArgPtrs<T> {
+    /// Collects the pointers of `explicit` followed by `varargs`.
+    pub fn from_parts<A, B>(explicit: A, varargs: B) -> Self
+    where
+        A: ExactSizeIterator<Item = T>,
+        B: ExactSizeIterator<Item = T>,
+    {
+        // Only used to pick the storage; `len` is the number of elements actually written, in case an iterator reports a wrong length.
+        let expected_len = explicit.len() + varargs.len();
+
+        let mut inline = [MaybeUninit::uninit(); INLINE_CAPACITY];
+        let (heap, len) = if expected_len <= INLINE_CAPACITY {
+            let mut written = 0;
+            for (slot, ptr) in inline.iter_mut().zip(explicit.chain(varargs)) {
+                slot.write(ptr);
+                written += 1;
+            }
+            (Vec::new(), written)
+        } else {
+            let heap: Vec<T> = explicit.chain(varargs).collect();
+            let len = heap.len();
+            (heap, len)
+        };
+
+        debug_assert_eq!(len, expected_len, "ExactSizeIterator::len() is wrong");
+
+        Self { inline, heap, len }
+    }
+
+    /// Pointer to the first element; valid for [`len()`][Self::len] elements while `self` is alive and not moved.
+    pub fn as_ptr(&self) -> *const T {
+        if self.is_inline() {
+            // MaybeUninit<T> has the same layout as T; the first `len` elements are initialized.
+            self.inline.as_ptr() as *const T
+        } else {
+            self.heap.as_ptr()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn is_inline(&self) -> bool {
+        self.heap.capacity() == 0
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    // Replaces the allocator of the whole unit test binary. Counts per thread, so that tests running in parallel don't interfere.
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            // try_with(): the thread-local may already be destroyed during thread shutdown.
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn count_allocations<R>(f: impl FnOnce() -> R) -> usize {
+        let before = ALLOCATIONS.with(Cell::get);
+        let result = f();
+        let after = ALLOCATIONS.with(Cell::get);
+
+        drop(result);
+        after - before
+    }
+
+    fn to_vec(ptrs: &ArgPtrs<u32>) -> Vec<u32> {
+        // SAFETY: as_ptr() is valid for len() elements.
+        unsafe { std::slice::from_raw_parts(ptrs.as_ptr(), ptrs.len()) }.to_vec()
+    }
+
+    #[test]
+    fn arg_ptrs_inline() {
+        let ptrs = ArgPtrs::from_parts([1, 2].into_iter(), [3, 4, 5].into_iter());
+
+        assert!(ptrs.is_inline());
+        assert_eq!(ptrs.heap.capacity(), 0);
+        assert_eq!(to_vec(&ptrs), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn arg_ptrs_empty() {
+        let ptrs = ArgPtrs::<u32>::from_parts([].into_iter(), [].into_iter());
+
+        assert_eq!(ptrs.len(), 0);
+        assert!(to_vec(&ptrs).is_empty());
+    }
+
+    #[test]
+    fn arg_ptrs_heap() {
+        let ptrs = ArgPtrs::from_parts([0].into_iter(), 1..INLINE_CAPACITY as u32 + 1);
+
+        assert!(!ptrs.is_inline());
+        assert_eq!(
+            to_vec(&ptrs),
+            (0..=INLINE_CAPACITY as u32).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn arg_ptrs_allocations() {
+        let explicit = [1u32, 2];
+        let varargs = [3u32, 4, 5, 6];
+
+        // Previous approach: one allocation per call.
+        let vec_allocs = count_allocations(|| {
+            let mut ptrs = Vec::with_capacity(explicit.len() + varargs.len());
+            ptrs.extend(explicit.iter().copied());
+            ptrs.extend(varargs.iter().copied());
+            ptrs
+        });
+        assert_eq!(vec_allocs, 1);
+
+        let inline_allocs = count_allocations(|| {
+            ArgPtrs::from_parts(explicit.iter().copied(), varargs.iter().copied())
+        });
+        assert_eq!(inline_allocs, 0);
+
+        // Beyond the inline capacity, falls back to a single allocation.
+        let heap_allocs =
+            count_allocations(|| ArgPtrs::from_parts(explicit.iter().copied(), 0..16u32));
+        assert_eq!(heap_allocs, 1);
+    }
+}
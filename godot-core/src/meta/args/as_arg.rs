@@ -6,6 +6,7 @@
  */
 
 use crate::builtin::{GString, NodePath, StringName};
+use crate::meta::args::string_name_cache::{cached_string_name, StaticStringName};
 use crate::meta::{sealed, CowArg};
 use std::ffi::CStr;
 
@@ -18,6 +19,9 @@ use std::ffi::CStr;
 /// - `T` for by-value builtins (typically `Copy`): `i32`, `bool`, `Vector3`, `Transform2D`, ...
 /// - `&T` for by-ref builtins: `GString`, `Array`, `Dictionary`, `Packed*Array`, `Variant`...
 /// - `&str`, `&String` additionally for string types `GString`, `StringName`, `NodePath`.
+/// - Enums deriving [`GodotConvert`][crate::meta::GodotConvert], for their `Via` type. This allows passing your own enums to string
+///   parameters and to integer parameters of engine APIs that hold custom IDs or layer numbers. Enums with `via = GString` can additionally be passed to `StringName` parameters,
+///   e.g. for animation or input action names.
 ///
 /// See also the [`AsObjectArg`][crate::meta::AsObjectArg] trait which is specialized for object arguments. It may be merged with `AsArg`
 /// in the future.
@@ -38,6 +42,11 @@ use std::ffi::CStr;
 /// cannot deal with raw Rust types. On the other hand, `GString` and `StringName` are sometimes used almost interchangeably (example:
 /// [`Node::set_name`](crate::classes::Node::set_name) takes `GString` but [`Node::get_name`](crate::classes::Node::get_name) returns `StringName`).
 ///
+/// `&str` arguments to `StringName` parameters -- such as method, signal, input action or audio bus names -- are cached: repeated
+/// conversions of the same string reuse the existing `StringName`, without allocating or hashing on Godot's side. The cache is bounded;
+/// in Debug builds, a warning is printed once it overflows, which usually means that names built at runtime (e.g. with `format!`) are passed
+/// in a hot path. Store those as `StringName` and pass them by reference instead. `&String` arguments are not cached.
+///
 /// If you want to convert between Godot's string types for the sake of argument passing, each type provides an `arg()` method, such as
 /// [`GString::arg()`]. You cannot use this method in other contexts.
 ///
@@ -45,8 +54,8 @@ use std::ffi::CStr;
 /// `AsArg` is meant to be used from the function call site, not the declaration site. If you declare a parameter as `impl AsArg<...>` yourself,
 /// you can only forward it as-is to a Godot API -- there are no stable APIs to access the inner object yet.
 ///
-/// Furthermore, there is currently no benefit in implementing `AsArg` for your own types, as it's only used by Godot APIs which don't accept
-/// custom types. Classes are already supported through upcasting and [`AsObjectArg`][crate::meta::AsObjectArg].
+/// For your own types, there is usually no need to implement `AsArg` manually: enums deriving `GodotConvert` already implement it, and classes
+/// are supported through upcasting and [`AsObjectArg`][crate::meta::AsObjectArg].
 #[diagnostic::on_unimplemented(
     message = "Argument of type `{Self}` cannot be passed to an `impl AsArg<{T}>` parameter",
     note = "If you pass by value, consider borrowing instead.",
@@ -218,7 +227,8 @@ impl AsArg<GString> for &String {
 
 impl AsArg<StringName> for &str {
     fn into_arg<'r>(self) -> CowArg<'r, StringName> {
-        CowArg::Owned(StringName::from(self))
+        // Typically literals, passed repeatedly.
+        CowArg::Owned(cached_string_name(self))
     }
 }
 
@@ -228,6 +238,12 @@ impl AsArg<StringName> for &String {
     }
 }
 
+impl AsArg<StringName> for &StaticStringName {
+    fn into_arg<'r>(self) -> CowArg<'r, StringName> {
+        CowArg::Owned(self.get())
+    }
+}
+
 #[cfg(since_api = "4.2")]
 impl AsArg<StringName> for &'static CStr {
     fn into_arg<'r>(self) -> CowArg<'r, StringName> {
@@ -5,10 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod arg_ptrs;
 mod as_arg;
 mod cow_arg;
 mod object_arg;
 mod ref_arg;
+mod string_name_cache;
+mod var_args;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Public APIs
@@ -16,6 +19,8 @@ mod ref_arg;
 pub use as_arg::{AsArg, ParamType};
 pub use object_arg::AsObjectArg;
 pub use ref_arg::RefArg;
+pub use string_name_cache::StaticStringName;
+pub use var_args::VarArgs;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Internal APIs
@@ -27,8 +32,11 @@ pub use cow_arg::CowArg;
 #[cfg(not(feature = "trace"))]
 pub(crate) use cow_arg::CowArg;
 
+pub(crate) use arg_ptrs::ArgPtrs;
 #[allow(unused_imports)] // ObjectCow is used in generated code.
 pub(crate) use object_arg::{ObjectArg, ObjectCow, ObjectNullArg};
+pub(crate) use string_name_cache::cleanup as cleanup_string_name_cache;
+pub(crate) use string_name_cache::static_string_name;
 
 // #[doc(hidden)]
 // pub use cow_arg::*;
@@ -0,0 +1,186 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Cache for `&str` -> `StringName` argument conversions.
+//!
+//! Most `&str` arguments to `StringName` parameters are literals (method, signal, action or bus names), which are passed over and over in
+//! hot paths such as `_process()`. Constructing a `StringName` from scratch means UTF-8 decoding, allocation, hashing and a lookup in
+//! Godot's global string table (behind a lock). Looking up an already converted `StringName` is much cheaper.
+//!
+//! Stable Rust cannot distinguish `&'static str` from other `&str` in trait impls, so the cache is keyed by content and bounded in size.
+//! Once full, further strings are converted without caching. In Debug builds, this is reported once, since it typically indicates
+//! dynamically built names in a hot path.
+//!
+//! Lookups must not be slower than the conversion they save, so the caches are thread-local instead of behind a global lock. Each thread
+//! converts a name once. [`cleanup()`] runs on the main thread, and can only release the main thread's names; caches of other threads
+//! are recognized as stale by their generation and leaked, since Godot may no longer be around when those threads exit.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use godot_ffi as sys;
+
+use crate::builtin::StringName;
+
+/// Maximum number of cached names per thread. Generous for literals, while bounding memory if dynamic strings are passed.
+const MAX_CACHED: usize = 1024;
+
+/// Incremented on every [`cleanup()`]. Names cached in an older generation belong to a previous Godot session.
+static GENERATION: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+    static CACHE: RefCell<NameCache> = RefCell::new(NameCache::new());
+}
+
+struct NameCache {
+    generation: u32,
+    by_content: HashMap<Box<str>, StringName>,
+
+    /// Names from `&'static str`, keyed by address and length. There are only finitely many such strings, so this map is unbounded.
+    by_address: HashMap<(usize, usize), StringName>,
+}
+
+impl NameCache {
+    fn new() -> Self {
+        Self {
+            generation: GENERATION.load(Ordering::Acquire),
+            by_content: HashMap::new(),
+            by_address: HashMap::new(),
+        }
+    }
+
+    /// Returns `self`, after discarding names from a previous generation.
+    fn current(&mut self) -> &mut Self {
+        let generation = GENERATION.load(Ordering::Acquire);
+        if self.generation != generation {
+            self.leak();
+            self.generation = generation;
+        }
+        self
+    }
+
+    /// Forgets all names without releasing them in Godot.
+    fn leak(&mut self) {
+        std::mem::forget(std::mem::take(&mut self.by_content));
+        std::mem::forget(std::mem::take(&mut self.by_address));
+    }
+}
+
+impl Drop for NameCache {
+    fn drop(&mut self) {
+        // Runs on thread exit, possibly after Godot has been unloaded.
+        if self.generation != GENERATION.load(Ordering::Acquire) || !sys::is_initialized() {
+            self.leak();
+        }
+    }
+}
+
+/// Returns a `StringName` equal to `string`, reusing a previous conversion on this thread if possible.
+pub(crate) fn cached_string_name(string: &str) -> StringName {
+    let cached = CACHE.try_with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let cache = cache.current();
+        if let Some(name) = cache.by_content.get(string) {
+            return name.clone();
+        }
+
+        let name = StringName::from(string);
+        if cache.by_content.len() < MAX_CACHED {
+            cache.by_content.insert(Box::from(string), name.clone());
+        } else {
+            #[cfg(debug_assertions)]
+            warn_cache_full(string);
+        }
+
+        name
+    });
+
+    // Thread-local storage is unavailable while the thread is being torn down.
+    cached.unwrap_or_else(|_| StringName::from(string))
+}
+
+/// Returns a `StringName` equal to `string`, converting each static string only once per thread.
+///
+/// Cheaper than [`cached_string_name()`], since the lookup neither hashes nor compares the string's content.
+pub(crate) fn static_string_name(string: &'static str) -> StringName {
+    let key = (string.as_ptr() as usize, string.len());
+
+    let cached = CACHE.try_with(|cache| {
+        cache
+            .borrow_mut()
+            .current()
+            .by_address
+            .entry(key)
+            .or_insert_with(|| StringName::from(string))
+            .clone()
+    });
+
+    cached.unwrap_or_else(|_| StringName::from(string))
+}
+
+/// Method, signal or property name stored in a `static` or `const`, converted to a `StringName` only once.
+///
+/// Counterpart to [`StaticClassName`][crate::meta::StaticClassName] for other names. Can be passed to `impl AsArg<StringName>` parameters
+/// by reference:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::meta::StaticStringName;
+///
+/// const HIT: StaticStringName = StaticStringName::new("hit");
+///
+/// fn on_damage(mut node: Gd<Node>) {
+///     node.emit_signal(&HIT, &[]);
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StaticStringName {
+    name: &'static str,
+}
+
+impl StaticStringName {
+    pub const fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    /// The name as a `StringName`, converted on first use.
+    pub fn get(&self) -> StringName {
+        static_string_name(self.name)
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[cfg(debug_assertions)]
+fn warn_cache_full(string: &str) {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static WARNED: AtomicBool = AtomicBool::new(false);
+    if !WARNED.swap(true, Ordering::Relaxed) {
+        crate::godot_warn!(
+            "godot-rust: more than {MAX_CACHED} distinct &str were converted to StringName arguments on one thread (latest: \"{string}\").\n\
+            Strings built at runtime are converted on every call; consider storing a StringName and passing it by reference."
+        );
+    }
+}
+
+/// Releases the main thread's names, and marks all other threads' caches as stale.
+///
+/// # Safety
+/// Must be called on the main thread, while Godot is still initialized. Must not convert any more `&str` arguments after this call.
+pub(crate) unsafe fn cleanup() {
+    let _ = CACHE.try_with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.by_content.clear();
+        cache.by_address.clear();
+    });
+
+    GENERATION.fetch_add(1, Ordering::AcqRel);
+}
@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::Variant;
+use crate::meta::error::CallError;
+use crate::meta::{CallContext, FromGodot};
+use crate::sys;
+
+/// Trailing arguments of a `#[func(vararg)]` function.
+///
+/// Iterates over all arguments passed beyond the declared parameters, as `Variant`s. To convert them on the fly, use
+/// [`next_as()`][Self::next_as], which reports failed conversions together with the argument's position.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::meta::VarArgs;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Logger {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Logger {
+///     // GDScript: logger.log("warn", "low health:", hp)
+///     #[func(vararg)]
+///     fn log(&self, level: GString, args: VarArgs) {
+///         let parts: Vec<String> = args.map(|arg| arg.stringify().to_string()).collect();
+///         godot_print!("[{level}] {}", parts.join(" "));
+///     }
+///
+///     // GDScript: logger.sum(1, 2, 3)
+///     #[func(vararg)]
+///     fn sum(&self, mut args: VarArgs) -> i64 {
+///         let mut sum = 0;
+///         while !args.is_empty() {
+///             sum += args.next_as::<i64>().expect("sum() accepts only integers");
+///         }
+///         sum
+///     }
+/// }
+/// ```
+pub struct VarArgs {
+    args: std::vec::IntoIter<Variant>,
+
+    /// Parameter index of the next argument, counting the declared parameters before the varargs.
+    next_index: usize,
+
+    call_ctx: CallContext<'static>,
+}
+
+impl VarArgs {
+    /// Copies the arguments `param_count..arg_count` of a varcall.
+    ///
+    /// # Safety
+    /// `args_ptr` must point to `arg_count` valid variant pointers.
+    pub(crate) unsafe fn from_varcall(
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: usize,
+        param_count: usize,
+        call_ctx: &CallContext<'static>,
+    ) -> Self {
+        let args: Vec<Variant> = (param_count..arg_count)
+            .map(|i| Variant::borrow_var_sys(*args_ptr.add(i)).clone())
+            .collect();
+
+        Self {
+            args: args.into_iter(),
+            next_index: param_count,
+            call_ctx: call_ctx.clone(),
+        }
+    }
+
+    /// Number of arguments not yet consumed.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Whether all arguments have been consumed.
+    pub fn is_empty(&self) -> bool {
+        self.args.len() == 0
+    }
+
+    /// Remaining arguments, without consuming them.
+    pub fn as_slice(&self) -> &[Variant] {
+        self.args.as_slice()
+    }
+
+    /// Consumes the next argument and converts it to `T`.
+    ///
+    /// # Errors
+    /// If there are no arguments left, or if the argument cannot be converted to `T`. The error message includes the function name and the
+    /// parameter index (counting from 0, including the declared parameters before the varargs).
+    pub fn next_as<T: FromGodot>(&mut self) -> Result<T, CallError> {
+        let index = self.next_index;
+        let Some(arg) = self.next() else {
+            return Err(CallError::failed_vararg_missing::<T>(&self.call_ctx, index));
+        };
+
+        arg.try_to::<T>().map_err(|err| {
+            CallError::failed_param_conversion::<T>(&self.call_ctx, index as isize, err)
+        })
+    }
+
+    /// Consumes all remaining arguments, converting each to `T`.
+    ///
+    /// # Errors
+    /// On the first argument that cannot be converted, see [`next_as()`][Self::next_as].
+    pub fn rest_as<T: FromGodot>(mut self) -> Result<Vec<T>, CallError> {
+        let mut values = Vec::with_capacity(self.len());
+        while !self.is_empty() {
+            values.push(self.next_as()?);
+        }
+
+        Ok(values)
+    }
+}
+
+impl Iterator for VarArgs {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Variant> {
+        let arg = self.args.next()?;
+        self.next_index += 1;
+        Some(arg)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.args.size_hint()
+    }
+}
+
+impl ExactSizeIterator for VarArgs {}
+
+impl fmt::Debug for VarArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.args.as_slice()).finish()
+    }
+}
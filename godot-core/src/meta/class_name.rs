@@ -7,10 +7,12 @@
 use std::any::TypeId;
 use std::borrow::Cow;
 use std::cell::OnceCell;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::ffi::CStr;
 use std::fmt;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
 
 use godot_ffi as sys;
 use sys::Global;
@@ -27,6 +29,10 @@ use crate::obj::GodotClass;
 static CLASS_NAMES: Global<Vec<ClassNameEntry>> = Global::new(|| vec![ClassNameEntry::none()]);
 static DYNAMIC_INDEX_BY_CLASS_TYPE: Global<HashMap<TypeId, u16>> = Global::default();
 
+// Class names with static storage, from engine classes, `#[derive(GodotClass)]` and `ClassName::new_static()`. The same name always maps to
+// the same index, so that these sources agree on equality.
+static STATIC_INDEX_BY_NAME: Global<HashMap<&'static str, u16>> = Global::default();
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// # Safety
@@ -34,6 +40,7 @@ static DYNAMIC_INDEX_BY_CLASS_TYPE: Global<HashMap<TypeId, u16>> = Global::defau
 pub unsafe fn cleanup() {
     CLASS_NAMES.lock().clear();
     DYNAMIC_INDEX_BY_CLASS_TYPE.lock().clear();
+    STATIC_INDEX_BY_NAME.lock().clear();
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -61,10 +68,11 @@ impl ClassNameEntry {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// `Cow`-like enum for class names, but with C strings as the borrowed variant.
+/// `Cow`-like enum for class names, with both C strings and Rust strings as borrowed variants.
 enum ClassNameSource {
     Owned(String),
     Borrowed(&'static CStr),
+    BorrowedStr(&'static str),
 }
 
 impl ClassNameSource {
@@ -76,6 +84,8 @@ impl ClassNameSource {
             ClassNameSource::Borrowed(cstr) => StringName::from(*cstr),
             #[cfg(before_api = "4.2")] // no C-string support for StringName.
             ClassNameSource::Borrowed(cstr) => StringName::from(ascii_cstr_to_str(cstr)),
+
+            ClassNameSource::BorrowedStr(s) => StringName::from(*s),
         }
     }
 
@@ -83,6 +93,7 @@ impl ClassNameSource {
         match self {
             ClassNameSource::Owned(s) => Cow::Owned(s.clone()),
             ClassNameSource::Borrowed(cstr) => Cow::Borrowed(ascii_cstr_to_str(cstr)),
+            ClassNameSource::BorrowedStr(s) => Cow::Borrowed(s),
         }
     }
 }
@@ -95,18 +106,55 @@ impl ClassNameSource {
 ///
 /// This struct is very cheap to copy. The actual names are cached globally.
 ///
-/// If you need to create your own class name, use [`new_cached()`][Self::new_cached].
+/// If you need to create your own class name, use [`new_static()`][Self::new_static] for names known at compile time, or
+/// [`new_cached()`][Self::new_cached] otherwise.
 ///
 /// # Ordering
 ///
 /// `ClassName`s are **not** ordered lexicographically, and the ordering relation is **not** stable across multiple runs of your
 /// application. When lexicographical order is needed, it's possible to convert this type to [`GString`] or [`String`].
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct ClassName {
-    global_index: u16,
+    repr: ClassNameRepr,
+}
+
+// Size: 16 bytes on 64-bit platforms, as `Index` is stored in the null-pointer niche of `Static`. An index-only representation would
+// take 2 bytes, but could not be constructed in const context. Class names are passed by value mostly once per call (registration,
+// casts, ptrcall checks), not stored in bulk, so the difference doesn't matter in practice.
+#[derive(Copy, Clone, Debug)]
+enum ClassNameRepr {
+    /// Index into the global cache.
+    Index(u16),
+
+    /// Not yet looked up in the global cache; resolved on each use. Use [`StaticClassName`] to resolve only once.
+    Static(&'static str),
 }
 
 impl ClassName {
+    /// Construct a class name from a string with static storage, e.g. a literal.
+    ///
+    /// This is a `const fn` and has no runtime cost: the name is only stored, and looked up in the global cache (which creates its
+    /// `StringName`) when first used. This allows class names in `const` and `static` items:
+    /// ```no_run
+    /// use godot::meta::ClassName;
+    ///
+    /// const PLAYER: ClassName = ClassName::new_static("Player");
+    /// ```
+    ///
+    /// Compares equal to the class name of the engine or `#[derive(GodotClass)]` class with the same Godot name; for example,
+    /// `ClassName::new_static("Node") == Node::class_name()`. Names created via [`new_cached()`][Self::new_cached] are distinct.
+    ///
+    /// Each use of a `ClassName` created here looks up the name in the global cache. For names used in hot paths, store a
+    /// [`StaticClassName`] in a `static` instead, which resolves the name only once.
+    ///
+    /// # Panics
+    /// On first use, if the string is not ASCII and the Godot version is older than 4.4.
+    pub const fn new_static(name: &'static str) -> Self {
+        Self {
+            repr: ClassNameRepr::Static(name),
+        }
+    }
+
     /// Construct a new class name.
     ///
     /// This is expensive the first time it called for a given `T`, but will be cached for subsequent calls.
@@ -134,54 +182,21 @@ impl ClassName {
             insert_class(ClassNameSource::Owned(name))
         });
 
-        ClassName { global_index }
+        Self::from_index(global_index)
     }
 
     #[doc(hidden)]
-    pub fn none() -> Self {
+    pub const fn none() -> Self {
         // First element is always the empty string name.
-        Self { global_index: 0 }
-    }
-
-    #[doc(hidden)]
-    pub fn alloc_next_ascii(class_name_cstr: &'static CStr) -> Self {
-        let utf8 = class_name_cstr
-            .to_str()
-            .expect("class name is invalid UTF-8");
-
-        assert!(
-            utf8.is_ascii(),
-            "ClassName::alloc_next_ascii() with non-ASCII Unicode string '{}'",
-            utf8
-        );
-
-        let global_index = insert_class(ClassNameSource::Borrowed(class_name_cstr));
-
-        Self { global_index }
-    }
-
-    #[doc(hidden)]
-    pub fn alloc_next_unicode(class_name_str: &'static str) -> Self {
-        assert!(
-            cfg!(since_api = "4.4"),
-            "Before Godot 4.4, class names must be ASCII, but '{class_name_str}' is not.\nSee https://github.com/godotengine/godot/pull/96501."
-        );
-
-        assert!(
-            !class_name_str.is_ascii(),
-            "ClassName::alloc_next_unicode() with ASCII string '{}'",
-            class_name_str
-        );
-
-        // StringNames use optimized 1-byte-per-char layout for Latin-1/ASCII, so Unicode can as well use the regular constructor.
-        let global_index = insert_class(ClassNameSource::Owned(class_name_str.to_owned()));
-
-        Self { global_index }
+        Self::from_index(0)
     }
 
     #[doc(hidden)]
     pub fn is_none(&self) -> bool {
-        self.global_index == 0
+        match self.repr {
+            ClassNameRepr::Index(index) => index == 0,
+            ClassNameRepr::Static(name) => name.is_empty(),
+        }
     }
     //
     // /// Returns the class name as a string slice with static storage duration.
@@ -202,8 +217,12 @@ impl ClassName {
 
     /// Returns an owned or borrowed `str`.
     pub fn to_cow_str(&self) -> Cow<'static, str> {
+        if let ClassNameRepr::Static(name) = self.repr {
+            return Cow::Borrowed(name);
+        }
+
         let cached_names = CLASS_NAMES.lock();
-        let entry = &cached_names[self.global_index as usize];
+        let entry = &cached_names[self.global_index() as usize];
 
         entry.rust_str.as_cow_str()
     }
@@ -217,8 +236,9 @@ impl ClassName {
 
     // Takes a closure because the mutex guard protects the reference; so the &StringName cannot leave the scope.
     fn with_string_name<R>(&self, func: impl FnOnce(&StringName) -> R) -> R {
+        let global_index = self.global_index();
         let cached_names = CLASS_NAMES.lock();
-        let entry = &cached_names[self.global_index as usize];
+        let entry = &cached_names[global_index as usize];
 
         let string_name = entry
             .godot_str
@@ -226,6 +246,108 @@ impl ClassName {
 
         func(string_name)
     }
+
+    const fn from_index(global_index: u16) -> Self {
+        Self {
+            repr: ClassNameRepr::Index(global_index),
+        }
+    }
+
+    fn global_index(&self) -> u16 {
+        match self.repr {
+            ClassNameRepr::Index(index) => index,
+            ClassNameRepr::Static(name) => static_index(name, || {
+                assert!(
+                    cfg!(since_api = "4.4") || name.is_ascii(),
+                    "Before Godot 4.4, class names must be ASCII, but '{name}' is not.\nSee https://github.com/godotengine/godot/pull/96501."
+                );
+
+                ClassNameSource::BorrowedStr(name)
+            }),
+        }
+    }
+
+    /// Looks up the name in the global cache, so that later uses don't need to.
+    fn resolved(self) -> Self {
+        Self::from_index(self.global_index())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Class name stored in a `static`, which is looked up in the global cache only on first use.
+///
+/// [`ClassName::new_static()`] creates a class name in const context, but has to look it up again each time it is used. This type stores
+/// the resolved name instead. Engine classes and `#[derive(GodotClass)]` classes use it for their [`GodotClass::class_name()`].
+///
+/// ```no_run
+/// use godot::meta::{ClassName, StaticClassName};
+///
+/// static PLAYER: StaticClassName = StaticClassName::new("Player");
+///
+/// fn is_player(class_name: ClassName) -> bool {
+///     class_name == PLAYER.get()
+/// }
+/// ```
+pub struct StaticClassName {
+    name: &'static str,
+    resolved: OnceLock<ClassName>,
+}
+
+impl StaticClassName {
+    /// Stores `name` without looking it up; see [`ClassName::new_static()`].
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            resolved: OnceLock::new(),
+        }
+    }
+
+    /// Returns the class name, looking it up in the global cache on first call.
+    ///
+    /// # Panics
+    /// On first call, if the string is not ASCII and the Godot version is older than 4.4.
+    pub fn get(&self) -> ClassName {
+        *self
+            .resolved
+            .get_or_init(|| ClassName::new_static(self.name).resolved())
+    }
+}
+
+impl fmt::Debug for StaticClassName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StaticClassName").field(&self.name).finish()
+    }
+}
+
+impl PartialEq for ClassName {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.repr, other.repr) {
+            // Same name always resolves to the same index; no need to access the cache.
+            (ClassNameRepr::Static(lhs), ClassNameRepr::Static(rhs)) => lhs == rhs,
+            _ => self.global_index() == other.global_index(),
+        }
+    }
+}
+
+impl Eq for ClassName {}
+
+impl PartialOrd for ClassName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ClassName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.global_index().cmp(&other.global_index())
+    }
+}
+
+impl Hash for ClassName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.global_index().hash(state);
+    }
 }
 
 impl fmt::Display for ClassName {
@@ -246,6 +368,18 @@ fn insert_class(name: ClassNameSource) -> u16 {
     index
 }
 
+/// Returns the index of a class name with static storage, adding it to the cache on first use.
+fn static_index(name: &'static str, make_source: impl FnOnce() -> ClassNameSource) -> u16 {
+    if name.is_empty() {
+        return 0;
+    }
+
+    *STATIC_INDEX_BY_NAME
+        .lock()
+        .entry(name)
+        .or_insert_with(|| insert_class(make_source()))
+}
+
 fn ascii_cstr_to_str(cstr: &CStr) -> &str {
     cstr.to_str().expect("should be validated ASCII")
 }
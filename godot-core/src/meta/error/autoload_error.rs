@@ -0,0 +1,96 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::meta::ClassName;
+
+/// Error when looking up an autoload singleton with [`try_autoload()`][crate::tools::try_autoload].
+#[derive(Debug)]
+pub struct AutoloadError {
+    name: String,
+    kind: AutoloadErrorKind,
+}
+
+#[derive(Debug)]
+enum AutoloadErrorKind {
+    NoSceneTree,
+    NotDeclared,
+    NotInTree,
+    WrongType {
+        expected: ClassName,
+        actual: GString,
+    },
+}
+
+impl AutoloadError {
+    pub(crate) fn no_scene_tree(name: &str) -> Self {
+        Self::new(name, AutoloadErrorKind::NoSceneTree)
+    }
+
+    pub(crate) fn not_declared(name: &str) -> Self {
+        Self::new(name, AutoloadErrorKind::NotDeclared)
+    }
+
+    pub(crate) fn not_in_tree(name: &str) -> Self {
+        Self::new(name, AutoloadErrorKind::NotInTree)
+    }
+
+    pub(crate) fn wrong_type(name: &str, expected: ClassName, actual: GString) -> Self {
+        Self::new(name, AutoloadErrorKind::WrongType { expected, actual })
+    }
+
+    fn new(name: &str, kind: AutoloadErrorKind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+        }
+    }
+
+    /// Name of the autoload that was looked up.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the autoload is declared in the project settings, but not (yet) part of the scene tree.
+    ///
+    /// This is the case when accessing an autoload before the scene tree is set up, e.g. from another autoload's `init()`.
+    pub fn is_not_in_tree(&self) -> bool {
+        matches!(
+            self.kind,
+            AutoloadErrorKind::NoSceneTree | AutoloadErrorKind::NotInTree
+        )
+    }
+}
+
+impl fmt::Display for AutoloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+
+        match &self.kind {
+            AutoloadErrorKind::NoSceneTree => {
+                write!(f, "autoload `{name}`: main loop is not a running SceneTree")
+            }
+            AutoloadErrorKind::NotDeclared => write!(
+                f,
+                "autoload `{name}` is not declared in the project settings (Project > Project Settings > Globals > Autoload)"
+            ),
+            AutoloadErrorKind::NotInTree => write!(
+                f,
+                "autoload `{name}` is declared, but not yet added to the scene tree"
+            ),
+            AutoloadErrorKind::WrongType { expected, actual } => write!(
+                f,
+                "autoload `{name}` has type {actual}, which is not {expected} or derived from it"
+            ),
+        }
+    }
+}
+
+impl Error for AutoloadError {}
@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::obj::BorrowInfo;
+
+/// Error when binding a user instance, e.g. with [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut] or
+/// [`WithBaseField::try_base_mut()`][crate::obj::WithBaseField::try_base_mut].
+#[derive(Debug)]
+pub struct BindError {
+    class: String,
+    kind: BindErrorKind,
+}
+
+#[derive(Debug)]
+enum BindErrorKind {
+    AlreadyBound {
+        mutable: bool,
+        held_by: Option<BorrowInfo>,
+        details: String,
+    },
+    BaseUnavailable,
+    Inaccessible {
+        details: String,
+    },
+}
+
+impl BindError {
+    pub(crate) fn already_bound(
+        class: &str,
+        mutable: bool,
+        held_by: Option<BorrowInfo>,
+        details: String,
+    ) -> Self {
+        Self::new(
+            class,
+            BindErrorKind::AlreadyBound {
+                mutable,
+                held_by,
+                details,
+            },
+        )
+    }
+
+    pub(crate) fn base_unavailable(class: &str) -> Self {
+        Self::new(class, BindErrorKind::BaseUnavailable)
+    }
+
+    pub(crate) fn inaccessible(class: &str, details: String) -> Self {
+        Self::new(class, BindErrorKind::Inaccessible { details })
+    }
+
+    fn new(class: &str, kind: BindErrorKind) -> Self {
+        Self {
+            class: class.to_string(),
+            kind,
+        }
+    }
+
+    /// Whether the instance could not be bound because of another live borrow, typically a method further up the call stack.
+    pub fn is_already_bound(&self) -> bool {
+        matches!(self.kind, BindErrorKind::AlreadyBound { .. })
+    }
+
+    /// The borrow that prevented binding, if known.
+    ///
+    /// Only recorded in debug builds; always `None` in release builds.
+    pub fn held_by(&self) -> Option<BorrowInfo> {
+        match &self.kind {
+            BindErrorKind::AlreadyBound { held_by, .. } => *held_by,
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let class = &self.class;
+
+        match &self.kind {
+            BindErrorKind::AlreadyBound {
+                mutable,
+                held_by,
+                details,
+            } => {
+                let (method, hint) = if *mutable {
+                    ("bind_mut", "`self.base_mut()`")
+                } else {
+                    ("bind", "`self.base_mut()` or `self.base()`")
+                };
+
+                write!(f, "Gd<T>::{method}() failed, already bound; T = {class}.\n  ")?;
+                if let Some(held_by) = held_by {
+                    write!(f, "Currently held by {held_by}.\n  ")?;
+                }
+                write!(
+                    f,
+                    "Make sure to use {hint} instead of `self.to_gd()` when possible.\n  \
+                    Details: {details}."
+                )
+            }
+            BindErrorKind::BaseUnavailable => write!(
+                f,
+                "base of class {class} is not available during construction or destruction of the instance"
+            ),
+            BindErrorKind::Inaccessible { details } => write!(
+                f,
+                "`base_mut()` failed for type T = {class}.\n  \
+                This is most likely a bug, please report it.\n  \
+                Details: {details}."
+            ),
+        }
+    }
+}
+
+impl Error for BindError {}
@@ -122,6 +122,28 @@ impl CallError {
         Err(call_error)
     }
 
+    /// Checks whether enough arguments are passed to a `#[func(vararg)]` function.
+    pub(crate) fn check_min_arg_count(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        param_count: usize,
+    ) -> Result<(), Self> {
+        if arg_count >= param_count {
+            return Ok(());
+        }
+
+        let param_plural = plural(param_count);
+        let arg_plural = plural(arg_count);
+
+        Err(Self::new(
+            call_ctx,
+            format!(
+                "function has at least {param_count} parameter{param_plural}, but received {arg_count} argument{arg_plural}"
+            ),
+            None,
+        ))
+    }
+
     /// Checks the Godot side of a varcall (low-level `sys::GDExtensionCallError`).
     pub(crate) fn check_out_varcall<T: ToGodot>(
         call_ctx: &CallContext,
@@ -181,6 +203,17 @@ impl CallError {
         )
     }
 
+    /// Returns an error for a `#[func(vararg)]` argument that was requested, but not passed.
+    pub(crate) fn failed_vararg_missing<P>(call_ctx: &CallContext, param_index: usize) -> Self {
+        let param_ty = std::any::type_name::<P>();
+
+        Self::new(
+            call_ctx,
+            format!("parameter #{param_index} ({param_ty}) missing"),
+            None,
+        )
+    }
+
     fn failed_param_conversion_engine(
         call_ctx: &CallContext,
         param_index: i32,
@@ -215,6 +248,11 @@ impl CallError {
         )
     }
 
+    /// Returns an error for a dynamic call to a method that doesn't exist on the runtime class.
+    pub(crate) fn failed_method_not_found(call_ctx: &CallContext) -> Self {
+        Self::new(call_ctx, "method not found", None)
+    }
+
     fn failed_param_count(
         call_ctx: &CallContext,
         arg_count: usize,
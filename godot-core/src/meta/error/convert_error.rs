@@ -18,10 +18,19 @@ type Cause = Box<dyn Error + Send + Sync>;
 /// Represents errors that can occur when converting values from Godot.
 ///
 /// To create user-defined errors, you can use [`ConvertError::default()`] or [`ConvertError::new("message")`][Self::new].
+///
+/// # Path context
+/// When a conversion fails inside a nested structure, the error records where this happened, for example
+/// `items[3].stats.hp: cannot convert from STRING to INT: "abc"`. Dictionary and array accessors in godot-rust add this context
+/// automatically. In your own [`FromGodot`](crate::meta::FromGodot) impls, use [`with_field()`][Self::with_field],
+/// [`with_key()`][Self::with_key] and [`with_index()`][Self::with_index] to extend the path while propagating errors from the inside out.
 #[derive(Debug)]
 pub struct ConvertError {
     kind: ErrorKind,
     value: Option<Variant>,
+
+    /// Location of the failed conversion within nested structures; outermost segment first.
+    path: Vec<PathSegment>,
 }
 
 impl ConvertError {
@@ -43,6 +52,7 @@ impl ConvertError {
         Self {
             kind,
             value: Some(value.to_variant()),
+            path: Vec::new(),
         }
     }
 
@@ -66,7 +76,94 @@ impl ConvertError {
         Self {
             kind: ErrorKind::Custom(Some(error.into())),
             value: Some(value.to_variant()),
+            path: Vec::new(),
+        }
+    }
+
+    /// Prepends a struct field to the path of this error.
+    ///
+    /// Call this when propagating an error from a field conversion in a custom [`FromGodot`](crate::meta::FromGodot) impl.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::meta::error::ConvertError;
+    /// struct Stats {
+    ///     hp: i64,
+    /// }
+    ///
+    /// fn stats_from_dict(dict: &Dictionary) -> Result<Stats, ConvertError> {
+    ///     let hp = dict.get_or_nil("hp").try_to().map_err(|e| e.with_field("hp"))?;
+    ///     Ok(Stats { hp })
+    /// }
+    /// ```
+    pub fn with_field(mut self, name: impl Into<String>) -> Self {
+        self.path.insert(0, PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Prepends a dictionary key to the path of this error.
+    ///
+    /// String keys that are valid identifiers are displayed like fields (`.name`), other keys in brackets (`[7]`, `["two words"]`).
+    pub fn with_key(mut self, key: impl ToGodot) -> Self {
+        let key = key.to_variant();
+        let segment = match key.get_type() {
+            VariantType::STRING | VariantType::STRING_NAME => {
+                let key = key.stringify().to_string();
+                if is_identifier(&key) {
+                    PathSegment::Field(key)
+                } else {
+                    PathSegment::Index(format!("{key:?}"))
+                }
+            }
+            _ => PathSegment::Index(key.stringify().to_string()),
+        };
+
+        self.path.insert(0, segment);
+        self
+    }
+
+    /// Prepends an array index to the path of this error.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.path.insert(0, PathSegment::Index(index.to_string()));
+        self
+    }
+
+    /// Adds the dictionary key under which the failed conversion happened. `part` is either `"key"` or `"value"`.
+    ///
+    /// The value that failed to convert is kept.
+    pub(crate) fn with_dictionary_key(mut self, key: &Variant, part: &str) -> Self {
+        if part == "key" {
+            let segment = PathSegment::Index(format!("key {}", key.stringify()));
+            self.path.insert(0, segment);
+            self
+        } else {
+            self.with_key(key.clone())
+        }
+    }
+
+    /// Returns where in a nested structure the conversion failed, e.g. `items[3].stats.hp`.
+    ///
+    /// Empty if the error did not occur inside a nested structure, or if no context was added.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.path {
+            match segment {
+                PathSegment::Field(name) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(name);
+                }
+                PathSegment::Index(index) => {
+                    path.push('[');
+                    path.push_str(index);
+                    path.push(']');
+                }
+            }
         }
+
+        path
     }
 
     /// Returns the rust-error that caused this error, if one exists.
@@ -91,6 +188,10 @@ impl ConvertError {
 
 impl fmt::Display for ConvertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path())?;
+        }
+
         write!(f, "{}", self.kind)?;
 
         if let Some(value) = &self.value {
@@ -115,25 +216,48 @@ impl Default for ConvertError {
         Self {
             kind: ErrorKind::Custom(None),
             value: None,
+            path: Vec::new(),
         }
     }
 }
 
+/// One step in the [path](ConvertError::path) of a conversion error.
+#[derive(Debug)]
+enum PathSegment {
+    /// Struct field or identifier-like dictionary key, displayed as `.name`.
+    Field(String),
+
+    /// Array index or other dictionary key, displayed as `[index]`.
+    Index(String),
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
 /// Erased type of [`ConvertError`].
 #[derive(Debug)]
 pub(crate) struct ErasedConvertError {
     kind: ErrorKind,
+    path: String,
 }
 
 impl From<ConvertError> for ErasedConvertError {
     fn from(v: ConvertError) -> Self {
+        let path = v.path();
         let ConvertError { kind, .. } = v;
-        Self { kind }
+        Self { kind, path }
     }
 }
 
 impl fmt::Display for ErasedConvertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+
         write!(f, "{}", self.kind)
     }
 }
@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::global::Error as GodotError;
+
+/// Error that can occur while using `gdext` crypto utilities in [`godot::tools`][crate::tools].
+#[derive(Debug)]
+pub struct CryptoError {
+    kind: CryptoErrorKind,
+    godot_error: Option<GodotError>,
+}
+
+#[derive(Debug)]
+enum CryptoErrorKind {
+    Hash,
+    Hmac,
+    GenerateKey { bits: u32 },
+    GenerateCertificate,
+    LoadKey { source: String },
+    LoadCertificate { path: String },
+}
+
+impl CryptoError {
+    pub(crate) fn hash(error: GodotError) -> Self {
+        Self::new(CryptoErrorKind::Hash, Some(error))
+    }
+
+    pub(crate) fn hmac() -> Self {
+        Self::new(CryptoErrorKind::Hmac, None)
+    }
+
+    pub(crate) fn generating_key(bits: u32) -> Self {
+        Self::new(CryptoErrorKind::GenerateKey { bits }, None)
+    }
+
+    pub(crate) fn generating_certificate() -> Self {
+        Self::new(CryptoErrorKind::GenerateCertificate, None)
+    }
+
+    pub(crate) fn loading_key(error: GodotError, source: String) -> Self {
+        Self::new(CryptoErrorKind::LoadKey { source }, Some(error))
+    }
+
+    pub(crate) fn loading_certificate(error: GodotError, path: String) -> Self {
+        Self::new(CryptoErrorKind::LoadCertificate { path }, Some(error))
+    }
+
+    fn new(kind: CryptoErrorKind, godot_error: Option<GodotError>) -> Self {
+        Self { kind, godot_error }
+    }
+
+    /// The error code reported by Godot, if the failed operation returns one.
+    pub fn godot_error(&self) -> Option<GodotError> {
+        self.godot_error
+    }
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            CryptoErrorKind::Hash => write!(f, "can't compute hash")?,
+            CryptoErrorKind::Hmac => write!(
+                f,
+                "can't compute HMAC (only SHA-1 and SHA-256 are supported)"
+            )?,
+            CryptoErrorKind::GenerateKey { bits } => {
+                write!(f, "can't generate RSA key of {bits} bits")?
+            }
+            CryptoErrorKind::GenerateCertificate => {
+                write!(f, "can't generate self-signed certificate")?
+            }
+            CryptoErrorKind::LoadKey { source } => write!(f, "can't load key from {source}")?,
+            CryptoErrorKind::LoadCertificate { path } => {
+                write!(f, "can't load certificate from path: '{path}'")?
+            }
+        }
+
+        if let Some(godot_error) = &self.godot_error {
+            write!(f, "; Godot error: {godot_error:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for CryptoError {}
@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::classes::image::Format;
+use crate::global::Error as GodotError;
+
+/// Error that can occur while creating an [`Image`][crate::classes::Image] or texture from raw data.
+#[derive(Debug)]
+pub struct ImageError {
+    kind: ImageErrorKind,
+    godot_error: Option<GodotError>,
+}
+
+#[derive(Debug)]
+enum ImageErrorKind {
+    InvalidSize {
+        width: u32,
+        height: u32,
+    },
+    UnsupportedFormat {
+        format: Format,
+    },
+    DataSize {
+        format: Format,
+        expected: usize,
+        actual: usize,
+    },
+    GenerateMipmaps,
+    EmptyImage,
+    Create,
+}
+
+impl ImageError {
+    pub(crate) fn invalid_size(width: u32, height: u32) -> Self {
+        Self::new(ImageErrorKind::InvalidSize { width, height }, None)
+    }
+
+    pub(crate) fn unsupported_format(format: Format) -> Self {
+        Self::new(ImageErrorKind::UnsupportedFormat { format }, None)
+    }
+
+    pub(crate) fn data_size(format: Format, expected: usize, actual: usize) -> Self {
+        Self::new(
+            ImageErrorKind::DataSize {
+                format,
+                expected,
+                actual,
+            },
+            None,
+        )
+    }
+
+    pub(crate) fn generating_mipmaps(error: GodotError) -> Self {
+        Self::new(ImageErrorKind::GenerateMipmaps, Some(error))
+    }
+
+    #[cfg(feature = "codegen-full")]
+    pub(crate) fn empty_image() -> Self {
+        Self::new(ImageErrorKind::EmptyImage, None)
+    }
+
+    pub(crate) fn creating() -> Self {
+        Self::new(ImageErrorKind::Create, None)
+    }
+
+    fn new(kind: ImageErrorKind, godot_error: Option<GodotError>) -> Self {
+        Self { kind, godot_error }
+    }
+
+    /// The error code reported by Godot, if the failed operation returns one.
+    pub fn godot_error(&self) -> Option<GodotError> {
+        self.godot_error
+    }
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ImageErrorKind::InvalidSize { width, height } => {
+                write!(f, "invalid image size {width}x{height}")?
+            }
+            ImageErrorKind::UnsupportedFormat { format } => write!(
+                f,
+                "image format {format:?} is not supported for raw data (compressed formats are not)"
+            )?,
+            ImageErrorKind::DataSize {
+                format,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "image data has {actual} bytes, but format {format:?} with the given size requires {expected} bytes"
+            )?,
+            ImageErrorKind::GenerateMipmaps => write!(f, "can't generate mipmaps")?,
+            ImageErrorKind::EmptyImage => write!(f, "can't create texture from empty image")?,
+            ImageErrorKind::Create => write!(f, "Godot failed to create the image or texture")?,
+        }
+
+        if let Some(godot_error) = &self.godot_error {
+            write!(f, "; Godot error: {godot_error:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ImageError {}
@@ -24,6 +24,8 @@ impl fmt::Display for IoError {
             ErrorData::Load(err) => err.fmt(f),
             ErrorData::Save(err) => err.fmt(f),
             ErrorData::GFile(err) => err.fmt(f),
+            #[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+            ErrorData::Uid(err) => err.fmt(f),
         }
     }
 }
@@ -35,6 +37,8 @@ impl Error for IoError {
             ErrorData::Load(err) => Some(err),
             ErrorData::Save(err) => Some(err),
             ErrorData::GFile(err) => Some(err),
+            #[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+            ErrorData::Uid(err) => Some(err),
         }
     }
 }
@@ -70,6 +74,27 @@ impl IoError {
         }
     }
 
+    #[cfg(feature = "experimental-threads")]
+    pub(crate) fn loading_request(error: GodotError, class: String, path: String) -> Self {
+        Self {
+            data: ErrorData::Load(LoaderError {
+                kind: LoaderErrorKind::Request(error),
+                class,
+                path,
+            }),
+        }
+    }
+
+    #[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+    pub(crate) fn assigning_uid(error: GodotError, path: String) -> Self {
+        Self {
+            data: ErrorData::Uid(UidError {
+                path,
+                godot_error: error,
+            }),
+        }
+    }
+
     pub(crate) fn check_unique_open_file_access(
         file_access: Gd<FileAccess>,
     ) -> Result<Gd<FileAccess>, Self> {
@@ -103,6 +128,8 @@ enum ErrorData {
     Load(LoaderError),
     Save(SaverError),
     GFile(GFileError),
+    #[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+    Uid(UidError),
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -118,6 +145,8 @@ struct LoaderError {
 enum LoaderErrorKind {
     Load,
     Cast,
+    #[cfg(feature = "experimental-threads")]
+    Request(GodotError),
 }
 
 impl Error for LoaderError {}
@@ -136,6 +165,11 @@ impl fmt::Display for LoaderError {
                 f,
                 "can't cast loaded resource to class: '{class}' from path: '{path}'"
             ),
+            #[cfg(feature = "experimental-threads")]
+            LoaderErrorKind::Request(godot_error) => write!(
+                f,
+                "can't request threaded load of class: '{class}' from path: '{path}'; Godot error: {godot_error:?}"
+            ),
         }
     }
 }
@@ -192,3 +226,28 @@ impl fmt::Display for GFileError {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+#[derive(Debug)]
+struct UidError {
+    path: String,
+    godot_error: GodotError,
+}
+
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+impl Error for UidError {}
+
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+impl fmt::Display for UidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = &self.path;
+        let godot_error = &self.godot_error;
+
+        write!(
+            f,
+            "can't assign UID to resource at path: '{path}'; Godot error: {godot_error:?}"
+        )
+    }
+}
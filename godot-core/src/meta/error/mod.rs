@@ -7,10 +7,20 @@
 
 //! Errors in the gdext library.
 
+mod autoload_error;
+mod bind_error;
 mod call_error;
 mod convert_error;
+#[cfg(feature = "codegen-full")]
+mod crypto_error;
+mod image_error;
 mod io_error;
 
+pub use autoload_error::*;
+pub use bind_error::*;
 pub use call_error::*;
 pub use convert_error::*;
+#[cfg(feature = "codegen-full")]
+pub use crypto_error::*;
+pub use image_error::*;
 pub use io_error::*;
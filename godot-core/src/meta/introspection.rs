@@ -0,0 +1,282 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Machine-readable description of the engine classes generated by godot-rust.
+//!
+//! Requires the `api-introspection` feature.
+//!
+//! Tools building on top of godot-rust (e.g. binding generators for scripting layers) can use this module instead of parsing
+//! `extension_api.json` themselves. The tables describe the API **as generated**: only classes and methods that exist in the `classes`
+//! module are listed (depending on the `codegen-full` and `experimental-godot-api` features), with their Rust names and types.
+//!
+//! All data is static and available without a running engine.
+//!
+//! # Stability
+//! The structure of the tables and of the JSON output is versioned with [`FORMAT_VERSION`]. Fields are only added or changed together
+//! with an increment of that version. The contents follow the generated API, and thus change with the Godot version.
+//!
+//! # Example
+//! ```no_run
+//! use godot::meta::introspection;
+//!
+//! let node = introspection::find_class("Node").expect("Node is always generated");
+//! for method in node.methods.iter().filter(|m| m.is_virtual) {
+//!     println!("virtual {}::{}", node.rust_name, method.rust_name);
+//! }
+//!
+//! std::fs::write("gdext_api.json", introspection::to_json()).unwrap();
+//! ```
+
+use std::fmt::Write as _;
+
+use crate::init::InitLevel;
+
+/// Version of the table structure and the JSON format, see [Stability](self#stability).
+pub const FORMAT_VERSION: u32 = 1;
+
+/// An engine class, as available in the `godot::classes` module.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ClassInfo {
+    /// Name in Godot, e.g. `CSGMesh3D`.
+    pub godot_name: &'static str,
+
+    /// Name in Rust, e.g. `CsgMesh3D`.
+    pub rust_name: &'static str,
+
+    /// Godot name of the direct base class; `None` for `Object`.
+    pub base: Option<&'static str>,
+
+    /// Level at which the class becomes available.
+    pub init_level: InitLevel,
+
+    pub is_refcounted: bool,
+
+    /// Whether instances can be created from Rust (`new_alloc()`/`new_gd()`).
+    pub is_instantiable: bool,
+
+    /// Whether the class is only available with the `experimental-godot-api` feature.
+    pub is_experimental: bool,
+
+    /// Methods declared by this class; inherited ones are listed on the base classes.
+    pub methods: &'static [MethodInfo],
+
+    /// Signals declared by this class; inherited ones are listed on the base classes.
+    pub signals: &'static [SignalInfo],
+}
+
+/// A method of an engine class, either callable from Rust or virtual (overridable in `I*` traits).
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct MethodInfo {
+    /// Name in Godot, e.g. `_ready` or `get_node`.
+    pub godot_name: &'static str,
+
+    /// Name in Rust, e.g. `ready` or `get_node`.
+    pub rust_name: &'static str,
+
+    pub params: &'static [ParamInfo],
+
+    /// Rust type of the return value, or `None` for methods without return value.
+    pub return_type: Option<&'static str>,
+
+    pub qualifier: MethodQualifier,
+
+    pub is_virtual: bool,
+
+    /// Whether the method accepts additional arguments as `&[Variant]`, after the listed parameters.
+    pub is_vararg: bool,
+}
+
+/// A signal declared by an engine class.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SignalInfo {
+    pub name: &'static str,
+    pub params: &'static [ParamInfo],
+}
+
+/// A parameter of a method or signal.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ParamInfo {
+    pub name: &'static str,
+
+    /// Rust type with full path, e.g. `godot::builtin::GString` or `godot::classes::node::ProcessMode`.
+    ///
+    /// Engine classes are described as `Gd<T>`, although they are passed as `impl AsObjectArg<T>` and returned as `Option<Gd<T>>`.
+    pub rust_type: &'static str,
+
+    /// Whether the parameter has a default value, i.e. can be omitted via the `*_ex()` builder.
+    pub has_default: bool,
+}
+
+/// Receiver of a method.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MethodQualifier {
+    /// `&self`
+    Const,
+
+    /// `&mut self`
+    Mut,
+
+    /// No receiver (associated function).
+    Static,
+}
+
+impl MethodQualifier {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Const => "const",
+            Self::Mut => "mut",
+            Self::Static => "static",
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Queries
+
+/// All generated engine classes, in the order of `extension_api.json`.
+pub fn classes() -> &'static [ClassInfo] {
+    crate::gen::introspection::CLASSES
+}
+
+/// Looks up a class by its Godot name.
+pub fn find_class(godot_name: &str) -> Option<&'static ClassInfo> {
+    classes()
+        .iter()
+        .find(|class| class.godot_name == godot_name)
+}
+
+/// Serializes all tables to JSON.
+///
+/// The top-level object has the keys `format_version` and `classes`. Field names match the ones of the Rust structs; enums are lowercase
+/// strings (`"scene"`, `"const"`), and absent values are `null`.
+pub fn to_json() -> String {
+    let mut out = String::new();
+
+    write!(out, r#"{{"format_version":{FORMAT_VERSION},"classes":["#).unwrap();
+    write_list(&mut out, classes(), write_class);
+    out.push_str("]}");
+
+    out
+}
+
+fn write_class(out: &mut String, class: &ClassInfo) {
+    out.push('{');
+    write_field(out, "godot_name", class.godot_name);
+    write_field(out, "rust_name", class.rust_name);
+    write_optional_field(out, "base", class.base);
+    write_field(
+        out,
+        "init_level",
+        &format!("{:?}", class.init_level).to_lowercase(),
+    );
+    write!(
+        out,
+        r#""is_refcounted":{},"is_instantiable":{},"is_experimental":{},"methods":["#,
+        class.is_refcounted, class.is_instantiable, class.is_experimental
+    )
+    .unwrap();
+    write_list(out, class.methods, write_method);
+    out.push_str(r#"],"signals":["#);
+    write_list(out, class.signals, write_signal);
+    out.push_str("]}");
+}
+
+fn write_method(out: &mut String, method: &MethodInfo) {
+    out.push('{');
+    write_field(out, "godot_name", method.godot_name);
+    write_field(out, "rust_name", method.rust_name);
+    write_optional_field(out, "return_type", method.return_type);
+    write_field(out, "qualifier", method.qualifier.as_str());
+    write!(
+        out,
+        r#""is_virtual":{},"is_vararg":{},"params":["#,
+        method.is_virtual, method.is_vararg
+    )
+    .unwrap();
+    write_list(out, method.params, write_param);
+    out.push_str("]}");
+}
+
+fn write_signal(out: &mut String, signal: &SignalInfo) {
+    out.push('{');
+    write_field(out, "name", signal.name);
+    out.push_str(r#""params":["#);
+    write_list(out, signal.params, write_param);
+    out.push_str("]}");
+}
+
+fn write_param(out: &mut String, param: &ParamInfo) {
+    out.push('{');
+    write_field(out, "name", param.name);
+    write_field(out, "rust_type", param.rust_type);
+    write!(out, r#""has_default":{}}}"#, param.has_default).unwrap();
+}
+
+fn write_list<T>(out: &mut String, items: &[T], write_item: fn(&mut String, &T)) {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_item(out, item);
+    }
+}
+
+/// Writes `"key":"value",`.
+fn write_field(out: &mut String, key: &str, value: &str) {
+    write!(out, r#""{key}":"#).unwrap();
+    write_string(out, value);
+    out.push(',');
+}
+
+/// Writes `"key":"value",` or `"key":null,`.
+fn write_optional_field(out: &mut String, key: &str, value: Option<&str>) {
+    match value {
+        Some(value) => write_field(out, key, value),
+        None => write!(out, r#""{key}":null,"#).unwrap(),
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_is_valid() {
+        let json = to_json();
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+
+        assert_eq!(value["format_version"], FORMAT_VERSION);
+        assert_eq!(value["classes"].as_array().unwrap().len(), classes().len());
+    }
+
+    #[test]
+    fn json_escapes_strings() {
+        let mut out = String::new();
+        write_string(&mut out, "a\"b\\c\n");
+
+        assert_eq!(out, r#""a\"b\\c\u000a""#);
+    }
+}
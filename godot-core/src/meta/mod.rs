@@ -49,15 +49,19 @@ mod class_name;
 mod godot_convert;
 mod method_info;
 mod property_info;
+mod rpc_payload;
 mod sealed;
 mod signature;
 mod traits;
 
 pub mod error;
+#[cfg(feature = "api-introspection")]
+pub mod introspection;
 
 pub use args::*;
-pub use class_name::ClassName;
+pub use class_name::{ClassName, StaticClassName};
 pub use godot_convert::{FromGodot, GodotConvert, ToGodot};
+pub use rpc_payload::{PayloadReader, RpcPayload};
 pub use traits::{ArrayElement, GodotType, PackedArrayElement};
 
 pub(crate) use array_type_info::ArrayTypeInfo;
@@ -89,4 +93,8 @@ pub use property_info::{PropertyHintInfo, PropertyInfo};
 /// Must not use meta facilities (e.g. `ClassName`) after this call.
 pub(crate) unsafe fn cleanup() {
     class_name::cleanup();
+    args::cleanup_string_name_cache();
+
+    #[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+    crate::sys::ptrcall_check::clear();
 }
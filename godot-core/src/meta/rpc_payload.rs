@@ -0,0 +1,344 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    real, Color, GString, PackedByteArray, Quaternion, StringName, Vector2, Vector2i, Vector3,
+    Vector3i, Vector4, Vector4i,
+};
+use crate::meta::error::ConvertError;
+
+/// Compact binary encoding of values, for sending structs over the network.
+///
+/// Usually derived with [`#[derive(RpcPayload)]`](../register/derive.RpcPayload.html), which also implements [`GodotConvert`][super::GodotConvert]
+/// with `Via = PackedByteArray`. This allows such structs to be used directly as parameters of `#[rpc]` functions, without going through
+/// a `Dictionary`.
+///
+/// # Format
+/// [`to_payload_bytes()`][Self::to_payload_bytes] writes a version byte ([`PAYLOAD_VERSION`][Self::PAYLOAD_VERSION]), followed by the
+/// fields in declaration order:
+/// - Integers and floats are stored little-endian, with their own width. `bool` takes one byte.
+/// - Strings are stored as a `u32` byte length followed by UTF-8.
+/// - `Vec<T>` and `PackedByteArray` are stored as a `u32` element count followed by the elements.
+/// - `Option<T>` is stored as a byte `0` (`None`) or `1` (`Some`), followed by the value.
+/// - Vectors, `Quaternion` and `Color` are stored component-wise; components of type `real` use the precision of the build, so all peers
+///   must use the same `double-precision` setting.
+///
+/// There is no schema information in the payload; both peers must agree on the type. Bump `PAYLOAD_VERSION` when changing the layout of
+/// a type, so that mismatches are reported as errors instead of garbage values.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::meta::RpcPayload;
+///
+/// #[derive(RpcPayload, Debug)]
+/// #[rpc_payload(version = 2)]
+/// struct PlayerState {
+///     position: Vector2,
+///     health: u16,
+///     name: GString,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[rpc(unreliable)]
+///     fn sync_state(&mut self, state: PlayerState) {
+///         godot_print!("received {state:?}");
+///     }
+/// }
+/// ```
+pub trait RpcPayload: Sized {
+    /// Version byte written in front of top-level payloads. Can be set with `#[rpc_payload(version = N)]`.
+    const PAYLOAD_VERSION: u8 = 0;
+
+    /// Appends the encoded value to `out`, without version byte.
+    fn encode_payload(&self, out: &mut Vec<u8>);
+
+    /// Decodes a value from `input`, advancing it past the consumed bytes.
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError>;
+
+    /// Encodes the value with a leading version byte.
+    fn to_payload_bytes(&self) -> PackedByteArray {
+        let mut out = vec![Self::PAYLOAD_VERSION];
+        self.encode_payload(&mut out);
+
+        PackedByteArray::from(out)
+    }
+
+    /// Decodes a value from bytes produced by [`to_payload_bytes()`][Self::to_payload_bytes].
+    ///
+    /// # Errors
+    /// If the version byte doesn't match [`PAYLOAD_VERSION`][Self::PAYLOAD_VERSION], the bytes are truncated or invalid for the type, or
+    /// there are trailing bytes.
+    fn from_payload_bytes(bytes: &PackedByteArray) -> Result<Self, ConvertError> {
+        let mut input = PayloadReader::new(bytes.as_slice());
+
+        let version = u8::decode_payload(&mut input)?;
+        if version != Self::PAYLOAD_VERSION {
+            return Err(ConvertError::new(format!(
+                "RPC payload has version {version}, expected {}",
+                Self::PAYLOAD_VERSION
+            )));
+        }
+
+        let value = Self::decode_payload(&mut input)?;
+        if !input.is_empty() {
+            return Err(ConvertError::new(format!(
+                "RPC payload has {} trailing bytes",
+                input.remaining()
+            )));
+        }
+
+        Ok(value)
+    }
+}
+
+/// Cursor over the bytes of an [`RpcPayload`] being decoded.
+pub struct PayloadReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> PayloadReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Consumes the next `len` bytes, or returns an error if fewer are left.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ConvertError> {
+        if len > self.bytes.len() {
+            return Err(ConvertError::new(format!(
+                "RPC payload truncated: needed {len} bytes, {} left",
+                self.bytes.len()
+            )));
+        }
+
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    /// Number of bytes not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn read_len(&mut self) -> Result<usize, ConvertError> {
+        let len = u32::decode_payload(self)?;
+        Ok(len as usize)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Impls for primitives
+
+macro_rules! impl_rpc_payload_number {
+    ($($T:ty),+) => {$(
+        impl RpcPayload for $T {
+            fn encode_payload(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+                let bytes = input.read_bytes(std::mem::size_of::<$T>())?;
+                Ok(<$T>::from_le_bytes(bytes.try_into().expect("length checked")))
+            }
+        }
+    )+};
+}
+
+impl_rpc_payload_number!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl RpcPayload for bool {
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+        match u8::decode_payload(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(ConvertError::new(format!(
+                "RPC payload has invalid bool byte {other}"
+            ))),
+        }
+    }
+}
+
+impl RpcPayload for String {
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        encode_len(self.len(), out);
+        out.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+        let len = input.read_len()?;
+        let bytes = input.read_bytes(len)?;
+
+        String::from_utf8(bytes.to_vec()).map_err(ConvertError::with_error)
+    }
+}
+
+impl RpcPayload for GString {
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        self.to_string().encode_payload(out);
+    }
+
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+        String::decode_payload(input).map(GString::from)
+    }
+}
+
+impl RpcPayload for StringName {
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        self.to_string().encode_payload(out);
+    }
+
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+        String::decode_payload(input).map(|s| StringName::from(s.as_str()))
+    }
+}
+
+impl RpcPayload for PackedByteArray {
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        let bytes = self.as_slice();
+        encode_len(bytes.len(), out);
+        out.extend_from_slice(bytes);
+    }
+
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+        let len = input.read_len()?;
+        input.read_bytes(len).map(PackedByteArray::from)
+    }
+}
+
+impl<T: RpcPayload> RpcPayload for Vec<T> {
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        encode_len(self.len(), out);
+        for element in self {
+            element.encode_payload(out);
+        }
+    }
+
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+        let len = input.read_len()?;
+
+        // Don't trust the length for pre-allocation; each element takes at least one byte (except ZSTs).
+        let mut vec = Vec::with_capacity(len.min(input.remaining()));
+        for _ in 0..len {
+            vec.push(T::decode_payload(input)?);
+        }
+
+        Ok(vec)
+    }
+}
+
+impl<T: RpcPayload> RpcPayload for Option<T> {
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        match self {
+            Some(value) => {
+                out.push(1);
+                value.encode_payload(out);
+            }
+            None => out.push(0),
+        }
+    }
+
+    fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+        match bool::decode_payload(input)? {
+            true => T::decode_payload(input).map(Some),
+            false => Ok(None),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Impls for builtins
+
+macro_rules! impl_rpc_payload_components {
+    ($($T:ty => $Component:ty : [$($field:ident),+];)+) => {$(
+        impl RpcPayload for $T {
+            fn encode_payload(&self, out: &mut Vec<u8>) {
+                $( self.$field.encode_payload(out); )+
+            }
+
+            fn decode_payload(input: &mut PayloadReader<'_>) -> Result<Self, ConvertError> {
+                Ok(Self {
+                    $( $field: <$Component>::decode_payload(input)?, )+
+                })
+            }
+        }
+    )+};
+}
+
+impl_rpc_payload_components! {
+    Vector2 => real: [x, y];
+    Vector3 => real: [x, y, z];
+    Vector4 => real: [x, y, z, w];
+    Vector2i => i32: [x, y];
+    Vector3i => i32: [x, y, z];
+    Vector4i => i32: [x, y, z, w];
+    Quaternion => real: [x, y, z, w];
+    Color => f32: [r, g, b, a];
+}
+
+fn encode_len(len: usize, out: &mut Vec<u8>) {
+    let len = u32::try_from(len).expect("RPC payload collections are limited to u32::MAX elements");
+    len.encode_payload(out);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn roundtrip<T: RpcPayload + PartialEq + std::fmt::Debug>(value: T) {
+        let mut out = Vec::new();
+        value.encode_payload(&mut out);
+
+        let mut input = PayloadReader::new(&out);
+        let decoded = T::decode_payload(&mut input).expect("decode");
+        assert_eq!(decoded, value);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn roundtrip_primitives() {
+        roundtrip(0x1234_5678u32);
+        roundtrip(-5i8);
+        roundtrip(1.5f64);
+        roundtrip(true);
+        roundtrip(String::from("héllo"));
+        roundtrip(vec![1u16, 2, 3]);
+        roundtrip(Some(7i64));
+        roundtrip(Option::<u8>::None);
+        roundtrip(Vector2i::new(-3, 4));
+    }
+
+    #[test]
+    fn decode_errors() {
+        let mut input = PayloadReader::new(&[1, 2]);
+        assert!(u32::decode_payload(&mut input).is_err());
+
+        let mut input = PayloadReader::new(&[2]);
+        assert!(bool::decode_payload(&mut input).is_err());
+
+        // Length prefix larger than the data.
+        let mut input = PayloadReader::new(&[10, 0, 0, 0, b'a']);
+        assert!(String::decode_payload(&mut input).is_err());
+    }
+}
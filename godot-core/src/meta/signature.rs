@@ -13,6 +13,7 @@ use godot_ffi as sys;
 use sys::{BuiltinMethodBind, ClassMethodBind, GodotFfi, UtilityFunctionBind};
 
 use crate::builtin::Variant;
+use crate::meta::args::ArgPtrs;
 use crate::meta::error::{CallError, ConvertError};
 use crate::meta::godot_convert::{into_ffi_variant, try_from_ffi};
 use crate::meta::*;
@@ -43,6 +44,17 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
     ) -> Result<(), CallError>;
 
+    /// Like `in_varcall()`, but for `#[func(vararg)]`: arguments beyond `PARAM_COUNT` are passed to `func` as [`VarArgs`].
+    unsafe fn in_varcall_vararg(
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        call_ctx: &CallContext<'static>,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: i64,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params, VarArgs) -> Self::Ret,
+    ) -> Result<(), CallError>;
+
     unsafe fn out_class_varcall(
         method_bind: ClassMethodBind,
         // Separate parameters to reduce tokens in generated class API.
@@ -91,6 +103,10 @@ pub trait PtrcallSignatureTuple {
     type Params;
     type Ret;
 
+    /// Variant types of parameters and return value, as passed through ptrcalls.
+    #[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+    fn ptrcall_signature() -> sys::ptrcall_check::PtrcallSignature;
+
     // Note: this method imposes extra bounds on GodotFfi, which may not be implemented for user types.
     // We could fall back to varcalls in such cases, and not require GodotFfi categorically.
     unsafe fn in_ptrcall(
@@ -194,6 +210,32 @@ macro_rules! impl_varcall_signature_for_tuple {
                 Ok(())
             }
 
+            #[inline]
+            unsafe fn in_varcall_vararg(
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                call_ctx: &CallContext<'static>,
+                args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: i64,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params, VarArgs) -> Self::Ret,
+            ) -> Result<(), CallError> {
+                //$crate::out!("in_varcall_vararg: {call_ctx}");
+                CallError::check_min_arg_count(call_ctx, arg_count as usize, $PARAM_COUNT)?;
+
+                #[cfg(feature = "trace")]
+                trace::push(true, false, &call_ctx);
+
+                let args = ($(
+                    unsafe { varcall_arg::<$Pn, $n>(args_ptr, call_ctx)? },
+                )*) ;
+                let varargs = VarArgs::from_varcall(args_ptr, arg_count as usize, $PARAM_COUNT, call_ctx);
+
+                let rust_result = func(instance_ptr, args, varargs);
+                varcall_return::<$R>(rust_result, ret, err);
+                Ok(())
+            }
+
             #[inline]
             unsafe fn out_class_varcall(
                 method_bind: ClassMethodBind,
@@ -206,6 +248,8 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varargs: &[Variant],
             ) -> Result<Self::Ret, CallError> {
                 let call_ctx = CallContext::outbound(class_name, method_name);
+                #[cfg(feature = "ffi-stats")]
+                let _timer = sys::ffi_stats::CallTimer::start(class_name, method_name);
                 //$crate::out!("out_class_varcall: {call_ctx}");
 
                 // Note: varcalls are not safe from failing, if they happen through an object pointer -> validity check necessary.
@@ -221,9 +265,10 @@ macro_rules! impl_varcall_signature_for_tuple {
                     )*
                 ];
 
-                let mut variant_ptrs = Vec::with_capacity(explicit_args.len() + varargs.len());
-                variant_ptrs.extend(explicit_args.iter().map(Variant::var_sys));
-                variant_ptrs.extend(varargs.iter().map(Variant::var_sys));
+                let variant_ptrs = ArgPtrs::from_parts(
+                    explicit_args.iter().map(Variant::var_sys),
+                    varargs.iter().map(Variant::var_sys),
+                );
 
                 let variant: Result<Variant, CallError> = Variant::new_with_var_uninit_result(|return_ptr| {
                     let mut err = sys::default_call_error();
@@ -257,6 +302,8 @@ macro_rules! impl_varcall_signature_for_tuple {
                 // Assumes that caller has previously checked existence of a virtual method.
 
                 let call_ctx = CallContext::outbound(class_name, method_name);
+                #[cfg(feature = "ffi-stats")]
+                let _timer = sys::ffi_stats::CallTimer::start(class_name, method_name);
                 //$crate::out!("out_script_virtual_call: {call_ctx}");
 
                 let object_call_script_method = sys::interface_fn!(object_call_script_method);
@@ -266,7 +313,10 @@ macro_rules! impl_varcall_signature_for_tuple {
                     )*
                 ];
 
-                let variant_ptrs = explicit_args.iter().map(Variant::var_sys).collect::<Vec<_>>();
+                let variant_ptrs = ArgPtrs::from_parts(
+                    explicit_args.iter().map(Variant::var_sys),
+                    std::iter::empty(),
+                );
 
                 let variant = Variant::new_with_var_uninit(|return_ptr| {
                     let mut err = sys::default_call_error();
@@ -293,6 +343,8 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varargs: &[Variant],
             ) -> Self::Ret {
                 let call_ctx = CallContext::outbound("", function_name);
+                #[cfg(feature = "ffi-stats")]
+                let _timer = sys::ffi_stats::CallTimer::start("", function_name);
                 //$crate::out!("out_utility_ptrcall_varargs: {call_ctx}");
 
                 let explicit_args: [Variant; $PARAM_COUNT] = [
@@ -301,9 +353,10 @@ macro_rules! impl_varcall_signature_for_tuple {
                     )*
                 ];
 
-                let mut type_ptrs = Vec::with_capacity(explicit_args.len() + varargs.len());
-                type_ptrs.extend(explicit_args.iter().map(sys::GodotFfi::sys));
-                type_ptrs.extend(varargs.iter().map(sys::GodotFfi::sys));
+                let type_ptrs = ArgPtrs::from_parts(
+                    explicit_args.iter().map(sys::GodotFfi::sys),
+                    varargs.iter().map(sys::GodotFfi::sys),
+                );
 
                 // Important: this calls from_sys_init_default().
                 let result = new_from_ptrcall::<Self::Ret>(|return_ptr| {
@@ -322,6 +375,8 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varargs: &[Variant],
             ) -> Self::Ret {
                 let call_ctx = CallContext::outbound(class_name, method_name);
+                #[cfg(feature = "ffi-stats")]
+                let _timer = sys::ffi_stats::CallTimer::start(class_name, method_name);
                 //$crate::out!("out_builtin_ptrcall_varargs: {call_ctx}");
 
                 let explicit_args: [Variant; $PARAM_COUNT] = [
@@ -330,9 +385,10 @@ macro_rules! impl_varcall_signature_for_tuple {
                     )*
                 ];
 
-                let mut type_ptrs = Vec::with_capacity(explicit_args.len() + varargs.len());
-                type_ptrs.extend(explicit_args.iter().map(sys::GodotFfi::sys));
-                type_ptrs.extend(varargs.iter().map(sys::GodotFfi::sys));
+                let type_ptrs = ArgPtrs::from_parts(
+                    explicit_args.iter().map(sys::GodotFfi::sys),
+                    varargs.iter().map(sys::GodotFfi::sys),
+                );
 
                 // Important: this calls from_sys_init_default().
                 let result = new_from_ptrcall::<Self::Ret>(|return_ptr| {
@@ -392,6 +448,14 @@ macro_rules! impl_ptrcall_signature_for_tuple {
             type Params = ($($Pn,)*);
             type Ret = $R;
 
+            #[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+            fn ptrcall_signature() -> sys::ptrcall_check::PtrcallSignature {
+                sys::ptrcall_check::PtrcallSignature {
+                    params: vec![$( ffi_variant_type::<$Pn>(), )*],
+                    ret: ffi_variant_type::<$R>(),
+                }
+            }
+
             #[inline]
             unsafe fn in_ptrcall(
                 instance_ptr: sys::GDExtensionClassInstancePtr,
@@ -427,12 +491,17 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 let call_ctx = CallContext::outbound(class_name, method_name);
+                #[cfg(feature = "ffi-stats")]
+                let _timer = sys::ffi_stats::CallTimer::start(class_name, method_name);
                 // $crate::out!("out_class_ptrcall: {call_ctx}");
 
                 if let Some(instance_id) = maybe_instance_id {
                     crate::classes::ensure_object_alive(instance_id, object_ptr, &call_ctx);
                 }
 
+                #[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+                validate_ptrcall::<Self>(class_name, method_name);
+
                 let class_fn = sys::interface_fn!(object_method_bind_ptrcall);
 
                 marshal_args! {
@@ -461,6 +530,8 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 let call_ctx = CallContext::outbound(class_name, method_name);
+                #[cfg(feature = "ffi-stats")]
+                let _timer = sys::ffi_stats::CallTimer::start(class_name, method_name);
                 // $crate::out!("out_builtin_ptrcall: {call_ctx}");
 
                 marshal_args! {
@@ -486,6 +557,8 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 let call_ctx = CallContext::outbound("", function_name);
+                #[cfg(feature = "ffi-stats")]
+                let _timer = sys::ffi_stats::CallTimer::start("", function_name);
                 // $crate::out!("out_utility_ptrcall: {call_ctx}");
 
                 marshal_args! {
@@ -601,6 +674,73 @@ fn return_error<R>(call_ctx: &CallContext, err: ConvertError) -> ! {
     panic!("in function `{call_ctx}` at return type {return_ty}: {err}");
 }
 
+/// Panics if the ptrcall signature `Sig` doesn't match the engine's metadata for the method.
+#[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+pub(crate) fn validate_ptrcall<Sig: PtrcallSignatureTuple>(class_name: &str, method_name: &str) {
+    sys::ptrcall_check::validate(
+        class_name,
+        method_name,
+        &Sig::ptrcall_signature(),
+        load_class_signatures,
+    );
+}
+
+#[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+fn ffi_variant_type<T: GodotConvert>() -> sys::VariantType {
+    <<T::Via as GodotType>::Ffi as GodotFfi>::variant_type()
+}
+
+/// Signatures of all ptrcall-able methods of `class_name` (including inherited ones), according to ClassDB.
+#[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+fn load_class_signatures(class_name: &str) -> Option<sys::ptrcall_check::ClassSignatures> {
+    use crate::builtin::{Dictionary, GString, StringName, VariantArray};
+    use crate::classes::ClassDb;
+    use crate::global::MethodFlags;
+    use crate::obj::EngineBitfield;
+
+    fn variant_type(info: Option<Variant>) -> Option<sys::VariantType> {
+        let ord = info?
+            .try_to::<Dictionary>()
+            .ok()?
+            .get("type")?
+            .try_to::<i64>()
+            .ok()?;
+        Some(sys::VariantType::from_sys(ord as _))
+    }
+
+    let class_db = ClassDb::singleton();
+    let class_name = StringName::from(class_name);
+    if !class_db.class_exists(&class_name) {
+        return None;
+    }
+
+    let vararg = MethodFlags::VARARG.ord() as i64;
+    let signatures = class_db
+        .class_get_method_list(&class_name)
+        .iter_shared()
+        .filter(|method| {
+            // Vararg methods go through varcalls.
+            let flags = method.get_or_nil("flags").try_to::<i64>().unwrap_or(0);
+            flags & vararg == 0
+        })
+        .filter_map(|method| {
+            let name = method.get("name")?.try_to::<GString>().ok()?.to_string();
+            let params = method
+                .get("args")?
+                .try_to::<VariantArray>()
+                .ok()?
+                .iter_shared()
+                .map(|arg| variant_type(Some(arg)))
+                .collect::<Option<Vec<_>>>()?;
+            let ret = variant_type(method.get("return"))?;
+
+            Some((name, sys::ptrcall_check::PtrcallSignature { params, ret }))
+        })
+        .collect();
+
+    Some(signatures)
+}
+
 unsafe fn new_from_ptrcall<T: FromGodot>(
     process_return_ptr: impl FnOnce(sys::GDExtensionTypePtr),
 ) -> Result<T, ConvertError> {
@@ -683,6 +823,14 @@ impl<'a> CallContext<'a> {
         }
     }
 
+    /// Dynamic call from Rust, dispatched on the runtime class of an object.
+    pub fn dynamic(class_name: String, function_name: &'a str) -> Self {
+        Self {
+            class_name: Cow::Owned(class_name),
+            function_name,
+        }
+    }
+
     /// Outbound call from Rust into the engine, via Gd methods.
     pub fn gd<T: GodotClass>(function_name: &'a str) -> Self {
         Self {
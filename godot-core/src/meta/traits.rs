@@ -7,7 +7,7 @@
 
 use crate::builtin::{Variant, VariantType};
 use crate::global::PropertyUsageFlags;
-use crate::meta::error::ConvertError;
+use crate::meta::error::{ConvertError, FromFfiError};
 use crate::meta::{
     sealed, ClassName, FromGodot, GodotConvert, PropertyHintInfo, PropertyInfo, ToGodot,
 };
@@ -212,17 +212,55 @@ pub trait PackedArrayElement: GodotType + sealed::Sealed {
     fn element_type_string() -> String {
         builtin_type_string::<Self>()
     }
+
+    /// Converts all elements of `array` in one engine call. See [`VariantArray::try_bulk_to()`][builtin::VariantArray::try_bulk_to].
+    ///
+    /// The caller must have verified that every element has the variant type of `Self::Ffi`; otherwise, Godot converts leniently.
+    #[doc(hidden)]
+    fn bulk_from_variant_array(
+        array: &builtin::VariantArray,
+    ) -> Result<Vec<Self>, (usize, ConvertError)>;
+}
+
+macro_rules! impl_packed_array_element {
+    // Element type is stored as-is in the corresponding packed array.
+    ($T:ty => $PackedArray:ty) => {
+        impl PackedArrayElement for $T {
+            fn bulk_from_variant_array(
+                array: &builtin::VariantArray,
+            ) -> Result<Vec<Self>, (usize, ConvertError)> {
+                Ok(<$PackedArray>::from(array).to_vec())
+            }
+        }
+    };
+
+    // Narrower integer: Godot's own conversion would silently truncate, so go through i64 and validate the range.
+    ($T:ty => int, $err:path) => {
+        impl PackedArrayElement for $T {
+            fn bulk_from_variant_array(
+                array: &builtin::VariantArray,
+            ) -> Result<Vec<Self>, (usize, ConvertError)> {
+                let ints = builtin::PackedInt64Array::from(array);
+
+                ints.as_slice()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &int)| <$T>::try_from(int).map_err(|_| (i, $err.into_error(int))))
+                    .collect()
+            }
+        }
+    };
 }
 
 // Implement all packed array element types.
-impl PackedArrayElement for u8 {}
-impl PackedArrayElement for i32 {}
-impl PackedArrayElement for i64 {}
-impl PackedArrayElement for f32 {}
-impl PackedArrayElement for f64 {}
-impl PackedArrayElement for builtin::Vector2 {}
-impl PackedArrayElement for builtin::Vector3 {}
+impl_packed_array_element!(u8 => int, FromFfiError::U8);
+impl_packed_array_element!(i32 => int, FromFfiError::I32);
+impl_packed_array_element!(i64 => builtin::PackedInt64Array);
+impl_packed_array_element!(f32 => builtin::PackedFloat32Array);
+impl_packed_array_element!(f64 => builtin::PackedFloat64Array);
+impl_packed_array_element!(builtin::Vector2 => builtin::PackedVector2Array);
+impl_packed_array_element!(builtin::Vector3 => builtin::PackedVector3Array);
 #[cfg(since_api = "4.3")]
-impl PackedArrayElement for builtin::Vector4 {}
-impl PackedArrayElement for builtin::Color {}
-impl PackedArrayElement for builtin::GString {}
+impl_packed_array_element!(builtin::Vector4 => builtin::PackedVector4Array);
+impl_packed_array_element!(builtin::Color => builtin::PackedColorArray);
+impl_packed_array_element!(builtin::GString => builtin::PackedStringArray);
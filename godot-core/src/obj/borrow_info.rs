@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::panic::Location;
+
+use crate::meta::ClassName;
+
+/// Describes a live borrow of a user instance, for diagnosing re-entrancy errors.
+///
+/// Borrows are recorded in debug builds only. They originate either from an explicit [`Gd::bind()`][crate::obj::Gd::bind] or
+/// [`Gd::bind_mut()`][crate::obj::Gd::bind_mut] call, or implicitly from Godot calling a `#[func]` or virtual method taking `&self` or
+/// `&mut self`. In the latter case, [`method()`][Self::method] and [`class_name()`][Self::class_name] name that method.
+///
+/// When several borrows are nested (e.g. a `&mut self` method calling back into itself through `base_mut()`), the innermost one that is
+/// still live is reported.
+///
+/// Obtained through [`Gd::borrow_info()`][crate::obj::Gd::borrow_info] or [`BindError::held_by()`][crate::meta::error::BindError::held_by].
+#[derive(Copy, Clone, Debug)]
+pub struct BorrowInfo {
+    mutable: bool,
+    method: Option<(ClassName, &'static str)>,
+    location: &'static Location<'static>,
+}
+
+impl BorrowInfo {
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    pub(crate) fn new(mutable: bool, location: &'static Location<'static>) -> Self {
+        Self {
+            mutable,
+            method: None,
+            location,
+        }
+    }
+
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    pub(crate) fn set_method(&mut self, class_name: ClassName, method: &'static str) {
+        self.method = Some((class_name, method));
+    }
+
+    /// Whether the borrow is exclusive (`bind_mut()`, `&mut self`) rather than shared (`bind()`, `&self`).
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+
+    /// Name of the method that Godot called, if the borrow was made on behalf of a `#[func]`, virtual method or engine callback (such as
+    /// `on_notification` or `get_property`).
+    pub fn method(&self) -> Option<&'static str> {
+        self.method.map(|(_, method)| method)
+    }
+
+    /// Class declaring [`method()`][Self::method], if the borrow was made on behalf of a method.
+    pub fn class_name(&self) -> Option<ClassName> {
+        self.method.map(|(class_name, _)| class_name)
+    }
+
+    /// Source location where the borrow was taken.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl fmt::Display for BorrowInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.mutable { "exclusive" } else { "shared" };
+
+        match self.method {
+            Some((class_name, method)) => write!(f, "{kind} borrow by `{class_name}::{method}()`"),
+            None => write!(f, "{kind} borrow at {}", self.location),
+        }
+    }
+}
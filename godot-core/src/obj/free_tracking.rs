@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Debug-mode record of where objects were freed from Rust, to point double frees back to the original call.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::panic::Location;
+
+use godot_ffi::Global;
+
+use crate::obj::InstanceId;
+
+/// Upper bound on remembered call-sites; the oldest entries are evicted first.
+const MAX_TRACKED: usize = 4096;
+
+static FREE_LOG: Global<FreeLog> = Global::default();
+
+#[derive(Default)]
+struct FreeLog {
+    sites: HashMap<InstanceId, FreeSite>,
+    order: VecDeque<InstanceId>,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum FreeKind {
+    Free,
+    QueueFree,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct FreeSite {
+    kind: FreeKind,
+    location: &'static Location<'static>,
+}
+
+impl fmt::Display for FreeSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            FreeKind::Free => write!(f, "freed at {}", self.location),
+            FreeKind::QueueFree => write!(f, "queued for deletion at {}", self.location),
+        }
+    }
+}
+
+pub(crate) fn record(id: InstanceId, kind: FreeKind, location: &'static Location<'static>) {
+    let mut log = FREE_LOG.lock();
+
+    if log.sites.insert(id, FreeSite { kind, location }).is_none() {
+        log.order.push_back(id);
+    }
+
+    if log.order.len() > MAX_TRACKED {
+        if let Some(oldest) = log.order.pop_front() {
+            log.sites.remove(&oldest);
+        }
+    }
+}
+
+fn lookup(id: InstanceId) -> Option<FreeSite> {
+    FREE_LOG.lock().sites.get(&id).copied()
+}
+
+/// Describes how the object `id` was destroyed, for appending to an error message.
+pub(crate) fn describe(id: InstanceId) -> String {
+    match lookup(id) {
+        Some(site) => format!("\n  Object was previously {site}."),
+        None => "\n  Object was not freed via free() or queue_free_checked(); possibly by Godot or another language.".to_string(),
+    }
+}
+
+pub(crate) fn clear() {
+    let mut log = FREE_LOG.lock();
+    log.sites.clear();
+    log.order.clear();
+}
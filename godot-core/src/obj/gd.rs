@@ -11,16 +11,21 @@ use std::ops::{Deref, DerefMut};
 use godot_ffi as sys;
 use sys::{static_assert_eq_size_align, SysPtr as _, VariantType};
 
-use crate::builtin::{Callable, NodePath, StringName, Variant};
+use crate::builtin::{Callable, GString, NodePath, Signal, SignalConnection, StringName, Variant};
 use crate::global::PropertyHint;
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{BindError, CallError, ConvertError, FromFfiError};
+use crate::meta::static_string_name;
 use crate::meta::{
-    ArrayElement, AsArg, CallContext, ClassName, CowArg, FromGodot, GodotConvert, GodotType,
-    ParamType, PropertyHintInfo, RefArg, ToGodot,
+    arg_into_ref, ArrayElement, AsArg, CallContext, ClassName, CowArg, FromGodot, GodotConvert,
+    GodotType, ParamType, PropertyHintInfo, RefArg, ToGodot,
 };
+#[cfg(debug_assertions)]
+use crate::obj::free_tracking;
+#[cfg(debug_assertions)]
+use crate::obj::BorrowInfo;
 use crate::obj::{
-    bounds, cap, Bounds, DynGd, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits,
-    InstanceId, RawGd,
+    bounds, cap, Bounds, DynGd, EngineEnum, GdBuilder, GdDerefTarget, GdMut, GdRef, GodotClass,
+    Inherits, InstanceId, RawGd,
 };
 use crate::private::callbacks;
 use crate::registry::property::{Export, Var};
@@ -68,6 +73,8 @@ use crate::{classes, out};
 ///
 /// * [`Gd::default()`] for reference-counted types that are constructible. For user types, this means they must expose an `init` function
 ///   or have a generated one. `Gd::<T>::default()` is equivalent to the shorter `T::new_gd()` and primarily useful for derives or generics.
+/// * [`Gd::new_with(init)`][Gd::new_with] and [`Gd::builder()`][Gd::builder] for default-constructible types, which are initialized
+///   (e.g. by setting properties) before being returned.
 /// * [`Gd::from_init_fn(function)`][Gd::from_init_fn] for Rust objects with `Base<T>` field, which are constructed inside the smart pointer.
 ///   This is a very handy function if you want to pass extra parameters to your object upon construction.
 /// * [`Gd::from_object(rust_obj)`][Gd::from_object] for existing Rust objects without a `Base<T>` field that are moved _into_ the smart pointer.
@@ -87,6 +94,10 @@ use crate::{classes, out};
 /// on the owning `Gd<T>` is performed. This is important to keep in mind, as you can get into situations that violate dynamic borrow rules; for
 /// example if you are inside a `&mut self` method, make a call to GDScript and indirectly call another method on the same object (re-entrancy).
 ///
+/// To handle such situations without panicking, [`try_bind()`][Self::try_bind] and [`try_bind_mut()`][Self::try_bind_mut] return an error
+/// instead, and [`is_bound()`][Self::is_bound] checks whether a bind is currently held. In debug builds, [`borrow_info()`][Self::borrow_info]
+/// tells which method holds it; bind panics include the same information.
+///
 /// # Conversions
 ///
 /// For type conversions, please read the [`godot::meta` module docs][crate::meta].
@@ -142,6 +153,8 @@ where
     where
         F: FnOnce(crate::obj::Base<T::Base>) -> T,
     {
+        ensure_not_abstract::<T>();
+
         let object_ptr = callbacks::create_custom(init);
         unsafe { Gd::from_obj_sys(object_ptr) }
     }
@@ -168,6 +181,7 @@ where
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub fn bind(&self) -> GdRef<T> {
         self.raw.bind()
     }
@@ -185,9 +199,59 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdRef` or `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&T` or `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    #[track_caller]
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Like [`bind()`][Self::bind], but returns an error instead of panicking if the user instance is bound exclusively.
+    ///
+    /// Useful in code that may run re-entrantly, e.g. signal handlers, to skip work instead of panicking when an outer method still
+    /// holds `&mut self`.
+    #[track_caller]
+    pub fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.raw.try_bind()
+    }
+
+    /// Like [`bind_mut()`][Self::bind_mut], but returns an error instead of panicking if the user instance is bound.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// #[derive(GodotClass)]
+    /// #[class(init, base=Node)]
+    /// struct Inventory {
+    ///     items: Vec<GString>,
+    /// }
+    ///
+    /// fn on_item_picked(mut inventory: Gd<Inventory>, item: GString) {
+    ///     match inventory.try_bind_mut() {
+    ///         Ok(mut inventory) => inventory.items.push(item),
+    ///         Err(err) => godot_warn!("dropping item {item}: {err}"),
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.raw.try_bind_mut()
+    }
+
+    /// Whether the user instance is currently bound, through a guard or a running `&self`/`&mut self` method.
+    ///
+    /// If this returns `false`, a subsequent [`bind_mut()`][Self::bind_mut] on the same thread succeeds.
+    pub fn is_bound(&self) -> bool {
+        self.raw.is_bound()
+    }
+
+    /// Describes the most recent borrow of the user instance, if it is currently bound.
+    ///
+    /// Names the `#[func]` or virtual method holding the borrow, or the source location of the `bind()`/`bind_mut()` call. Only available
+    /// in debug builds.
+    #[cfg(debug_assertions)]
+    pub fn borrow_info(&self) -> Option<BorrowInfo> {
+        self.raw.borrow_info()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -277,6 +341,17 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Explains how a dead object was destroyed, if known. Appended to error messages.
+    #[cfg(debug_assertions)]
+    fn free_history(&self) -> String {
+        free_tracking::describe(self.instance_id_unchecked())
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn free_history(&self) -> String {
+        String::new()
+    }
+
     /// Returns the dynamic class name of the object as `StringName`.
     ///
     /// This method retrieves the class name of the object at runtime, which can be different from [`T::class_name()`] if derived
@@ -405,6 +480,40 @@ impl<T: GodotClass> Gd<T> {
         unsafe { self.raw.as_upcast_mut::<Base>() }
     }
 
+    /// **Upcast smart-pointer-ref:** access this object as a shared reference to `Gd<Base>`, without cloning.
+    ///
+    /// Unlike [`upcast()`][Self::upcast], this neither consumes `self` nor touches the reference count, which makes it suitable for
+    /// passing objects to functions expecting `&Gd<Base>` in hot code:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn register(node: &Gd<Node>) {
+    ///     // ...
+    /// }
+    ///
+    /// let sprite: Gd<Sprite2D> = Sprite2D::new_alloc();
+    /// register(sprite.upcast_gd_ref());
+    /// ```
+    ///
+    /// If you need access to the base class' methods instead, [`upcast_ref()`][Self::upcast_ref] is usually more convenient.
+    ///
+    /// There are deliberately no `&mut` or downcast counterparts:
+    /// - Through `&mut Gd<Base>`, another object could be assigned that is not a `T`.
+    /// - A `Gd` caches the class it was created with, and Debug builds check on each access that this class inherits the static type. A
+    ///   borrowed `&Gd<Derived>` would keep the cache of `T`, so the check would fail even if the object's dynamic type is `Derived`.
+    ///   Updating the cache requires a new `Gd`, so use [`try_cast()`][Self::try_cast] on a clone for downcasts.
+    pub fn upcast_gd_ref<Base>(&self) -> &Gd<Base>
+    where
+        Base: GodotClass + Bounds<Declarer = bounds::DeclEngine>,
+        T: Inherits<Base>,
+    {
+        // SAFETY: `Gd<T>` and `RawGd<T>` are `#[repr(C)]`, with the object pointer and RTTI as the first fields. For engine classes, the
+        // instance cache is a ZST, so `Gd<Base>` is a layout prefix of `Gd<T>` (for user classes, `T` additionally caches the storage pointer).
+        // The pointer is valid for `Base`, as Godot class casts are identity casts on the object pointer (same assumption as `upcast_ref()`).
+        // The cached RTTI refers to `T`, which inherits `Base`, so runtime type checks for `Gd<Base>` succeed.
+        // Since only a shared reference is handed out, the object cannot be replaced with one that is not a `T`.
+        unsafe { &*(self as *const Self).cast::<Gd<Base>>() }
+    }
+
     /// **Downcast:** try to convert into a smart pointer to a derived class.
     ///
     /// If `T`'s dynamic type is not `Derived` or one of its subclasses, `Err(self)` is returned, meaning you can reuse the original
@@ -453,6 +562,8 @@ impl<T: GodotClass> Gd<T> {
     where
         T: cap::GodotDefault,
     {
+        ensure_not_abstract::<T>();
+
         unsafe {
             // Default value (and compat one) for `p_notify_postinitialize` is true in Godot.
             #[cfg(since_api = "4.4")]
@@ -547,6 +658,39 @@ impl<T: GodotClass> Gd<T> {
     }
 }
 
+/// _The methods in this impl block are only available for default-constructible `T`._ <br><br>
+impl<T> Gd<T>
+where
+    T: GodotClass + cap::GodotDefault,
+{
+    /// Constructs a default instance and runs `init` on it, before returning it.
+    ///
+    /// Shorthand for `Gd::builder().with(init).build()`, see [`builder()`][Self::builder]. As with `new_alloc()`, manually-managed
+    /// objects must be freed or handed over to an owner (e.g. the scene tree).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let node = Gd::<Node3D>::new_with(|node| {
+    ///     node.set_name("Player");
+    ///     node.set_position(Vector3::new(1.0, 0.0, 2.0));
+    /// });
+    /// ```
+    #[must_use]
+    pub fn new_with(init: impl FnOnce(&mut Gd<T>)) -> Self {
+        Self::builder().with(init).build()
+    }
+
+    /// Constructs a default instance, and returns a builder to initialize its properties before handing it out.
+    ///
+    /// See [`GdBuilder`] for details.
+    pub fn builder() -> GdBuilder<T> {
+        use bounds::Declarer as _;
+
+        GdBuilder::new(<T as Bounds>::Declarer::create_gd())
+    }
+}
+
 /// _The methods in this impl block are only available for objects `T` that are manually managed,
 /// i.e. anything that is not `RefCounted` or inherited from it._ <br><br>
 impl<T> Gd<T>
@@ -563,10 +707,17 @@ where
     /// Not calling `free()` on manually-managed instances causes memory leaks, unless their ownership is delegated, for
     /// example to the node tree in case of nodes.
     ///
+    /// Nodes inside the scene tree should usually not be freed directly: the tree may still be processing them, e.g. when `free()` is
+    /// called from one of their signals. Use [`queue_free_checked()`][Self::queue_free_checked] instead, which destroys the node at the
+    /// end of the frame.
+    ///
+    /// In debug builds, the call-site of each `free()` is remembered. Freeing the same object again panics and names the original call.
+    ///
     /// # Panics
     /// - When the referred-to object has already been destroyed.
     /// - When this is invoked on an upcast `Gd<Object>` that dynamically points to a reference-counted type (i.e. operation not supported).
     /// - When the object is bound by an ongoing `bind()` or `bind_mut()` call (through a separate `Gd` pointer).
+    #[track_caller]
     pub fn free(self) {
         // Note: this method is NOT invoked when the free() call happens dynamically (e.g. through GDScript or reflection).
         // As such, do not use it for operations and validations to perform upon destruction.
@@ -601,7 +752,10 @@ where
 
         // If ref_counted returned None, that means the instance was destroyed
         if ref_counted != Some(false) || !self.is_instance_valid() {
-            return error_or_panic("called free() on already destroyed object".to_string());
+            return error_or_panic(format!(
+                "called free() on already destroyed object{}",
+                self.free_history()
+            ));
         }
 
         // If the object is still alive, make sure the dynamic type matches. Necessary because subsequent checks may rely on the
@@ -622,17 +776,104 @@ where
             );
         }
 
+        #[cfg(debug_assertions)]
+        let instance_id = self.instance_id_unchecked();
+
         // SAFETY: object alive as checked.
         // This destroys the Storage instance, no need to run destructor again.
         unsafe {
             sys::interface_fn!(object_destroy)(self.raw.obj_sys());
         }
 
+        #[cfg(debug_assertions)]
+        free_tracking::record(
+            instance_id,
+            free_tracking::FreeKind::Free,
+            std::panic::Location::caller(),
+        );
+
         // TODO: this might leak associated data in Gd<T>, e.g. ClassName.
         std::mem::forget(self);
     }
 }
 
+/// _The methods in this impl block are only available for objects `T` that inherit `Node`._ <br><br>
+impl<T> Gd<T>
+where
+    T: Inherits<classes::Node>,
+{
+    /// Schedules the node for deletion at the end of the current frame, after validating that this will succeed.
+    ///
+    /// This is the recommended way to destroy nodes that are part of the scene tree. Unlike `Node::queue_free()`, which silently does
+    /// nothing in some situations, this method checks that:
+    /// - the node is still alive (panics otherwise, naming the call-site that freed it in debug builds),
+    /// - a `SceneTree` exists to perform the deletion, if the node is not inside one.
+    ///
+    /// Calling it again on a node that is already queued for deletion is a no-op.
+    ///
+    /// Other `Gd` pointers to the node stay valid until the end of the frame; use [`is_instance_valid()`][Self::is_instance_valid] if you
+    /// need to check afterward. For nodes that were never added to the tree, [`free()`][Self::free] destroys them immediately.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// #[derive(GodotClass)]
+    /// #[class(init, base=Node)]
+    /// struct Coin {
+    ///     base: Base<Node>,
+    /// }
+    ///
+    /// #[godot_api]
+    /// impl Coin {
+    ///     // Connected to a signal, e.g. `body_entered` of a sibling area.
+    ///     #[func]
+    ///     fn collect(&mut self) {
+    ///         // Calling free() here could destroy the node while the tree is still notifying it.
+    ///         self.to_gd().queue_free_checked();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// - When the node has already been destroyed.
+    /// - When the node is not inside the scene tree and no `SceneTree` main loop is running.
+    #[track_caller]
+    pub fn queue_free_checked(&mut self) {
+        if !self.is_instance_valid() {
+            panic!(
+                "called queue_free_checked() on already destroyed node{}",
+                self.free_history()
+            );
+        }
+
+        let node = self.upcast_ref::<classes::Node>();
+        if node.is_queued_for_deletion() {
+            return;
+        }
+
+        if !node.is_inside_tree() {
+            let has_scene_tree = classes::Engine::singleton()
+                .get_main_loop()
+                .is_some_and(|main_loop| main_loop.is_class("SceneTree"));
+
+            assert!(
+                has_scene_tree,
+                "called queue_free_checked() on node outside the scene tree, while no SceneTree is running to delete it; \
+                use free() instead\n  Node: {self:?}"
+            );
+        }
+
+        self.upcast_mut::<classes::Node>().queue_free();
+
+        #[cfg(debug_assertions)]
+        free_tracking::record(
+            self.instance_id_unchecked(),
+            free_tracking::FreeKind::QueueFree,
+            std::panic::Location::caller(),
+        );
+    }
+}
+
 /// _The methods in this impl block are only available for objects `T` that are reference-counted,
 /// i.e. anything that inherits `RefCounted`._ <br><br>
 impl<T> Gd<T>
@@ -700,6 +941,288 @@ where
     }
 }
 
+/// _The methods in this impl block deal with scripts attached to the object._ <br><br>
+impl<T> Gd<T>
+where
+    T: Inherits<classes::Object>,
+{
+    /// Returns the script attached to this object, if any.
+    ///
+    /// Typed shorthand for `Object::get_script()`, which returns a `Variant`.
+    pub fn script(&self) -> Option<Gd<classes::Script>> {
+        self.script_as::<classes::Script>()
+    }
+
+    /// Returns the attached script, if there is one and it has type `S` (or inherited).
+    ///
+    /// Useful to differentiate between scripting languages, e.g. `obj.script_as::<GDScript>()`.
+    pub fn script_as<S>(&self) -> Option<Gd<S>>
+    where
+        S: Inherits<classes::Script>,
+    {
+        let object = self.upcast_ref::<classes::Object>();
+
+        object
+            .get_script()
+            .try_to::<Option<Gd<classes::Script>>>()
+            .ok()
+            .flatten()
+            .and_then(|script| script.try_cast::<S>().ok())
+    }
+
+    /// Attaches `script` to this object, replacing any previously attached script.
+    ///
+    /// The object holds a strong reference to the script; it stays alive as long as it is attached, even if all `Gd` pointers in Rust
+    /// are dropped. Note that Godot re-creates the script instance when attaching, which resets the values of script-declared variables.
+    ///
+    /// Typed shorthand for `Object::set_script()`, which takes a `Variant`.
+    pub fn attach_script<S>(&mut self, script: &Gd<S>)
+    where
+        S: Inherits<classes::Script>,
+    {
+        let object = self.upcast_mut::<classes::Object>();
+        object.set_script(&script.to_variant());
+    }
+
+    /// Removes the script from this object, if any. Returns the previously attached script.
+    ///
+    /// If no other references to the script exist, it is freed once the returned value is dropped.
+    pub fn detach_script(&mut self) -> Option<Gd<classes::Script>> {
+        let previous = self.script();
+
+        let object = self.upcast_mut::<classes::Object>();
+        object.set_script(&Variant::nil());
+
+        previous
+    }
+
+    /// Returns `true` if a script is attached, and that script (or one of its base scripts) declares `method`.
+    ///
+    /// Unlike `Object::has_method()`, this does not consider methods of the object's class itself. It reflects the script's current
+    /// state: after editing and reloading a script (e.g. through hot-reload), newly added methods are recognized.
+    pub fn has_script_method(&self, method: impl AsArg<StringName>) -> bool {
+        arg_into_ref!(method);
+
+        // Not script.has_method(), which would look up methods of the Script class itself (e.g. reload()).
+        // Deref explicitly: `script.has_script_method()` would resolve to this very helper on `Gd<Script>`, asking for the script's script.
+        self.script()
+            .is_some_and(|script| (*script).has_script_method(method))
+    }
+}
+
+/// _The methods in this impl block inspect signal connections of the object._ <br><br>
+impl<T> Gd<T>
+where
+    T: Inherits<classes::Object>,
+{
+    /// Returns the connections of this object's `signal`, in the order in which Godot invokes them on emission.
+    ///
+    /// Typed alternative to `Object::get_signal_connection_list()`.
+    pub fn signal_connections(&self, signal: impl AsArg<StringName>) -> Vec<SignalConnection> {
+        Signal::from_object_signal(self, signal).connection_list()
+    }
+
+    /// Returns `true` if this object's `signal` is connected to `method` on `target`, regardless of bound arguments.
+    ///
+    /// See [`Signal::is_connected_to()`].
+    pub fn is_connected_to<U>(
+        &self,
+        signal: impl AsArg<StringName>,
+        target: &Gd<U>,
+        method: impl AsArg<StringName>,
+    ) -> bool
+    where
+        U: Inherits<classes::Object>,
+    {
+        Signal::from_object_signal(self, signal).is_connected_to(target, method)
+    }
+}
+
+/// _The methods in this impl block access properties by name._ <br><br>
+impl<T> Gd<T>
+where
+    T: Inherits<classes::Object>,
+{
+    /// Returns the value of `property`, reading `#[var]` properties of user classes directly.
+    ///
+    /// If `T` is the user class declaring `property` as `#[var]`, its getter is called directly: the property is looked up in a table
+    /// owned by `T`, which is filled on first use and not locked afterward. Otherwise (engine properties, script properties, `T` being a
+    /// base class), this falls back to `Object::get()`. This makes it suitable for data-binding layers that access many properties.
+    ///
+    /// # Bypassed hooks
+    /// On the direct path, Godot is not involved at all. Compared to `Object::get()`, the following are therefore **not** consulted:
+    /// - An attached script: its `_get()` override and a script variable of the same name, which would otherwise take precedence.
+    /// - The class's own `get_property()` virtual function (Godot's `_get()`), which Godot calls before looking up registered properties.
+    ///
+    /// If an object relies on a script or `get_property()`/`set_property()` to intercept `#[var]` properties, use `Object::get()` and
+    /// `Object::set()` instead.
+    ///
+    /// Only calls through this method (and [`set_fast()`][Self::set_fast]) take the direct path. Accesses from Godot, e.g. `Object::get()`
+    /// or GDScript's `obj.property`, still go through ClassDB's lookup by name.
+    ///
+    /// Returns nil if the property doesn't exist.
+    ///
+    /// # Panics
+    /// If the user instance is already bound mutably, see [`bind()`][Self::bind].
+    pub fn get_fast(&self, property: impl AsArg<StringName>) -> Variant {
+        arg_into_ref!(property);
+
+        let getter = T::__property_table()
+            .and_then(|table| table.get(property))
+            .and_then(|accessors| accessors.getter);
+
+        match getter {
+            Some(getter) => getter(self),
+            None => self.upcast_ref::<classes::Object>().get(property),
+        }
+    }
+
+    /// Sets `property` to `value`, writing `#[var]` properties of user classes directly.
+    ///
+    /// Counterpart to [`get_fast()`][Self::get_fast]; falls back to `Object::set()` in the same cases.
+    ///
+    /// On the direct path, an attached script's `_set()`, script variables and the class's own `set_property()` are bypassed, see
+    /// [bypassed hooks][Self::get_fast#bypassed-hooks]. The engine is not notified either: no `property_list_changed` or editor
+    /// update is triggered beyond what the `#[var]` setter itself does.
+    ///
+    /// # Panics
+    /// - If the user instance is already bound, see [`bind_mut()`][Self::bind_mut].
+    /// - If `value` cannot be converted to the type of a user-declared `#[var]` property. `Object::set()` instead ignores such values.
+    pub fn set_fast(&mut self, property: impl AsArg<StringName>, value: &Variant) {
+        arg_into_ref!(property);
+
+        let setter = T::__property_table()
+            .and_then(|table| table.get(property))
+            .and_then(|accessors| accessors.setter);
+
+        match setter {
+            Some(setter) => setter(self, value).unwrap_or_else(|err| {
+                panic!(
+                    "set_fast(): cannot set property {}::{property}: {err}",
+                    T::class_name()
+                )
+            }),
+            None => self.upcast_mut::<classes::Object>().set(property, value),
+        }
+    }
+}
+
+/// _The methods in this impl block call methods dynamically._ <br><br>
+impl<T> Gd<T>
+where
+    T: Inherits<classes::Object>,
+{
+    /// Calls `method` on the object's runtime class, and converts the return value to `R`.
+    ///
+    /// The method is looked up on the most-derived class of the object, not on `T`. This allows calling `#[func]` methods of user classes
+    /// through base pointers, without trying casts to each candidate type -- useful for heterogeneous collections such as a node's
+    /// children. Methods of attached scripts are found as well.
+    ///
+    /// Compared to `Object::try_call()`, this returns a typed value, and reports a missing method with the runtime class name.
+    ///
+    /// # Errors
+    /// If the runtime class has no method `method`, if the call fails (e.g. due to mismatched arguments), or if the return value cannot be
+    /// converted to `R`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// fn total_damage(enemies: &[Gd<Node>]) -> i64 {
+    ///     enemies
+    ///         .iter()
+    ///         .map(|enemy| enemy.clone().dyn_call::<i64>("damage", &[]).unwrap_or(0))
+    ///         .sum()
+    /// }
+    /// ```
+    pub fn dyn_call<R: FromGodot>(
+        &mut self,
+        method: impl AsArg<StringName>,
+        args: &[Variant],
+    ) -> Result<R, CallError> {
+        arg_into_ref!(method);
+
+        let class_name = self.dynamic_class_string().to_string();
+        let method_name = method.to_string();
+        let call_ctx = CallContext::dynamic(class_name, &method_name);
+
+        let object = self.upcast_mut::<classes::Object>();
+        if !object.has_method(method) {
+            return Err(CallError::failed_method_not_found(&call_ctx));
+        }
+
+        let result = object.try_call(method, args)?;
+        result
+            .try_to::<R>()
+            .map_err(|err| CallError::failed_return_conversion::<R>(&call_ctx, err))
+    }
+
+    /// Calls `method` if the object has it, or returns `Ok(None)` otherwise.
+    ///
+    /// For optional hooks on objects of unknown type, e.g. duck-typed scripts. The method name is converted to `StringName` only once
+    /// per string literal, see [`has_method_fast()`][Self::has_method_fast].
+    ///
+    /// # Errors
+    /// If the method exists, but the call fails (e.g. due to mismatched arguments).
+    pub fn try_call_if_exists(
+        &mut self,
+        method: &'static str,
+        args: &[Variant],
+    ) -> Result<Option<Variant>, CallError> {
+        let method = static_string_name(method);
+
+        let object = self.upcast_mut::<classes::Object>();
+        if !object.has_method(&method) {
+            return Ok(None);
+        }
+
+        object.try_call(&method, args).map(Some)
+    }
+
+    /// Whether the object has a method named `method`, including script methods.
+    ///
+    /// Equivalent to `Object::has_method()`, but the `StringName` for each `&'static str` is created once and then looked up by address,
+    /// making repeated capability checks cheap.
+    pub fn has_method_fast(&self, method: &'static str) -> bool {
+        let method = static_string_name(method);
+        self.upcast_ref::<classes::Object>().has_method(&method)
+    }
+
+    /// Whether the object has a signal named `signal`, including script and user signals.
+    ///
+    /// Equivalent to `Object::has_signal()`; see [`has_method_fast()`][Self::has_method_fast] for caching.
+    pub fn has_signal_fast(&self, signal: &'static str) -> bool {
+        let signal = static_string_name(signal);
+        self.upcast_ref::<classes::Object>().has_signal(&signal)
+    }
+
+    /// Whether the object has a property named `property`.
+    ///
+    /// If `T` is a user class declaring `property` as `#[var]`, the property is found in the accessor table of
+    /// [`get_fast()`][Self::get_fast] without querying Godot. Otherwise, `ClassDB.class_has_property()` is asked for the object's dynamic
+    /// class, which is a hash lookup in Godot. Only if that fails, the (allocating) `Object::get_property_list()` is searched, to also find
+    /// script variables and dynamically provided properties (e.g. from a `get_property_list()` override). Checks for missing properties
+    /// are thus not faster than with `get_property_list()`.
+    pub fn has_property_fast(&self, property: &'static str) -> bool {
+        let property = static_string_name(property);
+        if T::__property_table().is_some_and(|table| table.get(&property).is_some()) {
+            return true;
+        }
+
+        let class = self.dynamic_class_string();
+        if classes::ClassDb::singleton().class_has_property(&class, &property) {
+            return true;
+        }
+
+        let property = GString::from(&property);
+        self.upcast_ref::<classes::Object>()
+            .get_property_list()
+            .iter_shared()
+            .filter_map(|info| info.get("name")?.try_to::<GString>().ok())
+            .any(|name| name == property)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait impls
 
@@ -973,3 +1496,16 @@ impl<T: GodotClass> std::hash::Hash for Gd<T> {
 // its mutability is anyway present, in the Godot engine.
 impl<T: GodotClass> std::panic::UnwindSafe for Gd<T> {}
 impl<T: GodotClass> std::panic::RefUnwindSafe for Gd<T> {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Classes declared with `#[class(abstract)]` can only be instantiated through scripts extending them.
+fn ensure_not_abstract<T: GodotClass>() {
+    if T::__IS_ABSTRACT {
+        panic!(
+            "cannot instantiate abstract class {}; instantiate a script extending it instead",
+            T::class_name()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GString, StringName, Variant, VariantType};
+use crate::meta::{arg_into_owned, AsArg, ToGodot};
+use crate::obj::{EngineEnum, Gd, GodotClass};
+
+/// Builder for a new object, setting properties before the object is handed out.
+///
+/// Obtained through [`Gd::builder()`]. The object is constructed right away (including `init` for user classes), and properties are applied
+/// in the order they are specified. Since nothing else can hold a reference to the object yet, none of the changes can be observed by other
+/// code -- in particular, nodes are not yet inside the scene tree, so no tree notifications are sent during building.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::Label;
+///
+/// let label: Gd<Label> = Gd::builder()
+///     .with_property("text", "Game over")
+///     .with_property("visible", false)
+///     .with(|label| label.set_name("GameOverLabel"))
+///     .build();
+/// ```
+#[must_use = "call build() to obtain the object; manually-managed objects would otherwise leak"]
+pub struct GdBuilder<T: GodotClass> {
+    object: Gd<T>,
+}
+
+impl<T: GodotClass> GdBuilder<T> {
+    pub(super) fn new(object: Gd<T>) -> Self {
+        Self { object }
+    }
+
+    /// Sets the property `name` to `value`, as if calling [`Object::set()`][crate::classes::Object::set].
+    ///
+    /// # Panics
+    /// If the class has no property `name` (also considering script properties), or if the property has a declared type different from
+    /// `value`'s type. `int` values are accepted for `float` properties.
+    ///
+    /// For properties that are only resolved dynamically (e.g. via `get_property_list()` overrides), use [`with()`][Self::with] instead.
+    pub fn with_property<V: ToGodot>(self, name: impl AsArg<StringName>, value: V) -> Self {
+        arg_into_owned!(name);
+
+        let value = value.to_variant();
+        self.validate_property(&name, &value);
+
+        let mut object = self.object.clone().upcast_object();
+        object.set(&name, &value);
+        self
+    }
+
+    /// Runs arbitrary initialization code on the object, e.g. calling setters that are not exposed as properties.
+    ///
+    /// For user classes, use `object.bind_mut()` to access the Rust instance.
+    pub fn with(mut self, init: impl FnOnce(&mut Gd<T>)) -> Self {
+        init(&mut self.object);
+        self
+    }
+
+    /// Finishes building and returns the object.
+    pub fn build(self) -> Gd<T> {
+        self.object
+    }
+
+    fn validate_property(&self, name: &StringName, value: &Variant) {
+        let class_name = self.object.dynamic_class_string();
+        let properties = self.object.clone().upcast_object().get_property_list();
+
+        let name_variant = GString::from(name).to_variant();
+        let property = properties
+            .iter_shared()
+            .find(|property| property.get_or_nil("name") == name_variant)
+            .unwrap_or_else(|| {
+                panic!("GdBuilder::with_property(): class {class_name} has no property `{name}`")
+            });
+
+        let declared_type = property
+            .get("type")
+            .and_then(|ty| ty.try_to::<i32>().ok())
+            .and_then(VariantType::try_from_ord)
+            .unwrap_or(VariantType::NIL);
+
+        let actual_type = value.get_type();
+        assert!(
+            is_implicitly_convertible(actual_type, declared_type),
+            "GdBuilder::with_property(): property `{class_name}.{name}` has type {declared_type:?}, but value has type {actual_type:?}"
+        );
+    }
+}
+
+/// Whether `Object::set()` accepts a value of type `from` for a property of type `to`, applying the same implicit conversions as Godot.
+fn is_implicitly_convertible(from: VariantType, to: VariantType) -> bool {
+    let is_string_like = |ty: VariantType| {
+        matches!(
+            ty,
+            VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH
+        )
+    };
+
+    to == VariantType::NIL // Variant property: anything goes.
+        || from == to
+        || (to == VariantType::FLOAT && from == VariantType::INT)
+        || (is_string_like(to) && is_string_like(from))
+        || (to == VariantType::OBJECT && from == VariantType::NIL)
+}
@@ -12,8 +12,12 @@
 //! * [`Gd`], a smart pointer that manages instances of Godot classes.
 
 mod base;
+mod borrow_info;
 mod dyn_gd;
+#[cfg(debug_assertions)]
+mod free_tracking;
 mod gd;
+mod gd_builder;
 mod guards;
 mod instance_id;
 mod onready;
@@ -22,9 +26,14 @@ mod traits;
 
 pub(crate) mod rtti;
 
+#[cfg(debug_assertions)]
+pub(crate) use free_tracking::clear as clear_free_tracking;
+
 pub use base::*;
+pub use borrow_info::BorrowInfo;
 pub use dyn_gd::DynGd;
 pub use gd::*;
+pub use gd_builder::GdBuilder;
 pub use guards::{BaseMut, BaseRef, DynGdMut, DynGdRef, GdMut, GdRef};
 pub use instance_id::*;
 pub use onready::*;
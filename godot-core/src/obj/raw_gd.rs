@@ -11,12 +11,14 @@ use godot_ffi as sys;
 use sys::{interface_fn, GodotFfi, GodotNullableFfi, PtrcallType};
 
 use crate::builtin::Variant;
-use crate::meta::error::{ConvertError, FromVariantError};
+use crate::meta::error::{BindError, ConvertError, FromVariantError};
 use crate::meta::{
     CallContext, ClassName, FromGodot, GodotConvert, GodotFfiVariant, GodotType, RefArg, ToGodot,
 };
 use crate::obj::bounds::{Declarer, DynMemory as _};
 use crate::obj::rtti::ObjectRtti;
+#[cfg(debug_assertions)]
+use crate::obj::BorrowInfo;
 use crate::obj::{bounds, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
 use crate::storage::{InstanceCache, InstanceStorage, Storage};
 use crate::{classes, out};
@@ -366,6 +368,7 @@ where
     ///
     /// See [`crate::obj::Gd::bind()`] for a more in depth explanation.
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub(crate) fn bind(&self) -> GdRef<T> {
         self.check_rtti("bind");
         GdRef::from_guard(self.storage().unwrap().get())
@@ -374,11 +377,39 @@ where
     /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written.
     ///
     /// See [`crate::obj::Gd::bind_mut()`] for a more in depth explanation.
+    #[track_caller]
     pub(crate) fn bind_mut(&mut self) -> GdMut<T> {
         self.check_rtti("bind_mut");
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`bind()`][Self::bind], but returns an error instead of panicking if the instance is bound exclusively.
+    #[track_caller]
+    pub(crate) fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.check_rtti("try_bind");
+        self.storage().unwrap().try_get().map(GdRef::from_guard)
+    }
+
+    /// Like [`bind_mut()`][Self::bind_mut], but returns an error instead of panicking if the instance is bound.
+    #[track_caller]
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.check_rtti("try_bind_mut");
+        self.storage().unwrap().try_get_mut().map(GdMut::from_guard)
+    }
+
+    /// Whether the user instance currently has a live shared or exclusive borrow.
+    pub(crate) fn is_bound(&self) -> bool {
+        self.check_rtti("is_bound");
+        self.storage().unwrap().is_bound()
+    }
+
+    /// The most recent borrow of the user instance, if it is currently bound.
+    #[cfg(debug_assertions)]
+    pub(crate) fn borrow_info(&self) -> Option<BorrowInfo> {
+        self.check_rtti("borrow_info");
+        self.storage().unwrap().borrow_info()
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -626,6 +657,11 @@ impl<T: GodotClass> Drop for RawGd<T> {
 
         out!("RawGd::drop   <{}>", std::any::type_name::<T>());
 
+        // After the extension is unloaded (e.g. thread-locals destroyed at exit), objects can no longer be accessed.
+        if !sys::is_initialized() {
+            return;
+        }
+
         // SAFETY: This `Gd` won't be dropped again after this.
         // If destruction is triggered by Godot, Storage already knows about it, no need to notify it
         let is_last = unsafe { T::DynMemory::maybe_dec_ref(self) }; // may drop
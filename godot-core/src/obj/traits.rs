@@ -8,8 +8,9 @@
 use crate::builder::ClassBuilder;
 use crate::builtin::GString;
 use crate::init::InitLevel;
-use crate::meta::ClassName;
-use crate::obj::{bounds, Base, BaseMut, BaseRef, Bounds, Gd};
+use crate::meta::error::BindError;
+use crate::meta::{ClassName, ToGodot};
+use crate::obj::{bounds, Base, BaseMut, BaseRef, Bounds, Gd, InstanceId};
 use crate::storage::Storage;
 
 use godot_ffi as sys;
@@ -43,6 +44,32 @@ where
     /// It must not be less than `Base::INIT_LEVEL`.
     const INIT_LEVEL: InitLevel = <Self::Base as GodotClass>::INIT_LEVEL;
 
+    /// Maximum number of live instances before an error is reported, as declared by `#[class(max_instances = N)]`. Only checked in Debug.
+    #[doc(hidden)]
+    const __MAX_INSTANCES: Option<usize> = None;
+
+    /// Current version and migration hook of a resource class, as declared by `#[resource_version(N)]`.
+    #[doc(hidden)]
+    const __RESOURCE_VERSIONING: Option<crate::private::ResourceVersioning> = None;
+
+    /// Naming policy for registered functions, properties and signals, as declared by `#[class(func_rename = ...)]`.
+    #[doc(hidden)]
+    const __FUNC_RENAME: crate::private::FuncRename = crate::private::FuncRename::Verbatim;
+
+    /// Whether instances are registered for [`WithUserBase`], i.e. the class has no `Base<T>` field. Set by `#[derive(GodotClass)]`.
+    #[doc(hidden)]
+    const __BASE_LOOKUP: bool = false;
+
+    /// Whether the class was declared with `#[class(abstract)]`, meaning it cannot be instantiated directly from Rust.
+    #[doc(hidden)]
+    const __IS_ABSTRACT: bool = false;
+
+    /// Table of `#[var]` accessors used by [`Gd::get_fast()`] and [`Gd::set_fast()`]. Provided by `#[derive(GodotClass)]`.
+    #[doc(hidden)]
+    fn __property_table() -> Option<&'static crate::private::PropertyTable<Self>> {
+        None
+    }
+
     /// Returns whether `Self` inherits from `U`.
     ///
     /// This is reflexive, i.e `Self` inherits from itself.
@@ -401,8 +428,17 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
     /// # #[gdextension]
     /// # unsafe impl ExtensionLibrary for Test {}
     /// ```
-    #[allow(clippy::let_unit_value)]
     fn base_mut(&mut self) -> BaseMut<'_, Self> {
+        self.try_base_mut().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like [`base_mut()`][Self::base_mut], but returns an error instead of panicking.
+    ///
+    /// Note that `base_mut()` itself rarely fails: re-entrancy panics typically occur one step later, when Godot calls back into a
+    /// `&self` or `&mut self` method of this object while the outer method is still running. To avoid those, use
+    /// [`Gd::is_bound()`] or [`Gd::try_bind_mut()`] on the receiving side.
+    #[allow(clippy::let_unit_value)]
+    fn try_base_mut(&mut self) -> Result<BaseMut<'_, Self>, BindError> {
         let base_gd = self.base_field().to_gd();
 
         let gd = self.to_gd();
@@ -422,12 +458,157 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
                 .expect("we have a `Gd<Self>` so the raw should not be null")
         };
 
-        let guard = storage.get_inaccessible(self);
+        let guard = storage.try_get_inaccessible(self)?;
+
+        Ok(BaseMut::new(base_gd, guard))
+    }
+}
+
+/// Access to the base object for user classes without a `Base<T>` field.
+///
+/// Implemented by `#[derive(GodotClass)]` for classes that don't declare a base field. Offers the same methods as [`WithBaseField`],
+/// but finds the object through a registry of live instances, instead of reading it from a field. This costs a lookup in a global map
+/// per call, and a registration per constructed instance. Classes which access their base frequently should declare a `Base<T>` field.
+///
+/// The base is not available inside `init()` (the instance does not exist yet) and in `Drop`. Calling these methods there panics.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Greeter {
+///     greeting: GString,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Greeter {
+///     fn ready(&mut self) {
+///         let name = self.base().get_name();
+///         godot_print!("{} from {name}", self.greeting);
+///
+///         self.base_mut().set_process(false);
+///     }
+/// }
+/// ```
+pub trait WithUserBase: GodotClass + Bounds<Declarer = bounds::DeclUser> {
+    /// Returns the `Gd` pointer containing this object.
+    ///
+    /// See [`WithBaseField::to_gd()`].
+    ///
+    /// # Panics
+    /// If called during construction or destruction of the instance.
+    fn to_gd(&self) -> Gd<Self> {
+        Gd::from_instance_id(find_instance_id(self))
+    }
+
+    /// Returns a shared reference suitable for calling engine methods on this object.
+    ///
+    /// See [`WithBaseField::base()`].
+    ///
+    /// # Panics
+    /// If called during construction or destruction of the instance.
+    fn base(&self) -> BaseRef<'_, Self> {
+        let gd = Gd::<Self::Base>::from_instance_id(find_instance_id(self));
+
+        BaseRef::new(gd, self)
+    }
+
+    /// Returns a mutable reference suitable for calling engine methods on this object.
+    ///
+    /// See [`WithBaseField::base_mut()`].
+    ///
+    /// # Panics
+    /// If called during construction or destruction of the instance.
+    fn base_mut(&mut self) -> BaseMut<'_, Self> {
+        self.try_base_mut().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like [`base_mut()`][Self::base_mut], but returns an error instead of panicking.
+    ///
+    /// See [`WithBaseField::try_base_mut()`]. In addition, fails if called during construction or destruction of the instance.
+    fn try_base_mut(&mut self) -> Result<BaseMut<'_, Self>, BindError> {
+        let instance_id = crate::storage::find_instance_id(self)
+            .ok_or_else(|| BindError::base_unavailable(&Self::class_name().to_string()))?;
+        let base_gd = Gd::<Self::Base>::from_instance_id(instance_id);
+
+        let gd = Gd::<Self>::from_instance_id(instance_id);
+        // SAFETY: the instance is registered, so its storage exists and is only destroyed after unregistering. `self` lives inside
+        // this storage, so the storage outlives the returned guard. See also WithBaseField::base_mut().
+        let storage = unsafe {
+            gd.raw
+                .storage_unbounded()
+                .expect("we have a `Gd<Self>` so the raw should not be null")
+        };
+
+        let guard = storage.try_get_inaccessible(self)?;
 
-        BaseMut::new(base_gd, guard)
+        Ok(BaseMut::new(base_gd, guard))
     }
 }
 
+fn find_instance_id<T: WithUserBase>(instance: &T) -> InstanceId {
+    crate::storage::find_instance_id(instance).unwrap_or_else(|| {
+        panic!(
+            "base of class {class} is not available during construction or destruction of the instance",
+            class = T::class_name()
+        )
+    })
+}
+
+/// Makes a user class iterable in GDScript `for` loops.
+///
+/// GDScript iterates over objects through the methods `_iter_init()`, `_iter_next()` and `_iter_get()`. Instead of implementing this
+/// protocol by hand, implement this trait and add the `iterable` key to the `#[godot_api]` attribute of an inherent `impl` block, which
+/// registers those methods for you. See also [`#[godot_api]`](../register/attr.godot_api.html#iteration-and-operators).
+///
+/// Note that Godot offers no such extension point for operators: `a + b` is not possible for objects in GDScript, regardless of the
+/// language the class is implemented in. Expose named methods (e.g. `add()`) instead.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::ScriptIterable;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Polyline {
+///     points: Vec<Vector2>,
+/// }
+///
+/// #[godot_api(iterable)]
+/// impl Polyline {}
+///
+/// impl ScriptIterable for Polyline {
+///     type Item = Vector2;
+///
+///     fn script_iter_len(&self) -> usize {
+///         self.points.len()
+///     }
+///
+///     fn script_iter_get(&self, index: usize) -> Vector2 {
+///         self.points[index]
+///     }
+/// }
+/// ```
+///
+/// In GDScript:
+/// ```gdscript
+/// for point in polyline:
+///     print(point)
+/// ```
+pub trait ScriptIterable: GodotClass {
+    /// Element type yielded to GDScript.
+    type Item: ToGodot;
+
+    /// Number of elements. Queried at each step, so the object may change during iteration.
+    fn script_iter_len(&self) -> usize;
+
+    /// Element at `index`, which is always less than [`script_iter_len()`][Self::script_iter_len].
+    fn script_iter_get(&self, index: usize) -> Self::Item;
+}
+
 /// Extension trait for all reference-counted classes.
 pub trait NewGd: GodotClass {
     /// Return a new, ref-counted `Gd` containing a default-constructed instance.
@@ -571,6 +752,16 @@ pub mod cap {
         fn __godot_property_get_revert(&self, property: StringName) -> Option<Variant>;
     }
 
+    /// Auto-implemented for `#[class(init)]` classes with `#[export]` fields, providing the values restored by the editor's revert button.
+    #[doc(hidden)]
+    pub trait GodotPropertyDefaults: GodotClass {
+        #[doc(hidden)]
+        fn __godot_has_property_default(property: &str) -> bool;
+
+        #[doc(hidden)]
+        fn __godot_property_default(property: &str) -> Option<Variant>;
+    }
+
     /// Auto-implemented for `#[godot_api] impl MyClass` blocks
     pub trait ImplementsGodotApi: GodotClass {
         #[doc(hidden)]
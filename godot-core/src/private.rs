@@ -11,15 +11,23 @@ pub use crate::registry::callbacks;
 pub use crate::registry::plugin::{
     ClassPlugin, ErasedDynGd, ErasedRegisterFn, ErasedRegisterRpcsFn, InherentImpl, PluginItem,
 };
+pub use crate::registry::property_table::PropertyTable;
 pub use crate::storage::{as_storage, Storage};
+#[cfg(debug_assertions)]
+pub use crate::storage::{live_instance_count, live_instance_counts, set_count_all_instances};
 pub use sys::out;
 
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use crate::registry::editor_icons::load_icon;
+
 #[cfg(feature = "trace")]
 pub use crate::meta::trace;
 
+use crate::builtin::{Variant, VariantArray};
 use crate::global::godot_error;
 use crate::meta::error::CallError;
-use crate::meta::CallContext;
+use crate::meta::{CallContext, ClassName, ToGodot};
+use crate::obj::{GodotClass, ScriptIterable, UserClass};
 use crate::sys;
 use std::sync::atomic;
 #[cfg(debug_assertions)]
@@ -120,6 +128,37 @@ pub(crate) fn call_error_remove(in_error: &sys::GDExtensionCallError) -> Option<
     call_error
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// GDScript iteration protocol, for #[godot_api(iterable)]
+
+/// Implements `_iter_init(iter)`. The iterator state (current index) is stored as the single element of `iter`.
+pub fn script_iter_init<T: ScriptIterable>(obj: &T, mut iter: VariantArray) -> bool {
+    iter.set(0, &0_i64.to_variant());
+    obj.script_iter_len() > 0
+}
+
+/// Implements `_iter_next(iter)`.
+pub fn script_iter_next<T: ScriptIterable>(obj: &T, mut iter: VariantArray) -> bool {
+    let next = iter
+        .at(0)
+        .try_to::<i64>()
+        .map_or(i64::MAX, |index| index.saturating_add(1));
+    iter.set(0, &next.to_variant());
+    script_iter_index(next).is_some_and(|index| index < obj.script_iter_len())
+}
+
+/// Implements `_iter_get(state)`.
+pub fn script_iter_get<T: ScriptIterable>(obj: &T, state: Variant) -> Variant {
+    match state.try_to::<i64>().ok().and_then(script_iter_index) {
+        Some(index) if index < obj.script_iter_len() => obj.script_iter_get(index).to_variant(),
+        _ => Variant::nil(),
+    }
+}
+
+fn script_iter_index(state: i64) -> Option<usize> {
+    usize::try_from(state).ok()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Plugin and global state handling
 
@@ -128,6 +167,16 @@ pub fn next_class_id() -> u16 {
     NEXT_CLASS_ID.fetch_add(1, atomic::Ordering::Relaxed)
 }
 
+/// Class name of a dependency in `#[class(after = ...)]`; only user-defined classes are allowed.
+pub fn user_class_name<T: GodotClass + UserClass>() -> ClassName {
+    T::class_name()
+}
+
+/// Names of the classes loaded at `init_level`, in registration order. Used by tests to verify `#[class(after = ...)]`.
+pub fn loaded_classes_in_registration_order(init_level: crate::init::InitLevel) -> Vec<ClassName> {
+    crate::registry::class::loaded_classes_in_registration_order(init_level)
+}
+
 pub(crate) fn iterate_plugins(mut visitor: impl FnMut(&ClassPlugin)) {
     sys::plugin_foreach!(__GODOT_PLUGIN_REGISTRY; visitor);
 }
@@ -164,9 +213,90 @@ pub struct ClassConfig {
     pub is_tool: bool,
 }
 
+/// Generated by `#[resource_version(N)]`.
+#[derive(Copy, Clone)]
+pub struct ResourceVersioning {
+    pub version: u32,
+    pub migrate: fn(u32, &mut crate::builtin::Dictionary),
+}
+
+/// Naming policy for registered functions, properties and signals, generated by `#[class(func_rename = ...)]`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum FuncRename {
+    /// Rust name is used as-is (`snake_case`).
+    Verbatim,
+
+    /// `some_name` is registered as `someName`.
+    CamelCase,
+
+    /// `some_name` is registered as `SomeName`.
+    PascalCase,
+}
+
+impl FuncRename {
+    /// Converts a `snake_case` Rust identifier according to the policy. Leading underscores (e.g. of virtual methods) are kept.
+    pub fn apply(self, rust_name: &str) -> String {
+        if self == Self::Verbatim {
+            return rust_name.to_string();
+        }
+
+        let body = rust_name.trim_start_matches('_');
+        let mut result = rust_name[..rust_name.len() - body.len()].to_string();
+
+        for (i, word) in body.split('_').enumerate() {
+            let mut chars = word.chars();
+            let Some(first) = chars.next() else {
+                continue; // Consecutive underscores.
+            };
+
+            if i == 0 && self == Self::CamelCase {
+                result.push(first);
+            } else {
+                result.extend(first.to_uppercase());
+            }
+            result.push_str(chars.as_str());
+        }
+
+        result
+    }
+
+    /// Returns the Rust name among `rust_names` that is registered as `godot_name` under this policy.
+    pub fn find_rust_name(
+        self,
+        rust_names: &[&'static str],
+        godot_name: &str,
+    ) -> Option<&'static str> {
+        if self == Self::Verbatim {
+            return rust_names.iter().copied().find(|name| *name == godot_name);
+        }
+
+        rust_names
+            .iter()
+            .copied()
+            .find(|name| self.apply(name) == godot_name)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Capability queries and internal access
 
+/// Checks the signature of an overridden virtual method against ClassDB, if the `debug-ptrcall-checks` feature is enabled.
+///
+/// `godot_method_name` is the engine name of the virtual method (e.g. `_ready` or `_init`). The class is looked up under its registered
+/// name, which accounts for `#[class(rename = ...)]`.
+#[inline]
+pub fn validate_virtual_ptrcall<C, Sig>(godot_method_name: &str)
+where
+    C: crate::obj::GodotClass,
+    Sig: crate::meta::PtrcallSignatureTuple,
+{
+    #[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+    crate::meta::validate_ptrcall::<Sig>(&C::class_name().to_string(), godot_method_name);
+
+    #[cfg(not(all(feature = "debug-ptrcall-checks", debug_assertions)))]
+    let _ = godot_method_name;
+}
+
 pub fn auto_init<T>(l: &mut crate::obj::OnReady<T>, base: &crate::obj::Gd<crate::classes::Node>) {
     l.init_auto(base);
 }
@@ -187,6 +317,16 @@ pub fn flush_stdout() {
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
+/// Whether `#[func(editor_only)]` methods are registered.
+pub fn is_editor_hint() -> bool {
+    crate::classes::Engine::singleton().is_editor_hint()
+}
+
+/// Whether `#[func(debug_only)]` methods are registered.
+pub fn is_debug_build() -> bool {
+    crate::classes::Os::singleton().is_debug_build()
+}
+
 // Starting from 4.3, Godot has "runtime classes"; this emulation is no longer needed.
 #[cfg(before_api = "4.3")]
 pub fn is_class_inactive(is_tool: bool) -> bool {
@@ -217,6 +357,62 @@ pub fn is_class_runtime(is_tool: bool) -> bool {
     global_config.tool_only_in_editor
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Assertions
+
+#[track_caller]
+pub fn godot_assert_failed(condition: &str, message: Option<std::fmt::Arguments>) -> ! {
+    match message {
+        Some(message) => panic!("Assertion failed: `{condition}`: {message}"),
+        None => panic!("Assertion failed: `{condition}`"),
+    }
+}
+
+#[track_caller]
+pub fn assert_gd_eq_impl(
+    left: crate::builtin::Variant,
+    right: crate::builtin::Variant,
+    left_expr: &str,
+    right_expr: &str,
+    message: Option<std::fmt::Arguments>,
+) {
+    if left == right {
+        return;
+    }
+
+    let describe =
+        |value: &crate::builtin::Variant| format!("{} ({:?})", value.stringify(), value.get_type());
+    let message = message.map_or_else(String::new, |message| format!(": {message}"));
+
+    panic!(
+        "assertion `{left_expr} == {right_expr}` failed (Godot equality){message}\n  \
+        left: {left}\n \
+        right: {right}",
+        left = describe(&left),
+        right = describe(&right),
+    );
+}
+
+#[track_caller]
+pub fn assert_freed_impl<T: crate::obj::GodotClass>(
+    gd: &crate::obj::Gd<T>,
+    expr: &str,
+    message: Option<std::fmt::Arguments>,
+) {
+    if !gd.is_instance_valid() {
+        return;
+    }
+
+    let message = message.map_or_else(String::new, |message| format!(": {message}"));
+
+    panic!(
+        "assertion `{expr}` freed failed{message}\n  \
+        instance {id} of class `{class}` is still alive",
+        id = gd.instance_id_unchecked(),
+        class = gd.dynamic_class_string(),
+    );
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Panic handling
 
@@ -11,11 +11,17 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::builder::ClassBuilder;
-use crate::builtin::{StringName, Variant};
-use crate::obj::{cap, Base, GodotClass, UserClass};
+use crate::builtin::{Callable, StringName, Variant};
+use crate::classes::object::ConnectFlags;
+use crate::classes::{Engine, Object, SceneTree};
+use crate::meta::ToGodot;
+use crate::obj::{cap, Base, EngineBitfield, Gd, GodotClass, InstanceId, UserClass};
+use crate::registry::instance_observers::{self, InstanceEvent};
 use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
 use godot_ffi as sys;
 use std::any::Any;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use sys::conv::u32_to_usize;
 use sys::interface_fn;
 
@@ -25,14 +31,109 @@ pub unsafe extern "C" fn create<T: cap::GodotDefault>(
     _class_userdata: *mut std::ffi::c_void,
     _notify_postinitialize: sys::GDExtensionBool,
 ) -> sys::GDExtensionObjectPtr {
-    create_custom(T::__godot_user_init)
+    let object_ptr = create_custom(T::__godot_user_init);
+    check_abstract_instance_deferred::<T>(object_ptr);
+    object_ptr
 }
 
 #[cfg(before_api = "4.4")]
 pub unsafe extern "C" fn create<T: cap::GodotDefault>(
     _class_userdata: *mut std::ffi::c_void,
 ) -> sys::GDExtensionObjectPtr {
-    create_custom(T::__godot_user_init)
+    let object_ptr = create_custom(T::__godot_user_init);
+    check_abstract_instance_deferred::<T>(object_ptr);
+    object_ptr
+}
+
+/// Error message if `object` is a direct instance of the `#[class(abstract)]` class `T`, i.e. not extended by a script.
+pub fn abstract_instance_error<T: GodotClass>(object: &Gd<Object>) -> Option<String> {
+    if !T::__IS_ABSTRACT || !object.get_script().is_nil() {
+        return None;
+    }
+
+    Some(format!(
+        "instantiated abstract class {class} directly (e.g. through {class}.new() or ClassDB.instantiate()); \
+        instantiate a script extending it instead",
+        class = T::class_name()
+    ))
+}
+
+/// Number of direct instances of abstract classes that have been reported so far. Used by tests.
+pub fn abstract_instance_report_count() -> usize {
+    ABSTRACT_INSTANCE_REPORTS.load(Ordering::Relaxed)
+}
+
+static ABSTRACT_INSTANCE_REPORTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Instance of an abstract class awaiting its check, with the check for its class.
+type PendingAbstractCheck = (InstanceId, fn(&Gd<Object>) -> Option<String>);
+
+thread_local! {
+    // Only accessed on the main thread.
+    static PENDING_ABSTRACT_CHECKS: RefCell<Vec<PendingAbstractCheck>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Reports direct instantiation of abstract classes from GDScript or `ClassDB`.
+///
+/// Godot creates scripted instances through the same callback and attaches the script only afterward, so instantiation cannot be refused
+/// here. Instead, the instance is queued and checked once the current frame has been processed. All instances created during a frame share
+/// a single check.
+///
+/// Instances created on other threads (e.g. by threaded resource loading) and instances created without a `SceneTree` main loop are not
+/// checked, since there is no frame to wait for on the main thread.
+fn check_abstract_instance_deferred<T: GodotClass>(object_ptr: sys::GDExtensionObjectPtr) {
+    if !T::__IS_ABSTRACT || !crate::init::is_main_thread() {
+        return;
+    }
+
+    // SAFETY: `object_ptr` points to a live object. The weak pointer does not touch the reference count, and is forgotten afterwards.
+    let object = unsafe { Gd::<Object>::from_obj_sys_weak(object_ptr) };
+    let instance_id = object.instance_id();
+    std::mem::forget(object);
+
+    let was_empty = PENDING_ABSTRACT_CHECKS.with_borrow_mut(|pending| {
+        pending.push((instance_id, abstract_instance_error::<T>));
+        pending.len() == 1
+    });
+
+    // A check for this frame is already scheduled.
+    if !was_empty {
+        return;
+    }
+
+    let Some(mut tree) = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+    else {
+        PENDING_ABSTRACT_CHECKS.with_borrow_mut(Vec::clear);
+        return;
+    };
+
+    tree.connect_ex(
+        "process_frame",
+        &Callable::from_local_fn("check_abstract_instances", |_args| {
+            check_pending_abstract_instances();
+            Ok(Variant::nil())
+        }),
+    )
+    .flags(ConnectFlags::ONE_SHOT.ord() as u32)
+    .done();
+}
+
+fn check_pending_abstract_instances() {
+    let pending = PENDING_ABSTRACT_CHECKS.with_borrow_mut(std::mem::take);
+
+    for (instance_id, check) in pending {
+        // Instance may have been freed in the meantime; RefCounted instances are not kept alive by the check.
+        let Ok(object) = Gd::<Object>::try_from_instance_id(instance_id) else {
+            continue;
+        };
+
+        if let Some(error) = check(&object) {
+            ABSTRACT_INSTANCE_REPORTS.fetch_add(1, Ordering::Relaxed);
+            crate::godot_error!("{error}");
+        }
+    }
 }
 
 #[cfg(since_api = "4.2")]
@@ -79,6 +180,7 @@ where
     let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
 
     let instance = InstanceStorage::<T>::construct(user_instance, base);
+    let instance_id = instance.base().instance_id();
     let instance_ptr = instance.into_raw();
     let instance_ptr = instance_ptr as sys::GDExtensionClassInstancePtr;
 
@@ -93,6 +195,19 @@ where
         );
     }
 
+    if let Some(versioning) = T::__RESOURCE_VERSIONING {
+        // Stamp new instances with the current version, so it is saved along with them. When loading, the stored version overwrites this.
+        // SAFETY: `base_ptr` points to a live object. The weak pointer does not touch the reference count, and is forgotten afterwards.
+        let mut object = unsafe { Gd::<Object>::from_obj_sys_weak(base_ptr) };
+        object.set_meta(
+            crate::tools::RESOURCE_VERSION_META,
+            &versioning.version.to_variant(),
+        );
+        std::mem::forget(object);
+    }
+
+    instance_observers::notify::<T>(InstanceEvent::Created, instance_id);
+
     // std::mem::forget(class_name);
     instance_ptr
 }
@@ -103,6 +218,7 @@ pub unsafe extern "C" fn free<T: GodotClass>(
 ) {
     {
         let storage = as_storage::<T>(instance);
+        instance_observers::notify::<T>(InstanceEvent::Destroyed, storage.base().instance_id());
         storage.mark_destroyed_by_godot();
     } // Ref no longer valid once next statement is executed.
 
@@ -181,7 +297,7 @@ pub unsafe extern "C" fn on_notification<T: cap::GodotNotification>(
     what: i32,
 ) {
     let storage = as_storage::<T>(instance);
-    let mut instance = storage.get_mut();
+    let mut instance = storage.get_mut_for_call("on_notification");
 
     T::__godot_notification(&mut *instance, what);
 }
@@ -193,7 +309,7 @@ pub unsafe extern "C" fn on_notification<T: cap::GodotNotification>(
     _reversed: sys::GDExtensionBool,
 ) {
     let storage = as_storage::<T>(instance);
-    let mut instance = storage.get_mut();
+    let mut instance = storage.get_mut_for_call("on_notification");
 
     T::__godot_notification(&mut *instance, what);
 }
@@ -204,7 +320,7 @@ pub unsafe extern "C" fn get_property<T: cap::GodotGet>(
     ret: sys::GDExtensionVariantPtr,
 ) -> sys::GDExtensionBool {
     let storage = as_storage::<T>(instance);
-    let instance = storage.get();
+    let instance = storage.get_for_call("get_property");
     let property = StringName::new_from_string_sys(name);
 
     match T::__godot_get_property(&*instance, property) {
@@ -222,7 +338,7 @@ pub unsafe extern "C" fn set_property<T: cap::GodotSet>(
     value: sys::GDExtensionConstVariantPtr,
 ) -> sys::GDExtensionBool {
     let storage = as_storage::<T>(instance);
-    let mut instance = storage.get_mut();
+    let mut instance = storage.get_mut_for_call("set_property");
 
     let property = StringName::new_from_string_sys(name);
     let value = Variant::new_from_var_sys(value);
@@ -250,7 +366,7 @@ pub unsafe extern "C" fn get_property_list<T: cap::GodotGetPropertyList>(
 ) -> *const sys::GDExtensionPropertyInfo {
     // SAFETY: Godot provides us with a valid instance pointer to a `T`. And it will live until the end of this function.
     let storage = unsafe { as_storage::<T>(instance) };
-    let mut instance = storage.get_mut();
+    let mut instance = storage.get_mut_for_call("get_property_list");
 
     let property_list = T::__godot_get_property_list(&mut *instance);
     let property_list_sys: Box<[sys::GDExtensionPropertyInfo]> = property_list
@@ -355,6 +471,45 @@ pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyGetRevert>(
 
     sys::conv::SYS_TRUE
 }
+/// # Safety
+///
+/// - Must only be called by Godot as a callback for `property_can_revert` for a rust-defined class of type `T`.
+#[deny(unsafe_op_in_unsafe_fn)]
+pub unsafe extern "C" fn default_property_can_revert<T: cap::GodotPropertyDefaults>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    property_name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: `property_name` is a valid `StringName` pointer for the duration of this call. This string is not ours, so we cannot call
+    // the destructor on it.
+    let property = unsafe { StringName::borrow_string_sys(property_name) }.to_string();
+
+    sys::conv::bool_to_sys(T::__godot_has_property_default(&property))
+}
+
+/// # Safety
+///
+/// - Must only be called by Godot as a callback for `property_get_revert` for a rust-defined class of type `T`.
+#[deny(unsafe_op_in_unsafe_fn)]
+pub unsafe extern "C" fn default_property_get_revert<T: cap::GodotPropertyDefaults>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    property_name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: `property_name` is a valid `StringName` pointer for the duration of this call.
+    let property = unsafe { StringName::borrow_string_sys(property_name) }.to_string();
+
+    let Some(revert) = T::__godot_property_default(&property) else {
+        return sys::conv::SYS_FALSE;
+    };
+
+    // SAFETY: Godot provides us with a valid `Variant` pointer.
+    unsafe {
+        revert.move_into_var_ptr(ret);
+    }
+
+    sys::conv::SYS_TRUE
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Safe, higher-level methods
 
@@ -6,7 +6,8 @@
  */
 
 use godot_ffi::join_with;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 use std::{any, ptr};
 
 use crate::builtin::GString;
@@ -15,8 +16,8 @@ use crate::meta::error::{ConvertError, FromGodotError};
 use crate::meta::ClassName;
 use crate::obj::{cap, DynGd, Gd, GodotClass};
 use crate::private::{ClassPlugin, PluginItem};
-use crate::registry::callbacks;
 use crate::registry::plugin::{ErasedDynifyFn, ErasedRegisterFn, InherentImpl};
+use crate::registry::{callbacks, instance_observers, property_table};
 use crate::{classes, godot_error, godot_warn, sys};
 use sys::{interface_fn, out, Global, GlobalGuard, GlobalLockError};
 
@@ -63,10 +64,56 @@ pub struct LoadedClass {
     is_editor_plugin: bool,
 }
 
-/// Represents a class which is currently loaded and retained in memory -- including metadata.
-//
-// Currently empty, but should already work for per-class queries.
-pub struct ClassMetadata {}
+/// Metadata of a Rust class registered with Godot.
+///
+/// Obtained via [`registered_classes()`]. This only covers classes registered by this extension through `#[derive(GodotClass)]`.
+#[derive(Clone, Debug)]
+pub struct ClassMetadata {
+    class_name: ClassName,
+    base_class_name: ClassName,
+    init_level: InitLevel,
+    is_tool: bool,
+    is_editor_plugin: bool,
+    is_internal: bool,
+    icon: Option<&'static str>,
+}
+
+impl ClassMetadata {
+    /// Name of the class, as registered with Godot (after `#[class(rename)]`).
+    pub fn class_name(&self) -> ClassName {
+        self.class_name
+    }
+
+    /// Name of the direct base class.
+    pub fn base_class_name(&self) -> ClassName {
+        self.base_class_name
+    }
+
+    /// Initialization level at which the class is registered.
+    pub fn init_level(&self) -> InitLevel {
+        self.init_level
+    }
+
+    /// Whether the class is declared with `#[class(tool)]`, i.e. runs in the editor.
+    pub fn is_tool(&self) -> bool {
+        self.is_tool
+    }
+
+    /// Whether the class is an editor plugin, i.e. inherits `EditorPlugin`.
+    pub fn is_editor_plugin(&self) -> bool {
+        self.is_editor_plugin
+    }
+
+    /// Whether the class is hidden from the editor and docs, via `#[class(internal)]`.
+    pub fn is_internal(&self) -> bool {
+        self.is_internal
+    }
+
+    /// Editor icon declared with `#[class(icon)]`: a resource path or SVG source.
+    pub fn icon(&self) -> Option<&'static str> {
+        self.icon
+    }
+}
 
 /// Represents a `dyn Trait` implemented (and registered) for a class.
 pub struct DynToClassRelation {
@@ -101,13 +148,20 @@ struct ClassRegistrationInfo {
     user_register_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is at least one OnReady field)
     user_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is a `#[godot_api] impl I*`)
+    default_property_can_revert_fn: sys::GDExtensionClassPropertyCanRevert, // Optional (set for #[class(init)] with #[export] fields)
+    default_property_get_revert_fn: sys::GDExtensionClassPropertyGetRevert,
 
     /// Godot low-level class creation parameters.
     godot_params: GodotCreationInfo,
 
-    #[allow(dead_code)] // Currently unused; may be useful for diagnostics in the future.
     init_level: InitLevel,
+    is_tool: bool,
     is_editor_plugin: bool,
+    is_internal: bool,
+    icon: Option<&'static str>,
+
+    /// Classes that must be registered before this one, from `#[class(after = ...)]`.
+    register_after: Vec<ClassName>,
 
     /// One entry for each `dyn Trait` implemented (and registered) for this class.
     dynify_fns_by_trait: HashMap<any::TypeId, ErasedDynifyFn>,
@@ -183,9 +237,15 @@ pub fn register_class<
         }),
         user_virtual_fn: None,
         default_virtual_fn: None,
+        default_property_can_revert_fn: None,
+        default_property_get_revert_fn: None,
         godot_params,
         init_level: T::INIT_LEVEL,
+        is_tool: false,
         is_editor_plugin: false,
+        is_internal: false,
+        icon: None,
+        register_after: vec![],
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
     });
@@ -221,10 +281,14 @@ pub fn auto_register_classes(init_level: InitLevel) {
     // We need all the dyn classes in the registry to properly register DynGd properties;
     // one can do it directly inside the loop – by locking and unlocking the mutex –
     // but it is much slower and doesn't guarantee that all the dependent classes will be already loaded in most cases.
-    register_classes_and_dyn_traits(&mut map, init_level);
+    let order = registration_order(&map, init_level);
+    register_classes_and_dyn_traits(&mut map, &order, init_level);
 
     // actually register all the classes
-    for info in map.into_values() {
+    for class_name in order {
+        let info = map
+            .remove(&class_name)
+            .expect("class in registration order");
         register_class_raw(info);
         out!("Class {class_name} loaded.");
     }
@@ -232,15 +296,114 @@ pub fn auto_register_classes(init_level: InitLevel) {
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
+/// Determines the order in which the classes of one init level are registered with Godot.
+///
+/// A class is registered after its base class (if that is a Rust class of the same level), and after the classes listed in
+/// `#[class(after = ...)]`. Otherwise, classes are ordered by name, so the order doesn't change between runs.
+fn registration_order(
+    map: &HashMap<ClassName, ClassRegistrationInfo>,
+    init_level: InitLevel,
+) -> Vec<ClassName> {
+    {
+        // Classes of earlier levels are already registered.
+        let loaded_classes_by_name = global_loaded_classes_by_name();
+        for info in map.values() {
+            for dependency in info.register_after.iter() {
+                let is_registered =
+                    map.contains_key(dependency) || loaded_classes_by_name.contains_key(dependency);
+
+                if !is_registered {
+                    panic!(
+                        "class `{class}` is declared with #[class(after = {dependency})], but `{dependency}` is not registered \
+                        at init level `{init_level:?}` or earlier",
+                        class = info.class_name,
+                    );
+                }
+            }
+        }
+    }
+
+    let mut class_names: Vec<ClassName> = map.keys().copied().collect();
+    class_names.sort_by(|a, b| a.to_cow_str().cmp(&b.to_cow_str()));
+
+    let dependencies = |class_name: &ClassName| {
+        let info = &map[class_name];
+        info.parent_class_name
+            .iter()
+            .chain(info.register_after.iter())
+            .copied()
+            .filter(|dependency| map.contains_key(dependency))
+            .collect()
+    };
+
+    topological_order(&class_names, dependencies).unwrap_or_else(|cycle| {
+        panic!(
+            "cyclic class registration order (via base classes or #[class(after)]): {}",
+            join_with(cycle.iter(), " -> ", |class_name| *class_name)
+        )
+    })
+}
+
+/// Orders `keys` so that each key comes after its dependencies; apart from that, the order of `keys` is kept.
+///
+/// All dependencies must be contained in `keys`. If there is a cycle, returns its keys, with the first one repeated at the end.
+fn topological_order<K: Copy + Eq + Hash>(
+    keys: &[K],
+    dependencies: impl Fn(&K) -> Vec<K>,
+) -> Result<Vec<K>, Vec<K>> {
+    fn visit<K: Copy + Eq + Hash>(
+        key: K,
+        dependencies: &impl Fn(&K) -> Vec<K>,
+        path: &mut Vec<K>,
+        visited: &mut HashSet<K>,
+        order: &mut Vec<K>,
+    ) -> Result<(), Vec<K>> {
+        if visited.contains(&key) {
+            return Ok(());
+        }
+
+        if let Some(start) = path.iter().position(|k| *k == key) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(key);
+            return Err(cycle);
+        }
+
+        path.push(key);
+        for dependency in dependencies(&key) {
+            visit(dependency, dependencies, path, visited, order)?;
+        }
+        path.pop();
+
+        visited.insert(key);
+        order.push(key);
+        Ok(())
+    }
+
+    let mut path = Vec::new();
+    let mut visited = HashSet::new();
+    let mut order = Vec::with_capacity(keys.len());
+
+    for &key in keys {
+        visit(key, &dependencies, &mut path, &mut visited, &mut order)?;
+    }
+
+    Ok(order)
+}
+
 fn register_classes_and_dyn_traits(
     map: &mut HashMap<ClassName, ClassRegistrationInfo>,
+    order: &[ClassName],
     init_level: InitLevel,
 ) {
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
     let mut dyn_traits_by_typeid = global_dyn_traits_by_typeid();
 
-    for info in map.values_mut() {
+    // Same order as registration, so that unregistering happens in reverse.
+    for class_name in order {
+        let info = map
+            .get_mut(class_name)
+            .expect("class in registration order");
         let class_name = info.class_name;
         out!("Register class:   {class_name} at level `{init_level:?}`");
 
@@ -248,7 +411,17 @@ fn register_classes_and_dyn_traits(
             name: class_name,
             is_editor_plugin: info.is_editor_plugin,
         };
-        let metadata = ClassMetadata {};
+        let metadata = ClassMetadata {
+            class_name,
+            base_class_name: info
+                .parent_class_name
+                .expect("class defined (parent_class_name)"),
+            init_level,
+            is_tool: info.is_tool,
+            is_editor_plugin: info.is_editor_plugin,
+            is_internal: info.is_internal,
+            icon: info.icon,
+        };
 
         // Transpose Class->Trait relations to Trait->Class relations.
         for (trait_type_id, dynify_fn) in info.dynify_fns_by_trait.drain() {
@@ -270,6 +443,45 @@ fn register_classes_and_dyn_traits(
     }
 }
 
+/// Returns metadata of all Rust classes that are currently registered with Godot, sorted by class name.
+///
+/// Classes are registered level by level during initialization (see [`ExtensionLibrary`][crate::init::ExtensionLibrary]). Call this once
+/// the levels you're interested in are loaded, e.g. in `on_level_init()` or later at runtime, to get a complete picture.
+///
+/// # Example
+/// ```no_run
+/// use godot::register::registered_classes;
+///
+/// for class in registered_classes() {
+///     if class.is_tool() {
+///         godot::global::godot_print!("{} (extends {})", class.class_name(), class.base_class_name());
+///     }
+/// }
+/// ```
+pub fn registered_classes() -> Vec<ClassMetadata> {
+    let mut classes: Vec<ClassMetadata> =
+        global_loaded_classes_by_name().values().cloned().collect();
+
+    classes.sort_by(|a, b| a.class_name.to_cow_str().cmp(&b.class_name.to_cow_str()));
+    classes
+}
+
+/// Returns the names of all classes loaded at `init_level`, in the order in which they were registered with Godot.
+pub(crate) fn loaded_classes_in_registration_order(init_level: InitLevel) -> Vec<ClassName> {
+    global_loaded_classes_by_init_level()
+        .get(&init_level)
+        .map(|classes| classes.iter().map(|class| class.name).collect())
+        .unwrap_or_default()
+}
+
+/// Returns all loaded classes that declare an editor icon, with the icon's path or SVG source.
+pub(crate) fn loaded_class_icons() -> Vec<(ClassName, &'static str)> {
+    global_loaded_classes_by_name()
+        .iter()
+        .filter_map(|(name, metadata)| metadata.icon.map(|icon| (*name, icon)))
+        .collect()
+}
+
 pub fn unregister_classes(init_level: InitLevel) {
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
@@ -283,6 +495,8 @@ pub fn unregister_classes(init_level: InitLevel) {
     for class in loaded_classes_current_level.into_iter().rev() {
         // Remove from other map.
         loaded_classes_by_name.remove(&class.name);
+        property_table::unregister_class(class.name);
+        instance_observers::unregister_class(class.name);
 
         // Unregister from Godot.
         unregister_class_raw(class);
@@ -395,17 +609,28 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             register_properties_fn,
             free_fn,
             default_get_virtual_fn,
+            default_property_can_revert_fn,
+            default_property_get_revert_fn,
             is_tool,
             is_editor_plugin,
             is_internal,
             is_instantiable,
+            is_abstract,
+            icon,
+            register_after,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
                 docs: _,
         } => {
             c.parent_class_name = Some(base_class_name);
             c.default_virtual_fn = default_get_virtual_fn;
+            c.default_property_can_revert_fn = default_property_can_revert_fn;
+            c.default_property_get_revert_fn = default_property_get_revert_fn;
             c.register_properties_fn = Some(register_properties_fn);
+            c.is_tool = is_tool;
             c.is_editor_plugin = is_editor_plugin;
+            c.is_internal = is_internal;
+            c.icon = icon;
+            c.register_after = register_after;
 
             // Classes marked #[class(no_init)] are translated to "abstract" in Godot. This disables their default constructor.
             // "Abstract" is a misnomer -- it's not an abstract base class, but rather a "utility/static class" (although it can have instance
@@ -416,6 +641,11 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             //
             // See also: https://github.com/godotengine/godot/pull/58972
             c.godot_params.is_abstract = sys::conv::bool_to_sys(!is_instantiable);
+
+            // Classes marked #[class(abstract)] are such ABCs: scripts can extend them, which requires a constructor. Godot does not
+            // prevent direct instantiation of virtual classes (ClassDB.can_instantiate() stays true). Rust constructors panic, while
+            // instances created from GDScript or ClassDB are reported by the create callback, once it is known that no script was attached.
+            c.godot_params.is_virtual = sys::conv::bool_to_sys(is_abstract);
             c.godot_params.free_instance_func = Some(free_fn);
 
             fill_into(
@@ -424,8 +654,6 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             )
             .expect("duplicate: create_instance_func (def)");
 
-            #[cfg(before_api = "4.2")]
-            let _ = is_internal; // mark used
             #[cfg(since_api = "4.2")]
             {
                 fill_into(
@@ -440,8 +668,6 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             #[cfg(before_api = "4.2")]
             assert!(generated_recreate_fn.is_none()); // not used
 
-            #[cfg(before_api = "4.3")]
-            let _ = is_tool; // mark used
             #[cfg(since_api = "4.3")]
             {
                 c.godot_params.is_runtime =
@@ -547,6 +773,12 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         info.godot_params.get_virtual_func = info.user_virtual_fn.or(info.default_virtual_fn);
     }
 
+    // Same for property reverts: a user-defined `property_get_revert` takes precedence over `#[init]` defaults of `#[export]` fields.
+    if info.godot_params.property_get_revert_func.is_none() {
+        info.godot_params.property_can_revert_func = info.default_property_can_revert_fn;
+        info.godot_params.property_get_revert_func = info.default_property_get_revert_fn;
+    }
+
     // The explicit () type notifies us if Godot API ever adds a return type.
     let registration_failed = unsafe {
         // Try to register class...
@@ -665,9 +897,15 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         user_register_fn: None,
         default_virtual_fn: None,
         user_virtual_fn: None,
+        default_property_can_revert_fn: None,
+        default_property_get_revert_fn: None,
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
+        is_tool: false,
         is_editor_plugin: false,
+        is_internal: false,
+        icon: None,
+        register_after: vec![],
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
     }
@@ -781,3 +1019,51 @@ fn default_creation_info() -> sys::GDExtensionClassCreationInfo4 {
         class_userdata: ptr::null_mut(),
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::topological_order;
+
+    fn deps_of(key: &&'static str) -> Vec<&'static str> {
+        match *key {
+            "Derived" => vec!["Base"],
+            "Manager" => vec!["Derived", "Registry"],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn topological_order_respects_dependencies() {
+        let keys = ["Manager", "Derived", "Registry", "Base", "Other"];
+        let order = topological_order(&keys, deps_of).expect("no cycle");
+
+        assert_eq!(order, ["Base", "Derived", "Registry", "Manager", "Other"]);
+    }
+
+    #[test]
+    fn topological_order_keeps_independent_order() {
+        let keys = ["C", "A", "B"];
+        let order = topological_order(&keys, |_| vec![]).expect("no cycle");
+
+        assert_eq!(order, ["C", "A", "B"]);
+    }
+
+    #[test]
+    fn topological_order_detects_cycles() {
+        let keys = ["A", "B", "C"];
+        let cycle = topological_order(&keys, |key| match *key {
+            "A" => vec!["B"],
+            "B" => vec!["C"],
+            "C" => vec!["A"],
+            _ => vec![],
+        })
+        .expect_err("cycle");
+
+        assert_eq!(cycle, ["A", "B", "C", "A"]);
+
+        let cycle = topological_order(&["A"], |_| vec!["A"]).expect_err("self-cycle");
+        assert_eq!(cycle, ["A", "A"]);
+    }
+}
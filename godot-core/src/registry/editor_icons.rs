@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Editor icons declared with `#[class(icon = ...)]`.
+//!
+//! Godot looks up icons of extension classes in the editor theme, under the theme type `EditorIcons` and the class name. The editor UI
+//! is only constructed after extensions have been initialized, so icons are added on the first frame, and re-added whenever the editor
+//! regenerates its theme (e.g. after changing the editor scale or colors).
+
+use crate::builtin::{Callable, Variant, Vector2};
+use crate::classes::object::ConnectFlags;
+use crate::classes::{EditorInterface, Engine, Image, ImageTexture, SceneTree, Texture2D};
+use crate::global::Error;
+use crate::godot_warn;
+use crate::obj::{EngineBitfield, Gd, NewGd};
+use crate::registry::class::loaded_class_icons;
+
+/// Theme type under which the editor looks up class icons.
+const EDITOR_ICONS: &str = "EditorIcons";
+
+/// Base size of editor icons, before applying the editor scale.
+const ICON_SIZE: f32 = 16.0;
+
+/// Schedules icons of all loaded classes to be added to the editor theme, once the editor UI is available.
+pub(crate) fn apply_deferred() {
+    if loaded_class_icons().is_empty() {
+        return;
+    }
+
+    // The main loop doesn't exist yet during extension initialization; Godot's message queue does.
+    Callable::from_local_fn("apply_icons", on_editor_ready).call_deferred(&[]);
+}
+
+fn on_editor_ready(_args: &[&Variant]) -> Result<Variant, ()> {
+    if Engine::singleton().has_singleton("EditorInterface") {
+        let editor = EditorInterface::singleton();
+        if let Some(mut base_control) = editor.get_base_control() {
+            // The editor replaces its theme when settings change, dropping our icons.
+            base_control.connect(
+                "theme_changed",
+                &Callable::from_local_fn("apply_icons", |_| {
+                    apply_icons();
+                    Ok(Variant::nil())
+                }),
+            );
+
+            apply_icons();
+            return Ok(Variant::nil());
+        }
+    }
+
+    // Editor not yet constructed: try again next frame.
+    match Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+    {
+        Some(mut tree) => {
+            tree.connect_ex(
+                "process_frame",
+                &Callable::from_local_fn("apply_icons", on_editor_ready),
+            )
+            .flags(ConnectFlags::ONE_SHOT.ord() as u32)
+            .done();
+        }
+        None => godot_warn!("godot-rust: editor not available, class icons are not shown"),
+    }
+
+    Ok(Variant::nil())
+}
+
+fn apply_icons() {
+    let editor = EditorInterface::singleton();
+    let Some(mut theme) = editor.get_editor_theme() else {
+        return;
+    };
+
+    let size = ICON_SIZE * editor.get_editor_scale();
+
+    for (class_name, icon) in loaded_class_icons() {
+        let class_name = class_name.to_string_name();
+
+        // Also prevents endless recursion: each set_icon() causes another "theme_changed" emission.
+        if theme.has_icon(&class_name, EDITOR_ICONS) {
+            continue;
+        }
+
+        match load_icon(icon, size) {
+            Some(texture) => theme.set_icon(&class_name, EDITOR_ICONS, &texture),
+            None => godot_warn!("godot-rust: failed to load icon for class `{class_name}`"),
+        }
+    }
+}
+
+/// Loads an icon from a resource path or SVG source, scaled to `size` pixels.
+///
+/// Re-exported in `godot::private` for tests.
+pub fn load_icon(icon: &str, size: f32) -> Option<Gd<Texture2D>> {
+    let svg = icon.trim_start();
+
+    let mut image = if svg.starts_with('<') {
+        let mut image = Image::new_gd();
+        let scale = size / ICON_SIZE;
+
+        if image.load_svg_from_string_ex(svg).scale(scale).done() != Error::OK {
+            return None;
+        }
+
+        image
+    } else {
+        let texture = crate::tools::try_load::<Texture2D>(icon).ok()?;
+        if texture.get_size() == Vector2::new(size, size) {
+            return Some(texture);
+        }
+
+        texture.get_image()?
+    };
+
+    // Like Godot's own icons from .gdextension files, images of other sizes (e.g. imported SVGs) are scaled to the icon size.
+    let size = size.round() as i32;
+    if image.get_width() != size || image.get_height() != size {
+        image.resize(size, size);
+    }
+
+    ImageTexture::create_from_image(&image).map(Gd::upcast)
+}
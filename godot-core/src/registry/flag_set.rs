@@ -0,0 +1,275 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{BitAnd, BitOr, BitOrAssign};
+
+use crate::builtin::GString;
+use crate::global::PropertyHint;
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, PropertyHintInfo, ToGodot};
+use crate::registry::property::{Export, Var};
+
+/// Single flag of a set of flags, exported to Godot as `@export_flags`.
+///
+/// Can be derived with [`#[derive(GodotFlags)]`](../derive.GodotFlags.html) on fieldless enums, and is typically used through
+/// [`FlagSet`].
+pub trait GodotFlags: Copy + 'static {
+    /// All flags, with the names shown in the editor, in declaration order.
+    const ALL: &'static [(Self, &'static str)];
+
+    /// Bit value of this flag; a power of two.
+    fn bit(self) -> u32;
+}
+
+/// Set of flags `F`, which can be used as `#[var]` and `#[export]` property.
+///
+/// In Godot, the set is an `int` with the `PROPERTY_HINT_FLAGS` hint, shown as a list of checkboxes in the editor. In Rust, flags are
+/// added, removed and queried through the type-safe enum `F`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::register::property::FlagSet;
+///
+/// #[derive(GodotFlags, Copy, Clone, Debug)]
+/// enum Ability {
+///     Fly,
+///     Swim,
+///     Climb,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Creature {
+///     #[export]
+///     abilities: FlagSet<Ability>,
+/// }
+///
+/// impl Creature {
+///     fn learn_swimming(&mut self) {
+///         self.abilities.set(Ability::Swim);
+///         assert!(self.abilities.contains(Ability::Swim));
+///     }
+/// }
+/// ```
+pub struct FlagSet<F> {
+    bits: u32,
+    _flags: PhantomData<F>,
+}
+
+impl<F: GodotFlags> FlagSet<F> {
+    /// Set without any flags.
+    pub const fn empty() -> Self {
+        Self {
+            bits: 0,
+            _flags: PhantomData,
+        }
+    }
+
+    /// Set with all flags of `F`.
+    pub fn all() -> Self {
+        Self::from_bits_truncate(u32::MAX)
+    }
+
+    /// Creates a set from its bits, or `None` if `bits` contains bits that don't correspond to any flag.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        let set = Self::from_bits_truncate(bits);
+        (set.bits == bits).then_some(set)
+    }
+
+    /// Creates a set from its bits, ignoring bits that don't correspond to any flag.
+    pub fn from_bits_truncate(bits: u32) -> Self {
+        Self {
+            bits: bits & Self::mask(),
+            _flags: PhantomData,
+        }
+    }
+
+    /// Bit representation, as seen by Godot.
+    pub fn bits(self) -> u32 {
+        self.bits
+    }
+
+    /// Whether `flag` is in the set.
+    pub fn contains(self, flag: F) -> bool {
+        self.bits & flag.bit() != 0
+    }
+
+    /// Adds `flag` to the set.
+    pub fn set(&mut self, flag: F) {
+        self.bits |= flag.bit();
+    }
+
+    /// Removes `flag` from the set.
+    pub fn clear(&mut self, flag: F) {
+        self.bits &= !flag.bit();
+    }
+
+    /// Adds `flag` if it's not in the set, otherwise removes it.
+    pub fn toggle(&mut self, flag: F) {
+        self.bits ^= flag.bit();
+    }
+
+    /// Returns a copy of the set, with `flag` added.
+    #[must_use]
+    pub fn with(mut self, flag: F) -> Self {
+        self.set(flag);
+        self
+    }
+
+    /// Whether the set has no flags.
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Iterates over the flags in the set, in declaration order.
+    pub fn iter(self) -> impl Iterator<Item = F> {
+        F::ALL
+            .iter()
+            .map(|(flag, _name)| *flag)
+            .filter(move |flag| self.contains(*flag))
+    }
+
+    fn mask() -> u32 {
+        F::ALL
+            .iter()
+            .fold(0, |mask, (flag, _name)| mask | flag.bit())
+    }
+
+    fn hint_string() -> String {
+        let flags: Vec<String> = F::ALL
+            .iter()
+            .map(|(flag, name)| format!("{name}:{}", flag.bit()))
+            .collect();
+
+        flags.join(",")
+    }
+}
+
+impl<F: GodotFlags> Default for FlagSet<F> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<F: GodotFlags> From<F> for FlagSet<F> {
+    fn from(flag: F) -> Self {
+        Self::empty().with(flag)
+    }
+}
+
+impl<F: GodotFlags> FromIterator<F> for FlagSet<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::empty(), Self::with)
+    }
+}
+
+impl<F: GodotFlags> BitOr<F> for FlagSet<F> {
+    type Output = Self;
+
+    fn bitor(self, flag: F) -> Self {
+        self.with(flag)
+    }
+}
+
+impl<F: GodotFlags> BitOr for FlagSet<F> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits | other.bits)
+    }
+}
+
+impl<F: GodotFlags> BitOrAssign<F> for FlagSet<F> {
+    fn bitor_assign(&mut self, flag: F) {
+        self.set(flag);
+    }
+}
+
+impl<F: GodotFlags> BitAnd for FlagSet<F> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits & other.bits)
+    }
+}
+
+// Manual impls, to not require the traits on F.
+impl<F> Copy for FlagSet<F> {}
+
+impl<F> Clone for FlagSet<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> PartialEq for FlagSet<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<F> Eq for FlagSet<F> {}
+
+impl<F> Hash for FlagSet<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
+
+impl<F: GodotFlags + fmt::Debug> fmt::Debug for FlagSet<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Godot integration
+
+impl<F: GodotFlags> GodotConvert for FlagSet<F> {
+    type Via = i64;
+}
+
+impl<F: GodotFlags> ToGodot for FlagSet<F> {
+    type ToVia<'v> = i64;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        i64::from(self.bits)
+    }
+}
+
+impl<F: GodotFlags> FromGodot for FlagSet<F> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        u32::try_from(via)
+            .ok()
+            .and_then(Self::from_bits)
+            .ok_or_else(|| ConvertError::new(format!("invalid bits for flag set: {via}")))
+    }
+}
+
+impl<F: GodotFlags> Var for FlagSet<F> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    // Godot's inspector only offers known flags; values set from scripts are truncated to those.
+    fn set_property(&mut self, value: Self::Via) {
+        *self = Self::from_bits_truncate(value as u32);
+    }
+
+    fn var_hint() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::FLAGS,
+            hint_string: GString::from(Self::hint_string()),
+        }
+    }
+}
+
+impl<F: GodotFlags> Export for FlagSet<F> {}
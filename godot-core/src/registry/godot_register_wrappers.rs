@@ -7,22 +7,45 @@
 
 //! Internal registration machinery used by proc-macro APIs.
 
-use crate::builtin::StringName;
+use crate::builtin::{StringName, Variant};
 use crate::global::PropertyUsageFlags;
+use crate::meta::error::ConvertError;
 use crate::meta::{ClassName, GodotConvert, GodotType, PropertyHintInfo, PropertyInfo};
-use crate::obj::GodotClass;
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits};
 use crate::registry::property::{Export, Var};
+use crate::registry::property_table;
 use crate::{classes, sys};
 use godot_ffi::GodotFfi;
 
+/// Rust accessors of a property, stored in the class's property table for [`Gd::get_fast()`][crate::obj::Gd::get_fast] and
+/// [`Gd::set_fast()`][crate::obj::Gd::set_fast].
+///
+/// `None` if the property has no getter or setter, respectively. Accessors bind the instance themselves.
+pub struct PropertyAccessors<C: GodotClass> {
+    pub getter: Option<fn(&Gd<C>) -> Variant>,
+    pub setter: Option<fn(&mut Gd<C>, &Variant) -> Result<(), ConvertError>>,
+}
+
+// Manual impls: derives would require `C: Clone`.
+impl<C: GodotClass> Clone for PropertyAccessors<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: GodotClass> Copy for PropertyAccessors<C> {}
+
 /// Same as [`register_var()`], but statically verifies the `Export` trait (again) and the fact that nodes can only be exported from nodes.
-pub fn register_export<C: GodotClass, T: Export>(
+pub fn register_export<C, T: Export>(
     property_name: &str,
     getter_name: &str,
     setter_name: &str,
     hint_info: PropertyHintInfo,
     usage: PropertyUsageFlags,
-) {
+    accessors: PropertyAccessors<C>,
+) where
+    C: Inherits<classes::Object> + Bounds<Declarer = bounds::DeclUser>,
+{
     // Note: if the user manually specifies `hint`, `hint_string` or `usage` keys, and thus is routed to `register_var()` instead,
     // they can bypass this validation.
     if !C::inherits::<classes::Node>() {
@@ -35,27 +58,45 @@ pub fn register_export<C: GodotClass, T: Export>(
         }
     }
 
-    register_var::<C, T>(property_name, getter_name, setter_name, hint_info, usage);
+    register_var::<C, T>(
+        property_name,
+        getter_name,
+        setter_name,
+        hint_info,
+        usage,
+        accessors,
+    );
 }
 
-pub fn register_var<C: GodotClass, T: Var>(
+pub fn register_var<C, T: Var>(
     property_name: &str,
     getter_name: &str,
     setter_name: &str,
     hint_info: PropertyHintInfo,
     usage: PropertyUsageFlags,
-) {
+    accessors: PropertyAccessors<C>,
+) where
+    C: Inherits<classes::Object> + Bounds<Declarer = bounds::DeclUser>,
+{
+    let rename = C::__FUNC_RENAME;
     let info = PropertyInfo {
         variant_type: <<T as GodotConvert>::Via as GodotType>::Ffi::variant_type(),
         class_name: <T as GodotConvert>::Via::class_name(),
-        property_name: StringName::from(property_name),
+        property_name: StringName::from(rename.apply(property_name)),
         hint_info,
         usage,
     };
 
     let class_name = C::class_name();
 
-    register_var_or_export_inner(info, class_name, getter_name, setter_name);
+    // Accessors are registered as #[func]s, which follow the same naming policy. Empty names (no getter/setter) stay empty.
+    let getter_name = rename.apply(getter_name);
+    let setter_name = rename.apply(setter_name);
+
+    // Godot-facing name, the same that Object::get() and Object::set() accept.
+    property_table::register_accessors::<C>(info.property_name.clone(), accessors);
+
+    register_var_or_export_inner(info, class_name, &getter_name, &setter_name);
 }
 
 fn register_var_or_export_inner(
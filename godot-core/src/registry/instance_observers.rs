@@ -0,0 +1,137 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Opt-in callbacks when instances of user-defined classes are created or destroyed.
+
+use std::collections::HashMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use godot_ffi as sys;
+
+use crate::godot_error;
+use crate::meta::ClassName;
+use crate::obj::{GodotClass, InstanceId};
+
+type ErasedObserver = Arc<dyn Fn(InstanceEvent, InstanceId) + Send + Sync>;
+
+static OBSERVERS: sys::Global<HashMap<ClassName, Vec<(u64, ErasedObserver)>>> =
+    sys::Global::default();
+
+/// Fast path: avoids locking on every construction/destruction while no observer is registered.
+static HAS_OBSERVERS: AtomicBool = AtomicBool::new(false);
+
+static NEXT_OBSERVER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Lifecycle event passed to instance observers.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum InstanceEvent {
+    /// The instance has been constructed, and its Rust part has been attached to the Godot object.
+    Created,
+
+    /// The instance is about to be destroyed; its Rust part is dropped right after the observers return.
+    Destroyed,
+}
+
+/// Handle to an observer registered with [`observe_instances()`].
+///
+/// Dropping the handle does _not_ remove the observer; call [`remove()`][Self::remove] for that.
+#[derive(Eq, PartialEq, Hash, Debug)]
+#[must_use = "without the handle, the observer cannot be removed"]
+pub struct InstanceObserver {
+    class_name: ClassName,
+    id: u64,
+}
+
+impl InstanceObserver {
+    /// Removes the observer. Creations and destructions after this call are no longer reported.
+    pub fn remove(self) {
+        let mut observers = OBSERVERS.lock();
+
+        if let Some(list) = observers.get_mut(&self.class_name) {
+            list.retain(|(id, _)| *id != self.id);
+            if list.is_empty() {
+                observers.remove(&self.class_name);
+            }
+        }
+
+        HAS_OBSERVERS.store(!observers.is_empty(), Ordering::Release);
+    }
+}
+
+/// Registers `observer` to be called whenever an instance of the user-defined class `T` is created or destroyed.
+///
+/// Useful for object pools, debug overlays, or mirroring Godot objects into an ECS. Observers of one class are called in registration
+/// order. Only instances of exactly `T` are reported, not those of derived classes.
+///
+/// # Restrictions inside the callback
+/// The observer runs in the middle of Godot's object construction or destruction, on the thread performing it. Therefore:
+/// - Do **not** access the object: no `Gd::from_instance_id()`, `bind()`, method calls or signal emissions. During `Created`, the
+///   object may not be fully initialized yet (e.g. `init()` of a script or derived class hasn't run); during `Destroyed`, parts of it
+///   are already torn down.
+/// - Do **not** create or free instances of observed classes, as this re-enters the observers.
+/// - Keep the callback short, and ideally just record the ID (e.g. push it into a queue processed later).
+/// - Do not panic. Panics are caught and reported as Godot errors, but the event is then lost for the remaining observers.
+///
+/// Registering or removing observers from within an observer is allowed, but only takes effect for subsequent events.
+///
+/// Observers are cleared when `T` is unregistered, e.g. on hot reload.
+pub fn observe_instances<T, F>(observer: F) -> InstanceObserver
+where
+    T: GodotClass,
+    F: Fn(InstanceEvent, InstanceId) + Send + Sync + 'static,
+{
+    let class_name = T::class_name();
+    let id = NEXT_OBSERVER_ID.fetch_add(1, Ordering::Relaxed);
+
+    OBSERVERS
+        .lock()
+        .entry(class_name)
+        .or_default()
+        .push((id, Arc::new(observer)));
+
+    HAS_OBSERVERS.store(true, Ordering::Release);
+
+    InstanceObserver { class_name, id }
+}
+
+/// Called from the instance creation and destruction callbacks.
+pub(crate) fn notify<T: GodotClass>(event: InstanceEvent, instance_id: InstanceId) {
+    if !HAS_OBSERVERS.load(Ordering::Acquire) {
+        return;
+    }
+
+    // Clone the list, so observers can (un)register observers without deadlocking.
+    let observers: Vec<ErasedObserver> = {
+        let map = OBSERVERS.lock();
+        match map.get(&T::class_name()) {
+            Some(list) => list.iter().map(|(_, observer)| observer.clone()).collect(),
+            None => return,
+        }
+    };
+
+    for observer in observers {
+        // We are inside an engine callback, so panics must not unwind further.
+        let result = catch_unwind(AssertUnwindSafe(|| observer(event, instance_id)));
+        if result.is_err() {
+            godot_error!(
+                "Instance observer of class {class} panicked during {event:?} of {instance_id}.",
+                class = T::class_name(),
+            );
+            return;
+        }
+    }
+}
+
+/// Removes all observers of `class_name`, when the class is unregistered.
+pub(crate) fn unregister_class(class_name: ClassName) {
+    let mut observers = OBSERVERS.lock();
+    observers.remove(&class_name);
+
+    HAS_OBSERVERS.store(!observers.is_empty(), Ordering::Release);
+}
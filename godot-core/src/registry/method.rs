@@ -47,7 +47,7 @@ impl ClassMethodInfo {
     ///
     /// `call_func`, if provided, must:
     ///
-    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s.
+    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s, or at least that many if `method_flags` contains `VARARG`.
     /// - Return a `Variant`.
     ///
     /// `call_func` and `ptrcall_func`, if provided, must:
@@ -11,10 +11,17 @@
 pub mod callbacks;
 pub mod class;
 pub mod constant;
+pub mod instance_observers;
 pub mod method;
 pub mod plugin;
 pub mod property;
 
+mod flag_set;
+pub(crate) mod property_table;
+
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub(crate) mod editor_icons;
+
 // RpcConfig uses MultiplayerPeer::TransferMode and MultiplayerApi::RpcMode, which are only enabled in `codegen-full` feature.
 #[cfg(feature = "codegen-full")]
 mod rpc_config;
@@ -112,6 +112,23 @@ pub enum PluginItem {
         /// overriding ready.
         default_get_virtual_fn: Option<GodotGetVirtual>,
 
+        /// Reverts `#[export]` fields to their `#[init]` values in the editor. Used if there is no `#[godot_api] impl` block overriding
+        /// `property_get_revert`.
+        default_property_can_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        default_property_get_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
         /// Whether `#[class(tool)]` was used.
         is_tool: bool,
 
@@ -124,6 +141,15 @@ pub enum PluginItem {
         /// Whether the class has a default constructor.
         is_instantiable: bool,
 
+        /// Whether `#[class(abstract)]` was used.
+        is_abstract: bool,
+
+        /// Icon from `#[class(icon = ...)]`: either a resource path or SVG source code.
+        icon: Option<&'static str>,
+
+        /// Classes from `#[class(after = ...)]`, which are registered before this one.
+        register_after: Vec<ClassName>,
+
         #[cfg(all(since_api = "4.3", feature = "register-docs"))]
         docs: Option<StructDocs>,
     },
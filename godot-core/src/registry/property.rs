@@ -11,6 +11,8 @@ use godot_ffi as sys;
 
 use crate::meta::{ClassName, FromGodot, GodotConvert, GodotType, PropertyHintInfo, ToGodot};
 
+pub use super::flag_set::{FlagSet, GodotFlags};
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait definitions
 
@@ -20,6 +22,24 @@ use crate::meta::{ClassName, FromGodot, GodotConvert, GodotType, PropertyHintInf
 /// This creates a copy of the value, according to copy semantics provided by `Clone`. For example, `Array`, `Dictionary` and `Gd` are
 /// returned by shared reference instead of copying the actual data.
 ///
+/// # Cost of property access
+/// [`get_property()`][Self::get_property] is invoked whenever GDScript or the editor's inspector reads a property. For all types
+/// implementing `Var` in this library, it never copies the contents of a value, so it is cheap even for large collections:
+///
+/// | Types                                                                     | Cost of `get_property()`                      |
+/// |---------------------------------------------------------------------------|-----------------------------------------------|
+/// | Scalars, vectors, `Color`, `Transform3D` etc.                             | Bitwise copy.                                 |
+/// | `Array`, `Dictionary`, `Gd`, `Callable`, `Signal`                         | Reference count increment; data is shared.    |
+/// | `GString`, `StringName`, `NodePath`, `Packed*Array`                       | Reference count increment; copy-on-write.     |
+/// | `Variant`                                                                 | Cost of the contained type.                   |
+///
+/// Copy-on-write types share their buffer with the returned value, until either of them is modified. Reading a property thus doesn't
+/// allocate, and a caller modifying the value (e.g. GDScript appending to a `PackedByteArray` it has read) copies the buffer only then,
+/// without affecting the field.
+///
+/// Custom implementations -- including `#[var(get = ...)]` getters -- should keep this property, e.g. by storing Godot types directly
+/// instead of converting from Rust collections such as `Vec<T>` on every access.
+///
 /// This does not require [`FromGodot`] or [`ToGodot`], so that something can be used as a property even if it can't be used in function
 /// arguments/return types.
 ///
@@ -164,9 +184,12 @@ where
 /// Each function is named the same as the equivalent Godot annotation.  
 /// For instance, `@export_range` in Godot is `fn export_range` here.
 pub mod export_info_functions {
-    use crate::builtin::GString;
+    use crate::builtin::{Array, Color, Dictionary, GString, PackedColorArray, VariantType};
+    use crate::classes::ProjectSettings;
     use crate::global::PropertyHint;
-    use crate::meta::PropertyHintInfo;
+    use crate::meta::{ArrayElement, PropertyHintInfo};
+    use crate::obj::EngineEnum;
+    use crate::sys;
 
     /// Turn a list of variables into a comma separated string containing only the identifiers corresponding
     /// to a true boolean variable.
@@ -383,6 +406,133 @@ pub mod export_info_functions {
         }
     }
 
+    /// Like `export_flags_2d_physics()` and its siblings, but shows the layers as a list of flags, labeled with the layer names from the
+    /// project settings (e.g. `layer_names/2d_physics/layer_1`).
+    ///
+    /// `layers` must be one of the `LAYERS_*` property hints. Layers without a name are labeled `Layer N`.
+    ///
+    /// The names are read when this function is called, i.e. during class registration. Layers renamed in the editor show up after the
+    /// extension is reloaded.
+    pub fn export_flags_named_layers(layers: PropertyHint) -> PropertyHintInfo {
+        let (category, layer_count) = match layers {
+            PropertyHint::LAYERS_2D_PHYSICS => ("2d_physics", 32),
+            PropertyHint::LAYERS_2D_RENDER => ("2d_render", 20),
+            PropertyHint::LAYERS_2D_NAVIGATION => ("2d_navigation", 32),
+            PropertyHint::LAYERS_3D_PHYSICS => ("3d_physics", 32),
+            PropertyHint::LAYERS_3D_RENDER => ("3d_render", 20),
+            PropertyHint::LAYERS_3D_NAVIGATION => ("3d_navigation", 32),
+            other => panic!("export_flags_named_layers(): expected a LAYERS_* hint, got {other:?}"),
+        };
+
+        let settings = ProjectSettings::singleton();
+        let flags: Vec<String> = (1..=layer_count)
+            .map(|layer| {
+                let name = settings
+                    .get_setting(&format!("layer_names/{category}/layer_{layer}"))
+                    .try_to::<GString>()
+                    .map(|name| name.to_string())
+                    .unwrap_or_default();
+
+                // ',' and ':' are separators in the hint string.
+                let name = if name.is_empty() {
+                    format!("Layer {layer}")
+                } else {
+                    name.replace([',', ':'], " ")
+                };
+
+                format!("{name}:{}", 1u32 << (layer - 1))
+            })
+            .collect();
+
+        PropertyHintInfo {
+            hint: PropertyHint::FLAGS,
+            hint_string: flags.join(",").into(),
+        }
+    }
+
+    /// Equivalent to `@export var dict: Dictionary[K, V]` in GDScript.
+    ///
+    /// You'll never call this function itself, but will instead use `#[export(dict_types = (K, V))]` on a `Dictionary` field. The
+    /// editor then shows a typed dictionary editor, which only accepts keys of type `K` and values of type `V`. The Rust field itself
+    /// stays an untyped `Dictionary`.
+    ///
+    /// Typed dictionaries are available since Godot 4.4. On earlier engine versions, the field is exported as an untyped dictionary.
+    ///
+    /// ```
+    /// # use godot::prelude::*;
+    /// #[derive(GodotClass)]
+    /// #[class(init, base=Node)]
+    /// struct Inventory {
+    ///     #[export(dict_types = (GString, i64))]
+    ///     item_counts: Dictionary,
+    /// }
+    /// ```
+    pub fn export_dictionary_types<K, V>() -> PropertyHintInfo
+    where
+        K: ArrayElement,
+        V: ArrayElement,
+    {
+        if sys::GdextBuild::godot_runtime_version_triple() < (4, 4, 0) {
+            return PropertyHintInfo::type_name::<Dictionary>();
+        }
+
+        // Key and value use the same format as array elements, separated by ';'.
+        let hint_string = format!(
+            "{key};{value}",
+            key = K::element_type_string(),
+            value = V::element_type_string()
+        );
+
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: hint_string.into(),
+        }
+    }
+
+    /// Types that can be exported with `#[export(color_no_alpha)]`: [`Color`] and collections of it.
+    ///
+    /// For collections, the hint applies to each element, like `@export_color_no_alpha` on `Array[Color]` or `PackedColorArray` in GDScript.
+    pub trait ExportColorNoAlpha {
+        #[doc(hidden)]
+        fn color_no_alpha_hint() -> PropertyHintInfo;
+    }
+
+    impl ExportColorNoAlpha for Color {
+        fn color_no_alpha_hint() -> PropertyHintInfo {
+            export_color_no_alpha()
+        }
+    }
+
+    impl ExportColorNoAlpha for PackedColorArray {
+        fn color_no_alpha_hint() -> PropertyHintInfo {
+            color_no_alpha_elements()
+        }
+    }
+
+    impl ExportColorNoAlpha for Array<Color> {
+        fn color_no_alpha_hint() -> PropertyHintInfo {
+            color_no_alpha_elements()
+        }
+    }
+
+    /// Used by `#[export(color_no_alpha)]`, dispatching on the field type.
+    pub fn export_color_no_alpha_for<T: ExportColorNoAlpha>() -> PropertyHintInfo {
+        T::color_no_alpha_hint()
+    }
+
+    fn color_no_alpha_elements() -> PropertyHintInfo {
+        let hint_string = format!(
+            "{variant}/{hint}:",
+            variant = VariantType::COLOR.ord(),
+            hint = PropertyHint::COLOR_NO_ALPHA.ord()
+        );
+
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: hint_string.into(),
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
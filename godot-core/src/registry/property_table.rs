@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Per-class table of `#[var]` accessors, to read and write properties by name without going through Godot.
+//!
+//! Accessors are collected while registering properties. On first use, [`GodotClass::__property_table()`] copies those of its class
+//! into a `static` owned by the class, so that [`Gd::get_fast()`] and [`Gd::set_fast()`] only do a single hash lookup, without locking.
+//!
+//! Only these Rust-side methods use the table. Godot's own `Object::get()`/`set()` resolve `#[var]` properties through ClassDB, which
+//! looks up the registered getter/setter methods by name and calls them via their method binds; gdext has no hook into that lookup.
+//! The `get_property()`/`set_property()` virtuals are not an alternative, as Godot calls them for every property access and they
+//! would change the semantics of user overrides.
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use godot_ffi as sys;
+
+use crate::builtin::StringName;
+use crate::meta::ClassName;
+use crate::obj::GodotClass;
+use crate::registry::godot_register_wrappers::PropertyAccessors;
+
+/// Accessors registered for each class, as `PropertyTable<C>`. Only locked during registration and when a class table is first used.
+static PENDING_TABLES: sys::Global<HashMap<ClassName, Box<dyn Any + Send>>> =
+    sys::Global::default();
+
+/// `#[var]` accessors of a user class `C`, by Godot-facing property name.
+#[doc(hidden)]
+pub struct PropertyTable<C: GodotClass> {
+    accessors: HashMap<StringName, PropertyAccessors<C>>,
+}
+
+impl<C: GodotClass> PropertyTable<C> {
+    /// Copies the accessors registered for `C` so far.
+    ///
+    /// Called once per class, from the `static` generated by `#[derive(GodotClass)]`. Classes are registered before any instance exists,
+    /// so the table is complete by then.
+    pub fn collect() -> Self {
+        let tables = PENDING_TABLES.lock();
+        let accessors = tables
+            .get(&C::class_name())
+            .and_then(|table| table.downcast_ref::<Self>())
+            .map(|table| table.accessors.clone())
+            .unwrap_or_default();
+
+        Self { accessors }
+    }
+
+    pub(crate) fn get(&self, property_name: &StringName) -> Option<&PropertyAccessors<C>> {
+        self.accessors.get(property_name)
+    }
+}
+
+/// Stores the accessors of property `property_name` for class `C`.
+pub(crate) fn register_accessors<C: GodotClass>(
+    property_name: StringName,
+    accessors: PropertyAccessors<C>,
+) {
+    let mut tables = PENDING_TABLES.lock();
+    let table = tables.entry(C::class_name()).or_insert_with(|| {
+        Box::new(PropertyTable::<C> {
+            accessors: HashMap::new(),
+        })
+    });
+
+    table
+        .downcast_mut::<PropertyTable<C>>()
+        .expect("property table has the class's type")
+        .accessors
+        .insert(property_name, accessors);
+}
+
+/// Removes all accessors of `class_name`, when the class is unregistered.
+pub(crate) fn unregister_class(class_name: ClassName) {
+    PENDING_TABLES.lock().remove(&class_name);
+}
@@ -0,0 +1,335 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+use super::{to_variant, Error, PathSegment};
+#[cfg(since_api = "4.3")]
+use crate::builtin::PackedVector4Array;
+use crate::builtin::{
+    Aabb, Basis, Color, Dictionary, PackedByteArray, PackedColorArray, PackedFloat32Array,
+    PackedFloat64Array, PackedInt32Array, PackedInt64Array, PackedStringArray, PackedVector2Array,
+    PackedVector3Array, Plane, Projection, Quaternion, Rect2, Rect2i, Transform2D, Transform3D,
+    Variant, VariantArray, VariantType, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
+
+/// Deserializer reading from a `Variant`.
+pub(super) struct VariantDeserializer {
+    variant: Variant,
+}
+
+impl VariantDeserializer {
+    pub fn new(variant: Variant) -> Self {
+        Self { variant }
+    }
+
+    fn unsupported(&self) -> Error {
+        Error::new(format!(
+            "cannot deserialize Variant of type {:?}",
+            self.variant.get_type()
+        ))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for VariantDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        // Godot math types and packed arrays are routed through their own `Serialize` impls, yielding a Dictionary or Array.
+        macro_rules! reserialize {
+            ($T:ty) => {{
+                let value: $T = self.variant.to();
+                let deserializer = VariantDeserializer::new(to_variant(&value)?);
+                de::Deserializer::deserialize_any(deserializer, visitor)
+            }};
+            (packed $T:ty) => {{
+                let value: $T = self.variant.to();
+                let deserializer = VariantDeserializer::new(to_variant(value.as_slice())?);
+                de::Deserializer::deserialize_any(deserializer, visitor)
+            }};
+        }
+
+        match self.variant.get_type() {
+            VariantType::NIL => visitor.visit_unit(),
+            VariantType::BOOL => visitor.visit_bool(self.variant.to()),
+            VariantType::INT => visitor.visit_i64(self.variant.to()),
+            VariantType::FLOAT => visitor.visit_f64(self.variant.to()),
+            VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH => {
+                visitor.visit_string(self.variant.stringify().to_string())
+            }
+            VariantType::ARRAY => {
+                let array: VariantArray = self.variant.to();
+                visitor.visit_seq(SeqAccess::new(array.iter_shared().collect()))
+            }
+            VariantType::DICTIONARY => {
+                let dict: Dictionary = self.variant.to();
+                visitor.visit_map(MapAccess::new(dict.iter_shared().collect()))
+            }
+            VariantType::PACKED_BYTE_ARRAY => {
+                let bytes: PackedByteArray = self.variant.to();
+                visitor.visit_byte_buf(bytes.as_slice().to_vec())
+            }
+
+            VariantType::VECTOR2 => reserialize!(Vector2),
+            VariantType::VECTOR2I => reserialize!(Vector2i),
+            VariantType::RECT2 => reserialize!(Rect2),
+            VariantType::RECT2I => reserialize!(Rect2i),
+            VariantType::VECTOR3 => reserialize!(Vector3),
+            VariantType::VECTOR3I => reserialize!(Vector3i),
+            VariantType::TRANSFORM2D => reserialize!(Transform2D),
+            VariantType::VECTOR4 => reserialize!(Vector4),
+            VariantType::VECTOR4I => reserialize!(Vector4i),
+            VariantType::PLANE => reserialize!(Plane),
+            VariantType::QUATERNION => reserialize!(Quaternion),
+            VariantType::AABB => reserialize!(Aabb),
+            VariantType::BASIS => reserialize!(Basis),
+            VariantType::TRANSFORM3D => reserialize!(Transform3D),
+            VariantType::PROJECTION => reserialize!(Projection),
+            VariantType::COLOR => reserialize!(Color),
+
+            VariantType::PACKED_INT32_ARRAY => reserialize!(packed PackedInt32Array),
+            VariantType::PACKED_INT64_ARRAY => reserialize!(packed PackedInt64Array),
+            VariantType::PACKED_FLOAT32_ARRAY => reserialize!(packed PackedFloat32Array),
+            VariantType::PACKED_FLOAT64_ARRAY => reserialize!(packed PackedFloat64Array),
+            VariantType::PACKED_STRING_ARRAY => reserialize!(packed PackedStringArray),
+            VariantType::PACKED_VECTOR2_ARRAY => reserialize!(packed PackedVector2Array),
+            VariantType::PACKED_VECTOR3_ARRAY => reserialize!(packed PackedVector3Array),
+            VariantType::PACKED_COLOR_ARRAY => reserialize!(packed PackedColorArray),
+            #[cfg(since_api = "4.3")]
+            VariantType::PACKED_VECTOR4_ARRAY => reserialize!(packed PackedVector4Array),
+
+            // Objects, callables, signals, RIDs.
+            _ => Err(self.unsupported()),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.variant.is_nil() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.variant.get_type() {
+            // Unit variant.
+            VariantType::STRING | VariantType::STRING_NAME => {
+                let variant = self.variant.stringify().to_string();
+                visitor.visit_enum(variant.into_deserializer())
+            }
+
+            // { variant: content }
+            VariantType::DICTIONARY => {
+                let dict: Dictionary = self.variant.to();
+                let mut entries = dict.iter_shared();
+
+                match (entries.next(), entries.next()) {
+                    (Some((key, content)), None) => visitor.visit_enum(EnumAccess {
+                        variant: key_to_string(&key)?,
+                        content,
+                    }),
+                    _ => Err(Error::new(format!(
+                        "expected enum as Dictionary with exactly 1 entry, got {} entries",
+                        dict.len()
+                    ))),
+                }
+            }
+
+            other => Err(Error::new(format!(
+                "expected enum as String or Dictionary, got Variant of type {other:?}"
+            ))),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Access types
+
+struct SeqAccess {
+    elements: std::vec::IntoIter<Variant>,
+    index: usize,
+}
+
+impl SeqAccess {
+    fn new(elements: Vec<Variant>) -> Self {
+        Self {
+            elements: elements.into_iter(),
+            index: 0,
+        }
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        let Some(element) = self.elements.next() else {
+            return Ok(None);
+        };
+
+        let index = self.index;
+        self.index += 1;
+
+        seed.deserialize(VariantDeserializer::new(element))
+            .map(Some)
+            .map_err(|e| e.within(PathSegment::Index(index)))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+struct MapAccess {
+    entries: std::vec::IntoIter<(Variant, Variant)>,
+    current_key: Option<Variant>,
+    current_value: Option<Variant>,
+}
+
+impl MapAccess {
+    fn new(entries: Vec<(Variant, Variant)>) -> Self {
+        Self {
+            entries: entries.into_iter(),
+            current_key: None,
+            current_value: None,
+        }
+    }
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+
+        self.current_key = Some(key.clone());
+        self.current_value = Some(value);
+
+        seed.deserialize(VariantDeserializer::new(key)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = self
+            .current_value
+            .take()
+            .expect("next_value_seed() called before next_key_seed()");
+        let key = self.current_key.take().unwrap_or_else(Variant::nil);
+
+        seed.deserialize(VariantDeserializer::new(value))
+            .map_err(|e| e.within(PathSegment::Key(key.stringify().to_string())))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct EnumAccess {
+    variant: String,
+    content: Variant,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess {
+    type Error = Error;
+    type Variant = VariantAccess;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantAccess), Error> {
+        let variant = seed.deserialize(self.variant.as_str().into_deserializer())?;
+        let access = VariantAccess {
+            variant: self.variant,
+            content: self.content,
+        };
+
+        Ok((variant, access))
+    }
+}
+
+struct VariantAccess {
+    variant: String,
+    content: Variant,
+}
+
+impl VariantAccess {
+    fn content(self) -> (VariantDeserializer, PathSegment) {
+        (
+            VariantDeserializer::new(self.content),
+            PathSegment::Key(self.variant),
+        )
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        let (deserializer, segment) = self.content();
+        seed.deserialize(deserializer)
+            .map_err(|e| e.within(segment))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        let (deserializer, segment) = self.content();
+        de::Deserializer::deserialize_seq(deserializer, visitor).map_err(|e| e.within(segment))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let (deserializer, segment) = self.content();
+        de::Deserializer::deserialize_map(deserializer, visitor).map_err(|e| e.within(segment))
+    }
+}
+
+fn key_to_string(key: &Variant) -> Result<String, Error> {
+    match key.get_type() {
+        VariantType::STRING | VariantType::STRING_NAME => Ok(key.stringify().to_string()),
+        other => Err(Error::new(format!(
+            "expected enum variant name as String, got Variant of type {other:?}"
+        ))),
+    }
+}
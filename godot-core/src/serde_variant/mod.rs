@@ -0,0 +1,196 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversion between `Variant` and Rust types implementing serde's `Serialize`/`Deserialize`.
+//!
+//! This is an alternative to implementing [`ToGodot`][crate::meta::ToGodot]/[`FromGodot`][crate::meta::FromGodot] by hand, mostly
+//! useful for configuration-like data that travels as nested `Dictionary`/`Array` values (e.g. parsed JSON, or values from GDScript).
+//!
+//! # Mapping
+//! | Rust (serde data model)                    | Godot                                                  |
+//! |--------------------------------------------|--------------------------------------------------------|
+//! | `bool`                                     | `bool`                                                 |
+//! | integers                                   | `int` (`u64` values above `i64::MAX` are an error)     |
+//! | `f32`, `f64`                               | `float`                                                |
+//! | `char`, strings                            | `String` (`StringName` and `NodePath` are accepted)    |
+//! | bytes                                      | `PackedByteArray`                                      |
+//! | `None`, `()`, unit structs                 | `null`                                                 |
+//! | `Some(value)`, newtype structs             | value itself                                           |
+//! | sequences, tuples                          | `Array` (packed arrays are accepted)                   |
+//! | maps                                       | `Dictionary`                                           |
+//! | structs                                    | `Dictionary` with `String` keys (`StringName` keys are accepted) |
+//! | unit enum variants                         | `String` with the variant name                         |
+//! | other enum variants                        | `Dictionary` with a single entry `{ variant: content }` |
+//!
+//! Godot math types such as `Vector2` or `Color` use their own `Serialize` impls, and are thus stored as a `Dictionary` of their
+//! components. When deserializing, both that `Dictionary` and the native Godot type are accepted.
+//!
+//! # Errors
+//! Errors report the path to the value that could not be converted, e.g. ``at `players[2].name`: invalid type: integer `5`, expected
+//! a string``.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     title: String,
+//!     volume: f32,
+//!     players: Vec<Player>,
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Player {
+//!     name: String,
+//!     color: Option<Color>,
+//! }
+//!
+//! // E.g. a Dictionary passed from GDScript.
+//! fn load_config(variant: &Variant) -> Config {
+//!     match godot::serde::from_variant(variant) {
+//!         Ok(config) => config,
+//!         Err(e) => panic!("invalid config {e}"), // "at `players[1].name`: invalid type..."
+//!     }
+//! }
+//!
+//! fn store_config(config: &Config) -> Variant {
+//!     godot::serde::to_variant(config).expect("Config is representable as Variant")
+//! }
+//! ```
+
+mod de;
+mod ser;
+
+use std::fmt;
+
+use crate::builtin::Variant;
+
+/// Converts a Rust value to a `Variant`, using its `Serialize` impl.
+///
+/// See [module docs](self) for how the serde data model is mapped to Godot types.
+pub fn to_variant<T>(value: &T) -> Result<Variant, Error>
+where
+    T: serde::Serialize + ?Sized,
+{
+    value.serialize(ser::VariantSerializer)
+}
+
+/// Converts a `Variant` to a Rust value, using its `Deserialize` impl.
+///
+/// See [module docs](self) for how Godot types are mapped to the serde data model.
+pub fn from_variant<T>(variant: &Variant) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    T::deserialize(de::VariantDeserializer::new(variant.clone()))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error during [`to_variant()`] or [`from_variant()`], with the path to the offending value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+
+    /// Innermost segment first, since errors are built up while unwinding out of nested values.
+    reversed_path: Vec<PathSegment>,
+}
+
+impl Error {
+    fn new(message: impl fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+            reversed_path: Vec::new(),
+        }
+    }
+
+    /// Message describing the error, without the path.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Path to the value that caused the error, e.g. `players[2].name`. Empty if the error concerns the top-level value.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in self.reversed_path.iter().rev() {
+            match segment {
+                PathSegment::Key(key) if path.is_empty() => path.push_str(key),
+                PathSegment::Key(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                PathSegment::Index(index) => {
+                    path.push_str(&format!("[{index}]"));
+                }
+            }
+        }
+
+        path
+    }
+
+    fn within(mut self, segment: PathSegment) -> Self {
+        self.reversed_path.push(segment);
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.reversed_path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "at `{}`: {}", self.path(), self.message)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{Error, PathSegment};
+
+    #[test]
+    fn error_path() {
+        let err = Error::new("invalid type")
+            .within(PathSegment::Key("name".to_string()))
+            .within(PathSegment::Index(2))
+            .within(PathSegment::Key("players".to_string()));
+
+        assert_eq!(err.path(), "players[2].name");
+        assert_eq!(err.to_string(), "at `players[2].name`: invalid type");
+
+        let err = Error::new("oops").within(PathSegment::Index(0));
+        assert_eq!(err.path(), "[0]");
+
+        let err = Error::new("top-level");
+        assert_eq!(err.path(), "");
+        assert_eq!(err.to_string(), "top-level");
+    }
+}
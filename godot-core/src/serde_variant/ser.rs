@@ -0,0 +1,357 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use serde::ser::{self, Serialize};
+
+use super::{Error, PathSegment};
+use crate::builtin::{Dictionary, GString, PackedByteArray, Variant, VariantArray};
+use crate::meta::ToGodot;
+
+/// Serializer producing a `Variant`.
+pub(super) struct VariantSerializer;
+
+impl ser::Serializer for VariantSerializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantWrapper<SeqSerializer>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = VariantWrapper<MapSerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<Variant, Error> {
+        Ok(v.to_variant())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Variant, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Variant, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Variant, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Variant, Error> {
+        Ok(v.to_variant())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Variant, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Variant, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Variant, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Variant, Error> {
+        let v = i64::try_from(v)
+            .map_err(|_| Error::new(format!("integer {v} does not fit into Godot's int (i64)")))?;
+
+        self.serialize_i64(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Variant, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Variant, Error> {
+        Ok(v.to_variant())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Variant, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Variant, Error> {
+        Ok(GString::from(v).to_variant())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Variant, Error> {
+        Ok(PackedByteArray::from(v).to_variant())
+    }
+
+    fn serialize_none(self) -> Result<Variant, Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Variant, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Variant, Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Variant, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Variant, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Variant, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Variant, Error> {
+        let content = value
+            .serialize(VariantSerializer)
+            .map_err(|e| e.within(PathSegment::Key(variant.to_string())))?;
+
+        Ok(wrap_variant(variant, content))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer {
+            array: VariantArray::new(),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Ok(VariantWrapper {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer, Error> {
+        Ok(MapSerializer {
+            dict: Dictionary::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapSerializer, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Ok(VariantWrapper {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Compound serializers
+
+pub(super) struct SeqSerializer {
+    array: VariantArray,
+}
+
+impl SeqSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let element = value
+            .serialize(VariantSerializer)
+            .map_err(|e| e.within(PathSegment::Index(self.array.len())))?;
+
+        self.array.push(&element);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(self.array.to_variant())
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+pub(super) struct MapSerializer {
+    dict: Dictionary,
+    next_key: Option<Variant>,
+}
+
+impl MapSerializer {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: Variant, value: &T) -> Result<(), Error> {
+        let value = value
+            .serialize(VariantSerializer)
+            .map_err(|e| e.within(PathSegment::Key(key.stringify().to_string())))?;
+
+        self.dict.set(key, value);
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.next_key = Some(key.serialize(VariantSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .next_key
+            .take()
+            .expect("serialize_value() called before serialize_key()");
+
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(self.dict.to_variant())
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(GString::from(key).to_variant(), value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+/// Wraps the content of an enum variant in a single-entry dictionary `{ variant: content }`.
+pub(super) struct VariantWrapper<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl VariantWrapper<SeqSerializer> {
+    fn end_wrapped(self) -> Result<Variant, Error> {
+        let content = ser::SerializeSeq::end(self.inner)?;
+        Ok(wrap_variant(self.variant, content))
+    }
+}
+
+impl ser::SerializeTupleVariant for VariantWrapper<SeqSerializer> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let variant = self.variant;
+        self.inner
+            .push(value)
+            .map_err(|e| e.within(PathSegment::Key(variant.to_string())))
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.end_wrapped()
+    }
+}
+
+impl ser::SerializeStructVariant for VariantWrapper<MapSerializer> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let variant = self.variant;
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+            .map_err(|e| e.within(PathSegment::Key(variant.to_string())))
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        let content = ser::SerializeMap::end(self.inner)?;
+        Ok(wrap_variant(self.variant, content))
+    }
+}
+
+fn wrap_variant(variant: &str, content: Variant) -> Variant {
+    let mut dict = Dictionary::new();
+    dict.set(GString::from(variant), content);
+    dict.to_variant()
+}
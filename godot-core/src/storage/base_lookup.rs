@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Lookup of the object that owns a user instance, for classes without a `Base<T>` field.
+//!
+//! Such classes have no way to get from `&self` to their object. Instead, the address of each user instance is registered when its
+//! storage is constructed. User instances live inside a pinned `GdCell` allocation, so the address is stable and unique while alive.
+
+use std::collections::HashMap;
+
+use godot_ffi as sys;
+
+use crate::obj::{GodotClass, InstanceId};
+
+static INSTANCE_IDS: sys::Global<HashMap<usize, InstanceId>> = sys::Global::default();
+
+/// Registers the user instance `instance`, which belongs to the object `instance_id`.
+pub(super) fn on_construct<T: GodotClass>(instance: &T, instance_id: InstanceId) {
+    INSTANCE_IDS
+        .lock()
+        .insert(address_of(instance), instance_id);
+}
+
+/// Unregisters the user instance `instance`, when its storage is destroyed.
+pub(super) fn on_destroy<T: GodotClass>(instance: &T) {
+    INSTANCE_IDS.lock().remove(&address_of(instance));
+}
+
+/// Returns the ID of the object owning `instance`, if `T` uses base lookup and the instance is registered.
+///
+/// Returns `None` during construction (before the storage exists) and destruction of the instance.
+pub(crate) fn find_instance_id<T: GodotClass>(instance: &T) -> Option<InstanceId> {
+    INSTANCE_IDS.lock().get(&address_of(instance)).copied()
+}
+
+fn address_of<T>(instance: &T) -> usize {
+    instance as *const T as usize
+}
@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Debug-only counting of live instances of user-defined classes.
+//!
+//! Used to enforce `#[class(max_instances = N)]` budgets, and to detect leaks in tests.
+//!
+//! Only classes with a budget are counted by default, so that other classes don't pay for the global lock on each construction and
+//! destruction. Counting of all classes is enabled with [`set_count_all_instances()`].
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use godot_ffi as sys;
+
+use crate::godot_error;
+use crate::meta::ClassName;
+use crate::obj::GodotClass;
+
+struct LiveInstances {
+    count: usize,
+
+    /// Whether the budget is currently exceeded and this has been reported. Avoids flooding the output with one error per instance.
+    reported: bool,
+}
+
+static LIVE_INSTANCES: sys::Global<HashMap<ClassName, LiveInstances>> = sys::Global::default();
+static COUNT_ALL: AtomicBool = AtomicBool::new(false);
+
+fn is_counted<T: GodotClass>() -> bool {
+    T::__MAX_INSTANCES.is_some() || COUNT_ALL.load(Ordering::Relaxed)
+}
+
+/// Registers a new instance of `T`. Reports an error once the budget is exceeded, if `T` declares one.
+///
+/// Returns whether the instance is counted; this must be passed to [`on_destroy()`] for the same instance.
+pub(super) fn on_construct<T: GodotClass>() -> bool {
+    if !is_counted::<T>() {
+        return false;
+    }
+
+    let exceeded_count = {
+        let mut map = LIVE_INSTANCES.lock();
+        let live = map.entry(T::class_name()).or_insert(LiveInstances {
+            count: 0,
+            reported: false,
+        });
+
+        live.count += 1;
+
+        match T::__MAX_INSTANCES {
+            Some(max) if live.count > max && !live.reported => {
+                live.reported = true;
+                Some((live.count, max))
+            }
+            _ => None,
+        }
+    }; // Lock released before printing.
+
+    if let Some((count, max)) = exceeded_count {
+        godot_error!(
+            "Class {class} exceeded its instance budget: {count} live instances, but #[class(max_instances = {max})].\n  \
+            This may indicate a leak or a runaway spawner. Further excess instances are not reported until the count drops.",
+            class = T::class_name(),
+        );
+    }
+
+    true
+}
+
+/// Unregisters a destroyed instance of `T`.
+///
+/// `counted` is the result of [`on_construct()`] for this instance. Only instances that were counted on construction are subtracted, so
+/// toggling [`set_count_all_instances()`] while instances are alive doesn't skew the counts.
+pub(super) fn on_destroy<T: GodotClass>(counted: bool) {
+    if !counted {
+        return;
+    }
+
+    let mut map = LIVE_INSTANCES.lock();
+    if let Some(live) = map.get_mut(&T::class_name()) {
+        live.count = live.count.saturating_sub(1);
+        if T::__MAX_INSTANCES.is_some_and(|max| live.count <= max) {
+            live.reported = false;
+        }
+    }
+}
+
+/// Number of live instances of `T`, if `T` declares an instance budget.
+pub fn live_instance_count<T: GodotClass>() -> Option<usize> {
+    T::__MAX_INSTANCES?;
+
+    let map = LIVE_INSTANCES.lock();
+    Some(map.get(&T::class_name()).map_or(0, |live| live.count))
+}
+
+/// Enables counting instances of all user-defined classes, not only those declaring `#[class(max_instances = N)]`.
+///
+/// Only instances constructed while enabled are counted (and subtracted again on destruction); instances that are already alive are ignored.
+/// Returns the previous value, so callers can restore it.
+pub fn set_count_all_instances(enabled: bool) -> bool {
+    COUNT_ALL.swap(enabled, Ordering::Relaxed)
+}
+
+/// Number of live instances for each counted user-defined class, sorted by class name. Classes without live instances are omitted.
+///
+/// Only classes with an instance budget are counted, unless [`set_count_all_instances()`] is enabled.
+pub fn live_instance_counts() -> Vec<(ClassName, usize)> {
+    let map = LIVE_INSTANCES.lock();
+    let mut counts: Vec<(ClassName, usize)> = map
+        .iter()
+        .filter(|(_, live)| live.count > 0)
+        .map(|(class_name, live)| (*class_name, live.count))
+        .collect();
+
+    counts.sort_by_cached_key(|(class_name, _)| class_name.to_string());
+    counts
+}
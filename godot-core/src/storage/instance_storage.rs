@@ -5,7 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 use godot_ffi as sys;
+use std::any::type_name;
 use std::cell::Cell;
+use std::error::Error;
 use std::ptr;
 
 #[cfg(not(feature = "experimental-threads"))]
@@ -14,6 +16,11 @@ use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 #[cfg(feature = "experimental-threads")]
 use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
 
+use crate::meta::error::BindError;
+#[cfg(debug_assertions)]
+use crate::meta::ClassName;
+#[cfg(debug_assertions)]
+use crate::obj::BorrowInfo;
 use crate::obj::{Base, Gd, GodotClass, Inherits};
 use crate::{godot_error, out};
 
@@ -81,17 +88,75 @@ pub unsafe trait Storage {
     /// The base object that this storage contains.
     fn base(&self) -> &Base<<Self::Instance as GodotClass>::Base>;
 
+    /// Returns a shared reference to this storage's instance, or an error if it is bound exclusively.
+    fn try_get(&self) -> Result<RefGuard<'_, Self::Instance>, BindError>;
+
+    /// Returns a mutable/exclusive reference to this storage's instance, or an error if it is bound.
+    fn try_get_mut(&self) -> Result<MutGuard<'_, Self::Instance>, BindError>;
+
+    /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`, or an error if the instance cannot be
+    /// made accessible for re-entrant calls.
+    fn try_get_inaccessible<'a: 'b, 'b>(
+        &'a self,
+        instance: &'b mut Self::Instance,
+    ) -> Result<InaccessibleGuard<'b, Self::Instance>, BindError>;
+
+    /// Records the borrows of this storage's instance, to report them in errors.
+    #[cfg(debug_assertions)]
+    fn borrow_tracker(&self) -> &BorrowTracker;
+
+    /// Number of live shared and exclusive borrows of this storage's instance, including inaccessible ones.
+    #[cfg(debug_assertions)]
+    fn borrow_count(&self) -> usize;
+
     /// Returns a shared reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
     /// they are violated.
-    fn get(&self) -> RefGuard<'_, Self::Instance>;
+    #[track_caller]
+    fn get(&self) -> RefGuard<'_, Self::Instance> {
+        self.try_get().unwrap_or_else(|err| panic!("{err}"))
+    }
 
     /// Returns a mutable/exclusive reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
     /// they are violated.
-    fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
+    #[track_caller]
+    fn get_mut(&self) -> MutGuard<'_, Self::Instance> {
+        self.try_get_mut().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like [`get()`](Storage::get()), but attributes the borrow to `method` (e.g. `"my_func"`) of the instance's class in diagnostics.
+    ///
+    /// Used for calls from Godot into `&self` methods.
+    #[track_caller]
+    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
+    fn get_for_call(&self, method: &'static str) -> RefGuard<'_, Self::Instance> {
+        let guard = self.get();
+
+        #[cfg(debug_assertions)]
+        self.borrow_tracker()
+            .set_method(Self::Instance::class_name(), method);
+
+        guard
+    }
+
+    /// Like [`get_mut()`](Storage::get_mut()), but attributes the borrow to `method` (e.g. `"my_func"`) of the instance's class in
+    /// diagnostics.
+    ///
+    /// Used for calls from Godot into `&mut self` methods.
+    #[track_caller]
+    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
+    fn get_mut_for_call(&self, method: &'static str) -> MutGuard<'_, Self::Instance> {
+        let guard = self.get_mut();
+
+        #[cfg(debug_assertions)]
+        self.borrow_tracker()
+            .set_method(Self::Instance::class_name(), method);
+
+        guard
+    }
 
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
@@ -100,7 +165,16 @@ pub unsafe trait Storage {
     fn get_inaccessible<'a: 'b, 'b>(
         &'a self,
         instance: &'b mut Self::Instance,
-    ) -> InaccessibleGuard<'b, Self::Instance>;
+    ) -> InaccessibleGuard<'b, Self::Instance> {
+        self.try_get_inaccessible(instance)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the innermost live borrow of this storage's instance, if it is currently bound.
+    #[cfg(debug_assertions)]
+    fn borrow_info(&self) -> Option<BorrowInfo> {
+        self.borrow_tracker().current(self.borrow_count())
+    }
 
     /// Returns whether this storage is currently alive or being destroyed.
     ///
@@ -158,6 +232,93 @@ pub unsafe trait Storage {
     }
 }
 
+/// Remembers the live borrows of a user instance, so that failing binds can name the innermost one.
+///
+/// Guards are not tracked individually. Instead, borrows are kept as a stack, which is trimmed to the number of live borrows reported by
+/// the cell whenever it is accessed. Nested borrows (e.g. through `base_mut()`) thus end in the same order as they started; only the
+/// order among several shared borrows can be mixed up.
+#[cfg(debug_assertions)]
+#[derive(Default)]
+pub struct BorrowTracker {
+    stack: BorrowStack,
+}
+
+// Without threads, instances are only borrowed from the main thread.
+#[cfg(all(debug_assertions, not(feature = "experimental-threads")))]
+type BorrowStack = std::cell::RefCell<Vec<BorrowInfo>>;
+#[cfg(all(debug_assertions, feature = "experimental-threads"))]
+type BorrowStack = std::sync::Mutex<Vec<BorrowInfo>>;
+
+#[cfg(debug_assertions)]
+impl BorrowTracker {
+    /// Records a new borrow; `live_count` includes it.
+    pub(super) fn record(
+        &self,
+        mutable: bool,
+        location: &'static std::panic::Location<'static>,
+        live_count: usize,
+    ) {
+        self.with_stack(|stack| {
+            stack.truncate(live_count.saturating_sub(1));
+            stack.push(BorrowInfo::new(mutable, location));
+        });
+    }
+
+    fn set_method(&self, class_name: ClassName, method: &'static str) {
+        self.with_stack(|stack| {
+            if let Some(info) = stack.last_mut() {
+                info.set_method(class_name, method);
+            }
+        });
+    }
+
+    /// Innermost borrow that is still live, given the number of live borrows.
+    fn current(&self, live_count: usize) -> Option<BorrowInfo> {
+        self.with_stack(|stack| {
+            stack.truncate(live_count);
+            stack.last().copied()
+        })
+    }
+
+    #[cfg(not(feature = "experimental-threads"))]
+    fn with_stack<R>(&self, f: impl FnOnce(&mut Vec<BorrowInfo>) -> R) -> R {
+        f(&mut self.stack.borrow_mut())
+    }
+
+    #[cfg(feature = "experimental-threads")]
+    fn with_stack<R>(&self, f: impl FnOnce(&mut Vec<BorrowInfo>) -> R) -> R {
+        // Only plain data is stored, so a poisoned lock is still consistent.
+        let mut stack = self
+            .stack
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        f(&mut stack)
+    }
+}
+
+/// Error for a failed `borrow()` or `borrow_mut()` of the instance cell, naming the conflicting borrow if known.
+pub(super) fn already_bound_error<S: Storage>(
+    storage: &S,
+    mutable: bool,
+    err: Box<dyn Error>,
+) -> BindError {
+    #[cfg(debug_assertions)]
+    let held_by = storage.borrow_info();
+    #[cfg(not(debug_assertions))]
+    let held_by = {
+        let _ = storage;
+        None
+    };
+
+    BindError::already_bound(
+        type_name::<S::Instance>(),
+        mutable,
+        held_by,
+        err.to_string(),
+    )
+}
+
 /// An internal trait for keeping track of reference counts for a storage.
 pub(crate) trait StorageRefCounted: Storage {
     fn godot_ref_count(&self) -> u32;
@@ -5,10 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod base_lookup;
+#[cfg(debug_assertions)]
+mod instance_budget;
 mod instance_storage;
 #[cfg_attr(not(feature = "experimental-threads"), allow(dead_code))]
 mod multi_threaded;
 #[cfg_attr(feature = "experimental-threads", allow(dead_code))]
 mod single_threaded;
 
+pub(crate) use base_lookup::find_instance_id;
+#[cfg(debug_assertions)]
+pub use instance_budget::{live_instance_count, live_instance_counts, set_count_all_instances};
 pub use instance_storage::*;
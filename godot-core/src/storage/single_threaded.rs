@@ -14,9 +14,12 @@ use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 #[cfg(feature = "experimental-threads")]
 use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 
+use crate::meta::error::BindError;
 use crate::obj::{Base, GodotClass};
 use crate::out;
-use crate::storage::{Lifecycle, Storage, StorageRefCounted};
+#[cfg(debug_assertions)]
+use crate::storage::BorrowTracker;
+use crate::storage::{already_bound_error, Lifecycle, Storage, StorageRefCounted};
 
 pub struct InstanceStorage<T: GodotClass> {
     user_instance: GdCell<T>,
@@ -25,6 +28,13 @@ pub struct InstanceStorage<T: GodotClass> {
     // Declared after `user_instance`, is dropped last
     pub(super) lifecycle: cell::Cell<Lifecycle>,
     godot_ref_count: cell::Cell<u32>,
+
+    #[cfg(debug_assertions)]
+    borrow_tracker: BorrowTracker,
+
+    /// Whether this instance is included in the live instance counts.
+    #[cfg(debug_assertions)]
+    is_counted: bool,
 }
 
 // SAFETY:
@@ -43,12 +53,26 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         base: Base<<Self::Instance as GodotClass>::Base>,
     ) -> Self {
         out!("    Storage::construct             <{}>", type_name::<T>());
-        Self {
+
+        #[cfg(debug_assertions)]
+        let is_counted = super::instance_budget::on_construct::<T>();
+
+        let storage = Self {
             user_instance: GdCell::new(user_instance),
             base,
             lifecycle: cell::Cell::new(Lifecycle::Alive),
             godot_ref_count: cell::Cell::new(1),
+            #[cfg(debug_assertions)]
+            borrow_tracker: BorrowTracker::default(),
+            #[cfg(debug_assertions)]
+            is_counted,
+        };
+
+        if T::__BASE_LOOKUP {
+            super::base_lookup::on_construct(&*storage.get(), storage.base.instance_id());
         }
+
+        storage
     }
 
     fn is_bound(&self) -> bool {
@@ -59,50 +83,58 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         &self.base
     }
 
-    fn get(&self) -> RefGuard<'_, T> {
-        self.user_instance.borrow().unwrap_or_else(|err| {
-            panic!(
-                "\
-                    Gd<T>::bind() failed, already bound; T = {}.\n  \
-                    Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible.\n  \
-                    Details: {err}.\
-                ",
-                type_name::<T>()
-            )
-        })
+    #[track_caller]
+    fn try_get(&self) -> Result<RefGuard<'_, T>, BindError> {
+        let guard = self
+            .user_instance
+            .borrow()
+            .map_err(|err| already_bound_error(self, false, err))?;
+
+        #[cfg(debug_assertions)]
+        self.borrow_tracker.record(
+            false,
+            std::panic::Location::caller(),
+            self.user_instance.borrow_count(),
+        );
+
+        Ok(guard)
     }
 
-    fn get_mut(&self) -> MutGuard<'_, T> {
-        self.user_instance.borrow_mut().unwrap_or_else(|err| {
-            panic!(
-                "\
-                    Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
-                    Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
-                    Details: {err}.\
-                ",
-                type_name::<T>()
-            )
-        })
+    #[track_caller]
+    fn try_get_mut(&self) -> Result<MutGuard<'_, T>, BindError> {
+        let guard = self
+            .user_instance
+            .borrow_mut()
+            .map_err(|err| already_bound_error(self, true, err))?;
+
+        #[cfg(debug_assertions)]
+        self.borrow_tracker.record(
+            true,
+            std::panic::Location::caller(),
+            self.user_instance.borrow_count(),
+        );
+
+        Ok(guard)
     }
 
-    fn get_inaccessible<'a: 'b, 'b>(
+    fn try_get_inaccessible<'a: 'b, 'b>(
         &'a self,
         value: &'b mut Self::Instance,
-    ) -> InaccessibleGuard<'b, T> {
+    ) -> Result<InaccessibleGuard<'b, T>, BindError> {
+        // We should never hit this error, except maybe in extreme cases like having more than `usize::MAX` borrows.
         self.user_instance
             .make_inaccessible(value)
-            .unwrap_or_else(|err| {
-                // We should never hit this, except maybe in extreme cases like having more than
-                // `usize::MAX` borrows.
-                panic!(
-                    "\
-                        `base_mut()` failed for type T = {}.\n  \
-                        This is most likely a bug, please report it.\n  \
-                        Details: {err}.\
-                    ",
-                    type_name::<T>()
-                )
-            })
+            .map_err(|err| BindError::inaccessible(type_name::<T>(), err.to_string()))
+    }
+
+    #[cfg(debug_assertions)]
+    fn borrow_tracker(&self) -> &BorrowTracker {
+        &self.borrow_tracker
+    }
+
+    #[cfg(debug_assertions)]
+    fn borrow_count(&self) -> usize {
+        self.user_instance.borrow_count()
     }
 
     fn get_lifecycle(&self) -> Lifecycle {
@@ -151,5 +183,14 @@ impl<T: GodotClass> Drop for InstanceStorage<T> {
             self.godot_ref_count(),
             self.base(),
         );
+
+        #[cfg(debug_assertions)]
+        super::instance_budget::on_destroy::<T>(self.is_counted);
+
+        if T::__BASE_LOOKUP {
+            if let Ok(instance) = self.user_instance.borrow() {
+                super::base_lookup::on_destroy(&*instance);
+            }
+        }
     }
 }
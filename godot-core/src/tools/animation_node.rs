@@ -0,0 +1,223 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{PackedFloat32Array, PackedFloat64Array};
+use crate::classes::animation::{LoopMode, LoopedFlag};
+use crate::classes::AnimationNodeExtension;
+use crate::obj::EngineEnum;
+
+/// Playback state passed to a custom animation node.
+///
+/// Godot passes this as a `PackedFloat64Array` to [`IAnimationNodeExtension::process_animation_node()`][crate::classes::IAnimationNodeExtension::process_animation_node].
+/// Use [`from_packed()`][Self::from_packed] to access the values by name.
+///
+/// # Example
+/// A blend node that plays its input at a fixed speed-up, without children. The node reports an infinite, looping animation to the blend
+/// tree. It can be added to an `AnimationNodeBlendTree` like any built-in node.
+///
+/// ```no_run
+/// # #[cfg(since_api = "4.4")]
+/// # mod conditional {
+/// use godot::prelude::*;
+/// use godot::classes::animation::LoopMode;
+/// use godot::classes::{AnimationNodeExtension, IAnimationNodeExtension};
+/// use godot::tools::{AnimationNodeTimeInfo, AnimationPlaybackInfo};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, tool, base=AnimationNodeExtension)]
+/// struct FastForward {
+///     #[export]
+///     #[init(val = 2.0)]
+///     speed: f64,
+///     position: f64,
+///     base: Base<AnimationNodeExtension>,
+/// }
+///
+/// #[godot_api]
+/// impl IAnimationNodeExtension for FastForward {
+///     fn process_animation_node(
+///         &mut self,
+///         playback_info: PackedFloat64Array,
+///         test_only: bool,
+///     ) -> PackedFloat32Array {
+///         let info = AnimationPlaybackInfo::from_packed(&playback_info);
+///         let delta = info.delta * self.speed;
+///
+///         if !test_only {
+///             self.position = if info.seeked { info.time } else { self.position + delta };
+///         }
+///
+///         AnimationNodeTimeInfo {
+///             position: self.position,
+///             delta,
+///             loop_mode: LoopMode::LINEAR,
+///             is_infinity: true,
+///             ..Default::default()
+///         }
+///         .to_packed()
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AnimationPlaybackInfo {
+    /// Current time, in seconds.
+    pub time: f64,
+
+    /// Time elapsed since the last update, in seconds.
+    pub delta: f64,
+
+    /// Start of the playback range, in seconds.
+    pub start: f64,
+
+    /// End of the playback range, in seconds.
+    pub end: f64,
+
+    /// Whether the time was set by seeking rather than by playback.
+    pub seeked: bool,
+
+    /// Whether seeking was requested from outside the blend tree (e.g. by `AnimationPlayer.seek()`), as opposed to a node inside it.
+    pub is_external_seeking: bool,
+
+    /// Whether the animation has just looped, and at which end.
+    pub looped_flag: LoopedFlag,
+
+    /// Blend weight of this node.
+    pub weight: f64,
+}
+
+impl AnimationPlaybackInfo {
+    /// Number of elements in the packed representation.
+    const PACKED_LEN: usize = 8;
+
+    /// Reads the playback info from the array Godot passes to `process_animation_node()`.
+    ///
+    /// # Panics
+    /// If the array has fewer elements than Godot provides.
+    pub fn from_packed(array: &PackedFloat64Array) -> Self {
+        let values = array.as_slice();
+        assert!(
+            values.len() >= Self::PACKED_LEN,
+            "playback info has {} elements, expected {}",
+            values.len(),
+            Self::PACKED_LEN
+        );
+
+        Self {
+            time: values[0],
+            delta: values[1],
+            start: values[2],
+            end: values[3],
+            seeked: values[4] != 0.0,
+            is_external_seeking: values[5] != 0.0,
+            looped_flag: LoopedFlag::try_from_ord(values[6] as i32).unwrap_or(LoopedFlag::NONE),
+            weight: values[7],
+        }
+    }
+
+    /// Converts to the array format that Godot passes to `process_animation_node()`, e.g. to forward it to a child node.
+    pub fn to_packed(&self) -> PackedFloat64Array {
+        PackedFloat64Array::from(&[
+            self.time,
+            self.delta,
+            self.start,
+            self.end,
+            f64::from(u8::from(self.seeked)),
+            f64::from(u8::from(self.is_external_seeking)),
+            f64::from(self.looped_flag.ord()),
+            self.weight,
+        ])
+    }
+}
+
+/// Timing result of a custom animation node.
+///
+/// Return [`to_packed()`][Self::to_packed] from [`IAnimationNodeExtension::process_animation_node()`][crate::classes::IAnimationNodeExtension::process_animation_node].
+/// See [`AnimationPlaybackInfo`] for an example.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AnimationNodeTimeInfo {
+    /// Length of the animation, in seconds.
+    pub length: f64,
+
+    /// Current position within the animation, in seconds.
+    pub position: f64,
+
+    /// Time advanced during this update, in seconds.
+    pub delta: f64,
+
+    /// How the animation loops.
+    pub loop_mode: LoopMode,
+
+    /// Whether the animation ends during this update.
+    pub will_end: bool,
+
+    /// Whether the animation has no end, e.g. because it is driven procedurally.
+    pub is_infinity: bool,
+}
+
+impl AnimationNodeTimeInfo {
+    /// Reads the time info from the array format returned by `process_animation_node()`, e.g. the result of a child node.
+    ///
+    /// Returns `None` if the array does not have the expected length.
+    pub fn try_from_packed(array: &PackedFloat32Array) -> Option<Self> {
+        let values = array.as_slice();
+        if values.len() != 6 {
+            return None;
+        }
+
+        Some(Self {
+            length: f64::from(values[0]),
+            position: f64::from(values[1]),
+            delta: f64::from(values[2]),
+            loop_mode: LoopMode::try_from_ord(values[3] as i32).unwrap_or(LoopMode::NONE),
+            will_end: values[4] != 0.0,
+            is_infinity: values[5] != 0.0,
+        })
+    }
+
+    /// Converts to the array format that Godot expects as the return value of `process_animation_node()`.
+    ///
+    /// Godot stores the values as 32-bit floats, so precision is lost for long animations.
+    pub fn to_packed(&self) -> PackedFloat32Array {
+        PackedFloat32Array::from(&[
+            self.length as f32,
+            self.position as f32,
+            self.delta as f32,
+            self.loop_mode.ord() as f32,
+            f32::from(u8::from(self.will_end)),
+            f32::from(u8::from(self.is_infinity)),
+        ])
+    }
+
+    /// Whether the animation loops.
+    ///
+    /// _Godot equivalent: `AnimationNodeExtension.is_looping()`_
+    pub fn is_looping(&self) -> bool {
+        AnimationNodeExtension::is_looping(&self.to_packed())
+    }
+
+    /// Time until the animation ends, in seconds. Looping and infinite animations report a very large value, unless `break_loop` is set
+    /// for a looping one.
+    ///
+    /// _Godot equivalent: `AnimationNodeExtension.get_remaining_time()`_
+    pub fn remaining_time(&self, break_loop: bool) -> f64 {
+        AnimationNodeExtension::get_remaining_time(&self.to_packed(), break_loop)
+    }
+}
+
+impl Default for AnimationNodeTimeInfo {
+    fn default() -> Self {
+        Self {
+            length: 0.0,
+            position: 0.0,
+            delta: 0.0,
+            loop_mode: LoopMode::NONE,
+            will_end: false,
+            is_infinity: false,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::classes::{Node, ProjectSettings, SceneTree};
+use crate::meta::error::AutoloadError;
+use crate::obj::{Gd, Inherits, InstanceId};
+use crate::tools::main_loop;
+
+/// Returns the autoload singleton `name`, as declared in the project settings.
+///
+/// Autoloads are added as children of the scene tree's root, under the name given in _Project Settings > Globals > Autoload_. The node
+/// is looked up once and then cached, so repeated calls are cheap.
+///
+/// For a typed accessor per autoload, see the [`autoloads!`][crate::tools::autoloads] macro.
+///
+/// # Panics
+/// If the autoload cannot be found or has the wrong type; see [`try_autoload()`] for the possible errors.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::autoload;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct GameState {
+///     score: u32,
+/// }
+///
+/// fn add_points(points: u32) {
+///     let mut state = autoload::<GameState>("GameState");
+///     state.bind_mut().score += points;
+/// }
+/// ```
+pub fn autoload<T>(name: &str) -> Gd<T>
+where
+    T: Inherits<Node>,
+{
+    try_autoload(name).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Returns the autoload singleton `name`, or an error describing why it is not available.
+///
+/// Fails if:
+/// - the main loop is not a `SceneTree`,
+/// - no autoload `name` is declared in the project settings,
+/// - the autoload is declared, but not yet part of the tree (e.g. when accessed from an earlier autoload's `init()`),
+/// - the node is not of type `T` or derived from it.
+pub fn try_autoload<T>(name: &str) -> Result<Gd<T>, AutoloadError>
+where
+    T: Inherits<Node>,
+{
+    let node = match cached_node(name) {
+        Some(node) => node,
+        None => {
+            let node = find_node(name)?;
+            CACHE.with_borrow_mut(|cache| cache.insert(name.to_string(), node.instance_id()));
+            node
+        }
+    };
+
+    node.try_cast::<T>()
+        .map_err(|node| AutoloadError::wrong_type(name, T::class_name(), node.get_class()))
+}
+
+thread_local! {
+    /// Instance IDs of autoloads found so far, by name.
+    static CACHE: RefCell<HashMap<String, InstanceId>> = RefCell::default();
+}
+
+fn cached_node(name: &str) -> Option<Gd<Node>> {
+    let id = CACHE.with_borrow(|cache| cache.get(name).copied())?;
+
+    // Autoloads are freed when the tree shuts down, or may have been removed manually.
+    match Gd::<Node>::try_from_instance_id(id) {
+        Ok(node) if node.is_inside_tree() => Some(node),
+        _ => {
+            CACHE.with_borrow_mut(|cache| cache.remove(name));
+            None
+        }
+    }
+}
+
+fn find_node(name: &str) -> Result<Gd<Node>, AutoloadError> {
+    let root = main_loop::<SceneTree>()
+        .and_then(|tree| tree.get_root())
+        .ok_or_else(|| AutoloadError::no_scene_tree(name))?;
+
+    if let Some(node) = root.get_node_or_null(name) {
+        return Ok(node);
+    }
+
+    let setting = format!("autoload/{name}");
+    if ProjectSettings::singleton().has_setting(&setting) {
+        Err(AutoloadError::not_in_tree(name))
+    } else {
+        Err(AutoloadError::not_declared(name))
+    }
+}
+
+/// Generates typed accessor functions for autoload singletons.
+///
+/// Each line declares a function returning `Gd<T>`, implemented with [`autoload()`][crate::tools::autoload]. This keeps the autoload
+/// names in one place, instead of spreading string literals across the code base.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::AudioStreamPlayer;
+/// use godot::tools::autoloads;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct GameState {
+///     score: u32,
+/// }
+///
+/// autoloads! {
+///     pub fn game_state() -> GameState = "GameState";
+///     fn music() -> AudioStreamPlayer = "Music";
+/// }
+///
+/// fn reset() {
+///     game_state().bind_mut().score = 0;
+///     music().stop();
+/// }
+/// ```
+#[macro_export]
+macro_rules! autoloads {
+    ($( $(#[$attr:meta])* $vis:vis fn $fn_name:ident() -> $T:ty = $name:literal; )*) => {
+        $(
+            $(#[$attr])*
+            $vis fn $fn_name() -> $crate::obj::Gd<$T> {
+                $crate::tools::autoload::<$T>($name)
+            }
+        )*
+    };
+}
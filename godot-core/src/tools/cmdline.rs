@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::classes::Os;
+
+/// Returns the user-provided command-line arguments, parsed and cached on first access.
+///
+/// User arguments are those passed after a `--` or `++` separator on the command line; Godot does not interpret them.
+/// They are read once via [`Os::get_cmdline_user_args()`] and then cached for the lifetime of the process.
+///
+/// This is primarily useful for headless tools and dedicated server builds, which are typically configured through the command line.
+///
+/// # Example
+/// Running `godot --headless -- --port=8080 --verbose map.tscn`:
+/// ```no_run
+/// use godot::tools::user_args;
+///
+/// let args = user_args();
+/// let port: u16 = args.get_parsed("port").unwrap_or(Ok(7777)).expect("port must be a number");
+/// let verbose = args.has_flag("verbose");
+/// let files: Vec<&str> = args.positional().collect(); // ["map.tscn"]
+/// ```
+///
+/// # Panics
+/// If called before the engine's `Core` init level, when the `OS` singleton is not yet available.
+pub fn user_args() -> &'static UserArgs {
+    static USER_ARGS: OnceLock<UserArgs> = OnceLock::new();
+
+    USER_ARGS.get_or_init(|| {
+        let packed = Os::singleton().get_cmdline_user_args();
+        UserArgs::from_args(packed.as_slice().iter().map(|arg| arg.to_string()))
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Parsed user command-line arguments.
+///
+/// Obtained through [`user_args()`], or constructed manually with [`from_args()`](Self::from_args).
+///
+/// Every argument falls into one of three categories:
+/// - `--key=value` is an **option**, accessible through [`get()`](Self::get) and [`get_parsed()`](Self::get_parsed).
+/// - `--name` (without `=`) is a **flag**, queried with [`has_flag()`](Self::has_flag).
+/// - Anything else is a **positional** argument, see [`positional()`](Self::positional).
+///
+/// If an option is specified multiple times, the last occurrence wins. The original order of all arguments is preserved in [`iter()`](Self::iter).
+#[derive(Clone, Debug, Default)]
+pub struct UserArgs {
+    args: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl UserArgs {
+    /// Parses arguments from an arbitrary source.
+    ///
+    /// The arguments should not contain the `--` separator itself.
+    pub fn from_args<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let args: Vec<String> = args.into_iter().map(Into::into).collect();
+
+        let options = args
+            .iter()
+            .filter_map(|arg| parse_option(arg))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        Self { args, options }
+    }
+
+    /// Iterates over all arguments in their original order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.args.iter().map(String::as_str)
+    }
+
+    /// Iterates over arguments that are neither options nor flags, i.e. don't start with `--`.
+    pub fn positional(&self) -> impl Iterator<Item = &str> + '_ {
+        self.iter().filter(|arg| !arg.starts_with("--"))
+    }
+
+    /// Number of arguments (of any kind).
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if no user arguments were passed.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns `true` if `--{name}` was passed (without a value).
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.iter()
+            .any(|arg| arg.strip_prefix("--") == Some(name) && !name.is_empty())
+    }
+
+    /// Returns the raw value of option `--{key}=value`, if present.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.options.get(key).map(String::as_str)
+    }
+
+    /// Returns the value of option `--{key}=value`, parsed as `T`.
+    ///
+    /// Returns `None` if the option is absent, and `Some(Err)` if it is present but cannot be parsed.
+    pub fn get_parsed<T: FromStr>(&self, key: &str) -> Option<Result<T, T::Err>> {
+        self.get(key).map(str::parse)
+    }
+
+    /// All `--key=value` options as a map from key to raw value.
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+}
+
+impl<'a> IntoIterator for &'a UserArgs {
+    type Item = &'a str;
+    type IntoIter = std::iter::Map<std::slice::Iter<'a, String>, fn(&'a String) -> &'a str>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.args.iter().map(String::as_str)
+    }
+}
+
+/// Splits `--key=value` into `(key, value)`. Returns `None` for flags and positional arguments.
+fn parse_option(arg: &str) -> Option<(&str, &str)> {
+    let (key, value) = arg.strip_prefix("--")?.split_once('=')?;
+
+    if key.is_empty() {
+        None
+    } else {
+        Some((key, value))
+    }
+}
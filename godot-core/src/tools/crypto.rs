@@ -0,0 +1,221 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Rust-native wrappers around [`Crypto`], [`HashingContext`], [`CryptoKey`] and [`X509Certificate`].
+//!
+//! Hashing and HMAC functions accept `&[u8]` slices, and operations that can fail return `Result<_, CryptoError>` instead of Godot error
+//! codes or empty arrays.
+//!
+//! # Example
+//! ```no_run
+//! use godot::classes::hashing_context::HashType;
+//! use godot::tools::crypto;
+//!
+//! let key = crypto::random_bytes(32);
+//! let mac = crypto::hmac(HashType::SHA256, key.as_slice(), b"payload").unwrap();
+//! assert!(crypto::constant_time_eq(mac.as_slice(), mac.as_slice()));
+//! ```
+
+use crate::builtin::{GString, PackedByteArray};
+use crate::classes::hashing_context::HashType;
+use crate::classes::{Crypto, CryptoKey, HashingContext, X509Certificate};
+use crate::global::Error as GodotError;
+use crate::meta::error::CryptoError;
+use crate::meta::{arg_into_ref, AsArg};
+use crate::obj::{Gd, NewGd};
+
+/// Incremental hash computation, e.g. for large files or network streams.
+///
+/// Wraps [`HashingContext`], accepting data as `&[u8]` slices. For data that is already in Godot, pass
+/// [`PackedByteArray::as_slice()`].
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::hashing_context::HashType;
+/// use godot::tools::crypto::Hasher;
+///
+/// let mut hasher = Hasher::new(HashType::SHA256);
+/// hasher.update(b"save file ").unwrap();
+/// hasher.update(b"contents").unwrap();
+/// let digest = hasher.finish();
+/// ```
+pub struct Hasher {
+    context: Gd<HashingContext>,
+}
+
+impl Hasher {
+    /// Starts a new hash computation with the given algorithm.
+    ///
+    /// _Godot equivalent: `HashingContext.start()`_
+    pub fn new(hash_type: HashType) -> Self {
+        let mut context = HashingContext::new_gd();
+        let result = context.start(hash_type);
+        assert_eq!(result, GodotError::OK, "HashingContext.start() failed");
+
+        Self { context }
+    }
+
+    /// Feeds `data` into the hash.
+    ///
+    /// _Godot equivalent: `HashingContext.update()`_
+    pub fn update(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        self.update_packed(&PackedByteArray::from(data))
+    }
+
+    /// Like [`update()`][Self::update], but avoids a copy for data that is already a `PackedByteArray`.
+    pub fn update_packed(&mut self, data: &PackedByteArray) -> Result<(), CryptoError> {
+        match self.context.update(data) {
+            GodotError::OK => Ok(()),
+            error => Err(CryptoError::hash(error)),
+        }
+    }
+
+    /// Consumes the hasher and returns the digest.
+    ///
+    /// _Godot equivalent: `HashingContext.finish()`_
+    pub fn finish(mut self) -> PackedByteArray {
+        self.context.finish()
+    }
+}
+
+/// Computes the digest of `data` in one step.
+pub fn hash(hash_type: HashType, data: &[u8]) -> Result<PackedByteArray, CryptoError> {
+    let mut hasher = Hasher::new(hash_type);
+    hasher.update(data)?;
+    Ok(hasher.finish())
+}
+
+/// Computes the HMAC of `message` under `key`.
+///
+/// Godot supports HMAC for [`HashType::SHA1`] and [`HashType::SHA256`]; other algorithms return an error.
+///
+/// _Godot equivalent: `Crypto.hmac_digest()`_
+pub fn hmac(
+    hash_type: HashType,
+    key: &[u8],
+    message: &[u8],
+) -> Result<PackedByteArray, CryptoError> {
+    let digest = Crypto::new_gd().hmac_digest(
+        hash_type,
+        &PackedByteArray::from(key),
+        &PackedByteArray::from(message),
+    );
+
+    // Godot prints an error and returns an empty array on failure; valid digests are never empty.
+    if digest.is_empty() {
+        Err(CryptoError::hmac())
+    } else {
+        Ok(digest)
+    }
+}
+
+/// Compares two byte sequences in constant time, e.g. a received HMAC against the expected one.
+///
+/// Use this instead of `==` for secrets, to prevent timing attacks.
+///
+/// _Godot equivalent: `Crypto.constant_time_compare()`_
+pub fn constant_time_eq(trusted: &[u8], received: &[u8]) -> bool {
+    // Godot prints an error for different lengths; the length of a digest is not secret.
+    if trusted.len() != received.len() {
+        return false;
+    }
+
+    Crypto::new_gd().constant_time_compare(
+        &PackedByteArray::from(trusted),
+        &PackedByteArray::from(received),
+    )
+}
+
+/// Returns `count` cryptographically secure random bytes.
+///
+/// _Godot equivalent: `Crypto.generate_random_bytes()`_
+pub fn random_bytes(count: usize) -> PackedByteArray {
+    let count = i32::try_from(count).expect("random_bytes(): count exceeds i32::MAX");
+    Crypto::new_gd().generate_random_bytes(count)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Keys and certificates
+
+/// Generates an RSA key pair with `bits` bits.
+///
+/// _Godot equivalent: `Crypto.generate_rsa()`_
+pub fn generate_rsa_key(bits: u32) -> Result<Gd<CryptoKey>, CryptoError> {
+    let size = i32::try_from(bits).map_err(|_| CryptoError::generating_key(bits))?;
+
+    Crypto::new_gd()
+        .generate_rsa(size)
+        .ok_or_else(|| CryptoError::generating_key(bits))
+}
+
+/// Loads a key from a file, e.g. `user://server.key`.
+///
+/// If `public_only` is true, only the public part is loaded, which is expected to be the only content of the file.
+///
+/// _Godot equivalent: `CryptoKey.load()`_
+pub fn load_key(
+    path: impl AsArg<GString>,
+    public_only: bool,
+) -> Result<Gd<CryptoKey>, CryptoError> {
+    arg_into_ref!(path);
+
+    let mut key = CryptoKey::new_gd();
+    match key.load_ex(path).public_only(public_only).done() {
+        GodotError::OK => Ok(key),
+        error => Err(CryptoError::loading_key(error, format!("path: '{path}'"))),
+    }
+}
+
+/// Parses a key in PEM format.
+///
+/// _Godot equivalent: `CryptoKey.load_from_string()`_
+pub fn key_from_pem(
+    pem: impl AsArg<GString>,
+    public_only: bool,
+) -> Result<Gd<CryptoKey>, CryptoError> {
+    arg_into_ref!(pem);
+
+    let mut key = CryptoKey::new_gd();
+    match key.load_from_string_ex(pem).public_only(public_only).done() {
+        GodotError::OK => Ok(key),
+        error => Err(CryptoError::loading_key(error, "PEM string".to_string())),
+    }
+}
+
+/// Loads an X509 certificate from a file, e.g. `res://ca.crt`.
+///
+/// _Godot equivalent: `X509Certificate.load()`_
+pub fn load_certificate(path: impl AsArg<GString>) -> Result<Gd<X509Certificate>, CryptoError> {
+    arg_into_ref!(path);
+
+    let mut certificate = X509Certificate::new_gd();
+    match certificate.load(path) {
+        GodotError::OK => Ok(certificate),
+        error => Err(CryptoError::loading_certificate(error, path.to_string())),
+    }
+}
+
+/// Generates a self-signed certificate for `key`, e.g. for a development server.
+///
+/// `issuer_name` uses the format `CN=myserver,O=myorganisation,C=IT`. `not_before` and `not_after` are UTC timestamps in the format
+/// `YYYYMMDDhhmmss`.
+///
+/// _Godot equivalent: `Crypto.generate_self_signed_certificate()`_
+pub fn generate_self_signed_certificate(
+    key: &Gd<CryptoKey>,
+    issuer_name: impl AsArg<GString>,
+    not_before: impl AsArg<GString>,
+    not_after: impl AsArg<GString>,
+) -> Result<Gd<X509Certificate>, CryptoError> {
+    Crypto::new_gd()
+        .generate_self_signed_certificate_ex(key)
+        .issuer_name(issuer_name)
+        .not_before(not_before)
+        .not_after(not_after)
+        .done()
+        .ok_or_else(CryptoError::generating_certificate)
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Type-checked deferred setters.
+
+pub use crate::defer_set;
+
+/// Calls one or more setters at the end of the current frame, with compile-time type checking.
+///
+/// Each argument has one of two forms, with `obj` being a `Gd<T>` or `&Gd<T>` (an identifier or a parenthesized expression):
+/// - `obj.set_prop(value)` for engine setters, such as `Label::set_text()`.
+/// - `obj.bind_mut().set_prop(value)` for setters of user classes, such as those generated for `#[var]` fields.
+///
+/// The value is converted to `Variant` immediately, and the setter is invoked later through
+/// [`Object::call_deferred()`][crate::classes::Object::call_deferred], which queues the call in Godot's message queue. In particular,
+/// `bind_mut()` is not called at this point.
+///
+/// Unlike calling `set_deferred("text", &value.to_variant())` directly, the compiler checks that the setter exists and accepts the value.
+/// The check happens in code that is never executed.
+///
+/// This is useful to apply results computed elsewhere -- e.g. in a callback during physics processing, or on a worker thread with the
+/// `experimental-threads` feature -- without modifying the scene tree mid-frame.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::{Label, ProgressBar};
+/// use godot::tools::defer_set;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Hud {
+///     #[var]
+///     progress: f64,
+///     base: Base<Node>,
+/// }
+///
+/// fn report_progress(hud: &Gd<Hud>, label: &Gd<Label>, bar: &Gd<ProgressBar>, done: u32, total: u32) {
+///     let percent = f64::from(done) / f64::from(total) * 100.0;
+///     let status = if done < total { "Loading..." } else { "Done" };
+///
+///     defer_set!(
+///         label.set_text(status),
+///         bar.set_value(percent),
+///         bar.set_visible(done < total),
+///         hud.bind_mut().set_progress(percent),
+///     );
+/// }
+/// ```
+///
+/// The setter name is used as the Godot method name, so it must be registered under the same name. This holds for engine classes and for
+/// `#[var]` setters, unless a class-level rename policy (`#[class(func_rename = ...)]`) changes registered names.
+#[macro_export]
+macro_rules! defer_set {
+    () => {};
+
+    ($obj:tt . bind_mut() . $setter:ident ( $value:expr ) $(, $($rest:tt)*)?) => {
+        {
+            let value = $value;
+            let variant = $crate::meta::ToGodot::to_variant(&value);
+
+            // Type check only: verifies that `$setter` exists and accepts the value. Never executed.
+            if false {
+                $crate::obj::Gd::clone(&$obj).bind_mut().$setter(value);
+            }
+
+            $crate::obj::Gd::clone(&$obj)
+                .upcast::<$crate::classes::Object>()
+                .call_deferred(stringify!($setter), &[variant]);
+        }
+        $crate::defer_set!($($($rest)*)?);
+    };
+
+    ($obj:tt . $setter:ident ( $value:expr ) $(, $($rest:tt)*)?) => {
+        {
+            let value = $value;
+            let variant = $crate::meta::ToGodot::to_variant(&value);
+
+            // Type check only: verifies that `$setter` exists and accepts the value. Never executed.
+            if false {
+                $crate::obj::Gd::clone(&$obj).$setter(value);
+            }
+
+            $crate::obj::Gd::clone(&$obj)
+                .upcast::<$crate::classes::Object>()
+                .call_deferred(stringify!($setter), &[variant]);
+        }
+        $crate::defer_set!($($($rest)*)?);
+    };
+}
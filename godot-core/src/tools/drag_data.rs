@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    dict, Dictionary, GString, NodePath, PackedStringArray, StringName, Variant, VariantArray,
+    VariantType,
+};
+use crate::classes::{DisplayServer, Resource};
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::obj::Gd;
+
+/// Typed payload for drag-and-drop between `Control` nodes.
+///
+/// Godot passes drag data as untyped `Variant` to the virtual methods `get_drag_data()`, `can_drop_data()` and `drop_data()` of
+/// [`IControl`][crate::classes::IControl]. The editor uses Dictionaries with a `"type"` key for its own payloads, e.g. when dragging files from
+/// the FileSystem dock or nodes from the Scene dock. This enum maps those conventions to Rust types, in both directions.
+///
+/// Conversion from `Variant` never fails: payloads that don't match a known shape are kept in [`Custom`][Self::Custom] (Dictionaries)
+/// or [`Other`][Self::Other] (everything else).
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{Control, IControl};
+/// use godot::tools::DragData;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Control)]
+/// struct FileDropZone {
+///     dropped: Vec<GString>,
+///     base: Base<Control>,
+/// }
+///
+/// #[godot_api]
+/// impl IControl for FileDropZone {
+///     fn get_drag_data(&mut self, _at_position: Vector2) -> Variant {
+///         DragData::Text("dragged from Rust".into()).to_variant()
+///     }
+///
+///     fn can_drop_data(&self, _at_position: Vector2, data: Variant) -> bool {
+///         matches!(DragData::from_variant(&data), DragData::Files(_))
+///     }
+///
+///     fn drop_data(&mut self, _at_position: Vector2, data: Variant) {
+///         if let DragData::Files(files) = DragData::from_variant(&data) {
+///             self.dropped.extend(files.as_slice().iter().cloned());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum DragData {
+    /// Plain text, passed as a `String` variant.
+    Text(GString),
+
+    /// Resource paths, as dragged from the editor's FileSystem dock (`"type": "files"` or `"files_and_dirs"`).
+    Files(PackedStringArray),
+
+    /// Paths of nodes, as dragged from the editor's Scene dock (`"type": "nodes"`).
+    Nodes(Vec<NodePath>),
+
+    /// A single resource, e.g. dragged from the Inspector (`"type": "resource"`), or passed as an object.
+    Resource(Gd<Resource>),
+
+    /// Any other Dictionary, for application-defined payloads.
+    Custom(Dictionary),
+
+    /// Any other value, including `null`.
+    Other(Variant),
+}
+
+impl DragData {
+    /// Reads the system clipboard as text, or `None` if it's empty.
+    ///
+    /// _Godot equivalent: `DisplayServer.clipboard_get()`_
+    pub fn from_clipboard() -> Option<Self> {
+        let text = DisplayServer::singleton().clipboard_get();
+
+        (!text.is_empty()).then_some(Self::Text(text))
+    }
+
+    /// Writes the payload to the system clipboard as text. Returns `false` if it has no text representation.
+    ///
+    /// Text is copied as-is, files and nodes as one path per line.
+    ///
+    /// _Godot equivalent: `DisplayServer.clipboard_set()`_
+    pub fn copy_to_clipboard(&self) -> bool {
+        let text = match self {
+            Self::Text(text) => text.clone(),
+            Self::Files(files) => {
+                let lines: Vec<String> = files.as_slice().iter().map(GString::to_string).collect();
+                GString::from(lines.join("\n"))
+            }
+            Self::Nodes(nodes) => {
+                let lines: Vec<String> = nodes.iter().map(NodePath::to_string).collect();
+                GString::from(lines.join("\n"))
+            }
+            Self::Resource(_) | Self::Custom(_) | Self::Other(_) => return false,
+        };
+
+        DisplayServer::singleton().clipboard_set(&text);
+        true
+    }
+
+    fn from_dictionary(dict: Dictionary) -> Self {
+        let Some(ty) = dict.get("type").and_then(|ty| ty.try_to::<GString>().ok()) else {
+            return Self::Custom(dict);
+        };
+
+        let parsed = match ty.to_string().as_str() {
+            "files" | "files_and_dirs" => dict
+                .get("files")
+                .and_then(|files| files.try_to::<PackedStringArray>().ok())
+                .map(Self::Files),
+            "nodes" => dict
+                .get("nodes")
+                .and_then(|nodes| nodes.try_to::<VariantArray>().ok())
+                .and_then(|nodes| {
+                    nodes
+                        .iter_shared()
+                        .map(|path| path.try_to::<NodePath>().ok())
+                        .collect::<Option<Vec<_>>>()
+                })
+                .map(Self::Nodes),
+            "resource" => dict
+                .get("resource")
+                .and_then(|res| res.try_to::<Gd<Resource>>().ok())
+                .map(Self::Resource),
+            _ => None,
+        };
+
+        parsed.unwrap_or(Self::Custom(dict))
+    }
+}
+
+impl GodotConvert for DragData {
+    type Via = Variant;
+}
+
+impl ToGodot for DragData {
+    type ToVia<'v> = Variant;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        match self {
+            Self::Text(text) => text.to_variant(),
+            Self::Files(files) => dict! {
+                "type": "files",
+                "files": files.clone(),
+            }
+            .to_variant(),
+            Self::Nodes(nodes) => {
+                let nodes: VariantArray = nodes.iter().map(|path| path.to_variant()).collect();
+                dict! {
+                    "type": "nodes",
+                    "nodes": nodes,
+                }
+                .to_variant()
+            }
+            Self::Resource(resource) => dict! {
+                "type": "resource",
+                "resource": resource.clone(),
+            }
+            .to_variant(),
+            Self::Custom(dict) => dict.to_variant(),
+            Self::Other(variant) => variant.clone(),
+        }
+    }
+}
+
+impl FromGodot for DragData {
+    fn try_from_godot(variant: Variant) -> Result<Self, ConvertError> {
+        let data = match variant.get_type() {
+            VariantType::STRING => Self::Text(variant.to()),
+            VariantType::STRING_NAME => Self::Text(GString::from(&variant.to::<StringName>())),
+            VariantType::DICTIONARY => Self::from_dictionary(variant.to()),
+            VariantType::OBJECT => match variant.try_to::<Gd<Resource>>() {
+                Ok(resource) => Self::Resource(resource),
+                Err(_) => Self::Other(variant),
+            },
+            _ => Self::Other(variant),
+        };
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,388 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Bridge between Godot objects and entities of an ECS (entity component system).
+//!
+//! The types here are independent of a specific ECS crate: entity IDs are any `Copy + Eq + Hash` type, such as `bevy_ecs::entity::Entity`
+//! or `hecs::Entity`. A typical frame looks like this:
+//!
+//! 1. Drain a [`LifecycleQueue`] and despawn entities whose Godot objects were freed, see [`EntityMap::apply_lifecycle()`].
+//! 2. Run the ECS systems, which record their results (e.g. new positions) into a [`ChangeBatch`].
+//! 3. Apply the batch to the Godot objects with [`ChangeBatch::apply()`].
+//!
+//! Requires the `ecs-bridge` feature.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use crate::builtin::{StringName, Transform2D, Transform3D, Variant, Vector2, Vector3};
+use crate::classes::{Node2D, Node3D, Object};
+use crate::meta::{arg_into_owned, AsArg};
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId};
+use crate::registry::instance_observers::{observe_instances, InstanceEvent, InstanceObserver};
+
+/// Bidirectional mapping between Godot instance IDs and ECS entity IDs.
+///
+/// Each instance is mapped to at most one entity and vice versa; inserting a pair replaces previous mappings of either side.
+#[derive(Clone, Debug)]
+pub struct EntityMap<E> {
+    entities: HashMap<InstanceId, E>,
+    instances: HashMap<E, InstanceId>,
+}
+
+impl<E: Copy + Eq + Hash> EntityMap<E> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            entities: HashMap::new(),
+            instances: HashMap::new(),
+        }
+    }
+
+    /// Maps `instance_id` to `entity`, removing any previous mapping of either of them.
+    pub fn insert(&mut self, instance_id: InstanceId, entity: E) {
+        self.remove_instance(instance_id);
+        self.remove_entity(entity);
+
+        self.entities.insert(instance_id, entity);
+        self.instances.insert(entity, instance_id);
+    }
+
+    /// Maps the object `gd` to `entity`; shorthand for [`insert()`][Self::insert] with `gd.instance_id()`.
+    pub fn insert_gd<T: GodotClass>(&mut self, gd: &Gd<T>, entity: E) {
+        self.insert(gd.instance_id(), entity);
+    }
+
+    /// Entity mapped to `instance_id`, if any.
+    pub fn entity(&self, instance_id: InstanceId) -> Option<E> {
+        self.entities.get(&instance_id).copied()
+    }
+
+    /// Instance ID mapped to `entity`, if any.
+    pub fn instance_id(&self, entity: E) -> Option<InstanceId> {
+        self.instances.get(&entity).copied()
+    }
+
+    /// Object mapped to `entity`, or `None` if there is no mapping or the object is no longer alive.
+    pub fn object(&self, entity: E) -> Option<Gd<Object>> {
+        let instance_id = self.instance_id(entity)?;
+        Gd::try_from_instance_id(instance_id).ok()
+    }
+
+    /// Removes the mapping of `instance_id`, returning its entity.
+    pub fn remove_instance(&mut self, instance_id: InstanceId) -> Option<E> {
+        let entity = self.entities.remove(&instance_id)?;
+        self.instances.remove(&entity);
+        Some(entity)
+    }
+
+    /// Removes the mapping of `entity`, returning its instance ID.
+    pub fn remove_entity(&mut self, entity: E) -> Option<InstanceId> {
+        let instance_id = self.instances.remove(&entity)?;
+        self.entities.remove(&instance_id);
+        Some(instance_id)
+    }
+
+    /// Removes the mappings of all `Destroyed` events in `events`, returning the affected entities (e.g. to despawn them).
+    ///
+    /// `Created` events are ignored, since only the application knows which entity to spawn for a new object.
+    pub fn apply_lifecycle(
+        &mut self,
+        events: impl IntoIterator<Item = (InstanceEvent, InstanceId)>,
+    ) -> Vec<E> {
+        events
+            .into_iter()
+            .filter(|(event, _)| *event == InstanceEvent::Destroyed)
+            .filter_map(|(_, instance_id)| self.remove_instance(instance_id))
+            .collect()
+    }
+
+    /// Removes the mappings of all objects that are no longer alive, returning the affected entities.
+    ///
+    /// Fallback for classes that cannot be observed with a [`LifecycleQueue`], e.g. engine classes. Checks every mapped object, so prefer
+    /// [`apply_lifecycle()`][Self::apply_lifecycle] where possible.
+    pub fn retain_alive(&mut self) -> Vec<E> {
+        let dead: Vec<InstanceId> = self
+            .entities
+            .keys()
+            .filter(|instance_id| !instance_id.lookup_validity())
+            .copied()
+            .collect();
+
+        dead.into_iter()
+            .filter_map(|instance_id| self.remove_instance(instance_id))
+            .collect()
+    }
+
+    /// Number of mapped pairs.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Whether the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Iterates over all `(instance ID, entity)` pairs, in unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (InstanceId, E)> + '_ {
+        self.entities
+            .iter()
+            .map(|(instance_id, entity)| (*instance_id, *entity))
+    }
+}
+
+impl<E: Copy + Eq + Hash> Default for EntityMap<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Collects creation and destruction events of one or more user classes, to be processed at a safe point in the frame.
+///
+/// Instance observers must not touch objects or ECS state while Godot is constructing or destroying objects (see
+/// [`observe_instances()`][crate::registry::instance_observers::observe_instances]). This queue only records the events; drain it once
+/// per frame and feed the result to [`EntityMap::apply_lifecycle()`].
+///
+/// Dropping the queue stops observing all classes.
+#[derive(Debug, Default)]
+pub struct LifecycleQueue {
+    events: Arc<Mutex<Vec<(InstanceEvent, InstanceId)>>>,
+    observers: Vec<InstanceObserver>,
+}
+
+impl LifecycleQueue {
+    /// Creates a queue that doesn't observe any class yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts recording events of user class `T`.
+    pub fn observe<T: GodotClass>(&mut self) {
+        let events = self.events.clone();
+        let observer = observe_instances::<T, _>(move |event, instance_id| {
+            events
+                .lock()
+                .expect("lifecycle queue poisoned")
+                .push((event, instance_id));
+        });
+
+        self.observers.push(observer);
+    }
+
+    /// Returns all events recorded since the last call, in the order they happened.
+    pub fn drain(&self) -> Vec<(InstanceEvent, InstanceId)> {
+        std::mem::take(&mut *self.events.lock().expect("lifecycle queue poisoned"))
+    }
+
+    /// Stops observing all classes. Events recorded so far are discarded.
+    ///
+    /// Equivalent to dropping the queue.
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for LifecycleQueue {
+    fn drop(&mut self) {
+        for observer in self.observers.drain(..) {
+            observer.remove();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Changes computed by the ECS, to be applied to the mapped Godot objects in one go.
+///
+/// Changes are applied in the order they were recorded. Transforms and positions use the typed setters of `Node2D`/`Node3D`; other
+/// properties go through `Object::set()`.
+#[derive(Clone, Debug)]
+pub struct ChangeBatch<E> {
+    changes: Vec<(E, Change)>,
+}
+
+#[derive(Clone, Debug)]
+enum Change {
+    Position2D(Vector2),
+    Position3D(Vector3),
+    Transform2D(Transform2D),
+    Transform3D(Transform3D),
+    Property(StringName, Variant),
+}
+
+/// Outcome of [`ChangeBatch::apply()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChangeReport<E> {
+    /// Number of changes applied.
+    pub applied: usize,
+
+    /// Entities without mapping or whose object is no longer alive; their changes were skipped.
+    pub missing: Vec<E>,
+
+    /// Entities whose object doesn't support a change, e.g. a 3D transform for a `Node2D`; those changes were skipped.
+    pub mismatched: Vec<E>,
+}
+
+impl<E: Copy + Eq + Hash> ChangeBatch<E> {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self {
+            changes: Vec::new(),
+        }
+    }
+
+    /// Sets `Node2D.position` of the entity's object.
+    pub fn set_position_2d(&mut self, entity: E, position: Vector2) {
+        self.changes.push((entity, Change::Position2D(position)));
+    }
+
+    /// Sets `Node3D.position` of the entity's object.
+    pub fn set_position_3d(&mut self, entity: E, position: Vector3) {
+        self.changes.push((entity, Change::Position3D(position)));
+    }
+
+    /// Sets `Node2D.transform` of the entity's object.
+    pub fn set_transform_2d(&mut self, entity: E, transform: Transform2D) {
+        self.changes.push((entity, Change::Transform2D(transform)));
+    }
+
+    /// Sets `Node3D.transform` of the entity's object.
+    pub fn set_transform_3d(&mut self, entity: E, transform: Transform3D) {
+        self.changes.push((entity, Change::Transform3D(transform)));
+    }
+
+    /// Sets `Node2D.position` of many entities' objects, e.g. straight from an ECS query.
+    pub fn set_positions_2d(&mut self, positions: impl IntoIterator<Item = (E, Vector2)>) {
+        self.extend(positions, Change::Position2D);
+    }
+
+    /// Sets `Node3D.position` of many entities' objects.
+    pub fn set_positions_3d(&mut self, positions: impl IntoIterator<Item = (E, Vector3)>) {
+        self.extend(positions, Change::Position3D);
+    }
+
+    /// Sets `Node2D.transform` of many entities' objects.
+    pub fn set_transforms_2d(&mut self, transforms: impl IntoIterator<Item = (E, Transform2D)>) {
+        self.extend(transforms, Change::Transform2D);
+    }
+
+    /// Sets `Node3D.transform` of many entities' objects.
+    pub fn set_transforms_3d(&mut self, transforms: impl IntoIterator<Item = (E, Transform3D)>) {
+        self.extend(transforms, Change::Transform3D);
+    }
+
+    /// Sets an arbitrary property of the entity's object.
+    pub fn set_property(&mut self, entity: E, property: impl AsArg<StringName>, value: Variant) {
+        arg_into_owned!(property);
+        self.changes
+            .push((entity, Change::Property(property, value)));
+    }
+
+    /// Sets the same property of many entities' objects, each to its own value.
+    pub fn set_properties(
+        &mut self,
+        property: impl AsArg<StringName>,
+        values: impl IntoIterator<Item = (E, Variant)>,
+    ) {
+        arg_into_owned!(property);
+        self.extend(values, |value| Change::Property(property.clone(), value));
+    }
+
+    /// Number of recorded changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Whether no changes are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies all changes to the objects mapped in `map`, and empties the batch.
+    ///
+    /// Each object is looked up once per change; changes to dead or unmapped entities are skipped and reported, rather than panicking.
+    pub fn apply(&mut self, map: &EntityMap<E>) -> ChangeReport<E> {
+        let mut report = ChangeReport {
+            applied: 0,
+            missing: Vec::new(),
+            mismatched: Vec::new(),
+        };
+
+        for (entity, change) in self.changes.drain(..) {
+            let Some(object) = map.object(entity) else {
+                report.missing.push(entity);
+                continue;
+            };
+
+            if apply_change(object, change) {
+                report.applied += 1;
+            } else {
+                report.mismatched.push(entity);
+            }
+        }
+
+        report
+    }
+
+    fn extend<T>(
+        &mut self,
+        values: impl IntoIterator<Item = (E, T)>,
+        make_change: impl Fn(T) -> Change,
+    ) {
+        self.changes.extend(
+            values
+                .into_iter()
+                .map(|(entity, value)| (entity, make_change(value))),
+        );
+    }
+}
+
+impl<E: Copy + Eq + Hash> Default for ChangeBatch<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns false if the object's class doesn't match the change.
+fn apply_change(object: Gd<Object>, change: Change) -> bool {
+    match change {
+        Change::Position2D(position) => {
+            with_node::<Node2D>(object, |node| node.set_position(position))
+        }
+        Change::Position3D(position) => {
+            with_node::<Node3D>(object, |node| node.set_position(position))
+        }
+        Change::Transform2D(transform) => {
+            with_node::<Node2D>(object, |node| node.set_transform(transform))
+        }
+        Change::Transform3D(transform) => {
+            with_node::<Node3D>(object, |node| node.set_transform(transform))
+        }
+        Change::Property(property, value) => {
+            let mut object = object;
+            object.set(&property, &value);
+            true
+        }
+    }
+}
+
+fn with_node<T>(object: Gd<Object>, apply: impl FnOnce(&mut Gd<T>)) -> bool
+where
+    T: Inherits<Object>,
+{
+    match object.try_cast::<T>() {
+        Ok(mut node) => {
+            apply(&mut node);
+            true
+        }
+        Err(_) => false,
+    }
+}
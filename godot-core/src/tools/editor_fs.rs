@@ -0,0 +1,241 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+
+use crate::builtin::{GString, PackedStringArray, Signal, StringName};
+use crate::classes::{EditorFileSystem, EditorFileSystemDirectory, EditorInterface, Engine};
+use crate::global::Error;
+use crate::meta::{arg_into_ref, AsArg};
+use crate::obj::Gd;
+
+/// Typed access to the editor's resource filesystem, for tools reacting to asset imports and moves.
+///
+/// Wraps [`EditorFileSystem`], which keeps track of all files in the project and their import state. Only available while the editor
+/// is running; see [`get()`][Self::get].
+///
+/// Integration tests run headless without the editor, so they only cover the `None` case of `get()`. Everything else, including
+/// [`scan()`][Self::scan], is untested outside of manual testing in the editor.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::EditorFiles;
+///
+/// fn watch_imports() {
+///     let Some(files) = EditorFiles::get() else {
+///         return; // Not running in the editor.
+///     };
+///
+///     files.on_resources_reimported(|paths| {
+///         for path in paths {
+///             godot_print!("reimported: {path}");
+///         }
+///     });
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct EditorFiles {
+    filesystem: Gd<EditorFileSystem>,
+}
+
+impl EditorFiles {
+    /// Returns the editor's filesystem, or `None` if not running in the editor.
+    ///
+    /// _Godot equivalent: `EditorInterface.get_resource_filesystem()`_
+    pub fn get() -> Option<Self> {
+        if !Engine::singleton().has_singleton("EditorInterface") {
+            return None;
+        }
+
+        let filesystem = EditorInterface::singleton().get_resource_filesystem()?;
+        Some(Self { filesystem })
+    }
+
+    /// The underlying engine object, for APIs not covered by this type.
+    pub fn filesystem(&self) -> &Gd<EditorFileSystem> {
+        &self.filesystem
+    }
+
+    /// Calls `function` whenever the filesystem changes, e.g. after files were added, moved or removed, or a scan completed.
+    ///
+    /// _Godot equivalent: signal `EditorFileSystem.filesystem_changed`_
+    pub fn on_filesystem_changed<F>(&self, mut function: F) -> Error
+    where
+        F: FnMut() + 'static,
+    {
+        self.signal("filesystem_changed")
+            .connect_mapped(move || function())
+    }
+
+    /// Calls `function` with the paths of resources after they were reimported.
+    ///
+    /// _Godot equivalent: signal `EditorFileSystem.resources_reimported`_
+    pub fn on_resources_reimported<F>(&self, mut function: F) -> Error
+    where
+        F: FnMut(Vec<String>) + 'static,
+    {
+        self.signal("resources_reimported")
+            .connect_mapped(move |resources: PackedStringArray| function(to_paths(&resources)))
+    }
+
+    /// Starts a full scan of the project's files, returning a future that resolves once the scan has completed.
+    ///
+    /// If a scan is already running, the future resolves when it completes. `filesystem_changed` is also emitted for changes unrelated
+    /// to the scan (e.g. moved files), so the future only resolves on an emission after which the editor no longer scans.
+    ///
+    /// _Godot equivalent: `EditorFileSystem.scan()`, then `await filesystem_changed` until `is_scanning()` is false_
+    pub fn scan(&self) -> impl Future<Output = ()> {
+        let mut filesystem = self.filesystem.clone();
+        let changed = self.signal("filesystem_changed");
+
+        // Connect before scanning, in case the scan completes synchronously.
+        let first_change = changed.to_future::<()>();
+        if !filesystem.is_scanning() {
+            filesystem.scan();
+        }
+
+        async move {
+            first_change.await;
+
+            // Checking and re-connecting happen in the same poll, so the final emission cannot be missed.
+            while filesystem.is_scanning() {
+                changed.to_future::<()>().await;
+            }
+        }
+    }
+
+    /// Whether a scan is currently running.
+    pub fn is_scanning(&self) -> bool {
+        self.filesystem.is_scanning()
+    }
+
+    /// Returns information about the file at `path` (e.g. `res://icon.svg`), or `None` if the editor doesn't know the file.
+    pub fn file_info(&self, path: impl AsArg<GString>) -> Option<EditorFileInfo> {
+        arg_into_ref!(path);
+
+        let path = path.to_string();
+        let (dir_path, file_name) = split_file_path(&path)?;
+
+        let dir = self.filesystem.clone().get_filesystem_path(&dir_path)?;
+        let index = dir.find_file_index(file_name);
+        if index < 0 {
+            return None;
+        }
+
+        Some(EditorFileInfo::from_directory(&dir, index))
+    }
+
+    /// Returns information about all files directly inside the directory `dir_path` (e.g. `res://textures`), excluding subdirectories.
+    ///
+    /// Returns an empty `Vec` if the directory is unknown.
+    pub fn files_in(&self, dir_path: impl AsArg<GString>) -> Vec<EditorFileInfo> {
+        arg_into_ref!(dir_path);
+
+        let Some(dir) = self.filesystem.clone().get_filesystem_path(dir_path) else {
+            return Vec::new();
+        };
+
+        (0..dir.get_file_count())
+            .map(|index| EditorFileInfo::from_directory(&dir, index))
+            .collect()
+    }
+
+    fn signal(&self, name: &str) -> Signal {
+        Signal::from_object_signal(&self.filesystem, name)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Information about a file, as tracked by the editor's filesystem.
+///
+/// Returned by [`EditorFiles::file_info()`] and [`EditorFiles::files_in()`].
+#[derive(Clone, Debug)]
+pub struct EditorFileInfo {
+    /// Full path, e.g. `res://textures/grass.png`.
+    pub path: GString,
+
+    /// Resource type of the file, e.g. `CompressedTexture2D` for an imported image.
+    pub file_type: StringName,
+
+    /// Whether the file's last import succeeded. Always `true` for files that are not imported.
+    pub import_valid: bool,
+
+    /// Global class name of a script declared with `class_name`, or `None` for other files.
+    pub script_class_name: Option<GString>,
+}
+
+impl EditorFileInfo {
+    fn from_directory(dir: &Gd<EditorFileSystemDirectory>, index: i32) -> Self {
+        let script_class_name = dir.get_file_script_class_name(index);
+
+        Self {
+            path: dir.get_file_path(index),
+            file_type: dir.get_file_type(index),
+            import_valid: dir.get_file_import_is_valid(index),
+            script_class_name: (!script_class_name.is_empty()).then_some(script_class_name),
+        }
+    }
+}
+
+/// Splits `path` into directory and file name, as expected by `EditorFileSystem.get_filesystem_path()`.
+///
+/// Returns `None` if `path` has no file name, e.g. `res://` or `res://textures/`.
+fn split_file_path(path: &str) -> Option<(String, &str)> {
+    let (dir_path, file_name) = path.rsplit_once('/')?;
+    if file_name.is_empty() {
+        return None;
+    }
+
+    // Files in the project root, e.g. "res://icon.svg", split into "res:/" and "icon.svg".
+    let dir_path = if dir_path.ends_with(":/") {
+        format!("{dir_path}/")
+    } else {
+        dir_path.to_string()
+    };
+
+    Some((dir_path, file_name))
+}
+
+fn to_paths(resources: &PackedStringArray) -> Vec<String> {
+    resources
+        .as_slice()
+        .iter()
+        .map(GString::to_string)
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_file_path_normalizes_root() {
+        assert_eq!(
+            split_file_path("res://icon.svg"),
+            Some(("res://".to_string(), "icon.svg"))
+        );
+        assert_eq!(
+            split_file_path("res://textures/grass.png"),
+            Some(("res://textures".to_string(), "grass.png"))
+        );
+        assert_eq!(
+            split_file_path("res://a/b/c.tres"),
+            Some(("res://a/b".to_string(), "c.tres"))
+        );
+    }
+
+    #[test]
+    fn split_file_path_without_file_name() {
+        assert_eq!(split_file_path("res://"), None);
+        assert_eq!(split_file_path("res://textures/"), None);
+        assert_eq!(split_file_path("icon.svg"), None);
+    }
+}
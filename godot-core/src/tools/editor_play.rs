@@ -0,0 +1,326 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::builtin::{Callable, GString, Variant};
+use crate::classes::{EditorInterface, Engine, SceneTree};
+use crate::global::Error;
+use crate::obj::Gd;
+use crate::tools::main_loop;
+
+/// Play-mode state of the editor, for `#[class(tool)]` classes that should react to the user pressing _Play_ or _Stop_.
+///
+/// While a scene runs, it does so in a separate process; tool scripts in the editor keep processing. Use this type to pause expensive
+/// editor-side work (previews, baking, live reloads) during that time.
+///
+/// All functions are cheap to call and return neutral values when not running in the editor.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::{EditorState, PlayEvent};
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=Node3D)]
+/// struct TerrainPreview {
+///     paused: bool,
+///     base: Base<Node3D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode3D for TerrainPreview {
+///     fn ready(&mut self) {
+///         let this = self.to_gd();
+///         let listener = EditorState::on_play_event(move |event| {
+///             if this.is_instance_valid() {
+///                 this.clone().bind_mut().paused = matches!(event, PlayEvent::Started { .. });
+///             }
+///         });
+///
+///         // Keep listening for the rest of the editor session; outside the editor, there is nothing to listen to.
+///         if let Ok(listener) = listener {
+///             listener.forget();
+///         }
+///     }
+///
+///     fn process(&mut self, _delta: f64) {
+///         if self.paused {
+///             return;
+///         }
+///         // Expensive preview update...
+///     }
+/// }
+/// ```
+pub struct EditorState {
+    _private: (),
+}
+
+impl EditorState {
+    /// Whether a scene is currently running from the editor. Always `false` outside the editor.
+    ///
+    /// _Godot equivalent: `EditorInterface.is_playing_scene()`_
+    pub fn is_playing_scene() -> bool {
+        editor_interface().is_some_and(|editor| editor.is_playing_scene())
+    }
+
+    /// Path of the scene running from the editor, e.g. `res://levels/intro.tscn`, or `None` if no scene is running.
+    ///
+    /// _Godot equivalent: `EditorInterface.get_playing_scene()`_
+    pub fn playing_scene() -> Option<GString> {
+        let editor = editor_interface()?;
+        if !editor.is_playing_scene() {
+            return None;
+        }
+
+        Some(editor.get_playing_scene())
+    }
+
+    /// Calls `function` whenever a scene starts or stops running from the editor, until the returned [`PlayListener`] is dropped.
+    ///
+    /// Godot has no signal for this; the state is checked once per editor frame, for all registered functions together. Functions are
+    /// unregistered at the latest when the extension's editor level is unloaded. If a function outlives objects it captured (e.g. after
+    /// [`PlayListener::forget()`]), it must handle them being freed in the meantime.
+    ///
+    /// # Errors
+    /// [`Error::ERR_UNAVAILABLE`] if not running in the editor, or if the editor's scene tree does not exist yet (e.g. during extension
+    /// initialization). Other errors come from connecting to the scene tree. In all these cases, `function` is not registered.
+    pub fn on_play_event<F>(function: F) -> Result<PlayListener, Error>
+    where
+        F: FnMut(PlayEvent) + 'static,
+    {
+        if editor_interface().is_none() {
+            return Err(Error::ERR_UNAVAILABLE);
+        }
+
+        let needs_watcher = WATCHER.with_borrow(|watcher| watcher.connection.is_none());
+        if needs_watcher {
+            let Some(tree) = main_loop::<SceneTree>() else {
+                return Err(Error::ERR_UNAVAILABLE);
+            };
+
+            let callable = Callable::from_local_fn("EditorState::on_play_event", on_frame);
+            let err = tree.clone().connect("process_frame", &callable);
+            if err != Error::OK {
+                return Err(err);
+            }
+
+            WATCHER.with_borrow_mut(|watcher| {
+                watcher.was_playing = EditorState::is_playing_scene();
+                watcher.connection = Some((tree, callable));
+            });
+        }
+
+        Ok(add_listener(function))
+    }
+}
+
+/// Registration of a function passed to [`EditorState::on_play_event()`]. Dropping it unregisters the function.
+#[must_use = "the function is unregistered when the listener is dropped"]
+#[derive(Debug)]
+pub struct PlayListener {
+    id: u64,
+}
+
+impl PlayListener {
+    /// Keeps the function registered until the extension's editor level is unloaded.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for PlayListener {
+    fn drop(&mut self) {
+        // The thread-local may already be destroyed at process exit; then there's nothing left to unregister.
+        let removed = WATCHER
+            .try_with(|watcher| {
+                let mut watcher = watcher.borrow_mut();
+                let index = watcher.listeners.iter().position(|(id, _)| *id == self.id);
+                index.map(|index| watcher.listeners.remove(index))
+            })
+            .ok()
+            .flatten();
+
+        // Drop outside the borrow, as captured values may run arbitrary code in their destructors.
+        drop(removed);
+    }
+}
+
+/// Change of the editor's play state, passed to [`EditorState::on_play_event()`].
+#[derive(Clone, Debug)]
+pub enum PlayEvent {
+    /// A scene started running, e.g. because the user pressed _Play_.
+    Started {
+        /// Path of the running scene.
+        scene: GString,
+    },
+
+    /// The running scene was stopped or exited.
+    Stopped,
+}
+
+/// Disconnects the watcher and drops all functions registered with [`EditorState::on_play_event()`].
+///
+/// Called when the `Editor` level is unloaded, as the functions may belong to user classes.
+pub(crate) fn unregister_play_listeners() {
+    let (connection, listeners) = WATCHER.with_borrow_mut(|watcher| {
+        (
+            watcher.connection.take(),
+            std::mem::take(&mut watcher.listeners),
+        )
+    });
+
+    if let Some((mut tree, callable)) = connection {
+        if tree.is_instance_valid() && tree.is_connected("process_frame", &callable) {
+            tree.disconnect("process_frame", &callable);
+        }
+    }
+
+    // Drop outside the borrow, as captured values may run arbitrary code in their destructors.
+    drop(listeners);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+thread_local! {
+    static WATCHER: RefCell<PlayWatcher> = RefCell::default();
+}
+
+type Listener = Rc<RefCell<dyn FnMut(PlayEvent)>>;
+
+#[derive(Default)]
+struct PlayWatcher {
+    was_playing: bool,
+    next_id: u64,
+    listeners: Vec<(u64, Listener)>,
+    connection: Option<(Gd<SceneTree>, Callable)>,
+}
+
+fn add_listener<F>(function: F) -> PlayListener
+where
+    F: FnMut(PlayEvent) + 'static,
+{
+    WATCHER.with_borrow_mut(|watcher| {
+        let id = watcher.next_id;
+        watcher.next_id += 1;
+        watcher
+            .listeners
+            .push((id, Rc::new(RefCell::new(function)) as Listener));
+
+        PlayListener { id }
+    })
+}
+
+/// Calls all registered listeners with `event`.
+fn dispatch(event: &PlayEvent) {
+    // Listeners may register or unregister listeners, so they are called outside the borrow. Ones registered during dispatch are
+    // only called for the next event.
+    let listeners = WATCHER.with_borrow(|watcher| watcher.listeners.clone());
+
+    for (id, listener) in listeners {
+        let still_registered =
+            WATCHER.with_borrow(|watcher| watcher.listeners.iter().any(|(other, _)| *other == id));
+
+        // A listener that is already running (re-entrant dispatch) is skipped.
+        if let (true, Ok(mut listener)) = (still_registered, listener.try_borrow_mut()) {
+            listener(event.clone());
+        }
+    }
+}
+
+fn on_frame(_args: &[&Variant]) -> Result<Variant, ()> {
+    let playing = EditorState::is_playing_scene();
+
+    let changed = WATCHER
+        .with_borrow_mut(|watcher| std::mem::replace(&mut watcher.was_playing, playing) != playing);
+    if !changed {
+        return Ok(Variant::nil());
+    }
+
+    let event = match EditorState::playing_scene() {
+        Some(scene) if playing => PlayEvent::Started { scene },
+        _ => PlayEvent::Stopped,
+    };
+
+    dispatch(&event);
+    Ok(Variant::nil())
+}
+
+fn editor_interface() -> Option<Gd<EditorInterface>> {
+    Engine::singleton()
+        .has_singleton("EditorInterface")
+        .then(EditorInterface::singleton)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Listeners are tested without the editor, by dispatching events directly.
+    fn count_events() -> (Rc<RefCell<u32>>, impl FnMut(PlayEvent)) {
+        let count = Rc::new(RefCell::new(0));
+        let counter = count.clone();
+        (count, move |_event| *counter.borrow_mut() += 1)
+    }
+
+    #[test]
+    fn listener_unregisters_on_drop() {
+        let (first_count, first) = count_events();
+        let (second_count, second) = count_events();
+        let first = add_listener(first);
+        let second = add_listener(second);
+
+        dispatch(&PlayEvent::Stopped);
+        drop(first);
+        dispatch(&PlayEvent::Stopped);
+
+        assert_eq!(*first_count.borrow(), 1);
+        assert_eq!(*second_count.borrow(), 2);
+
+        drop(second);
+        assert!(WATCHER.with_borrow(|watcher| watcher.listeners.is_empty()));
+    }
+
+    #[test]
+    fn listener_changes_during_dispatch() {
+        let (late_count, late) = count_events();
+        let late = Rc::new(RefCell::new(Some(late)));
+        let registered = Rc::new(RefCell::new(Vec::new()));
+        let victim = Rc::new(RefCell::new(None::<PlayListener>));
+
+        // The first listener registers another one and unregisters the third, which must then not be called anymore.
+        let registering = {
+            let late = late.clone();
+            let registered = registered.clone();
+            let victim = victim.clone();
+            add_listener(move |_event| {
+                if let Some(late) = late.borrow_mut().take() {
+                    registered.borrow_mut().push(add_listener(late));
+                }
+                victim.borrow_mut().take();
+            })
+        };
+
+        let (victim_count, victim_fn) = count_events();
+        *victim.borrow_mut() = Some(add_listener(victim_fn));
+
+        dispatch(&PlayEvent::Stopped);
+        assert_eq!(*late_count.borrow(), 0);
+        assert_eq!(*victim_count.borrow(), 0);
+
+        dispatch(&PlayEvent::Stopped);
+        assert_eq!(*late_count.borrow(), 1);
+
+        drop(registering);
+        registered.borrow_mut().clear();
+        assert!(WATCHER.with_borrow(|watcher| watcher.listeners.is_empty()));
+    }
+}
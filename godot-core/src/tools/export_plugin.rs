@@ -0,0 +1,360 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+
+use crate::builtin::{GString, PackedByteArray, PackedStringArray};
+use crate::classes::{EditorExportPlugin, EditorPlugin, Engine};
+use crate::godot_error;
+use crate::obj::{Gd, GodotClass, Inherits, NewGd, WithBaseField};
+
+/// Export in progress, passed to [`ExportHooks::begin()`].
+#[derive(Clone, Debug)]
+pub struct ExportInfo {
+    /// Features of the export preset, e.g. `"windows"`, `"release"` or custom features.
+    pub features: PackedStringArray,
+
+    /// Whether this is a debug export.
+    pub is_debug: bool,
+
+    /// Output path of the export.
+    pub path: GString,
+
+    /// Debug flags of the export (`EditorExportPlatform.DebugFlags`).
+    pub flags: u32,
+}
+
+/// File about to be exported, passed to [`ExportHooks::export_file()`].
+#[derive(Clone, Debug)]
+pub struct ExportFile {
+    /// Path of the file in the project, e.g. `res://levels/intro.tscn`.
+    pub path: GString,
+
+    /// Resource type of the file, e.g. `PackedScene`. Empty for non-resource files.
+    pub resource_type: GString,
+
+    /// Features of the export preset.
+    pub features: PackedStringArray,
+}
+
+impl ExportFile {
+    /// Whether the export preset has `feature`.
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.contains(&GString::from(feature))
+    }
+}
+
+/// What to do with a file, as decided by [`ExportHooks::export_file()`].
+#[derive(Clone, Debug)]
+pub enum ExportAction {
+    /// Export the file unchanged.
+    Keep,
+
+    /// Leave the file out of the export.
+    Skip,
+
+    /// Export `contents` under the file's path, instead of the original file.
+    Replace { contents: PackedByteArray },
+
+    /// Export `contents` under a different path `remap_to`, instead of the original file.
+    ///
+    /// Loading the original path is redirected to `remap_to`, as Godot does for imported resources. Use this if the replacement has a
+    /// different format, e.g. `res://data.json` exported as binary `res://data.res`.
+    Remap {
+        remap_to: GString,
+        contents: PackedByteArray,
+    },
+}
+
+/// Typed callbacks for an [`EditorExportPlugin`], to customize exports from Rust.
+///
+/// Implement this trait for your `#[class(tool, base=EditorExportPlugin)]` class, wire up the virtual methods with
+/// [`impl_export_plugin!`][crate::tools::impl_export_plugin], and add an instance to the editor with [`ExportPlugins`].
+///
+/// Errors are reported in the editor's output, naming the plugin and file. A failing `export_file()` exports the file unchanged, as
+/// Godot offers no way to abort an export from a plugin.
+///
+/// Additional files can be injected anytime during the export with `self.base_mut().add_file()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::EditorExportPlugin;
+/// use godot::tools::{impl_export_plugin, ExportAction, ExportFile, ExportHooks};
+/// use std::error::Error;
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorExportPlugin)]
+/// struct StripDebugAssets {
+///     base: Base<EditorExportPlugin>,
+/// }
+///
+/// impl ExportHooks for StripDebugAssets {
+///     fn export_file(&mut self, file: &ExportFile) -> Result<ExportAction, Box<dyn Error>> {
+///         if file.path.to_string().starts_with("res://debug/") && !file.has_feature("debug") {
+///             return Ok(ExportAction::Skip);
+///         }
+///         Ok(ExportAction::Keep)
+///     }
+/// }
+///
+/// impl_export_plugin!(StripDebugAssets, name = "StripDebugAssets");
+/// ```
+///
+/// # Manual forwarding
+/// The macro generates the whole `IEditorExportPlugin` impl. If your plugin overrides further virtual methods, write that impl yourself
+/// and forward with [`forward_export_begin()`], [`forward_export_file()`] and [`forward_export_end()`]:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::{EditorExportPlugin, IEditorExportPlugin};
+/// # use godot::tools::{forward_export_begin, forward_export_end, forward_export_file, ExportHooks};
+/// # #[derive(GodotClass)]
+/// # #[class(tool, init, base=EditorExportPlugin)]
+/// # struct StripDebugAssets {
+/// #     base: Base<EditorExportPlugin>,
+/// # }
+/// # impl ExportHooks for StripDebugAssets {}
+/// #[godot_api]
+/// impl IEditorExportPlugin for StripDebugAssets {
+///     fn get_name(&self) -> GString {
+///         "StripDebugAssets".into()
+///     }
+///
+///     fn export_begin(&mut self, features: PackedStringArray, is_debug: bool, path: GString, flags: u32) {
+///         forward_export_begin(self, features, is_debug, path, flags);
+///     }
+///
+///     fn export_file(&mut self, path: GString, type_: GString, features: PackedStringArray) {
+///         forward_export_file(self, path, type_, features);
+///     }
+///
+///     fn export_end(&mut self) {
+///         forward_export_end(self);
+///     }
+///
+///     // Further overrides...
+/// }
+/// ```
+pub trait ExportHooks {
+    /// Called when an export starts.
+    #[allow(unused_variables)]
+    fn begin(&mut self, info: &ExportInfo) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Called for each exported file; decides whether the file is kept, skipped or replaced.
+    #[allow(unused_variables)]
+    fn export_file(&mut self, file: &ExportFile) -> Result<ExportAction, Box<dyn Error>> {
+        Ok(ExportAction::Keep)
+    }
+
+    /// Called when the export has finished.
+    fn end(&mut self) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
+
+/// Forwards `IEditorExportPlugin::export_begin()` to [`ExportHooks::begin()`].
+pub fn forward_export_begin<T>(
+    plugin: &mut T,
+    features: PackedStringArray,
+    is_debug: bool,
+    path: GString,
+    flags: u32,
+) where
+    T: ExportHooks + WithBaseField + GodotClass<Base = EditorExportPlugin>,
+{
+    let info = ExportInfo {
+        features,
+        is_debug,
+        path,
+        flags,
+    };
+
+    if let Err(err) = plugin.begin(&info) {
+        report_error::<T>("export begin", err.as_ref());
+    }
+}
+
+/// Forwards `IEditorExportPlugin::export_file()` to [`ExportHooks::export_file()`], and applies its decision.
+pub fn forward_export_file<T>(
+    plugin: &mut T,
+    path: GString,
+    resource_type: GString,
+    features: PackedStringArray,
+) where
+    T: ExportHooks + WithBaseField + GodotClass<Base = EditorExportPlugin>,
+{
+    let file = ExportFile {
+        path,
+        resource_type,
+        features,
+    };
+
+    match plugin.export_file(&file) {
+        Ok(ExportAction::Keep) => {}
+        Ok(ExportAction::Skip) => plugin.base_mut().skip(),
+        Ok(ExportAction::Replace { contents }) => {
+            // Godot exports files added during export_file() in addition to the current one; skip() drops the original, so only the
+            // replacement ends up under this path.
+            let mut base = plugin.base_mut();
+            base.skip();
+            base.add_file(&file.path, &contents, false);
+        }
+        Ok(ExportAction::Remap { remap_to, contents }) => {
+            // With remap, Godot leaves out the current file by itself and records the redirection.
+            plugin.base_mut().add_file(&remap_to, &contents, true);
+        }
+        Err(err) => report_error::<T>(&format!("file '{}'", file.path), err.as_ref()),
+    }
+}
+
+/// Forwards `IEditorExportPlugin::export_end()` to [`ExportHooks::end()`].
+pub fn forward_export_end<T>(plugin: &mut T)
+where
+    T: ExportHooks + WithBaseField + GodotClass<Base = EditorExportPlugin>,
+{
+    if let Err(err) = plugin.end() {
+        report_error::<T>("export end", err.as_ref());
+    }
+}
+
+/// Implements `IEditorExportPlugin` for a class, forwarding the export virtuals to its [`ExportHooks`] impl.
+///
+/// `name` is returned from `get_name()`, which Godot requires for every export plugin. See [`ExportHooks`] for an example.
+#[macro_export]
+macro_rules! impl_export_plugin {
+    ($Class:ident, name = $name:expr $(,)?) => {
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IEditorExportPlugin for $Class {
+            fn get_name(&self) -> $crate::builtin::GString {
+                $crate::builtin::GString::from($name)
+            }
+
+            fn export_begin(
+                &mut self,
+                features: $crate::builtin::PackedStringArray,
+                is_debug: bool,
+                path: $crate::builtin::GString,
+                flags: u32,
+            ) {
+                $crate::tools::forward_export_begin(self, features, is_debug, path, flags);
+            }
+
+            fn export_file(
+                &mut self,
+                path: $crate::builtin::GString,
+                type_: $crate::builtin::GString,
+                features: $crate::builtin::PackedStringArray,
+            ) {
+                $crate::tools::forward_export_file(self, path, type_, features);
+            }
+
+            fn export_end(&mut self) {
+                $crate::tools::forward_export_end(self);
+            }
+        }
+    };
+}
+
+fn report_error<T: GodotClass>(context: &str, err: &dyn Error) {
+    godot_error!("export plugin {}: {context}: {err}", T::class_name());
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration
+
+/// Export plugins added to the editor through an `EditorPlugin`, and removed together.
+///
+/// Godot only accepts export plugins from an [`EditorPlugin`] that is part of the editor. Keep an `ExportPlugins` in your
+/// `#[class(editor_plugin)]` class, [`add()`][Self::add] plugins in `enter_tree()` and [`remove_all()`][Self::remove_all] of them in
+/// `exit_tree()`. This ties the export plugins to the lifecycle of the editor plugin, which the editor manages (including when the
+/// extension is reloaded).
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{EditorExportPlugin, EditorPlugin, IEditorPlugin};
+/// use godot::tools::{impl_export_plugin, ExportHooks, ExportPlugins};
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorExportPlugin)]
+/// struct StripDebugAssets {
+///     base: Base<EditorExportPlugin>,
+/// }
+///
+/// impl ExportHooks for StripDebugAssets {}
+/// impl_export_plugin!(StripDebugAssets, name = "StripDebugAssets");
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, editor_plugin, base=EditorPlugin)]
+/// struct MyEditorPlugin {
+///     export_plugins: ExportPlugins,
+///     base: Base<EditorPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorPlugin for MyEditorPlugin {
+///     fn enter_tree(&mut self) {
+///         let mut editor_plugin = self.to_gd().upcast();
+///         self.export_plugins.add::<StripDebugAssets>(&mut editor_plugin);
+///     }
+///
+///     fn exit_tree(&mut self) {
+///         let mut editor_plugin = self.to_gd().upcast();
+///         self.export_plugins.remove_all(&mut editor_plugin);
+///     }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ExportPlugins {
+    installed: Vec<Gd<EditorExportPlugin>>,
+}
+
+impl ExportPlugins {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an instance of `T` and adds it to the editor's export plugins, through `editor_plugin`.
+    ///
+    /// Returns the new plugin, or `None` outside the editor, where no export system exists.
+    pub fn add<T>(&mut self, editor_plugin: &mut Gd<EditorPlugin>) -> Option<Gd<T>>
+    where
+        T: Inherits<EditorExportPlugin> + NewGd,
+    {
+        if !Engine::singleton().is_editor_hint() {
+            return None;
+        }
+
+        let plugin = T::new_gd();
+        let upcast = plugin.clone().upcast::<EditorExportPlugin>();
+        editor_plugin.add_export_plugin(&upcast);
+        self.installed.push(upcast);
+
+        Some(plugin)
+    }
+
+    /// Removes all plugins added through [`add()`][Self::add] from the editor.
+    pub fn remove_all(&mut self, editor_plugin: &mut Gd<EditorPlugin>) {
+        for plugin in self.installed.drain(..) {
+            editor_plugin.remove_export_plugin(&plugin);
+        }
+    }
+
+    /// Number of plugins currently added.
+    pub fn len(&self) -> usize {
+        self.installed.len()
+    }
+
+    /// Whether no plugins are currently added.
+    pub fn is_empty(&self) -> bool {
+        self.installed.is_empty()
+    }
+}
@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::{Engine, Node, SceneTree};
+use crate::obj::Gd;
+use crate::tools::main_loop;
+
+/// Snapshot of the engine's timing state for the current frame.
+///
+/// Bundles values that are otherwise spread across [`Engine`] and [`Node`], so timing code can read them in one place. Construction
+/// only performs a few engine calls, so it is fine to create a snapshot every frame.
+///
+/// The values are copied at construction time and do not update.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::FrameInfo;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node3D)]
+/// struct Interpolated {
+///     previous: Vector3,
+///     current: Vector3,
+///     base: Base<Node3D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode3D for Interpolated {
+///     fn process(&mut self, _delta: f64) {
+///         let frame = FrameInfo::current();
+///         let weight = frame.physics_interpolation_fraction as real;
+///
+///         let position = self.previous.lerp(self.current, weight);
+///         self.base_mut().set_position(position);
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FrameInfo {
+    /// Number of process frames since startup.
+    ///
+    /// _Godot equivalent: `Engine.get_process_frames()`_
+    pub process_frame: u64,
+
+    /// Number of physics frames since startup.
+    ///
+    /// _Godot equivalent: `Engine.get_physics_frames()`_
+    pub physics_frame: u64,
+
+    /// Seconds elapsed since the previous process frame, as passed to `process()`.
+    ///
+    /// `0.0` if the main loop is not a `SceneTree`.
+    ///
+    /// _Godot equivalent: `Node.get_process_delta_time()`_
+    pub process_delta: f64,
+
+    /// Seconds elapsed since the previous physics frame, as passed to `physics_process()`.
+    ///
+    /// _Godot equivalent: `Node.get_physics_process_delta_time()`_
+    pub physics_delta: f64,
+
+    /// Progress between the last and the next physics frame, in `[0, 1]`. Used to interpolate physics state for rendering.
+    ///
+    /// _Godot equivalent: `Engine.get_physics_interpolation_fraction()`_
+    pub physics_interpolation_fraction: f64,
+
+    /// Whether the snapshot was taken during a physics frame (i.e. inside `physics_process()` or physics callbacks).
+    ///
+    /// _Godot equivalent: `Engine.is_in_physics_frame()`_
+    pub in_physics_frame: bool,
+
+    /// Physics frames per second, as configured in the project settings.
+    ///
+    /// _Godot equivalent: `Engine.physics_ticks_per_second`_
+    pub physics_ticks_per_second: i32,
+
+    /// Global time scale applied to deltas.
+    ///
+    /// _Godot equivalent: `Engine.time_scale`_
+    pub time_scale: f64,
+
+    /// Processing priority of the node the snapshot was taken for; `None` for [`current()`][Self::current].
+    ///
+    /// _Godot equivalent: `Node.process_priority`_
+    pub process_priority: Option<i32>,
+
+    /// Physics processing priority of the node the snapshot was taken for; `None` for [`current()`][Self::current].
+    ///
+    /// _Godot equivalent: `Node.process_physics_priority`_
+    #[cfg(since_api = "4.2")]
+    pub physics_process_priority: Option<i32>,
+}
+
+impl FrameInfo {
+    /// Takes a snapshot of the engine-wide timing state.
+    ///
+    /// Deltas are read from the scene tree's root. Node priorities are not available; see [`for_node()`][Self::for_node].
+    pub fn current() -> Self {
+        let root = main_loop::<SceneTree>()
+            .and_then(|tree| tree.get_root())
+            .map(Gd::upcast::<Node>);
+
+        Self::from_node_deltas(root.as_ref())
+    }
+
+    /// Takes a snapshot of the timing state as seen by `node`, including its processing priorities.
+    pub fn for_node(node: &Gd<Node>) -> Self {
+        Self {
+            process_priority: Some(node.get_process_priority()),
+            #[cfg(since_api = "4.2")]
+            physics_process_priority: Some(node.get_physics_process_priority()),
+            ..Self::from_node_deltas(Some(node))
+        }
+    }
+
+    fn from_node_deltas(node: Option<&Gd<Node>>) -> Self {
+        let engine = Engine::singleton();
+        let physics_ticks_per_second = engine.get_physics_ticks_per_second();
+
+        let (process_delta, physics_delta) = match node {
+            Some(node) => (
+                node.get_process_delta_time(),
+                node.get_physics_process_delta_time(),
+            ),
+            None => (0.0, 1.0 / f64::from(physics_ticks_per_second)),
+        };
+
+        Self {
+            process_frame: engine.get_process_frames(),
+            physics_frame: engine.get_physics_frames(),
+            process_delta,
+            physics_delta,
+            physics_interpolation_fraction: engine.get_physics_interpolation_fraction(),
+            in_physics_frame: engine.is_in_physics_frame(),
+            physics_ticks_per_second,
+            time_scale: engine.get_time_scale(),
+            process_priority: None,
+            #[cfg(since_api = "4.2")]
+            physics_process_priority: None,
+        }
+    }
+
+    /// Seconds elapsed since the previous frame of the current kind: [`physics_delta`][Self::physics_delta] inside a physics frame,
+    /// [`process_delta`][Self::process_delta] otherwise.
+    pub fn delta(&self) -> f64 {
+        if self.in_physics_frame {
+            self.physics_delta
+        } else {
+            self.process_delta
+        }
+    }
+}
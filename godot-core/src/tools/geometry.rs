@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Rust-native wrappers around the [`Geometry2D`] and [`Geometry3D`] singletons.
+//!
+//! The Godot APIs take and return packed arrays and untyped `Array`/`Variant` values. The functions in this module accept slices and
+//! return `Vec`s instead, and represent triangulations as index triples.
+//!
+//! # Example
+//! ```no_run
+//! use godot::builtin::Vector2;
+//! use godot::tools::geometry;
+//!
+//! let square = [
+//!     Vector2::new(0.0, 0.0),
+//!     Vector2::new(10.0, 0.0),
+//!     Vector2::new(10.0, 10.0),
+//!     Vector2::new(0.0, 10.0),
+//! ];
+//!
+//! let grown = geometry::offset_polygon(&square, 2.0, geometry::PolyJoinType::ROUND);
+//! let triangles = geometry::triangulate_polygon(&square).expect("simple polygon");
+//! assert_eq!(triangles.len(), 2);
+//! ```
+
+use crate::builtin::{
+    Array, PackedInt32Array, PackedVector2Array, PackedVector3Array, Vector2, Vector3,
+};
+use crate::classes::{Geometry2D, Geometry3D};
+
+pub use crate::classes::geometry_2d::{PolyEndType, PolyJoinType};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 2D polygon boolean operations
+
+/// Clips `polygon_a` against `polygon_b` (difference `A \ B`).
+///
+/// May return multiple polygons, e.g. when `B` splits `A`. Holes are returned as clockwise polygons, see [`is_polygon_clockwise()`].
+pub fn clip_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    let result = Geometry2D::singleton().clip_polygons(&packed2(polygon_a), &packed2(polygon_b));
+    unpack_polygons(result)
+}
+
+/// Intersects `polygon_a` with `polygon_b` (`A ∩ B`).
+pub fn intersect_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    let result =
+        Geometry2D::singleton().intersect_polygons(&packed2(polygon_a), &packed2(polygon_b));
+    unpack_polygons(result)
+}
+
+/// Merges `polygon_a` and `polygon_b` (`A ∪ B`).
+///
+/// Holes are returned as clockwise polygons, see [`is_polygon_clockwise()`].
+pub fn merge_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    let result = Geometry2D::singleton().merge_polygons(&packed2(polygon_a), &packed2(polygon_b));
+    unpack_polygons(result)
+}
+
+/// Returns the areas covered by exactly one of the polygons (`A ⊕ B`).
+pub fn exclude_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    let result = Geometry2D::singleton().exclude_polygons(&packed2(polygon_a), &packed2(polygon_b));
+    unpack_polygons(result)
+}
+
+/// Grows (`delta > 0`) or shrinks (`delta < 0`) a polygon, using `join` for the corners.
+///
+/// Shrinking may split the polygon into several, or make it vanish (empty result).
+pub fn offset_polygon(polygon: &[Vector2], delta: f64, join: PolyJoinType) -> Vec<Vec<Vector2>> {
+    let result = Geometry2D::singleton()
+        .offset_polygon_ex(&packed2(polygon), delta)
+        .join_type(join)
+        .done();
+    unpack_polygons(result)
+}
+
+/// Inflates an open polyline into polygons of half-width `delta`, using `join` for the corners and `end` for both ends.
+pub fn offset_polyline(
+    polyline: &[Vector2],
+    delta: f64,
+    join: PolyJoinType,
+    end: PolyEndType,
+) -> Vec<Vec<Vector2>> {
+    let result = Geometry2D::singleton()
+        .offset_polyline_ex(&packed2(polyline), delta)
+        .join_type(join)
+        .end_type(end)
+        .done();
+    unpack_polygons(result)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 2D analysis and triangulation
+
+/// Returns the convex hull of `points`, in counter-clockwise order. The first point is repeated at the end.
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    Geometry2D::singleton()
+        .convex_hull(&packed2(points))
+        .to_vec()
+}
+
+/// Returns `true` if the polygon's vertices are ordered clockwise.
+pub fn is_polygon_clockwise(polygon: &[Vector2]) -> bool {
+    Geometry2D::singleton().is_polygon_clockwise(&packed2(polygon))
+}
+
+/// Splits a polygon into convex parts.
+pub fn decompose_polygon_in_convex(polygon: &[Vector2]) -> Vec<Vec<Vector2>> {
+    let result = Geometry2D::singleton().decompose_polygon_in_convex(&packed2(polygon));
+    unpack_polygons(result)
+}
+
+/// Triangulates a simple polygon using ear clipping.
+///
+/// Returns triangles as index triples into `polygon`, or `None` if triangulation failed (e.g. for self-intersecting polygons).
+pub fn triangulate_polygon(polygon: &[Vector2]) -> Option<Vec<[usize; 3]>> {
+    let indices = Geometry2D::singleton().triangulate_polygon(&packed2(polygon));
+    index_tuples(&indices)
+}
+
+/// Computes the Delaunay triangulation of a point set.
+///
+/// Returns triangles as index triples into `points`, or `None` if triangulation failed (e.g. for fewer than three points).
+pub fn triangulate_delaunay(points: &[Vector2]) -> Option<Vec<[usize; 3]>> {
+    let indices = Geometry2D::singleton().triangulate_delaunay(&packed2(points));
+    index_tuples(&indices)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 3D
+
+/// Returns the point where the segment `from`..`to` intersects triangle `abc`, if any.
+pub fn segment_intersects_triangle(
+    from: Vector3,
+    to: Vector3,
+    triangle: [Vector3; 3],
+) -> Option<Vector3> {
+    let [a, b, c] = triangle;
+    Geometry3D::singleton()
+        .segment_intersects_triangle(from, to, a, b, c)
+        .try_to::<Vector3>()
+        .ok()
+}
+
+/// Returns the point where the ray starting at `from` in direction `dir` intersects triangle `abc`, if any.
+pub fn ray_intersects_triangle(
+    from: Vector3,
+    dir: Vector3,
+    triangle: [Vector3; 3],
+) -> Option<Vector3> {
+    let [a, b, c] = triangle;
+    Geometry3D::singleton()
+        .ray_intersects_triangle(from, dir, a, b, c)
+        .try_to::<Vector3>()
+        .ok()
+}
+
+/// Computes the Delaunay tetrahedralization of a point set.
+///
+/// Returns tetrahedra as index quadruples into `points`, or `None` if it failed (e.g. for coplanar points).
+pub fn tetrahedralize_delaunay(points: &[Vector3]) -> Option<Vec<[usize; 4]>> {
+    let indices =
+        Geometry3D::singleton().tetrahedralize_delaunay(&PackedVector3Array::from(points));
+    index_tuples(&indices)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn packed2(points: &[Vector2]) -> PackedVector2Array {
+    PackedVector2Array::from(points)
+}
+
+fn unpack_polygons(polygons: Array<PackedVector2Array>) -> Vec<Vec<Vector2>> {
+    polygons
+        .iter_shared()
+        .map(|polygon| polygon.to_vec())
+        .collect()
+}
+
+/// Groups a flat index array into `N`-tuples. Godot returns an empty array on failure.
+fn index_tuples<const N: usize>(indices: &PackedInt32Array) -> Option<Vec<[usize; N]>> {
+    let indices = indices.as_slice();
+    if indices.is_empty() {
+        return None;
+    }
+
+    let tuples = indices
+        .chunks_exact(N)
+        .map(|chunk| std::array::from_fn(|i| chunk[i] as usize))
+        .collect();
+
+    Some(tuples)
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::image::Format;
+use crate::classes::Image;
+use crate::meta::error::ImageError;
+
+/// Mipmap handling when creating an image from raw data.
+///
+/// Used by [`Image::from_raw()`][crate::classes::Image::from_raw] and related constructors.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum ImageMipmaps {
+    /// The image has no mipmaps. The data contains only the full-size image.
+    #[default]
+    None,
+
+    /// The data contains only the full-size image; mipmaps are generated by Godot after creation.
+    Generate,
+
+    /// The data contains the full-size image, followed by each mipmap level down to 1x1. Each level halves both dimensions
+    /// (rounding down, but at least 1 pixel).
+    Included,
+}
+
+/// Checks the dimensions and data size for an image created from raw data.
+pub(crate) fn validate_image_data(
+    width: u32,
+    height: u32,
+    format: Format,
+    data_len: usize,
+    mipmaps: ImageMipmaps,
+) -> Result<(), ImageError> {
+    // Godot additionally limits the pixel count, see Image::MAX_PIXELS in C++.
+    const MAX_PIXELS: u64 = 1 << 28;
+
+    let max_width = Image::MAX_WIDTH as u32;
+    let max_height = Image::MAX_HEIGHT as u32;
+
+    if width == 0
+        || height == 0
+        || width > max_width
+        || height > max_height
+        || u64::from(width) * u64::from(height) > MAX_PIXELS
+    {
+        return Err(ImageError::invalid_size(width, height));
+    }
+
+    let bytes_per_pixel =
+        bytes_per_pixel(format).ok_or_else(|| ImageError::unsupported_format(format))?;
+
+    let pixels = if mipmaps == ImageMipmaps::Included {
+        mipmap_chain_pixels(width, height)
+    } else {
+        u64::from(width) * u64::from(height)
+    };
+
+    let expected = (pixels * bytes_per_pixel) as usize;
+    if data_len != expected {
+        return Err(ImageError::data_size(format, expected, data_len));
+    }
+
+    Ok(())
+}
+
+/// Size of one pixel in bytes, for uncompressed formats. Returns `None` for compressed formats.
+fn bytes_per_pixel(format: Format) -> Option<u64> {
+    let size = match format {
+        Format::L8 | Format::R8 => 1,
+        Format::LA8 | Format::RG8 | Format::RGBA4444 | Format::RGB565 | Format::RH => 2,
+        Format::RGB8 => 3,
+        Format::RGBA8 | Format::RF | Format::RGH | Format::RGBE9995 => 4,
+        Format::RGBH => 6,
+        Format::RGF | Format::RGBAH => 8,
+        Format::RGBF => 12,
+        Format::RGBAF => 16,
+        _ => return None,
+    };
+
+    Some(size)
+}
+
+/// Total number of pixels in the image and all its mipmap levels.
+fn mipmap_chain_pixels(width: u32, height: u32) -> u64 {
+    let (mut width, mut height) = (u64::from(width), u64::from(height));
+    let mut total = width * height;
+
+    while width > 1 || height > 1 {
+        width = (width / 2).max(1);
+        height = (height / 2).max(1);
+        total += width * height;
+    }
+
+    total
+}
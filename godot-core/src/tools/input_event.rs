@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::{
+    InputEvent, InputEventAction, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMouseButton, InputEventMouseMotion, InputEventScreenDrag, InputEventScreenTouch,
+};
+use crate::global::{Key, MouseButton};
+use crate::obj::{Gd, Inherits};
+
+/// Typed view of an [`InputEvent`], for `match` expressions.
+///
+/// Obtained through [`InputEventExt::to_typed()`]. Each variant holds the event, downcast to its concrete class.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum TypedInputEvent {
+    Key(Gd<InputEventKey>),
+    MouseButton(Gd<InputEventMouseButton>),
+    MouseMotion(Gd<InputEventMouseMotion>),
+    ScreenTouch(Gd<InputEventScreenTouch>),
+    ScreenDrag(Gd<InputEventScreenDrag>),
+    JoypadButton(Gd<InputEventJoypadButton>),
+    JoypadMotion(Gd<InputEventJoypadMotion>),
+    Action(Gd<InputEventAction>),
+
+    /// Any other event type, e.g. gestures or MIDI.
+    Other(Gd<InputEvent>),
+}
+
+/// Typed extensions for [`InputEvent`].
+///
+/// Input callbacks such as `input()` and `unhandled_input()` receive a `Gd<InputEvent>`, which usually needs to be downcast before its
+/// data can be accessed. This trait provides shortcuts for the common checks, and [`to_typed()`][Self::to_typed] for exhaustive handling.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::InputEvent;
+/// use godot::global::{Key, MouseButton};
+/// use godot::tools::{InputEventExt, TypedInputEvent};
+///
+/// fn handle_input(event: Gd<InputEvent>) {
+///     if event.is_key_press(Key::SPACE) {
+///         godot_print!("jump");
+///     } else if event.is_mouse_button_press(MouseButton::LEFT) {
+///         godot_print!("shoot");
+///     }
+///
+///     match event.to_typed() {
+///         TypedInputEvent::MouseMotion(motion) => godot_print!("aim: {}", motion.get_relative()),
+///         TypedInputEvent::JoypadMotion(motion) => godot_print!("stick: {}", motion.get_axis_value()),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub trait InputEventExt {
+    /// Downcasts the event to its concrete class, returned as an enum variant.
+    fn to_typed(&self) -> TypedInputEvent;
+
+    /// Returns the key event, if this is a key press that isn't an echo (repeated event while the key is held).
+    fn as_key_press(&self) -> Option<Gd<InputEventKey>>;
+
+    /// Returns the mouse button event, if this is a mouse button press.
+    fn as_mouse_button_press(&self) -> Option<Gd<InputEventMouseButton>>;
+
+    /// Whether this is a press of `key` that isn't an echo, according to the keyboard layout.
+    ///
+    /// Compares the layout-dependent keycode, i.e. the key's label. Use this for shortcuts that refer to letters, such as `Ctrl+S`.
+    fn is_key_press(&self, key: Key) -> bool {
+        self.as_key_press()
+            .is_some_and(|event| event.get_keycode() == key)
+    }
+
+    /// Whether this is a press of the key at the position of `key` on a US QWERTY keyboard, and isn't an echo.
+    ///
+    /// Compares the physical keycode, which is independent of the keyboard layout: `Key::W` matches the `Z` key on AZERTY keyboards. Use
+    /// this for movement and other controls where the key position matters.
+    fn is_physical_key_press(&self, key: Key) -> bool {
+        self.as_key_press()
+            .is_some_and(|event| event.get_physical_keycode() == key)
+    }
+
+    /// Whether this is a press of mouse button `button`.
+    fn is_mouse_button_press(&self, button: MouseButton) -> bool {
+        self.as_mouse_button_press()
+            .is_some_and(|event| event.get_button_index() == button)
+    }
+
+    /// Whether this is a release of mouse button `button`.
+    fn is_mouse_button_release(&self, button: MouseButton) -> bool;
+}
+
+impl InputEventExt for Gd<InputEvent> {
+    fn to_typed(&self) -> TypedInputEvent {
+        let event = self.clone();
+
+        // Order doesn't matter, since none of these classes inherit from each other.
+        let event = match event.try_cast::<InputEventKey>() {
+            Ok(key) => return TypedInputEvent::Key(key),
+            Err(event) => event,
+        };
+        let event = match event.try_cast::<InputEventMouseButton>() {
+            Ok(button) => return TypedInputEvent::MouseButton(button),
+            Err(event) => event,
+        };
+        let event = match event.try_cast::<InputEventMouseMotion>() {
+            Ok(motion) => return TypedInputEvent::MouseMotion(motion),
+            Err(event) => event,
+        };
+        let event = match event.try_cast::<InputEventScreenTouch>() {
+            Ok(touch) => return TypedInputEvent::ScreenTouch(touch),
+            Err(event) => event,
+        };
+        let event = match event.try_cast::<InputEventScreenDrag>() {
+            Ok(drag) => return TypedInputEvent::ScreenDrag(drag),
+            Err(event) => event,
+        };
+        let event = match event.try_cast::<InputEventJoypadButton>() {
+            Ok(button) => return TypedInputEvent::JoypadButton(button),
+            Err(event) => event,
+        };
+        let event = match event.try_cast::<InputEventJoypadMotion>() {
+            Ok(motion) => return TypedInputEvent::JoypadMotion(motion),
+            Err(event) => event,
+        };
+        match event.try_cast::<InputEventAction>() {
+            Ok(action) => TypedInputEvent::Action(action),
+            Err(event) => TypedInputEvent::Other(event),
+        }
+    }
+
+    fn as_key_press(&self) -> Option<Gd<InputEventKey>> {
+        let key = downcast::<InputEventKey>(self)?;
+
+        (key.is_pressed() && !key.is_echo()).then_some(key)
+    }
+
+    fn as_mouse_button_press(&self) -> Option<Gd<InputEventMouseButton>> {
+        let button = downcast::<InputEventMouseButton>(self)?;
+
+        button.is_pressed().then_some(button)
+    }
+
+    fn is_mouse_button_release(&self, button: MouseButton) -> bool {
+        downcast::<InputEventMouseButton>(self)
+            .is_some_and(|event| !event.is_pressed() && event.get_button_index() == button)
+    }
+}
+
+fn downcast<T: Inherits<InputEvent>>(event: &Gd<InputEvent>) -> Option<Gd<T>> {
+    event.clone().try_cast::<T>().ok()
+}
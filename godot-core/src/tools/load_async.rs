@@ -0,0 +1,261 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, GString, StringName, Variant, VariantArray};
+use crate::classes::resource_loader::ThreadLoadStatus;
+use crate::classes::{RefCounted, Resource, ResourceLoader};
+use crate::global::Error as GodotError;
+use crate::meta::error::IoError;
+use crate::meta::{arg_into_owned, AsArg, ToGodot};
+use crate::obj::{Gd, GodotClass, Inherits, NewGd};
+use crate::tools::save_load::finish_load;
+
+/// Loads a resource in the background, returning a future that resolves once loading has finished.
+///
+/// Uses Godot's threaded loading (`ResourceLoader.load_threaded_request()`). The returned [`LoadFuture`] polls the load status; while
+/// loading is in progress, it schedules its own wake-up as a deferred call, i.e. it is re-polled once per frame. It can thus be driven
+/// by any executor running on the main thread.
+///
+/// Progress can be observed through [`LoadFuture::on_progress()`] or a [`LoadProgress`] handle obtained before awaiting.
+///
+/// # Threading
+/// This API requires the `experimental-threads` feature. Godot loads the resource on a worker thread, which constructs the resource and
+/// its sub-resources there. If any of them is a Rust class, its `init()` and property setters run on that thread. Without the feature,
+/// godot-rust assumes that all Godot calls happen on the main thread, and panics otherwise in Debug builds.
+///
+/// The future itself is not `Send` and must be polled on the main thread, e.g. by a task started with `tools::spawn_local()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::load_async;
+///
+/// async fn show_level(mut progress_bar: Gd<Node>) -> Gd<PackedScene> {
+///     load_async::<PackedScene>("res://levels/level1.tscn")
+///         .on_progress(move |ratio| {
+///             progress_bar.set("value", &(ratio * 100.0).to_variant());
+///         })
+///         .await
+///         .expect("level exists")
+/// }
+/// ```
+pub fn load_async<T>(path: impl AsArg<GString>) -> LoadFuture<T>
+where
+    T: Inherits<Resource>,
+{
+    arg_into_owned!(path);
+
+    LoadFuture {
+        path,
+        state: State::NotRequested,
+        progress: LoadProgress::default(),
+        on_progress: None,
+        waker: None,
+        _marker: PhantomData,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future returned by [`load_async()`].
+///
+/// The load is requested on first poll. Dropping the future before completion does not cancel the load; Godot finishes it in the
+/// background and keeps it cached until the same path is loaded again.
+#[must_use = "futures do nothing unless awaited or polled"]
+pub struct LoadFuture<T: GodotClass> {
+    path: GString,
+    state: State,
+    progress: LoadProgress,
+    on_progress: Option<Box<dyn FnMut(f32)>>,
+    waker: Option<DeferredWaker>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> LoadFuture<T>
+where
+    T: Inherits<Resource>,
+{
+    /// Invokes `callback` with the load progress in `0.0..=1.0` whenever it changes.
+    pub fn on_progress(mut self, callback: impl FnMut(f32) + 'static) -> Self {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns a handle to observe the progress of this load, e.g. from a UI node while the future is being awaited elsewhere.
+    pub fn progress_handle(&self) -> LoadProgress {
+        self.progress.clone()
+    }
+
+    /// Path of the resource being loaded.
+    pub fn path(&self) -> &GString {
+        &self.path
+    }
+
+    fn request(&self) -> Result<(), IoError> {
+        let result = ResourceLoader::singleton()
+            .load_threaded_request_ex(&self.path)
+            .type_hint(&T::class_name().to_gstring())
+            .done();
+
+        if result == GodotError::OK {
+            Ok(())
+        } else {
+            Err(IoError::loading_request(
+                result,
+                T::class_name().to_string(),
+                self.path.to_string(),
+            ))
+        }
+    }
+
+    fn update_progress(&mut self, ratio: f32) {
+        if ratio == self.progress.get() {
+            return;
+        }
+
+        self.progress.set(ratio);
+        if let Some(callback) = self.on_progress.as_mut() {
+            callback(ratio);
+        }
+    }
+}
+
+impl<T> Future for LoadFuture<T>
+where
+    T: Inherits<Resource>,
+{
+    type Output = Result<Gd<T>, IoError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // No field is structurally pinned.
+        let this = self.get_mut();
+
+        match this.state {
+            State::NotRequested => {
+                if let Err(err) = this.request() {
+                    this.state = State::Done;
+                    return Poll::Ready(Err(err));
+                }
+                this.state = State::Requested;
+            }
+            State::Requested => {}
+            State::Done => panic!("LoadFuture polled after completion"),
+        }
+
+        let progress = VariantArray::new();
+        let status = ResourceLoader::singleton()
+            .load_threaded_get_status_ex(&this.path)
+            .progress(&progress)
+            .done();
+
+        if status == ThreadLoadStatus::IN_PROGRESS {
+            if let Some(ratio) = progress.get(0).and_then(|v| v.try_to::<f32>().ok()) {
+                this.update_progress(ratio);
+            }
+
+            this.waker
+                .get_or_insert_with(DeferredWaker::new)
+                .wake_next_frame(cx.waker());
+            return Poll::Pending;
+        }
+
+        this.state = State::Done;
+
+        let loaded = if status == ThreadLoadStatus::LOADED {
+            this.update_progress(1.0);
+            ResourceLoader::singleton().load_threaded_get(&this.path)
+        } else {
+            // FAILED or INVALID_RESOURCE.
+            None
+        };
+
+        Poll::Ready(finish_load(loaded, &this.path))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Shared handle to the progress of a [`LoadFuture`].
+///
+/// Cloning the handle is cheap; all clones observe the same load.
+#[derive(Clone, Default, Debug)]
+pub struct LoadProgress {
+    ratio: Rc<Cell<f32>>,
+}
+
+impl LoadProgress {
+    /// Returns the last observed progress, in `0.0..=1.0`.
+    ///
+    /// The value is updated whenever the future is polled, so it can lag one frame behind the actual load.
+    pub fn get(&self) -> f32 {
+        self.ratio.get()
+    }
+
+    fn set(&self, ratio: f32) {
+        self.ratio.set(ratio);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum State {
+    NotRequested,
+    Requested,
+    Done,
+}
+
+/// Wakes a task at the end of the current frame, by emitting a signal through `call_deferred()`.
+///
+/// Godot's message queue only holds the object ID, so a deferred call to an already freed holder is skipped.
+pub(crate) struct DeferredWaker {
+    holder: Gd<RefCounted>,
+    waker: Rc<RefCell<Option<Waker>>>,
+}
+
+impl DeferredWaker {
+    const SIGNAL: &'static str = "wake";
+
+    pub(crate) fn new() -> Self {
+        let waker = Rc::new(RefCell::new(None::<Waker>));
+        let mut holder = RefCounted::new_gd();
+
+        let callback_waker = waker.clone();
+        let callable = Callable::from_local_fn("wake", move |_args| {
+            if let Some(waker) = callback_waker.borrow_mut().take() {
+                waker.wake();
+            }
+            Ok(Variant::nil())
+        });
+
+        holder.add_user_signal(Self::SIGNAL);
+        holder.connect(Self::SIGNAL, &callable);
+
+        Self { holder, waker }
+    }
+
+    pub(crate) fn wake_next_frame(&mut self, waker: &Waker) {
+        let mut slot = self.waker.borrow_mut();
+        let already_scheduled = slot.is_some();
+        *slot = Some(waker.clone());
+        drop(slot);
+
+        if !already_scheduled {
+            let signal = StringName::from(Self::SIGNAL);
+            self.holder
+                .call_deferred("emit_signal", &[signal.to_variant()]);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Wake, Waker};
+#[cfg(not(wasm_nothreads))]
+use std::thread::ThreadId;
+
+use crate::builtin::{Callable, Signal, StringName, Variant};
+use crate::classes::{Object, RefCounted};
+use crate::meta::ToGodot;
+use crate::obj::{NewAlloc, NewGd};
+
+/// Runs `future` to completion on the main thread, driven by Godot's main loop.
+///
+/// The future is first polled at the end of the current frame (like `call_deferred()`), and then every time it is woken. Futures
+/// provided by godot-rust, such as signal futures, `load_async()` or worker-pool task handles, wake on the main thread; this executor does
+/// not support waking from other threads.
+///
+/// Must be called on the main thread. Tasks that are still pending when the extension is unloaded are dropped without completing.
+///
+/// # Wasm without threads
+/// With the `experimental-wasm-nothreads` feature, there is no message queue flush from other threads to rely on; woken tasks are instead
+/// polled from the `SceneTree::process_frame` signal, i.e. once per frame. Signal futures work as usual, whereas thread-based futures
+/// (`load_async()`, worker-pool tasks) are not available in such builds.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::Timer;
+/// use godot::tools::spawn_local;
+///
+/// fn flash(mut node: Gd<Node2D>, timer: Gd<Timer>) {
+///     spawn_local(async move {
+///         node.hide();
+///         timer.await_timeout().await;
+///         node.show();
+///     });
+/// }
+/// ```
+pub fn spawn_local<F>(future: F)
+where
+    F: Future<Output = ()> + 'static,
+{
+    EXECUTOR.with_borrow_mut(|executor| {
+        let id = executor.next_id;
+        executor.next_id += 1;
+        executor.tasks.insert(id, Box::pin(future));
+        executor.schedule(id);
+    });
+}
+
+/// Runs `future` like [`spawn_local()`], and returns a signal that is emitted with the future's output on completion.
+///
+/// Return this signal from a `#[func]` to make an async Rust function awaitable in GDScript: `await` on a signal resolves to its argument.
+/// The signal is emitted no earlier than at the end of the current frame, so callers that `await` the returned value right away never miss
+/// the emission.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::spawn_awaitable;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Calculator {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Calculator {
+///     // GDScript: var sum = await calculator.slow_add(1, 2)
+///     #[func]
+///     fn slow_add(&self, a: i64, b: i64) -> Signal {
+///         let timer = self.base().get_tree().unwrap().create_timer(1.0).unwrap();
+///
+///         spawn_awaitable(async move {
+///             timer.await_timeout().await;
+///             a + b
+///         })
+///     }
+/// }
+/// ```
+pub fn spawn_awaitable<F, R>(future: F) -> Signal
+where
+    F: Future<Output = R> + 'static,
+    R: ToGodot,
+{
+    const COMPLETED: &str = "completed";
+
+    let mut emitter = RefCounted::new_gd();
+    emitter.add_user_signal(COMPLETED);
+    let signal = Signal::from_object_signal(&emitter, COMPLETED);
+
+    spawn_local(async move {
+        let output = future.await.to_variant();
+        emitter.emit_signal(COMPLETED, &[output]);
+    });
+
+    signal
+}
+
+/// Drops all pending tasks.
+///
+/// Called on unloading of the `Scene` level, while objects held by the tasks can still be released.
+pub(crate) fn cleanup() {
+    let tasks = EXECUTOR.with_borrow_mut(|executor| {
+        executor.ready.clear();
+        std::mem::take(&mut executor.tasks)
+    });
+
+    // Drop outside of the borrow, in case a destructor spawns another task.
+    drop(tasks);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type TaskId = u64;
+
+thread_local! {
+    static EXECUTOR: RefCell<Executor> = RefCell::new(Executor::default());
+}
+
+#[derive(Default)]
+struct Executor {
+    tasks: HashMap<TaskId, Pin<Box<dyn Future<Output = ()>>>>,
+    next_id: TaskId,
+
+    /// Tasks to poll in the next deferred run, in wake order.
+    ready: Vec<TaskId>,
+}
+
+impl Executor {
+    fn schedule(&mut self, id: TaskId) {
+        if self.ready.contains(&id) {
+            return;
+        }
+
+        // The first scheduled task of a batch requests the deferred run.
+        if self.ready.is_empty() {
+            run_deferred();
+        }
+        self.ready.push(id);
+    }
+}
+
+/// Requests [`poll_ready_tasks()`] at the start of the next frame, from the scene tree's frame callback.
+///
+/// Falls back to the message queue if the main loop is not a `SceneTree`.
+#[cfg(wasm_nothreads)]
+fn run_deferred() {
+    use crate::classes::object::ConnectFlags;
+    use crate::classes::{Engine, SceneTree};
+    use crate::obj::EngineBitfield;
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok());
+
+    match tree {
+        Some(mut tree) => {
+            tree.connect_ex(
+                "process_frame",
+                &Callable::from_local_fn("spawn_local", |_args| {
+                    poll_ready_tasks();
+                    Ok(Variant::nil())
+                }),
+            )
+            .flags(ConnectFlags::ONE_SHOT.ord() as u32)
+            .done();
+        }
+        None => run_deferred_via_message_queue(),
+    }
+}
+
+#[cfg(not(wasm_nothreads))]
+fn run_deferred() {
+    run_deferred_via_message_queue();
+}
+
+/// Requests [`poll_ready_tasks()`] at the end of the frame, through Godot's message queue.
+fn run_deferred_via_message_queue() {
+    let mut holder = Object::new_alloc();
+    let signal = StringName::from("poll_tasks");
+
+    holder.add_user_signal(&signal);
+    holder.connect(
+        &signal,
+        &Callable::from_local_fn("spawn_local", |_args| {
+            poll_ready_tasks();
+            Ok(Variant::nil())
+        }),
+    );
+    holder.call_deferred("emit_signal", &[signal.to_variant()]);
+    holder.call_deferred("free", &[]);
+}
+
+fn poll_ready_tasks() {
+    let ready = EXECUTOR.with_borrow_mut(|executor| std::mem::take(&mut executor.ready));
+
+    for id in ready {
+        // Take the task out while polling, so it can spawn or wake tasks (including itself) without conflicting borrows.
+        let Some(mut task) = EXECUTOR.with_borrow_mut(|executor| executor.tasks.remove(&id)) else {
+            // Completed or cleaned up in the meantime.
+            continue;
+        };
+
+        let waker = Waker::from(Arc::new(TaskWaker {
+            id,
+            #[cfg(not(wasm_nothreads))]
+            thread: std::thread::current().id(),
+        }));
+
+        if task
+            .as_mut()
+            .poll(&mut Context::from_waker(&waker))
+            .is_pending()
+        {
+            EXECUTOR.with_borrow_mut(|executor| executor.tasks.insert(id, task));
+        }
+    }
+}
+
+struct TaskWaker {
+    id: TaskId,
+
+    // Wasm builds without threads cannot query thread IDs; there is only the main thread anyway.
+    #[cfg(not(wasm_nothreads))]
+    thread: ThreadId,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        #[cfg(not(wasm_nothreads))]
+        assert_eq!(
+            std::thread::current().id(),
+            self.thread,
+            "task spawned with spawn_local() must be woken on the main thread"
+        );
+
+        EXECUTOR.with_borrow_mut(|executor| executor.schedule(self.id));
+    }
+}
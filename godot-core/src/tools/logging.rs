@@ -0,0 +1,463 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Integration of the `log` and `tracing` ecosystems with Godot's output.
+
+use std::thread::ThreadId;
+
+use crate::builtin::Variant;
+use crate::sys;
+
+/// Severity with which a forwarded message is reported to Godot.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum GodotSeverity {
+    /// `godot_error!`, shown in the debugger's error panel.
+    Error,
+    /// `godot_warn!`, shown in the debugger's error panel.
+    Warning,
+    /// `godot_print!`, shown in the output panel.
+    Print,
+}
+
+/// Forwards a formatted record to Godot, or to stderr if Godot cannot be reached from the current thread.
+fn forward_to_godot(
+    severity: GodotSeverity,
+    main_thread: ThreadId,
+    level: &str,
+    target: &str,
+    message: &str,
+    file: Option<&str>,
+    line: Option<u32>,
+) {
+    // Without experimental-threads, the Godot binding may only be accessed on the main thread.
+    let reachable = sys::is_initialized()
+        && (cfg!(feature = "experimental-threads") || std::thread::current().id() == main_thread);
+
+    if !reachable {
+        eprintln!("[{level}] {target}: {message}");
+        return;
+    }
+
+    match severity {
+        GodotSeverity::Print => {
+            let line = format!("[{level}] {target}: {message}");
+            crate::global::print(&[Variant::from(line)]);
+        }
+        GodotSeverity::Warning | GodotSeverity::Error => {
+            let msg = format!("{target}: {message}\0");
+            let file = format!("{}\0", file.unwrap_or_default());
+            let line = line.map_or(0, |l| l as i32);
+
+            // SAFETY: Godot is initialized and accessed from a valid thread; all strings are null-terminated and outlive the call.
+            unsafe {
+                let print_fn = if severity == GodotSeverity::Error {
+                    sys::interface_fn!(print_error)
+                } else {
+                    sys::interface_fn!(print_warning)
+                };
+
+                print_fn(
+                    sys::c_str_from_str(&msg),
+                    sys::c_str_from_str("\0"),
+                    sys::c_str_from_str(&file),
+                    line,
+                    false as sys::GDExtensionBool,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the most specific level override whose target prefix matches `target`, or `default` otherwise.
+fn level_for_target<L: Copy>(overrides: &[(String, L)], default: L, target: &str) -> L {
+    overrides
+        .iter()
+        .filter(|(prefix, _)| {
+            target == prefix
+                || target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.starts_with("::"))
+        })
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map_or(default, |(_, level)| *level)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// log
+
+#[cfg(feature = "log")]
+pub use log_impl::GodotLogger;
+
+#[cfg(feature = "log")]
+mod log_impl {
+    use super::*;
+    use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+    /// Logger for the [`log`](https://docs.rs/log) crate, forwarding records to the Godot console.
+    ///
+    /// Levels are mapped as follows:
+    ///
+    /// | `log` level                 | Godot output          |
+    /// |-----------------------------|-----------------------|
+    /// | `Error`                     | [`godot_error!`]      |
+    /// | `Warn`                      | [`godot_warn!`]       |
+    /// | `Info`, `Debug`, `Trace`    | [`godot_print!`]      |
+    ///
+    /// Records emitted on threads other than the main thread are written to stderr instead, unless the `experimental-threads`
+    /// feature is enabled.
+    ///
+    /// # Example
+    /// Return the logger from [`ExtensionLibrary::logger()`][crate::init::ExtensionLibrary::logger], and godot-rust installs it when the
+    /// `Scene` level is initialized (requires a dependency on `log`):
+    /// ```ignore
+    /// use godot::prelude::*;
+    /// use godot::tools::GodotLogger;
+    ///
+    /// struct MyExtension;
+    ///
+    /// #[gdextension]
+    /// unsafe impl ExtensionLibrary for MyExtension {
+    ///     fn logger() -> Option<GodotLogger> {
+    ///         let logger = GodotLogger::new()
+    ///             .max_level(log::LevelFilter::Info)
+    ///             .target_level("noisy_crate", log::LevelFilter::Warn);
+    ///
+    ///         Some(logger)
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// To install it at another time, call [`install()`][Self::install] yourself.
+    ///
+    /// [`godot_error!`]: crate::global::godot_error
+    /// [`godot_warn!`]: crate::global::godot_warn
+    /// [`godot_print!`]: crate::global::godot_print
+    #[cfg_attr(published_docs, doc(cfg(feature = "log")))]
+    pub struct GodotLogger {
+        max_level: LevelFilter,
+        target_levels: Vec<(String, LevelFilter)>,
+        main_thread: ThreadId,
+    }
+
+    impl GodotLogger {
+        /// Creates a logger with maximum level `Info` and no target-specific overrides.
+        ///
+        /// Must be called on the main thread.
+        pub fn new() -> Self {
+            Self {
+                max_level: LevelFilter::Info,
+                target_levels: Vec::new(),
+                main_thread: std::thread::current().id(),
+            }
+        }
+
+        /// Sets the maximum level for all targets without a specific override.
+        pub fn max_level(mut self, level: LevelFilter) -> Self {
+            self.max_level = level;
+            self
+        }
+
+        /// Sets the maximum level for `target` and its sub-modules (e.g. `"my_crate"` also affects `"my_crate::net"`).
+        ///
+        /// If multiple overrides match, the longest target wins.
+        pub fn target_level(mut self, target: impl Into<String>, level: LevelFilter) -> Self {
+            self.target_levels.push((target.into(), level));
+            self
+        }
+
+        /// Installs this logger as the global logger of the `log` crate.
+        ///
+        /// Fails if another logger has already been installed. The global maximum level is set to the most verbose configured level.
+        pub fn install(self) -> Result<(), SetLoggerError> {
+            let global_max = self
+                .target_levels
+                .iter()
+                .map(|(_, level)| *level)
+                .fold(self.max_level, Ord::max);
+
+            log::set_boxed_logger(Box::new(self))?;
+            log::set_max_level(global_max);
+            Ok(())
+        }
+    }
+
+    impl Default for GodotLogger {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Log for GodotLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            let max = level_for_target(&self.target_levels, self.max_level, metadata.target());
+            metadata.level() <= max
+        }
+
+        fn log(&self, record: &Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+
+            let severity = match record.level() {
+                Level::Error => GodotSeverity::Error,
+                Level::Warn => GodotSeverity::Warning,
+                Level::Info | Level::Debug | Level::Trace => GodotSeverity::Print,
+            };
+
+            forward_to_godot(
+                severity,
+                self.main_thread,
+                record.level().as_str(),
+                record.target(),
+                &record.args().to_string(),
+                record.file(),
+                record.line(),
+            );
+        }
+
+        fn flush(&self) {}
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// tracing
+
+#[cfg(feature = "tracing")]
+pub use tracing_impl::GodotTracingLayer;
+
+#[cfg(feature = "tracing")]
+mod tracing_impl {
+    use super::*;
+    use std::fmt::{self, Write as _};
+    use tracing_core::dispatcher::SetGlobalDefaultError;
+    use tracing_core::field::{Field, Visit};
+    use tracing_core::{Dispatch, Event, Level, LevelFilter, Metadata, Subscriber};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    /// Layer for [`tracing-subscriber`](https://docs.rs/tracing-subscriber), forwarding events to the Godot console.
+    ///
+    /// Levels are mapped as follows:
+    ///
+    /// | `tracing` level             | Godot output          |
+    /// |-----------------------------|-----------------------|
+    /// | `ERROR`                     | [`godot_error!`]      |
+    /// | `WARN`                      | [`godot_warn!`]       |
+    /// | `INFO`, `DEBUG`, `TRACE`    | [`godot_print!`]      |
+    ///
+    /// The `message` field is printed first, followed by other fields as `key=value`. Spans are not printed.
+    ///
+    /// Events emitted on threads other than the main thread are written to stderr instead, unless the `experimental-threads`
+    /// feature is enabled.
+    ///
+    /// # Example
+    /// Return the layer from [`ExtensionLibrary::tracing_layer()`][crate::init::ExtensionLibrary::tracing_layer], and godot-rust installs
+    /// it as the global default subscriber when the `Scene` level is initialized (requires a dependency on `tracing`):
+    /// ```ignore
+    /// use godot::prelude::*;
+    /// use godot::tools::GodotTracingLayer;
+    ///
+    /// struct MyExtension;
+    ///
+    /// #[gdextension]
+    /// unsafe impl ExtensionLibrary for MyExtension {
+    ///     fn tracing_layer() -> Option<GodotTracingLayer> {
+    ///         Some(GodotTracingLayer::new().target_level("noisy_crate", tracing::Level::WARN))
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// To combine it with other layers, add it to your own subscriber instead (requires a dependency on `tracing-subscriber`):
+    /// ```ignore
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// tracing_subscriber::registry()
+    ///     .with(GodotTracingLayer::new())
+    ///     .with(my_other_layer)
+    ///     .init();
+    /// ```
+    ///
+    /// [`godot_error!`]: crate::global::godot_error
+    /// [`godot_warn!`]: crate::global::godot_warn
+    /// [`godot_print!`]: crate::global::godot_print
+    #[cfg_attr(published_docs, doc(cfg(feature = "tracing")))]
+    pub struct GodotTracingLayer {
+        max_level: LevelFilter,
+        target_levels: Vec<(String, LevelFilter)>,
+        main_thread: ThreadId,
+    }
+
+    impl GodotTracingLayer {
+        /// Creates a layer with maximum level `INFO` and no target-specific overrides.
+        ///
+        /// Must be called on the main thread.
+        pub fn new() -> Self {
+            Self {
+                max_level: LevelFilter::INFO,
+                target_levels: Vec::new(),
+                main_thread: std::thread::current().id(),
+            }
+        }
+
+        /// Sets the maximum level for all targets without a specific override.
+        pub fn max_level(mut self, level: impl Into<LevelFilter>) -> Self {
+            self.max_level = level.into();
+            self
+        }
+
+        /// Sets the maximum level for `target` and its sub-modules (e.g. `"my_crate"` also affects `"my_crate::net"`).
+        ///
+        /// If multiple overrides match, the longest target wins.
+        pub fn target_level(
+            mut self,
+            target: impl Into<String>,
+            level: impl Into<LevelFilter>,
+        ) -> Self {
+            self.target_levels.push((target.into(), level.into()));
+            self
+        }
+
+        /// Installs a subscriber consisting of only this layer as the global default of the `tracing` crate.
+        ///
+        /// Fails if another global default subscriber has already been installed.
+        pub fn install(self) -> Result<(), SetGlobalDefaultError> {
+            let subscriber = tracing_subscriber::registry().with(self);
+            tracing_core::dispatcher::set_global_default(Dispatch::new(subscriber))
+        }
+    }
+
+    impl Default for GodotTracingLayer {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for GodotTracingLayer {
+        fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+            let max = level_for_target(&self.target_levels, self.max_level, metadata.target());
+            *metadata.level() <= max
+        }
+
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+
+            let severity = match *metadata.level() {
+                Level::ERROR => GodotSeverity::Error,
+                Level::WARN => GodotSeverity::Warning,
+                _ => GodotSeverity::Print,
+            };
+
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+
+            forward_to_godot(
+                severity,
+                self.main_thread,
+                metadata.level().as_str(),
+                metadata.target(),
+                &visitor.finish(),
+                metadata.file(),
+                metadata.line(),
+            );
+        }
+    }
+
+    /// Collects the `message` field and all other fields of an event.
+    #[derive(Default)]
+    struct MessageVisitor {
+        message: String,
+        fields: String,
+    }
+
+    impl MessageVisitor {
+        fn finish(self) -> String {
+            match (self.message.is_empty(), self.fields.is_empty()) {
+                (_, true) => self.message,
+                (true, false) => self.fields,
+                (false, false) => format!("{} {}", self.message, self.fields),
+            }
+        }
+    }
+
+    impl Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                let _ = write!(self.message, "{value:?}");
+            } else {
+                if !self.fields.is_empty() {
+                    self.fields.push(' ');
+                }
+                let _ = write!(self.fields, "{}={value:?}", field.name());
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides() -> Vec<(String, u8)> {
+        vec![
+            ("net".to_string(), 1),
+            ("net::http".to_string(), 2),
+            ("network".to_string(), 3),
+        ]
+    }
+
+    #[test]
+    fn level_for_target_default() {
+        let overrides = overrides();
+
+        assert_eq!(level_for_target(&overrides, 0, "render"), 0);
+        assert_eq!(level_for_target(&[], 0, "net"), 0);
+    }
+
+    #[test]
+    fn level_for_target_module_boundary() {
+        let overrides = overrides();
+
+        assert_eq!(level_for_target(&overrides, 0, "net"), 1);
+        assert_eq!(level_for_target(&overrides, 0, "net::tcp"), 1);
+        assert_eq!(level_for_target(&overrides, 0, "network"), 3);
+        assert_eq!(level_for_target(&overrides, 0, "networking"), 0);
+        assert_eq!(level_for_target(&overrides, 0, "net:"), 0);
+    }
+
+    #[test]
+    fn level_for_target_longest_prefix() {
+        let overrides = overrides();
+
+        assert_eq!(level_for_target(&overrides, 0, "net::http"), 2);
+        assert_eq!(level_for_target(&overrides, 0, "net::http::client"), 2);
+        assert_eq!(level_for_target(&overrides, 0, "net::https"), 1);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn logger_enabled_per_target() {
+        use log::{Level, LevelFilter, Log, Metadata};
+
+        let logger = GodotLogger::new()
+            .max_level(LevelFilter::Warn)
+            .target_level("game::ai", LevelFilter::Debug)
+            .target_level("game::ai::path", LevelFilter::Off);
+
+        let enabled = |level: Level, target: &str| {
+            let metadata = Metadata::builder().level(level).target(target).build();
+            logger.enabled(&metadata)
+        };
+
+        assert!(enabled(Level::Error, "game"));
+        assert!(!enabled(Level::Info, "game"));
+        assert!(enabled(Level::Debug, "game::ai"));
+        assert!(!enabled(Level::Trace, "game::ai"));
+        assert!(!enabled(Level::Error, "game::ai::path"));
+    }
+}
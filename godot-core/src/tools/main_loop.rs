@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::GString;
+use crate::classes::{Engine, MainLoop, ProjectSettings};
+use crate::meta::ToGodot;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+const MAIN_LOOP_TYPE_SETTING: &str = "application/run/main_loop_type";
+
+/// Makes Godot run `T` as its main loop, instead of `SceneTree`.
+///
+/// Godot reads the project setting `application/run/main_loop_type` once, when creating the main loop after all extensions have been
+/// initialized. Calling this function from [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] at
+/// [`InitLevel::Scene`][crate::init::InitLevel::Scene] thus changes the main loop of the current run, without modifying `project.godot`.
+/// Calling it later only affects the setting value, not the running main loop.
+///
+/// This has no effect in the editor, which always runs on `SceneTree`. To start the game with the custom main loop, run the project
+/// directly, e.g. `godot --path <project_dir>`. Add `--headless` for tools and simulations without a window.
+///
+/// Your class receives the `MainLoop` virtual functions: `initialize()` once at startup, then `physics_process()` and `process()` each
+/// frame -- returning `true` from either of them quits the application -- and `finalize()` at shutdown. There is no scene tree, so
+/// nodes are not processed.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{IMainLoop, MainLoop};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=MainLoop)]
+/// struct Simulation {
+///     steps: u32,
+///     base: Base<MainLoop>,
+/// }
+///
+/// #[godot_api]
+/// impl IMainLoop for Simulation {
+///     fn initialize(&mut self) {
+///         godot_print!("Simulation started.");
+///     }
+///
+///     fn process(&mut self, _delta: f64) -> bool {
+///         self.steps += 1;
+///         self.steps >= 1000 // Quit after 1000 frames.
+///     }
+///
+///     fn finalize(&mut self) {
+///         godot_print!("Simulation finished after {} steps.", self.steps);
+///     }
+/// }
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             godot::tools::set_main_loop_type::<Simulation>();
+///         }
+///     }
+/// }
+/// ```
+pub fn set_main_loop_type<T>()
+where
+    T: GodotClass + Inherits<MainLoop>,
+{
+    if Engine::singleton().is_editor_hint() {
+        return;
+    }
+
+    let class_name = T::class_name().to_gstring();
+    ProjectSettings::singleton().set_setting(MAIN_LOOP_TYPE_SETTING, &class_name.to_variant());
+}
+
+/// Returns the class name in the project setting `application/run/main_loop_type` (`"SceneTree"` by default).
+pub fn main_loop_type() -> GString {
+    ProjectSettings::singleton()
+        .get_setting(MAIN_LOOP_TYPE_SETTING)
+        .try_to()
+        .unwrap_or_default()
+}
+
+/// Returns the running main loop, if it is of type `T`.
+///
+/// Returns `None` if there is no main loop yet (e.g. during extension initialization), or if it has a different type.
+///
+/// _Godot equivalent: `Engine.get_main_loop() as T`_
+pub fn main_loop<T>() -> Option<Gd<T>>
+where
+    T: GodotClass + Inherits<MainLoop>,
+{
+    Engine::singleton().get_main_loop()?.try_cast::<T>().ok()
+}
@@ -10,10 +10,138 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+#[cfg(all(feature = "codegen-full", since_api = "4.4"))]
+mod animation_node;
+mod autoload;
+mod cmdline;
+#[cfg(feature = "codegen-full")]
+pub mod crypto;
+mod defer;
+#[cfg(feature = "codegen-full")]
+mod drag_data;
+#[cfg(feature = "ecs-bridge")]
+pub mod ecs;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod editor_fs;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod editor_play;
+#[cfg(since_api = "4.2")]
+mod export_plugin;
+mod frame_info;
+#[cfg(feature = "codegen-full")]
+pub mod geometry;
 mod gfile;
+mod image;
+#[cfg(feature = "codegen-full")]
+mod input_event;
+#[cfg(feature = "experimental-threads")]
+mod load_async;
+#[cfg(since_api = "4.2")]
+mod local_task;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod logging;
+mod main_loop;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub mod nav;
+mod node_group;
+mod property_defaults;
+mod res_path;
+#[cfg(feature = "codegen-full")]
+mod resource_uid;
 mod save_load;
+mod scene_snapshot;
+#[cfg(feature = "codegen-full")]
+mod shader_uniforms;
+mod sidecar;
+#[cfg(since_api = "4.2")]
+mod signal_adapter;
+#[cfg(since_api = "4.2")]
+mod signal_future;
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+mod tile_map;
 mod translate;
+#[cfg(feature = "codegen-full")]
+mod tween;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod undo_redo;
+#[cfg(feature = "variant-parser")]
+pub mod variant_parser;
+mod variant_text;
+#[cfg(feature = "codegen-full")]
+mod window;
+#[cfg(all(
+    feature = "experimental-threads",
+    feature = "codegen-full",
+    since_api = "4.2"
+))]
+mod worker_pool;
 
+#[cfg(since_api = "4.2")]
+pub use crate::impl_export_plugin;
+#[cfg(feature = "ffi-stats")]
+pub use crate::sys::ffi_stats;
+#[cfg(feature = "mem-stats")]
+pub use crate::sys::mem_stats;
+pub use crate::{autoloads, res};
+#[cfg(all(feature = "codegen-full", since_api = "4.4"))]
+pub use animation_node::*;
+pub use autoload::*;
+pub use cmdline::*;
+pub use defer::*;
+#[cfg(feature = "codegen-full")]
+pub use drag_data::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use editor_fs::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub(crate) use editor_play::unregister_play_listeners;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use editor_play::{EditorState, PlayEvent, PlayListener};
+#[cfg(since_api = "4.2")]
+pub use export_plugin::*;
+pub use frame_info::*;
 pub use gfile::*;
+pub(crate) use image::validate_image_data;
+pub use image::ImageMipmaps;
+#[cfg(feature = "codegen-full")]
+pub use input_event::*;
+#[cfg(feature = "experimental-threads")]
+pub use load_async::*;
+#[cfg(since_api = "4.2")]
+pub(crate) use local_task::cleanup as cleanup_local_tasks;
+#[cfg(since_api = "4.2")]
+pub use local_task::{spawn_awaitable, spawn_local};
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub use logging::*;
+pub use main_loop::*;
+pub use node_group::*;
+pub use property_defaults::*;
+pub use res_path::*;
+#[cfg(feature = "codegen-full")]
+pub use resource_uid::*;
 pub use save_load::*;
+pub use scene_snapshot::*;
+#[cfg(feature = "codegen-full")]
+pub use shader_uniforms::*;
+pub use sidecar::*;
+#[cfg(since_api = "4.2")]
+pub(crate) use signal_adapter::connect_mapped;
+#[cfg(since_api = "4.2")]
+pub use signal_adapter::{BoundSignal, SignalReceiver, ToSignalArgs};
+#[cfg(since_api = "4.2")]
+pub use signal_future::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+pub use tile_map::*;
 pub use translate::*;
+#[cfg(feature = "codegen-full")]
+pub use tween::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use undo_redo::*;
+pub use variant_text::*;
+#[cfg(feature = "codegen-full")]
+pub use window::*;
+#[cfg(all(
+    feature = "experimental-threads",
+    feature = "codegen-full",
+    since_api = "4.2"
+))]
+pub use worker_pool::*;
@@ -0,0 +1,307 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed, RAII-based access to [`NavigationServer2D`] and [`NavigationServer3D`].
+//!
+//! The navigation servers identify maps, regions and agents through [`Rid`]s, return paths as packed arrays, and report avoidance results
+//! through `Callable`s taking `Variant` arguments. This module wraps the most important flows for Rust-side AI systems:
+//!
+//! - [`NavMap2D`]/[`NavMap3D`]: navigation maps, with path queries returning `Vec`s and closure-based change notifications.
+//! - [`NavRegion2D`]/[`NavRegion3D`]: regions providing the navigable geometry of a map.
+//! - [`NavAgent2D`]/[`NavAgent3D`]: avoidance agents, with closures receiving the typed safe velocity.
+//!
+//! All handles free their server resource when dropped. The server-side objects can still be accessed through [`rid()`][NavMap3D::rid],
+//! e.g. to call APIs not covered here.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::NavigationMesh;
+//! use godot::tools::nav::{NavAgent3D, NavMap3D, NavRegion3D};
+//!
+//! let mut map = NavMap3D::new();
+//! map.on_changed(|| godot_print!("navigation map was updated"));
+//!
+//! let mut region = NavRegion3D::new(&map);
+//! region.set_navigation_mesh(&NavigationMesh::new_gd());
+//!
+//! let mut agent = NavAgent3D::new(&map);
+//! agent.set_radius(0.5);
+//! agent.set_avoidance_enabled(true);
+//! agent.set_avoidance_callback(|safe_velocity: Vector3| {
+//!     godot_print!("move with {safe_velocity}");
+//! });
+//!
+//! // After the map has been synchronized (next physics frame):
+//! let path: Vec<Vector3> = map.find_path(Vector3::ZERO, Vector3::new(10.0, 0.0, 5.0), true);
+//! ```
+//!
+//! # Threading
+//! Callbacks are invoked by Godot on the main thread, during physics processing. Handles must thus be created on the main thread, and
+//! closures need not be `Send`.
+
+use crate::builtin::{Callable, Rid, Transform2D, Transform3D, Variant, Vector2, Vector3};
+use crate::classes::{NavigationMesh, NavigationPolygon, NavigationServer2D, NavigationServer3D};
+use crate::obj::Gd;
+
+macro_rules! impl_nav_types {
+    (
+        Map: $Map:ident,
+        Region: $Region:ident,
+        Agent: $Agent:ident,
+        Server: $Server:ident,
+        Vector: $Vector:ident,
+        Transform: $Transform:ident,
+        Mesh: $Mesh:ident,
+        set_mesh: $set_mesh:ident => $region_set_mesh:ident,
+    ) => {
+        #[doc = concat!("Navigation map of the [`", stringify!($Server), "`], freed on drop.")]
+        ///
+        /// The map is active from the start. Like any navigation map, it is synchronized with its regions and agents once per physics
+        /// frame; queries before the first synchronization return empty results.
+        pub struct $Map {
+            rid: Rid,
+            change_callbacks: Vec<Callable>,
+        }
+
+        impl $Map {
+            /// Creates a new, active navigation map.
+            pub fn new() -> Self {
+                let mut server = $Server::singleton();
+                let rid = server.map_create();
+                server.map_set_active(rid, true);
+
+                Self {
+                    rid,
+                    change_callbacks: Vec::new(),
+                }
+            }
+
+            /// The server-side ID of this map.
+            pub fn rid(&self) -> Rid {
+                self.rid
+            }
+
+            /// Activates or deactivates the map. Inactive maps are not synchronized, and do not process avoidance.
+            pub fn set_active(&mut self, active: bool) {
+                $Server::singleton().map_set_active(self.rid, active);
+            }
+
+            /// Sets the cell size used to rasterize navigation mesh vertices. Must match the cell size of the regions' meshes.
+            pub fn set_cell_size(&mut self, cell_size: f32) {
+                $Server::singleton().map_set_cell_size(self.rid, cell_size);
+            }
+
+            /// Computes a path from `from` to `to`, on all navigation layers.
+            ///
+            /// If `optimize` is true, the path is smoothed by merging polygon edges; otherwise it follows polygon centers.
+            /// Returns an empty `Vec` if no path exists, or if the map has not been synchronized yet.
+            pub fn find_path(&self, from: $Vector, to: $Vector, optimize: bool) -> Vec<$Vector> {
+                self.find_path_on_layers(from, to, optimize, u32::MAX)
+            }
+
+            /// Like [`find_path()`][Self::find_path], but only considers regions whose navigation layers intersect `navigation_layers`.
+            pub fn find_path_on_layers(
+                &self,
+                from: $Vector,
+                to: $Vector,
+                optimize: bool,
+                navigation_layers: u32,
+            ) -> Vec<$Vector> {
+                $Server::singleton()
+                    .map_get_path_ex(self.rid, from, to, optimize)
+                    .navigation_layers(navigation_layers)
+                    .done()
+                    .to_vec()
+            }
+
+            /// Returns the point on the navigation mesh that is closest to `to_point`.
+            pub fn closest_point(&self, to_point: $Vector) -> $Vector {
+                $Server::singleton().map_get_closest_point(self.rid, to_point)
+            }
+
+            /// Calls `f` whenever the server reports a change of this map, e.g. after regions were added or modified.
+            ///
+            /// The callback stays connected until the map is dropped.
+            pub fn on_changed(&mut self, mut f: impl FnMut() + 'static) {
+                let rid = self.rid;
+                let callable = Callable::from_local_fn("NavMap::on_changed", move |args| {
+                    if args.first().and_then(|arg| arg.try_to::<Rid>().ok()) == Some(rid) {
+                        f();
+                    }
+                    Ok(Variant::nil())
+                });
+
+                $Server::singleton().connect("map_changed", &callable);
+                self.change_callbacks.push(callable);
+            }
+        }
+
+        impl Default for $Map {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Drop for $Map {
+            fn drop(&mut self) {
+                let mut server = $Server::singleton();
+                for callable in self.change_callbacks.drain(..) {
+                    server.disconnect("map_changed", &callable);
+                }
+
+                server.free_rid(self.rid);
+            }
+        }
+
+        // -------------------------------------------------------------------------------------------------------------------------------------------
+
+        #[doc = concat!("Navigation region of the [`", stringify!($Server), "`], freed on drop.")]
+        pub struct $Region {
+            rid: Rid,
+        }
+
+        impl $Region {
+            /// Creates a region and adds it to `map`.
+            pub fn new(map: &$Map) -> Self {
+                let mut server = $Server::singleton();
+                let rid = server.region_create();
+                server.region_set_map(rid, map.rid);
+
+                Self { rid }
+            }
+
+            /// The server-side ID of this region.
+            pub fn rid(&self) -> Rid {
+                self.rid
+            }
+
+            #[doc = concat!("Sets the navigable geometry of this region, as a [`", stringify!($Mesh), "`].")]
+            pub fn $set_mesh(&mut self, mesh: &Gd<$Mesh>) {
+                $Server::singleton().$region_set_mesh(self.rid, mesh);
+            }
+
+            /// Sets the global transform of this region.
+            pub fn set_transform(&mut self, transform: $Transform) {
+                $Server::singleton().region_set_transform(self.rid, transform);
+            }
+
+            /// Sets the navigation layers (bitmask), which path queries can filter on.
+            pub fn set_navigation_layers(&mut self, navigation_layers: u32) {
+                $Server::singleton().region_set_navigation_layers(self.rid, navigation_layers);
+            }
+        }
+
+        impl Drop for $Region {
+            fn drop(&mut self) {
+                $Server::singleton().free_rid(self.rid);
+            }
+        }
+
+        // -------------------------------------------------------------------------------------------------------------------------------------------
+
+        #[doc = concat!("Avoidance agent of the [`", stringify!($Server), "`], freed on drop.")]
+        ///
+        /// To steer an agent, set its current [position][Self::set_position] and desired [velocity][Self::set_velocity] each physics frame.
+        /// Once the map is synchronized, the [avoidance callback][Self::set_avoidance_callback] receives the safe velocity, which avoids
+        /// collisions with other agents and obstacles.
+        pub struct $Agent {
+            rid: Rid,
+        }
+
+        impl $Agent {
+            /// Creates an agent and adds it to `map`.
+            pub fn new(map: &$Map) -> Self {
+                let mut server = $Server::singleton();
+                let rid = server.agent_create();
+                server.agent_set_map(rid, map.rid);
+
+                Self { rid }
+            }
+
+            /// The server-side ID of this agent.
+            pub fn rid(&self) -> Rid {
+                self.rid
+            }
+
+            /// Enables or disables avoidance. Without it, the avoidance callback is never invoked.
+            pub fn set_avoidance_enabled(&mut self, enabled: bool) {
+                $Server::singleton().agent_set_avoidance_enabled(self.rid, enabled);
+            }
+
+            /// Sets the current position of the agent.
+            pub fn set_position(&mut self, position: $Vector) {
+                $Server::singleton().agent_set_position(self.rid, position);
+            }
+
+            /// Sets the desired velocity of the agent, which avoidance adjusts into the safe velocity.
+            pub fn set_velocity(&mut self, velocity: $Vector) {
+                $Server::singleton().agent_set_velocity(self.rid, velocity);
+            }
+
+            /// Sets the radius of the agent, used for avoidance.
+            pub fn set_radius(&mut self, radius: f32) {
+                $Server::singleton().agent_set_radius(self.rid, radius);
+            }
+
+            /// Sets the maximum speed; the safe velocity never exceeds it.
+            pub fn set_max_speed(&mut self, max_speed: f32) {
+                $Server::singleton().agent_set_max_speed(self.rid, max_speed);
+            }
+
+            /// Calls `f` with the safe velocity, each time avoidance has been computed for this agent.
+            ///
+            /// Replaces any previous callback.
+            pub fn set_avoidance_callback(&mut self, mut f: impl FnMut($Vector) + 'static) {
+                let callable =
+                    Callable::from_local_fn("NavAgent::avoidance_callback", move |args| {
+                        let safe_velocity = args.first().ok_or(())?;
+                        let safe_velocity = safe_velocity.try_to::<$Vector>().map_err(|_| ())?;
+
+                        f(safe_velocity);
+                        Ok(Variant::nil())
+                    });
+
+                $Server::singleton().agent_set_avoidance_callback(self.rid, &callable);
+            }
+
+            /// Removes the avoidance callback, if any.
+            pub fn clear_avoidance_callback(&mut self) {
+                $Server::singleton()
+                    .agent_set_avoidance_callback(self.rid, &Callable::invalid());
+            }
+        }
+
+        impl Drop for $Agent {
+            fn drop(&mut self) {
+                $Server::singleton().free_rid(self.rid);
+            }
+        }
+    };
+}
+
+impl_nav_types!(
+    Map: NavMap2D,
+    Region: NavRegion2D,
+    Agent: NavAgent2D,
+    Server: NavigationServer2D,
+    Vector: Vector2,
+    Transform: Transform2D,
+    Mesh: NavigationPolygon,
+    set_mesh: set_navigation_polygon => region_set_navigation_polygon,
+);
+
+impl_nav_types!(
+    Map: NavMap3D,
+    Region: NavRegion3D,
+    Agent: NavAgent3D,
+    Server: NavigationServer3D,
+    Vector: Vector3,
+    Transform: Transform3D,
+    Mesh: NavigationMesh,
+    set_mesh: set_navigation_mesh => region_set_navigation_mesh,
+);
@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Marker type for a node group, replacing string literals for group names.
+///
+/// Godot identifies groups by name. Declaring each group once as a marker type avoids typos and makes usages searchable. The name is
+/// converted to a cached `StringName`, so repeated calls don't allocate.
+///
+/// Used by [`Node::add_to_group_typed()`][crate::classes::Node::add_to_group_typed] and related methods, as well as
+/// [`SceneTree::nodes_in_group_as()`][crate::classes::SceneTree::nodes_in_group_as] (via [`NAME`][Self::NAME]).
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::NodeGroup;
+///
+/// struct Enemies;
+/// impl NodeGroup for Enemies {
+///     const NAME: &'static str = "enemies";
+/// }
+///
+/// fn spawn(mut enemy: Gd<Node2D>) {
+///     enemy.add_to_group_typed::<Enemies>();
+/// }
+///
+/// fn enemy_positions(tree: &mut Gd<SceneTree>) -> Vec<Vector2> {
+///     tree.nodes_in_group_as::<Node2D>(Enemies::NAME)
+///         .map(|enemy| enemy.get_global_position())
+///         .collect()
+/// }
+/// ```
+pub trait NodeGroup {
+    /// Name of the group, as used in Godot (e.g. in the editor's _Groups_ dock or in GDScript's `is_in_group()`).
+    const NAME: &'static str;
+}
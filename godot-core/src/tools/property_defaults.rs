@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::builtin::{Dictionary, GString, StringName, Variant};
+use crate::classes::{ClassDb, Object, RefCounted};
+use crate::global::PropertyUsageFlags;
+use crate::meta::{arg_into_owned, arg_into_ref, AsArg};
+use crate::obj::{EngineBitfield, Gd, Inherits};
+
+/// Default values of a class's stored properties, to find out which properties of an object were changed.
+///
+/// Useful for prefab/override systems and compact serialization: only properties differing from the class defaults need to be saved.
+/// Stored properties are those with [`PropertyUsageFlags::STORAGE`], i.e. the ones Godot writes to `.tscn`/`.tres` files.
+///
+/// Defaults are determined by instantiating the class once, like the editor does. Create one `PropertyDefaults` per class and reuse it;
+/// for engine classes, [`class_property_default()`] is an alternative for single properties (Godot 4.3+).
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::PropertyDefaults;
+///
+/// fn overrides(node: &Gd<Node>) -> Dictionary {
+///     let defaults = PropertyDefaults::of_object(node).expect("class cannot be instantiated");
+///     defaults.changed_properties(node)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct PropertyDefaults {
+    class_name: StringName,
+    defaults: HashMap<GString, Variant>,
+}
+
+impl PropertyDefaults {
+    /// Determines the defaults of class `class_name`, which may be an engine or user-defined class.
+    ///
+    /// Returns `None` if the class doesn't exist or cannot be instantiated (e.g. abstract classes, singletons).
+    ///
+    /// Instantiation runs the class's constructor (`init()` for user classes), so it should be free of side effects.
+    pub fn of_class(class_name: impl AsArg<StringName>) -> Option<Self> {
+        arg_into_owned!(class_name);
+
+        let mut class_db = ClassDb::singleton();
+        if !class_db.can_instantiate(&class_name) {
+            return None;
+        }
+
+        let instance = class_db
+            .instantiate(&class_name)
+            .try_to::<Gd<Object>>()
+            .ok()?;
+        let defaults = stored_properties(&instance)
+            .into_iter()
+            .map(|name| {
+                let value = instance.get(&StringName::from(&name));
+                (name, value)
+            })
+            .collect();
+
+        // Reference-counted instances are freed when the last reference goes out of scope.
+        if let Err(instance) = instance.try_cast::<RefCounted>() {
+            instance.free();
+        }
+
+        Some(Self {
+            class_name,
+            defaults,
+        })
+    }
+
+    /// Determines the defaults of the runtime class of `object`; see [`of_class()`][Self::of_class].
+    pub fn of_object<T: Inherits<Object>>(object: &Gd<T>) -> Option<Self> {
+        let class_name = object.upcast_ref::<Object>().get_class();
+        Self::of_class(&StringName::from(&class_name))
+    }
+
+    /// The class whose defaults are stored.
+    pub fn class_name(&self) -> &StringName {
+        &self.class_name
+    }
+
+    /// Default value of `property`, or `None` if it's not a stored property of the class.
+    pub fn get(&self, property: impl AsArg<GString>) -> Option<&Variant> {
+        arg_into_ref!(property);
+        self.defaults.get(property)
+    }
+
+    /// Stored properties of `object` whose values differ from the defaults, as a dictionary of names (strings) to current values.
+    ///
+    /// Stored properties without a known default -- such as variables of an attached script, or properties of a derived class -- are
+    /// always included, as they cannot be reconstructed from the class alone.
+    pub fn changed_properties<T: Inherits<Object>>(&self, object: &Gd<T>) -> Dictionary {
+        let object = object.upcast_ref::<Object>();
+
+        let mut changed = Dictionary::new();
+        for name in stored_properties(object) {
+            let value = object.get(&StringName::from(&name));
+            if self.defaults.get(&name) != Some(&value) {
+                changed.set(name, value);
+            }
+        }
+
+        changed
+    }
+
+    /// Whether `object` has any stored property differing from the defaults.
+    pub fn is_modified<T: Inherits<Object>>(&self, object: &Gd<T>) -> bool {
+        !self.changed_properties(object).is_empty()
+    }
+}
+
+/// Default value of `property` in class `class_name`, as reported by Godot.
+///
+/// Returns `None` if the class has no such property. Godot computes this from a cached default instance of the class.
+///
+/// _Godot equivalent: `ClassDB.class_get_property_default_value()`_
+#[cfg(since_api = "4.3")]
+pub fn class_property_default(
+    class_name: impl AsArg<StringName>,
+    property: impl AsArg<StringName>,
+) -> Option<Variant> {
+    arg_into_ref!(class_name);
+    arg_into_ref!(property);
+
+    let class_db = ClassDb::singleton();
+    let exists = class_db
+        .class_get_property_list(class_name)
+        .iter_shared()
+        .any(|info| info.get_or_nil("name").stringify() == GString::from(property));
+
+    exists.then(|| class_db.class_get_property_default_value(class_name, property))
+}
+
+fn stored_properties(object: &Object) -> Vec<GString> {
+    let storage = PropertyUsageFlags::STORAGE.ord() as i64;
+
+    object
+        .get_property_list()
+        .iter_shared()
+        .filter(|property| {
+            let usage = property.get_or_nil("usage").try_to::<i64>().unwrap_or(0);
+            usage & storage != 0
+        })
+        .map(|property| property.get_or_nil("name").stringify())
+        .collect()
+}
@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::meta::{AsArg, CowArg};
+
+/// Path of a resource inside the project, such as `res://scenes/player.tscn`, checked at compile time.
+///
+/// Create instances with the [`res!`][crate::tools::res] macro. It rejects paths that don't start with `res://` or don't have a file
+/// extension, catching typos such as `res:/player.tscn` or `res://player` before they turn into failed loads at runtime.
+///
+/// `ResPath` can be passed wherever a resource path is expected as `impl AsArg<GString>`, for example to [`load()`][crate::tools::load]
+/// and [`try_load()`][crate::tools::try_load]. Paths only known at runtime can still be passed as strings.
+///
+/// This type only covers `res://` paths. For UIDs (`uid://...`), see [`Uid`][crate::tools::Uid].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::{res, ResPath};
+///
+/// const PLAYER_SCENE: ResPath = res!("res://scenes/player.tscn");
+///
+/// fn spawn_player(parent: &mut Gd<Node>) {
+///     let player = load::<PackedScene>(PLAYER_SCENE).instantiate_as::<Node2D>();
+///     parent.add_child(&player);
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ResPath {
+    path: &'static str,
+}
+
+impl ResPath {
+    const SCHEME: &'static str = "res://";
+
+    /// Validates `path`, panicking if it is not a `res://` path with a file extension.
+    ///
+    /// When evaluated in a const context (as in [`res!`][crate::tools::res]), the panic is a compile-time error.
+    pub const fn new(path: &'static str) -> Self {
+        if let Err(message) = Self::validate(path) {
+            panic!("{}", message);
+        }
+
+        Self { path }
+    }
+
+    /// Returns the path as a string slice, including the `res://` scheme.
+    pub const fn as_str(&self) -> &'static str {
+        self.path
+    }
+
+    /// Returns the file extension without leading dot, e.g. `"tscn"`.
+    pub fn extension(&self) -> &'static str {
+        let dot = self.path.rfind('.').expect("validated on construction");
+        &self.path[dot + 1..]
+    }
+
+    /// Converts to a Godot string.
+    pub fn to_gstring(&self) -> GString {
+        GString::from(self.path)
+    }
+
+    // Const-compatible subset of string operations: no iterators, no str methods except len() and as_bytes().
+    const fn validate(path: &str) -> Result<(), &'static str> {
+        let bytes = path.as_bytes();
+        let scheme = Self::SCHEME.as_bytes();
+
+        if bytes.len() <= scheme.len() {
+            return Err("resource path must start with `res://` and name a file");
+        }
+
+        let mut i = 0;
+        while i < scheme.len() {
+            if bytes[i] != scheme[i] {
+                return Err("resource path must start with `res://`");
+            }
+            i += 1;
+        }
+
+        // Find start of extension in the last path segment; reject separators that Godot doesn't accept.
+        let mut dot = None;
+        let mut i = scheme.len();
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => return Err("resource path must use `/` as separator, not `\\`"),
+                b'/' => {
+                    if bytes[i - 1] == b'/' {
+                        return Err("resource path must not contain empty segments (`//`)");
+                    }
+                    dot = None;
+                }
+                b'.' => dot = Some(i),
+                _ => {}
+            }
+            i += 1;
+        }
+
+        let Some(dot) = dot else {
+            return Err(
+                "resource path must end in a file name with extension, e.g. `res://scene.tscn`",
+            );
+        };
+
+        let mut i = dot + 1;
+        if i == bytes.len() {
+            return Err("resource path must not end with `.`");
+        }
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_alphanumeric() && bytes[i] != b'_' {
+                return Err("file extension of resource path must be alphanumeric");
+            }
+            i += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ResPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.path)
+    }
+}
+
+impl AsArg<GString> for ResPath {
+    fn into_arg<'r>(self) -> CowArg<'r, GString> {
+        CowArg::Owned(self.to_gstring())
+    }
+}
+
+impl AsArg<GString> for &ResPath {
+    fn into_arg<'r>(self) -> CowArg<'r, GString> {
+        CowArg::Owned(self.to_gstring())
+    }
+}
+
+/// Creates a [`ResPath`][crate::tools::ResPath], validating the path at compile time.
+///
+/// The path must be a string literal starting with `res://` and ending in a file name with extension. Other paths fail to compile.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::res;
+///
+/// let scene = load::<PackedScene>(res!("res://scenes/player.tscn"));
+/// ```
+///
+/// ```compile_fail
+/// use godot::tools::res;
+///
+/// let path = res!("res://scenes/player"); // missing extension
+/// ```
+#[macro_export]
+macro_rules! res {
+    ($path:literal) => {
+        const { $crate::tools::ResPath::new($path) }
+    };
+}
@@ -0,0 +1,187 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::GString;
+use crate::classes::ResourceUid;
+use crate::meta::{arg_into_ref, AsArg};
+
+/// Unique ID of a resource, independent of its path.
+///
+/// Godot assigns UIDs to resources, so that references survive moving or renaming files. In text form, UIDs look like
+/// `uid://cecaux1sm7mo0` and can be used wherever a resource path is expected, e.g. in [`load()`][crate::tools::load].
+///
+/// This type wraps the integer representation and the [`ResourceUid`] singleton, which maps UIDs to paths. It only holds valid IDs;
+/// Godot's `ResourceUID.INVALID_ID` is represented as `None`.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::Uid;
+///
+/// let uid = Uid::parse("uid://cecaux1sm7mo0").expect("valid UID syntax");
+/// if let Some(path) = uid.path() {
+///     godot::global::godot_print!("{uid} -> {path}");
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Uid {
+    id: i64,
+}
+
+impl Uid {
+    /// Creates a `Uid` from its integer representation, or `None` if `id` is negative (invalid).
+    pub fn from_i64(id: i64) -> Option<Self> {
+        (id >= 0).then_some(Self { id })
+    }
+
+    /// Parses the text form `uid://...`, returning `None` if the syntax is invalid.
+    ///
+    /// This does not check whether the UID is registered; see [`path()`][Self::path] for that.
+    ///
+    /// _Godot equivalent: `ResourceUID.text_to_id()`_
+    pub fn parse(text: impl AsArg<GString>) -> Option<Self> {
+        arg_into_ref!(text);
+
+        // Returns INVALID_ID for malformed input, without printing an error.
+        Self::from_i64(ResourceUid::singleton().text_to_id(text))
+    }
+
+    /// Generates a new random UID.
+    ///
+    /// The UID is not associated with any path yet; use [`set_path()`][Self::set_path] or [`ensure_uid_for()`] for that.
+    ///
+    /// _Godot equivalent: `ResourceUID.create_id()`_
+    pub fn create() -> Self {
+        let id = ResourceUid::singleton().create_id();
+        Self::from_i64(id).expect("ResourceUID.create_id() returned invalid ID")
+    }
+
+    /// Integer representation, as used by Godot APIs taking `int` UIDs.
+    pub fn to_i64(self) -> i64 {
+        self.id
+    }
+
+    /// Text form `uid://...`.
+    ///
+    /// _Godot equivalent: `ResourceUID.id_to_text()`_
+    pub fn to_gstring(self) -> GString {
+        ResourceUid::singleton().id_to_text(self.id)
+    }
+
+    /// Returns the resource path this UID is registered for, or `None` if it is unknown.
+    ///
+    /// _Godot equivalent: `ResourceUID.get_id_path()`_
+    pub fn path(self) -> Option<GString> {
+        let uid_singleton = ResourceUid::singleton();
+
+        uid_singleton
+            .has_id(self.id)
+            .then(|| uid_singleton.get_id_path(self.id))
+    }
+
+    /// Associates this UID with `path` in the running engine, replacing a previous association.
+    ///
+    /// This only updates the in-memory mapping; resource files are unchanged. To persist a UID, see [`ensure_uid_for()`].
+    ///
+    /// _Godot equivalent: `ResourceUID.add_id()` or `ResourceUID.set_id()`_
+    pub fn set_path(self, path: impl AsArg<GString>) {
+        arg_into_ref!(path);
+        let mut uid_singleton = ResourceUid::singleton();
+
+        if uid_singleton.has_id(self.id) {
+            uid_singleton.set_id(self.id, path);
+        } else {
+            uid_singleton.add_id(self.id, path);
+        }
+    }
+
+    /// Removes the association of this UID with a path, if any. Returns whether there was one.
+    ///
+    /// _Godot equivalent: `ResourceUID.remove_id()`_
+    pub fn remove_path(self) -> bool {
+        let mut uid_singleton = ResourceUid::singleton();
+        if !uid_singleton.has_id(self.id) {
+            return false;
+        }
+
+        uid_singleton.remove_id(self.id);
+        true
+    }
+}
+
+impl fmt::Display for Uid {
+    /// Formats the UID as `uid://...`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_gstring())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Resolves a `uid://...` path to the `res://` path of the resource, leaving other paths unchanged.
+///
+/// Returns `None` if `path` is a UID that is malformed or not registered.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::resolve_uid_path;
+///
+/// let path = resolve_uid_path("uid://cecaux1sm7mo0");
+/// ```
+pub fn resolve_uid_path(path: impl AsArg<GString>) -> Option<GString> {
+    arg_into_ref!(path);
+
+    if !path.to_string().starts_with("uid://") {
+        return Some(path.clone());
+    }
+
+    Uid::parse(path)?.path()
+}
+
+/// Returns the UID of the resource at `path`, or `None` if it has none.
+///
+/// _Godot equivalent: `ResourceLoader.get_resource_uid()`_
+#[cfg(since_api = "4.3")]
+pub fn uid_for(path: impl AsArg<GString>) -> Option<Uid> {
+    arg_into_ref!(path);
+
+    let id = crate::classes::ResourceLoader::singleton().get_resource_uid(path);
+    Uid::from_i64(id)
+}
+
+/// Returns the UID of the resource at `path`, assigning and saving a new one if it has none.
+///
+/// Useful for resources generated by tools, which should be referable by UID like resources created in the editor. A newly assigned UID
+/// is stored alongside the resource (inside the file or in a `.uid` file, depending on the format) and registered with the running engine.
+///
+/// # Errors
+/// If the resource's format doesn't support UIDs, or the file can't be written.
+#[cfg(since_api = "4.3")]
+pub fn ensure_uid_for(path: impl AsArg<GString>) -> Result<Uid, crate::meta::error::IoError> {
+    use crate::classes::ResourceSaver;
+    use crate::global::Error as GodotError;
+    use crate::meta::error::IoError;
+
+    arg_into_ref!(path);
+
+    if let Some(uid) = uid_for(path) {
+        if uid.path().is_none() {
+            uid.set_path(path);
+        }
+        return Ok(uid);
+    }
+
+    let uid = Uid::create();
+    let result = ResourceSaver::singleton().set_uid(path, uid.to_i64());
+    if result != GodotError::OK {
+        return Err(IoError::assigning_uid(result, path.to_string()));
+    }
+
+    uid.set_path(path);
+    Ok(uid)
+}
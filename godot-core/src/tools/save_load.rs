@@ -5,12 +5,19 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::GString;
-use crate::classes::{Resource, ResourceLoader, ResourceSaver};
-use crate::global::Error as GodotError;
+use crate::builtin::{Dictionary, GString, StringName};
+use crate::classes::{ClassDb, Resource, ResourceLoader, ResourceSaver};
+use crate::global::{Error as GodotError, PropertyUsageFlags};
 use crate::meta::error::IoError;
-use crate::meta::{arg_into_ref, AsArg};
-use crate::obj::{Gd, Inherits};
+use crate::meta::{arg_into_ref, AsArg, ToGodot};
+use crate::obj::{EngineBitfield, Gd, GodotClass, Inherits};
+use crate::private::ResourceVersioning;
+
+/// Metadata key under which the version of a `#[resource_version(N)]` class is stored.
+pub(crate) const RESOURCE_VERSION_META: &str = "_resource_version";
+
+/// Metadata key under which Godot keeps stored properties that no longer exist in the class.
+const MISSING_PROPERTIES_META: &str = "_missing_resources";
 
 /// ⚠️ Loads a resource from the filesystem located at `path`, panicking on error.
 ///
@@ -51,6 +58,8 @@ where
 ///
 /// The path must be absolute (typically starting with `res://`), a local path will fail.
 ///
+/// Resources of `#[resource_version(N)]` classes are migrated to the current version, see [`MigrateResource`].
+///
 /// # Example
 /// Loads a scene called `Main` located in the `path/to` subdirectory of the Godot project and caches it in a variable.
 /// The resource is directly stored with type `PackedScene`.
@@ -73,6 +82,74 @@ where
     load_impl(path)
 }
 
+/// Migration of stored data for resource classes that evolve over time.
+///
+/// Declare the current version of the class with `#[resource_version(N)]` next to `#[derive(GodotClass)]`, and implement this trait.
+/// The version is stored in the metadata of every instance, and thus saved along with it.
+///
+/// When a resource with an older version is loaded via [`load()`] or [`try_load()`] (with the versioned class as `T`), [`migrate()`][Self::migrate]
+/// is invoked once with the stored version and all stored property values. Afterwards, the modified values are assigned back to the
+/// resource, and its version is updated.
+///
+/// # Limitations
+/// Migration is not hooked into Godot's resource loading itself. Resources obtained in any other way are **not migrated automatically**,
+/// including:
+/// - [`ResourceLoader::load()`][crate::classes::ResourceLoader::load] and `load_async()`,
+/// - GDScript `load()`/`preload()` and resources loaded by the editor,
+/// - sub-resources embedded in scenes or other resources, e.g. values of `#[export]` properties.
+///
+/// Such resources keep the stored data of their old version until they are passed to [`migrate_resource()`], or accessed once through
+/// [`load()`] or [`try_load()`] (Godot caches loaded resources, so these return the same instance).
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::MigrateResource;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Resource, init)]
+/// #[resource_version(2)]
+/// struct PlayerStats {
+///     // Version 1 stored this as `hp`.
+///     #[export]
+///     health: i32,
+/// }
+///
+/// impl MigrateResource for PlayerStats {
+///     fn migrate(from_version: u32, data: &mut Dictionary) {
+///         if from_version < 2 {
+///             if let Some(hp) = data.remove("hp") {
+///                 data.set("health", hp);
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - `data` contains the stored properties declared by the class itself, as well as properties from the file that no longer exist in the
+///   class (e.g. renamed fields). Entries that still don't correspond to a property after migration are discarded.
+/// - Resources saved before `#[resource_version]` was added carry no version and are treated as the current version. Add the attribute
+///   before changing the stored format for the first time.
+pub trait MigrateResource: GodotClass + Inherits<Resource> {
+    /// Updates `data` from the format of `from_version` to the current format.
+    ///
+    /// Implementations typically handle each version step in turn, so resources can be migrated from any older version.
+    fn migrate(from_version: u32, data: &mut Dictionary);
+}
+
+/// Migrates a resource of a `#[resource_version(N)]` class that was not loaded through [`load()`] or [`try_load()`].
+///
+/// Does nothing if the resource already has the current version. See [`MigrateResource`] for when this is needed.
+pub fn migrate_resource<T>(resource: &Gd<T>)
+where
+    T: MigrateResource,
+{
+    if let Some(versioning) = T::__RESOURCE_VERSIONING {
+        migrate_loaded(resource.clone().upcast(), versioning);
+    }
+}
+
 /// ⚠️ Saves a [`Resource`]-inheriting object into the file located at `path`.
 ///
 /// See [`try_save`] for more information.
@@ -149,9 +226,22 @@ where
         .type_hint(&T::class_name().to_gstring())
         .done();
 
+    finish_load(loaded, path)
+}
+
+/// Casts a resource returned by `ResourceLoader` to `T` and applies `#[resource_version]` migrations.
+pub(crate) fn finish_load<T>(loaded: Option<Gd<Resource>>, path: &GString) -> Result<Gd<T>, IoError>
+where
+    T: Inherits<Resource>,
+{
     match loaded {
         Some(res) => match res.try_cast::<T>() {
-            Ok(obj) => Ok(obj),
+            Ok(obj) => {
+                if let Some(versioning) = T::__RESOURCE_VERSIONING {
+                    migrate_loaded(obj.clone().upcast(), versioning);
+                }
+                Ok(obj)
+            }
             Err(_) => Err(IoError::loading_cast(
                 T::class_name().to_string(),
                 path.to_string(),
@@ -180,3 +270,51 @@ where
         ))
     }
 }
+
+fn migrate_loaded(mut res: Gd<Resource>, versioning: ResourceVersioning) {
+    let stored_version = res
+        .get_meta(RESOURCE_VERSION_META)
+        .try_to::<u32>()
+        .unwrap_or(0);
+
+    if stored_version >= versioning.version {
+        return;
+    }
+
+    // Collect the stored properties declared by the class itself.
+    let mut data = Dictionary::new();
+    let class_name = StringName::from(&res.get_class());
+    let storage = PropertyUsageFlags::STORAGE.ord() as i64;
+
+    let properties = ClassDb::singleton()
+        .class_get_property_list_ex(&class_name)
+        .no_inheritance(true)
+        .done();
+
+    for property in properties.iter_shared() {
+        let usage = property.get_or_nil("usage").try_to::<i64>().unwrap_or(0);
+        if usage & storage == 0 {
+            continue;
+        }
+
+        // Keys are strings, so users can index with `&str`.
+        let name = property.get_or_nil("name").stringify();
+        data.set(name.clone(), res.get(&StringName::from(&name)));
+    }
+
+    // Properties from the file which no longer exist in the class.
+    if res.has_meta(MISSING_PROPERTIES_META) {
+        if let Ok(missing) = res.get_meta(MISSING_PROPERTIES_META).try_to::<Dictionary>() {
+            data.extend_dictionary(&missing, false);
+        }
+        res.remove_meta(MISSING_PROPERTIES_META);
+    }
+
+    (versioning.migrate)(stored_version, &mut data);
+
+    for (key, value) in data.iter_shared() {
+        res.set(&StringName::from(&key.stringify()), &value);
+    }
+
+    res.set_meta(RESOURCE_VERSION_META, &versioning.version.to_variant());
+}
@@ -0,0 +1,224 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Dictionary, GString, NodePath, StringName, Variant};
+use crate::classes::Node;
+use crate::global::PropertyUsageFlags;
+use crate::meta::{arg_into_owned, AsArg, ToGodot};
+use crate::obj::{EngineBitfield, Gd, Inherits};
+
+type CaptureFn = dyn Fn(&Gd<Node>) -> Option<Variant>;
+type RestoreFn = dyn Fn(&Gd<Node>, &Variant) -> bool;
+
+/// Captures the state of a node subtree into a `Dictionary`, and re-applies it later.
+///
+/// `PackedScene` stores a subtree including its structure, which makes it unsuitable for save games: loading replaces nodes instead of
+/// updating them, and every node must be owned by the packed root. `SceneSnapshotter` instead records only _values_, keyed by the path
+/// of each node relative to the root. This fits the common case where the scene is loaded as usual, and the save game overrides state.
+///
+/// By default, each node's properties with [`PropertyUsageFlags::STORAGE`] are recorded, i.e. those that would be saved in a `.tscn`
+/// file (including `#[export]` fields and exported script variables). Per-class hooks can replace this with custom serialization; see
+/// [`with_hook()`][Self::with_hook].
+///
+/// # Format
+/// The snapshot maps node paths (as strings, relative to the root; the root itself is `"."`) to the captured data of that node. Without
+/// a hook, the data is a `Dictionary` of property names (as strings) to values.
+///
+/// Values are stored as-is. Objects are stored by reference, so a snapshot referring to nodes or non-saved resources cannot be
+/// persisted meaningfully. Plain values can be written to disk with e.g. `FileAccess::store_var()` or `var_to_str()`, or stored in a
+/// `#[export]` field of a custom `Resource`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::AnimationPlayer;
+/// use godot::tools::SceneSnapshotter;
+///
+/// fn snapshotter() -> SceneSnapshotter {
+///     // Animation players are saved as their current animation and position, instead of their properties.
+///     SceneSnapshotter::new().with_hook::<AnimationPlayer>(
+///         |player| {
+///             let state = dict! {
+///                 "animation": player.get_current_animation(),
+///                 "position": player.get_current_animation_position(),
+///             };
+///             state.to_variant()
+///         },
+///         |player, state| {
+///             let state: Dictionary = state.to();
+///             player.play_ex().name(&state.at("animation").to::<StringName>()).done();
+///             player.seek(state.at("position").to());
+///         },
+///     )
+/// }
+///
+/// fn save_level(level: &Gd<Node>) -> Dictionary {
+///     snapshotter().capture(level)
+/// }
+///
+/// fn load_level(level: &Gd<Node>, snapshot: &Dictionary) {
+///     for missing in snapshotter().restore(level, snapshot) {
+///         godot_warn!("node {missing} no longer exists in the level");
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct SceneSnapshotter {
+    hooks: Vec<SnapshotHook>,
+    skipped_properties: Vec<StringName>,
+}
+
+impl SceneSnapshotter {
+    /// Creates a snapshotter that records stored properties of all nodes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers custom serialization for nodes of type `T` or inherited.
+    ///
+    /// `capture` returns the data to store for a node, and `restore` applies that data to a node at the same path. Both replace the
+    /// default property handling for matching nodes; children are still visited.
+    ///
+    /// Hooks are tried in registration order, and the first one whose class matches is used. Register hooks for derived classes before
+    /// those for their base classes.
+    pub fn with_hook<T>(
+        mut self,
+        capture: impl Fn(&Gd<T>) -> Variant + 'static,
+        restore: impl Fn(&mut Gd<T>, Variant) + 'static,
+    ) -> Self
+    where
+        T: Inherits<Node>,
+    {
+        self.hooks.push(SnapshotHook {
+            capture: Box::new(move |node| {
+                let node = node.clone().try_cast::<T>().ok()?;
+                Some(capture(&node))
+            }),
+            restore: Box::new(move |node, data| match node.clone().try_cast::<T>() {
+                Ok(mut node) => {
+                    restore(&mut node, data.clone());
+                    true
+                }
+                Err(_) => false,
+            }),
+        });
+        self
+    }
+
+    /// Excludes a property from default capturing, for all classes.
+    ///
+    /// Useful for stored properties that belong to the scene's design rather than its state, e.g. `"unique_name_in_owner"`, or which
+    /// are managed by another system. The `script` property is always excluded.
+    pub fn without_property(mut self, property: impl AsArg<StringName>) -> Self {
+        arg_into_owned!(property);
+        self.skipped_properties.push(property);
+        self
+    }
+
+    /// Records the state of `root` and all its descendants.
+    ///
+    /// Internal children (e.g. those of built-in controls) are not included.
+    pub fn capture(&self, root: &Gd<Node>) -> Dictionary {
+        let mut snapshot = Dictionary::new();
+        let mut stack = vec![root.clone()];
+
+        while let Some(node) = stack.pop() {
+            let path = GString::from(&root.get_path_to(&node));
+            snapshot.set(path, self.capture_node(&node));
+
+            for i in (0..node.get_child_count()).rev() {
+                if let Some(child) = node.get_child(i) {
+                    stack.push(child);
+                }
+            }
+        }
+
+        snapshot
+    }
+
+    /// Applies a snapshot previously returned by [`capture()`][Self::capture] to the subtree at `root`.
+    ///
+    /// Nodes are looked up by their path relative to `root`. Nodes that exist in the tree but not in the snapshot are left unchanged.
+    ///
+    /// Returns the paths in the snapshot for which no node was found, in snapshot order.
+    pub fn restore(&self, root: &Gd<Node>, snapshot: &Dictionary) -> Vec<NodePath> {
+        let mut missing = vec![];
+
+        for (path, data) in snapshot.iter_shared() {
+            let path = NodePath::from(&path.stringify());
+
+            match root.get_node_or_null(&path) {
+                Some(node) => self.restore_node(&node, data),
+                None => missing.push(path),
+            }
+        }
+
+        missing
+    }
+
+    fn capture_node(&self, node: &Gd<Node>) -> Variant {
+        if let Some(data) = self.hooks.iter().find_map(|hook| (hook.capture)(node)) {
+            return data;
+        }
+
+        let storage = PropertyUsageFlags::STORAGE.ord() as i64;
+        let mut properties = Dictionary::new();
+
+        for property in node.get_property_list().iter_shared() {
+            let usage = property.get_or_nil("usage").try_to::<i64>().unwrap_or(0);
+            if usage & storage == 0 {
+                continue;
+            }
+
+            let name = StringName::from(&property.get_or_nil("name").stringify());
+            if self.is_skipped(&name) {
+                continue;
+            }
+
+            // Keys are strings, so users can index with `&str`.
+            properties.set(GString::from(&name), node.get(&name));
+        }
+
+        properties.to_variant()
+    }
+
+    fn restore_node(&self, node: &Gd<Node>, data: Variant) {
+        if self.hooks.iter().any(|hook| (hook.restore)(node, &data)) {
+            return;
+        }
+
+        // Data of nodes that had a hook during capture, but no longer has one, is ignored.
+        let Ok(properties) = data.try_to::<Dictionary>() else {
+            return;
+        };
+
+        let mut node = node.clone();
+        for (name, value) in properties.iter_shared() {
+            node.set(&StringName::from(&name.stringify()), &value);
+        }
+    }
+
+    fn is_skipped(&self, name: &StringName) -> bool {
+        name == &StringName::from("script") || self.skipped_properties.contains(name)
+    }
+}
+
+impl fmt::Debug for SceneSnapshotter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SceneSnapshotter")
+            .field("hooks", &self.hooks.len())
+            .field("skipped_properties", &self.skipped_properties)
+            .finish()
+    }
+}
+
+struct SnapshotHook {
+    capture: Box<CaptureFn>,
+    restore: Box<RestoreFn>,
+}
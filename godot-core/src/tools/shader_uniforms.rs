@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{StringName, Variant, VariantType};
+use crate::classes::{Shader, ShaderMaterial};
+use crate::meta::{static_string_name, FromGodot, ToGodot};
+use crate::obj::Gd;
+
+/// A uniform declared in a shader, as reported by `Shader.get_shader_uniform_list()`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShaderUniform {
+    /// Name of the uniform, as used in `set_shader_parameter()`.
+    pub name: StringName,
+
+    /// Type of values the uniform accepts, e.g. `VECTOR3` for `vec3`, `COLOR` for `vec4` with `source_color`, or `OBJECT` for samplers.
+    pub variant_type: VariantType,
+}
+
+/// Typed access to the uniforms of a [`ShaderMaterial`].
+///
+/// `set_shader_parameter()` silently ignores unknown names and values of the wrong type. [`set_uniform()`][Self::set_uniform] checks
+/// both against the uniforms declared in the shader in debug builds, and converts names to `StringName` only once, which makes it
+/// suitable for per-frame updates.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::ShaderMaterial;
+/// use godot::tools::ShaderMaterialExt;
+///
+/// fn pulse(material: &mut Gd<ShaderMaterial>, time: f64) {
+///     material.set_uniform("glow_strength", time.sin().abs());
+///     material.set_uniform("tint", Color::from_rgb(1.0, 0.5, 0.0));
+/// }
+/// ```
+pub trait ShaderMaterialExt {
+    /// Uniforms declared by the material's shader, in declaration order. Empty if no shader is assigned.
+    fn uniforms(&self) -> Vec<ShaderUniform>;
+
+    /// Sets the uniform `name` to `value`.
+    ///
+    /// The name must be a string literal (or otherwise `'static`); its `StringName` is created on first use and cached afterwards.
+    ///
+    /// # Panics
+    /// In debug builds, if the shader doesn't declare a uniform `name`, or declares it with a type that `value` cannot be converted to.
+    /// Integers and floats are interchangeable, as are colors and vectors of matching size. Nil values are always accepted; they reset
+    /// the uniform to its default.
+    fn set_uniform<T: ToGodot>(&mut self, name: &'static str, value: T);
+
+    /// Current value of the uniform `name`, or `None` if it is not set or cannot be converted to `T`.
+    fn uniform<T: FromGodot>(&self, name: &'static str) -> Option<T>;
+}
+
+impl ShaderMaterialExt for Gd<ShaderMaterial> {
+    fn uniforms(&self) -> Vec<ShaderUniform> {
+        self.get_shader()
+            .map(|shader| declared_uniforms(&shader))
+            .unwrap_or_default()
+    }
+
+    fn set_uniform<T: ToGodot>(&mut self, name: &'static str, value: T) {
+        let param = static_string_name(name);
+        let value = value.to_variant();
+
+        #[cfg(debug_assertions)]
+        validate_uniform(self, &param, &value);
+
+        self.set_shader_parameter(&param, &value);
+    }
+
+    fn uniform<T: FromGodot>(&self, name: &'static str) -> Option<T> {
+        self.get_shader_parameter(&static_string_name(name))
+            .try_to()
+            .ok()
+    }
+}
+
+fn declared_uniforms(shader: &Gd<Shader>) -> Vec<ShaderUniform> {
+    shader
+        .get_shader_uniform_list()
+        .iter_shared()
+        .filter_map(|info| {
+            let name = info.get("name")?.try_to::<StringName>().ok()?;
+            let ord = info.get("type")?.try_to::<i64>().ok()?;
+
+            Some(ShaderUniform {
+                name,
+                variant_type: VariantType::from_sys(ord as _),
+            })
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Debug validation
+
+#[cfg(debug_assertions)]
+fn validate_uniform(material: &Gd<ShaderMaterial>, name: &StringName, value: &Variant) {
+    let Some(shader) = material.get_shader() else {
+        panic!("set_uniform(\"{name}\"): material has no shader");
+    };
+
+    let Some(expected) = cache::uniform_type(&shader, name) else {
+        panic!(
+            "set_uniform(\"{name}\"): shader {shader:?} declares no such uniform; declared are: {:?}",
+            cache::uniform_names(&shader)
+        );
+    };
+
+    let actual = value.get_type();
+    if !is_compatible(actual, expected) {
+        panic!("set_uniform(\"{name}\"): uniform has type {expected:?}, but value {value} has type {actual:?}");
+    }
+}
+
+#[cfg(debug_assertions)]
+fn is_compatible(actual: VariantType, expected: VariantType) -> bool {
+    use VariantType as V;
+
+    matches!(
+        (actual, expected),
+        (V::NIL, _)
+            | (V::INT | V::FLOAT, V::INT | V::FLOAT)
+            | (V::COLOR, V::VECTOR3 | V::VECTOR4)
+            | (V::VECTOR3 | V::VECTOR4, V::COLOR)
+    ) || actual == expected
+}
+
+/// Declared uniforms per shader, refreshed once per frame, so that edited shader code is picked up.
+#[cfg(debug_assertions)]
+mod cache {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use super::{declared_uniforms, ShaderUniform};
+    use crate::builtin::{StringName, VariantType};
+    use crate::classes::{Engine, Shader};
+    use crate::obj::{Gd, InstanceId};
+
+    thread_local! {
+        static UNIFORMS: RefCell<HashMap<InstanceId, (u64, Vec<ShaderUniform>)>> = RefCell::default();
+    }
+
+    pub fn uniform_type(shader: &Gd<Shader>, name: &StringName) -> Option<VariantType> {
+        with_uniforms(shader, |uniforms| {
+            uniforms
+                .iter()
+                .find(|uniform| &uniform.name == name)
+                .map(|uniform| uniform.variant_type)
+        })
+    }
+
+    pub fn uniform_names(shader: &Gd<Shader>) -> Vec<String> {
+        with_uniforms(shader, |uniforms| {
+            uniforms
+                .iter()
+                .map(|uniform| uniform.name.to_string())
+                .collect()
+        })
+    }
+
+    fn with_uniforms<R>(shader: &Gd<Shader>, f: impl FnOnce(&[ShaderUniform]) -> R) -> R {
+        let frame = Engine::singleton().get_process_frames();
+
+        UNIFORMS.with_borrow_mut(|cache| {
+            // Entries of freed shaders are dropped whenever the cache is refreshed.
+            cache.retain(|_, (cached_frame, _)| *cached_frame == frame);
+
+            let (_, uniforms) = cache
+                .entry(shader.instance_id())
+                .or_insert_with(|| (frame, declared_uniforms(shader)));
+
+            f(uniforms)
+        })
+    }
+}
@@ -0,0 +1,321 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::obj::{bounds, Bounds, Gd, GodotClass, InstanceId};
+use crate::registry::instance_observers::{observe_instances, InstanceEvent, InstanceObserver};
+
+/// Per-object data stored outside of the object, keyed by [`InstanceId`].
+///
+/// Useful for auxiliary Rust data (AI state, caches, gameplay stats) attached to nodes, without adding fields to a class -- which would
+/// require `bind()`/`bind_mut()` for each access, and isn't possible for engine classes.
+///
+/// Values live in slots of a `Vec`, addressed by generational [`SidecarKey`]s. Lookup by instance ID goes through a hash map, insertion and
+/// removal are O(1), and iteration is a linear walk over the slots. For hot paths, store the key returned by [`key()`][Self::key]: access
+/// through [`get_by_key()`][Self::get_by_key] is a plain index, and a key never refers to a later entry reusing its slot.
+///
+/// # Cleanup
+/// Entries of freed objects are removed automatically for user classes registered with [`track_class()`][Self::track_class]. The removal
+/// is applied on the next mutating access (or [`purge()`][Self::purge]), as the map cannot be modified during object destruction. For
+/// other classes, [`retain_alive()`][Self::retain_alive] removes entries of all dead objects.
+///
+/// Instance IDs are not reused by Godot, so a stale entry never returns data for a different object; it only occupies memory until purged.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::SidecarMap;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node3D)]
+/// struct Enemy {}
+///
+/// struct Threat {
+///     level: f32,
+/// }
+///
+/// let mut threats = SidecarMap::<Threat>::new();
+/// threats.track_class::<Enemy>();
+///
+/// let enemy = Enemy::new_alloc();
+/// threats.insert_gd(&enemy, Threat { level: 0.5 });
+///
+/// if let Some(threat) = threats.get_gd_mut(&enemy) {
+///     threat.level += 0.1;
+/// }
+///
+/// enemy.free();
+/// threats.purge();
+/// assert!(threats.is_empty());
+/// ```
+pub struct SidecarMap<T> {
+    slots: Vec<Slot<T>>,
+    vacant: Vec<u32>,
+    index_by_id: HashMap<InstanceId, u32>,
+    freed: Arc<Mutex<Vec<InstanceId>>>,
+    observers: Vec<InstanceObserver>,
+}
+
+/// Handle to an entry of a [`SidecarMap`], for access without hashing.
+///
+/// Once the entry is removed, the key stays invalid, even if its slot is reused for another object.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SidecarKey {
+    index: u32,
+    generation: u32,
+}
+
+struct Slot<T> {
+    /// Incremented whenever the slot's entry is removed.
+    generation: u32,
+    entry: Option<(InstanceId, T)>,
+}
+
+impl<T> SidecarMap<T> {
+    /// Creates an empty map, which doesn't track any class yet.
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            vacant: Vec::new(),
+            index_by_id: HashMap::new(),
+            freed: Arc::default(),
+            observers: Vec::new(),
+        }
+    }
+
+    /// Automatically removes entries of objects of user class `C` when they are freed.
+    ///
+    /// Tracking is stopped when the map is dropped.
+    pub fn track_class<C>(&mut self)
+    where
+        C: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+    {
+        let freed = self.freed.clone();
+        let observer = observe_instances::<C, _>(move |event, instance_id| {
+            if event == InstanceEvent::Destroyed {
+                freed
+                    .lock()
+                    .expect("sidecar queue poisoned")
+                    .push(instance_id);
+            }
+        });
+
+        self.observers.push(observer);
+    }
+
+    /// Inserts `value` for `instance_id`, returning the previous value if present.
+    ///
+    /// Replacing a value keeps the entry's key valid.
+    pub fn insert(&mut self, instance_id: InstanceId, value: T) -> Option<T> {
+        self.purge();
+
+        if let Some(&index) = self.index_by_id.get(&instance_id) {
+            let (_, old_value) = self.slots[index as usize]
+                .entry
+                .as_mut()
+                .expect("indexed slot is occupied");
+            return Some(std::mem::replace(old_value, value));
+        }
+
+        let entry = Some((instance_id, value));
+        let index = match self.vacant.pop() {
+            Some(index) => {
+                self.slots[index as usize].entry = entry;
+                index
+            }
+            None => {
+                let index = u32::try_from(self.slots.len()).expect("too many sidecar entries");
+                self.slots.push(Slot {
+                    generation: 0,
+                    entry,
+                });
+                index
+            }
+        };
+
+        self.index_by_id.insert(instance_id, index);
+        None
+    }
+
+    /// Inserts `value` for the object `gd`; see [`insert()`][Self::insert].
+    pub fn insert_gd<C: GodotClass>(&mut self, gd: &Gd<C>, value: T) -> Option<T> {
+        self.insert(gd.instance_id(), value)
+    }
+
+    /// Key of the entry of `instance_id`, if any.
+    pub fn key(&self, instance_id: InstanceId) -> Option<SidecarKey> {
+        let index = *self.index_by_id.get(&instance_id)?;
+        Some(SidecarKey {
+            index,
+            generation: self.slots[index as usize].generation,
+        })
+    }
+
+    /// Value of `instance_id`, if any.
+    pub fn get(&self, instance_id: InstanceId) -> Option<&T> {
+        let index = *self.index_by_id.get(&instance_id)?;
+        self.slots[index as usize]
+            .entry
+            .as_ref()
+            .map(|(_, value)| value)
+    }
+
+    /// Mutable value of `instance_id`, if any.
+    pub fn get_mut(&mut self, instance_id: InstanceId) -> Option<&mut T> {
+        self.purge();
+
+        let index = *self.index_by_id.get(&instance_id)?;
+        self.slots[index as usize]
+            .entry
+            .as_mut()
+            .map(|(_, value)| value)
+    }
+
+    /// Value of the object `gd`, if any.
+    pub fn get_gd<C: GodotClass>(&self, gd: &Gd<C>) -> Option<&T> {
+        self.get(gd.instance_id())
+    }
+
+    /// Mutable value of the object `gd`, if any.
+    pub fn get_gd_mut<C: GodotClass>(&mut self, gd: &Gd<C>) -> Option<&mut T> {
+        self.get_mut(gd.instance_id())
+    }
+
+    /// Value of the entry `key`, or `None` if the entry has been removed.
+    pub fn get_by_key(&self, key: SidecarKey) -> Option<&T> {
+        let slot = self.slots.get(key.index as usize)?;
+        if slot.generation != key.generation {
+            return None;
+        }
+
+        slot.entry.as_ref().map(|(_, value)| value)
+    }
+
+    /// Mutable value of the entry `key`, or `None` if the entry has been removed.
+    pub fn get_by_key_mut(&mut self, key: SidecarKey) -> Option<&mut T> {
+        self.purge();
+
+        let slot = self.slots.get_mut(key.index as usize)?;
+        if slot.generation != key.generation {
+            return None;
+        }
+
+        slot.entry.as_mut().map(|(_, value)| value)
+    }
+
+    /// Whether there is a value for `instance_id`.
+    pub fn contains(&self, instance_id: InstanceId) -> bool {
+        self.index_by_id.contains_key(&instance_id)
+    }
+
+    /// Removes and returns the value of `instance_id`.
+    pub fn remove(&mut self, instance_id: InstanceId) -> Option<T> {
+        self.purge();
+        self.remove_entry(instance_id)
+    }
+
+    /// Applies pending removals of freed objects of tracked classes.
+    ///
+    /// Called implicitly by all `&mut self` methods, but can be used to release memory at a defined point, e.g. once per frame.
+    pub fn purge(&mut self) {
+        let freed = std::mem::take(&mut *self.freed.lock().expect("sidecar queue poisoned"));
+        for instance_id in freed {
+            self.remove_entry(instance_id);
+        }
+    }
+
+    /// Removes entries of all objects that are no longer alive, regardless of tracking. Returns the number of removed entries.
+    ///
+    /// Checks every entry, so prefer [`track_class()`][Self::track_class] for user classes.
+    pub fn retain_alive(&mut self) -> usize {
+        self.purge();
+
+        let dead: Vec<InstanceId> = self
+            .iter()
+            .map(|(instance_id, _)| instance_id)
+            .filter(|instance_id| !instance_id.lookup_validity())
+            .collect();
+
+        for instance_id in &dead {
+            self.remove_entry(*instance_id);
+        }
+
+        dead.len()
+    }
+
+    /// Number of entries, including not yet purged ones.
+    pub fn len(&self) -> usize {
+        self.index_by_id.len()
+    }
+
+    /// Whether there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.index_by_id.is_empty()
+    }
+
+    /// Removes all entries, invalidating all keys. Tracked classes remain tracked.
+    pub fn clear(&mut self) {
+        self.purge();
+
+        let ids: Vec<InstanceId> = self.index_by_id.keys().copied().collect();
+        for instance_id in ids {
+            self.remove_entry(instance_id);
+        }
+    }
+
+    /// Iterates over all entries, in unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (InstanceId, &T)> + '_ {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.entry.as_ref())
+            .map(|(instance_id, value)| (*instance_id, value))
+    }
+
+    /// Iterates mutably over all entries, in unspecified order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (InstanceId, &mut T)> + '_ {
+        self.purge();
+        self.slots
+            .iter_mut()
+            .filter_map(|slot| slot.entry.as_mut())
+            .map(|(instance_id, value)| (*instance_id, value))
+    }
+
+    fn remove_entry(&mut self, instance_id: InstanceId) -> Option<T> {
+        let index = self.index_by_id.remove(&instance_id)?;
+        let slot = &mut self.slots[index as usize];
+
+        // Invalidate keys of the removed entry before the slot is reused.
+        slot.generation = slot.generation.wrapping_add(1);
+        let (_, value) = slot.entry.take().expect("indexed slot is occupied");
+        self.vacant.push(index);
+
+        Some(value)
+    }
+}
+
+impl<T> Default for SidecarMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for SidecarMap<T> {
+    fn drop(&mut self) {
+        for observer in self.observers.drain(..) {
+            observer.remove();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SidecarMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
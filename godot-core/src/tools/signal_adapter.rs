@@ -0,0 +1,144 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::global::Error;
+use crate::meta::ToGodot;
+use crate::tools::FromSignalArgs;
+
+/// Rust function that can be connected to a signal with [`Signal::connect_mapped()`].
+///
+/// Implemented for closures and functions with up to 8 parameters, each of which implements [`FromGodot`][crate::meta::FromGodot].
+/// `Ps` is the tuple of parameter types.
+pub trait SignalReceiver<Ps>: 'static {
+    /// Number of parameters, used to drop signal arguments the function doesn't accept.
+    const PARAM_COUNT: usize;
+
+    /// Invokes the function with already converted arguments.
+    fn call_mapped(&mut self, params: Ps);
+}
+
+/// Extra arguments appended to each signal emission, see [`Signal::bind_args()`].
+///
+/// Implemented for tuples of up to 8 elements, each of which implements [`ToGodot`].
+pub trait ToSignalArgs {
+    /// Converts the arguments to variants, in order.
+    fn to_signal_args(&self) -> Vec<Variant>;
+}
+
+macro_rules! impl_signal_args {
+    ($count:literal; $($T:ident : $index:tt),*) => {
+        impl<F, $($T),*> SignalReceiver<($($T,)*)> for F
+        where
+            F: FnMut($($T),*) + 'static,
+        {
+            const PARAM_COUNT: usize = $count;
+
+            #[allow(unused_variables)]
+            fn call_mapped(&mut self, params: ($($T,)*)) {
+                self($(params.$index),*)
+            }
+        }
+
+        impl<$($T: ToGodot),*> ToSignalArgs for ($($T,)*) {
+            fn to_signal_args(&self) -> Vec<Variant> {
+                vec![$(self.$index.to_variant()),*]
+            }
+        }
+    };
+}
+
+impl_signal_args!(0;);
+impl_signal_args!(1; T0: 0);
+impl_signal_args!(2; T0: 0, T1: 1);
+impl_signal_args!(3; T0: 0, T1: 1, T2: 2);
+impl_signal_args!(4; T0: 0, T1: 1, T2: 2, T3: 3);
+impl_signal_args!(5; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_signal_args!(6; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_signal_args!(7; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_signal_args!(8; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Signal with extra arguments bound to all connections made through it.
+///
+/// Obtained from [`Signal::bind_args()`]. The bound arguments are passed after the signal's own arguments, like with
+/// [`Callable::bind()`][crate::builtin::Callable::bind].
+#[derive(Clone, Debug)]
+pub struct BoundSignal {
+    signal: Signal,
+    bound: Vec<Variant>,
+}
+
+impl BoundSignal {
+    pub(crate) fn new(signal: Signal, bound: Vec<Variant>) -> Self {
+        Self { signal, bound }
+    }
+
+    /// Binds further arguments, after the ones already bound.
+    pub fn bind_args<B: ToSignalArgs>(mut self, args: B) -> Self {
+        self.bound.extend(args.to_signal_args());
+        self
+    }
+
+    /// Connects `function`, which receives the signal's arguments followed by the bound arguments.
+    ///
+    /// Signal arguments that don't fit before the bound arguments are dropped from the end. See [`Signal::connect_mapped()`] for details.
+    ///
+    /// # Panics
+    /// If `function` has fewer parameters than there are bound arguments. On emission, see [`Signal::connect_mapped()`].
+    pub fn connect_mapped<Ps, F>(&self, function: F) -> Error
+    where
+        Ps: FromSignalArgs,
+        F: SignalReceiver<Ps>,
+    {
+        connect_mapped(&self.signal, self.bound.clone(), function)
+    }
+
+    /// The signal to which connections are made.
+    pub fn signal(&self) -> &Signal {
+        &self.signal
+    }
+}
+
+pub(crate) fn connect_mapped<Ps, F>(signal: &Signal, bound: Vec<Variant>, mut function: F) -> Error
+where
+    Ps: FromSignalArgs,
+    F: SignalReceiver<Ps>,
+{
+    let signal_name = signal.to_string();
+
+    // Bound arguments are always passed; the function receives as many leading signal arguments as it has parameters left.
+    let Some(signal_arg_count) = F::PARAM_COUNT.checked_sub(bound.len()) else {
+        panic!(
+            "connect_mapped: function for signal {signal_name} has {} parameters, but {} arguments are bound",
+            F::PARAM_COUNT,
+            bound.len()
+        );
+    };
+
+    let callable = Callable::from_local_fn("Signal::connect_mapped", move |args| {
+        let result = if bound.is_empty() && args.len() <= signal_arg_count {
+            Ps::from_signal_args(args)
+        } else {
+            let signal_args = &args[..args.len().min(signal_arg_count)];
+            let all_args: Vec<&Variant> = signal_args.iter().copied().chain(bound.iter()).collect();
+            Ps::from_signal_args(&all_args)
+        };
+
+        match result {
+            Ok(params) => function.call_mapped(params),
+            Err(err) => {
+                panic!("connect_mapped: arguments of signal {signal_name} have unexpected types: {err}")
+            }
+        }
+
+        Ok(Variant::nil())
+    });
+
+    signal.connect(&callable, 0)
+}
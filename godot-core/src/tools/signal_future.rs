@@ -0,0 +1,213 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::classes::object::ConnectFlags;
+use crate::classes::Object;
+use crate::meta::error::ConvertError;
+use crate::meta::FromGodot;
+use crate::obj::{EngineBitfield, Gd, InstanceId};
+
+/// Future that resolves when a signal is emitted, yielding the signal's arguments.
+///
+/// This is the Rust equivalent of GDScript's `await object.signal`. It is obtained from [`Signal::to_future()`], or from the `await_*()`
+/// methods generated for each signal of an engine class, such as `Timer::await_timeout()`.
+///
+/// The future connects to the signal when created, and resolves on the next emission. It can be driven by any executor running on the
+/// main thread; it is woken directly from the signal emission.
+///
+/// Dropping the future before the signal is emitted abandons the result and disconnects it from the signal.
+///
+/// # Panics
+/// When polled after the object owning the signal has been freed without emitting the signal, or if the signal arguments cannot be
+/// converted to `R`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::Timer;
+///
+/// async fn blink(mut timer: Gd<Timer>, mut node: Gd<Node2D>) {
+///     node.hide();
+///     timer.start();
+///     timer.await_timeout().await;
+///     node.show();
+/// }
+/// ```
+#[must_use = "futures do nothing unless awaited or polled"]
+pub struct SignalFuture<R: FromSignalArgs> {
+    state: Rc<RefCell<State<R>>>,
+    signal: Signal,
+    callable: Callable,
+}
+
+impl<R: FromSignalArgs> SignalFuture<R> {
+    pub(crate) fn new(signal: Signal) -> Self {
+        let state = Rc::new(RefCell::new(State::Pending(None)));
+
+        let guard = SourceGuard {
+            state: state.clone(),
+        };
+        let callable = Callable::from_local_fn("SignalFuture::resolve", move |args| {
+            guard.resolve(R::from_signal_args(args));
+            Ok(Variant::nil())
+        });
+
+        signal.connect(&callable, ConnectFlags::ONE_SHOT.ord() as i64);
+
+        Self {
+            state,
+            signal,
+            callable,
+        }
+    }
+
+    /// Used by generated `await_*()` methods of engine classes.
+    #[doc(hidden)]
+    pub fn __from_object(instance_id: Option<InstanceId>, signal_name: &str) -> Self {
+        let instance_id = instance_id.expect("object is alive");
+        let object = Gd::<Object>::from_instance_id(instance_id);
+
+        Self::new(Signal::from_object_signal(&object, signal_name))
+    }
+
+    /// The signal this future is waiting for.
+    pub fn signal(&self) -> &Signal {
+        &self.signal
+    }
+}
+
+impl<R: FromSignalArgs> Future for SignalFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        match std::mem::replace(&mut *state, State::Taken) {
+            State::Pending(_) => {
+                *state = State::Pending(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+            State::Ready(Ok(args)) => Poll::Ready(args),
+            State::Ready(Err(err)) => {
+                panic!(
+                    "SignalFuture: arguments of signal {} have unexpected types: {err}",
+                    self.signal
+                )
+            }
+            State::SourceFreed => {
+                panic!(
+                    "SignalFuture: object of signal {} was freed before emitting it",
+                    self.signal
+                )
+            }
+            State::Taken => panic!("SignalFuture polled after completion"),
+        }
+    }
+}
+
+impl<R: FromSignalArgs> Drop for SignalFuture<R> {
+    fn drop(&mut self) {
+        // Turns a later emission into a no-op, in case the connection cannot be removed.
+        *self.state.borrow_mut() = State::Taken;
+
+        // After emission, the one-shot connection is already gone. If the object has been freed, so has the connection.
+        if self.signal.object().is_some() && self.signal.is_connected(&self.callable) {
+            self.signal.disconnect(&self.callable);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Signal arguments that can be received by a [`SignalFuture`].
+///
+/// Implemented for tuples of up to 8 elements, each of which implements [`FromGodot`]. Signals without arguments use `()`.
+pub trait FromSignalArgs: Sized + 'static {
+    /// Converts the arguments of a signal emission.
+    fn from_signal_args(args: &[&Variant]) -> Result<Self, ConvertError>;
+}
+
+macro_rules! impl_from_signal_args {
+    ($count:literal; $($T:ident : $index:tt),*) => {
+        impl<$($T: FromGodot + 'static),*> FromSignalArgs for ($($T,)*) {
+            #[allow(unused_variables)]
+            fn from_signal_args(args: &[&Variant]) -> Result<Self, ConvertError> {
+                if args.len() != $count {
+                    return Err(ConvertError::new(format!(
+                        "expected {} signal arguments, got {}",
+                        $count,
+                        args.len()
+                    )));
+                }
+
+                Ok(($(args[$index].try_to::<$T>()?,)*))
+            }
+        }
+    };
+}
+
+impl_from_signal_args!(0;);
+impl_from_signal_args!(1; T0: 0);
+impl_from_signal_args!(2; T0: 0, T1: 1);
+impl_from_signal_args!(3; T0: 0, T1: 1, T2: 2);
+impl_from_signal_args!(4; T0: 0, T1: 1, T2: 2, T3: 3);
+impl_from_signal_args!(5; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_from_signal_args!(6; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_from_signal_args!(7; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_from_signal_args!(8; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+enum State<R> {
+    Pending(Option<Waker>),
+    Ready(Result<R, ConvertError>),
+    SourceFreed,
+    Taken,
+}
+
+/// Owned by the connected callable, which is destroyed after emission (one-shot), or when the object is freed.
+struct SourceGuard<R> {
+    state: Rc<RefCell<State<R>>>,
+}
+
+impl<R> SourceGuard<R> {
+    fn resolve(&self, result: Result<R, ConvertError>) {
+        self.transition(State::Ready(result));
+    }
+
+    fn transition(&self, new_state: State<R>) {
+        let mut state = self.state.borrow_mut();
+
+        // Already resolved, or future dropped.
+        let State::Pending(waker) = &mut *state else {
+            return;
+        };
+
+        let waker = waker.take();
+        *state = new_state;
+        drop(state);
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<R> Drop for SourceGuard<R> {
+    fn drop(&mut self) {
+        // If the signal hasn't been emitted by now, it never will be through this connection.
+        self.transition(State::SourceFreed);
+    }
+}
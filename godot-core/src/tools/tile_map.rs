@@ -0,0 +1,361 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::builtin::{PackedByteArray, Vector2i, Vector3i};
+use crate::classes::{GridMap, TileData, TileMapLayer};
+use crate::obj::Gd;
+
+/// A single cell of a tile map layer: which tile is placed at which coordinates.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TileCell {
+    /// Cell coordinates in the layer.
+    pub coords: Vector2i,
+
+    /// ID of the `TileSetSource`. `-1` denotes an empty cell; passing it to bulk setters erases the cell.
+    pub source_id: i32,
+
+    /// Coordinates of the tile in a `TileSetAtlasSource`.
+    pub atlas_coords: Vector2i,
+
+    /// Alternative tile ID, `0` for the base tile.
+    pub alternative_tile: i32,
+}
+
+impl TileCell {
+    /// Creates a cell referring to an atlas tile.
+    pub fn new(coords: Vector2i, source_id: i32, atlas_coords: Vector2i) -> Self {
+        Self {
+            coords,
+            source_id,
+            atlas_coords,
+            alternative_tile: 0,
+        }
+    }
+
+    /// Creates an entry that erases the cell at `coords`.
+    pub fn empty(coords: Vector2i) -> Self {
+        Self {
+            coords,
+            source_id: -1,
+            atlas_coords: Vector2i::new(-1, -1),
+            alternative_tile: -1,
+        }
+    }
+
+    /// Returns `true` if no tile is placed in the cell.
+    pub fn is_empty(&self) -> bool {
+        self.source_id == -1
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// TileMapLayer
+
+/// Bulk editing and typed queries for [`TileMapLayer`], e.g. for procedural map generation.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::TileMapLayer;
+/// use godot::tools::{TileCell, TileMapLayerExt};
+///
+/// fn generate(layer: &mut Gd<TileMapLayer>) {
+///     let floor = Vector2i::new(0, 0);
+///     let cells = (0..256).flat_map(|x| (0..256).map(move |y| (x, y)))
+///         .map(|(x, y)| TileCell::new(Vector2i::new(x, y), 0, floor));
+///
+///     layer.set_cells_bulk(cells);
+/// }
+/// ```
+pub trait TileMapLayerExt {
+    /// Places many cells at once. Cells with `source_id == -1` are erased.
+    ///
+    /// Instead of one `set_cell()` call per cell, the layer's data is read once as `tile_map_data`, modified in Rust and written back
+    /// in a single call. This is much faster for large numbers of cells, but costs time proportional to the existing layer size;
+    /// for a handful of cells on a large layer, prefer `set_cell()`.
+    ///
+    /// Godot's serialized format stores coordinates as 16-bit values. Cells outside `i16` range are placed with individual `set_cell()`
+    /// calls instead; if the layer already contains such cells, all cells are placed individually. Their coordinates are never truncated.
+    /// Source IDs, atlas coordinates and alternative IDs of existing cells must fit into `u16`, as Godot cannot serialize larger values.
+    fn set_cells_bulk(&mut self, cells: impl IntoIterator<Item = TileCell>);
+
+    /// Returns all non-empty cells of the layer, obtained in a single engine call.
+    fn cells(&self) -> Vec<TileCell>;
+
+    /// Returns the tile at `coords`, or `None` if the cell is empty.
+    fn cell(&self, coords: Vector2i) -> Option<TileCell>;
+
+    /// Returns the [`TileData`] of the tile at `coords`, to query custom data, collision and navigation settings.
+    fn cell_tile_data(&self, coords: Vector2i) -> Option<Gd<TileData>>;
+}
+
+impl TileMapLayerExt for Gd<TileMapLayer> {
+    fn set_cells_bulk(&mut self, cells: impl IntoIterator<Item = TileCell>) {
+        let Some(stored) = read_tile_map_data(self) else {
+            for cell in cells {
+                set_single_cell(self, &cell);
+            }
+            return;
+        };
+
+        let mut stored = stored
+            .into_iter()
+            .map(|cell| (cell.coords, cell))
+            .collect::<HashMap<_, _>>();
+
+        let mut unencodable = Vec::new();
+        for cell in cells {
+            if cell.is_empty() {
+                stored.remove(&cell.coords);
+            } else if is_encodable(&cell) {
+                stored.insert(cell.coords, cell);
+            } else {
+                unencodable.push(cell);
+            }
+        }
+
+        let data = encode_tile_map_data(stored.values());
+        self.set_tile_map_data_from_array(&PackedByteArray::from(data.as_slice()));
+
+        // Coordinates or IDs outside the 16-bit range of the serialized format go through the regular API.
+        for cell in unencodable {
+            set_single_cell(self, &cell);
+        }
+    }
+
+    fn cells(&self) -> Vec<TileCell> {
+        if let Some(cells) = read_tile_map_data(self) {
+            return cells;
+        }
+
+        self.get_used_cells()
+            .iter_shared()
+            .filter_map(|coords| self.cell(coords))
+            .collect()
+    }
+
+    fn cell(&self, coords: Vector2i) -> Option<TileCell> {
+        let source_id = self.get_cell_source_id(coords);
+        if source_id == -1 {
+            return None;
+        }
+
+        Some(TileCell {
+            coords,
+            source_id,
+            atlas_coords: self.get_cell_atlas_coords(coords),
+            alternative_tile: self.get_cell_alternative_tile(coords),
+        })
+    }
+
+    fn cell_tile_data(&self, coords: Vector2i) -> Option<Gd<TileData>> {
+        self.get_cell_tile_data(coords)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// GridMap
+
+/// A single cell of a [`GridMap`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GridCell {
+    /// Cell coordinates in the grid.
+    pub coords: Vector3i,
+
+    /// Index of the `MeshLibrary` item. `GridMap::INVALID_CELL_ITEM` (`-1`) denotes an empty cell.
+    pub item: i32,
+
+    /// Orthogonal orientation index of the item, see `GridMap::get_orthogonal_index_from_basis()`.
+    pub orientation: i32,
+}
+
+/// Bulk editing and typed queries for [`GridMap`].
+pub trait GridMapExt {
+    /// Places many cells at once. Cells with `item == -1` are erased.
+    ///
+    /// Godot offers no batch API for grid maps, so this performs one engine call per cell.
+    fn set_cells_bulk(&mut self, cells: impl IntoIterator<Item = GridCell>);
+
+    /// Returns all non-empty cells of the grid map.
+    fn cells(&self) -> Vec<GridCell>;
+}
+
+impl GridMapExt for Gd<GridMap> {
+    fn set_cells_bulk(&mut self, cells: impl IntoIterator<Item = GridCell>) {
+        for cell in cells {
+            self.set_cell_item_ex(cell.coords, cell.item)
+                .orientation(cell.orientation)
+                .done();
+        }
+    }
+
+    fn cells(&self) -> Vec<GridCell> {
+        self.get_used_cells()
+            .iter_shared()
+            .map(|coords| GridCell {
+                coords,
+                item: self.get_cell_item(coords),
+                orientation: self.get_cell_item_orientation(coords),
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+// Serialized `TileMapLayer.tile_map_data`: a u16 format version, followed by 12 bytes per cell -- coordinates as two i16, then source ID,
+// atlas coordinates and alternative tile as u16. All little-endian. The format is stable, as it is stored in scene files.
+const TILE_MAP_DATA_FORMAT: u16 = 0;
+const HEADER_SIZE: usize = 2;
+const CELL_SIZE: usize = 12;
+
+/// Reads all cells of `layer` in one call, or returns `None` if the per-cell API must be used instead.
+fn read_tile_map_data(layer: &Gd<TileMapLayer>) -> Option<Vec<TileCell>> {
+    // Reading the layer would truncate existing cells outside the 16-bit range, and writing them back would move them.
+    if !coords_encodable(layer) {
+        return None;
+    }
+
+    decode_tile_map_data(layer.get_tile_map_data_as_array().as_slice())
+}
+
+/// Whether all cells of `layer` have coordinates that fit into the serialized format.
+fn coords_encodable(layer: &Gd<TileMapLayer>) -> bool {
+    let rect = layer.get_used_rect();
+    let fits_i16 = |v: i32| i16::try_from(v).is_ok();
+
+    // Empty layers have a zero-sized rect at the origin.
+    fits_i16(rect.position.x)
+        && fits_i16(rect.position.y)
+        && fits_i16(rect.end().x - 1)
+        && fits_i16(rect.end().y - 1)
+}
+
+fn set_single_cell(layer: &mut Gd<TileMapLayer>, cell: &TileCell) {
+    if cell.is_empty() {
+        layer.erase_cell(cell.coords);
+        return;
+    }
+
+    layer
+        .set_cell_ex(cell.coords)
+        .source_id(cell.source_id)
+        .atlas_coords(cell.atlas_coords)
+        .alternative_tile(cell.alternative_tile)
+        .done();
+}
+
+fn is_encodable(cell: &TileCell) -> bool {
+    let fits_i16 = |v: i32| i16::try_from(v).is_ok();
+    let fits_u16 = |v: i32| u16::try_from(v).is_ok();
+
+    fits_i16(cell.coords.x)
+        && fits_i16(cell.coords.y)
+        && fits_u16(cell.source_id)
+        && fits_u16(cell.atlas_coords.x)
+        && fits_u16(cell.atlas_coords.y)
+        && fits_u16(cell.alternative_tile)
+}
+
+/// Decodes serialized cells. Returns `None` if the data has a format that is not known, e.g. from a newer Godot version.
+fn decode_tile_map_data(bytes: &[u8]) -> Option<Vec<TileCell>> {
+    // Empty layers serialize to an empty array.
+    if bytes.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let (header, cells) = bytes.split_at_checked(HEADER_SIZE)?;
+    let format = u16::from_le_bytes([header[0], header[1]]);
+    if format != TILE_MAP_DATA_FORMAT || cells.len() % CELL_SIZE != 0 {
+        return None;
+    }
+
+    let cells = cells
+        .chunks_exact(CELL_SIZE)
+        .map(|chunk| {
+            let u16_at = |i: usize| u16::from_le_bytes([chunk[i], chunk[i + 1]]);
+            let i16_at = |i: usize| i16::from_le_bytes([chunk[i], chunk[i + 1]]);
+
+            TileCell {
+                coords: Vector2i::new(i16_at(0) as i32, i16_at(2) as i32),
+                source_id: u16_at(4) as i32,
+                atlas_coords: Vector2i::new(u16_at(6) as i32, u16_at(8) as i32),
+                alternative_tile: u16_at(10) as i32,
+            }
+        })
+        .collect();
+
+    Some(cells)
+}
+
+fn encode_tile_map_data<'a>(cells: impl ExactSizeIterator<Item = &'a TileCell>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_SIZE + cells.len() * CELL_SIZE);
+    bytes.extend_from_slice(&TILE_MAP_DATA_FORMAT.to_le_bytes());
+
+    // Values were range-checked by is_encodable().
+    for cell in cells {
+        bytes.extend_from_slice(&(cell.coords.x as i16).to_le_bytes());
+        bytes.extend_from_slice(&(cell.coords.y as i16).to_le_bytes());
+        bytes.extend_from_slice(&(cell.source_id as u16).to_le_bytes());
+        bytes.extend_from_slice(&(cell.atlas_coords.x as u16).to_le_bytes());
+        bytes.extend_from_slice(&(cell.atlas_coords.y as u16).to_le_bytes());
+        bytes.extend_from_slice(&(cell.alternative_tile as u16).to_le_bytes());
+    }
+
+    bytes
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tile_map_data_roundtrip() {
+        let cells = [
+            TileCell::new(Vector2i::new(-3, 7), 2, Vector2i::new(4, 1)),
+            TileCell {
+                alternative_tile: 5,
+                ..TileCell::new(
+                    Vector2i::new(i16::MAX as i32, i16::MIN as i32),
+                    0,
+                    Vector2i::new(0, 9),
+                )
+            },
+        ];
+
+        let bytes = encode_tile_map_data(cells.iter());
+        assert_eq!(bytes.len(), HEADER_SIZE + 2 * CELL_SIZE);
+        assert_eq!(decode_tile_map_data(&bytes), Some(cells.to_vec()));
+    }
+
+    #[test]
+    fn tile_map_data_empty() {
+        assert_eq!(decode_tile_map_data(&[]), Some(vec![]));
+        assert_eq!(decode_tile_map_data(&[0, 0]), Some(vec![]));
+    }
+
+    #[test]
+    fn tile_map_data_unsupported() {
+        let mut bytes =
+            encode_tile_map_data([TileCell::new(Vector2i::new(1, 2), 0, Vector2i::ZERO)].iter());
+
+        // Truncated cell.
+        assert_eq!(decode_tile_map_data(&bytes[..bytes.len() - 1]), None);
+
+        // Only part of the header.
+        assert_eq!(decode_tile_map_data(&bytes[..1]), None);
+
+        // Unknown format version: callers fall back to the per-cell API.
+        bytes[0] = 1;
+        assert_eq!(decode_tile_map_data(&bytes), None);
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::builtin::NodePath;
+use crate::classes::tween::{EaseType, TransitionType};
+use crate::classes::{AnimationTree, Object, PropertyTweener, Tween};
+use crate::meta::{arg_into_ref, AsArg, FromGodot, ToGodot};
+use crate::obj::{Gd, Inherits};
+
+/// Typed extensions for [`Tween`].
+///
+/// Godot's `Tween::tween_property()` takes a stringly-typed property path and `Variant` values, so mistakes such as typos in the
+/// property name or a mismatch between value types only surface as engine errors at runtime. The methods in this trait accept typed
+/// values and validate the property in Debug builds.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::TweenExt;
+///
+/// fn slide_in(node: &Gd<Node2D>) {
+///     let mut tween = node.create_tween().unwrap();
+///
+///     tween
+///         .tween_property_typed(node, "position", Vector2::new(100.0, 0.0), 0.5)
+///         .from(Vector2::ZERO)
+///         .trans(TransitionType::QUAD)
+///         .ease(EaseType::OUT);
+/// }
+/// ```
+pub trait TweenExt {
+    /// Animates `property` of `object` towards `final_val` over `duration` seconds.
+    ///
+    /// `property` may contain sub-property paths such as `"position:x"`.
+    ///
+    /// In Debug builds, panics if `object` has no such property, or if the property's current value has a different type than `final_val`.
+    /// Validation is skipped for properties whose current value is `null`, as their type cannot be determined.
+    fn tween_property_typed<T, O>(
+        &mut self,
+        object: &Gd<O>,
+        property: impl AsArg<NodePath>,
+        final_val: T,
+        duration: f64,
+    ) -> TypedPropertyTweener<T>
+    where
+        T: ToGodot,
+        O: Inherits<Object>;
+}
+
+impl TweenExt for Gd<Tween> {
+    fn tween_property_typed<T, O>(
+        &mut self,
+        object: &Gd<O>,
+        property: impl AsArg<NodePath>,
+        final_val: T,
+        duration: f64,
+    ) -> TypedPropertyTweener<T>
+    where
+        T: ToGodot,
+        O: Inherits<Object>,
+    {
+        arg_into_ref!(property);
+        let final_val = final_val.to_variant();
+
+        #[cfg(debug_assertions)]
+        validate_tweened_property(object.upcast_ref(), property, &final_val);
+
+        let tweener = self
+            .tween_property(object, property, &final_val, duration)
+            .unwrap_or_else(|| panic!("Tween::tween_property() failed for property `{property}`"));
+
+        TypedPropertyTweener {
+            tweener,
+            _value: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Wrapper around [`PropertyTweener`] that remembers the value type `T` of the tweened property.
+///
+/// Returned by [`TweenExt::tween_property_typed()`]. All methods configure the tweener in place and return `self` for chaining.
+/// The underlying tweener is already registered with its tween, so the wrapper can simply be dropped when done.
+pub struct TypedPropertyTweener<T> {
+    tweener: Gd<PropertyTweener>,
+    _value: PhantomData<fn(T)>,
+}
+
+impl<T: ToGodot> TypedPropertyTweener<T> {
+    /// Sets a custom initial value, instead of the property's value at the time the tweener starts.
+    pub fn from(mut self, value: T) -> Self {
+        self.tweener.from(&value.to_variant());
+        self
+    }
+
+    /// Uses the property's value at the time of creation as the initial value (instead of when the tweener starts).
+    pub fn from_current(mut self) -> Self {
+        self.tweener.from_current();
+        self
+    }
+
+    /// Interprets the final value relative to the initial value.
+    pub fn as_relative(mut self) -> Self {
+        self.tweener.as_relative();
+        self
+    }
+
+    /// Sets the easing type.
+    pub fn ease(mut self, ease: EaseType) -> Self {
+        self.tweener.set_ease(ease);
+        self
+    }
+
+    /// Sets the transition type.
+    pub fn trans(mut self, trans: TransitionType) -> Self {
+        self.tweener.set_trans(trans);
+        self
+    }
+
+    /// Waits `delay` seconds before starting the tweener.
+    pub fn delay(mut self, delay: f64) -> Self {
+        self.tweener.set_delay(delay);
+        self
+    }
+
+    /// Returns the underlying Godot tweener, for APIs not covered by this wrapper.
+    pub fn into_inner(self) -> Gd<PropertyTweener> {
+        self.tweener
+    }
+}
+
+#[cfg(debug_assertions)]
+fn validate_tweened_property(
+    object: &Object,
+    property: &NodePath,
+    final_val: &crate::builtin::Variant,
+) {
+    let path = crate::builtin::GString::from(property).to_string();
+    let name = path.split(':').next().unwrap_or_default();
+
+    let has_property = object.get_property_list().iter_shared().any(|info| {
+        info.get("name")
+            .is_some_and(|prop_name| prop_name.to_string() == name)
+    });
+
+    assert!(
+        has_property,
+        "cannot tween property `{path}`: class {class} has no property `{name}`",
+        class = object.get_class()
+    );
+
+    let current = object.get_indexed(property);
+    if !current.is_nil() && current.get_type() != final_val.get_type() {
+        panic!(
+            "cannot tween property `{path}`: current value has type {current:?}, but final value has type {final_ty:?}",
+            current = current.get_type(),
+            final_ty = final_val.get_type()
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Typed extensions for [`AnimationTree`].
+///
+/// Animation tree parameters are exposed as properties under `parameters/`, e.g. `parameters/blend_position` or
+/// `parameters/StateMachine/conditions/is_running`. These methods take the parameter path without the `parameters/` prefix.
+pub trait AnimationTreeExt {
+    /// Sets the parameter at `path` (relative to `parameters/`) to `value`.
+    fn set_parameter<T: ToGodot>(&mut self, path: &str, value: T);
+
+    /// Returns the parameter at `path` (relative to `parameters/`), or `None` if it doesn't exist or has a different type.
+    fn get_parameter<T: FromGodot>(&self, path: &str) -> Option<T>;
+}
+
+impl AnimationTreeExt for Gd<AnimationTree> {
+    fn set_parameter<T: ToGodot>(&mut self, path: &str, value: T) {
+        let property = format!("parameters/{path}");
+        self.set(property.as_str(), &value.to_variant());
+    }
+
+    fn get_parameter<T: FromGodot>(&self, path: &str) -> Option<T> {
+        let property = format!("parameters/{path}");
+        self.get(property.as_str()).try_to().ok()
+    }
+}
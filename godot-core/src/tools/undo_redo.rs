@@ -0,0 +1,349 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{varray, Callable, GString, StringName, Variant};
+use crate::classes::undo_redo::MergeMode;
+use crate::classes::{EditorUndoRedoManager, Object, RefCounted, UndoRedo};
+use crate::meta::{arg_into_owned, AsArg, ToGodot};
+use crate::obj::{Gd, Inherits, NewGd};
+
+/// Closure-based actions for [`UndoRedo`] and [`EditorUndoRedoManager`].
+///
+/// Godot's undo/redo APIs identify operations through an object and a method name, which is inconvenient from Rust. This trait lets you
+/// build actions from closures and typed property values instead.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::{EditorPlugin, IEditorPlugin};
+/// use godot::classes::undo_redo::MergeMode;
+/// use godot::tools::UndoRedoExt;
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorPlugin)]
+/// struct MyPlugin {
+///     base: Base<EditorPlugin>,
+/// }
+///
+/// impl MyPlugin {
+///     fn nudge(&mut self, mut node: Gd<Node2D>) {
+///         let old_pos = node.get_position();
+///         let new_pos = old_pos + Vector2::new(8.0, 0.0);
+///
+///         let mut undo_redo = self.base().get_undo_redo().unwrap();
+///         undo_redo
+///             .build_action("Nudge node")
+///             .merge_mode(MergeMode::ENDS)
+///             .context(&node)
+///             .do_property(&node, "position", new_pos)
+///             .undo_property(&node, "position", old_pos)
+///             .do_fn(|| godot_print!("nudged"))
+///             .commit();
+///     }
+/// }
+/// ```
+pub trait UndoRedoExt {
+    /// Starts building an action named `name`. The action is only registered once [`UndoRedoAction::commit()`] is called.
+    fn build_action(&mut self, name: impl AsArg<GString>) -> UndoRedoAction<'_>;
+}
+
+impl UndoRedoExt for Gd<UndoRedo> {
+    fn build_action(&mut self, name: impl AsArg<GString>) -> UndoRedoAction<'_> {
+        UndoRedoAction::new(Target::UndoRedo(self), name)
+    }
+}
+
+impl UndoRedoExt for Gd<EditorUndoRedoManager> {
+    fn build_action(&mut self, name: impl AsArg<GString>) -> UndoRedoAction<'_> {
+        UndoRedoAction::new(Target::Editor(self), name)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builder for an undo/redo action, returned by [`UndoRedoExt::build_action()`].
+///
+/// Operations are executed in the order they are added, unless [`backward_undo_ops()`][Self::backward_undo_ops] is set.
+///
+/// Closures are kept alive by an internal `RefCounted` holder object, which the undo history references for as long as the action exists.
+/// Since the closures are invoked by Godot on the main thread, they need not be `Send`.
+#[must_use = "the action is only registered when calling commit()"]
+pub struct UndoRedoAction<'a> {
+    target: Target<'a>,
+    name: GString,
+    merge_mode: MergeMode,
+    backward_undo_ops: bool,
+    context: Option<Gd<Object>>,
+    holder: Gd<RefCounted>,
+    ops: Vec<Operation>,
+}
+
+impl<'a> UndoRedoAction<'a> {
+    fn new(target: Target<'a>, name: impl AsArg<GString>) -> Self {
+        arg_into_owned!(name);
+
+        Self {
+            target,
+            name,
+            merge_mode: MergeMode::DISABLE,
+            backward_undo_ops: false,
+            context: None,
+            holder: RefCounted::new_gd(),
+            ops: Vec::new(),
+        }
+    }
+
+    /// Sets how consecutive actions with the same name are merged. Default is [`MergeMode::DISABLE`].
+    pub fn merge_mode(mut self, merge_mode: MergeMode) -> Self {
+        self.merge_mode = merge_mode;
+        self
+    }
+
+    /// If `true`, undo operations are executed in reverse order of their addition. Default is `false`.
+    pub fn backward_undo_ops(mut self, backward: bool) -> Self {
+        self.backward_undo_ops = backward;
+        self
+    }
+
+    /// Object that determines the editor history the action belongs to (e.g. the node being edited).
+    ///
+    /// Only relevant for [`EditorUndoRedoManager`]. If not set, the object of the first property operation is used; if there is none,
+    /// the action goes into the global history.
+    pub fn context<O: Inherits<Object>>(mut self, object: &Gd<O>) -> Self {
+        self.context = Some(object.clone().upcast());
+        self
+    }
+
+    /// Runs `f` when the action is done or redone.
+    pub fn do_fn(self, f: impl FnMut() + 'static) -> Self {
+        self.add_fn(Kind::Do, f)
+    }
+
+    /// Runs `f` when the action is undone.
+    pub fn undo_fn(self, f: impl FnMut() + 'static) -> Self {
+        self.add_fn(Kind::Undo, f)
+    }
+
+    /// Calls `callable` when the action is done or redone, e.g. one created via [`Callable::from_object_method()`].
+    pub fn do_callable(self, callable: &Callable) -> Self {
+        self.add_callable(Kind::Do, callable)
+    }
+
+    /// Calls `callable` when the action is undone, e.g. one created via [`Callable::from_object_method()`].
+    pub fn undo_callable(self, callable: &Callable) -> Self {
+        self.add_callable(Kind::Undo, callable)
+    }
+
+    /// Sets `property` of `object` to `value` when the action is done or redone.
+    pub fn do_property<O, V>(
+        self,
+        object: &Gd<O>,
+        property: impl AsArg<StringName>,
+        value: V,
+    ) -> Self
+    where
+        O: Inherits<Object>,
+        V: ToGodot,
+    {
+        self.add_property(Kind::Do, object, property, value)
+    }
+
+    /// Sets `property` of `object` to `value` when the action is undone.
+    pub fn undo_property<O, V>(
+        self,
+        object: &Gd<O>,
+        property: impl AsArg<StringName>,
+        value: V,
+    ) -> Self
+    where
+        O: Inherits<Object>,
+        V: ToGodot,
+    {
+        self.add_property(Kind::Undo, object, property, value)
+    }
+
+    /// Registers the action, and performs its do-operations.
+    pub fn commit(self) {
+        self.commit_impl(true);
+    }
+
+    /// Registers the action without performing its do-operations, e.g. when the change has already been applied.
+    pub fn commit_without_execute(self) {
+        self.commit_impl(false);
+    }
+
+    fn add_fn(self, kind: Kind, mut f: impl FnMut() + 'static) -> Self {
+        let callable = Callable::from_local_fn(kind.callable_name(), move |_args| {
+            f();
+            Ok(Variant::nil())
+        });
+
+        self.add_callable(kind, &callable)
+    }
+
+    fn add_callable(mut self, kind: Kind, callable: &Callable) -> Self {
+        // Godot skips operations whose target object no longer exists, and custom callables have no object at all. Thus, route the call
+        // through a signal on the holder object, which the undo history keeps alive.
+        let signal = format!("{}_{}", kind.callable_name(), self.ops.len());
+        self.holder.add_user_signal(signal.as_str());
+        self.holder.connect(signal.as_str(), callable);
+
+        self.ops.push(Operation::Signal {
+            kind,
+            signal: StringName::from(signal),
+        });
+        self
+    }
+
+    fn add_property<O, V>(
+        mut self,
+        kind: Kind,
+        object: &Gd<O>,
+        property: impl AsArg<StringName>,
+        value: V,
+    ) -> Self
+    where
+        O: Inherits<Object>,
+        V: ToGodot,
+    {
+        arg_into_owned!(property);
+
+        self.ops.push(Operation::Property {
+            kind,
+            object: object.clone().upcast(),
+            property,
+            value: value.to_variant(),
+        });
+        self
+    }
+
+    fn commit_impl(self, execute: bool) {
+        let Self {
+            target,
+            name,
+            merge_mode,
+            backward_undo_ops,
+            context,
+            holder,
+            ops,
+        } = self;
+
+        match target {
+            Target::UndoRedo(undo_redo) => {
+                undo_redo
+                    .create_action_ex(&name)
+                    .merge_mode(merge_mode)
+                    .backward_undo_ops(backward_undo_ops)
+                    .done();
+
+                for op in ops {
+                    match op {
+                        Operation::Signal { kind, signal } => {
+                            let callable = Callable::from_object_method(&holder, "emit_signal")
+                                .bindv(&varray![signal]);
+
+                            match kind {
+                                Kind::Do => undo_redo.add_do_method(&callable),
+                                Kind::Undo => undo_redo.add_undo_method(&callable),
+                            }
+                        }
+                        Operation::Property {
+                            kind,
+                            object,
+                            property,
+                            value,
+                        } => match kind {
+                            Kind::Do => undo_redo.add_do_property(&object, &property, &value),
+                            Kind::Undo => undo_redo.add_undo_property(&object, &property, &value),
+                        },
+                    }
+                }
+
+                undo_redo.commit_action_ex().execute(execute).done();
+            }
+
+            Target::Editor(manager) => {
+                let context = context.or_else(|| {
+                    ops.iter().find_map(|op| match op {
+                        Operation::Property { object, .. } => Some(object.clone()),
+                        Operation::Signal { .. } => None,
+                    })
+                });
+
+                let mut builder = manager
+                    .create_action_ex(&name)
+                    .merge_mode(merge_mode)
+                    .backward_undo_ops(backward_undo_ops);
+                if let Some(context) = &context {
+                    builder = builder.custom_context(context);
+                }
+                builder.done();
+
+                for op in ops {
+                    match op {
+                        Operation::Signal { kind, signal } => {
+                            let args = [signal.to_variant()];
+                            match kind {
+                                Kind::Do => manager.add_do_method(&holder, "emit_signal", &args),
+                                Kind::Undo => {
+                                    manager.add_undo_method(&holder, "emit_signal", &args)
+                                }
+                            }
+                        }
+                        Operation::Property {
+                            kind,
+                            object,
+                            property,
+                            value,
+                        } => match kind {
+                            Kind::Do => manager.add_do_property(&object, &property, &value),
+                            Kind::Undo => manager.add_undo_property(&object, &property, &value),
+                        },
+                    }
+                }
+
+                manager.commit_action_ex().execute(execute).done();
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+enum Target<'a> {
+    UndoRedo(&'a mut Gd<UndoRedo>),
+    Editor(&'a mut Gd<EditorUndoRedoManager>),
+}
+
+#[derive(Copy, Clone)]
+enum Kind {
+    Do,
+    Undo,
+}
+
+impl Kind {
+    fn callable_name(self) -> &'static str {
+        match self {
+            Kind::Do => "do",
+            Kind::Undo => "undo",
+        }
+    }
+}
+
+enum Operation {
+    Signal {
+        kind: Kind,
+        signal: StringName,
+    },
+    Property {
+        kind: Kind,
+        object: Gd<Object>,
+        property: StringName,
+        value: Variant,
+    },
+}
@@ -0,0 +1,757 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Pure-Rust parser and formatter for Godot's variant text format.
+//!
+//! This is the format of `var_to_str()` and of property values in `.tres`/`.tscn` files, for example
+//! `{ "pos": Vector2(1, 2.5), "tags": [&"enemy", ^"../Player"] }`. Unlike [`str_to_var()`][super::str_to_var], parsing works without
+//! a running engine, so it can be used in build scripts, editors or asset pipelines. Errors contain the line and column of the problem.
+//!
+//! Values are parsed into a [`TextValue`] tree rather than [`Variant`][crate::builtin::Variant]s, since the latter require the engine.
+//! Constructor calls such as `Vector2(1, 2)`, `PackedInt32Array(1, 2)` or `ExtResource("1_abc")` are kept generic, with their
+//! name and arguments. To obtain a `Variant`, format the tree and pass it to `str_to_var()`.
+//!
+//! Not supported are `Object(...)` literals with inline properties and typed dictionaries.
+//!
+//! Requires the `variant-parser` feature.
+//!
+//! # Example
+//! ```no_run
+//! use godot::tools::variant_parser::{parse, TextValue};
+//!
+//! let value = parse(r#"{ "speed": 2.5, "spawn": Vector2(10, 20) }"#).unwrap();
+//! let TextValue::Dictionary(entries) = &value else { unreachable!() };
+//! assert_eq!(entries[0], (TextValue::String("speed".into()), TextValue::Float(2.5)));
+//!
+//! // Formatting produces text accepted by Godot's str_to_var().
+//! assert_eq!(value.to_string(), "{\n\"speed\": 2.5,\n\"spawn\": Vector2(10, 20)\n}");
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+/// Value in Godot's variant text format.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextValue {
+    /// `null`.
+    Nil,
+
+    /// `true` or `false`.
+    Bool(bool),
+
+    /// Integer literal, e.g. `-12`.
+    Int(i64),
+
+    /// Floating-point literal, e.g. `2.5`, `1e-3`, `inf` or `nan`.
+    Float(f64),
+
+    /// String literal, e.g. `"text"`.
+    String(String),
+
+    /// String name literal, e.g. `&"name"`.
+    StringName(String),
+
+    /// Node path literal, e.g. `^"Path/To/Node"`.
+    NodePath(String),
+
+    /// Untyped array, e.g. `[1, "two"]`.
+    Array(Vec<TextValue>),
+
+    /// Typed array, e.g. `Array[int]([1, 2])`.
+    TypedArray {
+        /// Element type, e.g. `int` or `Resource`. Arrays typed with a script class refer to the script resource, which is kept
+        /// in normalized text form, e.g. `ExtResource("1_abc")`.
+        element_type: String,
+        elements: Vec<TextValue>,
+    },
+
+    /// Dictionary, e.g. `{ "key": 1 }`. Entries are kept in source order.
+    Dictionary(Vec<(TextValue, TextValue)>),
+
+    /// Constructor call, e.g. `Vector2(1, 2)`, `Color(1, 0, 0, 1)` or `ExtResource("1_abc")`.
+    Construct {
+        /// Type or function name, e.g. `Vector2`.
+        name: String,
+        args: Vec<TextValue>,
+    },
+}
+
+/// Parses a single value in Godot's variant text format.
+///
+/// Whitespace and `;` line comments around tokens are ignored. Input after the value is an error.
+///
+/// Containers and constructor calls can be nested up to [`MAX_DEPTH`] levels, so that untrusted input cannot overflow the stack.
+pub fn parse(text: &str) -> Result<TextValue, ParseError> {
+    let mut parser = Parser::new(text);
+
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(ParseErrorKind::TrailingInput(c))),
+    }
+}
+
+/// Maximum nesting depth of arrays, dictionaries and constructor calls accepted by [`parse()`].
+pub const MAX_DEPTH: usize = 256;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Errors
+
+/// Error while parsing the variant text format, with the position of the problem.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// Kind of the problem.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Line of the problem, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the problem (in characters), starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Kind of a [`ParseError`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// Input ended in the middle of a value.
+    UnexpectedEnd,
+
+    /// Character that cannot appear at this position.
+    UnexpectedChar(char),
+
+    /// Number that cannot be represented, e.g. an integer out of `i64` range.
+    InvalidNumber(String),
+
+    /// Unknown escape sequence in a string, e.g. `\q`.
+    InvalidEscape(char),
+
+    /// Identifier that is neither a keyword nor followed by `(`, e.g. `Vector2` without arguments.
+    UnknownIdentifier(String),
+
+    /// Non-whitespace input after the value.
+    TrailingInput(char),
+
+    /// Values are nested more than [`MAX_DEPTH`] levels deep.
+    NestingTooDeep,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::UnexpectedChar(c) => write!(f, "unexpected character {c:?}"),
+            Self::InvalidNumber(number) => write!(f, "invalid number `{number}`"),
+            Self::InvalidEscape(c) => write!(f, "invalid escape sequence `\\{c}`"),
+            Self::UnknownIdentifier(ident) => write!(f, "unknown identifier `{ident}`"),
+            Self::TrailingInput(c) => write!(f, "unexpected character {c:?} after value"),
+            Self::NestingTooDeep => write!(f, "values nested more than {MAX_DEPTH} levels deep"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Parser
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+
+    /// Number of values currently being parsed, i.e. the nesting level.
+    depth: usize,
+}
+
+impl Parser {
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            pos: 0,
+            line: 1,
+            column: 1,
+            depth: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            kind,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    /// Error for the current character, or for the end of input.
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.advance();
+            } else if c == ';' {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.advance();
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<TextValue, ParseError> {
+        // Nested values are parsed recursively; bound the recursion.
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::NestingTooDeep));
+        }
+
+        self.depth += 1;
+        let result = self.parse_value_unbounded();
+        self.depth -= 1;
+
+        result
+    }
+
+    fn parse_value_unbounded(&mut self) -> Result<TextValue, ParseError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('"') => self.parse_string().map(TextValue::String),
+            Some('&') => {
+                self.advance();
+                self.parse_prefixed_string().map(TextValue::StringName)
+            }
+            Some('^') => {
+                self.advance();
+                self.parse_prefixed_string().map(TextValue::NodePath)
+            }
+            Some('[') => self.parse_list('[', ']').map(TextValue::Array),
+            Some('{') => self.parse_dictionary(),
+            Some(c) if c == '-' || c == '.' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) if c == '_' || c.is_alphabetic() => self.parse_identifier_value(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// String right after `&` or `^`, without whitespace in between.
+    fn parse_prefixed_string(&mut self) -> Result<String, ParseError> {
+        if self.peek() != Some('"') {
+            return Err(self.unexpected());
+        }
+        self.parse_string()
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        self.advance(); // Opening quote.
+
+        let mut string = String::new();
+        loop {
+            let Some(c) = self.advance() else {
+                return Err(self.error(ParseErrorKind::UnexpectedEnd));
+            };
+
+            match c {
+                '"' => return Ok(string),
+                '\\' => string.push(self.parse_escape()?),
+                _ => string.push(c),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char, ParseError> {
+        let Some(c) = self.advance() else {
+            return Err(self.error(ParseErrorKind::UnexpectedEnd));
+        };
+
+        let escaped = match c {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            '"' | '\\' | '\'' | '/' => c,
+            'u' => {
+                let mut code = 0;
+                for _ in 0..4 {
+                    let digit = self
+                        .peek()
+                        .and_then(|c| c.to_digit(16))
+                        .ok_or_else(|| self.unexpected())?;
+                    self.advance();
+                    code = code * 16 + digit;
+                }
+
+                char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            _ => return Err(self.error(ParseErrorKind::InvalidEscape(c))),
+        };
+
+        Ok(escaped)
+    }
+
+    fn parse_number(&mut self) -> Result<TextValue, ParseError> {
+        let (line, column) = (self.line, self.column);
+
+        let mut number = String::new();
+        if self.peek() == Some('-') {
+            number.push('-');
+            self.advance();
+
+            // Negative infinity is written as `-inf`.
+            if self.peek().is_some_and(|c| c.is_alphabetic()) {
+                return match self.parse_identifier().as_str() {
+                    "inf" => Ok(TextValue::Float(f64::NEG_INFINITY)),
+                    ident => Err(ParseError {
+                        kind: ParseErrorKind::UnknownIdentifier(format!("-{ident}")),
+                        line,
+                        column,
+                    }),
+                };
+            }
+        }
+
+        let mut is_float = false;
+        while let Some(c) = self.peek() {
+            match c {
+                '0'..='9' => {}
+                '.' | 'e' | 'E' => is_float = true,
+                '+' | '-' if number.ends_with(['e', 'E']) => {}
+                _ => break,
+            }
+
+            number.push(c);
+            self.advance();
+        }
+
+        let parsed = if is_float {
+            number.parse().ok().map(TextValue::Float)
+        } else {
+            number.parse().ok().map(TextValue::Int)
+        };
+
+        parsed.ok_or(ParseError {
+            kind: ParseErrorKind::InvalidNumber(number),
+            line,
+            column,
+        })
+    }
+
+    fn parse_identifier(&mut self) -> String {
+        let mut ident = String::new();
+        while let Some(c) = self.peek().filter(|&c| c == '_' || c.is_alphanumeric()) {
+            ident.push(c);
+            self.advance();
+        }
+
+        ident
+    }
+
+    /// Keyword, constructor call or typed array.
+    fn parse_identifier_value(&mut self) -> Result<TextValue, ParseError> {
+        let (line, column) = (self.line, self.column);
+        let ident = self.parse_identifier();
+
+        let keyword = match ident.as_str() {
+            "null" | "nil" => Some(TextValue::Nil),
+            "true" => Some(TextValue::Bool(true)),
+            "false" => Some(TextValue::Bool(false)),
+            "inf" => Some(TextValue::Float(f64::INFINITY)),
+            "inf_neg" => Some(TextValue::Float(f64::NEG_INFINITY)),
+            "nan" => Some(TextValue::Float(f64::NAN)),
+            _ => None,
+        };
+        if let Some(keyword) = keyword {
+            return Ok(keyword);
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some('[') if ident == "Array" => {
+                self.advance();
+                self.skip_whitespace();
+                let element_type = self.parse_element_type()?;
+                self.expect(']')?;
+
+                self.expect('(')?;
+                self.skip_whitespace();
+                let elements = self.parse_list('[', ']')?;
+                self.expect(')')?;
+
+                Ok(TextValue::TypedArray {
+                    element_type,
+                    elements,
+                })
+            }
+            Some('(') => {
+                let args = self.parse_list('(', ')')?;
+                Ok(TextValue::Construct { name: ident, args })
+            }
+            _ => Err(ParseError {
+                kind: ParseErrorKind::UnknownIdentifier(ident),
+                line,
+                column,
+            }),
+        }
+    }
+
+    /// Element type of a typed array: a class or builtin name, or a script resource such as `ExtResource("1_abc")`.
+    fn parse_element_type(&mut self) -> Result<String, ParseError> {
+        let name = self.parse_identifier();
+        if name.is_empty() {
+            return Err(self.unexpected());
+        }
+
+        self.skip_whitespace();
+        if self.peek() != Some('(') {
+            return Ok(name);
+        }
+
+        let args = self.parse_list('(', ')')?;
+        Ok(TextValue::Construct { name, args }.to_string())
+    }
+
+    /// Comma-separated values between `open` and `close`; a trailing comma is allowed.
+    fn parse_list(&mut self, open: char, close: char) -> Result<Vec<TextValue>, ParseError> {
+        self.expect(open)?;
+
+        let mut values = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(close) {
+                self.advance();
+                return Ok(values);
+            }
+
+            values.push(self.parse_value()?);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.advance();
+                }
+                Some(c) if c == close => {}
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn parse_dictionary(&mut self) -> Result<TextValue, ParseError> {
+        self.expect('{')?;
+
+        let mut entries = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.advance();
+                return Ok(TextValue::Dictionary(entries));
+            }
+
+            let key = self.parse_value()?;
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.advance();
+                }
+                Some('}') => {}
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Formatting
+
+/// Formats the value in Godot's variant text format, which can be read by `str_to_var()` and [`parse()`].
+///
+/// Layout and number formatting may differ from Godot's own `var_to_str()`, e.g. `1.0` instead of `1` inside constructors.
+impl fmt::Display for TextValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nil => write!(f, "null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write_float(f, *value),
+            Self::String(value) => write_string(f, value),
+            Self::StringName(value) => {
+                write!(f, "&")?;
+                write_string(f, value)
+            }
+            Self::NodePath(value) => {
+                write!(f, "^")?;
+                write_string(f, value)
+            }
+            Self::Array(elements) => write_list(f, "[", elements, "]"),
+            Self::TypedArray {
+                element_type,
+                elements,
+            } => {
+                write!(f, "Array[{element_type}](")?;
+                write_list(f, "[", elements, "]")?;
+                write!(f, ")")
+            }
+            Self::Dictionary(entries) => {
+                if entries.is_empty() {
+                    return write!(f, "{{}}");
+                }
+
+                writeln!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    let separator = if i + 1 < entries.len() { "," } else { "" };
+                    writeln!(f, "{key}: {value}{separator}")?;
+                }
+                write!(f, "}}")
+            }
+            Self::Construct { name, args } => {
+                write!(f, "{name}")?;
+                write_list(f, "(", args, ")")
+            }
+        }
+    }
+}
+
+fn write_float(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    if value.is_nan() {
+        write!(f, "nan")
+    } else if value.is_infinite() {
+        let sign = if value < 0.0 { "-" } else { "" };
+        write!(f, "{sign}inf")
+    } else {
+        // Debug formatting always contains `.` or `e`, so the value is read back as float.
+        write!(f, "{value:?}")
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\t' => write!(f, "\\t")?,
+            '\r' => write!(f, "\\r")?,
+            _ => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+fn write_list(
+    f: &mut fmt::Formatter<'_>,
+    open: &str,
+    values: &[TextValue],
+    close: &str,
+) -> fmt::Result {
+    write!(f, "{open}")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{value}")?;
+    }
+    write!(f, "{close}")
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_scalars() {
+        assert_eq!(parse("null"), Ok(TextValue::Nil));
+        assert_eq!(parse(" true "), Ok(TextValue::Bool(true)));
+        assert_eq!(parse("-12"), Ok(TextValue::Int(-12)));
+        assert_eq!(parse("2.5"), Ok(TextValue::Float(2.5)));
+        assert_eq!(parse("1e-3"), Ok(TextValue::Float(1e-3)));
+        assert_eq!(parse("-inf"), Ok(TextValue::Float(f64::NEG_INFINITY)));
+        assert_eq!(
+            parse(r#""a\"b\né""#),
+            Ok(TextValue::String("a\"b\né".into()))
+        );
+        assert_eq!(
+            parse(r#"&"name""#),
+            Ok(TextValue::StringName("name".into()))
+        );
+        assert_eq!(
+            parse(r#"^"../Node""#),
+            Ok(TextValue::NodePath("../Node".into()))
+        );
+    }
+
+    #[test]
+    fn parse_containers() {
+        let text = r#"{
+            "pos": Vector2(1, 2.5), ; comment
+            "items": Array[int]([1, 2]),
+            3: [ExtResource("1_abc"), {}],
+        }"#;
+
+        let expected = TextValue::Dictionary(vec![
+            (
+                TextValue::String("pos".into()),
+                TextValue::Construct {
+                    name: "Vector2".into(),
+                    args: vec![TextValue::Int(1), TextValue::Float(2.5)],
+                },
+            ),
+            (
+                TextValue::String("items".into()),
+                TextValue::TypedArray {
+                    element_type: "int".into(),
+                    elements: vec![TextValue::Int(1), TextValue::Int(2)],
+                },
+            ),
+            (
+                TextValue::Int(3),
+                TextValue::Array(vec![
+                    TextValue::Construct {
+                        name: "ExtResource".into(),
+                        args: vec![TextValue::String("1_abc".into())],
+                    },
+                    TextValue::Dictionary(vec![]),
+                ]),
+            ),
+        ]);
+
+        assert_eq!(parse(text), Ok(expected));
+    }
+
+    #[test]
+    fn parse_script_typed_array() {
+        let text = r#"Array[ExtResource( "1_abc" )]([ExtResource("2_def"), null])"#;
+
+        let expected = TextValue::TypedArray {
+            element_type: r#"ExtResource("1_abc")"#.into(),
+            elements: vec![
+                TextValue::Construct {
+                    name: "ExtResource".into(),
+                    args: vec![TextValue::String("2_def".into())],
+                },
+                TextValue::Nil,
+            ],
+        };
+
+        let value = parse(text).unwrap();
+        assert_eq!(value, expected);
+        assert_eq!(parse(&value.to_string()), Ok(expected));
+
+        let err = parse("Array[]([])").unwrap_err();
+        assert_eq!(err.kind(), &ParseErrorKind::UnexpectedChar(']'));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse("[1, 2\n  3]").unwrap_err();
+        assert_eq!(err.kind(), &ParseErrorKind::UnexpectedChar('3'));
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert_eq!(err.to_string(), "2:3: unexpected character '3'");
+
+        let err = parse(r#""open"#).unwrap_err();
+        assert_eq!(err.kind(), &ParseErrorKind::UnexpectedEnd);
+
+        let err = parse("Vector2").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ParseErrorKind::UnknownIdentifier("Vector2".into())
+        );
+        assert_eq!((err.line(), err.column()), (1, 1));
+
+        let err = parse("99999999999999999999").unwrap_err();
+        assert!(matches!(err.kind(), ParseErrorKind::InvalidNumber(_)));
+
+        let err = parse("1 2").unwrap_err();
+        assert_eq!(err.kind(), &ParseErrorKind::TrailingInput('2'));
+        assert_eq!(err.column(), 3);
+    }
+
+    #[test]
+    fn parse_nesting_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+
+        let err = parse(&nested(MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(err.kind(), &ParseErrorKind::NestingTooDeep);
+        assert_eq!(err.column(), MAX_DEPTH + 1);
+
+        // Deep input must not overflow the stack, regardless of container kind.
+        let deep = "{ 1: Vector2(".repeat(100_000);
+        let err = parse(&deep).unwrap_err();
+        assert_eq!(err.kind(), &ParseErrorKind::NestingTooDeep);
+    }
+
+    #[test]
+    fn format_roundtrip() {
+        let text = r#"{ "a": [1, 2.0, &"sn", ^"np"], "b": Color(1, 0.5, 0, 1), "c": Array[String](["x\ty"]) }"#;
+        let value = parse(text).unwrap();
+
+        let formatted = value.to_string();
+        assert_eq!(parse(&formatted), Ok(value));
+        assert!(formatted.contains(r#""a": [1, 2.0, &"sn", ^"np"],"#));
+    }
+}
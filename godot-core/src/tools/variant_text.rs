@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GString, Variant};
+use crate::global;
+use crate::meta::{arg_into_ref, AsArg};
+
+/// Converts `value` to Godot's text format, as used for values in `.tres`/`.tscn` files.
+///
+/// The result can be converted back with [`str_to_var()`]. Objects are not serialized, except for their class name.
+///
+/// _Godot equivalent: `@GlobalScope.var_to_str()`_
+pub fn var_to_str(value: &Variant) -> GString {
+    global::var_to_str(value)
+}
+
+/// Parses a value in Godot's text format, e.g. the output of [`var_to_str()`] or a literal from a `.tres` file.
+///
+/// Returns `None` if `text` is not a valid value. In that case, Godot additionally prints an error with the line of the problem.
+/// For tooling that needs detailed errors or has to run without the engine, see the `variant_parser` module (requires the
+/// `variant-parser` feature).
+///
+/// _Godot equivalent: `@GlobalScope.str_to_var()`_
+pub fn str_to_var(text: impl AsArg<GString>) -> Option<Variant> {
+    arg_into_ref!(text);
+
+    let value = global::str_to_var(text);
+
+    // Godot returns nil on errors, which is indistinguishable from a parsed `null` literal.
+    if value.is_nil() && !is_null_literal(&text.to_string()) {
+        return None;
+    }
+
+    Some(value)
+}
+
+fn is_null_literal(text: &str) -> bool {
+    matches!(text.trim(), "null" | "nil")
+}
@@ -0,0 +1,194 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#[cfg(since_api = "4.2")]
+use std::future::Future;
+
+use crate::builtin::{Callable, GString, Vector2i};
+use crate::classes::sub_viewport::UpdateMode;
+use crate::classes::window::{Flags, WindowInitialPosition};
+#[cfg(since_api = "4.2")]
+use crate::classes::{Image, RenderingServer};
+use crate::classes::{Node, SubViewport, Window};
+use crate::obj::{Gd, Inherits, NewAlloc};
+
+/// Settings for a window created with [`spawn_window()`].
+///
+/// Construct with struct update syntax, starting from the defaults:
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::WindowConfig;
+///
+/// let config = WindowConfig {
+///     title: "Preview".into(),
+///     size: Vector2i::new(800, 600),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct WindowConfig {
+    /// Title shown in the window decoration.
+    pub title: GString,
+
+    /// Size of the window's content area, in pixels.
+    pub size: Vector2i,
+
+    /// Position of the window, relative to the screen (or to the embedder, for embedded windows).
+    ///
+    /// `None` centers the window on the main window's screen.
+    pub position: Option<Vector2i>,
+
+    /// Whether the user can resize the window.
+    pub resizable: bool,
+
+    /// Whether the window is shown without decoration.
+    pub borderless: bool,
+
+    /// Whether the window stays on top of its parent window and is minimized and closed with it.
+    pub transient: bool,
+
+    /// Whether the window blocks input to its parent window while open. Requires `transient`.
+    pub exclusive: bool,
+
+    /// Whether the window renders its own 3D world, instead of sharing the one of its parent viewport.
+    ///
+    /// Enable this for previews that must not show the game scene.
+    pub own_world_3d: bool,
+
+    /// Whether the window is freed when the user closes it.
+    ///
+    /// Godot does not close windows on its own; without this, you need to handle the `close_requested` signal.
+    pub free_on_close: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: GString::new(),
+            size: Vector2i::new(640, 480),
+            position: None,
+            resizable: true,
+            borderless: false,
+            transient: false,
+            exclusive: false,
+            own_world_3d: false,
+            free_on_close: true,
+        }
+    }
+}
+
+/// Creates a [`Window`] as a child of `parent`, configured according to `config`.
+///
+/// The window becomes visible as soon as `parent` is inside the scene tree. Depending on the project setting
+/// `display/window/subwindows/embed_subwindows`, it is either a native window or embedded in the parent viewport.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::Control;
+/// use godot::tools::{spawn_window, WindowConfig};
+///
+/// fn open_inspector(editor_root: &Gd<Node>, content: Gd<Control>) {
+///     let config = WindowConfig {
+///         title: "Inspector".into(),
+///         transient: true,
+///         ..Default::default()
+///     };
+///
+///     let mut window = spawn_window(editor_root, &config);
+///     window.add_child(&content);
+/// }
+/// ```
+pub fn spawn_window<P>(parent: &Gd<P>, config: &WindowConfig) -> Gd<Window>
+where
+    P: Inherits<Node>,
+{
+    let mut window = Window::new_alloc();
+
+    window.set_title(&config.title);
+    window.set_size(config.size);
+    match config.position {
+        Some(position) => {
+            window.set_initial_position(WindowInitialPosition::ABSOLUTE);
+            window.set_position(position);
+        }
+        None => window.set_initial_position(WindowInitialPosition::CENTER_MAIN_WINDOW_SCREEN),
+    }
+
+    window.set_flag(Flags::RESIZE_DISABLED, !config.resizable);
+    window.set_flag(Flags::BORDERLESS, config.borderless);
+    window.set_transient(config.transient);
+    window.set_exclusive(config.exclusive);
+    window.set_use_own_world_3d(config.own_world_3d);
+
+    if config.free_on_close {
+        let queue_free = Callable::from_object_method(&window, "queue_free");
+        window.connect("close_requested", &queue_free);
+    }
+
+    parent.clone().upcast::<Node>().add_child(&window);
+    window
+}
+
+/// Creates a [`SubViewport`] of the given size as a child of `parent`, for rendering that is not shown on screen.
+///
+/// The viewport has a transparent background and its own 3D world, so it only renders the nodes added to it. It does not redraw on its own;
+/// use [`capture_viewport()`] to render a frame and read it back.
+pub fn spawn_offscreen_viewport<P>(parent: &Gd<P>, size: Vector2i) -> Gd<SubViewport>
+where
+    P: Inherits<Node>,
+{
+    let mut viewport = SubViewport::new_alloc();
+
+    viewport.set_size(size);
+    viewport.set_transparent_background(true);
+    viewport.set_use_own_world_3d(true);
+    viewport.set_update_mode(UpdateMode::DISABLED);
+
+    parent.clone().upcast::<Node>().add_child(&viewport);
+    viewport
+}
+
+/// Renders one frame of `viewport` and returns it as an [`Image`], once the GPU has finished drawing.
+///
+/// Reading back a viewport directly after changing its content yields the previous frame (or an empty image). This function schedules a
+/// single update and waits for the `RenderingServer.frame_post_draw` signal before fetching the texture data.
+///
+/// Resolves to `None` without waiting if the viewport is not inside the scene tree, since it would never be drawn. Also resolves to
+/// `None` if the renderer provides no image data, e.g. with the dummy renderer. Note that in `--headless` mode, no frames are drawn and
+/// the future does not resolve.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::Image;
+/// use godot::tools::{capture_viewport, spawn_offscreen_viewport};
+///
+/// async fn render_thumbnail(root: Gd<Node>, model: Gd<Node3D>) -> Option<Gd<Image>> {
+///     let mut viewport = spawn_offscreen_viewport(&root, Vector2i::new(128, 128));
+///     viewport.add_child(&model);
+///
+///     let image = capture_viewport(&viewport).await;
+///     viewport.queue_free();
+///     image
+/// }
+/// ```
+#[cfg(since_api = "4.2")]
+pub fn capture_viewport(viewport: &Gd<SubViewport>) -> impl Future<Output = Option<Gd<Image>>> {
+    let mut viewport = viewport.clone();
+
+    // Subscribe before returning, so a frame drawn before the first poll is not missed.
+    let frame_drawn = viewport.is_inside_tree().then(|| {
+        viewport.set_update_mode(UpdateMode::ONCE);
+        RenderingServer::singleton().await_frame_post_draw()
+    });
+
+    async move {
+        frame_drawn?.await;
+        viewport.get_texture()?.get_image()
+    }
+}
@@ -0,0 +1,408 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use crate::builtin::{Callable, GString, Variant};
+use crate::classes::WorkerThreadPool;
+use crate::global::Error as GodotError;
+use crate::meta::{arg_into_owned, AsArg};
+use crate::tools::load_async::DeferredWaker;
+
+/// Runs a Rust closure on Godot's [`WorkerThreadPool`], returning a handle to its result.
+///
+/// Shorthand for `TaskBuilder::new().spawn(task)`; see [`TaskBuilder`] for options.
+///
+/// The closure runs on a worker thread, so it must be `Send`. It may call Godot APIs that are thread-safe on the engine side; see
+/// [`TaskBuilder`] for caveats.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::spawn_task;
+///
+/// let handle = spawn_task(|| (1..=1_000_000u64).sum::<u64>());
+///
+/// // ... do other work on the main thread ...
+///
+/// let sum = handle.join();
+/// assert_eq!(sum, 500_000_500_000);
+/// ```
+pub fn spawn_task<F, R>(task: F) -> TaskHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    TaskBuilder::new().spawn(task)
+}
+
+/// Runs a Rust closure on Godot's [`WorkerThreadPool`] once for each index in `0..element_count`.
+///
+/// Shorthand for `TaskBuilder::new().spawn_group(element_count, task)`; see [`TaskBuilder`] for options.
+///
+/// Invocations are distributed across worker threads and may run concurrently, so the closure must be `Send + Sync`.
+///
+/// # Example
+/// ```no_run
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::sync::Arc;
+/// use godot::tools::spawn_group_task;
+///
+/// let total = Arc::new(AtomicU64::new(0));
+/// let shared = total.clone();
+///
+/// spawn_group_task(100, move |index| {
+///     shared.fetch_add(index as u64, Ordering::Relaxed);
+/// })
+/// .join();
+///
+/// assert_eq!(total.load(Ordering::Relaxed), 4950);
+/// ```
+pub fn spawn_group_task<F>(element_count: u32, task: F) -> GroupTaskHandle
+where
+    F: Fn(u32) + Send + Sync + 'static,
+{
+    TaskBuilder::new().spawn_group(element_count, task)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Configures tasks submitted to Godot's [`WorkerThreadPool`].
+///
+/// # Threading
+/// This API requires the `experimental-threads` feature, because worker threads call back into Rust and may use the Godot FFI. Without
+/// the feature, godot-rust assumes that all Godot calls happen on the main thread, and panics otherwise in Debug builds.
+///
+/// Even with the feature, most Godot APIs are not thread-safe on the engine side. Inside a task, stick to Rust code and thread-safe Godot
+/// functionality (e.g. servers, or objects not yet added to the scene tree). In particular:
+/// - Don't access nodes that are in the scene tree.
+/// - Don't call `bind()`/`bind_mut()` on objects that may be used concurrently from another thread.
+/// - Send results back through the task's return value, or through Rust synchronization primitives.
+///
+/// # Completion
+/// Godot requires every task to be waited for, so it can release its resources. [`TaskHandle`] and [`GroupTaskHandle`] do this on
+/// [`join()`][TaskHandle::join], when awaited, or on drop -- in the latter case, dropping blocks the calling thread until the task has
+/// finished. Handles should therefore be kept and awaited/joined, not dropped early.
+///
+/// Panics inside a task are caught on the worker thread and resumed when the handle is joined or awaited.
+#[derive(Clone, Debug, Default)]
+pub struct TaskBuilder {
+    high_priority: bool,
+    description: GString,
+    tasks_needed: Option<u32>,
+}
+
+impl TaskBuilder {
+    /// Creates a builder for a normal-priority task without description.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedules the task before normal-priority tasks.
+    pub fn high_priority(mut self, high_priority: bool) -> Self {
+        self.high_priority = high_priority;
+        self
+    }
+
+    /// Sets a description, which is shown in Godot's debugger/profiler.
+    pub fn description(mut self, description: impl AsArg<GString>) -> Self {
+        arg_into_owned!(description);
+        self.description = description;
+        self
+    }
+
+    /// For group tasks: the maximum number of worker threads processing the elements. By default, all available workers are used.
+    ///
+    /// Ignored by [`spawn()`][Self::spawn].
+    pub fn tasks_needed(mut self, tasks_needed: u32) -> Self {
+        self.tasks_needed = Some(tasks_needed);
+        self
+    }
+
+    /// Submits a single task.
+    ///
+    /// _Godot equivalent: `WorkerThreadPool.add_task()`_
+    pub fn spawn<F, R>(self, task: F) -> TaskHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let result = Arc::new(Mutex::new(None));
+
+        let task = Mutex::new(Some(task));
+        let task_result = result.clone();
+        let callable = Callable::from_sync_fn(&callable_name(&self.description), move |_args| {
+            let task = lock(&task)
+                .take()
+                .expect("WorkerThreadPool runs each task once");
+
+            let outcome = panic::catch_unwind(AssertUnwindSafe(task));
+            *lock(&task_result) = Some(outcome);
+
+            Ok(Variant::nil())
+        });
+
+        let task_id = WorkerThreadPool::singleton()
+            .add_task_ex(&callable)
+            .high_priority(self.high_priority)
+            .description(&self.description)
+            .done();
+
+        TaskHandle {
+            task_id,
+            result,
+            waited: false,
+            waker: None,
+        }
+    }
+
+    /// Submits a group task, invoking `task` once for each index in `0..element_count`.
+    ///
+    /// _Godot equivalent: `WorkerThreadPool.add_group_task()`_
+    pub fn spawn_group<F>(self, element_count: u32, task: F) -> GroupTaskHandle
+    where
+        F: Fn(u32) + Send + Sync + 'static,
+    {
+        let element_count = i32::try_from(element_count).expect("element count must fit in i32");
+        let tasks_needed = self.tasks_needed.map_or(-1, |tasks| {
+            i32::try_from(tasks).expect("tasks_needed must fit in i32")
+        });
+
+        let first_panic = Arc::new(Mutex::new(None));
+
+        let task_panic = first_panic.clone();
+        // Godot invokes the group callable from several worker threads at once, so it must never be accessed through `&mut`.
+        let callable =
+            Callable::from_concurrent_fn(callable_name(&self.description), move |args| {
+                let index = args
+                    .first()
+                    .and_then(|arg| arg.try_to::<u32>().ok())
+                    .ok_or(())?;
+
+                if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| task(index))) {
+                    // Keep the first panic; later ones are likely follow-up errors.
+                    lock(&task_panic).get_or_insert(payload);
+                }
+
+                Ok(Variant::nil())
+            });
+
+        let group_id = WorkerThreadPool::singleton()
+            .add_group_task_ex(&callable, element_count)
+            .tasks_needed(tasks_needed)
+            .high_priority(self.high_priority)
+            .description(&self.description)
+            .done();
+
+        GroupTaskHandle {
+            group_id,
+            first_panic,
+            waited: false,
+            waker: None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Handle to a task submitted with [`spawn_task()`] or [`TaskBuilder::spawn()`].
+///
+/// Can be joined (blocking) or awaited. The future polls the task once per frame, so it can be driven by any executor on the main thread.
+///
+/// Dropping the handle blocks until the task has finished, because Godot requires each task to be waited for.
+#[must_use = "dropping a TaskHandle blocks until the task has finished"]
+pub struct TaskHandle<R> {
+    task_id: i64,
+    result: Arc<Mutex<Option<TaskOutcome<R>>>>,
+    waited: bool,
+    waker: Option<DeferredWaker>,
+}
+
+impl<R> TaskHandle<R> {
+    /// ID of the task in Godot's `WorkerThreadPool`.
+    pub fn task_id(&self) -> i64 {
+        self.task_id
+    }
+
+    /// Returns whether the task has finished running, without blocking.
+    ///
+    /// _Godot equivalent: `WorkerThreadPool.is_task_completed()`_
+    pub fn is_finished(&self) -> bool {
+        self.waited || WorkerThreadPool::singleton().is_task_completed(self.task_id)
+    }
+
+    /// Blocks until the task has finished, and returns its result.
+    ///
+    /// # Panics
+    /// If the task itself panicked, the panic is resumed on the calling thread.
+    ///
+    /// _Godot equivalent: `WorkerThreadPool.wait_for_task_completion()`_
+    pub fn join(mut self) -> R {
+        self.wait();
+        self.take_result()
+    }
+
+    fn wait(&mut self) {
+        if self.waited {
+            return;
+        }
+        self.waited = true;
+
+        let error = WorkerThreadPool::singleton().wait_for_task_completion(self.task_id);
+        assert_eq!(
+            error,
+            GodotError::OK,
+            "failed to wait for WorkerThreadPool task {}",
+            self.task_id
+        );
+    }
+
+    fn take_result(&mut self) -> R {
+        let outcome = lock(&self.result)
+            .take()
+            .expect("task result is available after completion");
+
+        outcome.unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+}
+
+impl<R> Future for TaskHandle<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // No field is structurally pinned.
+        let this = self.get_mut();
+
+        if !this.is_finished() {
+            this.waker
+                .get_or_insert_with(DeferredWaker::new)
+                .wake_next_frame(cx.waker());
+            return Poll::Pending;
+        }
+
+        // Doesn't block anymore, but releases the task in Godot.
+        this.wait();
+        Poll::Ready(this.take_result())
+    }
+}
+
+impl<R> Drop for TaskHandle<R> {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Handle to a group task submitted with [`spawn_group_task()`] or [`TaskBuilder::spawn_group()`].
+///
+/// Can be joined (blocking) or awaited. The future polls the task once per frame, so it can be driven by any executor on the main thread.
+///
+/// Dropping the handle blocks until all elements have been processed, because Godot requires each task to be waited for.
+#[must_use = "dropping a GroupTaskHandle blocks until the task has finished"]
+pub struct GroupTaskHandle {
+    group_id: i64,
+    first_panic: Arc<Mutex<Option<Box<dyn Any + Send>>>>,
+    waited: bool,
+    waker: Option<DeferredWaker>,
+}
+
+impl GroupTaskHandle {
+    /// ID of the group task in Godot's `WorkerThreadPool`.
+    pub fn group_id(&self) -> i64 {
+        self.group_id
+    }
+
+    /// Number of elements that have been processed so far.
+    ///
+    /// _Godot equivalent: `WorkerThreadPool.get_group_processed_element_count()`_
+    pub fn processed_count(&self) -> u32 {
+        WorkerThreadPool::singleton().get_group_processed_element_count(self.group_id)
+    }
+
+    /// Returns whether all elements have been processed, without blocking.
+    ///
+    /// _Godot equivalent: `WorkerThreadPool.is_group_task_completed()`_
+    pub fn is_finished(&self) -> bool {
+        self.waited || WorkerThreadPool::singleton().is_group_task_completed(self.group_id)
+    }
+
+    /// Blocks until all elements have been processed.
+    ///
+    /// # Panics
+    /// If the task panicked for any element, the first such panic is resumed on the calling thread.
+    ///
+    /// _Godot equivalent: `WorkerThreadPool.wait_for_group_task_completion()`_
+    pub fn join(mut self) {
+        self.wait();
+        self.resume_panic();
+    }
+
+    fn wait(&mut self) {
+        if self.waited {
+            return;
+        }
+        self.waited = true;
+
+        WorkerThreadPool::singleton().wait_for_group_task_completion(self.group_id);
+    }
+
+    fn resume_panic(&mut self) {
+        if let Some(payload) = lock(&self.first_panic).take() {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+impl Future for GroupTaskHandle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if !this.is_finished() {
+            this.waker
+                .get_or_insert_with(DeferredWaker::new)
+                .wake_next_frame(cx.waker());
+            return Poll::Pending;
+        }
+
+        this.wait();
+        this.resume_panic();
+        Poll::Ready(())
+    }
+}
+
+impl Drop for GroupTaskHandle {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type TaskOutcome<R> = std::thread::Result<R>;
+
+fn callable_name(description: &GString) -> GString {
+    if description.is_empty() {
+        GString::from("WorkerThreadPool task")
+    } else {
+        description.clone()
+    }
+}
+
+/// Locks ignoring poisoning; panics in tasks are caught before they can poison.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
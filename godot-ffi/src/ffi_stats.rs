@@ -0,0 +1,184 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Per-method counters and timers for outbound FFI calls (Rust -> Godot), enabled by the `ffi-stats` feature.
+//!
+//! Every call through a generated class, builtin or utility function is recorded under its `Class::method` name, together with the
+//! time it took -- including argument marshalling and return value conversion, i.e. the full cost as observed from Rust.
+//!
+//! Without the feature, the instrumentation is not compiled in at all.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+use crate::Global;
+
+type Key = (&'static str, &'static str);
+
+static STATS: Global<HashMap<Key, Entry>> = Global::default();
+
+#[derive(Copy, Clone, Default)]
+struct Entry {
+    count: u64,
+    total_time: Duration,
+}
+
+/// Accumulated statistics for one engine method.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallStats {
+    /// Class or builtin type of the method; empty for utility functions.
+    pub class_name: &'static str,
+
+    /// Name of the method or utility function.
+    pub method_name: &'static str,
+
+    /// Number of calls.
+    pub count: u64,
+
+    /// Sum of the durations of all calls.
+    pub total_time: Duration,
+}
+
+impl CallStats {
+    /// Average duration of a single call.
+    pub fn average_time(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total_time.div_f64(self.count as f64)
+        }
+    }
+}
+
+/// Order of the results returned by [`stats()`] and [`dump()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    /// Highest total time first.
+    TotalTime,
+
+    /// Highest number of calls first.
+    Count,
+}
+
+/// Returns the statistics recorded so far, for all methods that have been called at least once.
+pub fn stats(sort_by: SortBy) -> Vec<CallStats> {
+    let mut stats: Vec<CallStats> = STATS
+        .lock()
+        .iter()
+        .map(|(&(class_name, method_name), entry)| CallStats {
+            class_name,
+            method_name,
+            count: entry.count,
+            total_time: entry.total_time,
+        })
+        .collect();
+
+    match sort_by {
+        SortBy::TotalTime => stats.sort_by(|a, b| b.total_time.cmp(&a.total_time)),
+        SortBy::Count => stats.sort_by(|a, b| b.count.cmp(&a.count)),
+    }
+
+    stats
+}
+
+/// Formats the `limit` top entries of [`stats()`] as a human-readable table.
+pub fn dump(sort_by: SortBy, limit: usize) -> String {
+    let mut out = format!(
+        "{:<60} {:>12} {:>14} {:>12}\n",
+        "method", "calls", "total", "average"
+    );
+
+    for entry in stats(sort_by).into_iter().take(limit) {
+        let name = if entry.class_name.is_empty() {
+            entry.method_name.to_string()
+        } else {
+            format!("{}::{}", entry.class_name, entry.method_name)
+        };
+
+        let _ = writeln!(
+            out,
+            "{name:<60} {:>12} {:>14.3?} {:>12.3?}",
+            entry.count,
+            entry.total_time,
+            entry.average_time()
+        );
+    }
+
+    out
+}
+
+/// Discards all statistics recorded so far, e.g. to measure only a specific section of code.
+pub fn reset() {
+    STATS.lock().clear();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Recording (used by generated code and godot-core)
+
+/// Measures one call from creation until drop.
+#[doc(hidden)]
+pub struct CallTimer {
+    key: Key,
+    start: Instant,
+}
+
+impl CallTimer {
+    #[inline]
+    pub fn start(class_name: &'static str, method_name: &'static str) -> Self {
+        Self {
+            key: (class_name, method_name),
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for CallTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+
+        let mut stats = STATS.lock();
+        let entry = stats.entry(self.key).or_default();
+        entry.count += 1;
+        entry.total_time += elapsed;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_and_sort() {
+        reset();
+
+        for _ in 0..3 {
+            let _timer = CallTimer::start("Node", "get_name");
+        }
+        {
+            let _timer = CallTimer::start("", "print");
+            std::thread::sleep(Duration::from_millis(2));
+        }
+
+        let by_count = stats(SortBy::Count);
+        assert_eq!(by_count.len(), 2);
+        assert_eq!(by_count[0].method_name, "get_name");
+        assert_eq!(by_count[0].count, 3);
+
+        let by_time = stats(SortBy::TotalTime);
+        assert_eq!(by_time[0].method_name, "print");
+
+        let table = dump(SortBy::Count, 1);
+        assert!(table.contains("Node::get_name"));
+        assert!(!table.contains("print"));
+
+        reset();
+        assert!(stats(SortBy::Count).is_empty());
+    }
+}
@@ -21,13 +21,14 @@ use crate as sys;
 #[cfg(target_family = "wasm")]
 pub fn ensure_static_runtime_compatibility(
     _get_proc_address: sys::GDExtensionInterfaceGetProcAddress,
-) {
+) -> Result<(), CompatibilityError> {
+    Ok(())
 }
 
 #[cfg(not(target_family = "wasm"))]
 pub fn ensure_static_runtime_compatibility(
     get_proc_address: sys::GDExtensionInterfaceGetProcAddress,
-) {
+) -> Result<(), CompatibilityError> {
     let static_version_str = crate::GdextBuild::godot_static_version_string();
 
     // In Godot 4.0.x, before the new GetProcAddress mechanism, the init function looked as follows.
@@ -72,13 +73,11 @@ pub fn ensure_static_runtime_compatibility(
             let data_ptr = get_proc_address as *const sys::GDExtensionGodotVersion;
             let runtime_version_str = unsafe { read_version_string(&data_ptr.read()) };
 
-            panic!(
-                "gdext was compiled against a newer Godot version: {static_version_str}\n\
-                but loaded by legacy Godot binary, with version:  {runtime_version_str}\n\
-                \n\
-                Update your Godot engine version, or read https://godot-rust.github.io/book/toolchain/compatibility.html.\n\
-                \n"
-            );
+            return Err(CompatibilityError {
+                kind: CompatibilityErrorKind::LegacyRuntime,
+                compiled_version: static_version_str.to_string(),
+                runtime_version: runtime_version_str,
+            });
         }
     }
 
@@ -99,15 +98,14 @@ pub fn ensure_static_runtime_compatibility(
     if runtime_version < static_version {
         let runtime_version_str = read_version_string(&runtime_version_raw);
 
-        panic!(
-            "gdext was compiled against newer Godot version: {static_version_str}\n\
-            but loaded by older Godot binary, with version: {runtime_version_str}\n\
-            \n\
-            Update your Godot engine version, or compile gdext against an older version.\n\
-            For more information, read https://godot-rust.github.io/book/toolchain/compatibility.html.\n\
-            \n"
-        );
+        return Err(CompatibilityError {
+            kind: CompatibilityErrorKind::OlderRuntime,
+            compiled_version: static_version_str.to_string(),
+            runtime_version: runtime_version_str,
+        });
     }
+
+    Ok(())
 }
 
 pub unsafe fn runtime_version(
@@ -141,6 +139,50 @@ unsafe fn runtime_version_inner(
     unsafe { version.assume_init() }
 }
 
+/// Reports a failed compatibility check through Godot's error log, before the binding is initialized.
+///
+/// If `notify_editor` is true, the editor additionally shows the error as a notification.
+///
+/// # Safety
+/// `get_proc_address` must be the pointer passed by Godot to the entry point.
+pub unsafe fn report_load_error(
+    get_proc_address: sys::GDExtensionInterfaceGetProcAddress,
+    error: &CompatibilityError,
+    notify_editor: bool,
+) {
+    let message = error.to_string();
+
+    // A legacy (4.0) runtime passes a data pointer instead of `get_proc_address`, so Godot's functions are not reachable.
+    let get_proc_address = match get_proc_address {
+        Some(f) if error.kind != CompatibilityErrorKind::LegacyRuntime => f,
+        _ => {
+            eprintln!("ERROR: {message}");
+            return;
+        }
+    };
+
+    // SAFETY: `get_proc_address` is valid for 4.1+ runtimes, which all provide `print_error_with_message`.
+    let print_error = unsafe { get_proc_address(sys::c_str(b"print_error_with_message\0")) };
+
+    // SAFETY: `sys::GDExtensionInterfacePrintErrorWithMessage` is an `Option` of an `unsafe extern "C"` function pointer.
+    let print_error =
+        crate::unsafe_cast_fn_ptr!(print_error as sys::GDExtensionInterfacePrintErrorWithMessage);
+
+    let message = std::ffi::CString::new(message.replace('\0', "")).expect("NUL bytes removed");
+
+    // SAFETY: all strings are valid, NUL-terminated and outlive the call.
+    unsafe {
+        print_error(
+            sys::c_str(b"godot-rust extension is incompatible with the running Godot version\0"),
+            message.as_ptr(),
+            sys::c_str(b"ensure_static_runtime_compatibility\0"),
+            sys::c_str_from_str(concat!(file!(), "\0")),
+            line!() as i32,
+            notify_editor as sys::GDExtensionBool,
+        )
+    };
+}
+
 pub unsafe fn load_interface(
     get_proc_address: sys::GDExtensionInterfaceGetProcAddress,
 ) -> sys::GDExtensionInterface {
@@ -159,3 +201,142 @@ fn read_version_string(version_ptr: &sys::GDExtensionGodotVersion) -> String {
         .unwrap_or(&String::from_utf8_lossy(c_str.to_bytes()))
         .to_string()
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Compatibility error
+
+/// The extension cannot run on the Godot binary that loaded it.
+///
+/// Provides the Godot version which godot-rust was compiled against, the version running, and advice how to resolve the mismatch.
+/// The `Display` impl combines all of these into a message suitable for end users.
+///
+/// See [`ExtensionLibrary::on_compatibility_error()`](trait.ExtensionLibrary.html#method.on_compatibility_error)
+/// to intercept such errors.
+#[derive(Clone, Debug)]
+pub struct CompatibilityError {
+    kind: CompatibilityErrorKind,
+    compiled_version: String,
+    runtime_version: String,
+}
+
+impl CompatibilityError {
+    /// Error for a Godot binary built with a different floating-point precision than godot-rust.
+    ///
+    /// # Safety
+    /// The binding must be initialized, and this must be called from the main thread.
+    #[doc(hidden)]
+    pub unsafe fn precision_mismatch(godot_is_double: bool, gdext_is_double: bool) -> Self {
+        // SAFETY: binding is initialized, as per precondition.
+        let runtime_version =
+            unsafe { read_version_string(&sys::runtime_metadata().godot_version) };
+        let precision = |is_double: bool| if is_double { "double" } else { "single" };
+
+        Self {
+            kind: CompatibilityErrorKind::PrecisionMismatch,
+            compiled_version: format!(
+                "{} ({} precision)",
+                crate::GdextBuild::godot_static_version_string(),
+                precision(gdext_is_double)
+            ),
+            runtime_version: format!(
+                "{runtime_version} ({} precision)",
+                precision(godot_is_double)
+            ),
+        }
+    }
+
+    /// What kind of incompatibility was detected.
+    pub fn kind(&self) -> CompatibilityErrorKind {
+        self.kind
+    }
+
+    /// The Godot version that godot-rust was compiled against, e.g. `4.3.stable.official`.
+    pub fn compiled_version(&self) -> &str {
+        &self.compiled_version
+    }
+
+    /// The version of the Godot binary that loaded the extension.
+    pub fn runtime_version(&self) -> &str {
+        &self.runtime_version
+    }
+
+    /// Suggestion how the user can resolve the error.
+    pub fn advice(&self) -> &'static str {
+        match self.kind {
+            CompatibilityErrorKind::LegacyRuntime => {
+                "Godot 4.0 is no longer supported. Update your Godot engine to the version listed above, or newer."
+            }
+            CompatibilityErrorKind::OlderRuntime => {
+                "Update your Godot engine to the version listed above (or newer), \
+                or compile the extension against an older API with one of the `api-4-*` Cargo features."
+            }
+            CompatibilityErrorKind::PrecisionMismatch => {
+                "The Cargo feature `double-precision` must be used if and only if Godot is compiled with `precision=double`."
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CompatibilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let problem = match self.kind {
+            CompatibilityErrorKind::LegacyRuntime => "was loaded by a legacy Godot binary",
+            CompatibilityErrorKind::OlderRuntime => "was compiled against a newer Godot version",
+            CompatibilityErrorKind::PrecisionMismatch => {
+                "uses a different floating-point precision than Godot"
+            }
+        };
+
+        write!(
+            f,
+            "This Rust extension {problem}.\n\
+            \n\
+            Compiled against:  Godot {compiled}\n\
+            Running on:        Godot {runtime}\n\
+            \n\
+            {advice}\n\
+            For more information, read https://godot-rust.github.io/book/toolchain/compatibility.html.",
+            compiled = self.compiled_version,
+            runtime = self.runtime_version,
+            advice = self.advice(),
+        )
+    }
+}
+
+impl std::error::Error for CompatibilityError {}
+
+/// Reason why an extension is incompatible with the running Godot binary.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum CompatibilityErrorKind {
+    /// Godot 4.0.x, which uses a GDExtension interface that is no longer supported.
+    LegacyRuntime,
+
+    /// Godot is older than the API version godot-rust was compiled against.
+    OlderRuntime,
+
+    /// Godot and godot-rust disagree on single/double precision for floats.
+    PrecisionMismatch,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compatibility_error_message() {
+        let error = CompatibilityError {
+            kind: CompatibilityErrorKind::OlderRuntime,
+            compiled_version: "4.3.stable.official".to_string(),
+            runtime_version: "4.2.2.stable.official".to_string(),
+        };
+
+        let message = error.to_string();
+        assert!(message.contains("compiled against a newer Godot version"));
+        assert!(message.contains("Compiled against:  Godot 4.3.stable.official"));
+        assert!(message.contains("Running on:        Godot 4.2.2.stable.official"));
+        assert!(message.contains(error.advice()));
+    }
+}
@@ -32,13 +32,19 @@ pub(crate) mod gen {
 pub mod conv;
 
 mod extras;
+#[cfg(feature = "ffi-stats")]
+pub mod ffi_stats;
 mod global;
 mod godot_ffi;
 mod interface_init;
 #[cfg(target_os = "linux")]
 pub mod linux_reload_workaround;
+#[cfg(feature = "mem-stats")]
+pub mod mem_stats;
 mod opaque;
 mod plugins;
+#[cfg(all(feature = "debug-ptrcall-checks", debug_assertions))]
+pub mod ptrcall_check;
 mod string_cache;
 mod toolbox;
 
@@ -69,6 +75,7 @@ pub use gen::central::*;
 pub use gen::gdextension_interface::*;
 pub use gen::interface::*;
 pub use global::*;
+pub use interface_init::{report_load_error, CompatibilityError, CompatibilityErrorKind};
 pub use string_cache::StringCache;
 pub use toolbox::*;
 
@@ -155,11 +162,14 @@ unsafe impl Send for GdextRuntimeMetadata {}
 /// - The `library` pointer must be the pointer given by Godot at initialisation.
 /// - This function must not be called from multiple threads.
 /// - This function must be called before any use of [`get_library`].
+///
+/// Returns an error if the Godot binary is incompatible with the API version gdext was compiled against. In that case, the
+/// binding is not initialized.
 pub unsafe fn initialize(
     get_proc_address: GDExtensionInterfaceGetProcAddress,
     library: GDExtensionClassLibraryPtr,
     config: GdextConfig,
-) {
+) -> Result<(), CompatibilityError> {
     out!("Initialize gdext...");
 
     out!(
@@ -167,8 +177,8 @@ pub unsafe fn initialize(
         GdextBuild::godot_static_version_string()
     );
 
-    // Before anything else: if we run into a Godot binary that's compiled differently from gdext, proceeding would be UB -> panic.
-    interface_init::ensure_static_runtime_compatibility(get_proc_address);
+    // Before anything else: if we run into a Godot binary that's compiled differently from gdext, proceeding would be UB -> abort.
+    interface_init::ensure_static_runtime_compatibility(get_proc_address)?;
 
     // SAFETY: `ensure_static_runtime_compatibility` succeeded.
     let version = unsafe { interface_init::runtime_version(get_proc_address) };
@@ -239,6 +249,8 @@ pub unsafe fn initialize(
     }
 
     print_preamble(version);
+
+    Ok(())
 }
 
 /// Deinitializes the library.
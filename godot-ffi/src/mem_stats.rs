@@ -0,0 +1,488 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Accounting for memory allocated through Godot's allocator, enabled by the `mem-stats` feature.
+//!
+//! [`alloc()`], [`realloc()`] and [`free()`] wrap the GDExtension `mem_alloc`, `mem_realloc` and `mem_free` functions. Every allocation
+//! is attributed to a category, which is chosen with [`scope()`] -- e.g. one per subsystem, to compare against a memory budget. The
+//! current totals are available through [`totals()`], and a hook installed with [`set_hook()`] observes each individual event.
+//!
+//! Allocations that the engine performs internally (for example when constructing a `Gd<Node>` or resizing an `Array`) do not pass
+//! through these functions. To cover them, each scope additionally samples the engine's static memory usage
+//! (`Os::get_static_memory_usage()`) when it is entered and left, and attributes the difference to its category as
+//! [`engine_bytes`][CategoryTotals::engine_bytes]. Nested scopes are subtracted from their parent, so each byte is counted once.
+//! The engine number is process-wide: allocations of other threads running at the same time end up in the measuring scope, too.
+//! It is only available in engine builds that track memory (debug builds), and stays 0 otherwise.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+
+use crate::Global;
+
+/// Category of allocations made outside any [`scope()`].
+pub const DEFAULT_CATEGORY: &str = "default";
+
+static STATE: Global<State> = Global::default();
+
+#[derive(Default)]
+struct State {
+    /// Size and category of each live allocation, by address.
+    live: HashMap<usize, (usize, &'static str)>,
+    categories: HashMap<&'static str, Entry>,
+    hook: Option<fn(&MemoryEvent)>,
+    engine_usage: Option<fn() -> u64>,
+}
+
+#[derive(Copy, Clone, Default)]
+struct Entry {
+    current_bytes: usize,
+    peak_bytes: usize,
+    live_allocations: usize,
+    total_allocations: u64,
+    engine_bytes: i64,
+}
+
+struct ActiveScope {
+    category: &'static str,
+
+    /// Engine memory usage when the scope was entered, if a source is installed.
+    engine_start: Option<u64>,
+
+    /// Engine memory change already attributed to scopes nested inside this one.
+    nested_engine_bytes: i64,
+}
+
+thread_local! {
+    static SCOPES: RefCell<Vec<ActiveScope>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Memory statistics for one category.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryTotals {
+    /// Name passed to [`scope()`], or [`DEFAULT_CATEGORY`].
+    pub category: &'static str,
+
+    /// Bytes currently allocated.
+    pub current_bytes: usize,
+
+    /// Highest value of `current_bytes` since the first allocation or the last [`reset_peaks()`].
+    pub peak_bytes: usize,
+
+    /// Number of allocations not yet freed.
+    pub live_allocations: usize,
+
+    /// Number of allocations made so far, including reallocations.
+    pub total_allocations: u64,
+
+    /// Net change of the engine's static memory usage while a scope of this category was active, excluding nested scopes.
+    ///
+    /// Negative if the category released more engine memory than it allocated.
+    pub engine_bytes: i64,
+}
+
+/// A single allocator call, passed to the hook installed with [`set_hook()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryEvent {
+    pub kind: MemoryEventKind,
+
+    /// Address of the memory after the call; for [`Free`][MemoryEventKind::Free], the address that was freed.
+    pub ptr: *mut c_void,
+
+    /// Size of the allocation after the call; for [`Free`][MemoryEventKind::Free], the size that was released.
+    pub bytes: usize,
+
+    /// Category the memory is attributed to.
+    pub category: &'static str,
+}
+
+/// Kind of a [`MemoryEvent`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MemoryEventKind {
+    Alloc,
+
+    /// Reallocation, possibly moving the memory. `old_bytes` is the previous size.
+    Realloc {
+        old_ptr: *mut c_void,
+        old_bytes: usize,
+    },
+
+    Free,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Allocation
+
+/// Allocates `bytes` through Godot's allocator, attributing them to the current [`scope()`].
+///
+/// Returns null if the allocation fails.
+///
+/// # Safety
+/// The returned memory must be released with [`free()`] (or handed to the engine, which then takes ownership) and not with Rust's
+/// allocator. Must not be called before the GDExtension interface is loaded.
+pub unsafe fn alloc(bytes: usize) -> *mut c_void {
+    let ptr = crate::interface_fn!(mem_alloc)(bytes);
+
+    if !ptr.is_null() {
+        record_alloc(ptr, bytes, current_category());
+    }
+    ptr
+}
+
+/// Resizes memory previously returned by [`alloc()`] or `realloc()`.
+///
+/// The allocation keeps its original category. A null `ptr` behaves like [`alloc()`]. Returns null if the allocation fails, in which case
+/// `ptr` stays valid.
+///
+/// # Safety
+/// `ptr` must be null or live memory allocated by Godot's allocator. Must not be called before the GDExtension interface is loaded.
+pub unsafe fn realloc(ptr: *mut c_void, bytes: usize) -> *mut c_void {
+    let new_ptr = crate::interface_fn!(mem_realloc)(ptr, bytes);
+
+    if !new_ptr.is_null() {
+        record_realloc(ptr, new_ptr, bytes, current_category());
+    }
+    new_ptr
+}
+
+/// Releases memory allocated by Godot's allocator.
+///
+/// Memory that was not allocated through [`alloc()`] or [`realloc()`] is released, but not recorded.
+///
+/// # Safety
+/// `ptr` must be live memory allocated by Godot's allocator, and not be used afterward. Must not be called before the GDExtension
+/// interface is loaded.
+pub unsafe fn free(ptr: *mut c_void) {
+    record_free(ptr);
+    crate::interface_fn!(mem_free)(ptr);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Categories and queries
+
+/// Attributes allocations on the current thread to `category`, until the returned guard is dropped.
+///
+/// Scopes can be nested; the innermost one applies.
+pub fn scope(category: &'static str) -> CategoryScope {
+    let engine_start = sample_engine_usage();
+    SCOPES.with_borrow_mut(|scopes| {
+        scopes.push(ActiveScope {
+            category,
+            engine_start,
+            nested_engine_bytes: 0,
+        })
+    });
+
+    CategoryScope { _private: () }
+}
+
+/// Guard returned by [`scope()`].
+#[must_use = "the category only applies while the guard is alive"]
+pub struct CategoryScope {
+    _private: (),
+}
+
+impl Drop for CategoryScope {
+    fn drop(&mut self) {
+        let engine_end = sample_engine_usage();
+        let Some(scope) = SCOPES.with_borrow_mut(|scopes| scopes.pop()) else {
+            return;
+        };
+
+        let (Some(start), Some(end)) = (scope.engine_start, engine_end) else {
+            return;
+        };
+
+        let delta = end as i64 - start as i64;
+        STATE
+            .lock()
+            .categories
+            .entry(scope.category)
+            .or_default()
+            .engine_bytes += delta - scope.nested_engine_bytes;
+
+        SCOPES.with_borrow_mut(|scopes| {
+            if let Some(parent) = scopes.last_mut() {
+                parent.nested_engine_bytes += delta;
+            }
+        });
+    }
+}
+
+/// Returns the statistics of all categories that had at least one allocation or engine measurement, ordered by current size
+/// (largest first).
+pub fn totals() -> Vec<CategoryTotals> {
+    let mut totals: Vec<CategoryTotals> = STATE
+        .lock()
+        .categories
+        .iter()
+        .map(|(&category, entry)| CategoryTotals {
+            category,
+            current_bytes: entry.current_bytes,
+            peak_bytes: entry.peak_bytes,
+            live_allocations: entry.live_allocations,
+            total_allocations: entry.total_allocations,
+            engine_bytes: entry.engine_bytes,
+        })
+        .collect();
+
+    totals.sort_by(|a, b| {
+        b.current_bytes
+            .cmp(&a.current_bytes)
+            .then(a.category.cmp(b.category))
+    });
+    totals
+}
+
+/// Bytes currently allocated in `category`.
+pub fn current_bytes(category: &str) -> usize {
+    STATE
+        .lock()
+        .categories
+        .get(category)
+        .map_or(0, |entry| entry.current_bytes)
+}
+
+/// Net engine memory attributed to `category`, see [`CategoryTotals::engine_bytes`].
+pub fn engine_bytes(category: &str) -> i64 {
+    STATE
+        .lock()
+        .categories
+        .get(category)
+        .map_or(0, |entry| entry.engine_bytes)
+}
+
+/// Bytes currently allocated across all categories.
+pub fn total_current_bytes() -> usize {
+    STATE
+        .lock()
+        .categories
+        .values()
+        .map(|entry| entry.current_bytes)
+        .sum()
+}
+
+/// Sets each category's peak to its current size, e.g. to measure the peak of a specific section of code.
+pub fn reset_peaks() {
+    for entry in STATE.lock().categories.values_mut() {
+        entry.peak_bytes = entry.current_bytes;
+    }
+}
+
+/// Installs `hook` to be called after each allocator call, or removes it with `None`.
+///
+/// The hook runs on the thread performing the allocation, without any lock held, so it may query [`totals()`]. It must not allocate
+/// through this module itself.
+pub fn set_hook(hook: Option<fn(&MemoryEvent)>) {
+    STATE.lock().hook = hook;
+}
+
+/// Installs the function that reports the engine's static memory usage, sampled by [`scope()`].
+///
+/// Set by godot-core while the engine is available.
+#[doc(hidden)]
+pub fn set_engine_usage_source(source: Option<fn() -> u64>) {
+    STATE.lock().engine_usage = source;
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Recording
+
+fn current_category() -> &'static str {
+    SCOPES.with_borrow(|scopes| {
+        scopes
+            .last()
+            .map_or(DEFAULT_CATEGORY, |scope| scope.category)
+    })
+}
+
+fn sample_engine_usage() -> Option<u64> {
+    // Call the source without holding the lock, as it may allocate.
+    let source = STATE.lock().engine_usage;
+    source.map(|source| source())
+}
+
+fn record_alloc(ptr: *mut c_void, bytes: usize, category: &'static str) {
+    let hook = {
+        let mut state = STATE.lock();
+        state.live.insert(ptr as usize, (bytes, category));
+        state.add(category, bytes);
+        state.hook
+    };
+
+    notify(
+        hook,
+        MemoryEvent {
+            kind: MemoryEventKind::Alloc,
+            ptr,
+            bytes,
+            category,
+        },
+    );
+}
+
+fn record_realloc(
+    old_ptr: *mut c_void,
+    new_ptr: *mut c_void,
+    bytes: usize,
+    category: &'static str,
+) {
+    let (hook, old_bytes, category) = {
+        let mut state = STATE.lock();
+
+        // Untracked or null memory: count the result as a fresh allocation.
+        let (old_bytes, category) = match state.live.remove(&(old_ptr as usize)) {
+            Some((old_bytes, old_category)) => {
+                state.subtract(old_category, old_bytes);
+                (old_bytes, old_category)
+            }
+            None => (0, category),
+        };
+
+        state.live.insert(new_ptr as usize, (bytes, category));
+        state.add(category, bytes);
+        (state.hook, old_bytes, category)
+    };
+
+    notify(
+        hook,
+        MemoryEvent {
+            kind: MemoryEventKind::Realloc { old_ptr, old_bytes },
+            ptr: new_ptr,
+            bytes,
+            category,
+        },
+    );
+}
+
+fn record_free(ptr: *mut c_void) {
+    let (hook, bytes, category) = {
+        let mut state = STATE.lock();
+        let Some((bytes, category)) = state.live.remove(&(ptr as usize)) else {
+            return;
+        };
+
+        state.subtract(category, bytes);
+        (state.hook, bytes, category)
+    };
+
+    notify(
+        hook,
+        MemoryEvent {
+            kind: MemoryEventKind::Free,
+            ptr,
+            bytes,
+            category,
+        },
+    );
+}
+
+fn notify(hook: Option<fn(&MemoryEvent)>, event: MemoryEvent) {
+    if let Some(hook) = hook {
+        hook(&event);
+    }
+}
+
+impl State {
+    fn add(&mut self, category: &'static str, bytes: usize) {
+        let entry = self.categories.entry(category).or_default();
+        entry.current_bytes += bytes;
+        entry.peak_bytes = entry.peak_bytes.max(entry.current_bytes);
+        entry.live_allocations += 1;
+        entry.total_allocations += 1;
+    }
+
+    fn subtract(&mut self, category: &'static str, bytes: usize) {
+        let entry = self.categories.entry(category).or_default();
+        entry.current_bytes -= bytes;
+        entry.live_allocations -= 1;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Recording is tested without the engine, using fake addresses in dedicated categories.
+    fn fake_ptr(addr: usize) -> *mut c_void {
+        addr as *mut c_void
+    }
+
+    #[test]
+    fn record_with_scopes() {
+        {
+            let _outer = scope("test-outer");
+            {
+                let _inner = scope("test-inner");
+                assert_eq!(current_category(), "test-inner");
+            }
+            assert_eq!(current_category(), "test-outer");
+        }
+        assert_eq!(current_category(), DEFAULT_CATEGORY);
+
+        record_alloc(fake_ptr(0x1000), 64, "test-record");
+        record_alloc(fake_ptr(0x2000), 32, "test-record");
+        record_realloc(fake_ptr(0x1000), fake_ptr(0x3000), 128, DEFAULT_CATEGORY);
+        assert_eq!(current_bytes("test-record"), 160);
+
+        record_free(fake_ptr(0x2000));
+        let totals = totals();
+        let entry = totals
+            .iter()
+            .find(|t| t.category == "test-record")
+            .expect("category recorded");
+
+        assert_eq!(entry.current_bytes, 128);
+        assert_eq!(entry.peak_bytes, 160);
+        assert_eq!(entry.live_allocations, 1);
+        assert_eq!(entry.total_allocations, 3);
+
+        record_free(fake_ptr(0x3000));
+        assert_eq!(current_bytes("test-record"), 0);
+
+        // Untracked memory is ignored.
+        record_free(fake_ptr(0x4000));
+    }
+
+    #[test]
+    fn engine_usage_per_scope() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        static FAKE_USAGE: AtomicU64 = AtomicU64::new(1000);
+        set_engine_usage_source(Some(|| FAKE_USAGE.load(Ordering::SeqCst)));
+
+        {
+            let _outer = scope("test-engine-outer");
+            FAKE_USAGE.fetch_add(100, Ordering::SeqCst);
+            {
+                let _inner = scope("test-engine-inner");
+                FAKE_USAGE.fetch_add(40, Ordering::SeqCst);
+            }
+            FAKE_USAGE.fetch_sub(10, Ordering::SeqCst);
+        }
+
+        // Inner scope's growth is not counted twice.
+        assert_eq!(engine_bytes("test-engine-inner"), 40);
+        assert_eq!(engine_bytes("test-engine-outer"), 90);
+
+        {
+            let _scope = scope("test-engine-outer");
+            FAKE_USAGE.fetch_sub(150, Ordering::SeqCst);
+        }
+        assert_eq!(engine_bytes("test-engine-outer"), -60);
+
+        let totals = totals();
+        let entry = totals
+            .iter()
+            .find(|t| t.category == "test-engine-inner")
+            .expect("category measured");
+        assert_eq!(entry.engine_bytes, 40);
+        assert_eq!(entry.current_bytes, 0);
+    }
+}
@@ -0,0 +1,239 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Validation of ptrcall signatures against engine metadata, enabled by the `debug-ptrcall-checks` feature in debug builds.
+//!
+//! Ptrcalls pass arguments as untyped pointers. If Rust and Godot disagree on a parameter type -- e.g. through manual use of the
+//! signature machinery, or a virtual method with a mismatched signature -- the callee reinterprets memory of the wrong type, and the
+//! resulting corruption surfaces far away from the call. With validation enabled, class ptrcalls compare the variant types of their
+//! parameters and return value with the method's signature in ClassDB, and panic on mismatch before any pointer is passed on.
+//!
+//! Metadata is only available where Godot provides it (e.g. virtual methods are only listed in editor and debug builds of the engine).
+//! Calls without metadata are not validated.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use crate::{Global, VariantType};
+
+/// Parameter and return types of a method, as seen by ptrcalls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PtrcallSignature {
+    pub params: Vec<VariantType>,
+
+    /// `NIL` for both `void` and `Variant` returns.
+    pub ret: VariantType,
+}
+
+/// Signatures of all methods of a class, by method name.
+pub type ClassSignatures = HashMap<String, PtrcallSignature>;
+
+/// Loads the signatures of a class from the engine; `None` if the class has no metadata.
+pub type LoadClassFn = fn(class_name: &str) -> Option<ClassSignatures>;
+
+static SIGNATURES: Global<HashMap<String, Option<ClassSignatures>>> = Global::default();
+
+thread_local! {
+    /// Set while metadata is loaded, which itself performs ptrcalls.
+    static IN_LOAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Panics if `actual` does not match the signature of `class_name.method_name` in the engine.
+///
+/// The metadata of each class is loaded once via `load_class`. Ptrcalls issued by `load_class` itself are not validated.
+pub fn validate(
+    class_name: &str,
+    method_name: &str,
+    actual: &PtrcallSignature,
+    load_class: LoadClassFn,
+) {
+    if IN_LOAD.get() {
+        return;
+    }
+
+    let Some(expected) = expected_signature(class_name, method_name, load_class) else {
+        return;
+    };
+
+    // Lock is released here, so the panic doesn't poison it.
+    if let Some(mismatch) = find_mismatch(actual, &expected) {
+        panic!(
+            "ptrcall signature mismatch in `{class_name}::{method_name}`: {mismatch}\n  \
+            Rust: {actual:?}\n  \
+            Godot: {expected:?}"
+        );
+    }
+}
+
+/// Discards all loaded metadata, e.g. on hot-reload.
+pub fn clear() {
+    SIGNATURES.lock().clear();
+}
+
+fn expected_signature(
+    class_name: &str,
+    method_name: &str,
+    load_class: LoadClassFn,
+) -> Option<PtrcallSignature> {
+    if !SIGNATURES.lock().contains_key(class_name) {
+        // Load without holding the lock; other threads may load concurrently, in which case the first result is kept.
+        let loaded = {
+            let _guard = LoadGuard::enter();
+            load_class(class_name)
+        };
+
+        SIGNATURES
+            .lock()
+            .entry(class_name.to_string())
+            .or_insert(loaded);
+    }
+
+    SIGNATURES
+        .lock()
+        .get(class_name)?
+        .as_ref()?
+        .get(method_name)
+        .cloned()
+}
+
+/// Sets `IN_LOAD` while alive, and resets it on drop -- also if `load_class` panics.
+struct LoadGuard;
+
+impl LoadGuard {
+    fn enter() -> Self {
+        IN_LOAD.set(true);
+        Self
+    }
+}
+
+impl Drop for LoadGuard {
+    fn drop(&mut self) {
+        IN_LOAD.set(false);
+    }
+}
+
+fn find_mismatch(actual: &PtrcallSignature, expected: &PtrcallSignature) -> Option<String> {
+    if actual.params.len() != expected.params.len() {
+        return Some(format!(
+            "passes {} parameters, but Godot expects {}",
+            actual.params.len(),
+            expected.params.len()
+        ));
+    }
+
+    let param_mismatch = actual
+        .params
+        .iter()
+        .zip(&expected.params)
+        .position(|(actual, expected)| actual != expected);
+
+    if let Some(index) = param_mismatch {
+        return Some(format!(
+            "parameter [{index}] has type {:?}, but Godot expects {:?}",
+            actual.params[index], expected.params[index]
+        ));
+    }
+
+    if actual.ret != expected.ret {
+        return Some(format!(
+            "return type is {:?}, but Godot declares {:?}",
+            actual.ret, expected.ret
+        ));
+    }
+
+    None
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_test_class(class_name: &str) -> Option<ClassSignatures> {
+        if class_name != "PtrcallCheckTest" {
+            return None;
+        }
+
+        let signature = PtrcallSignature {
+            params: vec![VariantType::INT, VariantType::STRING],
+            ret: VariantType::BOOL,
+        };
+
+        Some(HashMap::from([("method".to_string(), signature)]))
+    }
+
+    fn signature(params: &[VariantType], ret: VariantType) -> PtrcallSignature {
+        PtrcallSignature {
+            params: params.to_vec(),
+            ret,
+        }
+    }
+
+    #[test]
+    fn validate_matching_or_unknown() {
+        let matching = signature(&[VariantType::INT, VariantType::STRING], VariantType::BOOL);
+        validate("PtrcallCheckTest", "method", &matching, load_test_class);
+
+        // No metadata: nothing to validate against.
+        let other = signature(&[], VariantType::NIL);
+        validate("PtrcallCheckTest", "unknown", &other, load_test_class);
+        validate("UnknownClass", "method", &other, load_test_class);
+    }
+
+    #[test]
+    fn find_mismatches() {
+        let expected = signature(&[VariantType::INT, VariantType::STRING], VariantType::BOOL);
+
+        let wrong_count = signature(&[VariantType::INT], VariantType::BOOL);
+        let wrong_param = signature(&[VariantType::INT, VariantType::FLOAT], VariantType::BOOL);
+        let wrong_ret = signature(&[VariantType::INT, VariantType::STRING], VariantType::NIL);
+
+        assert_eq!(
+            find_mismatch(&wrong_count, &expected).as_deref(),
+            Some("passes 1 parameters, but Godot expects 2")
+        );
+        assert_eq!(
+            find_mismatch(&wrong_param, &expected).as_deref(),
+            Some("parameter [1] has type FLOAT, but Godot expects STRING")
+        );
+        assert!(find_mismatch(&wrong_ret, &expected)
+            .unwrap()
+            .starts_with("return type is NIL"));
+        assert_eq!(find_mismatch(&expected, &expected), None);
+    }
+
+    #[test]
+    fn load_panic_resets_state() {
+        fn load_panicking(_class_name: &str) -> Option<ClassSignatures> {
+            panic!("load failed");
+        }
+
+        let signature = signature(&[], VariantType::NIL);
+        let result = std::panic::catch_unwind(|| {
+            validate(
+                "PtrcallCheckPanicking",
+                "method",
+                &signature,
+                load_panicking,
+            )
+        });
+
+        assert!(result.is_err());
+        assert!(
+            !IN_LOAD.get(),
+            "validation is re-enabled after a panicking load"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ptrcall signature mismatch in `PtrcallCheckTest::method`")]
+    fn validate_panics_on_mismatch() {
+        let wrong = signature(&[VariantType::INT, VariantType::INT], VariantType::BOOL);
+        validate("PtrcallCheckTest", "method", &wrong, load_test_class);
+    }
+}
@@ -24,8 +24,8 @@ impl FieldExport {
         Ok(Self { export_type, span })
     }
 
-    pub fn to_export_hint(&self) -> Option<TokenStream> {
-        self.export_type.to_export_hint()
+    pub fn to_export_hint(&self, field_type: &venial::TypeExpr) -> Option<TokenStream> {
+        self.export_type.to_export_hint(field_type)
     }
 }
 
@@ -98,9 +98,11 @@ pub enum ExportType {
     /// - `LAYERS_3D_PHYSICS`
     /// - `LAYERS_3D_RENDER`
     /// - `LAYERS_3D_NAVIGATION`
+    /// - `FLAGS`, if `named_layers` is specified (no GDScript equivalent)
     Layers {
         dimension: LayerDimension,
         kind: LayerKind,
+        named: bool,
     },
 
     /// ### GDScript annotations
@@ -134,8 +136,19 @@ pub enum ExportType {
     /// - `@export_color_no_alpha`
     ///
     /// ### Property hints
-    /// - `COLOR_NO_ALPHA`
+    /// - `COLOR_NO_ALPHA` for `Color`
+    /// - `TYPE_STRING` with `COLOR_NO_ALPHA` elements for `PackedColorArray` and `Array<Color>`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - `@export` on `Dictionary[K, V]` (Godot 4.4+)
+    ///
+    /// ### Property hints
+    /// - `TYPE_STRING` with key and value type strings
+    DictTypes {
+        key: TokenStream,
+        value: TokenStream,
+    },
 }
 
 impl ExportType {
@@ -170,6 +183,7 @@ impl ExportType {
             return Ok(Self::Layers {
                 dimension: LayerDimension::_2d,
                 kind: LayerKind::Render,
+                named: parser.handle_alone("named_layers")?,
             });
         }
 
@@ -177,6 +191,7 @@ impl ExportType {
             return Ok(Self::Layers {
                 dimension: LayerDimension::_2d,
                 kind: LayerKind::Physics,
+                named: parser.handle_alone("named_layers")?,
             });
         }
 
@@ -184,6 +199,7 @@ impl ExportType {
             return Ok(Self::Layers {
                 dimension: LayerDimension::_2d,
                 kind: LayerKind::Navigation,
+                named: parser.handle_alone("named_layers")?,
             });
         }
 
@@ -191,6 +207,7 @@ impl ExportType {
             return Ok(Self::Layers {
                 dimension: LayerDimension::_3d,
                 kind: LayerKind::Render,
+                named: parser.handle_alone("named_layers")?,
             });
         }
 
@@ -198,6 +215,7 @@ impl ExportType {
             return Ok(Self::Layers {
                 dimension: LayerDimension::_3d,
                 kind: LayerKind::Physics,
+                named: parser.handle_alone("named_layers")?,
             });
         }
 
@@ -205,6 +223,7 @@ impl ExportType {
             return Ok(Self::Layers {
                 dimension: LayerDimension::_3d,
                 kind: LayerKind::Navigation,
+                named: parser.handle_alone("named_layers")?,
             });
         }
 
@@ -270,6 +289,21 @@ impl ExportType {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if let Some(list_parser) = parser.handle_list("dict_types")? {
+            return Self::new_dict_types(list_parser);
+        }
+
+        // Godot has no property hints for these; reject them instead of ignoring them.
+        for key in ["curve_range", "curve_domain", "gradient"] {
+            if let Some((ident, _)) = parser.handle_any_entry(key) {
+                return bail!(
+                    ident,
+                    "`#[export({key})]` is not supported: Godot has no property hint for it. \
+                    The inspector reads Curve and Gradient settings from the resource itself, e.g. `Curve::set_min_value()`"
+                );
+            }
+        }
+
         Ok(Self::Default)
     }
 
@@ -365,6 +399,14 @@ impl ExportType {
         })
     }
 
+    fn new_dict_types(mut parser: ListParser) -> ParseResult<Self> {
+        let key = parser.next_expr()?;
+        let value = parser.next_expr()?;
+        parser.finish()?;
+
+        Ok(Self::DictTypes { key, value })
+    }
+
     fn new_flags(mut parser: ListParser) -> ParseResult<Self> {
         let mut bits = Vec::new();
 
@@ -392,7 +434,7 @@ macro_rules! quote_export_func {
 }
 
 impl ExportType {
-    pub fn to_export_hint(&self) -> Option<TokenStream> {
+    pub fn to_export_hint(&self, field_type: &venial::TypeExpr) -> Option<TokenStream> {
         match self {
             Self::Default => None,
 
@@ -459,33 +501,58 @@ impl ExportType {
             Self::Layers {
                 dimension: LayerDimension::_2d,
                 kind: LayerKind::Physics,
+                named: false,
             } => quote_export_func! { export_flags_2d_physics() },
 
             Self::Layers {
                 dimension: LayerDimension::_2d,
                 kind: LayerKind::Render,
+                named: false,
             } => quote_export_func! { export_flags_2d_render() },
 
             Self::Layers {
                 dimension: LayerDimension::_2d,
                 kind: LayerKind::Navigation,
+                named: false,
             } => quote_export_func! { export_flags_2d_navigation() },
 
             Self::Layers {
                 dimension: LayerDimension::_3d,
                 kind: LayerKind::Physics,
+                named: false,
             } => quote_export_func! { export_flags_3d_physics() },
 
             Self::Layers {
                 dimension: LayerDimension::_3d,
                 kind: LayerKind::Render,
+                named: false,
             } => quote_export_func! { export_flags_3d_render() },
 
             Self::Layers {
                 dimension: LayerDimension::_3d,
                 kind: LayerKind::Navigation,
+                named: false,
             } => quote_export_func! { export_flags_3d_navigation() },
 
+            Self::Layers {
+                dimension,
+                kind,
+                named: true,
+            } => {
+                let hint = match (dimension, kind) {
+                    (LayerDimension::_2d, LayerKind::Physics) => quote! { LAYERS_2D_PHYSICS },
+                    (LayerDimension::_2d, LayerKind::Render) => quote! { LAYERS_2D_RENDER },
+                    (LayerDimension::_2d, LayerKind::Navigation) => quote! { LAYERS_2D_NAVIGATION },
+                    (LayerDimension::_3d, LayerKind::Physics) => quote! { LAYERS_3D_PHYSICS },
+                    (LayerDimension::_3d, LayerKind::Render) => quote! { LAYERS_3D_RENDER },
+                    (LayerDimension::_3d, LayerKind::Navigation) => quote! { LAYERS_3D_NAVIGATION },
+                };
+
+                quote_export_func! {
+                    export_flags_named_layers(::godot::global::PropertyHint::#hint)
+                }
+            }
+
             Self::File {
                 global: false,
                 kind: FileKind::Dir,
@@ -510,7 +577,13 @@ impl ExportType {
             Self::PlaceholderText { placeholder } => quote_export_func! {
                 export_placeholder(#placeholder)
             },
-            Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            // Dispatches on the field type, so that collections of colors apply the hint to their elements.
+            Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha_for::<#field_type>() },
+
+            Self::DictTypes { key, value } => quote_export_func! {
+                export_dictionary_types::<#key, #value>()
+            },
         }
     }
 }
@@ -10,9 +10,9 @@ use quote::{format_ident, quote};
 
 use crate::class::{
     into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
-    FuncDefinition,
+    FuncAvailability, FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -33,7 +33,8 @@ impl FieldVar {
     /// - `set = expr`
     /// - `hint = ident`
     /// - `hint_string = expr`
-    /// - `usage_flags =
+    /// - `usage_flags = [ident, ident, ...]`
+    /// - `storage`, `editor_only`, `no_instance_state` (shortcuts for usage flags)
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
         let mut getter = GetterSetter::parse(parser, "get")?;
@@ -68,6 +69,8 @@ impl FieldVar {
             UsageFlags::Inferred
         };
 
+        let usage_flags = Self::parse_usage_shortcuts(parser, usage_flags)?;
+
         Ok(FieldVar {
             getter,
             setter,
@@ -76,6 +79,60 @@ impl FieldVar {
             span,
         })
     }
+
+    /// Parses `storage`, `editor_only` and `no_instance_state`, which are shorthands for the most common usage flag combinations.
+    fn parse_usage_shortcuts(
+        parser: &mut KvParser,
+        usage_flags: UsageFlags,
+    ) -> ParseResult<UsageFlags> {
+        let storage = parser.handle_alone_with_span("storage")?;
+        let editor_only = parser.handle_alone_with_span("editor_only")?;
+        let no_instance_state = parser.handle_alone_with_span("no_instance_state")?;
+
+        let Some(first) = [&storage, &editor_only, &no_instance_state]
+            .into_iter()
+            .flatten()
+            .next()
+        else {
+            return Ok(usage_flags);
+        };
+
+        if !usage_flags.is_inferred() {
+            return bail!(
+                first,
+                "`{first}` cannot be combined with `usage_flags`; add the corresponding flag to the list instead"
+            );
+        }
+
+        if let (Some(_), Some(editor_only)) = (&storage, &editor_only) {
+            return bail!(
+                editor_only,
+                "`storage` and `editor_only` are mutually exclusive"
+            );
+        }
+
+        let extra_flags = no_instance_state
+            .map(|ident| Ident::new("NO_INSTANCE_STATE", ident.span()))
+            .into_iter();
+
+        // `storage` is added to the inferred flags, so that `#[export]` keeps its `EDITOR` flag (and DEFAULT already contains STORAGE).
+        // `editor_only` replaces them, to remove STORAGE also for exported properties.
+        let usage_flags = match (storage, editor_only) {
+            (Some(storage), _) => UsageFlags::InferredWith(
+                std::iter::once(Ident::new("STORAGE", storage.span()))
+                    .chain(extra_flags)
+                    .collect(),
+            ),
+            (None, Some(editor_only)) => UsageFlags::Custom(
+                std::iter::once(Ident::new("EDITOR", editor_only.span()))
+                    .chain(extra_flags)
+                    .collect(),
+            ),
+            (None, None) => UsageFlags::InferredWith(extra_flags.collect()),
+        };
+
+        Ok(usage_flags)
+    }
 }
 
 impl Default for FieldVar {
@@ -220,6 +277,7 @@ impl GetterSetterImpl {
                 registered_name: None,
                 is_script_virtual: false,
                 rpc_info: None,
+                availability: FuncAvailability::Always,
             },
         );
 
@@ -250,6 +308,9 @@ pub enum UsageFlags {
     /// The usage flags should be inferred based on context, such that they include export.
     InferredExport,
 
+    /// The usage flags should be inferred based on context, with additional flags added on top.
+    InferredWith(Vec<Ident>),
+
     /// Use a custom set of usage flags provided by the user.
     Custom(Vec<Ident>),
 }
@@ -29,6 +29,22 @@ pub struct FuncDefinition {
 
     /// Information about the RPC configuration, if provided.
     pub rpc_info: Option<RpcAttr>,
+
+    /// Runtime condition under which the function is registered.
+    pub availability: FuncAvailability,
+}
+
+/// Whether a function is always registered, or only in certain runtime environments.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub enum FuncAvailability {
+    #[default]
+    Always,
+
+    /// `#[func(editor_only)]`: only registered if the engine runs in the editor.
+    EditorOnly,
+
+    /// `#[func(debug_only)]`: only registered in debug builds of the engine (editor or debug export templates).
+    DebugOnly,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -36,6 +52,7 @@ pub struct FuncDefinition {
 // Virtual methods are non-static by their nature; so there's no support for static ones.
 pub fn make_virtual_callback(
     class_name: &Ident,
+    godot_method_name: &str,
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
 ) -> TokenStream {
@@ -63,7 +80,11 @@ pub fn make_virtual_callback(
                 let call_ctx = #call_ctx;
                 let _success = ::godot::private::handle_ptrcall_panic(
                     &call_ctx,
-                    || #invocation
+                    || {
+                        // Inside the panic handler, so that a mismatch doesn't unwind across the C ABI.
+                        ::godot::private::validate_virtual_ptrcall::<#class_name, Sig>(#godot_method_name);
+                        #invocation
+                    }
                 );
             }
             Some(virtual_fn)
@@ -80,10 +101,12 @@ pub fn make_method_registration(
     let sig_tuple = signature_info.tuple_type();
 
     let is_script_virtual = func_definition.is_script_virtual;
-    let method_flags = match make_method_flags(signature_info.receiver_type, is_script_virtual) {
-        Ok(mf) => mf,
-        Err(msg) => return bail_fn(msg, &signature_info.method_name),
-    };
+    let is_vararg = signature_info.vararg_ident.is_some();
+    let method_flags =
+        match make_method_flags(signature_info.receiver_type, is_script_virtual, is_vararg) {
+            Ok(mf) => mf,
+            Err(msg) => return bail_fn(msg, &signature_info.method_name),
+        };
 
     let forwarding_closure =
         make_forwarding_closure(class_name, signature_info, BeforeKind::Without);
@@ -91,6 +114,11 @@ pub fn make_method_registration(
     // String literals
     let method_name = &signature_info.method_name;
     let class_name_str = class_name.to_string();
+    let registered_name = make_registered_name(
+        class_name,
+        &method_name.to_string(),
+        func_definition.registered_name.as_deref(),
+    );
     let method_name_str = if let Some(updated_name) = func_definition.registered_name {
         updated_name
     } else {
@@ -98,8 +126,15 @@ pub fn make_method_registration(
     };
 
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
-    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure, is_vararg);
+
+    // Godot never ptrcalls vararg methods, and a ptrcall couldn't pass the extra arguments anyway.
+    let (ptrcall_fn_decl, ptrcall_fn) = if is_vararg {
+        (TokenStream::new(), quote! { None })
+    } else {
+        let decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+        (decl, quote! { Some(ptrcall_fn) })
+    };
 
     // String literals II
     let param_ident_strs = signature_info
@@ -114,7 +149,6 @@ pub fn make_method_registration(
         .collect::<Vec<_>>();
 
     let registration = quote! {
-        #(#cfg_attrs)*
         {
             use ::godot::obj::GodotClass;
             use ::godot::register::private::method::ClassMethodInfo;
@@ -123,7 +157,7 @@ pub fn make_method_registration(
 
             type Sig = #sig_tuple;
 
-            let method_name = StringName::from(#method_name_str);
+            let method_name = StringName::from(#registered_name);
 
             #varcall_fn_decl;
             #ptrcall_fn_decl;
@@ -133,7 +167,7 @@ pub fn make_method_registration(
                 ClassMethodInfo::from_signature::<#class_name, Sig>(
                     method_name,
                     Some(varcall_fn),
-                    Some(ptrcall_fn),
+                    #ptrcall_fn,
                     #method_flags,
                     &[
                         #( #param_ident_strs ),*
@@ -149,10 +183,43 @@ pub fn make_method_registration(
 
             // Note: information whether the method is virtual is stored in method method_info's flags.
             method_info.register_extension_class_method();
-        };
+        }
     };
 
-    Ok(registration)
+    // #[func(editor_only)] and #[func(debug_only)] are checked when the class is registered, not at compile time: the same binary is
+    // loaded by the editor and by exported games.
+    let registration = match func_definition.availability {
+        FuncAvailability::Always => registration,
+        FuncAvailability::EditorOnly => quote! {
+            if ::godot::private::is_editor_hint() #registration
+        },
+        FuncAvailability::DebugOnly => quote! {
+            if ::godot::private::is_debug_build() #registration
+        },
+    };
+
+    Ok(quote! {
+        #(#cfg_attrs)*
+        {
+            #registration
+        };
+    })
+}
+
+/// Expression of type `String`, evaluating to the name under which a function, signal or property is registered.
+///
+/// Explicit renames are taken verbatim; otherwise, the class-level `#[class(func_rename = ...)]` policy is applied to the Rust name.
+pub fn make_registered_name(
+    class_name: &Ident,
+    rust_name: &str,
+    explicit_name: Option<&str>,
+) -> TokenStream {
+    match explicit_name {
+        Some(name) => quote! { ::std::string::String::from(#name) },
+        None => quote! {
+            <#class_name as ::godot::obj::GodotClass>::__FUNC_RENAME.apply(#rust_name)
+        },
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -173,6 +240,9 @@ pub struct SignatureInfo {
     pub param_idents: Vec<Ident>,
     pub param_types: Vec<venial::TypeExpr>,
     pub ret_type: TokenStream,
+
+    /// Name of the trailing `VarArgs` parameter for `#[func(vararg)]`. Not part of `param_idents`/`param_types`.
+    pub vararg_ident: Option<Ident>,
 }
 
 impl SignatureInfo {
@@ -183,6 +253,7 @@ impl SignatureInfo {
             param_idents: vec![],
             param_types: vec![],
             ret_type: quote! { () },
+            vararg_ident: None,
         }
     }
 
@@ -213,12 +284,19 @@ fn make_forwarding_closure(
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
 
+    // For #[func(vararg)], the closure receives the extra arguments separately, and passes them on as the last argument.
+    let vararg = &signature_info.vararg_ident;
+    let closure_vararg = vararg.as_ref().map(|vararg| quote! { , #vararg });
+    let call_args: Vec<&Ident> = params.iter().chain(vararg).collect();
+
+    // Names the method in diagnostics when the instance is already bound (re-entrancy). The class is added by the storage.
+    let method_name_str = method_name.to_string();
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
-            let instance = ::godot::private::Storage::get(storage);
+            let instance = ::godot::private::Storage::get_for_call(storage, #method_name_str);
         },
         ReceiverType::Mut => quote! {
-            let mut instance = ::godot::private::Storage::get_mut(storage);
+            let mut instance = ::godot::private::Storage::get_mut_for_call(storage, #method_name_str);
         },
         _ => quote! {},
     };
@@ -238,11 +316,11 @@ fn make_forwarding_closure(
             let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
                 TokenStream::new()
             } else {
-                quote! { instance.#method_name( #(#params),* ) }
+                quote! { instance.#method_name( #(#call_args),* ) }
             };
 
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #closure_vararg| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
@@ -258,23 +336,23 @@ fn make_forwarding_closure(
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #closure_vararg| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#call_args),*)
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
             quote! {
-                |_, params| {
+                |_, params #closure_vararg| {
                     let ( #(#params,)* ) = params;
-                    #class_name::#method_name(#(#params),*)
+                    #class_name::#method_name(#(#call_args),*)
                 }
             }
         }
@@ -359,6 +437,7 @@ pub(crate) fn into_signature_info(
         param_idents,
         param_types,
         ret_type,
+        vararg_ident: None,
     }
 }
 
@@ -384,6 +463,7 @@ pub(crate) fn maybe_rename_parameter(param_ident: Ident, next_unnamed_index: &mu
 fn make_method_flags(
     method_type: ReceiverType,
     is_script_virtual: bool,
+    is_vararg: bool,
 ) -> Result<TokenStream, String> {
     let flags = quote! { ::godot::global::MethodFlags };
 
@@ -405,18 +485,28 @@ fn make_method_flags(
         }
     };
 
-    let flags = if is_script_virtual {
+    let base_flags = if is_script_virtual {
         quote! { #base_flags | #flags::VIRTUAL }
     } else {
         base_flags
     };
 
+    let flags = if is_vararg {
+        quote! { #base_flags | #flags::VARARG }
+    } else {
+        base_flags
+    };
+
     Ok(flags)
 }
 
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
-    let invocation = make_varcall_invocation(wrapped_method);
+fn make_varcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    is_vararg: bool,
+) -> TokenStream {
+    let invocation = make_varcall_invocation(wrapped_method, is_vararg);
 
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
     quote! {
@@ -483,9 +573,15 @@ fn make_ptrcall_invocation(wrapped_method: &TokenStream, is_virtual: bool) -> To
 }
 
 /// Generate code for a `varcall()` call expression.
-fn make_varcall_invocation(wrapped_method: &TokenStream) -> TokenStream {
+fn make_varcall_invocation(wrapped_method: &TokenStream, is_vararg: bool) -> TokenStream {
+    let in_varcall = if is_vararg {
+        quote! { in_varcall_vararg }
+    } else {
+        quote! { in_varcall }
+    };
+
     quote! {
-        <Sig as ::godot::meta::VarcallSignatureTuple>::in_varcall(
+        <Sig as ::godot::meta::VarcallSignatureTuple>::#in_varcall(
             instance_ptr,
             &call_ctx,
             args_ptr,
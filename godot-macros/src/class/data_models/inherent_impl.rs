@@ -7,8 +7,8 @@
 
 use crate::class::{
     into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
-    SignatureInfo, TransferMode,
+    make_signal_registrations, ConstDefinition, FuncAvailability, FuncDefinition, RpcAttr, RpcMode,
+    SignalDefinition, SignatureInfo, TransferMode,
 };
 use crate::util::{bail, c_str, ident, require_api_version, KvParser};
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
@@ -61,6 +61,8 @@ struct FuncAttr {
     pub rename: Option<String>,
     pub is_virtual: bool,
     pub has_gd_self: bool,
+    pub has_vararg: bool,
+    pub availability: FuncAvailability,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -69,6 +71,9 @@ pub struct InherentImplAttr {
     /// For implementation reasons, there can be a single 'primary' impl block and 0 or more 'secondary' impl blocks.
     /// For now, this is controlled by a key in the 'godot_api' attribute.
     pub secondary: bool,
+
+    /// Whether to register the GDScript iteration protocol (`_iter_init` etc.), backed by `ScriptIterable`.
+    pub iterable: bool,
 }
 
 /// Codegen for `#[godot_api] impl MyType`
@@ -89,7 +94,7 @@ pub fn transform_inherent_impl(
     #[cfg(not(all(feature = "register-docs", since_api = "4.3")))]
     let docs = quote! {};
 
-    let signal_registrations = make_signal_registrations(signals, &class_name_obj);
+    let signal_registrations = make_signal_registrations(signals, &class_name, &class_name_obj);
 
     #[cfg(feature = "codegen-full")]
     let rpc_registrations = crate::class::make_rpc_registrations_fn(&class_name, &funcs);
@@ -103,6 +108,12 @@ pub fn transform_inherent_impl(
 
     let constant_registration = make_constant_registration(consts, &class_name, &class_name_obj)?;
 
+    let iterable_impl = if meta.iterable {
+        make_script_iterable_impl(&class_name)
+    } else {
+        TokenStream::new()
+    };
+
     let method_storage_name = format_ident!("__registration_methods_{class_name}");
     let constants_storage_name = format_ident!("__registration_constants_{class_name}");
 
@@ -174,6 +185,7 @@ pub fn transform_inherent_impl(
             #trait_impl
             #fill_storage
             #class_registration
+            #iterable_impl
         };
 
         Ok(result)
@@ -184,12 +196,38 @@ pub fn transform_inherent_impl(
         let result = quote! {
             #impl_block
             #fill_storage
+            #iterable_impl
         };
 
         Ok(result)
     }
 }
 
+/// Registers `_iter_init`, `_iter_next` and `_iter_get` in a secondary impl block, forwarding to `ScriptIterable`.
+fn make_script_iterable_impl(class_name: &Ident) -> TokenStream {
+    let prv = quote! { ::godot::private };
+
+    quote! {
+        #[::godot::register::godot_api(secondary)]
+        impl #class_name {
+            #[func]
+            fn _iter_init(&self, iter: ::godot::builtin::VariantArray) -> bool {
+                #prv::script_iter_init(self, iter)
+            }
+
+            #[func]
+            fn _iter_next(&self, iter: ::godot::builtin::VariantArray) -> bool {
+                #prv::script_iter_next(self, iter)
+            }
+
+            #[func]
+            fn _iter_get(&self, state: ::godot::builtin::Variant) -> ::godot::builtin::Variant {
+                #prv::script_iter_get(self, state)
+            }
+        }
+    }
+}
+
 fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
@@ -225,6 +263,13 @@ fn process_godot_fns(
 
         match attr.ty {
             ItemAttrType::Func(func, rpc_info) => {
+                if function.body.is_none() && !func.is_virtual {
+                    return attr.bail(
+                        "#[func] requires a function body; only #[func(virtual)] methods can omit it, to be overridden by scripts",
+                        function,
+                    );
+                }
+
                 let external_attributes = function.attributes.clone();
 
                 // Signatures are the same thing without body.
@@ -254,9 +299,34 @@ fn process_godot_fns(
                     None
                 };
 
+                let vararg_parameter = if func.has_vararg {
+                    if func.is_virtual {
+                        return bail_attr(
+                            attr.attr_name,
+                            "attribute keys `vararg` and `virtual` cannot be combined",
+                            function,
+                        );
+                    }
+
+                    // The parameter name is irrelevant, since it is only forwarded positionally.
+                    match signature.params.inner.pop() {
+                        Some((venial::FnParam::Typed(_), _)) => Some(ident("__varargs")),
+                        _ => {
+                            return bail_attr(
+                                attr.attr_name,
+                                "with attribute key `vararg`, the method must have a last parameter of type `VarArgs`",
+                                function,
+                            )
+                        }
+                    }
+                } else {
+                    None
+                };
+
                 // Clone might not strictly be necessary, but the 2 other callers of into_signature_info() are better off with pass-by-value.
-                let signature_info =
+                let mut signature_info =
                     into_signature_info(signature.clone(), class_name, gd_self_parameter.is_some());
+                signature_info.vararg_ident = vararg_parameter;
 
                 // For virtual methods, rename/mangle existing user method and create a new method with the original name,
                 // which performs a dynamic dispatch.
@@ -281,6 +351,7 @@ fn process_godot_fns(
                     registered_name,
                     is_script_virtual: func.is_virtual,
                     rpc_info,
+                    availability: func.availability,
                 });
             }
 
@@ -397,6 +468,9 @@ fn add_virtual_script_call(
     let sig_tuple = signature_info.tuple_type();
     let arg_names = &signature_info.param_idents;
 
+    // Methods without body must be overridden by a script.
+    let is_abstract = function.body.is_none();
+
     let (object_ptr, receiver);
     if let Some(gd_self_parameter) = gd_self_parameter {
         object_ptr = quote! { #gd_self_parameter.obj_sys() };
@@ -406,6 +480,17 @@ fn add_virtual_script_call(
         receiver = ident("self");
     };
 
+    let fallback = if is_abstract {
+        let panic_message = format!(
+            "abstract method {class_name_str}::{method_name_str}() must be overridden by a script"
+        );
+
+        quote! { panic!(#panic_message) }
+    } else {
+        // Fall back to default implementation.
+        quote! { Self::#early_bound_name(#receiver, #( #arg_names ),*) }
+    };
+
     let code = quote! {
         let object_ptr = #object_ptr;
         let method_sname = ::godot::builtin::StringName::from(#method_name_cstr);
@@ -426,19 +511,23 @@ fn add_virtual_script_call(
                 )
             }
         } else {
-            // Fall back to default implementation.
-            Self::#early_bound_name(#receiver, #( #arg_names ),*)
+            #fallback
         }
     };
 
-    let mut early_bound_function = venial::Function {
-        name: early_bound_name,
-        body: Some(Group::new(Delimiter::Brace, code)),
-        ..function.clone()
-    };
+    if is_abstract {
+        function.body = Some(Group::new(Delimiter::Brace, code));
+        function.tk_semicolon = None;
+    } else {
+        let mut early_bound_function = venial::Function {
+            name: early_bound_name,
+            body: Some(Group::new(Delimiter::Brace, code)),
+            ..function.clone()
+        };
 
-    std::mem::swap(&mut function.body, &mut early_bound_function.body);
-    virtual_functions.push(early_bound_function);
+        std::mem::swap(&mut function.body, &mut early_bound_function.body);
+        virtual_functions.push(early_bound_function);
+    }
 
     method_name_str
 }
@@ -482,12 +571,28 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(vararg)]
+                let has_vararg = parser.handle_alone("vararg")?;
+
+                // #[func(editor_only)] or #[func(debug_only)]
+                let availability = match handle_mutually_exclusive_keys(
+                    &mut parser,
+                    "#[func]",
+                    &["editor_only", "debug_only"],
+                )? {
+                    Some(0) => FuncAvailability::EditorOnly,
+                    Some(1) => FuncAvailability::DebugOnly,
+                    _ => FuncAvailability::Always,
+                };
+
                 parser.finish()?;
 
                 AttrParseResult::Func(FuncAttr {
                     rename,
                     is_virtual,
                     has_gd_self,
+                    has_vararg,
+                    availability,
                 })
             }
 
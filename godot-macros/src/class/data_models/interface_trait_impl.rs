@@ -468,8 +468,12 @@ impl OverriddenVirtualFn<'_> {
         let pattern = method_name_str;
 
         // Lazily generate code for the actual work (calling user function).
-        let method_callback =
-            make_virtual_callback(class_name, &self.signature_info, self.before_kind);
+        let method_callback = make_virtual_callback(
+            class_name,
+            method_name_str,
+            &self.signature_info,
+            self.before_kind,
+        );
 
         quote! {
             #(#cfg_attrs)*
@@ -81,7 +81,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                 usage_flags = UsageFlags::InferredExport;
             }
 
-            export_hint = export.to_export_hint();
+            export_hint = export.to_export_hint(field_type);
             registration_fn = quote! { register_export };
         } else {
             export_hint = None;
@@ -95,6 +95,19 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             UsageFlags::InferredExport => {
                 quote! { ::godot::global::PropertyUsageFlags::DEFAULT }
             }
+            UsageFlags::InferredWith(flags) => {
+                let base = if export.is_some() {
+                    quote! { ::godot::global::PropertyUsageFlags::DEFAULT }
+                } else {
+                    quote! { ::godot::global::PropertyUsageFlags::NONE }
+                };
+
+                quote! {
+                    #base #(
+                        | ::godot::global::PropertyUsageFlags::#flags
+                    )*
+                }
+            }
             UsageFlags::Custom(flags) => quote! {
                 #(
                     ::godot::global::PropertyUsageFlags::#flags
@@ -134,17 +147,21 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             },
         };
 
-        let getter_name = make_getter_setter(
+        let getter_ident = make_getter_setter(
             getter.to_impl(class_name, GetSet::Get, field),
             &mut getter_setter_impls,
             &mut export_tokens,
         );
-        let setter_name = make_getter_setter(
+        let setter_ident = make_getter_setter(
             setter.to_impl(class_name, GetSet::Set, field),
             &mut getter_setter_impls,
             &mut export_tokens,
         );
 
+        let accessors = make_accessors(class_name, getter_ident.as_ref(), setter_ident.as_ref());
+        let getter_name = ident_name_or_empty(getter_ident.as_ref());
+        let setter_name = ident_name_or_empty(setter_ident.as_ref());
+
         export_tokens.push(quote! {
             ::godot::register::private::#registration_fn::<#class_name, #field_type>(
                 #field_name,
@@ -152,6 +169,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                 #setter_name,
                 #hint,
                 #usage_flags,
+                #accessors,
             );
         });
     }
@@ -177,19 +195,55 @@ fn make_getter_setter(
     getter_setter_impl: Option<GetterSetterImpl>,
     getter_setter_impls: &mut Vec<TokenStream>,
     export_tokens: &mut Vec<TokenStream>,
-) -> String {
-    if let Some(getter_impl) = getter_setter_impl {
-        let GetterSetterImpl {
-            function_name,
-            function_impl,
-            export_token,
-        } = getter_impl;
-
-        getter_setter_impls.push(function_impl);
-        export_tokens.push(export_token);
-
-        function_name.to_string()
-    } else {
-        String::new()
+) -> Option<Ident> {
+    let GetterSetterImpl {
+        function_name,
+        function_impl,
+        export_token,
+    } = getter_setter_impl?;
+
+    getter_setter_impls.push(function_impl);
+    export_tokens.push(export_token);
+
+    Some(function_name)
+}
+
+fn ident_name_or_empty(ident: Option<&Ident>) -> String {
+    ident.map(Ident::to_string).unwrap_or_default()
+}
+
+/// Type-erased accessors for the class's property table, which call the getter/setter without going through Godot.
+fn make_accessors(
+    class_name: &Ident,
+    getter: Option<&Ident>,
+    setter: Option<&Ident>,
+) -> TokenStream {
+    let getter = match getter {
+        Some(getter) => quote! {
+            Some(|this: &::godot::obj::Gd<#class_name>| -> ::godot::builtin::Variant {
+                ::godot::meta::ToGodot::to_variant(&this.bind().#getter())
+            })
+        },
+        None => quote! { None },
+    };
+
+    let setter = match setter {
+        Some(setter) => quote! {
+            Some(|this: &mut ::godot::obj::Gd<#class_name>, value: &::godot::builtin::Variant|
+                -> ::std::result::Result<(), ::godot::meta::error::ConvertError>
+            {
+                let value = ::godot::meta::FromGodot::try_from_variant(value)?;
+                this.bind_mut().#setter(value);
+                Ok(())
+            })
+        },
+        None => quote! { None },
+    };
+
+    quote! {
+        ::godot::register::private::PropertyAccessors::<#class_name> {
+            getter: #getter,
+            setter: #setter,
+        }
     }
 }
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::class::FuncDefinition;
+use crate::class::{make_registered_name, FuncDefinition};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -64,7 +64,7 @@ impl TransferMode {
 pub fn make_rpc_registrations_fn(class_name: &Ident, funcs: &[FuncDefinition]) -> TokenStream {
     let rpc_registrations = funcs
         .iter()
-        .filter_map(make_rpc_registration)
+        .filter_map(|func_def| make_rpc_registration(class_name, func_def))
         .collect::<Vec<TokenStream>>();
 
     // This check is necessary because the class might not implement `WithBaseField` or `Inherits<Node>`,
@@ -97,7 +97,7 @@ pub fn make_rpc_registrations_fn(class_name: &Ident, funcs: &[FuncDefinition]) -
     }
 }
 
-fn make_rpc_registration(func_def: &FuncDefinition) -> Option<TokenStream> {
+fn make_rpc_registration(class_name: &Ident, func_def: &FuncDefinition) -> Option<TokenStream> {
     let rpc_info = func_def.rpc_info.as_ref()?;
 
     let create_struct = match rpc_info {
@@ -149,16 +149,16 @@ fn make_rpc_registration(func_def: &FuncDefinition) -> Option<TokenStream> {
         }
     };
 
-    let method_name_str = if let Some(rename) = &func_def.registered_name {
-        rename.to_string()
-    } else {
-        func_def.signature_info.method_name.to_string()
-    };
+    let method_name = make_registered_name(
+        class_name,
+        &func_def.signature_info.method_name.to_string(),
+        func_def.registered_name.as_deref(),
+    );
 
     let registration = quote! {
         {
             #create_struct
-            args.configure_node(node, #method_name_str)
+            args.configure_node(node, #method_name.as_str())
         }
     };
 
@@ -5,8 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::class::make_registered_name;
 use crate::util;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 /// Holds information known from a signal's definition
@@ -20,6 +21,7 @@ pub struct SignalDefinition {
 
 pub fn make_signal_registrations(
     signals: Vec<SignalDefinition>,
+    class_name: &Ident,
     class_name_obj: &TokenStream,
 ) -> Vec<TokenStream> {
     let mut signal_registrations = Vec::new();
@@ -60,7 +62,7 @@ pub fn make_signal_registrations(
             util::extract_cfg_attrs(external_attributes)
                 .into_iter()
                 .collect();
-        let signal_name_str = signature.name.to_string();
+        let signal_name = make_registered_name(class_name, &signature.name.to_string(), None);
         let signal_parameters_count = param_names.len();
         let signal_parameters = param_array_decl;
 
@@ -73,7 +75,7 @@ pub fn make_signal_registrations(
                 let mut parameters_info_sys: [sys::GDExtensionPropertyInfo; #signal_parameters_count] =
                     std::array::from_fn(|i| parameters_info[i].property_sys());
 
-                let signal_name = ::godot::builtin::StringName::from(#signal_name_str);
+                let signal_name = ::godot::builtin::StringName::from(#signal_name);
 
                 sys::interface_fn!(classdb_register_extension_class_signal)(
                     sys::get_library(),
@@ -5,14 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Punct, TokenStream};
+use proc_macro2::{Delimiter, Ident, Literal, Punct, TokenStream, TokenTree};
 use quote::{format_ident, quote, quote_spanned};
 
 use crate::class::{
     make_property_impl, make_virtual_callback, BeforeKind, Field, FieldDefault, FieldExport,
     FieldVar, Fields, SignatureInfo,
 };
-use crate::util::{bail, error, ident, path_ends_with_complex, require_api_version, KvParser};
+use crate::util::{
+    bail, error, ident, is_punct, path_ends_with_complex, path_is_single, require_api_version,
+    KvParser, ListParser,
+};
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 
 pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
@@ -23,40 +26,79 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         )
     })?;
 
-    if class.generic_params.is_some() {
-        return bail!(
-            &class.generic_params,
-            "#[derive(GodotClass)] does not support lifetimes or generic parameters",
-        );
-    }
-
     let named_fields = named_fields(class)?;
     let mut struct_cfg = parse_struct_attributes(class)?;
     let mut fields = parse_fields(named_fields, struct_cfg.init_strategy)?;
-    let is_editor_plugin = struct_cfg.is_editor_plugin();
+    let instances = make_class_instances(class, &mut struct_cfg)?;
+    let resource_version = parse_resource_version(class)?;
 
     let mut deprecations = std::mem::take(&mut struct_cfg.deprecations);
     deprecations.append(&mut fields.deprecations);
 
     let errors = fields.errors.iter().map(|error| error.to_compile_error());
 
-    let class_name = &class.name;
-    let class_name_str: String = struct_cfg
-        .rename
-        .map_or_else(|| class.name.clone(), |rename| rename)
-        .to_string();
+    let class_impls = instances
+        .iter()
+        .map(|instance| {
+            let impls = make_class_impls(
+                class,
+                &instance.rust_name,
+                &instance.godot_name,
+                &struct_cfg,
+                &fields,
+                resource_version.as_ref(),
+            );
+
+            // Generic parameters are declared as local items, so that field types such as `Vec<T>` resolve to the instantiation.
+            let ClassInstance {
+                alias_decl,
+                param_decls,
+                ..
+            } = instance;
+
+            if param_decls.is_empty() {
+                impls
+            } else {
+                quote! {
+                    #alias_decl
 
-    // Determine if we can use ASCII for the class name (in most cases).
-    let class_name_allocation = if class_name_str.is_ascii() {
-        let c_str = util::c_str(&class_name_str);
-        quote! { ClassName::alloc_next_ascii(#c_str) }
-    } else {
-        quote! { ClassName::alloc_next_unicode(#class_name_str) }
-    };
+                    const _: () = {
+                        #( #param_decls )*
+                        #impls
+                    };
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        #( #class_impls )*
+        #( #deprecations )*
+        #( #errors )*
+    })
+}
+
+/// Generates the trait impls and registration of one Godot class.
+///
+/// For generic structs, `class_name` is the alias of one instantiation.
+fn make_class_impls(
+    class: &venial::Struct,
+    class_name: &Ident,
+    class_name_str: &str,
+    struct_cfg: &ClassAttributes,
+    fields: &Fields,
+    resource_version: Option<&TokenStream>,
+) -> TokenStream {
+    let is_editor_plugin = struct_cfg.is_editor_plugin();
 
     let class_name_obj = util::class_name_obj(class_name);
 
     let is_internal = struct_cfg.is_internal;
+    let icon = match &struct_cfg.icon {
+        Some(icon) => quote! { Some(#icon) },
+        None => quote! { None },
+    };
+    let register_after = &struct_cfg.register_after;
     let base_ty = &struct_cfg.base_ty;
     #[cfg(all(feature = "register-docs", since_api = "4.3"))]
     let docs = crate::docs::make_definition_docs(
@@ -71,7 +113,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", base_ty);
 
     let prv = quote! { ::godot::private };
-    let godot_exports_impl = make_property_impl(class_name, &fields);
+    let godot_exports_impl = make_property_impl(class_name, fields);
 
     let godot_withbase_impl = if let Some(Field { name, ty, .. }) = &fields.base_field {
         // Apply the span of the field's type so that errors show up on the field's type.
@@ -89,6 +131,15 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 }
             }
         }
+    } else {
+        quote! {
+            impl ::godot::obj::WithUserBase for #class_name {}
+        }
+    };
+
+    // Without a base field, instances are registered so that WithUserBase can find their object.
+    let base_lookup_const = if fields.base_field.is_none() {
+        quote! { const __BASE_LOOKUP: bool = true; }
     } else {
         TokenStream::new()
     };
@@ -100,13 +151,23 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let mut godot_init_impl = TokenStream::new();
     let mut create_fn = quote! { None };
     let mut recreate_fn = quote! { None };
+    let mut property_can_revert_fn = quote! { None };
+    let mut property_get_revert_fn = quote! { None };
     let mut is_instantiable = true;
 
     match struct_cfg.init_strategy {
         InitStrategy::Generated => {
-            godot_init_impl = make_godot_init_impl(class_name, &fields);
+            godot_init_impl = make_godot_init_impl(class_name, fields);
             create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
 
+            if let Some(defaults_impl) = make_property_defaults_impl(class_name, fields) {
+                godot_init_impl.extend(defaults_impl);
+                property_can_revert_fn =
+                    quote! { Some(#prv::callbacks::default_property_can_revert::<#class_name>) };
+                property_get_revert_fn =
+                    quote! { Some(#prv::callbacks::default_property_get_revert::<#class_name>) };
+            }
+
             if cfg!(since_api = "4.2") {
                 recreate_fn = quote! { Some(#prv::callbacks::recreate::<#class_name>) };
             }
@@ -134,21 +195,58 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     };
 
     let is_tool = struct_cfg.is_tool;
+    let is_abstract = struct_cfg.is_abstract;
 
-    Ok(quote! {
+    let abstract_const = if is_abstract {
+        quote! { const __IS_ABSTRACT: bool = true; }
+    } else {
+        TokenStream::new()
+    };
+
+    let max_instances_const = match &struct_cfg.max_instances {
+        Some(max) => quote! { const __MAX_INSTANCES: Option<usize> = Some(#max); },
+        None => TokenStream::new(),
+    };
+
+    let func_rename_const = match &struct_cfg.func_rename {
+        Some(policy) => {
+            quote! { const __FUNC_RENAME: #prv::FuncRename = #prv::FuncRename::#policy; }
+        }
+        None => TokenStream::new(),
+    };
+
+    let resource_versioning_const = match resource_version {
+        Some(version) => quote! {
+            const __RESOURCE_VERSIONING: Option<#prv::ResourceVersioning> = Some(#prv::ResourceVersioning {
+                version: #version,
+                migrate: <#class_name as ::godot::tools::MigrateResource>::migrate,
+            });
+        },
+        None => TokenStream::new(),
+    };
+
+    quote! {
         impl ::godot::obj::GodotClass for #class_name {
             type Base = #base_class;
 
             // Code duplicated in godot-codegen.
             fn class_name() -> ::godot::meta::ClassName {
-                use ::godot::meta::ClassName;
-
                 // Optimization note: instead of lazy init, could use separate static which is manually initialized during registration.
-                static CLASS_NAME: std::sync::OnceLock<ClassName> = std::sync::OnceLock::new();
+                static CLASS_NAME: ::godot::meta::StaticClassName = ::godot::meta::StaticClassName::new(#class_name_str);
+                CLASS_NAME.get()
+            }
 
-                let name: &'static ClassName = CLASS_NAME.get_or_init(|| #class_name_allocation);
-                *name
+            fn __property_table() -> Option<&'static #prv::PropertyTable<Self>> {
+                // Per-class static, so that Gd::get_fast() needs no global lock. Filled after all properties have been registered.
+                static PROPERTY_TABLE: ::std::sync::OnceLock<#prv::PropertyTable<#class_name>> = ::std::sync::OnceLock::new();
+                Some(PROPERTY_TABLE.get_or_init(#prv::PropertyTable::collect))
             }
+
+            #max_instances_const
+            #resource_versioning_const
+            #func_rename_const
+            #base_lookup_const
+            #abstract_const
         }
 
         unsafe impl ::godot::obj::Bounds for #class_name {
@@ -163,8 +261,6 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #godot_exports_impl
         #user_class_impl
         #init_expecter
-        #( #deprecations )*
-        #( #errors )*
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
             class_name: #class_name_obj,
@@ -177,10 +273,15 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 },
                 free_fn: #prv::callbacks::free::<#class_name>,
                 default_get_virtual_fn: #default_get_virtual_fn,
+                default_property_can_revert_fn: #property_can_revert_fn,
+                default_property_get_revert_fn: #property_get_revert_fn,
                 is_tool: #is_tool,
                 is_editor_plugin: #is_editor_plugin,
                 is_internal: #is_internal,
                 is_instantiable: #is_instantiable,
+                is_abstract: #is_abstract,
+                icon: #icon,
+                register_after: vec![ #( #prv::user_class_name::<#register_after>() ),* ],
                 #docs
             },
             init_level: {
@@ -201,7 +302,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         });
 
         #prv::class_macros::#inherits_macro!(#class_name);
-    })
+    }
 }
 
 /// Checks at compile time that a function with the given name exists on `Self`.
@@ -228,7 +329,13 @@ struct ClassAttributes {
     init_strategy: InitStrategy,
     is_tool: bool,
     is_internal: bool,
+    is_abstract: bool,
     rename: Option<Ident>,
+    max_instances: Option<TokenStream>,
+    func_rename: Option<Ident>,
+    icon: Option<TokenStream>,
+    register_after: Vec<Ident>,
+    instantiate_as: Vec<Vec<TokenTree>>,
     deprecations: Vec<TokenStream>,
 }
 
@@ -269,6 +376,68 @@ fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     }
 }
 
+/// For `#[export]` fields, generates the values to which the editor's revert button restores them: the `#[init(val)]` expression, or
+/// `Default::default()`.
+///
+/// Returns `None` if there are no exported fields.
+fn make_property_defaults_impl(class_name: &Ident, fields: &Fields) -> Option<TokenStream> {
+    let exported_fields = fields
+        .all_fields
+        .iter()
+        .filter(|field| field.export.is_some() && !field.is_onready)
+        .collect::<Vec<_>>();
+
+    if exported_fields.is_empty() {
+        return None;
+    }
+
+    let property_names = exported_fields
+        .iter()
+        .map(|field| field.name.to_string())
+        .collect::<Vec<_>>();
+
+    let default_arms = exported_fields.iter().map(|field| {
+        let property_name = field.name.to_string();
+        let field_type = &field.ty;
+        let value_expr = field
+            .default_val
+            .clone()
+            .map(|field| field.default_val)
+            .unwrap_or_else(|| quote_spanned! { field.span=> ::std::default::Default::default() });
+
+        quote! {
+            #property_name => {
+                let value: #field_type = #value_expr;
+                let value = <#field_type as ::godot::register::property::Var>::get_property(&value);
+                ::godot::meta::ToGodot::to_variant(&value)
+            }
+        }
+    });
+
+    Some(quote! {
+        impl ::godot::obj::cap::GodotPropertyDefaults for #class_name {
+            // Godot passes the registered property name, which follows the class's `func_rename` policy.
+            fn __godot_has_property_default(property: &str) -> bool {
+                <Self as ::godot::obj::GodotClass>::__FUNC_RENAME
+                    .find_rust_name(&[ #( #property_names ),* ], property)
+                    .is_some()
+            }
+
+            fn __godot_property_default(property: &str) -> Option<::godot::builtin::Variant> {
+                let field = <Self as ::godot::obj::GodotClass>::__FUNC_RENAME
+                    .find_rust_name(&[ #( #property_names ),* ], property)?;
+
+                let value = match field {
+                    #( #default_arms )*
+                    _ => return None,
+                };
+
+                Some(value)
+            }
+        }
+    })
+}
+
 fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
@@ -308,7 +477,12 @@ fn make_user_class_impl(
         let tool_check = util::make_virtual_tool_check();
         let signature_info = SignatureInfo::fn_ready();
 
-        let callback = make_virtual_callback(class_name, &signature_info, BeforeKind::OnlyBefore);
+        let callback = make_virtual_callback(
+            class_name,
+            "_ready",
+            &signature_info,
+            BeforeKind::OnlyBefore,
+        );
 
         // See also __virtual_call() codegen.
         // This doesn't explicitly check if the base class inherits from Node (and thus has `_ready`), but the derive-macro already does
@@ -368,7 +542,13 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_internal = false;
+    let mut is_abstract = false;
     let mut rename: Option<Ident> = None;
+    let mut max_instances = None;
+    let mut func_rename = None;
+    let mut icon = None;
+    let mut register_after = vec![];
+    let mut instantiate_as = vec![];
     let mut deprecations = vec![];
 
     // #[class] attribute on struct
@@ -385,6 +565,18 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             None => {}
         }
 
+        // #[class(abstract)]
+        if let Some(key) = parser.handle_alone_with_span("abstract")? {
+            // Scripts extending the class are instantiated through its constructor.
+            if matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    key,
+                    "#[class(abstract)] cannot be combined with `no_init`; scripts extending the class need a constructor"
+                );
+            }
+            is_abstract = true;
+        }
+
         // #[class(tool)]
         if parser.handle_alone("tool")? {
             is_tool = true;
@@ -407,6 +599,34 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             is_internal = true;
         }
 
+        // #[class(max_instances = 10_000)]
+        max_instances = parser.handle_expr("max_instances")?;
+
+        // #[class(func_rename = camel_case)]
+        if let Some(policy) = parser.handle_ident("func_rename")? {
+            let variant = match policy.to_string().as_str() {
+                "snake_case" => "Verbatim",
+                "camel_case" => "CamelCase",
+                "pascal_case" => "PascalCase",
+                _ => {
+                    return bail!(
+                        policy,
+                        "#[class(func_rename)] expects one of: snake_case, camel_case, pascal_case"
+                    )
+                }
+            };
+            func_rename = Some(ident(variant));
+        }
+
+        // #[class(icon = "res://icon.svg")]
+        icon = parser.handle_expr("icon")?;
+
+        // #[class(after = OtherClass)], #[class(after = [ClassA, ClassB])]
+        register_after = parse_register_after(&mut parser)?;
+
+        // #[class(instantiate_as = Grid<i32> as "IntGrid")], #[class(instantiate_as = [...])]
+        instantiate_as = parse_instantiate_as(&mut parser)?;
+
         // Deprecated #[class(hidden)]
         if let Some(ident) = parser.handle_alone_with_span("hidden")? {
             require_api_version!("4.2", &ident, "#[class(hidden)]")?;
@@ -427,11 +647,327 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         init_strategy,
         is_tool,
         is_internal,
+        is_abstract,
         rename,
+        max_instances,
+        func_rename,
+        icon,
+        register_after,
+        instantiate_as,
         deprecations,
     })
 }
 
+fn parse_register_after(parser: &mut KvParser) -> ParseResult<Vec<Ident>> {
+    let value = match parser.handle_any_entry("after") {
+        None => return Ok(vec![]),
+        Some((key, None)) => {
+            return bail!(
+                key,
+                "expected `after` to be followed by `= ClassName` or `= [ClassA, ClassB]`"
+            )
+        }
+        Some((_, Some(value))) => value,
+    };
+
+    match value.single()? {
+        TokenTree::Ident(class) => Ok(vec![class]),
+        tree => {
+            let mut list = ListParser::new_from_tree(tree, Delimiter::Bracket)?;
+            let mut classes = vec![];
+            while let Some(class) = list.next_ident()? {
+                classes.push(class);
+            }
+            list.finish()?;
+
+            Ok(classes)
+        }
+    }
+}
+
+/// Returns the raw tokens of each `Struct<Args> as "GodotName"` entry, with enclosing parentheses removed.
+fn parse_instantiate_as(parser: &mut KvParser) -> ParseResult<Vec<Vec<TokenTree>>> {
+    let value = match parser.handle_any_entry("instantiate_as") {
+        None => return Ok(vec![]),
+        Some((key, None)) => {
+            return bail!(
+                key,
+                "expected `instantiate_as` to be followed by `= Struct<Args> as \"GodotName\"` or a list of such entries"
+            )
+        }
+        Some((_, Some(value))) => value,
+    };
+
+    let tokens = value.into_tokens();
+    let entries = match tokens.as_slice() {
+        [tree @ TokenTree::Group(group)] if group.delimiter() == Delimiter::Bracket => {
+            let mut list = ListParser::new_from_tree(tree.clone(), Delimiter::Bracket)?;
+            let mut entries = vec![];
+            while list.peek().is_some() {
+                entries.push(list.next_expr()?.into_iter().collect());
+            }
+            list.finish()?;
+
+            entries
+        }
+        _ => vec![tokens],
+    };
+
+    // Parentheses are needed around entries whose generic arguments contain commas, since those separate attribute keys.
+    let entries = entries
+        .into_iter()
+        .map(|entry: Vec<TokenTree>| match entry.as_slice() {
+            [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                group.stream().into_iter().collect()
+            }
+            _ => entry,
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// One Godot class generated by the derive: either the struct itself, or one instantiation of a generic struct.
+struct ClassInstance {
+    /// Name of the Rust type: the struct, or the alias declared for the instantiation.
+    rust_name: Ident,
+
+    /// Name under which the class is registered in Godot.
+    godot_name: String,
+
+    /// For instantiations, declaration of the alias `rust_name`.
+    alias_decl: TokenStream,
+
+    /// For instantiations, one `type T = ...;` or `const N: usize = ...;` item per generic parameter.
+    param_decls: Vec<TokenStream>,
+}
+
+fn make_class_instances(
+    class: &venial::Struct,
+    struct_cfg: &mut ClassAttributes,
+) -> ParseResult<Vec<ClassInstance>> {
+    let instantiations = std::mem::take(&mut struct_cfg.instantiate_as);
+
+    let Some(generic_params) = &class.generic_params else {
+        if let Some(first) = instantiations.first() {
+            let first: TokenStream = first.iter().cloned().collect();
+            return bail!(first, "#[class(instantiate_as)] requires a generic struct");
+        }
+
+        let godot_name = struct_cfg
+            .rename
+            .as_ref()
+            .unwrap_or(&class.name)
+            .to_string();
+
+        return Ok(vec![ClassInstance {
+            rust_name: class.name.clone(),
+            godot_name,
+            alias_decl: TokenStream::new(),
+            param_decls: vec![],
+        }]);
+    };
+
+    if instantiations.is_empty() {
+        return bail!(
+            generic_params,
+            "#[derive(GodotClass)] on generic structs requires #[class(instantiate_as = ...)], \
+            listing the instantiations to register in Godot"
+        );
+    }
+
+    if let Some(rename) = &struct_cfg.rename {
+        return bail!(
+            rename,
+            "#[class(rename)] cannot be combined with #[class(instantiate_as)], which names each instantiation"
+        );
+    }
+
+    let params = generic_params
+        .params
+        .inner
+        .iter()
+        .map(|(param, _punct)| param)
+        .collect::<Vec<_>>();
+
+    if let Some(lifetime) = params.iter().find(|param| param.is_lifetime()) {
+        return bail!(
+            lifetime,
+            "#[derive(GodotClass)] does not support lifetime parameters"
+        );
+    }
+
+    instantiations
+        .into_iter()
+        .map(|entry| parse_class_instance(class, &params, entry))
+        .collect()
+}
+
+/// Parses `Struct<Args> as "GodotName"`.
+fn parse_class_instance(
+    class: &venial::Struct,
+    params: &[&venial::GenericParam],
+    entry: Vec<TokenTree>,
+) -> ParseResult<ClassInstance> {
+    let entry_tokens: TokenStream = entry.iter().cloned().collect();
+    let struct_name = &class.name;
+
+    let Some(as_pos) = entry
+        .iter()
+        .rposition(|tree| matches!(tree, TokenTree::Ident(id) if id == "as"))
+    else {
+        return bail!(
+            entry_tokens,
+            "expected `{struct_name}<Args> as \"GodotName\"`"
+        );
+    };
+
+    let (ty, godot_name) = (&entry[..as_pos], &entry[as_pos + 1..]);
+
+    match ty.first() {
+        Some(TokenTree::Ident(id)) if id == struct_name => {}
+        _ => {
+            return bail!(
+                entry_tokens,
+                "instantiation must be of the form `{struct_name}<Args>`"
+            )
+        }
+    }
+
+    let generic_args: TokenStream = ty[1..].iter().cloned().collect();
+    let args = split_generic_args(&ty[1..], &entry_tokens)?;
+    if args.len() != params.len() {
+        return bail!(
+            generic_args,
+            "expected {} generic arguments, found {}",
+            params.len(),
+            args.len()
+        );
+    }
+
+    let godot_name = match godot_name {
+        [TokenTree::Literal(literal)] => parse_string_literal(literal)?,
+        _ => {
+            return bail!(
+                entry_tokens,
+                "expected the Godot class name as a string literal after `as`"
+            )
+        }
+    };
+
+    let param_decls = params
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            let name = &param.name;
+            match &param.bound {
+                Some((_colon, ty)) if param.is_const() => quote! {
+                    #[allow(dead_code)]
+                    const #name: #ty = #arg;
+                },
+                _ => quote! {
+                    #[allow(dead_code)]
+                    type #name = #arg;
+                },
+            }
+        })
+        .collect();
+
+    let rust_name = util::generic_class_alias(struct_name, &generic_args);
+    let vis = &class.vis_marker;
+    let alias_decl = quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis type #rust_name = #struct_name #generic_args;
+    };
+
+    Ok(ClassInstance {
+        rust_name,
+        godot_name,
+        alias_decl,
+        param_decls,
+    })
+}
+
+/// Splits `<A, B<C, D>, 16>` into its top-level arguments.
+fn split_generic_args(
+    tokens: &[TokenTree],
+    context: &TokenStream,
+) -> ParseResult<Vec<TokenStream>> {
+    let inner = match tokens {
+        [first, inner @ .., last] if is_punct(first, '<') && is_punct(last, '>') => inner,
+        _ => return bail!(context, "expected generic arguments in `<...>`"),
+    };
+
+    let mut args = vec![];
+    let mut current = TokenStream::new();
+    let mut depth = 0;
+
+    for tree in inner {
+        if is_punct(tree, '<') {
+            depth += 1;
+        } else if is_punct(tree, '>') {
+            depth -= 1;
+        } else if depth == 0 && is_punct(tree, ',') {
+            args.push(std::mem::take(&mut current));
+            continue;
+        }
+
+        current.extend([tree.clone()]);
+    }
+
+    // Allow trailing comma.
+    if !current.is_empty() {
+        args.push(current);
+    }
+
+    Ok(args)
+}
+
+fn parse_string_literal(literal: &Literal) -> ParseResult<String> {
+    let text = literal.to_string();
+
+    match text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+    {
+        Some(content) if !content.contains('\\') => Ok(content.to_string()),
+        _ => bail!(
+            literal,
+            "expected a string literal without escape sequences"
+        ),
+    }
+}
+
+/// Parses `#[resource_version(N)]` on the struct, returning the version expression.
+fn parse_resource_version(class: &venial::Struct) -> ParseResult<Option<TokenStream>> {
+    let mut version = None;
+
+    for attr in class.attributes.iter() {
+        if !path_is_single(&attr.path, "resource_version") {
+            continue;
+        }
+
+        if version.is_some() {
+            return bail!(attr, "only a single #[resource_version] attribute allowed");
+        }
+
+        match &attr.value {
+            venial::AttributeValue::Group(_, tokens) if !tokens.is_empty() => {
+                version = Some(tokens.iter().cloned().collect());
+            }
+            _ => {
+                return bail!(
+                    attr,
+                    "expected #[resource_version(N)], where N is the current version"
+                )
+            }
+        }
+    }
+
+    Ok(version)
+}
+
 /// Fetches data for all named fields for a struct.
 ///
 /// Errors if `class` is a tuple struct.
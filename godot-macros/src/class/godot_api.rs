@@ -17,9 +17,13 @@ fn parse_inherent_impl_attr(meta: TokenStream) -> Result<super::InherentImplAttr
     let item = venial_parse_meta(&meta, format_ident!("godot_api"), &quote! { fn func(); })?;
     let mut attr = KvParser::parse_required(item.attributes(), "godot_api", &meta)?;
     let secondary = attr.handle_alone("secondary")?;
+    let iterable = attr.handle_alone("iterable")?;
     attr.finish()?;
 
-    Ok(super::InherentImplAttr { secondary })
+    Ok(super::InherentImplAttr {
+        secondary,
+        iterable,
+    })
 }
 
 pub fn attribute_godot_api(
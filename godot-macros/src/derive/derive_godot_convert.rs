@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::derive::data_models::GodotConvert;
+use crate::derive::data_models::{ConvertType, GodotConvert, ViaType};
 use crate::derive::{make_fromgodot, make_togodot};
 use crate::ParseResult;
 use proc_macro2::{Ident, TokenStream, TokenTree};
@@ -14,7 +14,7 @@ use std::collections::HashMap;
 
 /// Derives `GodotConvert` for the given declaration.
 ///
-/// This also derives `FromGodot` and `ToGodot`.
+/// This also derives `FromGodot` and `ToGodot`, and for enums `AsArg<Via>` (string enums additionally `AsArg<StringName>`).
 pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
     let convert = GodotConvert::parse_declaration(item)?;
 
@@ -24,6 +24,7 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
 
     let to_godot_impl = make_togodot(&convert, &mut cache);
     let from_godot_impl = make_fromgodot(&convert, &mut cache);
+    let as_arg_impl = make_as_arg(&convert);
 
     Ok(quote! {
         impl ::godot::meta::GodotConvert for #name  {
@@ -32,9 +33,56 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
 
         #to_godot_impl
         #from_godot_impl
+        #as_arg_impl
     })
 }
 
+/// Allows enums to be passed directly to engine APIs declaring `impl AsArg<Via>` parameters (e.g. integers or strings).
+///
+/// Not done for newtypes, since their field type is not necessarily a valid parameter type.
+fn make_as_arg(convert: &GodotConvert) -> TokenStream {
+    let ConvertType::Enum { variants, via } = &convert.convert_type else {
+        return TokenStream::new();
+    };
+
+    let name = &convert.ty_name;
+    let via_type = convert.convert_type.via_type();
+
+    // Names of animations, input actions, theme items etc. are mostly `StringName` parameters.
+    let string_name_impl = match via {
+        ViaType::GString { .. } => {
+            let names = variants.enumerator_names();
+            let names_str = names.iter().map(ToString::to_string);
+
+            quote! {
+                impl ::godot::meta::AsArg<::godot::builtin::StringName> for #name {
+                    fn into_arg<'r>(self) -> <::godot::builtin::StringName as ::godot::meta::ParamType>::Arg<'r> {
+                        // Goes through `&'static str`, whose conversion to `StringName` is cached.
+                        let name: &'static str = match self {
+                            #(
+                                #name::#names => #names_str,
+                            )*
+                        };
+
+                        ::godot::meta::AsArg::<::godot::builtin::StringName>::into_arg(name)
+                    }
+                }
+            }
+        }
+        ViaType::Int { .. } => TokenStream::new(),
+    };
+
+    quote! {
+        impl ::godot::meta::AsArg<#via_type> for #name {
+            fn into_arg<'r>(self) -> <#via_type as ::godot::meta::ParamType>::Arg<'r> {
+                ::godot::meta::ParamType::owned_to_arg(::godot::meta::ToGodot::to_godot(&self))
+            }
+        }
+
+        #string_name_impl
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers for submodules
 
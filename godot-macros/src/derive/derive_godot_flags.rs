@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::bail;
+use crate::ParseResult;
+
+/// Derives `GodotFlags` for a fieldless enum, each variant being one flag.
+pub fn derive_godot_flags(item: venial::Item) -> ParseResult<TokenStream> {
+    let venial::Item::Enum(enum_) = item else {
+        return bail!(&item, "#[derive(GodotFlags)] is only supported on enums");
+    };
+
+    if let Some(generic_params) = &enum_.generic_params {
+        return bail!(
+            generic_params,
+            "#[derive(GodotFlags)] does not support generic enums"
+        );
+    }
+
+    let variants: Vec<&venial::EnumVariant> = enum_.variants.items().collect();
+    if variants.is_empty() {
+        return bail!(
+            &enum_,
+            "#[derive(GodotFlags)] requires at least one variant"
+        );
+    }
+
+    let explicit_count = variants.iter().filter(|v| v.value.is_some()).count();
+    if explicit_count != 0 && explicit_count != variants.len() {
+        return bail!(
+            &enum_,
+            "#[derive(GodotFlags)] requires either all or no variants to have explicit bit values"
+        );
+    }
+
+    if explicit_count == 0 && variants.len() > 32 {
+        return bail!(&enum_, "#[derive(GodotFlags)] supports at most 32 flags");
+    }
+
+    let name = &enum_.name;
+    let mut all_entries = Vec::new();
+    let mut match_arms = Vec::new();
+    let mut bit_checks = Vec::new();
+
+    for (index, variant) in variants.iter().enumerate() {
+        if !matches!(variant.fields, venial::Fields::Unit) {
+            return bail!(
+                &variant.fields,
+                "#[derive(GodotFlags)] only supports fieldless variants"
+            );
+        }
+
+        let variant_name = &variant.name;
+        let variant_str = variant_name.to_string();
+
+        let bit = match &variant.value {
+            Some(value) => {
+                let expr = &value.value;
+                let msg = format!("flag {name}::{variant_str} must be a single bit (power of two)");
+                bit_checks.push(quote! {
+                    assert!(((#expr) as u32).is_power_of_two(), #msg);
+                });
+
+                quote! { (#expr) as u32 }
+            }
+            None => {
+                let shift = index as u32;
+                quote! { 1u32 << #shift }
+            }
+        };
+
+        all_entries.push(quote! { (#name::#variant_name, #variant_str) });
+        match_arms.push(quote! { #name::#variant_name => #bit, });
+    }
+
+    Ok(quote! {
+        impl ::godot::register::property::GodotFlags for #name {
+            const ALL: &'static [(Self, &'static str)] = &[ #( #all_entries ),* ];
+
+            fn bit(self) -> u32 {
+                match self {
+                    #( #match_arms )*
+                }
+            }
+        }
+
+        const _: () = {
+            #( #bit_checks )*
+        };
+    })
+}
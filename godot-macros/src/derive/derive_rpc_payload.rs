@@ -0,0 +1,182 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Derives `RpcPayload` for the given declaration.
+///
+/// This also derives `GodotConvert`, `ToGodot` and `FromGodot` with `Via = PackedByteArray`.
+pub fn derive_rpc_payload(item: venial::Item) -> ParseResult<TokenStream> {
+    let version = match KvParser::parse(item.attributes(), "rpc_payload")? {
+        Some(mut parser) => {
+            let version = parser.handle_expr_required("version")?;
+            parser.finish()?;
+
+            quote! { const PAYLOAD_VERSION: u8 = #version; }
+        }
+        None => TokenStream::new(),
+    };
+
+    let (name, encode_body, decode_body) = match &item {
+        venial::Item::Struct(struct_) => {
+            if let Some(generic_params) = &struct_.generic_params {
+                return bail!(
+                    generic_params,
+                    "#[derive(RpcPayload)] does not support lifetimes or generic parameters"
+                );
+            }
+
+            let (pattern, bindings, decode) = make_fields(&struct_.fields, quote! { Self });
+            let encode = quote! {
+                let #pattern = self;
+                #( ::godot::meta::RpcPayload::encode_payload(#bindings, out); )*
+            };
+
+            (&struct_.name, encode, decode)
+        }
+        venial::Item::Enum(enum_) => {
+            if let Some(generic_params) = &enum_.generic_params {
+                return bail!(
+                    generic_params,
+                    "#[derive(RpcPayload)] does not support lifetimes or generic parameters"
+                );
+            }
+
+            let variants = enum_.variants.items().collect::<Vec<_>>();
+            if variants.len() > 256 {
+                return bail!(
+                    &enum_.name,
+                    "#[derive(RpcPayload)] supports at most 256 enum variants"
+                );
+            }
+
+            let mut encode_arms = Vec::new();
+            let mut decode_arms = Vec::new();
+            for (index, variant) in variants.into_iter().enumerate() {
+                let variant_name = &variant.name;
+                let tag = Literal::u8_suffixed(index as u8);
+                let (pattern, bindings, decode) =
+                    make_fields(&variant.fields, quote! { Self::#variant_name });
+
+                encode_arms.push(quote! {
+                    #pattern => {
+                        out.push(#tag);
+                        #( ::godot::meta::RpcPayload::encode_payload(#bindings, out); )*
+                    }
+                });
+                decode_arms.push(quote! {
+                    #tag => #decode,
+                });
+            }
+
+            let enum_name = enum_.name.to_string();
+            let encode = quote! {
+                match self {
+                    #( #encode_arms )*
+                }
+            };
+            let decode = quote! {
+                match <u8 as ::godot::meta::RpcPayload>::decode_payload(input)? {
+                    #( #decode_arms )*
+                    other => {
+                        return Err(::godot::meta::error::ConvertError::new(format!(
+                            "RPC payload has invalid variant index {other} for enum {}",
+                            #enum_name
+                        )))
+                    }
+                }
+            };
+
+            (&enum_.name, encode, decode)
+        }
+        other => {
+            return bail!(
+                other,
+                "#[derive(RpcPayload)] only supports structs and enums"
+            )
+        }
+    };
+
+    Ok(quote! {
+        impl ::godot::meta::RpcPayload for #name {
+            #version
+
+            fn encode_payload(&self, out: &mut Vec<u8>) {
+                #encode_body
+            }
+
+            fn decode_payload(
+                input: &mut ::godot::meta::PayloadReader<'_>,
+            ) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                Ok(#decode_body)
+            }
+        }
+
+        impl ::godot::meta::GodotConvert for #name {
+            type Via = ::godot::builtin::PackedByteArray;
+        }
+
+        impl ::godot::meta::ToGodot for #name {
+            type ToVia<'v> = ::godot::builtin::PackedByteArray;
+
+            fn to_godot(&self) -> ::godot::builtin::PackedByteArray {
+                ::godot::meta::RpcPayload::to_payload_bytes(self)
+            }
+        }
+
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(
+                via: ::godot::builtin::PackedByteArray,
+            ) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                ::godot::meta::RpcPayload::from_payload_bytes(&via)
+            }
+        }
+    })
+}
+
+/// Returns a destructuring pattern for `path`, the bindings it introduces (as references), and the constructor expression decoding all
+/// fields from `input`.
+fn make_fields(
+    fields: &venial::Fields,
+    path: TokenStream,
+) -> (TokenStream, Vec<Ident>, TokenStream) {
+    match fields {
+        venial::Fields::Unit => (path.clone(), vec![], path),
+        venial::Fields::Tuple(tuple) => {
+            let types = tuple.fields.items().map(|field| &field.ty);
+            let bindings = (0..tuple.fields.len())
+                .map(|i| format_ident!("__field{i}"))
+                .collect::<Vec<_>>();
+
+            let pattern = quote! { #path( #( #bindings ),* ) };
+            let decode = quote! {
+                #path( #( <#types as ::godot::meta::RpcPayload>::decode_payload(input)? ),* )
+            };
+
+            (pattern, bindings, decode)
+        }
+        venial::Fields::Named(named) => {
+            let names = named
+                .fields
+                .items()
+                .map(|field| field.name.clone())
+                .collect::<Vec<_>>();
+            let types = named.fields.items().map(|field| &field.ty);
+
+            let pattern = quote! { #path { #( #names ),* } };
+            let decode = quote! {
+                #path { #( #names: <#types as ::godot::meta::RpcPayload>::decode_payload(input)? ),* }
+            };
+
+            (pattern, names, decode)
+        }
+    }
+}
@@ -11,6 +11,9 @@ use quote::{quote, ToTokens};
 use crate::util::{bail, path_ends_with, KvParser};
 use crate::ParseResult;
 
+/// Time after which an async test fails, unless overridden with `#[itest(timeout = ms)]`.
+const DEFAULT_ASYNC_TIMEOUT_MS: usize = 5000;
+
 pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
     let func = match input_item {
         venial::Item::Function(f) => f,
@@ -29,8 +32,18 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
     let mut attr = KvParser::parse_required(&func.attributes, "itest", &func.name)?;
     let skipped = attr.handle_alone("skip")?;
     let focused = attr.handle_alone("focus")?;
+    let timeout_ms = attr.handle_usize("timeout")?;
+    let allow_leaks = attr.handle_alone("allow_leaks")?;
     attr.finish()?;
 
+    let is_async = func.qualifiers.tk_async.is_some();
+    if timeout_ms.is_some() && !is_async {
+        return bail!(
+            func.name,
+            "#[itest]: key `timeout` is only supported for `async fn` tests",
+        );
+    }
+
     if skipped && focused {
         return bail!(
             func.name,
@@ -59,18 +72,52 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
 
     let body = &func.body;
 
+    let (test_fn, function) = if is_async {
+        let timeout_ms = timeout_ms.unwrap_or(DEFAULT_ASYNC_TIMEOUT_MS) as u64;
+
+        // The future outlives the call, so it owns a copy of the context.
+        let test_fn = quote! {
+            pub fn #test_name(
+                __ctx: &crate::framework::TestContext,
+            ) -> crate::framework::AsyncTestFuture {
+                let __ctx = __ctx.clone();
+                ::std::boxed::Box::pin(async move {
+                    #[allow(unused_variables)]
+                    let #param = &__ctx;
+                    #body
+                })
+            }
+        };
+        let function = quote! {
+            crate::framework::TestFunction::Async {
+                function: #test_name,
+                timeout_ms: #timeout_ms,
+            }
+        };
+
+        (test_fn, function)
+    } else {
+        let test_fn = quote! {
+            pub fn #test_name(#param) {
+                #body
+            }
+        };
+        let function = quote! { crate::framework::TestFunction::Sync(#test_name) };
+
+        (test_fn, function)
+    };
+
     Ok(quote! {
-        pub fn #test_name(#param) {
-            #body
-        }
+        #test_fn
 
         ::godot::sys::plugin_add!(__GODOT_ITEST in crate::framework; crate::framework::RustTestCase {
             name: #test_name_str,
             skipped: #skipped,
             focused: #focused,
+            allow_leaks: #allow_leaks,
             file: std::file!(),
             line: std::line!(),
-            function: #test_name,
+            function: #function,
         });
     })
 }
@@ -80,7 +127,9 @@ fn bad_signature(func: &venial::Function) -> Result<TokenStream, venial::Error>
         func,
         "#[itest] function must have one of these signatures:\
         \n  fn {f}() {{ ... }}\
-        \n  fn {f}(ctx: &TestContext) {{ ... }}",
+        \n  fn {f}(ctx: &TestContext) {{ ... }}\
+        \n  async fn {f}() {{ ... }}\
+        \n  async fn {f}(ctx: &TestContext) {{ ... }}",
         f = func.name,
     )
 }
@@ -46,6 +46,10 @@ use crate::util::{bail, ident, KvParser};
 ///    - [Editor plugins](#editor-plugins)
 ///    - [Class renaming](#class-renaming)
 ///    - [Class hiding](#class-hiding)
+///    - [Editor icon](#editor-icon)
+///    - [Registration order](#registration-order)
+///    - [Instance budget](#instance-budget)
+///    - [Resource versioning](#resource-versioning)
 /// - [Further field customization](#further-field-customization)
 ///    - [Fine-grained inference hints](#fine-grained-inference-hints)
 ///
@@ -139,6 +143,10 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// Classes without such a field can still call `self.base()`, `self.base_mut()` and `self.to_gd()` through
+/// [`WithUserBase`](../obj/trait.WithUserBase.html), which looks up the object at runtime. A `Base<T>` field is faster and required for
+/// some features, such as `#[rpc]`.
+///
 ///
 /// # Properties and exports
 ///
@@ -234,6 +242,12 @@ use crate::util::{bail, ident, KvParser};
 /// ```
 ///
 /// If you don't also include a `#[var]` attribute, then a default one will be generated.
+///
+/// With `#[class(init)]`, the editor shows a revert button for exported fields whose value differs from their initial one, i.e. the
+/// `#[init(val = ...)]` expression or `Default::default()`. Implementing `property_get_revert()` in the `I*` trait replaces this behavior.
+/// The default is not cached: the expression is evaluated again each time the editor queries it, so it should be cheap and free of side
+/// effects. Properties are looked up by their registered name, which takes the class's `func_rename` policy into account.
+///
 /// `#[export]` also supports all of GDScript's annotations, in a slightly different format. The format is
 /// translated from an annotation by following these four rules:
 ///
@@ -306,6 +320,51 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// Layer exports such as `flags_2d_physics` show Godot's numbered layer grid, with layer names only visible as tooltips. Adding
+/// `named_layers` instead shows a list of checkboxes labeled with the names configured under `layer_names/*` in the project settings.
+/// The names are read from `ProjectSettings` when the class is registered, so renaming a layer takes effect after reloading the extension.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(flags_2d_physics, named_layers)]
+///     collision_mask: u32,
+/// }
+/// ```
+///
+/// `Dictionary` fields can declare key and value types with `dict_types`. Since Godot 4.4, the editor then shows a typed dictionary
+/// editor, like for `Dictionary[K, V]` in GDScript; the Rust field remains an untyped `Dictionary`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // @export var item_counts: Dictionary[String, int]
+///     #[export(dict_types = (GString, i64))]
+///     item_counts: Dictionary,
+/// }
+/// ```
+///
+/// `color_no_alpha` applies to `Color` fields, and to each element of `PackedColorArray` and `Array<Color>` fields. Other field types
+/// are rejected at compile time.
+///
+/// There are no export keys for `Gd<Curve>` value ranges or `Gd<Gradient>` display options, since Godot has no property hints for them.
+/// The inspector reads these settings from the resource itself, e.g. `Curve::set_min_value()`/`set_max_value()`:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// # use godot::classes::Curve;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(curve_range = (0.0, 10.0))] // Error: not supported.
+///     curve: Option<Gd<Curve>>,
+/// }
+/// ```
+///
 /// You can specify custom property hints, hint strings, and usage flags in a `#[var]` attribute using the
 /// `hint`, `hint_string`, and `usage_flags` keys in the attribute. These are constants in the `PropertyHint`
 /// and `PropertyUsageFlags` enums, respectively.
@@ -327,6 +386,39 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// For the most common cases, there are shortcuts that set the usage flags for you:
+///
+/// | Key                 | Usage flags                                              | Effect                                    |
+/// |---------------------|----------------------------------------------------------|-------------------------------------------|
+/// | `storage`           | `STORAGE` (with `#[export]`: `DEFAULT`)                  | Saved in scenes/resources.                |
+/// | `editor_only`       | `EDITOR`                                                 | Shown in the inspector, but not saved.    |
+/// | `no_instance_state` | inferred flags, or the above, plus `NO_INSTANCE_STATE`   | Not stored as per-instance scene state.   |
+///
+/// `storage` adds to the flags inferred from `#[export]`, so exported properties stay visible in the inspector. Without `#[export]`, the
+/// property is saved, but not shown. `editor_only` removes `STORAGE` also from exported properties.
+///
+/// `storage` and `editor_only` are mutually exclusive, and none of them can be combined with `usage_flags`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // Persisted, but invisible in the inspector.
+///     #[var(storage)]
+///     seed: i64,
+///
+///     // Visible in the inspector for debugging, but never saved.
+///     #[var(editor_only)]
+///     debug_info: GString,
+///
+///     // Exported (DEFAULT usage) + NO_INSTANCE_STATE.
+///     #[export]
+///     #[var(no_instance_state)]
+///     template: Option<Gd<Resource>>,
+/// }
+/// ```
+///
 /// # Signals
 ///
 /// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
@@ -398,6 +490,66 @@ use crate::util::{bail, ident, KvParser};
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
 ///
+/// ## Generic classes
+///
+/// Godot has no concept of generics, so a generic struct cannot be registered as such. Instead, you can list concrete instantiations
+/// with `instantiate_as`, each of which is registered as a separate Godot class under the given name:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, instantiate_as = [Grid<i32> as "IntGrid", Grid<f32> as "FloatGrid"])]
+/// struct Grid<T> {
+///     #[export]
+///     fill: T,
+///     cells: Vec<T>,
+/// }
+///
+/// #[godot_api]
+/// impl Grid<i32> {
+///     #[func]
+///     fn cell_count(&self) -> i64 {
+///         self.cells.len() as i64
+///     }
+/// }
+/// ```
+///
+/// Both type and const parameters are supported, lifetimes are not. A single instantiation does not need brackets. If the generic
+/// arguments contain commas, surround the entry with parentheses: `instantiate_as = [(Ring<i64, 4> as "SmallRing"), ...]`.
+///
+/// `#[godot_api]` blocks must name a concrete instantiation, such as `impl Grid<i32>`, and be located in the same module as the struct,
+/// with the generic arguments spelled the same way. Logic shared by all instantiations can live in a regular `impl<T> Grid<T>` block.
+///
+/// ## Member naming
+///
+/// By default, functions, properties and signals are registered under their Rust (`snake_case`) names. If your API should follow a
+/// different convention in Godot, for example to be consumed from C#, you can set a policy with `func_rename`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, func_rename = camel_case)]
+/// struct Inventory {
+///     #[var]
+///     item_count: i32, // registered as `itemCount`, with accessors `getItemCount` and `setItemCount`
+/// }
+///
+/// #[godot_api]
+/// impl Inventory {
+///     #[signal]
+///     fn item_added(); // registered as `itemAdded`
+///
+///     #[func]
+///     fn add_item(&mut self) {} // registered as `addItem`
+///
+///     #[func(rename = clear)]
+///     fn remove_all_items(&mut self) {} // explicit rename: registered as `clear`
+/// }
+/// ```
+///
+/// Supported policies are `snake_case` (the default), `camel_case` and `pascal_case`. Leading underscores are kept.
+/// Explicit `#[func(rename)]` names are taken verbatim, as are virtual methods from `I*` traits, which Godot looks up by their engine name.
+///
 /// ## Class hiding
 ///
 /// If you want to register a class with Godot, but not have it show up in the editor then you can use `#[class(internal)]`.
@@ -412,6 +564,111 @@ use crate::util::{bail, ident, KvParser};
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hidden` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Abstract classes
+///
+/// A class declared with `#[class(abstract)]` is meant to be extended by scripts, not instantiated directly. Rust constructors like
+/// `new_gd()`, `new_alloc()` and `Gd::from_init_fn()` panic for such classes.
+///
+/// On the Godot side, the class is registered as _virtual_, like engine base classes such as `Mesh` or `Texture2D`. This is a hint for the
+/// editor, not an enforced restriction: Godot creates instances of scripts extending the class through the class's own constructor, so
+/// `ClassDB.can_instantiate()` returns `true`, and GDScript's `ClassName.new()` or `ClassDB.instantiate()` cannot be refused. Such direct
+/// instances are reported as an error naming the class, after the current frame (when it is known that no script has been attached). This
+/// check requires a `SceneTree` main loop. For the same reason, `abstract` cannot be combined with `no_init`, which makes Godot refuse all
+/// instantiation (including of extending scripts).
+///
+/// Methods that scripts must implement are declared as [`#[func(virtual)]`](attr.godot_api.html#virtual-methods) without a body:
+///
+/// ```no_run
+/// # #[cfg(since_api = "4.3")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, abstract, base=Node)]
+/// pub struct Weapon {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Weapon {
+///     #[func(virtual)]
+///     fn damage(&self) -> i64;
+/// }
+/// # }
+/// ```
+///
+/// ## Editor icon
+///
+/// Classes show the icon of their base class in the editor's scene tree and "Create New Node" dialog. To use a custom icon, provide
+/// either a resource path or the SVG source code itself:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node2D, init, icon = "res://icons/enemy.svg")]
+/// pub struct Enemy {}
+///
+/// const SPAWNER_ICON: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+///     <circle cx="8" cy="8" r="6" fill="#8eef97"/>
+/// </svg>"##;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init, icon = SPAWNER_ICON)]
+/// pub struct Spawner {}
+/// ```
+///
+/// The value can be any `&'static str` expression, so `include_str!("spawner.svg")` works, too.
+///
+/// Icons are scaled to the editor's icon size (16 pixels times the editor scale). Embedded SVG is rendered at that resolution, so it stays
+/// sharp on high-DPI screens. Requires Godot 4.2 or later; older versions, and builds without the full codegen, ignore the icon.
+///
+/// Godot's creation dialog groups classes only by inheritance, so there is no separate category setting.
+///
+/// ## Registration order
+///
+/// Classes of the same init level are registered in alphabetical order, except that a class always comes after its base class.
+/// If registration of one class relies on another one being available in Godot (e.g. because a `register_class()` hook or a static
+/// initializer looks it up in `ClassDB`), you can declare this dependency explicitly:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init)]
+/// pub struct Registry {}
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init, after = Registry)]
+/// pub struct Spawner {}
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init, after = [Registry, Spawner])]
+/// pub struct Level {}
+/// ```
+///
+/// Dependencies must be Rust classes, registered at the same or an earlier [init level](../init/enum.InitLevel.html). Cyclic
+/// dependencies, and dependencies on classes that are not registered by then, cause a panic during registration, naming the classes
+/// involved. Classes are unregistered in reverse order.
+///
+/// ## Instance budget
+///
+/// To catch leaks or runaway spawners during development, you can declare how many instances of a class are expected to be alive at most:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node2D, init, max_instances = 10_000)]
+/// pub struct Bullet {}
+/// ```
+///
+/// In Debug builds, live instances are then counted, and an error is printed once the count exceeds the budget. It is reported again
+/// only after the count has dropped back within the budget. In Release builds, the counting is compiled out.
+///
+/// ## Resource versioning
+///
+/// Resource classes whose stored fields change over time can declare a version with `#[resource_version(N)]`, and implement
+/// [`MigrateResource`](../godot/tools/trait.MigrateResource.html) to upgrade data saved by older versions. Migration happens automatically
+/// only when loading through `godot::tools::load()`/`try_load()`; other loading paths require an explicit call to `migrate_resource()`.
+/// See the trait for details.
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
@@ -485,7 +742,10 @@ use crate::util::{bail, ident, KvParser};
     alias = "tool",
     alias = "rename"
 )]
-#[proc_macro_derive(GodotClass, attributes(class, base, hint, var, export, init))]
+#[proc_macro_derive(
+    GodotClass,
+    attributes(class, base, hint, var, export, init, resource_version)
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -531,6 +791,7 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///   - [RPC attributes](#rpc-attributes)
 /// - [Constants and signals](#signals)
 /// - [Multiple inherent `impl` blocks](#multiple-inherent-impl-blocks)
+/// - [Iteration and operators](#iteration-and-operators)
 ///
 /// # Constructors
 ///
@@ -686,8 +947,65 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Now, `obj.language()` from Rust will dynamically dispatch the call.
 ///
+/// A virtual method can be declared without a body, if scripts are required to override it. Calling such a method on an object whose
+/// script doesn't override it panics. This is typically combined with [`#[class(abstract)]`](derive.GodotClass.html#abstract-classes).
+///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
+/// ## Variadic functions
+///
+/// With `#[func(vararg)]`, the function accepts any number of arguments after its declared parameters. The last parameter must be of type
+/// [`VarArgs`](../meta/struct.VarArgs.html), which iterates over the extra arguments as `Variant`s and offers typed access via `next_as()`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::meta::VarArgs;
+///
+/// # #[derive(GodotClass)]
+/// # #[class(init, base=Node)]
+/// # struct MyStruct {
+/// #     base: Base<Node>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     // GDScript: obj.broadcast("on_hit", 10, "fire")
+///     #[func(vararg)]
+///     fn broadcast(&mut self, method: StringName, args: VarArgs) {
+///         let args: VariantArray = args.collect();
+///         self.base_mut().propagate_call_ex(&method).args(&args).done();
+///     }
+/// }
+/// ```
+///
+/// Godot does not check the argument count of variadic functions; calls with fewer arguments than declared parameters fail at runtime.
+/// Variadic functions cannot be `virtual`.
+///
+/// ## Editor-only and debug-only functions
+///
+/// Some functions are only meant for tooling, and should not be callable in exported games. With `#[func(editor_only)]`, the function is only
+/// registered if the engine runs as the editor (`Engine.is_editor_hint()`). With `#[func(debug_only)]`, it is only registered in debug builds
+/// of the engine, i.e. the editor and debug export templates (`OS.is_debug_build()`).
+///
+/// Both are checked at runtime, when the class is registered. The Rust method itself is still compiled and can be called from Rust; only its
+/// exposure to Godot is skipped. Calling an unregistered function from GDScript fails like calling any other non-existent method.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init, tool, base=Node)]
+/// # struct Level {
+/// #     base: Base<Node>,
+/// # }
+/// #[godot_api]
+/// impl Level {
+///     #[func(editor_only)]
+///     fn bake_navigation(&mut self) { /* ... */ }
+///
+///     #[func(debug_only)]
+///     fn cheat_unlock_all(&mut self) { /* ... */ }
+/// }
+/// ```
+///
 /// ## RPC attributes
 ///
 /// You can use the `#[rpc]` attribute to let your functions act as remote procedure calls (RPCs) in Godot. This is the Rust equivalent of
@@ -797,6 +1115,39 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///     pub fn two(&self) { }
 /// }
 /// ```
+///
+/// # Iteration and operators
+///
+/// GDScript `for` loops can iterate over objects which implement the methods `_iter_init()`, `_iter_next()` and `_iter_get()`.
+/// With the `iterable` key, these methods are registered for you, forwarding to the class' [`ScriptIterable`](../obj/trait.ScriptIterable.html)
+/// impl. The key can be added to any of the class' inherent `impl` blocks, primary or secondary.
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::ScriptIterable;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Inventory {
+///     items: Vec<GString>,
+/// }
+///
+/// #[godot_api(iterable)]
+/// impl Inventory {
+///     #[func]
+///     fn count(&self) -> i64 { self.items.len() as i64 }
+/// }
+///
+/// impl ScriptIterable for Inventory {
+///     type Item = GString;
+///
+///     fn script_iter_len(&self) -> usize { self.items.len() }
+///     fn script_iter_get(&self, index: usize) -> GString { self.items[index].clone() }
+/// }
+/// ```
+///
+/// Godot has no equivalent for operators: expressions like `a + b` or `a[i]` are not supported for objects in GDScript, independent of
+/// the language the class is written in. Expose named `#[func]` methods such as `add()` or `at()` instead. For dynamic property
+/// access (`obj.some_name`), override `get_property()`/`set_property()` in the class' `I*` interface trait.
 #[doc(
     alias = "func",
     alias = "rpc",
@@ -804,7 +1155,8 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     alias = "signal",
     alias = "constant",
     alias = "rename",
-    alias = "secondary"
+    alias = "secondary",
+    alias = "iterable"
 )]
 #[proc_macro_attribute]
 pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
@@ -986,11 +1338,91 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ## Passing enums to engine APIs
+///
+/// Enums additionally implement [`AsArg<Via>`](../meta/trait.AsArg.html), so they can be passed directly to engine parameters that
+/// conventionally hold a game-defined enum -- without calling `to_godot()`. This applies to a fixed list of item IDs (e.g. `PopupMenu`
+/// item IDs) and layer numbers (e.g. collision layers):
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::PopupMenu;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = i32)]
+/// enum MenuAction {
+///     NewGame = 1,
+///     Quit = 2,
+/// }
+///
+/// let mut menu = PopupMenu::new_alloc();
+/// menu.add_item_ex("New game").id(MenuAction::NewGame).done();
+/// menu.add_item_ex("Quit").id(MenuAction::Quit).done();
+/// ```
+///
+/// The `via` type must match the parameter type exactly (`i32` for IDs and layer numbers). Other integer parameters are declared by value,
+/// so enums must be converted with `to_godot()` there.
+///
+/// Enums with `via = GString` can be passed to both `GString` and `StringName` parameters. The latter are common for names of animations,
+/// input actions or theme items, which keeps such call sites typo-proof:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::AnimationPlayer;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = GString)]
+/// enum PlayerAnimation {
+///     Idle,
+///     Run,
+/// }
+///
+/// fn start_running(player: &mut Gd<AnimationPlayer>) {
+///     player.play_ex().name(PlayerAnimation::Run).done();
+/// }
+/// ```
+///
+/// The string is the enumerator's name, exactly as written.
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
 }
 
+/// Derive macro for [`RpcPayload`](../meta/trait.RpcPayload.html) on structs and enums.
+///
+/// Encodes the fields in declaration order into a compact `PackedByteArray`; every field type must implement `RpcPayload` itself. Enum
+/// variants are prefixed with their index as a byte, so enums may have at most 256 variants.
+///
+/// The type also gets `GodotConvert`, `ToGodot` and `FromGodot` implementations with `Via = PackedByteArray`, so it can be used directly
+/// as parameter of `#[rpc]` functions. Decoding fails with a `ConvertError` if the payload is malformed or its version doesn't match.
+///
+/// # Attributes
+/// `#[rpc_payload(version = N)]` sets [`RpcPayload::PAYLOAD_VERSION`](../meta/trait.RpcPayload.html#associatedconstant.PAYLOAD_VERSION),
+/// which defaults to 0. Increment it whenever the layout of the type changes.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::meta::RpcPayload;
+///
+/// #[derive(RpcPayload, PartialEq, Debug)]
+/// #[rpc_payload(version = 1)]
+/// enum Command {
+///     Move { target: Vector2i },
+///     Chat(GString),
+///     Leave,
+/// }
+///
+/// let bytes = Command::Chat("hello".into()).to_payload_bytes();
+/// let decoded = Command::from_payload_bytes(&bytes).expect("valid payload");
+/// assert_eq!(decoded, Command::Chat("hello".into()));
+/// ```
+#[proc_macro_derive(RpcPayload, attributes(rpc_payload))]
+pub fn derive_rpc_payload(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_rpc_payload)
+}
+
 /// Derive macro for [`Var`](../register/property/trait.Var.html) on enums.
 ///
 /// This expects a derived [`GodotConvert`](../meta/trait.GodotConvert.html) implementation, using a manual
@@ -1008,9 +1440,50 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [`GodotFlags`](../register/property/trait.GodotFlags.html) on fieldless enums.
+///
+/// Each variant is one flag. The enum is then used through [`FlagSet`](../register/property/struct.FlagSet.html), which can be a
+/// `#[var]` or `#[export]` property and shows up in the editor as a list of checkboxes (like `@export_flags` in GDScript).
+///
+/// Without explicit discriminants, variants are assigned consecutive bits `1, 2, 4, ...` in declaration order. Discriminants can be
+/// given to choose the bits; in that case, every variant needs one and each must be a power of two. The enum must also implement
+/// `Copy`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::register::property::FlagSet;
+///
+/// #[derive(GodotFlags, Copy, Clone, Debug)]
+/// enum Layer {
+///     Ground = 1,
+///     Water = 4,
+///     Air = 8,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Unit {
+///     #[export]
+///     walkable: FlagSet<Layer>,
+/// }
+///
+/// impl Unit {
+///     fn can_swim(&self) -> bool {
+///         self.walkable.contains(Layer::Water)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(GodotFlags)]
+pub fn derive_godot_flags(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_godot_flags)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
+///
+/// `async fn` tests are driven by the main loop and can await frames and signals. They fail if they don't complete within
+/// `#[itest(timeout = ms)]` milliseconds (default 5000).
 #[proc_macro_attribute]
 pub fn itest(meta: TokenStream, input: TokenStream) -> TokenStream {
     translate_meta("itest", meta, input, itest::attribute_itest)
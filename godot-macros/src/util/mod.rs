@@ -116,7 +116,7 @@ pub fn make_signature_tuple_type(
     }
 }
 
-fn is_punct(tt: &TokenTree, c: char) -> bool {
+pub(crate) fn is_punct(tt: &TokenTree, c: char) -> bool {
     match tt {
         TokenTree::Punct(punct) => punct.as_char() == c,
         _ => false,
@@ -196,13 +196,14 @@ pub(crate) fn validate_trait_impl_virtual<'a>(
 
 fn validate_self(original_impl: &venial::Impl, attr: &str) -> ParseResult<Ident> {
     if let Some(segment) = extract_typename(&original_impl.self_ty) {
-        if segment.generic_args.is_none() {
-            Ok(segment.ident)
-        } else {
-            bail!(
-                original_impl,
-                "#[{attr}] for does currently not support generic arguments",
-            )
+        match &segment.generic_args {
+            None => Ok(segment.ident),
+
+            // Instantiation of a generic class, e.g. `impl IntGrid<i32>`: refer to the alias declared by #[derive(GodotClass)].
+            Some(generic_args) => Ok(generic_class_alias(
+                &segment.ident,
+                &generic_args.to_token_stream(),
+            )),
         }
     } else {
         bail!(
@@ -212,6 +213,37 @@ fn validate_self(original_impl: &venial::Impl, attr: &str) -> ParseResult<Ident>
     }
 }
 
+/// Name of the type alias which `#[derive(GodotClass)]` declares for an instantiation of a generic class, e.g. `__godot_IntGrid_i32`.
+///
+/// Only identifiers and literals of the generic arguments contribute to the name, so that `#[godot_api]` impls can derive the same
+/// alias from their `Self` type, regardless of spacing or punctuation.
+pub(crate) fn generic_class_alias(class_name: &Ident, generic_args: &TokenStream) -> Ident {
+    fn collect_parts(tokens: TokenStream, parts: &mut Vec<String>) {
+        for tree in tokens {
+            let part = match tree {
+                TokenTree::Group(group) => {
+                    collect_parts(group.stream(), parts);
+                    continue;
+                }
+                TokenTree::Ident(ident) => ident.to_string(),
+                TokenTree::Literal(literal) => literal.to_string(),
+                TokenTree::Punct(_) => continue,
+            };
+
+            let part = part
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            parts.push(part);
+        }
+    }
+
+    let mut parts = vec![];
+    collect_parts(generic_args.clone(), &mut parts);
+
+    format_ident!("__godot_{}_{}", class_name, parts.join("_"))
+}
+
 /// Gets the right-most type name in the path.
 pub(crate) fn extract_typename(ty: &venial::TypeExpr) -> Option<venial::PathSegment> {
     match ty.as_path() {
@@ -90,13 +90,47 @@
 //!   Instead of loading all engine function pointers at startup, load them lazily on first use. This reduces startup time and RAM usage, but
 //!   incurs additional overhead in each FFI call. Also, you lose the guarantee that once the library has booted, all function pointers are
 //!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
-//!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.<br><br>
+//!
+//!   This feature also reduces **binary size**. With eagerly loaded tables, the startup code references the name and hash of every engine
+//!   method, keeping all of them alive in the final binary. With lazy tables, this information only lives at the call sites of each method,
+//!   so the linker can strip methods (and entire classes) which your code never calls. This is especially relevant for Wasm and mobile
+//!   exports. The gdext repository measures this with `check.sh size`, which builds the `dodge-the-creeps` example as a release cdylib
+//!   with `lto = true` and `strip = true`, natively and for `wasm32-unknown-emscripten`, with and without this feature. To measure the
+//!   effect for your own project, compare release builds in the same way, e.g. using [`cargo bloat`](https://github.com/RazrFalcon/cargo-bloat)
+//!   or [`twiggy`](https://github.com/rustwasm/twiggy) for Wasm.
+//!
+//!   This feature can be combined with `experimental-threads`. In that case, the lazy tables are synchronized with a mutex, and **every**
+//!   engine call looks up its function pointer under that lock -- not just the first one. Multithreaded code calling into the engine
+//!   frequently can thus suffer from lock contention.<br><br>
+//!
+//! * **`ffi-stats`**
+//!
+//!   Counts and times every call from Rust into an engine method, builtin method or utility function. The results are available
+//!   through `tools::ffi_stats::dump()` and related functions, e.g. to find out which engine calls dominate a slow frame. Each call
+//!   takes a global lock while recording, so only enable this for diagnostics. Without the feature, there is no overhead.<br><br>
+//!
+//! * **`mem-stats`**
+//!
+//!   Provides `tools::mem_stats`, wrappers around Godot's allocator (`alloc()`, `realloc()`, `free()`) that attribute each allocation
+//!   to a category chosen with `mem_stats::scope()`, e.g. per subsystem. Current and peak totals can be queried, and a hook can observe
+//!   individual allocations. Allocations made inside the engine are attributed to the active scope by sampling the engine's static
+//!   memory usage when the scope is entered and left (debug engine builds only).<br><br>
+//!
+//! * **`debug-ptrcall-checks`**
+//!
+//!   In debug builds, validates pointer calls (ptrcalls) to engine methods and from Godot into virtual methods: the argument and
+//!   return types are compared to the method's signature in ClassDB, and a mismatch panics with a description of the affected
+//!   parameter, instead of silently corrupting memory. Methods without engine metadata are not checked. Each call performs a
+//!   lookup under a global lock; release builds are not affected. With this feature, error messages of engine calls name classes
+//!   and methods as in Godot (e.g. `GDScript`), not as in Rust.<br><br>
 //!
 //! * **`experimental-threads`**
 //!
 //!   Experimental threading support. This adds synchronization to access the user instance in `Gd<T>` and disables several single-thread checks.
 //!   The safety aspects are not ironed out yet; there is a high risk of unsoundness at the moment.
-//!   As this evolves, it is very likely that the API becomes stricter.<br><br>
+//!   As this evolves, it is very likely that the API becomes stricter.
+//!   Also enables running Rust closures on Godot's `WorkerThreadPool`, via `tools::spawn_task()` and related APIs, and loading resources
+//!   in the background via `tools::load_async()`, since both run Rust code on Godot's worker threads.<br><br>
 //!
 //! * **`experimental-wasm`**
 //!
@@ -111,7 +145,18 @@
 //!
 //!   Requires the `experimental-wasm` feature. Disables threading support for WebAssembly exports. This needs to be kept in sync with
 //!   Godot's Web export setting (threading support disabled), and must _not_ use the `"-C", "link-args=-sUSE_PTHREADS=1"` flag in the
-//!   `wasm32-unknown-unknown` target.<br><br>
+//!   `wasm32-unknown-unknown` target.
+//!
+//!   Without threads, some APIs degrade or are unavailable:
+//!   * `tools::spawn_local()`, `tools::spawn_awaitable()` and signal futures work; woken tasks are polled once per frame from the
+//!     scene tree's `process_frame` signal.
+//!   * `tools::load_async()` and the worker-pool functions (`tools::spawn_task()` etc.) require `experimental-threads`, which cannot be combined with this feature.<br><br>
+//!
+//! * **`fixed-point`**
+//!
+//!   Provides `builtin::Fixed`, a 32.32 fixed-point number, and the vectors `FixedVector2`/`FixedVector3` mirroring `Vector2`/`Vector3`.
+//!   Their arithmetic is integer-only and thus bit-identical across platforms, for deterministic (e.g. lockstep) simulations that convert
+//!   to engine types only at the boundary. Only basic vector operations are provided; this is not meant as a general math library.<br><br>
 //!
 //! * **`codegen-rustfmt`**
 //!
@@ -123,6 +168,7 @@
 //!   Generates documentation for your structs from your Rust documentation.
 //!   Documentation is visible in Godot via `F1` -> searching for that class.
 //!   This feature requires at least Godot 4.3.
+//!   Documentation can also be supplied at runtime, e.g. for dynamically created members, via `docs::amend_class_docs()`.
 //!   See also: [`#[derive(GodotClass)]`](register/derive.GodotClass.html#documentation)
 //!
 //! _Integrations:_
@@ -131,6 +177,32 @@
 //!
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
+//!   Also enables the `godot::serde` module, which converts between `Variant` and any serde-compatible type.
+//!
+//! * **`log`**
+//!
+//!   Provides [`GodotLogger`](tools::GodotLogger), a logger for the [log](https://docs.rs/log) crate which forwards records to the
+//!   Godot console (`godot_print!`, `godot_warn!`, `godot_error!`). Return it from `ExtensionLibrary::logger()` to install it.<br><br>
+//!
+//! * **`tracing`**
+//!
+//!   Provides [`GodotTracingLayer`](tools::GodotTracingLayer), a [tracing-subscriber](https://docs.rs/tracing-subscriber) layer which
+//!   forwards events to the Godot console. Return it from `ExtensionLibrary::tracing_layer()` to install it.<br><br>
+//!
+//! * **`ecs-bridge`**
+//!
+//!   Provides [`tools::ecs`], utilities to mirror Godot objects into an entity component system (ECS) such as `bevy_ecs` or `hecs`:
+//!   a mapping between instance IDs and entities, lifecycle events of user classes, and batched application of changes.<br><br>
+//!
+//! * **`variant-parser`**
+//!
+//!   Provides [`tools::variant_parser`], a pure-Rust parser and formatter for Godot's variant text format (`var_to_str()`,
+//!   `.tres`/`.tscn` values). It works without a running engine and reports errors with line and column.<br><br>
+//!
+//! * **`api-introspection`**
+//!
+//!   Provides [`meta::introspection`], static tables describing all generated engine classes with their methods and signals, as well as
+//!   a JSON dump. Meant for tools building on top of godot-rust, which would otherwise have to parse `extension_api.json` themselves.
 //!
 
 #![doc(
@@ -143,9 +215,6 @@ pub mod __docs;
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Validations
 
-#[cfg(all(feature = "lazy-function-tables", feature = "experimental-threads"))]
-compile_error!("Thread safety for lazy function pointers is not yet implemented.");
-
 #[cfg(all(
     feature = "experimental-wasm-nothreads",
     feature = "experimental-threads"
@@ -183,8 +252,14 @@ pub mod init {
 
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
+    pub use godot_core::registry::class::{registered_classes, ClassMetadata};
+    pub use godot_core::registry::instance_observers::{
+        observe_instances, InstanceEvent, InstanceObserver,
+    };
     pub use godot_core::registry::property;
-    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{
+        godot_api, godot_dyn, Export, GodotClass, GodotConvert, GodotFlags, RpcPayload, Var,
+    };
 
     #[cfg(feature = "__codegen-full")]
     pub use godot_core::registry::RpcConfig;
@@ -200,6 +275,12 @@ pub mod register {
     }
 }
 
+/// Conversion between `Variant` and types implementing serde's `Serialize`/`Deserialize`.
+#[cfg(feature = "serde")]
+pub mod serde {
+    pub use godot_core::serde_variant::*;
+}
+
 /// Testing facilities (unstable).
 #[doc(hidden)]
 pub mod test {
@@ -8,20 +8,25 @@
 pub use super::register::property::{Export, Var};
 
 // Re-export macros.
-pub use super::register::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+pub use super::register::{
+    godot_api, godot_dyn, Export, GodotClass, GodotConvert, GodotFlags, RpcPayload, Var,
+};
 
 pub use super::builtin::__prelude_reexport::*;
 pub use super::builtin::math::FloatExt as _;
 pub use super::meta::error::{ConvertError, IoError};
 pub use super::meta::{FromGodot, GodotConvert, ToGodot};
 
+#[cfg(feature = "__codegen-full")]
+pub use super::classes::tween::{EaseType, TransitionType};
 pub use super::classes::{
     AudioStreamPlayer, Camera2D, Camera3D, IAudioStreamPlayer, ICamera2D, ICamera3D, INode,
     INode2D, INode3D, IObject, IPackedScene, IRefCounted, IResource, ISceneTree, Input, Node,
     Node2D, Node3D, Object, PackedScene, RefCounted, Resource, SceneTree,
 };
 pub use super::global::{
-    godot_error, godot_print, godot_print_rich, godot_script_error, godot_warn,
+    assert_freed, assert_gd_eq, godot_assert, godot_error, godot_print, godot_print_rich,
+    godot_script_error, godot_warn,
 };
 pub use super::tools::{load, save, try_load, try_save, GFile};
 
@@ -37,3 +42,4 @@ pub use super::obj::EngineEnum as _;
 pub use super::obj::NewAlloc as _;
 pub use super::obj::NewGd as _;
 pub use super::obj::WithBaseField as _; // base(), base_mut(), to_gd()
+pub use super::obj::WithUserBase as _; // base(), base_mut(), to_gd() without Base<T> field
@@ -390,3 +390,20 @@ func test_get_set():
 	assert_eq(obj.set_get, 1000)
 	assert(obj.is_set_called())
 	assert(obj.is_get_called())
+
+func test_script_iterable():
+	var words = IterableWords.new()
+	words.push("first")
+	words.push("second")
+
+	var collected = []
+	for word in words:
+		collected.append(word)
+
+	assert_eq(collected, ["first", "second"])
+
+func test_func_vararg():
+	var obj = VarargObj.new()
+	assert_eq(obj.sum(), 0)
+	assert_eq(obj.sum(4, 5, 6), 15)
+	assert_eq(VarargObj.join(", ", "a", 1, true), "a, 1, true")
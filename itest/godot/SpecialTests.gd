@@ -52,3 +52,12 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+# Awaiting a Rust #[func] that returns spawn_awaitable(): the future completes only after the current frame.
+# The class is only registered in Godot 4.2+, so it's looked up dynamically.
+func test_func_awaitable():
+	if not ClassDB.class_exists("AsyncFuncs"):
+		return
+
+	var obj = ClassDB.instantiate("AsyncFuncs")
+	var result = await obj.double_later(21)
+	assert_eq(result, 42)
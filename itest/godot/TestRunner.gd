@@ -20,12 +20,15 @@ func _ready():
 	await get_tree().physics_frame
 
 	var allow_focus := true
+	var leak_check := false
 	var filters: Array = []
 	var unrecognized_args: Array = []
 	for arg in OS.get_cmdline_user_args():
 		match arg:
 			"--disallow-focus":
 				allow_focus = false
+			"--leak-check":
+				leak_check = true
 			_:
 				if not arg.begins_with("[") or not arg.ends_with("]"):
 					unrecognized_args.push_back(arg)
@@ -66,15 +69,22 @@ func _ready():
 
 	var property_tests = load("res://gen/GenPropertyTests.gd").new()
 
-	var success: bool = rust_runner.run_all_tests(
+	rust_runner.begin_tests(
 		gdscript_tests,
 		gdscript_suites.size(),
 		allow_focus,
 		self,
 		filters,
-		property_tests
+		property_tests,
+		leak_check
 	)
 
+	# Async Rust tests are driven by the main loop, so they need to be awaited here. Not available before Godot 4.2.
+	if rust_runner.has_method("run_async_tests"):
+		await rust_runner.run_async_tests()
+
+	var success: bool = rust_runner.finish_tests()
+
 	if success:
 		rust_runner.run_all_benchmarks(self)
 
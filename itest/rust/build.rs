@@ -456,6 +456,30 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
         }
     }
 
+    // GDScript supports hint annotations on typed arrays since Godot 4.3.
+    if godot_bindings::since_api("4.3") {
+        rust.push(quote! {
+            #[export(color_no_alpha)]
+            export_color_no_alpha_array: Array<Color>
+        });
+
+        gdscript.push(String::from(
+            "@export_color_no_alpha var export_color_no_alpha_array: Array[Color]",
+        ));
+    }
+
+    // Typed dictionaries are available since Godot 4.4.
+    if godot_bindings::since_api("4.4") {
+        rust.push(quote! {
+            #[export(dict_types = (GString, i64))]
+            export_dict_types_string_int: Dictionary
+        });
+
+        gdscript.push(String::from(
+            "@export var export_dict_types_string_int: Dictionary[String, int]",
+        ));
+    }
+
     let rust = quote! {
         #[derive(GodotClass)]
         #[class(base = Node, init)]
@@ -10,10 +10,14 @@
 use std::hint::black_box;
 
 use godot::builtin::inner::InnerRect2i;
-use godot::builtin::{GString, Rect2i, StringName, Vector2i};
-use godot::classes::{Node3D, Os, RefCounted};
+use godot::builtin::{
+    real, Array, Basis, Callable, GString, PackedVector3Array, Rect2i, Signal, StringName,
+    Transform3D, Variant, VariantArray, Vector2i, Vector3,
+};
+use godot::classes::{Node, Node3D, Os, RefCounted, SceneTree, Window};
+use godot::meta::{StaticStringName, ToGodot};
 use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
-use godot::register::GodotClass;
+use godot::register::{godot_api, GodotClass};
 
 use crate::framework::bench;
 
@@ -93,9 +97,273 @@ fn utilities_ffi_call() -> f64 {
     godot::global::pow(base, exponent)
 }
 
+#[bench(repeat = 25)]
+fn array_push_loop() -> Array<i64> {
+    let mut array = Array::new();
+    for i in 0..ARRAY_BENCH_LEN {
+        array.push(black_box(i));
+    }
+    array
+}
+
+#[bench(repeat = 25)]
+fn array_extend_from_slice() -> Array<i64> {
+    let values: Vec<i64> = (0..ARRAY_BENCH_LEN).collect();
+
+    // Same starting point as array_push_loop(): an empty array, filled with a single resize.
+    let mut array = Array::new();
+    array.extend_from_slice(black_box(&values));
+    array
+}
+
+#[bench(repeat = 25)]
+fn array_variant_to_vec_loop() -> Vec<i64> {
+    let array = variant_int_array();
+
+    array
+        .iter_shared()
+        .map(|variant| variant.try_to::<i64>().unwrap())
+        .collect()
+}
+
+#[bench(repeat = 25)]
+fn array_variant_try_bulk_to() -> Vec<i64> {
+    let array = variant_int_array();
+
+    black_box(&array).try_bulk_to::<i64>().unwrap()
+}
+
+#[bench(repeat = 25)]
+fn packed_transform_index_loop() -> PackedVector3Array {
+    let mut points = packed_points();
+    let transform = bench_transform();
+
+    // Element-wise access through the Index/IndexMut operators, as a baseline.
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..points.len() {
+        points[i] = transform * points[i];
+    }
+    points
+}
+
+#[bench(repeat = 25)]
+fn packed_transform_all() -> PackedVector3Array {
+    let mut points = packed_points();
+
+    points.transform_all(black_box(&bench_transform()));
+    points
+}
+
+#[bench(repeat = 25)]
+fn callable_from_object_method_loop() -> Callable {
+    let object = RefCounted::new_gd();
+
+    let mut callable = Callable::invalid();
+    for _ in 0..CALLABLE_BENCH_LEN {
+        let gd = black_box(&object).clone();
+        callable = Callable::from_object_method(&gd, &StringName::from(CALLABLE_BENCH_METHOD));
+    }
+    callable
+}
+
+#[bench(repeat = 25)]
+fn callable_from_instance_id_loop() -> Callable {
+    let object = RefCounted::new_gd();
+    let instance_id = object.instance_id();
+
+    let mut callable = Callable::invalid();
+    for _ in 0..CALLABLE_BENCH_LEN {
+        callable = Callable::from_instance_id(black_box(instance_id), CALLABLE_BENCH_METHOD);
+    }
+    callable
+}
+
+// Both variants add the same nodes to the scene tree. The first lets each child enter the tree individually, the second composes the
+// subtree outside the tree and adds it in one step, as recommended by Node::assign_owner_recursive().
+#[bench(repeat = 10)]
+fn node_add_child_in_tree() -> InstanceId {
+    let mut parent = Node::new_alloc();
+    scene_root().add_child(&parent);
+    add_children(&mut parent, alloc_nodes(NODE_BENCH_LEN));
+
+    free_returning_id(parent)
+}
+
+#[bench(repeat = 10)]
+fn node_add_child_detached() -> InstanceId {
+    let mut parent = Node::new_alloc();
+    add_children(&mut parent, alloc_nodes(NODE_BENCH_LEN));
+    scene_root().add_child(&parent);
+
+    free_returning_id(parent)
+}
+
+#[bench(repeat = 10)]
+fn node_assign_owner_recursive() -> InstanceId {
+    // Two levels, so that traversal of nested children is included.
+    let mut root = Node::new_alloc();
+    for mut branch in alloc_nodes(NODE_BENCH_LEN / 10) {
+        add_children(&mut branch, alloc_nodes(10));
+        root.add_child(&branch);
+    }
+
+    let owner = root.clone();
+    root.assign_owner_recursive(&owner);
+
+    free_returning_id(root)
+}
+
+// Emits a signal with several arguments. No receiver is connected, so this measures the emission path itself. The signal name is
+// converted to StringName once, so argument marshalling dominates.
+#[bench(repeat = 25)]
+fn signal_emit_loop() -> Gd<RefCounted> {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal(SIGNAL_BENCH_NAME.as_str());
+
+    let args = signal_bench_args();
+    for _ in 0..SIGNAL_BENCH_LEN {
+        object.emit_signal(&SIGNAL_BENCH_NAME, black_box(&args));
+    }
+    object
+}
+
+// Same as above, through the Signal builtin.
+#[bench(repeat = 25)]
+fn signal_emit_builtin_loop() -> Gd<RefCounted> {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal(SIGNAL_BENCH_NAME.as_str());
+
+    let signal = Signal::from_object_signal(&object, &SIGNAL_BENCH_NAME);
+    let args = signal_bench_args();
+    for _ in 0..SIGNAL_BENCH_LEN {
+        signal.emit(black_box(&args));
+    }
+    object
+}
+
+// Reads a #[var] through Godot's property lookup by name.
+#[bench(repeat = 25)]
+fn property_get_by_name() -> i64 {
+    let object = bench_properties();
+    let name = StringName::from(PROPERTY_BENCH_NAME);
+
+    let mut sum = 0;
+    for _ in 0..PROPERTY_BENCH_LEN {
+        sum += object.get(black_box(&name)).to::<i64>();
+    }
+    sum
+}
+
+// Same as above, but through the accessor table of the user class.
+#[bench(repeat = 25)]
+fn property_get_fast() -> i64 {
+    let object = bench_properties();
+    let name = StringName::from(PROPERTY_BENCH_NAME);
+
+    let mut sum = 0;
+    for _ in 0..PROPERTY_BENCH_LEN {
+        sum += object.get_fast(black_box(&name)).to::<i64>();
+    }
+    sum
+}
+
+#[bench(repeat = 25)]
+fn property_set_by_name() -> Gd<MyBenchProperties> {
+    let mut object = bench_properties();
+    let name = StringName::from(PROPERTY_BENCH_NAME);
+
+    for i in 0..PROPERTY_BENCH_LEN {
+        object.set(black_box(&name), &i.to_variant());
+    }
+    object
+}
+
+#[bench(repeat = 25)]
+fn property_set_fast() -> Gd<MyBenchProperties> {
+    let mut object = bench_properties();
+    let name = StringName::from(PROPERTY_BENCH_NAME);
+
+    for i in 0..PROPERTY_BENCH_LEN {
+        object.set_fast(black_box(&name), &i.to_variant());
+    }
+    object
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers for benchmarks above
 
+const ARRAY_BENCH_LEN: i64 = 1000;
+const CALLABLE_BENCH_LEN: usize = 100;
+const PACKED_BENCH_LEN: usize = 10_000;
+const CALLABLE_BENCH_METHOD: &str = "get_reference_count";
+const NODE_BENCH_LEN: usize = 1000;
+const SIGNAL_BENCH_LEN: usize = 100;
+const SIGNAL_BENCH_NAME: StaticStringName = StaticStringName::new("bench_signal");
+const PROPERTY_BENCH_LEN: i64 = 100;
+const PROPERTY_BENCH_NAME: &str = "value";
+
+fn packed_points() -> PackedVector3Array {
+    (0..PACKED_BENCH_LEN)
+        .map(|i| Vector3::new(i as real, 1.0, -(i as real)))
+        .collect()
+}
+
+fn bench_transform() -> Transform3D {
+    Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, 0.5),
+        Vector3::new(1.0, 2.0, 3.0),
+    )
+}
+
+fn variant_int_array() -> VariantArray {
+    (0..ARRAY_BENCH_LEN).map(|i| i.to_variant()).collect()
+}
+
+fn signal_bench_args() -> [Variant; 4] {
+    [
+        1.to_variant(),
+        2.5.to_variant(),
+        "three".to_variant(),
+        Vector2i::new(4, 4).to_variant(),
+    ]
+}
+
+fn alloc_nodes(count: usize) -> Vec<Gd<Node>> {
+    (0..count).map(|_| Node::new_alloc()).collect()
+}
+
+fn add_children(parent: &mut Gd<Node>, children: Vec<Gd<Node>>) {
+    for child in children {
+        parent.add_child(&child);
+    }
+}
+
+fn bench_properties() -> Gd<MyBenchProperties> {
+    Gd::from_object(MyBenchProperties { value: 7 })
+}
+
+fn scene_root() -> Gd<Window> {
+    godot::tools::main_loop::<SceneTree>()
+        .and_then(|tree| tree.get_root())
+        .expect("benchmarks run inside a SceneTree")
+}
+
+fn free_returning_id(node: Gd<Node>) -> InstanceId {
+    let instance_id = node.instance_id();
+    node.free();
+    instance_id
+}
+
 #[derive(GodotClass)]
 #[class(init)]
 struct MyBenchType {}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct MyBenchProperties {
+    #[var]
+    value: i64,
+}
+
+#[godot_api]
+impl MyBenchProperties {}
@@ -198,3 +198,57 @@ fn color_hsv_multi_roundtrip() {
         assert_eq_approx!(original, c_back);
     }
 }
+
+#[itest]
+fn color_from_rgba8_const() {
+    const ACCENT: Color = Color::from_rgb8(0x47, 0x8c, 0xbf);
+
+    assert_eq!(ACCENT, Color::from_rgba8(0x47, 0x8c, 0xbf, 0xff));
+    assert_eq!(ACCENT.to_html(), "478cbfff".into());
+}
+
+#[itest]
+fn color_hsl_roundtrip() {
+    assert_eq_approx!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+    assert_eq_approx!(
+        Color::from_hsl(1.0 / 3.0, 1.0, 0.25),
+        Color::from_rgb(0.0, 0.5, 0.0)
+    );
+    assert_eq_approx!(
+        Color::from_hsl(0.5, 0.0, 0.75),
+        Color::from_rgb(0.75, 0.75, 0.75)
+    );
+
+    // Hue wraps around.
+    assert_eq_approx!(
+        Color::from_hsl(1.5, 1.0, 0.5),
+        Color::from_hsl(0.5, 1.0, 0.5)
+    );
+
+    let color = Color::from_rgb(0.2, 0.6, 0.9);
+    let (h, s, l) = color.to_hsl();
+    assert_eq_approx!(Color::from_hsl(h, s, l), color);
+
+    // CSS: hsl(205deg 75% 55%).
+    assert_eq_approx!(
+        Color::from_hsl(205.0 / 360.0, 0.75, 0.55),
+        Color::from_rgba8(54, 155, 226, 255),
+        fn = |a: &Color, b: &Color| a.to_u32(ColorChannelOrder::RGBA) == b.to_u32(ColorChannelOrder::RGBA)
+    );
+}
+
+#[itest]
+fn color_oklab() {
+    let (l, a, b) = Color::WHITE.to_oklab();
+    assert!((l - 1.0).abs() < 1e-4 && a.abs() < 1e-4 && b.abs() < 1e-4);
+
+    // Reference values from https://bottosson.github.io/posts/oklab/.
+    let (l, a, b) = Color::RED.to_oklab();
+    assert!((l - 0.627955).abs() < 1e-4, "l={l}");
+    assert!((a - 0.224863).abs() < 1e-4, "a={a}");
+    assert!((b - 0.125846).abs() < 1e-4, "b={b}");
+
+    let color = Color::from_rgb(0.3, 0.7, 0.1);
+    let (l, a, b) = color.to_oklab();
+    assert_eq_approx!(Color::from_oklab(l, a, b), color);
+}
@@ -85,6 +85,41 @@ fn array_try_into_vec() {
     assert_eq!(result, Ok(vec![1, 2]));
 }
 
+#[itest]
+fn array_try_bulk_to() {
+    let array = varray![1, 2, 3];
+    assert_eq!(array.try_bulk_to::<i64>().unwrap(), vec![1, 2, 3]);
+    assert_eq!(array.try_bulk_to::<u8>().unwrap(), vec![1u8, 2, 3]);
+
+    let floats = varray![1.5, -2.25];
+    assert_eq!(floats.try_bulk_to::<f64>().unwrap(), vec![1.5, -2.25]);
+    assert_eq!(floats.try_bulk_to::<f32>().unwrap(), vec![1.5f32, -2.25]);
+
+    let strings = varray!["a", "bc"];
+    let expected = vec![GString::from("a"), GString::from("bc")];
+    assert_eq!(strings.try_bulk_to::<GString>().unwrap(), expected);
+
+    assert_eq!(VariantArray::new().try_bulk_to::<i32>().unwrap(), vec![]);
+}
+
+#[itest]
+fn array_try_bulk_to_error() {
+    // Strict type match, like Variant::try_to().
+    let mixed = varray![1, 2.0, 3];
+    let (index, err) = mixed.try_bulk_to::<i64>().unwrap_err();
+    assert_eq!(index, 1);
+    assert_eq!(err.value(), Some(&2.0.to_variant()));
+
+    let mixed = varray![1.0, "two"];
+    let (index, _err) = mixed.try_bulk_to::<f64>().unwrap_err();
+    assert_eq!(index, 1);
+
+    // Range is checked for narrower integers.
+    let out_of_range = varray![0, 255, 256];
+    let (index, _err) = out_of_range.try_bulk_to::<u8>().unwrap_err();
+    assert_eq!(index, 2);
+}
+
 #[itest]
 fn array_iter_shared() {
     let array = array![1, 2];
@@ -298,6 +333,73 @@ fn array_extend() {
     assert_eq!(array, array![1, 2, 3, 4]);
 }
 
+#[itest]
+fn array_extend_from_slice() {
+    let mut array = array![1, 2];
+    array.extend_from_slice(&[3, 4, 5]);
+    assert_eq!(array, array![1, 2, 3, 4, 5]);
+
+    array.extend_from_slice(&[]);
+    assert_eq!(array.len(), 5);
+
+    let mut strings: Array<GString> = Array::new();
+    strings.extend_from_slice(&[GString::from("a"), GString::from("b")]);
+    assert_eq!(strings, array!["a", "b"]);
+}
+
+#[itest]
+fn array_split_off_truncate() {
+    let mut array = array![1, 2, 3, 4, 5];
+
+    let tail = array.split_off(3);
+    assert_eq!(array, array![1, 2, 3]);
+    assert_eq!(tail, array![4, 5]);
+
+    let empty = array.split_off(3);
+    assert!(empty.is_empty());
+    assert_eq!(array, array![1, 2, 3]);
+
+    array.truncate(10);
+    assert_eq!(array.len(), 3);
+    array.truncate(1);
+    assert_eq!(array, array![1]);
+
+    expect_panic("split_off out of bounds", || {
+        let _ = array.split_off(2);
+    });
+}
+
+#[itest]
+fn array_drain() {
+    let mut array = array![0, 1, 2, 3, 4, 5];
+
+    let drained = array.drain(1..3);
+    assert_eq!(drained, array![1, 2]);
+    assert_eq!(array, array![0, 3, 4, 5]);
+
+    let drained = array.drain(2..);
+    assert_eq!(drained, array![4, 5]);
+    assert_eq!(array, array![0, 3]);
+
+    let drained = array.drain(1..1);
+    assert!(drained.is_empty());
+    assert_eq!(array, array![0, 3]);
+
+    let drained = array.drain(..);
+    assert_eq!(drained, array![0, 3]);
+    assert!(array.is_empty());
+
+    // Non-Copy elements are moved, not duplicated.
+    let mut strings = array!["a", "b", "c", "d"];
+    let drained = strings.drain(..=1);
+    assert_eq!(drained, array!["a", "b"]);
+    assert_eq!(strings, array!["c", "d"]);
+
+    expect_panic("drain out of bounds", || {
+        let _ = strings.drain(1..5);
+    });
+}
+
 #[itest]
 fn array_sort() {
     let mut array = array![2, 1];
@@ -97,6 +97,22 @@ fn callable_object_method() {
     assert_eq!(callable.object(), None);
 }
 
+#[itest]
+fn callable_from_instance_id() {
+    let object = CallableTestObj::new_gd();
+    let object_id = object.instance_id();
+    let callable = Callable::from_instance_id(object_id, "assign_int");
+
+    assert_eq!(callable, object.callable("assign_int"));
+    assert_eq!(callable.object_id(), Some(object_id));
+    assert_eq!(callable.method_name(), Some("assign_int".into()));
+
+    // Callable does not keep the object alive.
+    drop(object);
+    assert_eq!(callable.object(), None);
+    assert!(Callable::try_from_instance_id(object_id, "assign_int").is_none());
+}
+
 #[itest]
 fn callable_static() {
     let callable = Callable::from_local_static("CallableTestObj", "concat_array");
@@ -7,7 +7,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, GString, Variant};
 use godot::meta::{FromGodot, ToGodot};
 use godot::sys::GdextBuild;
 
@@ -210,6 +210,25 @@ fn dictionary_get() {
     assert_eq!(dictionary.get("foobar"), None, "key = \"foobar\"");
 }
 
+#[itest]
+fn dictionary_get_as() {
+    let dictionary = dict! {
+        "foo": 0,
+        "baz": "foobar",
+    };
+
+    assert_eq!(dictionary.get_as::<i64>("foo").unwrap().unwrap(), 0);
+    assert_eq!(
+        dictionary.get_as::<GString>("baz").unwrap().unwrap(),
+        GString::from("foobar")
+    );
+    assert!(dictionary.get_as::<i64>("missing").is_none());
+
+    let err = dictionary.get_as::<i64>("baz").unwrap().unwrap_err();
+    assert_eq!(err.value(), Some(&"foobar".to_variant()));
+    assert!(err.to_string().contains("baz"), "error: {err}");
+}
+
 #[itest]
 fn dictionary_at() {
     let dictionary = dict! {
@@ -251,6 +270,21 @@ fn dictionary_insert() {
     );
 }
 
+#[itest]
+fn dictionary_insert_typed() {
+    let mut dictionary = dict! {
+        "foo": 0,
+        "bar": "text",
+    };
+
+    assert_eq!(dictionary.insert_typed("foo", 1).unwrap().unwrap(), 0);
+    assert!(dictionary.insert_typed("baz", 2).is_none());
+
+    let err = dictionary.insert_typed("bar", 3).unwrap().unwrap_err();
+    assert!(err.to_string().contains("bar"), "error: {err}");
+    assert_eq!(dictionary.at("bar"), 3.to_variant());
+}
+
 #[itest]
 fn dictionary_insert_multiple() {
     let mut dictionary = dict! {};
@@ -399,6 +433,36 @@ fn dictionary_iter() {
     assert_eq!(map, map2);
 }
 
+#[itest]
+fn dictionary_iter_typed() {
+    let dictionary = dict! {
+        "foo": 0,
+        "bar": 1,
+    };
+
+    let map: HashMap<String, i64> = dictionary
+        .iter_typed::<String, i64>()
+        .collect::<Result<_, _>>()
+        .expect("all entries convertible");
+    assert_eq!(map, HashMap::from([("foo".into(), 0), ("bar".into(), 1)]));
+
+    let dictionary = dict! {
+        "foo": 0,
+        "bar": "text",
+        7: 2,
+    };
+
+    let errors: Vec<String> = dictionary
+        .iter_typed::<String, i64>()
+        .filter_map(Result::err)
+        .map(|err| err.to_string())
+        .collect();
+
+    assert_eq!(errors.len(), 2, "errors: {errors:?}");
+    assert!(errors.iter().any(|e| e.starts_with("bar: ")));
+    assert!(errors.iter().any(|e| e.starts_with("[key 7]: ")));
+}
+
 #[itest]
 fn dictionary_iter_size_hint() {
     // Test a completely empty dict.
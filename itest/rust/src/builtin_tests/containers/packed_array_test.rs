@@ -6,9 +6,11 @@
  */
 
 use crate::framework::{expect_panic, itest};
+use godot::builtin::math::assert_eq_approx;
 use godot::builtin::{
-    dict, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
-    PackedStringArray, Variant,
+    dict, Basis, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array,
+    PackedInt32Array, PackedStringArray, PackedVector2Array, PackedVector3Array, Transform2D,
+    Transform3D, Variant, Vector2, Vector3,
 };
 use godot::prelude::ToGodot;
 
@@ -374,3 +376,42 @@ fn packed_byte_array_encode_decode_variant() {
     assert_eq!(decoded.0, Variant::nil());
     assert_eq!(decoded.1, 0);
 }
+
+#[itest]
+fn packed_vector2_array_transform_all() {
+    let points = [
+        Vector2::new(1.0, 2.0),
+        Vector2::new(-3.5, 0.25),
+        Vector2::ZERO,
+    ];
+    let transform = Transform2D::from_angle_origin(0.7, Vector2::new(10.0, -4.0))
+        .scaled(Vector2::new(2.0, 0.5));
+
+    let mut array = PackedVector2Array::from(&points[..]);
+    array.transform_all(&transform);
+
+    assert_eq!(array.len(), points.len());
+    for (actual, point) in array.as_slice().iter().zip(points) {
+        assert_eq_approx!(*actual, transform * point);
+    }
+}
+
+#[itest]
+fn packed_vector3_array_transform_all() {
+    let points = [
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(-3.5, 0.25, 8.0),
+        Vector3::ZERO,
+    ];
+    let basis = Basis::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalized(), 1.2)
+        .scaled(Vector3::new(2.0, 1.0, 0.5));
+    let transform = Transform3D::new(basis, Vector3::new(10.0, -4.0, 2.0));
+
+    let mut array = PackedVector3Array::from(&points[..]);
+    array.transform_all(&transform);
+
+    assert_eq!(array.len(), points.len());
+    for (actual, point) in array.as_slice().iter().zip(points) {
+        assert_eq_approx!(*actual, transform * point);
+    }
+}
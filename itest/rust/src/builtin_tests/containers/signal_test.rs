@@ -6,9 +6,10 @@
  */
 
 use godot::builtin::{GString, Signal, StringName};
+use godot::classes::object::ConnectFlags;
 use godot::classes::{Object, RefCounted};
 use godot::meta::ToGodot;
-use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::obj::{Base, EngineBitfield, Gd, NewAlloc, NewGd, WithBaseField};
 use godot::register::{godot_api, GodotClass};
 use godot::sys;
 use std::cell::Cell;
@@ -60,6 +61,46 @@ fn signal_construction_and_id() {
     assert_eq!(signal.object(), None);
 }
 
+#[itest]
+fn signal_connection_list() {
+    let mut emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+    let other = Receiver::new_alloc();
+
+    emitter.connect("emitter_1", &receiver.callable("receiver_1"));
+    emitter
+        .connect_ex("emitter_1", &other.callable("receiver_0").unbind(1))
+        .flags(ConnectFlags::DEFERRED.ord() as u32)
+        .done();
+
+    let connections = emitter.signal_connections("emitter_1");
+    assert_eq!(connections.len(), 2);
+
+    // Connection order is preserved.
+    let first = &connections[0];
+    assert_eq!(first.signal.name(), StringName::from("emitter_1"));
+    assert_eq!(first.target, Some(receiver.clone().upcast::<Object>()));
+    assert_eq!(first.method(), Some(StringName::from("receiver_1")));
+    assert!(!first.has_flag(ConnectFlags::DEFERRED));
+
+    let second = &connections[1];
+    assert_eq!(second.target, Some(other.clone().upcast::<Object>()));
+    assert!(second.has_flag(ConnectFlags::DEFERRED));
+
+    // Matches regardless of unbound arguments.
+    assert!(emitter.is_connected_to("emitter_1", &receiver, "receiver_1"));
+    assert!(emitter.is_connected_to("emitter_1", &other, "receiver_0"));
+    assert!(!emitter.is_connected_to("emitter_1", &other, "receiver_1"));
+    assert!(!emitter.is_connected_to("emitter_0", &receiver, "receiver_1"));
+
+    let signal = Signal::from_object_signal(&emitter, "emitter_0");
+    assert!(signal.connection_list().is_empty());
+
+    other.free();
+    receiver.free();
+    emitter.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper types
 
@@ -256,3 +297,250 @@ mod custom_callable {
         Callable::from_custom(PanicCallable(received))
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 4.2+ signal futures
+
+#[cfg(since_api = "4.2")]
+mod signal_future {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use godot::builtin::{GString, Signal};
+    use godot::classes::{Node, Object};
+    use godot::meta::ToGodot;
+    use godot::obj::{Gd, NewAlloc};
+
+    use crate::framework::{itest, TestContext};
+
+    struct CountingWake(AtomicU32);
+
+    impl Wake for CountingWake {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn poll_once<F: Future + Unpin>(future: &mut F, waker: &Waker) -> Poll<F::Output> {
+        let mut cx = Context::from_waker(waker);
+        Pin::new(future).poll(&mut cx)
+    }
+
+    #[itest]
+    fn signal_future_resolves_with_args() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal");
+
+        let wake = Arc::new(CountingWake(AtomicU32::new(0)));
+        let waker = Waker::from(wake.clone());
+
+        let signal = Signal::from_object_signal(&object, "test_signal");
+        let mut future = signal.to_future::<(i64, GString)>();
+        assert!(poll_once(&mut future, &waker).is_pending());
+
+        object.emit_signal("test_signal", &[7.to_variant(), "seven".to_variant()]);
+        assert_eq!(wake.0.load(Ordering::SeqCst), 1);
+
+        let Poll::Ready((number, text)) = poll_once(&mut future, &waker) else {
+            panic!("future should be ready after emission");
+        };
+        assert_eq!(number, 7);
+        assert_eq!(text, GString::from("seven"));
+
+        // One-shot: the connection is gone after emission.
+        assert!(signal.connections().is_empty());
+        object.free();
+    }
+
+    #[itest]
+    fn signal_future_dropped_before_emit() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal");
+
+        let signal = Signal::from_object_signal(&object, "test_signal");
+        let future = signal.to_future::<()>();
+
+        let connections = signal.connection_list();
+        assert_eq!(connections.len(), 1);
+        let callable = connections[0].callable.clone();
+        assert!(signal.is_connected(&callable));
+
+        // Dropping the future disconnects it.
+        drop(future);
+        assert!(!signal.is_connected(&callable));
+        assert!(signal.connections().is_empty());
+
+        // Emission after drop must not panic.
+        object.emit_signal("test_signal", &[]);
+        object.free();
+    }
+
+    #[itest]
+    fn signal_future_generated_await_method(ctx: &TestContext) {
+        let node: Gd<Node> = Node::new_alloc();
+
+        let waker = Waker::from(Arc::new(CountingWake(AtomicU32::new(0))));
+        let mut future = node.await_tree_entered();
+        assert!(poll_once(&mut future, &waker).is_pending());
+
+        let mut tree = ctx.scene_tree.clone();
+        tree.add_child(&node);
+        assert!(poll_once(&mut future, &waker).is_ready());
+
+        tree.remove_child(&node);
+        node.free();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 4.2+ typed connections
+
+#[cfg(since_api = "4.2")]
+mod signal_adapter {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use godot::builtin::{GString, Signal};
+    use godot::classes::Object;
+    use godot::global::Error;
+    use godot::meta::ToGodot;
+    use godot::obj::NewAlloc;
+
+    use crate::framework::{expect_panic, itest};
+
+    #[itest]
+    fn signal_connect_mapped() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal");
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+
+        let signal = Signal::from_object_signal(&object, "test_signal");
+        let error = signal.connect_mapped(move |number: i64, text: GString, _: bool| {
+            received_clone.borrow_mut().push((number, text));
+        });
+        assert_eq!(error, Error::OK);
+
+        let args = [7.to_variant(), "seven".to_variant(), true.to_variant()];
+        object.emit_signal("test_signal", &args);
+        assert_eq!(*received.borrow(), vec![(7, GString::from("seven"))]);
+
+        object.free();
+    }
+
+    #[itest]
+    fn signal_bind_args() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal");
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+
+        Signal::from_object_signal(&object, "test_signal")
+            .bind_args((10,))
+            .bind_args(("bound",))
+            .connect_mapped(move |number: i64, bound_number: i32, bound_text: GString| {
+                received_clone
+                    .borrow_mut()
+                    .push((number, bound_number, bound_text));
+            });
+
+        object.emit_signal("test_signal", &[1.to_variant()]);
+        object.emit_signal("test_signal", &[2.to_variant()]);
+        assert_eq!(
+            *received.borrow(),
+            vec![
+                (1, 10, GString::from("bound")),
+                (2, 10, GString::from("bound")),
+            ]
+        );
+
+        object.free();
+    }
+
+    #[itest]
+    fn signal_connect_mapped_drops_trailing_args() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal");
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+
+        let signal = Signal::from_object_signal(&object, "test_signal");
+        signal.connect_mapped(move |number: i64| {
+            received_clone.borrow_mut().push(number);
+        });
+
+        let calls = Rc::new(RefCell::new(0));
+        let calls_clone = calls.clone();
+        signal.connect_mapped(move || *calls_clone.borrow_mut() += 1);
+
+        let args = [7.to_variant(), "seven".to_variant(), true.to_variant()];
+        object.emit_signal("test_signal", &args);
+        assert_eq!(*received.borrow(), vec![7]);
+        assert_eq!(*calls.borrow(), 1);
+
+        object.free();
+    }
+
+    #[itest]
+    fn signal_bind_args_drops_trailing_args() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal");
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+
+        // The signal's second and third arguments are dropped, the bound argument follows the first one.
+        Signal::from_object_signal(&object, "test_signal")
+            .bind_args(("bound",))
+            .connect_mapped(move |number: i64, bound_text: GString| {
+                received_clone.borrow_mut().push((number, bound_text));
+            });
+
+        let args = [7.to_variant(), "seven".to_variant(), true.to_variant()];
+        object.emit_signal("test_signal", &args);
+        assert_eq!(*received.borrow(), vec![(7, GString::from("bound"))]);
+
+        object.free();
+    }
+
+    #[itest]
+    fn signal_bind_args_too_many() {
+        let object = Object::new_alloc();
+        let signal = Signal::from_object_signal(&object, "script_changed");
+
+        expect_panic("more bound arguments than parameters", || {
+            signal.bind_args((1, 2)).connect_mapped(|_: i32| {});
+        });
+
+        object.free();
+    }
+
+    #[itest]
+    fn signal_connect_mapped_wrong_args() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal");
+
+        let calls = Rc::new(RefCell::new(0));
+        let calls_clone = calls.clone();
+
+        Signal::from_object_signal(&object, "test_signal").connect_mapped(move |_: i64| {
+            *calls_clone.borrow_mut() += 1;
+        });
+
+        // Mismatched arguments panic inside the callable; the panic is caught and reported, and the function is not invoked.
+        object.emit_signal("test_signal", &[]);
+        object.emit_signal("test_signal", &["not a number".to_variant()]);
+        assert_eq!(*calls.borrow(), 0);
+
+        object.emit_signal("test_signal", &[3.to_variant()]);
+        assert_eq!(*calls.borrow(), 1);
+
+        object.free();
+    }
+}
@@ -158,7 +158,7 @@ fn variant_array_bad_conversions() {
         let err = i8_back.expect_err("Array<i32> -> Array<i8> conversion should fail");
         assert_eq!(
             err.to_string(),
-            "integer value 160 does not fit into Array of type INT: [1, 2, 160, -40]"
+            "[2]: integer value 160 does not fit into Array of type INT: [1, 2, 160, -40]"
         )
     }
 
@@ -6,8 +6,8 @@
  */
 
 use godot::builtin::{
-    array, dict, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray, Vector2,
-    Vector2Axis,
+    array, dict, varray, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray,
+    Vector2, Vector2Axis,
 };
 use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
@@ -134,8 +134,8 @@ impl FromGodot for ConvertedStruct {
         }
 
         Ok(Self {
-            a: a.try_to()?,
-            b: b.try_to()?,
+            a: a.try_to().map_err(|err| err.with_field("a"))?,
+            b: b.try_to().map_err(|err| err.with_field("b"))?,
         })
     }
 }
@@ -210,6 +210,7 @@ fn custom_convert_error_from_variant() {
         .expect_err("should have wrongly typed key `a`");
 
     assert!(err.cause().is_none());
+    assert_eq!(err.path(), "a");
     assert_eq!(
         format!("{:?}", err.value().unwrap()),
         format!("{:?}", "hello".to_variant())
@@ -246,6 +247,56 @@ fn custom_convert_error_from_variant() {
     );
 }
 
+#[itest]
+fn error_path_nested() {
+    fn read_hp(data: &Dictionary) -> Result<i64, ConvertError> {
+        let items: VariantArray = data.get_as("items").expect("items present")?;
+
+        let mut hp = 0;
+        for (index, item) in items.iter_shared().enumerate() {
+            let item: Dictionary = item.try_to().map_err(|err| err.with_index(index))?;
+            let stats: Dictionary = item.get_as("stats").expect("stats present")?;
+
+            hp += stats
+                .get_as::<i64>("hp")
+                .expect("hp present")
+                .map_err(|err| err.with_key("stats").with_index(index))?;
+        }
+
+        Ok(hp)
+    }
+
+    let data = dict! {
+        "items": varray![
+            dict! { "stats": dict! { "hp": 10 } },
+            dict! { "stats": dict! { "hp": "abc" } },
+        ]
+    };
+
+    let err = read_hp(&data)
+        .map_err(|err| err.with_key("items"))
+        .expect_err("hp of items[1] is a string");
+
+    assert_eq!(err.path(), "items[1].stats.hp");
+    assert_eq!(
+        err.to_string(),
+        r#"items[1].stats.hp: cannot convert from STRING to INT: "abc""#
+    );
+}
+
+#[itest]
+fn error_path_segments() {
+    let err = Variant::nil().try_to::<i64>().unwrap_err();
+    assert_eq!(err.path(), "");
+
+    let err = err
+        .with_index(2)
+        .with_key("two words")
+        .with_key(7)
+        .with_field("root");
+    assert_eq!(err.path(), r#"root[7]["two words"][2]"#);
+}
+
 #[itest]
 fn vec_to_array() {
     let from = vec![1, 2, 3];
@@ -8,7 +8,8 @@
 use crate::framework::itest;
 
 use godot::builtin::inner::InnerTransform3D;
-use godot::builtin::{Aabb, Basis, Plane, Transform3D, VariantOperator, Vector3};
+use godot::builtin::math::TransformInterpolator;
+use godot::builtin::{real, Aabb, Basis, Plane, Transform3D, VariantOperator, Vector3};
 use godot::meta::ToGodot;
 use godot::private::class_macros::assert_eq_approx;
 
@@ -82,3 +83,25 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_interpolator_equiv() {
+    let from = Transform3D::IDENTITY.translated(Vector3::new(1.0, 2.0, 3.0));
+    let to = Transform3D::new(
+        Basis::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalized(), 1.2)
+            .scaled(Vector3::new(1.0, 2.0, 0.5)),
+        Vector3::new(-4.0, 0.0, 8.0),
+    );
+
+    let mut interpolator = TransformInterpolator::new(from);
+    interpolator.push(to);
+
+    let inner = InnerTransform3D::from_outer(&from);
+    for fraction in [0.0, 0.25, 0.5, 0.9, 1.0] {
+        assert_eq_approx!(
+            interpolator.blended(fraction as real),
+            inner.interpolate_with(to, fraction),
+            "fraction: {fraction}"
+        );
+    }
+}
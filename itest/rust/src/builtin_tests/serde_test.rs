@@ -92,3 +92,142 @@ fn color_hsv_serde() {
     let expected_json = r#"{"h":0.0,"s":0.0,"v":0.0,"a":1.0}"#;
     serde_roundtrip(&color, expected_json);
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Variant (de)serializer
+
+mod variant {
+    use crate::framework::itest;
+    use godot::builtin::{
+        dict, varray, Callable, Dictionary, GString, PackedInt32Array, StringName, VariantArray,
+        Vector2,
+    };
+    use godot::meta::ToGodot;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Config {
+        title: String,
+        volume: f32,
+        position: Vector2,
+        players: Vec<Player>,
+        mode: Mode,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Player {
+        name: String,
+        level: u8,
+        nickname: Option<String>,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    enum Mode {
+        Easy,
+        Custom { lives: u32 },
+    }
+
+    fn sample_config() -> Config {
+        Config {
+            title: "Demo".to_string(),
+            volume: 0.5,
+            position: Vector2::new(1.0, 2.0),
+            players: vec![
+                Player {
+                    name: "Ann".to_string(),
+                    level: 3,
+                    nickname: None,
+                },
+                Player {
+                    name: "Bob".to_string(),
+                    level: 7,
+                    nickname: Some("B".to_string()),
+                },
+            ],
+            mode: Mode::Custom { lives: 5 },
+        }
+    }
+
+    #[itest]
+    fn serde_variant_roundtrip() {
+        let config = sample_config();
+
+        let variant = godot::serde::to_variant(&config).expect("to_variant");
+        let back: Config = godot::serde::from_variant(&variant).expect("from_variant");
+
+        assert_eq!(back, config);
+    }
+
+    #[itest]
+    fn serde_variant_layout() {
+        let variant = godot::serde::to_variant(&sample_config()).expect("to_variant");
+        let dict: Dictionary = variant.to();
+
+        assert_eq!(dict.get("title"), Some("Demo".to_variant()));
+        assert_eq!(
+            dict.get("mode"),
+            Some(dict! { "Custom": dict! { "lives": 5 } }.to_variant())
+        );
+
+        let unit = godot::serde::to_variant(&Mode::Easy).expect("to_variant");
+        assert_eq!(unit, GString::from("Easy").to_variant());
+    }
+
+    #[itest]
+    fn serde_variant_from_godot_values() {
+        // Native Vector2, StringName keys, packed array instead of Array.
+        let mut player = Dictionary::new();
+        player.set(StringName::from("name"), "Cid");
+        player.set(StringName::from("level"), 9);
+
+        let config = dict! {
+            "title": "Native",
+            "volume": 1,
+            "position": Vector2::new(3.0, 4.0),
+            "players": varray![player],
+            "mode": "Easy",
+        };
+
+        let config: Config =
+            godot::serde::from_variant(&config.to_variant()).expect("from_variant");
+        assert_eq!(config.volume, 1.0);
+        assert_eq!(config.position, Vector2::new(3.0, 4.0));
+        assert_eq!(config.players[0].name, "Cid");
+        assert_eq!(config.players[0].nickname, None);
+        assert_eq!(config.mode, Mode::Easy);
+
+        let packed = PackedInt32Array::from(&[1, 2, 3]);
+        let numbers: Vec<i64> =
+            godot::serde::from_variant(&packed.to_variant()).expect("from_variant");
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[itest]
+    fn serde_variant_error_path() {
+        let mut variant = godot::serde::to_variant(&sample_config()).expect("to_variant");
+
+        let dict: Dictionary = variant.to();
+        let players: VariantArray = dict.get("players").unwrap().to();
+        let mut second: Dictionary = players.at(1).to();
+        second.set("name", 5);
+        variant = dict.to_variant();
+
+        let err = godot::serde::from_variant::<Config>(&variant).expect_err("invalid name");
+        assert_eq!(err.path(), "players[1].name");
+        assert!(
+            err.to_string()
+                .starts_with("at `players[1].name`: invalid type"),
+            "{err}"
+        );
+
+        let err = godot::serde::from_variant::<u8>(&300.to_variant()).expect_err("out of range");
+        assert_eq!(err.path(), "");
+
+        let err = godot::serde::to_variant(&u64::MAX).expect_err("too large");
+        assert!(err.message().contains("does not fit"), "{err}");
+
+        let callable = Callable::invalid().to_variant();
+        let err = godot::serde::from_variant::<String>(&callable).expect_err("unsupported");
+        assert!(err.message().contains("CALLABLE"), "{err}");
+    }
+}
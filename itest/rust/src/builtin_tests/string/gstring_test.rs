@@ -8,6 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::{expect_debug_panic_or_release_ok, itest};
+use godot::builtin::strings::GStringBuilder;
 use godot::builtin::{GString, PackedStringArray};
 
 // TODO use tests from godot-rust/gdnative
@@ -266,6 +267,40 @@ fn string_pad() {
     assert_eq!(s.pad_zeros(2), "123.456".into());
 }
 
+#[itest]
+fn string_builder() {
+    use std::fmt::Write;
+
+    let mut builder = GStringBuilder::new();
+    assert!(builder.is_empty());
+
+    write!(builder, "{}-{:03}", "id", 7).unwrap();
+    builder.push_char('¿');
+    builder.push_gstring(&GString::from("🎉"));
+    assert_eq!(builder.len(), 8);
+
+    // Longer than one internal chunk, with an embedded null that is skipped.
+    let long = "abc\0".repeat(50);
+    builder.push_str(&long);
+
+    let expected = format!("id-007¿🎉{}", "abc".repeat(50));
+    assert_eq!(builder.build(), GString::from(expected));
+}
+
+#[itest]
+fn string_builder_continue() {
+    let original = GString::from("Hello");
+    let mut builder = GStringBuilder::from_gstring(original.clone());
+    builder.push_str(", World");
+
+    assert_eq!(builder.build(), "Hello, World".into());
+    assert_eq!(
+        original,
+        "Hello".into(),
+        "copy-on-write keeps original intact"
+    );
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn packed(strings: &[&str]) -> PackedStringArray {
@@ -9,6 +9,8 @@ use std::collections::HashSet;
 
 use crate::framework::{assert_eq_self, itest};
 use godot::builtin::{GString, NodePath, StringName};
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
 
 #[itest]
 fn string_name_default() {
@@ -162,3 +164,22 @@ fn string_name_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_name_arg_from_str() {
+    let mut obj = godot::classes::Object::new_alloc();
+
+    // Repeated &str arguments reuse cached StringNames; results must be the same as for fresh conversions.
+    for _ in 0..3 {
+        obj.set_meta("some_key", &42.to_variant());
+        obj.set_meta("ünïcödé", &"value".to_variant());
+    }
+
+    let dynamic_key = format!("some_{}", "key");
+    assert!(obj.has_meta(dynamic_key.as_str()));
+    assert!(obj.has_meta(&StringName::from("ünïcödé")));
+    assert!(!obj.has_meta("other_key"));
+    assert_eq!(obj.get_meta("some_key"), 42.to_variant());
+
+    obj.free();
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{PackedFloat32Array, PackedFloat64Array};
+use godot::classes::animation::{LoopMode, LoopedFlag};
+use godot::classes::{AnimationNodeExtension, IAnimationNodeExtension};
+use godot::obj::{Base, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{AnimationNodeTimeInfo, AnimationPlaybackInfo};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=AnimationNodeExtension)]
+struct DoubleSpeedNode {
+    position: f64,
+    base: Base<AnimationNodeExtension>,
+}
+
+#[godot_api]
+impl IAnimationNodeExtension for DoubleSpeedNode {
+    fn process_animation_node(
+        &mut self,
+        playback_info: PackedFloat64Array,
+        test_only: bool,
+    ) -> PackedFloat32Array {
+        let info = AnimationPlaybackInfo::from_packed(&playback_info);
+        let delta = info.delta * 2.0;
+
+        if !test_only {
+            self.position += delta;
+        }
+
+        AnimationNodeTimeInfo {
+            length: 4.0,
+            position: self.position,
+            delta,
+            ..Default::default()
+        }
+        .to_packed()
+    }
+}
+
+#[itest]
+fn animation_playback_info_roundtrip() {
+    let info = AnimationPlaybackInfo {
+        time: 1.5,
+        delta: 0.25,
+        start: 0.0,
+        end: 3.0,
+        seeked: true,
+        is_external_seeking: false,
+        looped_flag: LoopedFlag::END,
+        weight: 0.5,
+    };
+
+    let packed = info.to_packed();
+    assert_eq!(packed.len(), 8);
+    assert_eq!(AnimationPlaybackInfo::from_packed(&packed), info);
+}
+
+#[itest]
+fn animation_node_time_info_roundtrip() {
+    let info = AnimationNodeTimeInfo {
+        length: 2.0,
+        position: 0.5,
+        delta: 0.125,
+        loop_mode: LoopMode::PINGPONG,
+        will_end: false,
+        is_infinity: false,
+    };
+
+    let packed = info.to_packed();
+    assert_eq!(AnimationNodeTimeInfo::try_from_packed(&packed), Some(info));
+    assert_eq!(
+        AnimationNodeTimeInfo::try_from_packed(&PackedFloat32Array::new()),
+        None
+    );
+}
+
+#[itest]
+fn animation_node_time_info_remaining() {
+    let once = AnimationNodeTimeInfo {
+        length: 2.0,
+        position: 0.5,
+        ..Default::default()
+    };
+    assert!(!once.is_looping());
+    assert_eq!(once.remaining_time(false), 1.5);
+
+    let looping = AnimationNodeTimeInfo {
+        loop_mode: LoopMode::LINEAR,
+        ..once
+    };
+    assert!(looping.is_looping());
+    assert!(looping.remaining_time(false) > 1000.0);
+    assert_eq!(looping.remaining_time(true), 1.5);
+}
+
+#[itest]
+fn animation_node_extension_process() {
+    let mut node = DoubleSpeedNode::new_gd();
+
+    let info = AnimationPlaybackInfo {
+        time: 0.0,
+        delta: 0.5,
+        start: 0.0,
+        end: 4.0,
+        seeked: false,
+        is_external_seeking: false,
+        looped_flag: LoopedFlag::NONE,
+        weight: 1.0,
+    };
+
+    let result = node
+        .bind_mut()
+        .process_animation_node(info.to_packed(), false);
+    let time_info = AnimationNodeTimeInfo::try_from_packed(&result).expect("valid time info");
+
+    assert_eq!(time_info.position, 1.0);
+    assert_eq!(time_info.delta, 1.0);
+    assert_eq!(time_info.remaining_time(false), 3.0);
+}
@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{Node, Node2D, Node3D};
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::{autoload, autoloads, try_autoload};
+
+use crate::framework::{expect_panic, itest, TestContext};
+
+// The itest project declares no autoloads; nodes added directly under the root are found the same way.
+
+autoloads! {
+    fn fake_autoload() -> Node3D = "ItestFakeAutoload";
+}
+
+#[itest]
+fn autoload_found_and_cached(ctx: &TestContext) {
+    let node = add_to_root(ctx, "ItestFakeAutoload");
+
+    assert_eq!(autoload::<Node3D>("ItestFakeAutoload"), node);
+    assert_eq!(fake_autoload(), node);
+
+    // Subclass access and repeated (cached) lookup.
+    assert_eq!(autoload::<Node>("ItestFakeAutoload"), node.clone().upcast());
+
+    remove_from_root(node);
+
+    let err = try_autoload::<Node3D>("ItestFakeAutoload").unwrap_err();
+    assert_eq!(err.name(), "ItestFakeAutoload");
+    assert!(!err.is_not_in_tree());
+}
+
+#[itest]
+fn autoload_errors(ctx: &TestContext) {
+    let err = try_autoload::<Node>("ItestNoSuchAutoload").unwrap_err();
+    assert!(err.to_string().contains("not declared"), "{err}");
+
+    let node = add_to_root(ctx, "ItestWrongTypeAutoload");
+
+    let err = try_autoload::<Node2D>("ItestWrongTypeAutoload").unwrap_err();
+    assert!(err.to_string().contains("Node3D"), "{err}");
+
+    expect_panic("autoload with wrong type", || {
+        autoload::<Node2D>("ItestWrongTypeAutoload");
+    });
+
+    remove_from_root(node);
+}
+
+fn add_to_root(ctx: &TestContext, name: &str) -> Gd<Node3D> {
+    let mut root = ctx.scene_tree.get_tree().unwrap().get_root().unwrap();
+
+    let mut node = Node3D::new_alloc();
+    node.set_name(name);
+    root.add_child(&node);
+    node
+}
+
+fn remove_from_root(mut node: Gd<Node3D>) {
+    let mut parent = node.get_parent().unwrap();
+    parent.remove_child(&node);
+    node.queue_free();
+}
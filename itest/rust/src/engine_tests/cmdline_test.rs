@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::tools::{user_args, UserArgs};
+
+use crate::framework::itest;
+
+#[itest]
+fn user_args_parse() {
+    let args = UserArgs::from_args(["--port=8080", "--verbose", "map.tscn", "--name=", "--=x"]);
+
+    assert_eq!(args.len(), 5);
+    assert_eq!(args.get("port"), Some("8080"));
+    assert_eq!(args.get_parsed::<u16>("port"), Some(Ok(8080)));
+    assert!(args.get_parsed::<u16>("name").unwrap().is_err());
+    assert_eq!(args.get("name"), Some(""));
+    assert_eq!(args.get("missing"), None);
+
+    assert!(args.has_flag("verbose"));
+    assert!(!args.has_flag("port"));
+    assert!(!args.has_flag(""));
+
+    assert_eq!(args.positional().collect::<Vec<_>>(), ["map.tscn"]);
+    assert_eq!(args.options().len(), 2);
+}
+
+#[itest]
+fn user_args_last_option_wins() {
+    let args = UserArgs::from_args(["--level=1", "--level=2"]);
+
+    assert_eq!(args.get("level"), Some("2"));
+    assert_eq!(args.iter().count(), 2);
+}
+
+#[itest]
+fn user_args_cached() {
+    let first = user_args();
+    let second = user_args();
+
+    assert!(std::ptr::eq(first, second));
+    assert_eq!(first.len(), first.into_iter().count());
+}
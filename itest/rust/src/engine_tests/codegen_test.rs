@@ -8,9 +8,9 @@
 // This file tests the presence, naming and accessibility of generated symbols.
 // Functionality is only tested on a superficial level (to make sure general FFI mechanisms work).
 
-use crate::framework::itest;
+use crate::framework::{itest, TestContext};
 use godot::builtin::inner::InnerColor;
-use godot::classes::{FileAccess, HttpRequest, IHttpRequest, Image};
+use godot::classes::{FileAccess, HttpRequest, IHttpRequest, Image, Window};
 use godot::prelude::*;
 
 #[itest]
@@ -51,6 +51,14 @@ fn codegen_static_class_method() {
     // see also object_test for reference count verification
 }
 
+#[itest]
+fn codegen_non_null_accessor(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree().unwrap();
+
+    let root: Gd<Window> = tree.get_root_expect();
+    assert_eq!(Some(root), tree.get_root());
+}
+
 #[itest]
 fn codegen_constants() {
     assert_eq!(Image::MAX_WIDTH, 16777216);
@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::hashing_context::HashType;
+use godot::tools::crypto;
+
+use crate::framework::itest;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[itest]
+fn crypto_hash() {
+    let digest = crypto::hash(HashType::SHA256, b"abc").unwrap();
+    assert_eq!(
+        hex(digest.as_slice()),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    let mut hasher = crypto::Hasher::new(HashType::SHA256);
+    hasher.update(b"a").unwrap();
+    hasher.update_packed(&b"bc".into()).unwrap();
+    assert_eq!(hasher.finish(), digest);
+}
+
+#[itest]
+fn crypto_hmac() {
+    let mac = crypto::hmac(
+        HashType::SHA256,
+        b"key",
+        b"The quick brown fox jumps over the lazy dog",
+    )
+    .unwrap();
+
+    assert_eq!(
+        hex(mac.as_slice()),
+        "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    );
+
+    assert!(crypto::constant_time_eq(mac.as_slice(), mac.as_slice()));
+    assert!(!crypto::constant_time_eq(mac.as_slice(), &[0; 32]));
+    assert!(!crypto::constant_time_eq(mac.as_slice(), b"short"));
+}
+
+#[itest]
+fn crypto_random_bytes() {
+    let a = crypto::random_bytes(16);
+    let b = crypto::random_bytes(16);
+
+    assert_eq!(a.len(), 16);
+    assert_ne!(a, b);
+}
+
+#[itest]
+fn crypto_keys_and_certificates() {
+    let key = crypto::generate_rsa_key(1024).unwrap();
+
+    let public_pem = key.save_to_string_ex().public_only(true).done();
+    let public_key = crypto::key_from_pem(&public_pem, true).unwrap();
+    assert!(public_key.is_public_only());
+
+    let err = crypto::key_from_pem("not a key", false).unwrap_err();
+    assert!(err.godot_error().is_some());
+
+    let certificate = crypto::generate_self_signed_certificate(
+        &key,
+        "CN=gdext,O=godot-rust,C=CH",
+        "20240101000000",
+        "20340101000000",
+    );
+    assert!(certificate.is_ok());
+
+    assert!(crypto::load_certificate("res://does_not_exist.crt").is_err());
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::Node;
+use godot::obj::{Base, Gd, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::defer_set;
+
+use crate::framework::{itest, wait_frames};
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DeferredTarget {
+    #[var]
+    score: i64,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl DeferredTarget {}
+
+#[itest]
+async fn defer_set_applies_next_frame() {
+    let mut node = Node::new_alloc();
+    node.set_name("before");
+
+    let node_ref: &Gd<Node> = &node;
+    defer_set!(
+        node_ref.set_name("after"),
+        (node.clone()).set_process_priority(7),
+    );
+
+    // Not applied yet.
+    assert_eq!(node.get_name(), "before".into());
+    assert_eq!(node.get_process_priority(), 0);
+
+    wait_frames(1).await;
+    assert_eq!(node.get_name(), "after".into());
+    assert_eq!(node.get_process_priority(), 7);
+
+    node.free();
+}
+
+#[itest]
+async fn defer_set_var_property() {
+    let target = DeferredTarget::new_alloc();
+    defer_set!(target.bind_mut().set_score(42));
+
+    assert_eq!(target.bind().score, 0);
+
+    wait_frames(1).await;
+    assert_eq!(target.bind().score, 42);
+
+    target.free();
+}
@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{dict, varray, GString, NodePath, PackedStringArray, StringName, Variant};
+use godot::classes::{Node, Resource};
+use godot::meta::{FromGodot, ToGodot};
+use godot::obj::{NewAlloc, NewGd};
+use godot::tools::DragData;
+
+use crate::framework::itest;
+
+#[itest]
+fn drag_data_text() {
+    let data = DragData::from_variant(&"hello".to_variant());
+    assert!(matches!(&data, DragData::Text(text) if text == &GString::from("hello")));
+    assert_eq!(data.to_variant(), "hello".to_variant());
+
+    let data = DragData::from_variant(&StringName::from("name").to_variant());
+    assert!(matches!(data, DragData::Text(text) if text == GString::from("name")));
+}
+
+#[itest]
+fn drag_data_editor_files() {
+    let files =
+        PackedStringArray::from(&[GString::from("res://a.png"), GString::from("res://b.tscn")]);
+
+    // Shape used by the editor's FileSystem dock.
+    let editor_payload = dict! {
+        "type": "files",
+        "files": files.clone(),
+        "from": Variant::nil(),
+    };
+
+    let data = DragData::from_variant(&editor_payload.to_variant());
+    let DragData::Files(parsed) = &data else {
+        panic!("expected files, got {data:?}");
+    };
+    assert_eq!(parsed, &files);
+
+    let roundtrip = DragData::from_variant(&data.to_variant());
+    assert!(matches!(roundtrip, DragData::Files(parsed) if parsed == files));
+}
+
+#[itest]
+fn drag_data_editor_nodes() {
+    let editor_payload = dict! {
+        "type": "nodes",
+        "nodes": varray![NodePath::from("/root/Main/Player"), NodePath::from("/root/Main/Enemy")],
+    };
+
+    let data = DragData::from_variant(&editor_payload.to_variant());
+    let DragData::Nodes(nodes) = &data else {
+        panic!("expected nodes, got {data:?}");
+    };
+    assert_eq!(
+        nodes,
+        &[
+            NodePath::from("/root/Main/Player"),
+            NodePath::from("/root/Main/Enemy")
+        ]
+    );
+
+    let roundtrip = DragData::from_variant(&data.to_variant());
+    assert!(matches!(roundtrip, DragData::Nodes(parsed) if parsed.len() == 2));
+}
+
+#[itest]
+fn drag_data_resource() {
+    let resource = Resource::new_gd();
+
+    let data = DragData::from_variant(&resource.to_variant());
+    assert!(matches!(&data, DragData::Resource(res) if *res == resource));
+
+    let dict = data.to_variant().to::<godot::builtin::Dictionary>();
+    assert_eq!(dict.at("type"), "resource".to_variant());
+
+    let roundtrip = DragData::from_variant(&dict.to_variant());
+    assert!(matches!(roundtrip, DragData::Resource(res) if res == resource));
+}
+
+#[itest]
+fn drag_data_custom_and_other() {
+    let custom = dict! { "type": "inventory_item", "id": 42 };
+    let data = DragData::from_variant(&custom.to_variant());
+    assert!(matches!(data, DragData::Custom(dict) if dict.at("id") == 42.to_variant()));
+
+    // Malformed editor payloads are kept as-is.
+    let malformed = dict! { "type": "files", "files": 5 };
+    assert!(matches!(
+        DragData::from_variant(&malformed.to_variant()),
+        DragData::Custom(_)
+    ));
+
+    assert!(matches!(
+        DragData::from_variant(&Variant::nil()),
+        DragData::Other(v) if v.is_nil()
+    ));
+
+    let node = Node::new_alloc();
+    assert!(matches!(
+        DragData::from_variant(&node.to_variant()),
+        DragData::Other(_)
+    ));
+    node.free();
+}
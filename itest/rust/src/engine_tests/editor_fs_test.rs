@@ -0,0 +1,19 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::tools::EditorFiles;
+
+use crate::framework::itest;
+
+// Integration tests run headless without the editor, so only the fallback behavior can be verified here. Signals, scan() and
+// file_info() need the editor and are not covered.
+// Path splitting for file_info() is covered by unit tests in godot-core.
+
+#[itest]
+fn editor_files_outside_editor() {
+    assert!(EditorFiles::get().is_none());
+}
@@ -0,0 +1,26 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::global::Error;
+use godot::tools::EditorState;
+
+use crate::framework::itest;
+
+// Integration tests run headless without the editor, so only the fallback behavior can be verified here.
+
+#[itest]
+fn editor_state_outside_editor() {
+    assert!(!EditorState::is_playing_scene());
+    assert_eq!(EditorState::playing_scene(), None);
+}
+
+#[itest]
+fn editor_state_on_play_event_outside_editor() {
+    let result = EditorState::on_play_event(|_event| panic!("no play events outside the editor"));
+
+    assert_eq!(result.err(), Some(Error::ERR_UNAVAILABLE));
+}
@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+
+use godot::builtin::{
+    dict, varray, Callable, Dictionary, GString, StringName, Variant, VariantArray,
+};
+use godot::classes::RefCounted;
+use godot::init::engine_available;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewGd};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::{itest, run_godot_script};
+
+#[itest]
+fn engine_available_during_tests() {
+    assert!(engine_available());
+}
+
+#[itest]
+fn engine_values_dropped_after_unload() {
+    // Thread-locals of the main thread are only dropped when the process exits, after the extension has been unloaded. This requires
+    // a separate Godot process; a crash in one of the destructors would make it fail.
+    let output = run_godot_script(
+        "engine_available_test.gd",
+        "extends MainLoop\n\
+        \n\
+        func _initialize():\n\
+        \tExitDropProbe.new().keep_values_until_exit()\n\
+        \n\
+        func _process(_delta):\n\
+        \treturn true\n",
+    );
+
+    assert!(
+        output.contains("ExitDropProbe dropped: engine_available=false"),
+        "unexpected output:\n{output}"
+    );
+}
+
+thread_local! {
+    static KEPT_UNTIL_EXIT: RefCell<Option<KeptValues>> = const { RefCell::new(None) };
+}
+
+/// Engine values whose destructors run after the engine is gone. Fields are dropped in order, so `probe` reports last.
+struct KeptValues {
+    _object: Gd<RefCounted>,
+    _variant: Variant,
+    _callable: Callable,
+    _array: VariantArray,
+    _dictionary: Dictionary,
+    _string: GString,
+    _string_name: StringName,
+    _probe: DropProbe,
+}
+
+/// User `Drop` impl following the pattern documented on `engine_available()`.
+struct DropProbe;
+
+impl Drop for DropProbe {
+    fn drop(&mut self) {
+        // godot_print! needs the engine; print to stdout directly, which the parent test process reads.
+        println!(
+            "ExitDropProbe dropped: engine_available={}",
+            engine_available()
+        );
+    }
+}
+
+/// Used by [`engine_values_dropped_after_unload()`], in a separate process.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ExitDropProbe {}
+
+#[godot_api]
+impl ExitDropProbe {
+    #[func]
+    fn keep_values_until_exit(&self) {
+        let dictionary = dict! { "key": "value" };
+
+        let object = RefCounted::new_gd();
+        let values = KeptValues {
+            _callable: Callable::from_object_method(&object, "get_reference_count"),
+            _object: object,
+            _variant: "text".to_variant(),
+            _array: varray![1, "two", 3.0],
+            _dictionary: dictionary,
+            _string: GString::from("string"),
+            _string_name: StringName::from("string_name"),
+            _probe: DropProbe,
+        };
+
+        KEPT_UNTIL_EXIT.with(|kept| *kept.borrow_mut() = Some(values));
+    }
+}
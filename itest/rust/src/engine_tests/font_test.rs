@@ -0,0 +1,52 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::classes::{Font, ThemeDb};
+use godot::obj::Gd;
+
+use crate::framework::itest;
+
+fn fallback_font() -> Gd<Font> {
+    ThemeDb::singleton()
+        .get_fallback_font()
+        .expect("fallback font available")
+}
+
+#[itest]
+fn font_measure() {
+    let font = fallback_font();
+
+    let short = font.measure("Hello", 16);
+    let long = font.measure("Hello, world!", 16);
+    let large = font.measure("Hello", 32);
+
+    assert!(short.x > 0.0 && short.y > 0.0);
+    assert!(long.x > short.x);
+    assert!(large.x > short.x && large.y > short.y);
+}
+
+#[itest]
+fn font_wrap_lines() {
+    let font = fallback_font();
+    let text = "the quick brown fox jumps over the lazy dog";
+
+    // Wide enough for the whole text.
+    let lines = font.wrap_lines(text, 16, 10_000.0);
+    assert_eq!(lines, vec![GString::from(text)]);
+
+    // Narrow: one word per line (words wider than the limit are not split).
+    let lines = font.wrap_lines(text, 16, 1.0);
+    let expected: Vec<GString> = text.split(' ').map(GString::from).collect();
+    assert_eq!(lines, expected);
+
+    // Wrapped size covers several lines.
+    let single = font.measure(text, 16);
+    let wrapped = font.measure_wrapped(text, 16, single.x / 2.0);
+    assert!(wrapped.y > single.y);
+    assert!(wrapped.x < single.x);
+}
@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::Engine;
+use godot::tools::FrameInfo;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn frame_info_current() {
+    let engine = Engine::singleton();
+    let frame = FrameInfo::current();
+
+    assert_eq!(frame.process_frame, engine.get_process_frames());
+    assert_eq!(frame.physics_frame, engine.get_physics_frames());
+    assert_eq!(
+        frame.physics_ticks_per_second,
+        engine.get_physics_ticks_per_second()
+    );
+    assert_eq!(frame.time_scale, engine.get_time_scale());
+    assert!((0.0..=1.0).contains(&frame.physics_interpolation_fraction));
+    assert!(!frame.in_physics_frame);
+    assert_eq!(frame.delta(), frame.process_delta);
+    assert_eq!(frame.process_priority, None);
+}
+
+#[itest]
+fn frame_info_for_node(ctx: &TestContext) {
+    let mut node = ctx.scene_tree.clone();
+    node.set_process_priority(7);
+
+    let frame = FrameInfo::for_node(&node);
+    assert_eq!(frame.process_priority, Some(7));
+    assert_eq!(frame.process_delta, node.get_process_delta_time());
+    assert_eq!(frame.physics_delta, node.get_physics_process_delta_time());
+
+    node.set_process_priority(0);
+}
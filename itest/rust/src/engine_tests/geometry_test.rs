@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Vector2, Vector3};
+use godot::tools::geometry;
+
+use crate::framework::itest;
+
+fn square(origin: Vector2, size: f32) -> [Vector2; 4] {
+    [
+        origin,
+        origin + Vector2::new(size, 0.0),
+        origin + Vector2::new(size, size),
+        origin + Vector2::new(0.0, size),
+    ]
+}
+
+#[itest]
+fn geometry_polygon_booleans() {
+    let a = square(Vector2::ZERO, 10.0);
+    let b = square(Vector2::new(5.0, 5.0), 10.0);
+    let far = square(Vector2::new(100.0, 100.0), 1.0);
+
+    let intersection = geometry::intersect_polygons(&a, &b);
+    assert_eq!(intersection.len(), 1);
+    assert_eq!(intersection[0].len(), 4);
+
+    let merged = geometry::merge_polygons(&a, &b);
+    assert_eq!(merged.len(), 1);
+
+    let clipped = geometry::clip_polygons(&a, &b);
+    assert_eq!(clipped.len(), 1);
+
+    assert!(geometry::intersect_polygons(&a, &far).is_empty());
+    assert_eq!(geometry::exclude_polygons(&a, &far).len(), 2);
+}
+
+#[itest]
+fn geometry_offset() {
+    let a = square(Vector2::ZERO, 10.0);
+
+    let grown = geometry::offset_polygon(&a, 2.0, geometry::PolyJoinType::MITER);
+    assert_eq!(grown.len(), 1);
+    assert!(grown[0].iter().any(|p| p.x < 0.0));
+
+    let vanished = geometry::offset_polygon(&a, -6.0, geometry::PolyJoinType::SQUARE);
+    assert!(vanished.is_empty());
+
+    let line = [Vector2::ZERO, Vector2::new(10.0, 0.0)];
+    let inflated = geometry::offset_polyline(
+        &line,
+        1.0,
+        geometry::PolyJoinType::SQUARE,
+        geometry::PolyEndType::BUTT,
+    );
+    assert_eq!(inflated.len(), 1);
+}
+
+#[itest]
+fn geometry_hull_and_triangulation() {
+    let mut points = square(Vector2::ZERO, 10.0).to_vec();
+    points.push(Vector2::new(5.0, 5.0));
+
+    let hull = geometry::convex_hull(&points);
+    assert!(!hull.contains(&Vector2::new(5.0, 5.0)));
+
+    let a = square(Vector2::ZERO, 10.0);
+    let triangles = geometry::triangulate_polygon(&a).expect("triangulate square");
+    assert_eq!(triangles.len(), 2);
+    assert!(triangles.iter().flatten().all(|&i| i < a.len()));
+
+    let delaunay = geometry::triangulate_delaunay(&points).expect("triangulate points");
+    assert_eq!(delaunay.len(), 4);
+
+    assert_eq!(geometry::triangulate_delaunay(&points[..2]), None);
+}
+
+#[itest]
+fn geometry_3d_intersections() {
+    let triangle = [
+        Vector3::new(-1.0, 0.0, -1.0),
+        Vector3::new(1.0, 0.0, -1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+
+    let hit =
+        geometry::ray_intersects_triangle(Vector3::new(0.0, 5.0, 0.0), Vector3::DOWN, triangle);
+    assert_eq!(hit, Some(Vector3::ZERO));
+
+    let miss = geometry::segment_intersects_triangle(
+        Vector3::new(0.0, 5.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        triangle,
+    );
+    assert_eq!(miss, None);
+}
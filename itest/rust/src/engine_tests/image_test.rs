@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Color;
+use godot::classes::image::Format;
+use godot::classes::Image;
+use godot::tools::ImageMipmaps;
+
+use crate::framework::itest;
+
+#[itest]
+fn image_from_rgba8() {
+    #[rustfmt::skip]
+    let pixels = [
+        255, 0, 0, 255,   0, 255, 0, 255,   0, 0, 255, 255,
+        0, 0, 0, 255,     0, 0, 0, 0,       255, 255, 255, 255,
+    ];
+
+    let image = Image::from_rgba8(3, 2, &pixels).expect("valid image data");
+    assert_eq!((image.get_width(), image.get_height()), (3, 2));
+    assert_eq!(image.get_format(), Format::RGBA8);
+    assert!(!image.has_mipmaps());
+
+    assert_eq!(image.get_pixel(0, 0), Color::from_rgba8(255, 0, 0, 255));
+    assert_eq!(image.get_pixel(2, 0), Color::from_rgba8(0, 0, 255, 255));
+    assert_eq!(image.get_pixel(1, 1), Color::from_rgba8(0, 0, 0, 0));
+    assert_eq!(image.get_pixel(2, 1), Color::WHITE);
+}
+
+#[itest]
+fn image_from_raw_validates() {
+    let err = Image::from_rgba8(2, 2, &[0; 15]).expect_err("one byte short");
+    assert_eq!(
+        err.to_string(),
+        "image data has 15 bytes, but format RGBA8 with the given size requires 16 bytes"
+    );
+
+    Image::from_rgba8(0, 4, &[]).expect_err("zero width");
+    Image::from_raw(4, 4, Format::DXT1, &[0; 8], ImageMipmaps::None)
+        .expect_err("compressed format");
+
+    let image = Image::from_raw(2, 1, Format::L8, &[10, 20], ImageMipmaps::None).unwrap();
+    assert_eq!(image.get_format(), Format::L8);
+}
+
+#[itest]
+fn image_from_raw_mipmaps() {
+    // 4x2 + 2x1 + 1x1 pixels.
+    let data = [0; (8 + 2 + 1) * 3];
+    let image = Image::from_raw(4, 2, Format::RGB8, &data, ImageMipmaps::Included).unwrap();
+    assert!(image.has_mipmaps());
+    assert_eq!(image.get_mipmap_count(), 2);
+
+    Image::from_raw(4, 2, Format::RGB8, &data[..24], ImageMipmaps::Included)
+        .expect_err("missing mipmap data");
+
+    let image = Image::from_raw(4, 2, Format::RGB8, &data[..24], ImageMipmaps::Generate).unwrap();
+    assert!(image.has_mipmaps());
+}
+
+#[cfg(feature = "codegen-full")]
+#[itest]
+fn image_texture_from_raw() {
+    use godot::classes::ImageTexture;
+    use godot::obj::NewGd;
+
+    let texture = ImageTexture::from_rgba8(2, 1, &[255; 8]).expect("valid image data");
+    assert_eq!((texture.get_width(), texture.get_height()), (2, 1));
+
+    ImageTexture::from_image(&Image::new_gd()).expect_err("empty image");
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{InputEvent, InputEventKey, InputEventMouseButton, InputEventMouseMotion};
+use godot::global::{Key, MouseButton};
+use godot::obj::{Gd, NewGd};
+use godot::tools::{InputEventExt, TypedInputEvent};
+
+use crate::framework::itest;
+
+fn key_event(key: Key, pressed: bool, echo: bool) -> Gd<InputEvent> {
+    let mut event = InputEventKey::new_gd();
+    event.set_keycode(key);
+    event.set_pressed(pressed);
+    event.set_echo(echo);
+    event.upcast()
+}
+
+fn mouse_button_event(button: MouseButton, pressed: bool) -> Gd<InputEvent> {
+    let mut event = InputEventMouseButton::new_gd();
+    event.set_button_index(button);
+    event.set_pressed(pressed);
+    event.upcast()
+}
+
+#[itest]
+fn input_event_key_press() {
+    let event = key_event(Key::SPACE, true, false);
+    assert!(event.is_key_press(Key::SPACE));
+    assert!(!event.is_key_press(Key::ENTER));
+    assert!(event.as_key_press().is_some());
+    assert!(event.as_mouse_button_press().is_none());
+
+    // Echoes and releases are not presses.
+    assert!(!key_event(Key::SPACE, true, true).is_key_press(Key::SPACE));
+    assert!(!key_event(Key::SPACE, false, false).is_key_press(Key::SPACE));
+}
+
+#[itest]
+fn input_event_key_press_logical_physical() {
+    // Pressing the key labeled Z, located where W is on QWERTY (AZERTY layout).
+    let mut event = InputEventKey::new_gd();
+    event.set_keycode(Key::Z);
+    event.set_physical_keycode(Key::W);
+    event.set_pressed(true);
+    let event = event.upcast::<InputEvent>();
+
+    assert!(event.is_key_press(Key::Z));
+    assert!(!event.is_key_press(Key::W));
+
+    assert!(event.is_physical_key_press(Key::W));
+    assert!(!event.is_physical_key_press(Key::Z));
+}
+
+#[itest]
+fn input_event_mouse_button() {
+    let press = mouse_button_event(MouseButton::LEFT, true);
+    assert!(press.is_mouse_button_press(MouseButton::LEFT));
+    assert!(!press.is_mouse_button_press(MouseButton::RIGHT));
+    assert!(!press.is_mouse_button_release(MouseButton::LEFT));
+
+    let release = mouse_button_event(MouseButton::LEFT, false);
+    assert!(release.is_mouse_button_release(MouseButton::LEFT));
+    assert!(!release.is_mouse_button_press(MouseButton::LEFT));
+    assert!(!release.is_key_press(Key::SPACE));
+}
+
+#[itest]
+fn input_event_to_typed() {
+    let key = key_event(Key::A, true, false);
+    assert!(matches!(key.to_typed(), TypedInputEvent::Key(k) if k.get_keycode() == Key::A));
+
+    let button = mouse_button_event(MouseButton::RIGHT, true);
+    assert!(matches!(button.to_typed(), TypedInputEvent::MouseButton(_)));
+
+    let motion = InputEventMouseMotion::new_gd().upcast::<InputEvent>();
+    assert!(matches!(motion.to_typed(), TypedInputEvent::MouseMotion(_)));
+}
@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{StringName, Vector2};
+use godot::classes::{InstancePlaceholder, Node, Node2D, PackedScene};
+use godot::global;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::tools::save;
+
+use crate::framework::{itest, remove_test_file};
+
+const SCENE_NAME: &str = "test_instance_placeholder.tscn";
+
+/// Packs a scene whose only child is a placeholder for the scene at `sub_scene_path`, and instantiates it.
+fn instantiate_outer_scene(sub_scene_path: &str) -> Gd<Node> {
+    let mut child = Node2D::new_alloc();
+    child.set_name("Chunk");
+    child.set_scene_file_path(sub_scene_path);
+    child.set_scene_instance_load_placeholder(true);
+    child.set_position(Vector2::new(1.0, 2.0));
+
+    let mut root = Node::new_alloc();
+    root.add_child(&child);
+    child.set_owner(&root);
+
+    let mut outer = PackedScene::new_gd();
+    assert_eq!(outer.pack(&root), global::Error::OK);
+    root.free();
+
+    outer.instantiate().expect("outer scene instantiated")
+}
+
+#[itest]
+fn instance_placeholder_stored_properties() {
+    let mut root = instantiate_outer_scene("res://does_not_need_to_exist.tscn");
+    let mut placeholder = root.get_node_as::<InstancePlaceholder>("Chunk");
+
+    let position = placeholder.stored_property::<Vector2>("position");
+    assert_eq!(position, Some(Vector2::new(1.0, 2.0)));
+    assert_eq!(placeholder.stored_property::<f32>("rotation"), None);
+
+    let wrong_type = placeholder.try_stored_property::<i64>("position");
+    assert!(matches!(wrong_type, Some(Err(_))));
+
+    let properties = placeholder.stored_properties();
+    assert!(properties
+        .iter()
+        .any(|(name, value)| name == &StringName::from("position")
+            && value == &Vector2::new(1.0, 2.0).to_variant()));
+
+    root.free();
+}
+
+#[itest]
+fn instance_placeholder_replace_with_overrides() {
+    let mut sub_root = Node2D::new_alloc();
+    let mut sub_scene = PackedScene::new_gd();
+    assert_eq!(sub_scene.pack(&sub_root), global::Error::OK);
+    sub_root.free();
+
+    let sub_scene_path = format!("res://{SCENE_NAME}");
+    save(&sub_scene, &sub_scene_path);
+
+    let mut root = instantiate_outer_scene(&sub_scene_path);
+    let mut placeholder = root.get_node_as::<InstancePlaceholder>("Chunk");
+
+    let chunk = placeholder
+        .replace_with_instance_as::<Node2D>([("visible".into(), false.to_variant())])
+        .expect("sub-scene root is Node2D");
+
+    assert_eq!(chunk.get_parent(), Some(root.clone()));
+    assert_eq!(chunk.get_position(), Vector2::new(1.0, 2.0));
+    assert!(!chunk.is_visible());
+
+    // The placeholder was queued for deletion.
+    root.free();
+    remove_test_file(SCENE_NAME);
+}
@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::ClassDb;
+use godot::meta::introspection::{self, MethodQualifier};
+
+use crate::framework::itest;
+
+#[itest]
+fn introspection_node() {
+    let node = introspection::find_class("Node").expect("Node is generated");
+
+    assert_eq!(node.rust_name, "Node");
+    assert_eq!(node.base, Some("Object"));
+    assert!(node.is_instantiable);
+    assert!(!node.is_refcounted);
+
+    let get_child = node
+        .methods
+        .iter()
+        .find(|method| method.godot_name == "get_child")
+        .expect("Node::get_child");
+
+    assert_eq!(get_child.qualifier, MethodQualifier::Const);
+    assert_eq!(get_child.return_type, Some("Gd<godot::classes::Node>"));
+    assert_eq!(get_child.params[0].rust_type, "i32");
+    assert!(get_child.params[1].has_default);
+
+    let ready = node
+        .methods
+        .iter()
+        .find(|method| method.godot_name == "_ready")
+        .expect("Node::_ready");
+    assert!(ready.is_virtual);
+    assert_eq!(ready.rust_name, "ready");
+
+    assert!(node.signals.iter().any(|signal| signal.name == "ready"));
+}
+
+#[itest]
+fn introspection_matches_class_db() {
+    let class_db = ClassDb::singleton();
+
+    for class in introspection::classes() {
+        assert!(
+            class_db.class_exists(class.godot_name),
+            "class {} not in ClassDB",
+            class.godot_name
+        );
+
+        if let Some(base) = class.base {
+            assert!(
+                introspection::find_class(base).is_some(),
+                "base {base} of {} not listed",
+                class.godot_name
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Signal;
+use godot::classes::{Engine, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Base, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{spawn_awaitable, spawn_local};
+
+use crate::framework::{itest, wait_frames, wait_signal, TestContext};
+
+// Awaited from GDScript in SpecialTests.gd, since completion requires frames to pass.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AsyncFuncs {
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl AsyncFuncs {
+    #[func]
+    fn double_later(&self, value: i64) -> Signal {
+        spawn_awaitable(async move { value * 2 })
+    }
+}
+
+#[itest]
+fn spawn_awaitable_completes_deferred() {
+    let signal = spawn_awaitable(async { 7 });
+
+    // The future is polled at the end of the frame, so the emitter is still alive and has not emitted yet.
+    assert!(signal.object().is_some());
+    assert_eq!(signal.name(), "completed".into());
+}
+
+#[itest]
+async fn spawn_awaitable_emits_output() {
+    let signal = spawn_awaitable(async { 7 });
+
+    let (output,): (i64,) = wait_signal(&signal).await;
+    assert_eq!(output, 7);
+}
+
+#[itest]
+async fn wait_frames_advances_main_loop(ctx: &TestContext) {
+    let start = Engine::singleton().get_process_frames();
+    wait_frames(3).await;
+
+    assert!(Engine::singleton().get_process_frames() >= start + 3);
+    assert!(ctx.scene_tree.is_inside_tree());
+}
+
+#[itest(timeout = 1000)]
+async fn spawn_local_runs_deferred() {
+    let mut emitter = RefCounted::new_gd();
+    emitter.add_user_signal("done");
+    let done = Signal::from_object_signal(&emitter, "done");
+    let received = wait_signal::<(i64,)>(&done);
+
+    spawn_local(async move {
+        wait_frames(1).await;
+        emitter.emit_signal("done", &[5.to_variant()]);
+    });
+
+    assert_eq!(received.await, (5,));
+}
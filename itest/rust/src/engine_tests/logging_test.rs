@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Both the logger and the tracing layer are installed by `ExtensionLibrary` hooks in lib.rs. If installation had failed, the `log` and
+// `tracing` crates would fall back to their no-op defaults, which report every level as disabled.
+
+use crate::framework::itest;
+
+/// Target with a more verbose level than the default.
+const VERBOSE_TARGET: &str = "itest_logging";
+
+#[cfg(feature = "log")]
+pub fn make_logger() -> godot::tools::GodotLogger {
+    use log::LevelFilter;
+
+    godot::tools::GodotLogger::new()
+        .max_level(LevelFilter::Warn)
+        .target_level(VERBOSE_TARGET, LevelFilter::Debug)
+}
+
+#[cfg(feature = "tracing")]
+pub fn make_tracing_layer() -> godot::tools::GodotTracingLayer {
+    use tracing::Level;
+
+    godot::tools::GodotTracingLayer::new()
+        .max_level(Level::WARN)
+        .target_level(VERBOSE_TARGET, Level::DEBUG)
+}
+
+#[cfg(feature = "log")]
+#[itest]
+fn log_logger_installed() {
+    use log::{Level, LevelFilter, Metadata};
+
+    let enabled = |level: Level, target: &str| {
+        let metadata = Metadata::builder().level(level).target(target).build();
+        log::logger().enabled(&metadata)
+    };
+
+    // Global maximum is the most verbose configured level.
+    assert_eq!(log::max_level(), LevelFilter::Debug);
+
+    assert!(enabled(Level::Warn, "itest_other"));
+    assert!(!enabled(Level::Info, "itest_other"));
+    assert!(enabled(Level::Debug, VERBOSE_TARGET));
+    assert!(enabled(Level::Debug, "itest_logging::sub"));
+    assert!(!enabled(Level::Trace, VERBOSE_TARGET));
+}
+
+#[cfg(feature = "log")]
+#[itest]
+fn log_records_forwarded() {
+    // Main thread: forwarded to Godot. Must not panic or deadlock.
+    log::info!(target: VERBOSE_TARGET, "log record from main thread");
+    log::debug!(target: VERBOSE_TARGET, "log record with arg {}", 42);
+
+    // Other thread: written to stderr unless experimental-threads is enabled.
+    std::thread::spawn(|| log::info!(target: VERBOSE_TARGET, "log record from other thread"))
+        .join()
+        .expect("logging from another thread must not panic");
+}
+
+#[cfg(feature = "tracing")]
+#[itest]
+fn tracing_layer_installed() {
+    use tracing::Level;
+
+    assert!(tracing::dispatcher::has_been_set());
+
+    assert!(tracing::enabled!(target: "itest_other", Level::WARN));
+    assert!(!tracing::enabled!(target: "itest_other", Level::INFO));
+    assert!(tracing::enabled!(target: VERBOSE_TARGET, Level::DEBUG));
+    assert!(!tracing::enabled!(target: VERBOSE_TARGET, Level::TRACE));
+}
+
+#[cfg(feature = "tracing")]
+#[itest]
+fn tracing_events_forwarded() {
+    tracing::info!(target: VERBOSE_TARGET, answer = 42, "tracing event from main thread");
+
+    std::thread::spawn(
+        || tracing::info!(target: VERBOSE_TARGET, "tracing event from other thread"),
+    )
+    .join()
+    .expect("tracing from another thread must not panic");
+}
@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{IMainLoop, MainLoop, ProjectSettings, SceneTree};
+use godot::prelude::*;
+use godot::tools::{main_loop, main_loop_type, set_main_loop_type};
+
+use crate::framework::{itest, run_godot_script};
+
+#[derive(GodotClass)]
+#[class(init, base=MainLoop)]
+struct TestMainLoop {
+    initialized: bool,
+    frames: u32,
+    base: Base<MainLoop>,
+}
+
+#[godot_api]
+impl IMainLoop for TestMainLoop {
+    fn initialize(&mut self) {
+        self.initialized = true;
+    }
+
+    fn physics_process(&mut self, _delta: f64) -> bool {
+        false
+    }
+
+    fn process(&mut self, _delta: f64) -> bool {
+        self.frames += 1;
+        self.frames >= 2
+    }
+
+    fn finalize(&mut self) {
+        // Read by main_loop_virtuals(), which runs this main loop in a separate Godot process.
+        godot_print!(
+            "TestMainLoop finalized: initialized={}, frames={}",
+            self.initialized,
+            self.frames
+        );
+    }
+}
+
+#[itest]
+fn main_loop_virtuals() {
+    // A main loop is only driven by the engine if it is *the* main loop of the process. So a second Godot process is started, with a
+    // script extending TestMainLoop as its main loop (like `set_main_loop_type()`, but selectable from the command line).
+    let output = run_godot_script("main_loop_test.gd", "extends TestMainLoop\n");
+
+    // process() requests to quit after the second frame, after which finalize() runs.
+    assert!(
+        output.contains("TestMainLoop finalized: initialized=true, frames=2"),
+        "unexpected output:\n{output}"
+    );
+}
+
+#[itest]
+fn main_loop_type_setting() {
+    let _restore = RestoreMainLoopType(main_loop_type());
+
+    set_main_loop_type::<TestMainLoop>();
+    assert_eq!(main_loop_type(), GString::from("TestMainLoop"));
+
+    // The running main loop is not affected.
+    assert!(main_loop::<SceneTree>().is_some());
+    assert!(main_loop::<TestMainLoop>().is_none());
+}
+
+/// Restores the main loop setting when dropped, also if the test panics.
+struct RestoreMainLoopType(GString);
+
+impl Drop for RestoreMainLoopType {
+    fn drop(&mut self) {
+        ProjectSettings::singleton()
+            .set_setting("application/run/main_loop_type", &self.0.to_variant());
+    }
+}
@@ -5,16 +5,80 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(all(feature = "codegen-full", since_api = "4.4"))]
+mod animation_node_test;
+mod autoload_test;
+mod cmdline_test;
 mod codegen_enums_test;
 mod codegen_test;
+#[cfg(feature = "codegen-full")]
+mod crypto_test;
+#[cfg(since_api = "4.2")]
+mod defer_test;
+#[cfg(feature = "codegen-full")]
+mod drag_data_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod editor_fs_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod editor_play_test;
+mod engine_available_test;
 mod engine_enum_test;
+#[cfg(feature = "codegen-full")]
+mod font_test;
+mod frame_info_test;
+#[cfg(feature = "codegen-full")]
+mod geometry_test;
 mod gfile_test;
+mod image_test;
+#[cfg(feature = "codegen-full")]
+mod input_event_test;
+#[cfg(feature = "codegen-full")]
+mod instance_placeholder_test;
+#[cfg(feature = "api-introspection")]
+mod introspection_test;
+#[cfg(since_api = "4.2")]
+mod local_task_test;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod logging_test;
+mod main_loop_test;
 /// Native audio structure tests are only enabled when both the `experimental-threads` and `codegen-full` features are active. The tests
 /// require these features to be able to execute.
 #[cfg(all(feature = "experimental-threads", feature = "codegen-full"))]
 mod native_audio_structures_test;
 mod native_structures_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod nav_test;
 mod node_test;
+mod property_defaults_test;
+#[cfg(feature = "codegen-full")]
+mod resource_uid_test;
 mod save_load_test;
+mod scene_snapshot_test;
+#[cfg(feature = "codegen-full")]
+mod shader_uniforms_test;
+mod sidecar_test;
+#[cfg(feature = "codegen-full")]
+mod theme_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+mod tile_map_test;
 mod translate_test;
+#[cfg(feature = "codegen-full")]
+mod tween_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod undo_redo_test;
 mod utilities_test;
+mod variant_text_test;
+#[cfg(feature = "codegen-full")]
+mod window_test;
+#[cfg(all(
+    feature = "experimental-threads",
+    feature = "codegen-full",
+    since_api = "4.2"
+))]
+mod worker_pool_test;
+
+// Installed through the `ExtensionLibrary` hooks.
+#[cfg(feature = "log")]
+pub use logging_test::make_logger;
+#[cfg(feature = "tracing")]
+pub use logging_test::make_tracing_layer;
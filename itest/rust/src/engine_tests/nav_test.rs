@@ -0,0 +1,169 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{PackedInt32Array, PackedVector3Array, Transform3D, Vector2, Vector3};
+use godot::classes::{NavigationMesh, NavigationServer2D, NavigationServer3D};
+use godot::obj::{Gd, NewGd};
+use godot::tools::nav::{NavAgent2D, NavAgent3D, NavMap2D, NavMap3D, NavRegion3D};
+
+use crate::framework::{itest, wait_frames};
+
+// The navigation servers queue most commands and apply them when synchronizing maps, once per physics frame. Tests force this
+// synchronization with map_force_update() before inspecting the server state.
+
+#[itest]
+fn nav_map_lifecycle_3d() {
+    let map = NavMap3D::new();
+    let rid = map.rid();
+
+    // Separate map used to flush the server's command queue once `map` is freed.
+    let anchor = NavMap3D::new();
+
+    let mut server = NavigationServer3D::singleton();
+    server.map_force_update(rid);
+    assert!(server.get_maps().contains(rid));
+    assert!(server.map_is_active(rid));
+
+    drop(map);
+    server.map_force_update(anchor.rid());
+    assert!(!server.get_maps().contains(rid));
+}
+
+#[itest]
+fn nav_region_agent_3d() {
+    let map = NavMap3D::new();
+    let mut server = NavigationServer3D::singleton();
+
+    let mut region = NavRegion3D::new(&map);
+    region.set_navigation_mesh(&NavigationMesh::new_gd());
+    region.set_transform(Transform3D::IDENTITY);
+    region.set_navigation_layers(0b10);
+    server.map_force_update(map.rid());
+    assert_eq!(server.region_get_map(region.rid()), map.rid());
+    assert_eq!(server.region_get_navigation_layers(region.rid()), 0b10);
+
+    let mut agent = NavAgent3D::new(&map);
+    agent.set_radius(0.5);
+    agent.set_max_speed(3.0);
+    agent.set_position(Vector3::new(1.0, 0.0, 2.0));
+    agent.set_velocity(Vector3::RIGHT);
+    agent.set_avoidance_enabled(true);
+    agent.set_avoidance_callback(|_safe_velocity: Vector3| {});
+    server.map_force_update(map.rid());
+    assert_eq!(server.agent_get_map(agent.rid()), map.rid());
+    assert!(server.agent_get_avoidance_enabled(agent.rid()));
+
+    agent.clear_avoidance_callback();
+}
+
+#[itest]
+fn nav_map_agent_2d() {
+    let mut map = NavMap2D::new();
+    map.on_changed(|| {});
+
+    let mut server = NavigationServer2D::singleton();
+    server.map_force_update(map.rid());
+    assert!(server.get_maps().contains(map.rid()));
+
+    let mut agent = NavAgent2D::new(&map);
+    agent.set_position(Vector2::new(4.0, 2.0));
+    agent.set_avoidance_callback(|_safe_velocity: Vector2| {});
+    server.map_force_update(map.rid());
+    assert_eq!(server.agent_get_map(agent.rid()), map.rid());
+
+    let agent_rid = agent.rid();
+    drop(agent);
+    server.map_force_update(map.rid());
+    assert!(!server.map_get_agents(map.rid()).contains(agent_rid));
+}
+
+#[itest]
+fn nav_find_path_3d() {
+    let mut map = NavMap3D::new();
+    map.set_cell_size(0.25);
+
+    let mut region = NavRegion3D::new(&map);
+    region.set_navigation_mesh(&triangle_mesh());
+    region.set_navigation_layers(0b01);
+    NavigationServer3D::singleton().map_force_update(map.rid());
+
+    let from = Vector3::new(1.0, 0.0, 1.0);
+    let to = Vector3::new(3.0, 0.0, 4.0);
+
+    let path = map.find_path(from, to, true);
+    assert!(path.len() >= 2, "path on synchronized map: {path:?}");
+    assert_eq_approx!(path[0], from);
+    assert_eq_approx!(*path.last().unwrap(), to);
+
+    // Points outside the mesh are snapped onto it.
+    let closest = map.closest_point(Vector3::new(1.0, 5.0, 1.0));
+    assert_eq_approx!(closest, from);
+
+    // No region on these layers.
+    let filtered = map.find_path_on_layers(from, to, true, 0b10);
+    assert!(filtered.is_empty(), "path on unused layers: {filtered:?}");
+}
+
+#[itest]
+async fn nav_avoidance_callback_3d() {
+    let map = NavMap3D::new();
+    let velocity = Vector3::new(1.0, 0.0, 0.5);
+
+    let received = Rc::new(RefCell::new(Vec::<Vector3>::new()));
+    let mut agent = NavAgent3D::new(&map);
+    agent.set_radius(0.5);
+    agent.set_max_speed(10.0);
+    agent.set_position(Vector3::ZERO);
+    agent.set_avoidance_enabled(true);
+    agent.set_avoidance_callback({
+        let received = received.clone();
+        move |safe_velocity: Vector3| received.borrow_mut().push(safe_velocity)
+    });
+
+    // Avoidance runs during the server's physics step; velocities must be submitted again each frame.
+    for _ in 0..30 {
+        agent.set_velocity(velocity);
+        wait_frames(1).await;
+
+        if !received.borrow().is_empty() {
+            break;
+        }
+    }
+
+    let first = *received
+        .borrow()
+        .first()
+        .expect("avoidance callback invoked within 30 frames");
+
+    // A single agent has nothing to avoid, so its desired velocity is safe.
+    assert_eq_approx!(first, velocity);
+
+    // After clearing, no further callbacks arrive.
+    agent.clear_avoidance_callback();
+    let count = received.borrow().len();
+    for _ in 0..3 {
+        agent.set_velocity(velocity);
+        wait_frames(1).await;
+    }
+    assert_eq!(received.borrow().len(), count);
+}
+
+/// Single triangle in the XZ plane, with corners at the origin, (9, 0, 0) and (0, 0, 9).
+fn triangle_mesh() -> Gd<NavigationMesh> {
+    let mut mesh = NavigationMesh::new_gd();
+    mesh.set_vertices(&PackedVector3Array::from(&[
+        Vector3::ZERO,
+        Vector3::new(9.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 9.0),
+    ]));
+    mesh.add_polygon(&PackedInt32Array::from(&[0, 1, 2]));
+    mesh
+}
@@ -10,7 +10,8 @@ use std::str::FromStr;
 use godot::builtin::{NodePath, Variant};
 use godot::classes::{Node, Node3D, PackedScene, SceneTree};
 use godot::global;
-use godot::obj::{NewAlloc, NewGd};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::tools::NodeGroup;
 
 use crate::framework::{itest, TestContext};
 
@@ -92,3 +93,67 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group");
     tree.call_group("group", "set_name", &[Variant::from("name")]);
 }
+
+#[itest]
+fn node_assign_owner_recursive() {
+    let mut root = Node::new_alloc();
+    let mut branch = Node::new_alloc();
+    let leaf = Node::new_alloc();
+
+    // Simulates a node instantiated from another scene; its children must keep their owner.
+    let mut sub_scene = Node::new_alloc();
+    sub_scene.set_scene_file_path("res://sub_scene.tscn");
+    let sub_scene_child = Node::new_alloc();
+    sub_scene.add_child(&sub_scene_child);
+
+    branch.add_child(&leaf);
+    root.add_child(&branch);
+    root.add_child(&sub_scene);
+
+    let owner = root.clone();
+    root.assign_owner_recursive(&owner);
+
+    assert_eq!(root.get_owner(), None);
+    assert_eq!(branch.get_owner(), Some(root.clone()));
+    assert_eq!(leaf.get_owner(), Some(root.clone()));
+    assert_eq!(sub_scene.get_owner(), Some(root.clone()));
+    assert_eq!(sub_scene_child.get_owner(), None);
+
+    root.free();
+}
+
+struct TestGroup;
+
+impl NodeGroup for TestGroup {
+    const NAME: &'static str = "node_test_group";
+}
+
+#[itest]
+fn node_group_typed(ctx: &TestContext) {
+    let mut tree = ctx.scene_tree.get_tree().unwrap();
+    let mut parent = Node::new_alloc();
+    ctx.scene_tree.clone().add_child(&parent);
+
+    let mut node3d = Node3D::new_alloc();
+    let mut plain = Node::new_alloc();
+    parent.add_child(&node3d);
+    parent.add_child(&plain);
+
+    node3d.add_to_group_typed::<TestGroup>();
+    plain.add_to_group_typed::<TestGroup>();
+    assert!(node3d.is_in_group_typed::<TestGroup>());
+
+    let members: Vec<Gd<Node3D>> = tree.nodes_in_group_as(TestGroup::NAME).collect();
+    assert_eq!(members, vec![node3d.clone()]);
+
+    let err = tree
+        .try_nodes_in_group_as::<Node3D>(TestGroup::NAME)
+        .unwrap_err();
+    assert_eq!(err, plain);
+
+    plain.remove_from_group_typed::<TestGroup>();
+    let members = tree.try_nodes_in_group_as::<Node3D>(TestGroup::NAME);
+    assert_eq!(members, Ok(vec![node3d.clone()]));
+
+    parent.free();
+}
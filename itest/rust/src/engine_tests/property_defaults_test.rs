@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+use godot::tools::PropertyDefaults;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(base=Node)]
+struct DefaultsNode {
+    #[export]
+    speed: f32,
+
+    #[export]
+    label: GString,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for DefaultsNode {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            speed: 2.5,
+            label: "default".into(),
+            base,
+        }
+    }
+}
+
+#[itest]
+fn property_defaults_engine_class() {
+    let defaults = PropertyDefaults::of_class("Node2D").expect("Node2D is instantiable");
+    assert_eq!(defaults.class_name(), &StringName::from("Node2D"));
+    assert_eq!(defaults.get("position"), Some(&Vector2::ZERO.to_variant()));
+    assert_eq!(defaults.get("not_a_property"), None);
+
+    let mut node = Node2D::new_alloc();
+    assert!(!defaults.is_modified(&node));
+
+    node.set_position(Vector2::new(3.0, 4.0));
+    node.set_visible(false);
+
+    let changed = defaults.changed_properties(&node);
+    assert_eq!(changed.len(), 2);
+    assert_eq!(
+        changed.get("position"),
+        Some(Vector2::new(3.0, 4.0).to_variant())
+    );
+    assert_eq!(changed.get("visible"), Some(false.to_variant()));
+
+    node.free();
+}
+
+#[itest]
+fn property_defaults_user_class() {
+    let mut node = DefaultsNode::new_alloc();
+    let defaults = PropertyDefaults::of_object(&node).expect("user class is instantiable");
+    assert_eq!(defaults.get("speed"), Some(&2.5.to_variant()));
+
+    node.bind_mut().label = "changed".into();
+    let changed = defaults.changed_properties(&node);
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed.get("label"), Some("changed".to_variant()));
+
+    node.free();
+}
+
+#[itest]
+fn property_defaults_not_instantiable() {
+    assert!(PropertyDefaults::of_class("CanvasItem").is_none());
+    assert!(PropertyDefaults::of_class("DoesNotExist").is_none());
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn class_property_default() {
+    use godot::tools::class_property_default;
+
+    assert_eq!(
+        class_property_default("Node2D", "rotation"),
+        Some(0.0.to_variant())
+    );
+    assert_eq!(class_property_default("Node2D", "not_a_property"), None);
+}
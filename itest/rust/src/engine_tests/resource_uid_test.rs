@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::tools::{resolve_uid_path, Uid};
+
+use crate::framework::{itest, remove_test_file};
+
+#[itest]
+fn uid_text_roundtrip() {
+    let uid = Uid::create();
+    let text = uid.to_gstring();
+
+    assert!(text.to_string().starts_with("uid://"));
+    assert_eq!(uid.to_string(), text.to_string());
+    assert_eq!(Uid::parse(&text), Some(uid));
+    assert_eq!(Uid::from_i64(uid.to_i64()), Some(uid));
+}
+
+#[itest]
+fn uid_parse_invalid() {
+    assert_eq!(Uid::parse("res://some/path.tres"), None);
+    assert_eq!(Uid::parse("uid://<invalid>"), None);
+    assert_eq!(Uid::from_i64(-1), None);
+}
+
+#[itest]
+fn uid_set_and_remove_path() {
+    let uid = Uid::create();
+    assert_eq!(uid.path(), None);
+
+    uid.set_path("res://generated/first.tres");
+    assert_eq!(
+        uid.path(),
+        Some(GString::from("res://generated/first.tres"))
+    );
+
+    uid.set_path("res://generated/second.tres");
+    assert_eq!(
+        resolve_uid_path(&uid.to_gstring()),
+        Some(GString::from("res://generated/second.tres"))
+    );
+
+    assert!(uid.remove_path());
+    assert!(!uid.remove_path());
+    assert_eq!(resolve_uid_path(&uid.to_gstring()), None);
+}
+
+#[itest]
+fn uid_resolve_non_uid_path() {
+    assert_eq!(
+        resolve_uid_path("res://some/path.tres"),
+        Some(GString::from("res://some/path.tres"))
+    );
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn uid_ensure_for_saved_resource() {
+    use godot::classes::Resource;
+    use godot::obj::NewGd;
+    use godot::tools::{ensure_uid_for, save, uid_for};
+
+    const FILE_NAME: &str = "test_uid_resource.tres";
+    let path = format!("res://{FILE_NAME}");
+
+    save(&Resource::new_gd(), &path);
+
+    let uid = ensure_uid_for(&path).expect("UID assigned");
+    assert_eq!(uid_for(&path), Some(uid));
+    assert_eq!(uid.path(), Some(GString::from(&path)));
+
+    // Idempotent.
+    assert_eq!(ensure_uid_for(&path).expect("UID exists"), uid);
+
+    uid.remove_path();
+    remove_test_file(FILE_NAME);
+}
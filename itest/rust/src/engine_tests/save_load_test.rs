@@ -5,18 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use godot::builtin::Dictionary;
+use godot::classes::ResourceLoader;
+use godot::meta::ToGodot;
 use godot::obj::NewGd;
 use godot::register::GodotClass;
-use godot::tools::{load, save, try_load, try_save};
+use godot::tools::{
+    load, migrate_resource, res, save, try_load, try_save, MigrateResource, ResPath,
+};
 
-use crate::framework::itest;
-
-fn remove_test_file(file_name: &str) {
-    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
-    let file_path = godot_path.join(file_name);
-    std::fs::remove_file(&file_path)
-        .unwrap_or_else(|_| panic!("couldn't remove test file: {}", file_path.display()));
-}
+use crate::framework::{itest, remove_test_file, test_file_path};
 
 #[derive(GodotClass)]
 #[class(base=Resource, init)]
@@ -25,7 +23,27 @@ struct SavedGame {
     level: u32,
 }
 
+#[derive(GodotClass)]
+#[class(base=Resource, init)]
+#[resource_version(2)]
+struct VersionedSave {
+    // Version 1 stored this as `hp`.
+    #[export]
+    health: i32,
+}
+
+impl MigrateResource for VersionedSave {
+    fn migrate(from_version: u32, data: &mut Dictionary) {
+        assert_eq!(from_version, 1);
+
+        if let Some(hp) = data.remove("hp") {
+            data.set("health", hp);
+        }
+    }
+}
+
 const RESOURCE_NAME: &str = "test_resource.tres";
+const VERSIONED_RESOURCE_NAME: &str = "test_versioned_resource.tres";
 const FAULTY_PATH: &str = "no_such_path";
 
 #[itest]
@@ -69,3 +87,138 @@ fn load_test() {
 
     remove_test_file(RESOURCE_NAME);
 }
+
+#[itest]
+fn load_res_path() {
+    const RES_PATH: ResPath = res!("res://test_res_path_resource.tres");
+
+    assert_eq!(RES_PATH.as_str(), "res://test_res_path_resource.tres");
+    assert_eq!(RES_PATH.extension(), "tres");
+
+    let mut resource = SavedGame::new_gd();
+    resource.bind_mut().set_level(42);
+    save(&resource, RES_PATH);
+
+    let loaded = load::<SavedGame>(RES_PATH);
+    assert_eq!(loaded.bind().get_level(), 42);
+
+    let loaded = try_load::<SavedGame>(&RES_PATH).expect("load via reference");
+    assert_eq!(loaded.bind().get_level(), 42);
+
+    remove_test_file("test_res_path_resource.tres");
+}
+
+#[itest]
+fn versioned_resource_new_instance() {
+    let resource = VersionedSave::new_gd();
+    assert_eq!(resource.get_meta("_resource_version"), 2.to_variant());
+}
+
+#[itest]
+fn versioned_resource_migrate_on_load() {
+    std::fs::write(
+        test_file_path(VERSIONED_RESOURCE_NAME),
+        versioned_resource_v1(42),
+    )
+    .expect("write versioned test resource");
+
+    let res_path = format!("res://{VERSIONED_RESOURCE_NAME}");
+    let loaded = load::<VersionedSave>(&res_path);
+    assert_eq!(loaded.bind().get_health(), 42);
+    assert_eq!(loaded.get_meta("_resource_version"), 2.to_variant());
+    assert!(!loaded.has_meta("_missing_resources"));
+
+    remove_test_file(VERSIONED_RESOURCE_NAME);
+}
+
+#[itest]
+fn versioned_resource_migrate_explicitly() {
+    // Separate file, so the resource is not served from Godot's cache of the other test.
+    const EXPLICIT_RESOURCE_NAME: &str = "test_versioned_resource_explicit.tres";
+
+    std::fs::write(
+        test_file_path(EXPLICIT_RESOURCE_NAME),
+        versioned_resource_v1(17),
+    )
+    .expect("write versioned test resource");
+
+    // Loading through Godot directly does not migrate.
+    let res_path = format!("res://{EXPLICIT_RESOURCE_NAME}");
+    let loaded = ResourceLoader::singleton()
+        .load(&res_path)
+        .expect("load versioned resource")
+        .cast::<VersionedSave>();
+    assert_eq!(loaded.bind().get_health(), 0);
+    assert_eq!(loaded.get_meta("_resource_version"), 1.to_variant());
+
+    migrate_resource(&loaded);
+    assert_eq!(loaded.bind().get_health(), 17);
+    assert_eq!(loaded.get_meta("_resource_version"), 2.to_variant());
+
+    // Already migrated: no-op (migrate() asserts the old version).
+    migrate_resource(&loaded);
+    assert_eq!(loaded.bind().get_health(), 17);
+
+    remove_test_file(EXPLICIT_RESOURCE_NAME);
+}
+
+/// Resource as saved by version 1 of `VersionedSave`.
+fn versioned_resource_v1(hp: i32) -> String {
+    format!(
+        "\
+        [gd_resource type=\"VersionedSave\" format=3]\n\
+        \n\
+        [resource]\n\
+        hp = {hp}\n\
+        metadata/_resource_version = 1\n"
+    )
+}
+
+// Async tests: the future wakes itself once per frame through a deferred call, so it is driven by the test runner's main loop.
+
+#[cfg(all(feature = "experimental-threads", since_api = "4.2"))]
+const ASYNC_RESOURCE_NAME: &str = "test_async_resource.tres";
+
+#[cfg(all(feature = "experimental-threads", since_api = "4.2"))]
+#[itest]
+async fn load_async_test() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use godot::tools::load_async;
+
+    let level = 51;
+    let res_path = format!("res://{ASYNC_RESOURCE_NAME}");
+
+    let mut resource = SavedGame::new_gd();
+    resource.bind_mut().set_level(level);
+    save(&resource, &res_path);
+
+    let last_progress = Rc::new(Cell::new(0.0));
+    let callback_progress = last_progress.clone();
+
+    let future =
+        load_async::<SavedGame>(&res_path).on_progress(move |ratio| callback_progress.set(ratio));
+    let handle = future.progress_handle();
+
+    let loaded = future.await.expect("load_async");
+    assert_eq!(loaded.bind().get_level(), level);
+    assert_eq!(handle.get(), 1.0);
+    assert_eq!(last_progress.get(), 1.0);
+
+    remove_test_file(ASYNC_RESOURCE_NAME);
+}
+
+#[cfg(all(feature = "experimental-threads", since_api = "4.2"))]
+#[itest]
+async fn load_async_missing_test() {
+    use godot::classes::Engine;
+    use godot::tools::load_async;
+
+    // Like suppress_godot_print(), but across the await.
+    Engine::singleton().set_print_error_messages(false);
+    let result = load_async::<SavedGame>("res://no_such_resource.tres").await;
+    Engine::singleton().set_print_error_messages(true);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Dictionary, NodePath, Vector2, Vector3};
+use godot::classes::{Node, Node2D, Node3D};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::SceneSnapshotter;
+
+use crate::framework::itest;
+
+/// Creates `Root -> (Player -> Sprite, Camera)`, with `Camera` being a `Node3D`.
+fn make_tree() -> Gd<Node> {
+    let mut root = Node::new_alloc();
+    root.set_name("Root");
+
+    let mut player = Node2D::new_alloc();
+    player.set_name("Player");
+    root.add_child(&player);
+
+    let mut sprite = Node2D::new_alloc();
+    sprite.set_name("Sprite");
+    player.add_child(&sprite);
+
+    let mut camera = Node3D::new_alloc();
+    camera.set_name("Camera");
+    root.add_child(&camera);
+
+    root
+}
+
+#[itest]
+fn scene_snapshot_capture_restore() {
+    let root = make_tree();
+    root.get_node_as::<Node2D>("Player")
+        .set_position(Vector2::new(10.0, 20.0));
+    root.get_node_as::<Node2D>("Player/Sprite")
+        .set_rotation(1.5);
+
+    let snapshotter = SceneSnapshotter::new();
+    let snapshot = snapshotter.capture(&root);
+
+    let keys: Vec<String> = snapshot
+        .keys_array()
+        .iter_shared()
+        .map(|k| k.to_string())
+        .collect();
+    assert_eq!(keys, [".", "Player", "Player/Sprite", "Camera"]);
+
+    let player = snapshot.at("Player").to::<Dictionary>();
+    assert_eq!(player.at("position"), Vector2::new(10.0, 20.0).to_variant());
+    assert!(!player.contains_key("script"));
+
+    // Restore into a fresh tree with the same structure.
+    let other = make_tree();
+    let missing = snapshotter.restore(&other, &snapshot);
+    assert!(missing.is_empty());
+
+    assert_eq!(
+        other.get_node_as::<Node2D>("Player").get_position(),
+        Vector2::new(10.0, 20.0)
+    );
+    assert_eq!(
+        other.get_node_as::<Node2D>("Player/Sprite").get_rotation(),
+        1.5
+    );
+
+    root.free();
+    other.free();
+}
+
+#[itest]
+fn scene_snapshot_missing_nodes() {
+    let root = make_tree();
+    let snapshot = SceneSnapshotter::new().capture(&root);
+
+    root.get_node_as::<Node>("Camera").free();
+    let missing = SceneSnapshotter::new().restore(&root, &snapshot);
+    assert_eq!(missing, [NodePath::from("Camera")]);
+
+    root.free();
+}
+
+#[itest]
+fn scene_snapshot_hooks_and_skipped_properties() {
+    let root = make_tree();
+    root.get_node_as::<Node3D>("Camera")
+        .set_position(Vector3::new(1.0, 2.0, 3.0));
+    root.get_node_as::<Node2D>("Player")
+        .set_position(Vector2::new(5.0, 5.0));
+
+    // Node3D only stores its position; Node2D skips it.
+    let snapshotter = SceneSnapshotter::new()
+        .with_hook::<Node3D>(
+            |node| node.get_position().to_variant(),
+            |node, data| node.set_position(data.to()),
+        )
+        .without_property("position");
+
+    let snapshot = snapshotter.capture(&root);
+    assert_eq!(
+        snapshot.at("Camera"),
+        Vector3::new(1.0, 2.0, 3.0).to_variant()
+    );
+
+    let player = snapshot.at("Player").to::<Dictionary>();
+    assert!(!player.contains_key("position"));
+    assert!(player.contains_key("rotation"));
+
+    let other = make_tree();
+    snapshotter.restore(&other, &snapshot);
+    assert_eq!(
+        other.get_node_as::<Node3D>("Camera").get_position(),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        other.get_node_as::<Node2D>("Player").get_position(),
+        Vector2::ZERO
+    );
+
+    root.free();
+    other.free();
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Color, StringName, VariantType, Vector3};
+use godot::classes::{Shader, ShaderMaterial};
+use godot::obj::{Gd, NewGd};
+use godot::tools::{ShaderMaterialExt, ShaderUniform};
+
+use crate::framework::{expect_debug_panic_or_release_ok, itest};
+
+fn make_material() -> Gd<ShaderMaterial> {
+    let mut shader = Shader::new_gd();
+    shader.set_code(
+        "shader_type spatial;\n\
+        uniform float strength = 1.0;\n\
+        uniform vec3 offset;\n\
+        uniform vec4 tint : source_color;\n",
+    );
+
+    let mut material = ShaderMaterial::new_gd();
+    material.set_shader(&shader);
+    material
+}
+
+#[itest]
+fn shader_uniforms_list() {
+    let material = make_material();
+
+    let uniforms = material.uniforms();
+    assert_eq!(
+        uniforms,
+        vec![
+            ShaderUniform {
+                name: StringName::from("strength"),
+                variant_type: VariantType::FLOAT,
+            },
+            ShaderUniform {
+                name: StringName::from("offset"),
+                variant_type: VariantType::VECTOR3,
+            },
+            ShaderUniform {
+                name: StringName::from("tint"),
+                variant_type: VariantType::COLOR,
+            },
+        ]
+    );
+
+    assert!(ShaderMaterial::new_gd().uniforms().is_empty());
+}
+
+#[itest]
+fn shader_uniforms_set_get() {
+    let mut material = make_material();
+
+    material.set_uniform("strength", 2.5);
+    material.set_uniform("offset", Vector3::new(1.0, 2.0, 3.0));
+    material.set_uniform("tint", Color::from_rgb(1.0, 0.0, 0.0));
+
+    // Integers are accepted for floats.
+    material.set_uniform("strength", 3);
+
+    assert_eq!(material.uniform::<i64>("strength"), Some(3));
+    assert_eq!(
+        material.uniform::<Vector3>("offset"),
+        Some(Vector3::new(1.0, 2.0, 3.0))
+    );
+    assert_eq!(material.uniform::<Color>("offset"), None);
+}
+
+#[itest]
+fn shader_uniforms_validation() {
+    let mut material = make_material();
+
+    expect_debug_panic_or_release_ok("unknown uniform", || {
+        material.set_uniform("strenght", 1.0);
+    });
+    expect_debug_panic_or_release_ok("wrong type", || {
+        material.set_uniform("offset", "text");
+    });
+}
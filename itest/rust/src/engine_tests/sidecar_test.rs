@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+use godot::tools::SidecarMap;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct SidecarOwner {}
+
+#[itest]
+fn sidecar_insert_get_remove() {
+    let mut map = SidecarMap::new();
+    let a = Node::new_alloc();
+    let b = Node::new_alloc();
+
+    assert_eq!(map.insert_gd(&a, "a"), None);
+    assert_eq!(map.insert_gd(&b, "b"), None);
+    assert_eq!(map.insert_gd(&a, "a2"), Some("a"));
+    assert_eq!(map.len(), 2);
+
+    assert_eq!(map.remove(a.instance_id()), Some("a2"));
+    assert_eq!(map.get_gd(&b), Some(&"b"));
+    assert!(!map.contains(a.instance_id()));
+
+    *map.get_gd_mut(&b).unwrap() = "b2";
+    let entries: Vec<_> = map.iter().collect();
+    assert_eq!(entries, vec![(b.instance_id(), &"b2")]);
+
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn sidecar_keys() {
+    let mut map = SidecarMap::new();
+    let a = Node::new_alloc();
+    let b = Node::new_alloc();
+
+    map.insert_gd(&a, 1);
+    let key_a = map.key(a.instance_id()).unwrap();
+    assert_eq!(map.get_by_key(key_a), Some(&1));
+
+    // Replacing the value keeps the key.
+    map.insert_gd(&a, 2);
+    *map.get_by_key_mut(key_a).unwrap() += 1;
+    assert_eq!(map.get_gd(&a), Some(&3));
+
+    // The slot of `a` is reused for `b`, but the old key doesn't see the new entry.
+    map.remove(a.instance_id());
+    map.insert_gd(&b, 10);
+    let key_b = map.key(b.instance_id()).unwrap();
+    assert_ne!(key_a, key_b);
+    assert_eq!(map.get_by_key(key_a), None);
+    assert_eq!(map.get_by_key(key_b), Some(&10));
+
+    map.clear();
+    assert_eq!(map.get_by_key(key_b), None);
+    assert!(map.key(b.instance_id()).is_none());
+
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn sidecar_tracked_cleanup() {
+    let mut map = SidecarMap::new();
+    map.track_class::<SidecarOwner>();
+
+    let kept = SidecarOwner::new_alloc();
+    let freed = SidecarOwner::new_alloc();
+    map.insert_gd(&kept, 1);
+    map.insert_gd(&freed, 2);
+
+    freed.free();
+    map.purge();
+
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get_gd(&kept), Some(&1));
+
+    kept.free();
+}
+
+#[itest]
+fn sidecar_retain_alive() {
+    let mut map = SidecarMap::new();
+    let kept = Node::new_alloc();
+    let freed = Node::new_alloc();
+    map.insert_gd(&kept, ());
+    map.insert_gd(&freed, ());
+
+    freed.free();
+    assert_eq!(map.retain_alive(), 1);
+    assert!(map.contains(kept.instance_id()));
+
+    kept.free();
+}
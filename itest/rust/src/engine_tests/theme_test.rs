@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Color, StringName};
+use godot::classes::{Button, StyleBox, StyleBoxFlat};
+use godot::obj::{NewAlloc, NewGd};
+
+use crate::framework::itest;
+
+#[itest]
+fn theme_override_values() {
+    let mut button = Button::new_alloc();
+
+    button.override_color("font_color", Color::ORANGE_RED);
+    button.override_constant("h_separation", 17);
+    button.override_font_size(&StringName::from("font_size"), 31);
+
+    assert_eq!(button.theme_color("font_color"), Some(Color::ORANGE_RED));
+    assert_eq!(button.theme_constant("h_separation"), Some(17));
+    assert_eq!(button.theme_font_size("font_size"), Some(31));
+
+    // Overrides are visible through the engine API, too.
+    assert!(button.has_theme_color_override("font_color"));
+    assert_eq!(button.get_theme_constant("h_separation"), 17);
+
+    button.free();
+}
+
+#[itest]
+fn theme_override_objects() {
+    let mut button = Button::new_alloc();
+    let stylebox = StyleBoxFlat::new_gd();
+
+    button.override_stylebox("normal", &stylebox);
+
+    let actual = button.theme_stylebox("normal").expect("stylebox present");
+    assert_eq!(actual, stylebox.upcast::<StyleBox>());
+
+    button.free();
+}
+
+#[itest]
+fn theme_missing_items() {
+    let button = Button::new_alloc();
+
+    // Items defined by the default theme.
+    assert!(button.theme_color("font_color").is_some());
+    assert!(button.theme_font("font").is_some());
+
+    assert_eq!(button.theme_color("no_such_color"), None);
+    assert_eq!(button.theme_constant("no_such_constant"), None);
+    assert_eq!(button.theme_font_size("no_such_size"), None);
+    assert_eq!(button.theme_icon("no_such_icon"), None);
+    assert_eq!(button.theme_stylebox("no_such_stylebox"), None);
+
+    button.free();
+}
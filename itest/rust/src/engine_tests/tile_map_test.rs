@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashSet;
+
+use godot::builtin::{Vector2i, Vector3i};
+use godot::classes::{GridMap, TileMapLayer};
+use godot::obj::NewAlloc;
+use godot::tools::{GridCell, GridMapExt, TileCell, TileMapLayerExt};
+
+use crate::framework::itest;
+
+#[itest]
+fn tile_map_layer_set_cells_bulk() {
+    let mut layer = TileMapLayer::new_alloc();
+    layer.set_cell_ex(Vector2i::new(-3, 7)).source_id(2).done();
+
+    let atlas = Vector2i::new(4, 1);
+    let cells = (0..10).map(|x| TileCell::new(Vector2i::new(x, -x), 1, atlas));
+    layer.set_cells_bulk(cells);
+
+    assert_eq!(layer.get_used_cells().len(), 11);
+    assert_eq!(layer.get_cell_source_id(Vector2i::new(5, -5)), 1);
+    assert_eq!(layer.get_cell_atlas_coords(Vector2i::new(5, -5)), atlas);
+
+    // Existing cells are kept.
+    assert_eq!(layer.get_cell_source_id(Vector2i::new(-3, 7)), 2);
+
+    // Erase, overwrite, and place cells outside the 16-bit coordinate range.
+    let far = Vector2i::new(100_000, 0);
+    layer.set_cells_bulk([
+        TileCell::empty(Vector2i::new(0, 0)),
+        TileCell {
+            alternative_tile: 3,
+            ..TileCell::new(Vector2i::new(1, -1), 6, atlas)
+        },
+        TileCell::new(far, 1, atlas),
+    ]);
+
+    assert_eq!(layer.cell(Vector2i::new(0, 0)), None);
+    assert_eq!(
+        layer.cell(Vector2i::new(1, -1)),
+        Some(TileCell {
+            coords: Vector2i::new(1, -1),
+            source_id: 6,
+            atlas_coords: atlas,
+            alternative_tile: 3,
+        })
+    );
+    assert_eq!(layer.cell(far).map(|cell| cell.source_id), Some(1));
+
+    layer.free();
+}
+
+#[itest]
+fn tile_map_layer_set_cells_bulk_large_coords() {
+    let mut layer = TileMapLayer::new_alloc();
+    let atlas = Vector2i::new(2, 3);
+
+    let far = [
+        Vector2i::new(40_000, -40_000),
+        Vector2i::new(-70_000, 123),
+        Vector2i::new(32_768, 32_767),
+    ];
+    layer.set_cells_bulk(far.map(|coords| TileCell::new(coords, 1, atlas)));
+
+    // Second bulk call on a layer that already holds out-of-range cells must not move or truncate them.
+    layer.set_cells_bulk([
+        TileCell::new(Vector2i::new(1, 1), 2, atlas),
+        TileCell::empty(far[2]),
+    ]);
+
+    assert_eq!(layer.get_used_cells().len(), 3);
+    assert_eq!(layer.cell(far[0]).map(|cell| cell.source_id), Some(1));
+    assert_eq!(
+        layer.cell(far[1]).map(|cell| cell.atlas_coords),
+        Some(atlas)
+    );
+    assert_eq!(layer.cell(far[2]), None);
+    assert_eq!(layer.get_cell_source_id(Vector2i::new(1, 1)), 2);
+
+    // Truncated coordinates (40_000 as i16 == -25_536) must not appear.
+    assert_eq!(layer.cell(Vector2i::new(-25_536, 25_536)), None);
+
+    let coords: HashSet<Vector2i> = layer.cells().iter().map(|cell| cell.coords).collect();
+    assert_eq!(coords, HashSet::from([far[0], far[1], Vector2i::new(1, 1)]));
+
+    layer.free();
+}
+
+#[itest]
+fn tile_map_layer_cells() {
+    let mut layer = TileMapLayer::new_alloc();
+    assert!(layer.cells().is_empty());
+
+    let expected = [
+        TileCell::new(Vector2i::new(0, 0), 0, Vector2i::new(1, 2)),
+        TileCell::new(Vector2i::new(-4, 9), 3, Vector2i::new(0, 0)),
+    ];
+    for cell in expected {
+        layer
+            .set_cell_ex(cell.coords)
+            .source_id(cell.source_id)
+            .atlas_coords(cell.atlas_coords)
+            .done();
+    }
+
+    let actual: HashSet<TileCell> = layer.cells().into_iter().collect();
+    assert_eq!(actual, HashSet::from(expected));
+
+    layer.free();
+}
+
+#[itest]
+fn grid_map_set_cells_bulk() {
+    let mut grid = GridMap::new_alloc();
+
+    let cells = (0..5).map(|i| GridCell {
+        coords: Vector3i::new(i, 0, -i),
+        item: i,
+        orientation: 0,
+    });
+    grid.set_cells_bulk(cells);
+    assert_eq!(grid.cells().len(), 5);
+    assert_eq!(grid.get_cell_item(Vector3i::new(3, 0, -3)), 3);
+
+    grid.set_cells_bulk([GridCell {
+        coords: Vector3i::new(3, 0, -3),
+        item: GridMap::INVALID_CELL_ITEM,
+        orientation: 0,
+    }]);
+    assert_eq!(grid.cells().len(), 4);
+
+    grid.free();
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Vector2;
+use godot::classes::tween::{EaseType, TransitionType};
+use godot::classes::{Node2D, Tween};
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::TweenExt;
+
+use crate::framework::{itest, TestContext};
+
+fn create_tween(ctx: &TestContext) -> Gd<Tween> {
+    let mut tween = ctx.scene_tree.get_tree().unwrap().create_tween().unwrap();
+
+    // Do not let the tween run during other tests.
+    tween.stop();
+    tween
+}
+
+#[itest]
+fn tween_property_typed(ctx: &TestContext) {
+    let node = Node2D::new_alloc();
+    let mut tween = create_tween(ctx);
+
+    let tweener = tween
+        .tween_property_typed(&node, "position", Vector2::new(10.0, 20.0), 1.0)
+        .from(Vector2::new(-5.0, 0.0))
+        .trans(TransitionType::QUAD)
+        .ease(EaseType::IN_OUT)
+        .delay(0.25)
+        .into_inner();
+
+    assert!(tweener.is_instance_valid());
+
+    // Sub-property paths are supported.
+    tween.tween_property_typed(&node, "position:x", 3.0f32, 0.5);
+
+    tween.kill();
+    node.free();
+}
+
+#[cfg(debug_assertions)]
+#[itest]
+fn tween_property_typed_validation(ctx: &TestContext) {
+    use crate::framework::expect_panic;
+
+    let node = Node2D::new_alloc();
+    let mut tween = create_tween(ctx);
+
+    expect_panic("unknown property", || {
+        tween.tween_property_typed(&node, "no_such_property", 1.0, 1.0);
+    });
+
+    expect_panic("type mismatch", || {
+        tween.tween_property_typed(&node, "position", 1.0, 1.0);
+    });
+
+    tween.kill();
+    node.free();
+}
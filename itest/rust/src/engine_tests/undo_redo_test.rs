@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use godot::builtin::Vector2;
+use godot::classes::undo_redo::MergeMode;
+use godot::classes::{Node2D, UndoRedo};
+use godot::obj::NewAlloc;
+use godot::tools::UndoRedoExt;
+
+use crate::framework::itest;
+
+#[itest]
+fn undo_redo_closures() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let counter = Rc::new(Cell::new(0));
+
+    let (c_do, c_undo) = (counter.clone(), counter.clone());
+    undo_redo
+        .build_action("Increment")
+        .do_fn(move || c_do.set(c_do.get() + 1))
+        .undo_fn(move || c_undo.set(c_undo.get() - 1))
+        .commit();
+
+    assert_eq!(counter.get(), 1);
+
+    assert!(undo_redo.undo());
+    assert_eq!(counter.get(), 0);
+
+    assert!(undo_redo.redo());
+    assert_eq!(counter.get(), 1);
+
+    undo_redo.free();
+}
+
+#[itest]
+fn undo_redo_properties() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let node = Node2D::new_alloc();
+
+    undo_redo
+        .build_action("Move")
+        .do_property(&node, "position", Vector2::new(5.0, 0.0))
+        .undo_property(&node, "position", Vector2::ZERO)
+        .commit();
+    assert_eq!(node.get_position(), Vector2::new(5.0, 0.0));
+
+    undo_redo.undo();
+    assert_eq!(node.get_position(), Vector2::ZERO);
+
+    node.free();
+    undo_redo.free();
+}
+
+#[itest]
+fn undo_redo_merge_and_no_execute() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let counter = Rc::new(Cell::new(0));
+
+    for _ in 0..3 {
+        let c = counter.clone();
+        undo_redo
+            .build_action("Merged")
+            .merge_mode(MergeMode::ENDS)
+            .do_fn(move || c.set(c.get() + 1))
+            .commit_without_execute();
+    }
+
+    assert_eq!(counter.get(), 0);
+    assert_eq!(undo_redo.get_history_count(), 1);
+
+    undo_redo.free();
+}
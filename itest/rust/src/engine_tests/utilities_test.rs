@@ -8,12 +8,12 @@
 // TODO remove once instance_from_id() etc are removed.
 #![allow(deprecated)]
 
-use crate::framework::itest;
+use crate::framework::{expect_panic, expect_panic_message, itest};
 
-use godot::builtin::{GString, Variant};
-use godot::classes::Node3D;
+use godot::builtin::{GString, StringName, Variant};
+use godot::classes::{Node3D, RefCounted};
 use godot::global::*;
-use godot::obj::NewAlloc;
+use godot::obj::{NewAlloc, NewGd};
 
 #[itest]
 fn utilities_abs() {
@@ -91,3 +91,57 @@ fn utilities_is_instance_valid() {
     node.free();
     assert!(!is_instance_valid(variant));
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Assertion macros
+
+#[itest]
+fn assert_gd_eq_godot_semantics() {
+    assert_gd_eq!(3, 3.0);
+    assert_gd_eq!(StringName::from("name"), GString::from("name"));
+    assert_gd_eq!(Variant::nil(), Variant::nil(), "with {}", "message");
+
+    let message = expect_panic_message("assert_gd_eq!", || assert_gd_eq!(1, "one"));
+    assert!(message.contains("assertion `1 == \"one\"` failed"));
+    assert!(message.contains("left: 1 (INT)"));
+    assert!(message.contains("right: one (STRING)"));
+
+    let message = expect_panic_message("assert_gd_eq! with message", || {
+        assert_gd_eq!(1.5, 2, "value {}", 7)
+    });
+    assert!(message.contains("failed (Godot equality): value 7"));
+}
+
+#[itest]
+fn assert_freed_checks_validity() {
+    let node = Node3D::new_alloc();
+    let copy = node.clone();
+
+    let message = expect_panic_message("assert_freed!", || assert_freed!(copy));
+    assert!(message.contains("of class `Node3D` is still alive"));
+
+    node.free();
+    assert_freed!(copy, "freed node");
+
+    // A remaining reference keeps RefCounted objects alive.
+    let obj = RefCounted::new_gd();
+    let other = obj.clone();
+    drop(obj);
+    expect_panic("object still referenced", || assert_freed!(other));
+}
+
+#[itest]
+fn godot_assert_debug_only() {
+    godot_assert!(1 + 1 == 2);
+
+    #[cfg(debug_assertions)]
+    {
+        let message = expect_panic_message("godot_assert!", || {
+            godot_assert!(1 > 2, "math is {}", "broken")
+        });
+        assert_eq!(message, "Assertion failed: `1 > 2`: math is broken");
+    }
+
+    #[cfg(not(debug_assertions))]
+    godot_assert!(1 > 2);
+}
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+use godot::tools::{str_to_var, var_to_str};
+
+use crate::framework::itest;
+
+#[itest]
+fn variant_text_roundtrip() {
+    let value = dict! {
+        "pos": Vector2::new(1.0, 2.5),
+        "name": StringName::from("enemy"),
+        "items": array![1, 2, 3],
+    }
+    .to_variant();
+
+    let text = var_to_str(&value);
+    assert!(text.to_string().contains("Vector2(1, 2.5)"));
+    assert_eq!(str_to_var(&text), Some(value));
+}
+
+#[itest]
+fn variant_text_null_and_invalid() {
+    assert_eq!(str_to_var("null"), Some(Variant::nil()));
+    assert_eq!(str_to_var(" 12 "), Some(12.to_variant()));
+
+    // Godot prints a parse error here.
+    assert_eq!(str_to_var("Vector2(1,"), None);
+}
+
+#[cfg(feature = "variant-parser")]
+#[itest]
+fn variant_parser_matches_engine() {
+    use godot::tools::variant_parser::{parse, TextValue};
+
+    let value = Vector3::new(1.0, -2.0, 0.5).to_variant();
+    let parsed = parse(&var_to_str(&value).to_string()).expect("valid engine output");
+    assert!(
+        matches!(&parsed, TextValue::Construct { name, args } if name == "Vector3" && args.len() == 3)
+    );
+
+    // Formatted output is accepted by the engine.
+    assert_eq!(str_to_var(parsed.to_string().as_str()), Some(value));
+}
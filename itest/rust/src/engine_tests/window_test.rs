@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, Vector2i};
+use godot::classes::sub_viewport::UpdateMode;
+use godot::classes::window::{Flags, WindowInitialPosition};
+use godot::classes::Node;
+use godot::obj::NewAlloc;
+#[cfg(since_api = "4.2")]
+use godot::tools::capture_viewport;
+use godot::tools::{spawn_offscreen_viewport, spawn_window, WindowConfig};
+
+use crate::framework::itest;
+
+#[itest]
+fn spawn_window_applies_config() {
+    let parent = Node::new_alloc();
+    let config = WindowConfig {
+        title: "Preview".into(),
+        size: Vector2i::new(320, 200),
+        position: Some(Vector2i::new(10, 20)),
+        resizable: false,
+        transient: true,
+        own_world_3d: true,
+        ..Default::default()
+    };
+
+    let window = spawn_window(&parent, &config);
+
+    assert_eq!(window.get_parent(), Some(parent.clone()));
+    assert_eq!(window.get_title(), GString::from("Preview"));
+    assert_eq!(window.get_size(), Vector2i::new(320, 200));
+    assert_eq!(window.get_position(), Vector2i::new(10, 20));
+    assert_eq!(
+        window.get_initial_position(),
+        WindowInitialPosition::ABSOLUTE
+    );
+    assert!(window.get_flag(Flags::RESIZE_DISABLED));
+    assert!(!window.get_flag(Flags::BORDERLESS));
+    assert!(window.is_transient());
+    assert!(window.is_using_own_world_3d());
+    assert!(window.is_connected("close_requested", &window.callable("queue_free")));
+
+    parent.free();
+}
+
+#[itest]
+fn spawn_offscreen_viewport_is_on_demand() {
+    let parent = Node::new_alloc();
+
+    let viewport = spawn_offscreen_viewport(&parent, Vector2i::new(64, 32));
+
+    assert_eq!(viewport.get_parent(), Some(parent.clone()));
+    assert_eq!(viewport.get_size(), Vector2i::new(64, 32));
+    assert_eq!(viewport.get_update_mode(), UpdateMode::DISABLED);
+    assert!(viewport.has_transparent_background());
+
+    parent.free();
+}
+
+// Successful captures need a frame to be drawn, which does not happen in headless mode.
+#[cfg(since_api = "4.2")]
+#[itest]
+async fn capture_viewport_outside_tree() {
+    let parent = Node::new_alloc();
+    let viewport = spawn_offscreen_viewport(&parent, Vector2i::new(16, 16));
+
+    let image = capture_viewport(&viewport).await;
+
+    assert!(image.is_none());
+    assert_eq!(viewport.get_update_mode(), UpdateMode::DISABLED);
+
+    parent.free();
+}
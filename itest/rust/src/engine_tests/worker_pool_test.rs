@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use godot::tools::{spawn_group_task, spawn_task, TaskBuilder};
+
+use crate::framework::itest;
+
+#[itest]
+fn worker_task_join() {
+    let handle = spawn_task(|| (1..=100u32).map(|i| i * 2).sum::<u32>());
+
+    assert_eq!(handle.join(), 10_100);
+}
+
+#[itest]
+fn worker_task_options() {
+    let handle = TaskBuilder::new()
+        .high_priority(true)
+        .description("itest task")
+        .spawn(|| String::from("done"));
+
+    assert_eq!(handle.join(), "done");
+}
+
+#[itest]
+fn worker_task_panic_resumed() {
+    let handle = spawn_task(|| -> u32 { panic!("task failed") });
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| handle.join()));
+    let payload = result.expect_err("panic is propagated");
+
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"task failed"));
+}
+
+#[itest]
+fn worker_group_task_join() {
+    let total = Arc::new(AtomicU64::new(0));
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let task_total = total.clone();
+    let task_calls = calls.clone();
+    let handle = spawn_group_task(50, move |index| {
+        task_total.fetch_add(index as u64, Ordering::Relaxed);
+        task_calls.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let group_id = handle.group_id();
+    handle.join();
+
+    assert!(group_id >= 0);
+    assert_eq!(calls.load(Ordering::Relaxed), 50);
+    assert_eq!(total.load(Ordering::Relaxed), (0..50).sum::<u64>());
+}
+
+#[itest]
+fn worker_group_task_limited() {
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let task_calls = calls.clone();
+    let handle = TaskBuilder::new()
+        .tasks_needed(2)
+        .spawn_group(10, move |_| {
+            task_calls.fetch_add(1, Ordering::Relaxed);
+        });
+
+    handle.join();
+    assert_eq!(calls.load(Ordering::Relaxed), 10);
+}
+
+#[itest]
+fn worker_group_task_many_elements() {
+    // Enough elements that all workers invoke the closure concurrently.
+    const COUNT: u32 = 10_000;
+
+    let seen = Arc::new((0..COUNT).map(|_| AtomicU32::new(0)).collect::<Vec<_>>());
+
+    let task_seen = seen.clone();
+    spawn_group_task(COUNT, move |index| {
+        task_seen[index as usize].fetch_add(1, Ordering::Relaxed);
+    })
+    .join();
+
+    let missed_or_repeated = seen
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| count.load(Ordering::Relaxed) != 1)
+        .count();
+
+    assert_eq!(
+        missed_or_repeated, 0,
+        "each index is processed exactly once"
+    );
+}
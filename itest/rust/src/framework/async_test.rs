@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Support for `#[itest] async fn` tests.
+
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use godot::builtin::Signal;
+use godot::classes::SceneTree;
+use godot::global::godot_error;
+use godot::obj::Gd;
+use godot::tools::{FromSignalArgs, SignalFuture};
+
+use crate::framework::AsyncTestFuture;
+
+/// Resolves after the main loop has processed `count` more frames.
+pub async fn wait_frames(count: usize) {
+    let tree = scene_tree();
+    for _ in 0..count {
+        tree.await_process_frame().await;
+    }
+}
+
+/// Resolves on the next emission of `signal`, with its arguments.
+///
+/// Connects immediately, so emissions between this call and the first `.await` are not missed. Use `()` as `R` for signals without
+/// parameters.
+pub fn wait_signal<R: FromSignalArgs>(signal: &Signal) -> SignalFuture<R> {
+    signal.to_future()
+}
+
+fn scene_tree() -> Gd<SceneTree> {
+    godot::tools::main_loop::<SceneTree>().expect("async itests require a SceneTree main loop")
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Runner support
+
+/// Polls an async test until it completes, panics or exceeds its timeout. Resolves to `true` on success.
+pub(crate) struct AsyncTestRun {
+    name: &'static str,
+    test: AsyncTestFuture,
+    timeout: Duration,
+    deadline: Instant,
+
+    /// Wakes the run every frame, so the deadline is checked even if the test waits for something that never happens.
+    next_frame: SignalFuture<()>,
+}
+
+impl AsyncTestRun {
+    pub fn new(name: &'static str, test: AsyncTestFuture, timeout: Duration) -> Self {
+        Self {
+            name,
+            test,
+            timeout,
+            deadline: Instant::now() + timeout,
+            next_frame: scene_tree().await_process_frame(),
+        }
+    }
+}
+
+impl Future for AsyncTestRun {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        let this = self.get_mut();
+        let name = this.name;
+
+        let err_context = || format!("itest `{name}` failed");
+        let test = &mut this.test;
+        let result =
+            godot::private::handle_panic(err_context, AssertUnwindSafe(|| test.as_mut().poll(cx)));
+
+        match result {
+            Ok(Poll::Ready(())) => return Poll::Ready(true),
+            Ok(Poll::Pending) => {}
+            Err(_) => return Poll::Ready(false),
+        }
+
+        if Instant::now() >= this.deadline {
+            godot_error!("itest `{name}` timed out after {:?}", this.timeout);
+            return Poll::Ready(false);
+        }
+
+        while Pin::new(&mut this.next_frame).poll(cx).is_ready() {
+            this.next_frame = scene_tree().await_process_frame();
+        }
+
+        Poll::Pending
+    }
+}
@@ -5,14 +5,21 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::classes::{Engine, Node, Os};
+use godot::builtin::{GString, PackedStringArray, VariantArray};
+use godot::classes::{Engine, Node, Os, ProjectSettings};
 use godot::obj::Gd;
 use godot::sys;
 use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
 
+#[cfg(since_api = "4.2")]
+mod async_test;
 mod bencher;
 mod runner;
 
+#[cfg(since_api = "4.2")]
+pub use async_test::{wait_frames, wait_signal};
 pub use bencher::*;
 pub use runner::*;
 
@@ -72,6 +79,7 @@ fn collect_rust_benchmarks() -> (Vec<RustBenchmark>, usize) {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Shared types
 
+#[derive(Clone)]
 pub struct TestContext {
     pub scene_tree: Gd<Node>,
     pub property_tests: Gd<Node>,
@@ -103,11 +111,33 @@ pub struct RustTestCase {
     pub skipped: bool,
     /// If one or more tests are focused, only they will be executed. Helpful for debugging and working on specific features.
     pub focused: bool,
+    /// Whether the test is exempt from `--leak-check`, e.g. because it deliberately keeps instances alive.
+    pub allow_leaks: bool,
     #[allow(dead_code)]
     pub line: u32,
-    pub function: fn(&TestContext),
+    pub function: TestFunction,
 }
 
+#[derive(Copy, Clone)]
+pub enum TestFunction {
+    Sync(fn(&TestContext)),
+
+    /// `async fn` test, driven by the main loop after all synchronous tests have run.
+    #[allow(dead_code)] // Only constructed if async tests exist (Godot 4.2+).
+    Async {
+        function: fn(&TestContext) -> AsyncTestFuture,
+        timeout_ms: u64,
+    },
+}
+
+impl TestFunction {
+    pub fn is_async(&self) -> bool {
+        matches!(self, Self::Async { .. })
+    }
+}
+
+pub type AsyncTestFuture = Pin<Box<dyn Future<Output = ()>>>;
+
 #[derive(Copy, Clone)]
 pub struct RustBenchmark {
     pub name: &'static str,
@@ -123,6 +153,11 @@ pub fn passes_filter(filters: &[String], test_name: &str) -> bool {
 }
 
 pub fn expect_panic(context: &str, code: impl FnOnce()) {
+    expect_panic_message(context, code);
+}
+
+/// Like [`expect_panic()`], but returns the panic message, to check its content.
+pub fn expect_panic_message(context: &str, code: impl FnOnce()) -> String {
     use std::panic;
 
     // Exchange panic hook, to disable printing during expected panics. Also disable gdext's panic printing.
@@ -138,10 +173,10 @@ pub fn expect_panic(context: &str, code: impl FnOnce()) {
     panic::set_hook(prev_hook);
     godot::private::set_error_print_level(prev_print_level);
 
-    assert!(
-        panic.is_err(),
-        "code should have panicked but did not: {context}",
-    );
+    match panic {
+        Ok(()) => panic!("code should have panicked but did not: {context}"),
+        Err(err) => godot::private::extract_panic_message(err),
+    }
 }
 
 pub fn expect_debug_panic_or_release_ok(_context: &str, code: impl FnOnce()) {
@@ -184,6 +219,54 @@ pub fn suppress_godot_print(mut f: impl FnMut()) {
     Engine::singleton().set_print_error_messages(true);
 }
 
+/// File system path of `file_name` in the test project, which Godot sees as `res://{file_name}`.
+pub fn test_file_path(file_name: &str) -> std::path::PathBuf {
+    std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/")).join(file_name)
+}
+
+/// Deletes `file_name` from the test project, after a test created it.
+pub fn remove_test_file(file_name: &str) {
+    let file_path = test_file_path(file_name);
+    std::fs::remove_file(&file_path)
+        .unwrap_or_else(|_| panic!("couldn't remove test file: {}", file_path.display()));
+}
+
+/// Runs GDScript `source` as main loop (`--script`) of a separate, headless Godot process, and returns the process's output.
+///
+/// The script is written to `script_file` in the test project, so it can use the project's classes. Useful for code that behaves
+/// differently outside the test runner's `SceneTree`. Panics if the process fails.
+pub fn run_godot_script(script_file: &str, source: &str) -> String {
+    std::fs::write(test_file_path(script_file), source).expect("write script");
+
+    let project_path = ProjectSettings::singleton().globalize_path("res://");
+    let args = [
+        "--headless",
+        "--path",
+        &project_path.to_string(),
+        "--script",
+        &format!("res://{script_file}"),
+    ]
+    .map(GString::from);
+
+    let mut os = Os::singleton();
+    let executable = os.get_executable_path();
+    let output = VariantArray::new();
+    let exit_code = os
+        .execute_ex(&executable, &PackedStringArray::from(&args))
+        .output(&output)
+        .done();
+
+    remove_test_file(script_file);
+
+    let output: String = output
+        .iter_shared()
+        .map(|line| line.stringify().to_string())
+        .collect();
+    assert_eq!(exit_code, 0, "Godot process failed; output:\n{output}");
+
+    output
+}
+
 /// Some tests are disabled, as they rely on Godot checks which are only available in Debug builds.
 /// See <https://github.com/godotengine/godot/issues/86264>.
 pub fn runs_release() -> bool {
@@ -7,32 +7,52 @@
 
 use std::time::{Duration, Instant};
 
+#[cfg(since_api = "4.2")]
+use godot::builtin::Signal;
 use godot::builtin::{Array, GString, Variant, VariantArray};
-use godot::classes::{Engine, Node, Os};
+use godot::classes::{Engine, Node, Os, RefCounted};
 use godot::global::godot_error;
 use godot::meta::ToGodot;
-use godot::obj::Gd;
+#[cfg(since_api = "4.2")]
+use godot::obj::WithBaseField;
+use godot::obj::{Base, Gd};
 use godot::register::{godot_api, GodotClass};
 
+#[cfg(since_api = "4.2")]
+use crate::framework::async_test::AsyncTestRun;
 use crate::framework::{
-    bencher, passes_filter, BenchResult, RustBenchmark, RustTestCase, TestContext,
+    bencher, passes_filter, BenchResult, RustBenchmark, RustTestCase, TestContext, TestFunction,
 };
 
-#[derive(GodotClass, Debug)]
-#[class(init)]
+/// Runs the tests in three phases, invoked by `TestRunner.gd`:
+/// 1. `begin_tests()` runs synchronous Rust tests.
+/// 2. `run_async_tests()` runs `async fn` Rust tests; GDScript awaits the returned signal while the main loop advances.
+/// 3. `finish_tests()` runs GDScript tests and prints the summary.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
 pub struct IntegrationTests {
     total: i64,
     passed: i64,
     skipped: i64,
     failed_list: Vec<String>,
     focus_run: bool,
+
+    // State carried between phases.
+    ctx: Option<TestContext>,
+    async_tests: Vec<RustTestCase>,
+    gdscript_tests: VariantArray,
+    allow_focus: bool,
+    leak_check: bool,
+    rust_time: Duration,
+
+    base: Base<RefCounted>,
 }
 
 #[godot_api]
 impl IntegrationTests {
     #[allow(clippy::uninlined_format_args)]
     #[func]
-    fn run_all_tests(
+    fn begin_tests(
         &mut self,
         gdscript_tests: VariantArray,
         gdscript_file_count: i64,
@@ -40,7 +60,8 @@ impl IntegrationTests {
         scene_tree: Gd<Node>,
         filters: VariantArray,
         property_tests: Gd<Node>,
-    ) -> bool {
+        leak_check: bool,
+    ) {
         println!("{}Run{} Godot integration tests...", FMT_CYAN_BOLD, FMT_END);
         let filters: Vec<String> = filters.iter_shared().map(|v| v.to::<String>()).collect();
         let gdscript_tests = gdscript_tests
@@ -71,19 +92,79 @@ impl IntegrationTests {
             );
         }
 
+        self.leak_check = leak_check;
+        if leak_check {
+            if cfg!(debug_assertions) {
+                #[cfg(debug_assertions)]
+                godot::private::set_count_all_instances(true);
+
+                println!("  {FMT_CYAN}Leak check{FMT_END} -- Rust tests fail if they leak instances of Rust classes.");
+            } else {
+                println!(
+                    "{FMT_YELLOW}  Warning: leak check requires a debug build; ignored.{FMT_END}"
+                );
+            }
+        }
+
+        let (async_tests, sync_tests): (Vec<_>, Vec<_>) = rust_tests
+            .into_iter()
+            .partition(|test| test.function.is_async());
+
+        let ctx = TestContext {
+            scene_tree,
+            property_tests,
+        };
+
         let clock = Instant::now();
-        self.run_rust_tests(rust_tests, scene_tree, property_tests.clone());
-        let rust_time = clock.elapsed();
-        property_tests.free();
+        self.run_rust_tests(sync_tests, &ctx);
+        self.rust_time = clock.elapsed();
+
+        self.ctx = Some(ctx);
+        self.async_tests = async_tests;
+        self.gdscript_tests = gdscript_tests;
+        self.allow_focus = allow_focus;
+    }
 
-        let gdscript_time = if !focus_run {
+    /// Returns a signal that is emitted once all async tests have completed.
+    #[cfg(since_api = "4.2")]
+    #[func]
+    fn run_async_tests(&mut self) -> Signal {
+        let tests = std::mem::take(&mut self.async_tests);
+        let ctx = self.ctx.clone().expect("begin_tests() not called");
+        let leak_check = self.leak_check;
+        let mut this = self.to_gd();
+
+        godot::tools::spawn_awaitable(async move {
+            let clock = Instant::now();
+            let mut last_file = None;
+            for test in tests {
+                print_test_pre(test.name, test.file.to_string(), &mut last_file, false);
+                let outcome = run_async_test(&test, &ctx, leak_check).await;
+
+                this.bind_mut().update_stats(&outcome, test.file, test.name);
+                print_test_post(test.name, outcome);
+            }
+
+            this.bind_mut().rust_time += clock.elapsed();
+            true
+        })
+    }
+
+    #[func]
+    fn finish_tests(&mut self) -> bool {
+        let ctx = self.ctx.take().expect("begin_tests() not called");
+        ctx.property_tests.free();
+
+        let gdscript_time = if !self.focus_run {
+            let clock = Instant::now();
+            let gdscript_tests = std::mem::take(&mut self.gdscript_tests);
             let extra_duration = self.run_gdscript_tests(gdscript_tests);
-            Some((clock.elapsed() - rust_time) + extra_duration)
+            Some(clock.elapsed() + extra_duration)
         } else {
             None
         };
 
-        self.conclude_tests(rust_time, gdscript_time, allow_focus)
+        self.conclude_tests(self.rust_time, gdscript_time, self.allow_focus)
     }
 
     #[func]
@@ -126,21 +207,11 @@ impl IntegrationTests {
         }
     }
 
-    fn run_rust_tests(
-        &mut self,
-        tests: Vec<RustTestCase>,
-        scene_tree: Gd<Node>,
-        property_tests: Gd<Node>,
-    ) {
-        let ctx = TestContext {
-            scene_tree,
-            property_tests,
-        };
-
+    fn run_rust_tests(&mut self, tests: Vec<RustTestCase>, ctx: &TestContext) {
         let mut last_file = None;
         for test in tests {
             print_test_pre(test.name, test.file.to_string(), &mut last_file, false);
-            let outcome = run_rust_test(&test, &ctx);
+            let outcome = run_rust_test(&test, ctx, self.leak_check);
 
             self.update_stats(&outcome, test.file, test.name);
             print_test_post(test.name, outcome);
@@ -308,16 +379,101 @@ const FMT_YELLOW: &str = "\x1b[33m";
 const FMT_RED: &str = "\x1b[31m";
 const FMT_END: &str = "\x1b[0m";
 
-fn run_rust_test(test: &RustTestCase, ctx: &TestContext) -> TestOutcome {
+fn run_rust_test(test: &RustTestCase, ctx: &TestContext, leak_check: bool) -> TestOutcome {
     if test.skipped {
         return TestOutcome::Skipped;
     }
 
+    let TestFunction::Sync(function) = test.function else {
+        panic!("async itest `{}` cannot run synchronously", test.name);
+    };
+
+    let leak_check = LeakCheck::begin(test, leak_check);
+
     // Explicit type to prevent tests from returning a value
     let err_context = || format!("itest `{}` failed", test.name);
-    let success: Result<(), _> = godot::private::handle_panic(err_context, || (test.function)(ctx));
+    let success: Result<(), _> = godot::private::handle_panic(err_context, || function(ctx));
 
-    TestOutcome::from_bool(success.is_ok())
+    TestOutcome::from_bool(success.is_ok() && leak_check.finish(test))
+}
+
+#[cfg(since_api = "4.2")]
+async fn run_async_test(test: &RustTestCase, ctx: &TestContext, leak_check: bool) -> TestOutcome {
+    if test.skipped {
+        return TestOutcome::Skipped;
+    }
+
+    let TestFunction::Async {
+        function,
+        timeout_ms,
+    } = test.function
+    else {
+        panic!("sync itest `{}` cannot run asynchronously", test.name);
+    };
+
+    let leak_check = LeakCheck::begin(test, leak_check);
+
+    let timeout = Duration::from_millis(timeout_ms);
+    let success = AsyncTestRun::new(test.name, function(ctx), timeout).await;
+
+    TestOutcome::from_bool(success && leak_check.finish(test))
+}
+
+/// Compares live instances of Rust classes before and after a test, if the run has leak checks enabled.
+///
+/// Only Rust classes are tracked, and only in debug builds. Nodes queued for deletion count as live, since they are freed at the end of
+/// the frame.
+pub struct LeakCheck {
+    #[cfg(debug_assertions)]
+    before: Option<Vec<(godot::meta::ClassName, usize)>>,
+}
+
+impl LeakCheck {
+    pub fn begin(test: &RustTestCase, enabled: bool) -> Self {
+        #[cfg(debug_assertions)]
+        let before = (enabled && !test.allow_leaks).then(godot::private::live_instance_counts);
+
+        #[cfg(not(debug_assertions))]
+        let _ = (test, enabled);
+
+        Self {
+            #[cfg(debug_assertions)]
+            before,
+        }
+    }
+
+    /// Returns `false` and prints the leaked classes, if the test leaked instances.
+    pub fn finish(self, test: &RustTestCase) -> bool {
+        #[cfg(debug_assertions)]
+        if let Some(before) = self.before {
+            let leaks: Vec<String> = godot::private::live_instance_counts()
+                .into_iter()
+                .filter_map(|(class_name, after)| {
+                    let before = before
+                        .iter()
+                        .find(|(name, _)| *name == class_name)
+                        .map_or(0, |(_, count)| *count);
+
+                    (after > before).then(|| format!("{class_name} (+{})", after - before))
+                })
+                .collect();
+
+            if !leaks.is_empty() {
+                godot_error!(
+                    "itest `{}` leaked instances: {}\n  \
+                    Free them before the test ends, or opt out with #[itest(allow_leaks)].",
+                    test.name,
+                    leaks.join(", ")
+                );
+                return false;
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        let _ = (self, test);
+
+        true
+    }
 }
 
 fn print_test_pre(test_case: &str, test_file: String, last_file: &mut Option<String>, flush: bool) {
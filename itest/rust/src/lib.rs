@@ -24,4 +24,14 @@ unsafe impl ExtensionLibrary for framework::IntegrationTests {
         // Testing that we can initialize and use `Object`-derived classes during `Servers` init level. See `object_tests::init_level_test`.
         object_tests::initialize_init_level_test(level);
     }
+
+    #[cfg(feature = "log")]
+    fn logger() -> Option<godot::tools::GodotLogger> {
+        Some(engine_tests::make_logger())
+    }
+
+    #[cfg(feature = "tracing")]
+    fn tracing_layer() -> Option<godot::tools::GodotTracingLayer> {
+        Some(engine_tests::make_tracing_layer())
+    }
 }
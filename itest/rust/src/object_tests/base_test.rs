@@ -47,6 +47,44 @@ fn base_access_unbound_no_field() {
     obj.free();
 }
 
+#[itest]
+fn base_access_from_self_no_field() {
+    let mut obj = Baseless::new_alloc();
+    obj.set_position(Vector2::new(1.0, 2.0));
+
+    {
+        let guard = obj.bind();
+        assert_eq!(guard.base().get_position(), Vector2::new(1.0, 2.0));
+        assert_eq!(guard.to_gd(), obj);
+    }
+
+    obj.bind_mut()
+        .base_mut()
+        .set_position(Vector2::new(3.0, 4.0));
+    assert_eq!(obj.get_position(), Vector2::new(3.0, 4.0));
+
+    obj.free();
+}
+
+#[itest]
+fn base_access_from_self_no_field_refcounted() {
+    let obj = BaselessRefCounted::new_gd();
+    let gd = obj.bind().to_gd();
+
+    assert_eq!(gd, obj);
+    assert_eq!(gd.get_reference_count(), 2);
+}
+
+#[itest]
+fn base_access_from_self_no_field_unregistered() {
+    // Not constructed through Godot, so there is no object.
+    let detached = Baseless {};
+
+    expect_panic("base() on instance without object", || {
+        let _ = detached.base();
+    });
+}
+
 #[itest]
 fn base_display() {
     let obj = Based::new_alloc();
@@ -214,6 +252,10 @@ impl Based {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct BaselessRefCounted {}
+
 #[derive(GodotClass)]
 #[class(init, base=Node2D)]
 struct Baseless {
@@ -6,9 +6,9 @@
  */
 use crate::framework::itest;
 use godot::builtin::{GString, StringName};
-use godot::meta::ClassName;
+use godot::meta::{ClassName, StaticClassName, StaticStringName};
 use godot::obj::bounds::implement_godot_bounds;
-use godot::obj::GodotClass;
+use godot::obj::{GodotClass, NewGd};
 use godot::sys;
 use std::borrow::Cow;
 
@@ -66,6 +66,76 @@ fn class_name_dynamic_unicode() {
     );
 }
 
+const STATIC_NODE: ClassName = ClassName::new_static("Node");
+
+#[itest]
+fn class_name_static() {
+    let a = STATIC_NODE;
+    let b = ClassName::new_static("Node");
+
+    assert_eq!(a, b);
+    assert_eq!(sys::hash_value(&a), sys::hash_value(&b));
+
+    assert_eq!(a.to_string(), "Node");
+    assert_eq!(a.to_string_name(), StringName::from("Node"));
+    assert_eq!(a.to_cow_str(), Cow::<'static, str>::Borrowed("Node"));
+    assert!(!a.is_none());
+    assert!(ClassName::new_static("").is_none());
+}
+
+#[itest]
+fn class_name_static_matches_generated() {
+    let node = godot::classes::Node::class_name();
+
+    assert_eq!(STATIC_NODE, node);
+    assert_eq!(sys::hash_value(&STATIC_NODE), sys::hash_value(&node));
+    assert_ne!(STATIC_NODE, godot::classes::Object::class_name());
+    assert_ne!(STATIC_NODE, A::class_name());
+}
+
+static STATIC_NODE_HANDLE: StaticClassName = StaticClassName::new("Node");
+const STATIC_METHOD: StaticStringName = StaticStringName::new("get_class");
+
+#[itest]
+fn class_name_static_handle() {
+    let node = godot::classes::Node::class_name();
+
+    assert_eq!(STATIC_NODE_HANDLE.get(), node);
+    assert_eq!(STATIC_NODE_HANDLE.get(), STATIC_NODE);
+    assert_eq!(
+        sys::hash_value(&STATIC_NODE_HANDLE.get()),
+        sys::hash_value(&node)
+    );
+}
+
+#[itest]
+fn string_name_static_handle() {
+    assert_eq!(STATIC_METHOD.get(), StringName::from("get_class"));
+    assert_eq!(STATIC_METHOD.as_str(), "get_class");
+
+    let mut object = godot::classes::RefCounted::new_gd();
+    let class = object.call(&STATIC_METHOD, &[]);
+    assert_eq!(class.to::<GString>(), GString::from("RefCounted"));
+}
+
+// Each thread has its own cache, which releases its names when the thread exits.
+#[cfg(feature = "experimental-threads")]
+#[itest]
+fn string_name_static_handle_other_thread() {
+    let main_name = STATIC_METHOD.get();
+
+    let equal = std::thread::spawn(|| {
+        let first = STATIC_METHOD.get();
+        let second = STATIC_METHOD.get();
+        first == second && first == StringName::from("get_class")
+    })
+    .join()
+    .expect("thread panicked");
+
+    assert!(equal);
+    assert_eq!(STATIC_METHOD.get(), main_name);
+}
+
 // Test Unicode proc-macro support for ClassName.
 #[cfg(since_api = "4.4")]
 #[derive(godot::register::GodotClass)]
@@ -5,11 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{Variant, Vector3};
-use godot::classes::{Node, Node3D, Object};
+use godot::builtin::{GString, Variant, Vector3};
+use godot::classes::{GDScript, Node, Node3D, Object};
 use godot::meta::error::CallError;
 use godot::meta::{FromGodot, ToGodot};
-use godot::obj::{InstanceId, NewAlloc};
+use godot::obj::{InstanceId, NewAlloc, NewGd};
 use std::error::Error;
 
 use crate::framework::{expect_panic, itest, runs_release};
@@ -289,3 +289,119 @@ fn dynamic_call_return_mismatch() {
     // The parser will fail since it knows the signature of take_1_int(). And if we enforce `: Variant` type hints, it will just
     // cause a runtime error, but that's entirely handled in GDScript.
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed dynamic calls via Gd::dyn_call()
+
+#[itest]
+fn dyn_call_through_base_pointer() {
+    let mut obj = ObjPayload::new_alloc().upcast::<Object>();
+
+    let value: i64 = obj
+        .dyn_call("take_1_int", &[42.to_variant()])
+        .expect("dyn_call() on derived class");
+    assert_eq!(value, 42);
+
+    obj.free();
+}
+
+#[itest]
+fn dyn_call_method_not_found() {
+    let mut node = Node3D::new_alloc().upcast::<Node>();
+
+    let call_error = node
+        .dyn_call::<i64>("take_1_int", &[])
+        .expect_err("expected missing method");
+
+    // Refers to the runtime class, not the static type Node.
+    assert_eq!(call_error.class_name(), Some("Node3D"));
+    assert_eq!(call_error.method_name(), "take_1_int");
+    assert_eq!(
+        call_error.to_string(),
+        "godot-rust function call failed: Node3D::take_1_int()\
+        \n    Reason: method not found"
+    );
+
+    node.free();
+}
+
+#[itest]
+fn dyn_call_return_mismatch() {
+    let mut obj = ObjPayload::new_alloc().upcast::<Object>();
+
+    let call_error = obj
+        .dyn_call::<GString>("take_1_int", &[7.to_variant()])
+        .expect_err("expected failed return conversion");
+
+    assert_eq!(call_error.class_name(), Some("ObjPayload"));
+    assert!(
+        call_error.to_string().contains("return value"),
+        "{call_error}"
+    );
+
+    obj.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Capability checks with cached names
+
+#[itest]
+fn has_method_signal_property_fast() {
+    let obj = ObjPayload::new_alloc().upcast::<Object>();
+
+    assert!(obj.has_method_fast("take_1_int"));
+    assert!(obj.has_method_fast("get_instance_id"));
+    assert!(!obj.has_method_fast("take_2_ints"));
+
+    assert!(obj.has_signal_fast("do_use"));
+    assert!(obj.has_signal_fast("script_changed"));
+    assert!(!obj.has_signal_fast("take_1_int"));
+
+    assert!(obj.has_property_fast("script"));
+    assert!(!obj.has_property_fast("take_1_int"));
+
+    // Same results on repeated calls, which hit the cache.
+    assert!(obj.has_method_fast("take_1_int"));
+    assert!(!obj.has_property_fast("take_1_int"));
+
+    obj.free();
+}
+
+#[itest]
+fn has_property_fast_inherited_and_script() {
+    let mut node = Node3D::new_alloc();
+
+    // Engine properties, including inherited ones, are found through ClassDB.
+    assert!(node.has_property_fast("position"));
+    assert!(node.has_property_fast("name"));
+    assert!(!node.has_property_fast("script_only_var"));
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code("extends Node3D\nvar script_only_var := 3\n");
+    script.reload();
+    node.set_script(&script.to_variant());
+
+    // Script variables are not known to ClassDB; found via the property list.
+    assert!(node.has_property_fast("script_only_var"));
+    assert!(node.has_property_fast("position"));
+    assert!(!node.has_property_fast("nonexistent_var"));
+
+    node.free();
+}
+
+#[itest]
+fn try_call_if_exists() {
+    let mut obj = ObjPayload::new_alloc().upcast::<Object>();
+
+    let result = obj.try_call_if_exists("take_1_int", &[5.to_variant()]);
+    assert_eq!(result.ok(), Some(Some(5.to_variant())));
+
+    let result = obj.try_call_if_exists("does_not_exist", &[]);
+    assert_eq!(result.ok(), Some(None));
+
+    // Method exists, but arguments don't match.
+    let result = obj.try_call_if_exists("take_1_int", &[]);
+    assert!(result.is_err());
+
+    obj.free();
+}
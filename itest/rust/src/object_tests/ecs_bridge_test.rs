@@ -0,0 +1,136 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "ecs-bridge")]
+
+use godot::prelude::*;
+use godot::register::InstanceEvent;
+use godot::tools::ecs::{ChangeBatch, EntityMap, LifecycleQueue};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node2D)]
+struct EcsMirroredNode {}
+
+#[itest]
+fn ecs_entity_map_lifecycle() {
+    let mut queue = LifecycleQueue::new();
+    queue.observe::<EcsMirroredNode>();
+
+    let mut map = EntityMap::<u32>::new();
+    let a = EcsMirroredNode::new_alloc();
+    let b = EcsMirroredNode::new_alloc();
+    map.insert_gd(&a, 1);
+    map.insert_gd(&b, 2);
+
+    assert_eq!(map.entity(a.instance_id()), Some(1));
+    assert_eq!(map.instance_id(2), Some(b.instance_id()));
+
+    // Remapping an entity drops its previous instance.
+    map.insert_gd(&a, 2);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.entity(b.instance_id()), None);
+
+    a.free();
+    b.free();
+
+    let despawned = map.apply_lifecycle(queue.drain());
+    assert_eq!(despawned, vec![2]);
+    assert!(map.is_empty());
+    assert!(queue.drain().is_empty());
+
+    queue.stop();
+}
+
+#[itest]
+fn ecs_change_batch_apply() {
+    let mut map = EntityMap::new();
+    let node_2d = Node2D::new_alloc();
+    let node_3d = Node3D::new_alloc();
+    let freed = Node2D::new_alloc();
+    map.insert_gd(&node_2d, "a");
+    map.insert_gd(&node_3d, "b");
+    map.insert_gd(&freed, "c");
+    freed.free();
+
+    let mut batch = ChangeBatch::new();
+    batch.set_position_2d("a", Vector2::new(1.0, 2.0));
+    batch.set_property("a", "rotation", 0.5.to_variant());
+    batch.set_position_3d("b", Vector3::new(1.0, 2.0, 3.0));
+    batch.set_transform_2d("b", Transform2D::IDENTITY);
+    batch.set_position_2d("c", Vector2::ZERO);
+    batch.set_position_2d("unmapped", Vector2::ZERO);
+
+    let report = batch.apply(&map);
+    assert!(batch.is_empty());
+    assert_eq!(report.applied, 3);
+    assert_eq!(report.missing, vec!["c", "unmapped"]);
+    assert_eq!(report.mismatched, vec!["b"]);
+
+    assert_eq!(node_2d.get_position(), Vector2::new(1.0, 2.0));
+    assert_eq!(node_2d.get_rotation(), 0.5);
+    assert_eq!(node_3d.get_position(), Vector3::new(1.0, 2.0, 3.0));
+
+    // Dead objects are detected without lifecycle events, too.
+    assert_eq!(map.retain_alive(), vec!["c"]);
+
+    node_2d.free();
+    node_3d.free();
+}
+
+#[itest]
+fn ecs_change_batch_bulk_setters() {
+    let mut map = EntityMap::new();
+    let nodes: Vec<Gd<Node2D>> = (0..3).map(|_| Node2D::new_alloc()).collect();
+    for (entity, node) in nodes.iter().enumerate() {
+        map.insert_gd(node, entity);
+    }
+
+    let mut batch = ChangeBatch::new();
+    batch.set_positions_2d((0..3).map(|entity| (entity, Vector2::new(entity as f32, 0.0))));
+    batch.set_properties("rotation", [(0, 0.25.to_variant()), (2, 0.5.to_variant())]);
+    batch.set_transforms_3d([(1, Transform3D::IDENTITY)]);
+    assert_eq!(batch.len(), 6);
+
+    let report = batch.apply(&map);
+    assert_eq!(report.applied, 5);
+    assert!(report.missing.is_empty());
+    assert_eq!(report.mismatched, vec![1]);
+
+    assert_eq!(nodes[2].get_position(), Vector2::new(2.0, 0.0));
+    assert_eq!(nodes[0].get_rotation(), 0.25);
+    assert_eq!(nodes[1].get_rotation(), 0.0);
+
+    for node in nodes {
+        node.free();
+    }
+}
+
+#[itest]
+fn ecs_lifecycle_queue_drop_unregisters() {
+    let mut kept = LifecycleQueue::new();
+    kept.observe::<EcsMirroredNode>();
+
+    {
+        let mut dropped = LifecycleQueue::new();
+        dropped.observe::<EcsMirroredNode>();
+    }
+
+    // The remaining queue still receives events after the other one's observers are removed.
+    let node = EcsMirroredNode::new_alloc();
+    let instance_id = node.instance_id();
+    node.free();
+
+    assert_eq!(
+        kept.drain(),
+        vec![
+            (InstanceEvent::Created, instance_id),
+            (InstanceEvent::Destroyed, instance_id),
+        ]
+    );
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, NodePath, StringName, Vector3};
+use godot::classes::Node3D;
+use godot::obj::Gd;
+use godot::register::GodotClass;
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(GodotClass)]
+#[class(init)]
+struct BuiltObject {
+    #[var]
+    label: GString,
+
+    #[var]
+    speed: f64,
+
+    #[var]
+    target: NodePath,
+
+    counter: i32,
+}
+
+#[itest]
+fn gd_builder_engine_class() {
+    let node: Gd<Node3D> = Gd::builder()
+        .with_property("name", "Built")
+        .with_property("position", Vector3::new(1.0, 2.0, 3.0))
+        .with_property("visible", false)
+        .build();
+
+    assert_eq!(node.get_name(), "Built".into());
+    assert_eq!(node.get_position(), Vector3::new(1.0, 2.0, 3.0));
+    assert!(!node.is_visible());
+
+    node.free();
+}
+
+#[itest]
+fn gd_builder_user_class() {
+    let obj: Gd<BuiltObject> = Gd::builder()
+        .with_property("label", "fast")
+        .with_property("speed", 7) // int accepted for float.
+        .with(|obj| obj.bind_mut().counter = 3)
+        .build();
+
+    let obj = obj.bind();
+    assert_eq!(obj.label, GString::from("fast"));
+    assert_eq!(obj.speed, 7.0);
+    assert_eq!(obj.counter, 3);
+}
+
+#[itest]
+fn gd_builder_string_conversions() {
+    // String types convert into each other, like in Object::set().
+    let obj: Gd<BuiltObject> = Gd::builder()
+        .with_property("label", StringName::from("from_name"))
+        .with_property("target", "../Sibling")
+        .build();
+
+    let obj = obj.bind();
+    assert_eq!(obj.label, GString::from("from_name"));
+    assert_eq!(obj.target, NodePath::from("../Sibling"));
+}
+
+#[itest]
+fn gd_new_with() {
+    let node = Gd::<Node3D>::new_with(|node| {
+        node.set_name("Closure");
+        node.set_position(Vector3::UP);
+    });
+
+    assert_eq!(node.get_name(), "Closure".into());
+    assert_eq!(node.get_position(), Vector3::UP);
+
+    node.free();
+}
+
+#[itest]
+fn gd_builder_invalid_property() {
+    expect_panic("unknown property", || {
+        let _ = Gd::<BuiltObject>::builder().with_property("does_not_exist", 1);
+    });
+
+    expect_panic("mismatched property type", || {
+        let _ = Gd::<BuiltObject>::builder().with_property("label", Vector3::ZERO);
+    });
+}
@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, instantiate_as = [Grid<i32> as "IntGrid", Grid<f32> as "FloatGrid"])]
+struct Grid<T> {
+    #[export]
+    fill: T,
+    cells: Vec<T>,
+}
+
+#[godot_api]
+impl Grid<i32> {
+    #[func]
+    fn sum(&self) -> i32 {
+        self.cells.iter().sum()
+    }
+}
+
+#[godot_api]
+impl Grid<f32> {
+    #[func]
+    fn sum(&self) -> f32 {
+        self.cells.iter().sum()
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, instantiate_as = [(Ring<i64, 4> as "SmallRing"), (Ring<i64, 16> as "LargeRing")])]
+struct Ring<T, const N: usize> {
+    slots: [T; N],
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl Ring<i64, 4> {
+    #[func]
+    fn capacity(&self) -> i64 {
+        self.slots.len() as i64
+    }
+}
+
+#[godot_api]
+impl Ring<i64, 16> {
+    #[func]
+    fn capacity(&self) -> i64 {
+        self.slots.len() as i64
+    }
+}
+
+#[itest]
+fn generic_class_instantiations_registered() {
+    assert_eq!(Grid::<i32>::class_name().to_string(), "IntGrid");
+    assert_eq!(Grid::<f32>::class_name().to_string(), "FloatGrid");
+    assert_eq!(Ring::<i64, 4>::class_name().to_string(), "SmallRing");
+    assert_eq!(Ring::<i64, 16>::class_name().to_string(), "LargeRing");
+
+    let db = ClassDb::singleton();
+    for class in ["IntGrid", "FloatGrid", "SmallRing", "LargeRing"] {
+        assert!(db.class_exists(class), "class {class} is registered");
+    }
+}
+
+#[itest]
+fn generic_class_properties_and_funcs() {
+    let mut grid = Grid::<i32>::new_gd();
+    grid.set("fill", &7.to_variant());
+    grid.bind_mut().cells = vec![1, 2, 3];
+
+    assert_eq!(grid.bind().fill, 7);
+    assert_eq!(grid.call("sum", &[]), 6.to_variant());
+
+    let mut grid = Grid::<f32>::new_gd();
+    grid.bind_mut().cells = vec![0.5, 0.25];
+    assert_eq!(grid.call("sum", &[]), 0.75f32.to_variant());
+
+    let mut ring = Ring::<i64, 16>::new_alloc();
+    assert_eq!(ring.call("capacity", &[]), 16.to_variant());
+    ring.free();
+}
@@ -78,3 +78,15 @@ fn get_property_list_returns() {
 
     obj.free();
 }
+
+#[itest]
+fn get_property_list_has_property_fast() {
+    let obj = GetPropertyListTest::new_alloc();
+
+    // Not registered in ClassDB, only provided by the get_property_list() override.
+    assert!(obj.has_property_fast("my_property"));
+    assert!(obj.has_property_fast("some_group_my_vector_2"));
+    assert!(!obj.has_property_fast("nonexistent_property"));
+
+    obj.free();
+}
@@ -0,0 +1,161 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Instance counting is compiled out in Release builds.
+#![cfg(debug_assertions)]
+
+use godot::prelude::*;
+use godot::private::{live_instance_count, live_instance_counts, set_count_all_instances};
+
+use crate::framework::{
+    itest, suppress_godot_print, LeakCheck, RustTestCase, TestContext, TestFunction,
+};
+
+#[derive(GodotClass)]
+#[class(init, base=Node, max_instances = 2)]
+struct BudgetedNode {}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, max_instances = 1_000)]
+struct BudgetedRefc {}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct UnbudgetedRefc {}
+
+#[itest]
+fn instance_budget_counts_live_instances() {
+    assert_eq!(BudgetedNode::__MAX_INSTANCES, Some(2));
+    assert_eq!(live_instance_count::<BudgetedNode>(), Some(0));
+
+    let a = BudgetedNode::new_alloc();
+    let b = BudgetedNode::new_alloc();
+    assert_eq!(live_instance_count::<BudgetedNode>(), Some(2));
+
+    // Exceeding the budget reports an error, but does not prevent creation.
+    let mut c = None;
+    suppress_godot_print(|| c = Some(BudgetedNode::new_alloc()));
+    assert_eq!(live_instance_count::<BudgetedNode>(), Some(3));
+
+    a.free();
+    b.free();
+    c.unwrap().free();
+    assert_eq!(live_instance_count::<BudgetedNode>(), Some(0));
+}
+
+#[itest]
+fn instance_budget_refcounted() {
+    let before = live_instance_count::<BudgetedRefc>().unwrap();
+
+    let obj = BudgetedRefc::new_gd();
+    assert_eq!(live_instance_count::<BudgetedRefc>(), Some(before + 1));
+
+    drop(obj);
+    assert_eq!(live_instance_count::<BudgetedRefc>(), Some(before));
+}
+
+#[itest]
+fn instance_budget_absent() {
+    assert_eq!(Node::__MAX_INSTANCES, None);
+    assert_eq!(live_instance_count::<Node>(), None);
+}
+
+#[itest]
+fn instance_counts_include_unbudgeted_classes() {
+    // Unbudgeted classes are only counted on demand, e.g. for --leak-check.
+    let _counting = CountAllInstances::enable();
+
+    let before = unbudgeted_count();
+    let obj = UnbudgetedRefc::new_gd();
+    assert_eq!(unbudgeted_count(), before + 1);
+
+    // No budget, so the per-class query stays unavailable.
+    assert_eq!(live_instance_count::<UnbudgetedRefc>(), None);
+
+    drop(obj);
+    assert_eq!(unbudgeted_count(), before);
+}
+
+#[itest]
+fn instance_counts_ignore_instances_before_enabling() {
+    let previous = set_count_all_instances(false);
+    let obj = UnbudgetedRefc::new_gd();
+
+    {
+        let _counting = CountAllInstances::enable();
+        let counted = UnbudgetedRefc::new_gd();
+        let before = unbudgeted_count();
+
+        // Created while not counted, so destroying it must not subtract from the count.
+        drop(obj);
+        assert_eq!(unbudgeted_count(), before);
+
+        drop(counted);
+        assert_eq!(unbudgeted_count(), before - 1);
+    }
+
+    set_count_all_instances(previous);
+}
+
+#[itest]
+fn leak_check_fails_leaking_test() {
+    fn leaking_test(_ctx: &TestContext) {}
+
+    let test = RustTestCase {
+        name: "leaking_test",
+        file: file!(),
+        skipped: false,
+        focused: false,
+        allow_leaks: false,
+        line: line!(),
+        function: TestFunction::Sync(leaking_test),
+    };
+
+    let _counting = CountAllInstances::enable();
+
+    // Without leaks, the check passes.
+    let check = LeakCheck::begin(&test, true);
+    drop(UnbudgetedRefc::new_gd());
+    assert!(check.finish(&test));
+
+    // With a leak, it fails.
+    let check = LeakCheck::begin(&test, true);
+    let leaked = UnbudgetedRefc::new_gd();
+
+    let mut check = Some(check);
+    let mut passed = true;
+    suppress_godot_print(|| passed = check.take().unwrap().finish(&test));
+    assert!(!passed, "leak check fails a test that leaks an instance");
+
+    drop(leaked);
+}
+
+fn unbudgeted_count() -> usize {
+    live_instance_counts()
+        .into_iter()
+        .find(|(class_name, _)| *class_name == UnbudgetedRefc::class_name())
+        .map_or(0, |(_, count)| count)
+}
+
+/// Counts instances of all classes while alive, and restores the previous setting on drop.
+struct CountAllInstances {
+    previous: bool,
+}
+
+impl CountAllInstances {
+    fn enable() -> Self {
+        Self {
+            previous: set_count_all_instances(true),
+        }
+    }
+}
+
+impl Drop for CountAllInstances {
+    fn drop(&mut self) {
+        set_count_all_instances(self.previous);
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::{Arc, Mutex};
+
+use godot::prelude::*;
+use godot::register::{observe_instances, InstanceEvent};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ObservedNode {}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ObservedRefc {}
+
+#[itest]
+fn instance_observer_node() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+
+    let observer = {
+        let events = events.clone();
+        observe_instances::<ObservedNode, _>(move |event, id| {
+            events.lock().unwrap().push((event, id))
+        })
+    };
+
+    let node = ObservedNode::new_alloc();
+    let id = node.instance_id();
+    node.free();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![(InstanceEvent::Created, id), (InstanceEvent::Destroyed, id)]
+    );
+
+    // No more events after removal.
+    observer.remove();
+    ObservedNode::new_alloc().free();
+    assert_eq!(events.lock().unwrap().len(), 2);
+}
+
+#[itest]
+fn instance_observer_refcounted() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+
+    let observer = {
+        let events = events.clone();
+        observe_instances::<ObservedRefc, _>(move |event, id| {
+            events.lock().unwrap().push((event, id))
+        })
+    };
+
+    // Other classes are not reported.
+    let _unobserved = RefCounted::new_gd();
+
+    let obj = ObservedRefc::new_gd();
+    let id = obj.instance_id();
+    assert_eq!(*events.lock().unwrap(), vec![(InstanceEvent::Created, id)]);
+
+    drop(obj);
+    assert_eq!(
+        events.lock().unwrap().last(),
+        Some(&(InstanceEvent::Destroyed, id))
+    );
+
+    observer.remove();
+}
@@ -10,12 +10,18 @@ mod class_name_test;
 mod class_rename_test;
 mod dyn_gd_test;
 mod dynamic_call_test;
+mod ecs_bridge_test;
 mod enum_test;
+mod gd_builder_test;
+mod generic_class_test;
 // `get_property_list` is only supported in Godot 4.3+
 #[cfg(since_api = "4.3")]
 mod get_property_list_test;
 mod init_level_test;
+mod instance_budget_test;
+mod instance_observer_test;
 mod object_arg_test;
+mod object_script_test;
 mod object_swap_test;
 mod object_test;
 mod onready_test;
@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{GDScript, Node, Script};
+use godot::obj::{Gd, NewAlloc, NewGd};
+
+use crate::framework::itest;
+
+fn make_script(source: &str) -> Gd<GDScript> {
+    let mut script = GDScript::new_gd();
+    script.set_source_code(source);
+    script.reload(); // Compile.
+    script
+}
+
+#[itest]
+fn object_script_attach_detach() {
+    let script = make_script("extends Node\n\nfunc greet():\n\treturn 1\n");
+    let mut node = Node::new_alloc();
+
+    assert!(node.script().is_none());
+    assert!(!node.has_script_method("greet"));
+
+    node.attach_script(&script);
+    assert_eq!(node.script(), Some(script.clone().upcast::<Script>()));
+    assert_eq!(node.script_as::<GDScript>(), Some(script.clone()));
+    assert!(node.has_script_method("greet"));
+    assert!(
+        !node.has_script_method("ready"),
+        "class methods are not script methods"
+    );
+    assert!(
+        !node.has_script_method("reload"),
+        "methods of the Script resource are not script methods"
+    );
+    assert!(
+        !node.has_script_method("greet_missing"),
+        "undeclared methods are not found"
+    );
+
+    let previous = node.detach_script();
+    assert_eq!(previous, Some(script.upcast::<Script>()));
+    assert!(node.script().is_none());
+
+    node.free();
+}
+
+#[itest]
+fn object_script_keeps_reference() {
+    let script = make_script("extends Node\n");
+    let id = script.instance_id();
+    let mut node = Node::new_alloc();
+
+    node.attach_script(&script);
+    drop(script);
+
+    // Object holds a strong reference.
+    let attached = node.script().expect("script attached");
+    assert_eq!(attached.instance_id(), id);
+    drop(attached);
+
+    node.detach_script();
+    assert!(!id.lookup_validity(), "script freed after detach");
+
+    node.free();
+}
+
+#[itest]
+fn object_script_reload() {
+    let mut script = make_script("extends Node\n\nfunc old():\n\tpass\n");
+    let mut node = Node::new_alloc();
+    node.attach_script(&script);
+
+    assert!(node.has_script_method("old"));
+    assert!(!node.has_script_method("new"));
+
+    // Emulate hot-reload: edit source and reload in place.
+    script.set_source_code("extends Node\n\nfunc new():\n\tpass\n");
+    script.reload_ex().keep_state(true).done();
+
+    assert!(node.has_script_method("new"));
+    assert!(!node.has_script_method("old"));
+
+    node.free();
+}
@@ -20,7 +20,7 @@ use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
 use godot::register::{godot_api, GodotClass};
 use godot::sys::{self, interface_fn, GodotFfi};
 
-use crate::framework::{expect_panic, itest, TestContext};
+use crate::framework::{expect_panic, expect_panic_message, itest, run_godot_script, TestContext};
 
 // TODO:
 // * make sure that ptrcalls are used when possible (ie. when type info available; maybe GDScript integration test)
@@ -604,6 +604,21 @@ fn object_engine_upcast_ref() {
     node3d.free();
 }
 
+#[itest]
+fn object_engine_upcast_gd_ref() {
+    let node3d: Gd<Node3D> = Node3D::new_alloc();
+    let id = node3d.instance_id();
+
+    let node: &Gd<Node> = node3d.upcast_gd_ref();
+    assert_eq!(node.instance_id(), id);
+    assert_eq!(node.get_class(), GString::from("Node3D"));
+
+    let cloned: Gd<Node> = node.clone();
+    assert_eq!(cloned, node3d.clone().upcast::<Node>());
+
+    node3d.free();
+}
+
 #[itest]
 fn object_engine_upcast_reflexive() {
     let node3d: Gd<Node3D> = Node3D::new_alloc();
@@ -752,6 +767,18 @@ fn object_user_upcast_ref() {
     assert_eq!(object.get_class(), GString::from("RefcPayload"));
 }
 
+#[itest]
+fn object_user_upcast_gd_ref() {
+    let obj = user_refc_instance();
+    let id = obj.instance_id();
+    let refc_before = obj.get_reference_count();
+
+    let refc: &Gd<RefCounted> = obj.upcast_gd_ref();
+    assert_eq!(refc.instance_id(), id);
+    assert_eq!(refc.get_class(), GString::from("RefcPayload"));
+    assert_eq!(refc.get_reference_count(), refc_before);
+}
+
 #[itest]
 fn object_user_upcast_mut() {
     let mut obj = user_refc_instance();
@@ -812,11 +839,24 @@ fn object_engine_shared_free() {
 fn object_engine_manual_double_free() {
     let node = Node3D::new_alloc();
     let node2 = node.clone();
+    #[cfg(debug_assertions)]
+    let first_free_line = line!() + 1;
     node.free();
 
-    expect_panic("double free()", move || {
+    let message = expect_panic_message("double free()", move || {
         node2.free();
     });
+    assert!(
+        message.contains("called free() on already destroyed object"),
+        "{message}"
+    );
+
+    // Debug builds point to the first call.
+    #[cfg(debug_assertions)]
+    assert!(
+        message.contains(&format!("freed at {}:{first_free_line}:", file!())),
+        "{message}"
+    );
 }
 
 #[itest]
@@ -838,6 +878,78 @@ fn object_user_double_free() {
     });
 }
 
+#[itest]
+fn object_engine_queue_free_checked(ctx: &TestContext) {
+    let mut node = Node::new_alloc();
+    ctx.scene_tree.clone().add_child(&node);
+
+    node.queue_free_checked();
+    assert!(node.is_queued_for_deletion());
+    assert!(node.is_instance_valid(), "deletion is deferred");
+
+    // Already queued: no-op.
+    node.queue_free_checked();
+}
+
+#[itest]
+fn object_engine_queue_free_checked_without_scene_tree() {
+    // The test runner always has a SceneTree, so the call is made from a separate Godot process with a plain MainLoop.
+    let output = run_godot_script(
+        "queue_free_checked_test.gd",
+        "extends MainLoop\n\
+        \n\
+        func _initialize():\n\
+        \tprint(\"queue_free_checked: \", QueueFreeProbe.new().queue_free_detached())\n\
+        \n\
+        func _process(_delta):\n\
+        \treturn true\n",
+    );
+
+    assert!(
+        output.contains(
+            "queue_free_checked: called queue_free_checked() on node outside the scene tree, \
+            while no SceneTree is running to delete it"
+        ),
+        "unexpected output:\n{output}"
+    );
+}
+
+/// Used by [`object_engine_queue_free_checked_without_scene_tree()`], in a process without `SceneTree`.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct QueueFreeProbe {}
+
+#[godot_api]
+impl QueueFreeProbe {
+    /// Calls `queue_free_checked()` on a node outside the tree, and returns the panic message.
+    #[func]
+    fn queue_free_detached(&self) -> GString {
+        let node = Node::new_alloc();
+
+        let mut node2 = node.clone();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            node2.queue_free_checked();
+        }));
+        node.free();
+
+        match result {
+            Ok(()) => GString::from("no panic"),
+            Err(err) => GString::from(godot::private::extract_panic_message(err)),
+        }
+    }
+}
+
+#[itest]
+fn object_engine_queue_free_checked_after_free() {
+    let node = Node::new_alloc();
+    let mut node2 = node.clone();
+    node.free();
+
+    expect_panic("queue_free_checked() after free()", move || {
+        node2.queue_free_checked();
+    });
+}
+
 #[itest]
 fn object_user_share_drop() {
     let drop_count = Rc::new(RefCell::new(0));
@@ -5,16 +5,22 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
+use godot::builtin::{
+    dict, Array, Color, Dictionary, GString, PackedByteArray, PackedColorArray, StringName,
+    Variant, VariantType,
+};
 use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
 use godot::global::{PropertyHint, PropertyUsageFlags};
-use godot::meta::{GodotConvert, PropertyHintInfo, ToGodot};
+use godot::meta::{FromGodot, GodotConvert, PropertyHintInfo, ToGodot};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
-use godot::register::property::{Export, Var};
-use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
+use godot::register::property::{Export, FlagSet, Var};
+use godot::register::{godot_api, Export, GodotClass, GodotConvert, GodotFlags, Var};
+use godot::sys::GdextBuild;
 use godot::test::itest;
 
-// No tests currently, tests using these classes are in Godot scripts.
+use crate::framework::expect_panic;
+
+// Most tests using these classes are in Godot scripts.
 
 #[derive(GodotClass)]
 #[class(base=Node)]
@@ -145,6 +151,82 @@ impl INode for HasProperty {
     }
 }
 
+#[itest]
+fn property_get_set_fast() {
+    let mut obj = HasProperty::new_alloc();
+
+    obj.set_fast("int_val", &5.to_variant());
+    assert_eq!(obj.bind().int_val, 5);
+    assert_eq!(obj.get_fast("int_val"), 5.to_variant());
+
+    // Custom accessors.
+    obj.set_fast("string_val", &"text".to_variant());
+    assert_eq!(obj.bind().string_val, GString::from("text"));
+    assert_eq!(obj.get_fast("int_val_read"), 2.to_variant());
+
+    // Same results as going through Godot.
+    assert_eq!(obj.get_fast("string_val"), obj.get("string_val"));
+
+    // Engine properties and unknown names fall back to Object::get/set.
+    obj.set_fast("name", &"Named".to_variant());
+    assert_eq!(obj.get_fast("name"), StringName::from("Named").to_variant());
+    assert_eq!(obj.get_fast("does_not_exist"), Variant::nil());
+
+    expect_panic("set_fast() with wrong type", || {
+        obj.set_fast("int_val", &"not an int".to_variant());
+    });
+    assert_eq!(obj.bind().int_val, 5);
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct HasLargeProperties {
+    #[var]
+    bytes: PackedByteArray,
+
+    #[var]
+    text: GString,
+
+    #[var]
+    array: Array<i64>,
+}
+
+// Reading properties must not copy the data of builtins; see "Cost of property access" in `Var` docs.
+#[itest]
+fn property_get_shares_builtin_data() {
+    let mut obj = HasLargeProperties::new_alloc();
+    {
+        let mut guard = obj.bind_mut();
+        guard.bytes = PackedByteArray::from(vec![7u8; 1024].as_slice());
+        guard.text = GString::from("a rather long string");
+        guard.array = (0..1024).collect();
+    }
+
+    // Copy-on-write types share their buffer, both through Var and through Godot's property dispatch.
+    let bytes = obj.bind().bytes.get_property();
+    let bytes_from_godot = obj.get("bytes").to::<PackedByteArray>();
+    let field_ptr = obj.bind().bytes.as_slice().as_ptr();
+    assert_eq!(bytes.as_slice().as_ptr(), field_ptr);
+    assert_eq!(bytes_from_godot.as_slice().as_ptr(), field_ptr);
+
+    let text = obj.get("text").to::<GString>();
+    assert_eq!(text.chars().as_ptr(), obj.bind().text.chars().as_ptr());
+
+    // Modifying a copy-on-write value copies it, leaving the field unchanged.
+    let mut bytes = bytes;
+    bytes.push(1u8);
+    assert_eq!(obj.bind().bytes.len(), 1024);
+
+    // Arrays are shared by reference.
+    let mut array = obj.get("array").to::<Array<i64>>();
+    array.push(-1i64);
+    assert_eq!(obj.bind().array.back(), Some(-1));
+
+    obj.free();
+}
+
 #[derive(Default, Copy, Clone)]
 #[repr(i64)]
 enum SomeCStyleEnum {
@@ -398,6 +480,212 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[derive(GodotFlags, Copy, Clone, Eq, PartialEq, Debug)]
+enum Ability {
+    Fly,
+    Swim,
+    Climb,
+}
+
+#[derive(GodotFlags, Copy, Clone, Eq, PartialEq, Debug)]
+enum SparseLayer {
+    Ground = 1,
+    Water = 4,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct FlagsExport {
+    #[export]
+    abilities: FlagSet<Ability>,
+
+    #[var]
+    layers: FlagSet<SparseLayer>,
+}
+
+#[itest]
+fn export_godot_flags() {
+    let mut class = FlagsExport::new_alloc();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "abilities".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::INT.ord());
+    check_property(&property, "hint", PropertyHint::FLAGS.ord());
+    check_property(&property, "hint_string", "Fly:1,Swim:2,Climb:4");
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "layers".to_variant())
+        .unwrap();
+    check_property(&property, "hint_string", "Ground:1,Water:4");
+
+    // Rust side: typed set/clear/test.
+    {
+        let mut guard = class.bind_mut();
+        guard.abilities.set(Ability::Fly);
+        guard.abilities |= Ability::Climb;
+        assert!(guard.abilities.contains(Ability::Climb));
+        assert!(!guard.abilities.contains(Ability::Swim));
+
+        guard.abilities.clear(Ability::Fly);
+        let flags: Vec<Ability> = guard.abilities.iter().collect();
+        assert_eq!(flags, vec![Ability::Climb]);
+    }
+    assert_eq!(class.get("abilities"), 4.to_variant());
+
+    // Godot side: unknown bits are dropped.
+    class.set("layers", &(1 | 2 | 4).to_variant());
+    let layers = class.bind().layers;
+    assert_eq!(layers.bits(), 5);
+    assert_eq!(
+        layers,
+        FlagSet::from(SparseLayer::Ground) | SparseLayer::Water
+    );
+
+    // Strict conversion rejects unknown bits.
+    assert_eq!(FlagSet::<SparseLayer>::try_from_godot(2i64).ok(), None);
+    assert_eq!(
+        FlagSet::<SparseLayer>::from_bits(4),
+        Some(SparseLayer::Water.into())
+    );
+    assert_eq!(FlagSet::<Ability>::all().bits(), 7);
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct NamedLayersExport {
+    #[export(flags_2d_physics, named_layers)]
+    collision_mask: u32,
+}
+
+#[itest]
+fn export_named_layers() {
+    let class = NamedLayersExport::new_alloc();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "collision_mask".to_variant())
+        .unwrap();
+
+    check_property(&property, "type", VariantType::INT.ord());
+    check_property(&property, "hint", PropertyHint::FLAGS.ord());
+
+    // Names configured in project.godot; separators in names are replaced.
+    let hint_string = property
+        .get_or_nil("hint_string")
+        .to::<GString>()
+        .to_string();
+    let flags: Vec<&str> = hint_string.split(',').collect();
+    assert_eq!(flags.len(), 32);
+    assert_eq!(flags[0], "Player:1");
+    assert_eq!(flags[1], "Layer 2:2");
+    assert_eq!(flags[2], "Enemy  Flying:4");
+    assert_eq!(flags[31], format!("Layer 32:{}", 1u32 << 31));
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ColorNoAlphaExport {
+    #[export(color_no_alpha)]
+    color: Color,
+
+    #[export(color_no_alpha)]
+    packed_colors: PackedColorArray,
+
+    #[export(color_no_alpha)]
+    color_array: Array<Color>,
+}
+
+#[itest]
+fn export_color_no_alpha_collections() {
+    let class = ColorNoAlphaExport::new_alloc();
+    let find_property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let property = find_property("color");
+    check_property(&property, "hint", PropertyHint::COLOR_NO_ALPHA.ord());
+    check_property(&property, "hint_string", "");
+
+    let element_hint = format!(
+        "{}/{}:",
+        VariantType::COLOR.ord(),
+        PropertyHint::COLOR_NO_ALPHA.ord()
+    );
+
+    for name in ["packed_colors", "color_array"] {
+        let property = find_property(name);
+        check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+        check_property(&property, "hint_string", element_hint.as_str());
+    }
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DictTypesExport {
+    #[export(dict_types = (GString, i64))]
+    counts: Dictionary,
+
+    #[export(dict_types = (StringName, Gd<Resource>))]
+    resources: Dictionary,
+}
+
+#[itest]
+fn export_dict_types() {
+    let class = DictTypesExport::new_alloc();
+    let find_property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let counts = find_property("counts");
+    let resources = find_property("resources");
+    check_property(&counts, "type", VariantType::DICTIONARY.ord());
+    check_property(&resources, "type", VariantType::DICTIONARY.ord());
+
+    if GdextBuild::godot_runtime_version_triple() >= (4, 4, 0) {
+        let string = format!("{}:", VariantType::STRING.ord());
+        let int = format!("{}:", VariantType::INT.ord());
+        let string_name = format!("{}:", VariantType::STRING_NAME.ord());
+        let resource = format!(
+            "{}/{}:Resource",
+            VariantType::OBJECT.ord(),
+            PropertyHint::RESOURCE_TYPE.ord()
+        );
+
+        check_property(&counts, "hint", PropertyHint::TYPE_STRING.ord());
+        check_property(&counts, "hint_string", format!("{string};{int}"));
+        check_property(&resources, "hint", PropertyHint::TYPE_STRING.ord());
+        check_property(
+            &resources,
+            "hint_string",
+            format!("{string_name};{resource}"),
+        );
+    } else {
+        check_property(&counts, "hint", PropertyHint::NONE.ord());
+    }
+
+    class.free();
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -479,6 +767,68 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct UsageShortcuts {
+    #[var(storage)]
+    stored: i64,
+
+    #[var(editor_only)]
+    editor_visible: i64,
+
+    #[var(no_instance_state)]
+    plain_no_instance_state: i64,
+
+    #[export]
+    #[var(no_instance_state)]
+    exported_no_instance_state: i64,
+
+    #[var(storage, no_instance_state)]
+    stored_no_instance_state: i64,
+
+    #[export]
+    #[var(storage)]
+    exported_stored: i64,
+
+    #[export]
+    #[var(editor_only)]
+    exported_editor_only: i64,
+}
+
+#[itest]
+fn var_usage_shortcuts() {
+    let class = UsageShortcuts::new_gd();
+    let properties = class.get_property_list();
+
+    let check_usage = |name: &str, expected: PropertyUsageFlags| {
+        let property = properties
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("property `{name}` not found"));
+
+        check_property(&property, "usage", expected.ord());
+    };
+
+    check_usage("stored", PropertyUsageFlags::STORAGE);
+    check_usage("editor_visible", PropertyUsageFlags::EDITOR);
+    check_usage(
+        "plain_no_instance_state",
+        PropertyUsageFlags::NONE | PropertyUsageFlags::NO_INSTANCE_STATE,
+    );
+    check_usage(
+        "exported_no_instance_state",
+        PropertyUsageFlags::DEFAULT | PropertyUsageFlags::NO_INSTANCE_STATE,
+    );
+    check_usage(
+        "stored_no_instance_state",
+        PropertyUsageFlags::STORAGE | PropertyUsageFlags::NO_INSTANCE_STATE,
+    );
+
+    // With #[export], the property stays visible in the editor.
+    check_usage("exported_stored", PropertyUsageFlags::DEFAULT);
+    check_usage("exported_editor_only", PropertyUsageFlags::EDITOR);
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
@@ -16,6 +16,12 @@ pub struct ReentrantClass {
     first_called_pre: bool,
     first_called_post: bool,
     second_called: bool,
+
+    // Bind state observed from inside observe_bind_state().
+    observed_bound: bool,
+    observed_try_bind_failed: bool,
+    observed_method: Option<&'static str>,
+    observed_description: String,
 }
 
 #[godot_api]
@@ -41,6 +47,22 @@ impl ReentrantClass {
     fn second(&mut self) {
         self.second_called = true;
     }
+
+    #[func]
+    fn observe_bind_state(&mut self) {
+        let gd = self.to_gd();
+        self.observed_bound = gd.is_bound();
+        self.observed_try_bind_failed = gd.try_bind().is_err();
+
+        #[cfg(debug_assertions)]
+        {
+            let info = gd.borrow_info();
+            self.observed_method = info.and_then(|info| info.method());
+            self.observed_description = info.map(|info| info.to_string()).unwrap_or_default();
+        }
+
+        assert!(self.try_base_mut().is_ok());
+    }
 }
 
 #[itest]
@@ -79,3 +101,86 @@ fn reentrant_emit_succeeds() {
 
     class.free()
 }
+
+#[itest]
+fn reentrant_try_bind_fails_while_bound() {
+    let mut class = ReentrantClass::new_alloc();
+    assert!(!class.is_bound());
+
+    let mut other = class.clone();
+    {
+        let _guard = class.bind_mut();
+        assert!(other.is_bound());
+
+        let err = other.try_bind().expect_err("instance is bound exclusively");
+        assert!(err.is_already_bound());
+        assert!(other.try_bind_mut().is_err());
+
+        #[cfg(debug_assertions)]
+        {
+            let info = other.borrow_info().expect("borrow is recorded");
+            assert!(info.is_mutable());
+            assert_eq!(info.method(), None);
+            assert!(info.location().file().ends_with("reentrant_test.rs"));
+
+            let held_by = err.held_by().expect("error names the borrow");
+            assert_eq!(held_by.location(), info.location());
+            assert!(err.to_string().contains("reentrant_test.rs"));
+        }
+    }
+
+    assert!(!other.is_bound());
+    assert!(other.try_bind_mut().is_ok());
+
+    class.free();
+}
+
+#[itest]
+fn reentrant_bind_state_names_method() {
+    let mut class = ReentrantClass::new_alloc();
+
+    class.call("observe_bind_state", &[]);
+
+    {
+        let class = class.bind();
+        assert!(class.observed_bound);
+        assert!(class.observed_try_bind_failed);
+
+        #[cfg(debug_assertions)]
+        {
+            assert_eq!(class.observed_method, Some("observe_bind_state"));
+            assert_eq!(
+                class.observed_description,
+                "exclusive borrow by `ReentrantClass::observe_bind_state()`"
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        assert_eq!(class.observed_method, None);
+    }
+
+    class.free();
+}
+
+#[cfg(debug_assertions)]
+#[itest]
+fn reentrant_borrow_info_tracks_nesting() {
+    let class = ReentrantClass::new_alloc();
+    let other = class.clone();
+
+    {
+        let (_outer, outer_line) = (class.bind(), line!());
+        {
+            let (_inner, inner_line) = (other.bind(), line!());
+            let info = other.borrow_info().expect("inner borrow is live");
+            assert_eq!(info.location().line(), inner_line);
+        }
+
+        // After the inner guard is dropped, the outer borrow is reported again.
+        let info = class.borrow_info().expect("outer borrow is live");
+        assert_eq!(info.location().line(), outer_line);
+        assert!(!info.is_mutable());
+    }
+
+    assert!(class.borrow_info().is_none());
+    class.free();
+}
@@ -336,6 +336,29 @@ impl IRefCounted for RevertTest {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct RevertDefaultsTest {
+    #[export]
+    #[init(val = 42)]
+    level: i32,
+
+    #[export]
+    name: GString,
+
+    #[var]
+    #[init(val = 7)]
+    not_exported: i32,
+}
+
+#[derive(GodotClass)]
+#[class(init, func_rename = camel_case)]
+struct RevertRenamedTest {
+    #[export]
+    #[init(val = 9)]
+    max_level: i32,
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[itest]
@@ -673,6 +696,37 @@ fn test_revert() {
     assert_eq!(revert.property_get_revert(&changes), true.to_variant());
 }
 
+#[itest]
+fn test_revert_to_init_defaults() {
+    let mut obj = RevertDefaultsTest::new_gd();
+    obj.bind_mut().level = 100;
+
+    let level = StringName::from("level");
+    assert!(obj.property_can_revert(&level));
+    assert_eq!(obj.property_get_revert(&level), 42.to_variant());
+
+    let name = StringName::from("name");
+    assert!(obj.property_can_revert(&name));
+    assert_eq!(obj.property_get_revert(&name), GString::new().to_variant());
+
+    let not_exported = StringName::from("not_exported");
+    assert!(!obj.property_can_revert(&not_exported));
+    assert_eq!(obj.property_get_revert(&not_exported), Variant::nil());
+}
+
+#[itest]
+fn test_revert_to_init_defaults_renamed() {
+    let obj = RevertRenamedTest::new_gd();
+
+    let renamed = StringName::from("maxLevel");
+    assert!(obj.property_can_revert(&renamed));
+    assert_eq!(obj.property_get_revert(&renamed), 9.to_variant());
+
+    // The Rust field name is not a registered property.
+    let rust_name = StringName::from("max_level");
+    assert!(!obj.property_can_revert(&rust_name));
+}
+
 // Used in `test_collision_object_2d_input_event` in `SpecialTests.gd`.
 #[derive(GodotClass)]
 #[class(init, base = RigidBody2D)]
@@ -795,3 +849,174 @@ impl IEditorPlugin for CustomEditorPlugin {
         true
     }
 }
+
+// Likewise, ensure that export hooks can be forwarded from the virtual methods of EditorExportPlugin.
+#[cfg(since_api = "4.2")]
+mod export_plugin {
+    use std::error::Error;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use godot::builtin::{GString, PackedByteArray, PackedStringArray};
+    use godot::classes::{EditorExportPlugin, EditorPlugin, IEditorExportPlugin};
+    use godot::obj::{Base, NewAlloc, NewGd};
+    use godot::register::{godot_api, GodotClass};
+    use godot::tools::{
+        forward_export_begin, forward_export_end, forward_export_file, impl_export_plugin,
+        ExportAction, ExportFile, ExportHooks, ExportInfo, ExportPlugins,
+    };
+
+    use crate::framework::itest;
+
+    #[derive(GodotClass)]
+    #[class(init, base = EditorExportPlugin, tool)]
+    struct CustomExportPlugin {
+        base: Base<EditorExportPlugin>,
+    }
+
+    impl ExportHooks for CustomExportPlugin {
+        fn export_file(
+            &mut self,
+            file: &ExportFile,
+        ) -> Result<ExportAction, Box<dyn std::error::Error>> {
+            if file.resource_type.is_empty() {
+                return Ok(ExportAction::Skip);
+            }
+            Ok(ExportAction::Keep)
+        }
+    }
+
+    #[godot_api]
+    impl IEditorExportPlugin for CustomExportPlugin {
+        fn get_name(&self) -> GString {
+            "CustomExportPlugin".into()
+        }
+
+        fn export_begin(
+            &mut self,
+            features: PackedStringArray,
+            is_debug: bool,
+            path: GString,
+            flags: u32,
+        ) {
+            forward_export_begin(self, features, is_debug, path, flags);
+        }
+
+        fn export_file(&mut self, path: GString, type_: GString, features: PackedStringArray) {
+            forward_export_file(self, path, type_, features);
+        }
+
+        fn export_end(&mut self) {
+            forward_export_end(self);
+        }
+    }
+
+    // Plugin wired up through impl_export_plugin!, recording the hook calls.
+    static CONSTRUCTED: AtomicU32 = AtomicU32::new(0);
+
+    #[derive(GodotClass)]
+    #[class(init, base = EditorExportPlugin, tool)]
+    struct RecordingExportPlugin {
+        base: Base<EditorExportPlugin>,
+        events: Vec<String>,
+        #[init(val = CONSTRUCTED.fetch_add(1, Ordering::Relaxed))]
+        _index: u32,
+    }
+
+    impl ExportHooks for RecordingExportPlugin {
+        fn begin(&mut self, info: &ExportInfo) -> Result<(), Box<dyn Error>> {
+            let features = info.features.to_vec();
+            self.events.push(format!(
+                "begin {} debug={} features={features:?}",
+                info.path, info.is_debug
+            ));
+            Ok(())
+        }
+
+        fn export_file(&mut self, file: &ExportFile) -> Result<ExportAction, Box<dyn Error>> {
+            self.events.push(format!(
+                "file {} {} {}",
+                file.path,
+                file.resource_type,
+                file.has_feature("mobile")
+            ));
+
+            match file.path.to_string().as_str() {
+                "res://skip.txt" => Ok(ExportAction::Skip),
+                "res://replace.txt" => Ok(ExportAction::Replace {
+                    contents: PackedByteArray::from(&b"replaced"[..]),
+                }),
+                "res://remap.json" => Ok(ExportAction::Remap {
+                    remap_to: "res://remap.res".into(),
+                    contents: PackedByteArray::from(&b"remapped"[..]),
+                }),
+                _ => Ok(ExportAction::Keep),
+            }
+        }
+
+        fn end(&mut self) -> Result<(), Box<dyn Error>> {
+            self.events.push("end".to_string());
+            Ok(())
+        }
+    }
+
+    impl_export_plugin!(RecordingExportPlugin, name = "RecordingExportPlugin");
+
+    fn features() -> PackedStringArray {
+        PackedStringArray::from(&[GString::from("mobile"), GString::from("release")])
+    }
+
+    #[itest]
+    fn export_plugin_macro_forwards_hooks() {
+        let mut plugin = RecordingExportPlugin::new_gd();
+        assert_eq!(
+            plugin.bind().get_name(),
+            GString::from("RecordingExportPlugin")
+        );
+
+        {
+            // Invoke the virtuals like Godot's exporter would; Skip, Replace and Remap call into the base plugin. Which files end up in
+            // the export can only be observed in an actual editor export, which itests cannot run.
+            let mut guard = plugin.bind_mut();
+            guard.export_begin(features(), false, "user://game.pck".into(), 0);
+            let paths = [
+                "res://keep.tres",
+                "res://skip.txt",
+                "res://replace.txt",
+                "res://remap.json",
+            ];
+            for path in paths {
+                guard.export_file(path.into(), "Resource".into(), features());
+            }
+            guard.export_end();
+        }
+
+        assert_eq!(
+            plugin.bind().events,
+            vec![
+                r#"begin user://game.pck debug=false features=["mobile", "release"]"#,
+                "file res://keep.tres Resource true",
+                "file res://skip.txt Resource true",
+                "file res://replace.txt Resource true",
+                "file res://remap.json Resource true",
+                "end",
+            ]
+        );
+    }
+
+    #[itest]
+    fn export_plugin_add_outside_editor() {
+        // Itests don't run in the editor, which has the only export system. So no plugin must be constructed or added.
+        let mut editor_plugin = EditorPlugin::new_alloc();
+        let mut export_plugins = ExportPlugins::new();
+
+        let before = CONSTRUCTED.load(Ordering::Relaxed);
+        let added = export_plugins.add::<RecordingExportPlugin>(&mut editor_plugin);
+
+        assert!(added.is_none());
+        assert!(export_plugins.is_empty());
+        assert_eq!(CONSTRUCTED.load(Ordering::Relaxed), before);
+
+        export_plugins.remove_all(&mut editor_plugin);
+        editor_plugin.free();
+    }
+}
@@ -8,7 +8,9 @@
 use std::fmt::Debug;
 
 use godot::builtin::{GString, Vector2};
+use godot::classes::{Area2D, Node};
 use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -124,6 +126,36 @@ fn enum_inty_with_complex_exprs() {
     assert_eq!(EnumIntyWithExprs::I as isize, 11);
 }
 
+#[derive(GodotConvert, Clone, Copy, PartialEq, Debug)]
+#[godot(via = i32)]
+enum PhysicsLayer {
+    Player = 2,
+    Enemies = 5,
+}
+
+#[itest]
+fn enum_passed_to_engine_api() {
+    // Layer number parameter, declared as `impl AsArg<i32>`.
+    let mut area = Area2D::new_alloc();
+    area.set_collision_layer_value(PhysicsLayer::Enemies, true);
+    assert!(area.get_collision_layer_value(PhysicsLayer::Enemies));
+    assert!(!area.get_collision_layer_value(PhysicsLayer::Player));
+
+    // Integer literals still infer their type.
+    assert!(area.get_collision_layer_value(5));
+    area.free();
+
+    // Class method with `impl AsArg<GString>` parameter.
+    let mut node = Node::new_alloc();
+    node.set_editor_description(EnumStringy::B);
+    assert_eq!(node.get_editor_description(), GString::from("B"));
+
+    // Class method with `impl AsArg<StringName>` parameter.
+    node.add_to_group(EnumStringy::C);
+    assert!(node.is_in_group("C"));
+    node.free();
+}
+
 macro_rules! test_inty {
     ($T:ident, $test_name:ident, $class_name:ident) => {
         #[derive(GodotConvert, Clone, PartialEq, Debug)]
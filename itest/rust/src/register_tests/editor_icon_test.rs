@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Vector2;
+use godot::classes::{Image, ImageTexture};
+use godot::obj::GodotClass;
+use godot::private::load_icon;
+use godot::register::{registered_classes, GodotClass};
+use godot::tools::save;
+
+use crate::framework::{itest, remove_test_file, suppress_godot_print};
+
+const ICON_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+    <circle cx="8" cy="8" r="6" fill="#8eef97"/>
+</svg>"##;
+
+#[derive(GodotClass)]
+#[class(init, base=Node, icon = ICON_SVG)]
+struct IconSvgNode {}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, icon = "res://icons/icon_path_node.svg")]
+struct IconPathNode {}
+
+#[itest]
+fn editor_icon_registered() {
+    let icon_of = |class_name| {
+        registered_classes()
+            .into_iter()
+            .find(|class| class.class_name() == class_name)
+            .expect("class is registered")
+            .icon()
+    };
+
+    assert_eq!(icon_of(IconSvgNode::class_name()), Some(ICON_SVG));
+    assert_eq!(
+        icon_of(IconPathNode::class_name()),
+        Some("res://icons/icon_path_node.svg")
+    );
+}
+
+#[itest]
+fn editor_icon_from_svg() {
+    let texture = load_icon(ICON_SVG, 16.0).expect("SVG icon loads");
+    assert_eq!(texture.get_size(), Vector2::new(16.0, 16.0));
+
+    // Rendered at the editor scale, not scaled afterward.
+    let texture = load_icon(ICON_SVG, 32.0).expect("SVG icon loads at 2x");
+    assert_eq!(texture.get_size(), Vector2::new(32.0, 32.0));
+
+    // Leading whitespace, e.g. from include_str!(), is accepted.
+    let indented = format!("\n  {ICON_SVG}");
+    assert!(load_icon(&indented, 16.0).is_some());
+}
+
+#[itest]
+fn editor_icon_from_resource_path() {
+    const ICON_FILE: &str = "test_editor_icon.tres";
+    let res_path = format!("res://{ICON_FILE}");
+
+    let image = Image::from_rgba8(32, 32, &[0; 32 * 32 * 4]).expect("create image");
+    let texture = ImageTexture::create_from_image(&image).expect("create texture");
+    save(&texture, &res_path);
+
+    // Other sizes are scaled to the icon size.
+    let icon = load_icon(&res_path, 16.0).expect("icon loads from path");
+    assert_eq!(icon.get_size(), Vector2::new(16.0, 16.0));
+
+    let icon = load_icon(&res_path, 32.0).expect("icon loads from path at 2x");
+    assert_eq!(icon.get_size(), Vector2::new(32.0, 32.0));
+
+    remove_test_file(ICON_FILE);
+}
+
+#[itest]
+fn editor_icon_invalid() {
+    let mut icon = None;
+    suppress_godot_print(|| icon = load_icon("res://no_such_icon.svg", 16.0));
+    assert!(icon.is_none(), "missing resource path");
+
+    let mut icon = None;
+    suppress_godot_print(|| icon = load_icon("<svg this is not SVG", 16.0));
+    assert!(icon.is_none(), "malformed SVG");
+}
@@ -10,6 +10,8 @@
 
 use crate::framework::itest;
 use godot::classes::ClassDb;
+use godot::meta::VarArgs;
+use godot::obj::EngineBitfield;
 use godot::prelude::*;
 
 #[derive(GodotClass)]
@@ -247,6 +249,34 @@ impl IRefCounted for GdSelfObj {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, func_rename = camel_case)]
+struct CamelCaseObj {
+    #[var]
+    item_count: i32,
+}
+
+#[godot_api]
+impl CamelCaseObj {
+    #[signal]
+    fn item_added(count: i32);
+
+    #[func]
+    fn add_item(&mut self) {
+        self.item_count += 1;
+    }
+
+    #[func(rename = reset)]
+    fn remove_all_items(&mut self) {
+        self.item_count = 0;
+    }
+
+    #[func]
+    fn _private_helper_fn() {}
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -292,6 +322,174 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_rename_policy() {
+    assert!(class_has_method::<CamelCaseObj>("addItem"));
+    assert!(!class_has_method::<CamelCaseObj>("add_item"));
+    assert!(class_has_method::<CamelCaseObj>("_privateHelperFn"));
+    assert!(class_has_method::<CamelCaseObj>("getItemCount"));
+    assert!(class_has_method::<CamelCaseObj>("setItemCount"));
+    assert!(class_has_signal::<CamelCaseObj>("itemAdded"));
+
+    // Explicit renames take precedence.
+    assert!(class_has_method::<CamelCaseObj>("reset"));
+    assert!(!class_has_method::<CamelCaseObj>("removeAllItems"));
+
+    let mut obj = CamelCaseObj::new_gd();
+    obj.call("addItem", &[]);
+    obj.call("addItem", &[]);
+    assert_eq!(obj.get("itemCount"), 2.to_variant());
+
+    obj.set("itemCount", &5.to_variant());
+    assert_eq!(obj.bind().item_count, 5);
+
+    obj.call("reset", &[]);
+    assert_eq!(obj.get("itemCount"), 0.to_variant());
+}
+
+#[itest]
+fn func_rename_policy_apply() {
+    use godot::private::FuncRename;
+
+    assert_eq!(FuncRename::CamelCase.apply("add_item"), "addItem");
+    assert_eq!(FuncRename::CamelCase.apply("_ready_up"), "_readyUp");
+    assert_eq!(FuncRename::CamelCase.apply("get_2d_pos"), "get2dPos");
+    assert_eq!(FuncRename::PascalCase.apply("add_item"), "AddItem");
+    assert_eq!(FuncRename::Verbatim.apply("add_item"), "add_item");
+    assert_eq!(FuncRename::CamelCase.apply(""), "");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Also called from GDScript, see `ManualFfiTests.gd`.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct VarargObj;
+
+#[godot_api]
+impl VarargObj {
+    #[func(vararg)]
+    fn sum(&self, args: VarArgs) -> i64 {
+        args.rest_as::<i64>()
+            .expect("sum() takes integers")
+            .into_iter()
+            .sum()
+    }
+
+    #[func(vararg)]
+    fn join(separator: GString, args: VarArgs) -> GString {
+        let parts: Vec<String> = args.map(|arg| arg.stringify().to_string()).collect();
+        parts.join(&separator.to_string()).into()
+    }
+
+    #[func(vararg)]
+    fn first_as_int(&self, _prefix: GString, mut args: VarArgs) -> GString {
+        match args.next_as::<i64>() {
+            Ok(value) => value.to_string().into(),
+            Err(err) => err.to_string().into(),
+        }
+    }
+}
+
+#[itest]
+fn func_vararg() {
+    let mut obj = VarargObj::new_gd();
+
+    let sum = obj.call("sum", &[1.to_variant(), 2.to_variant(), 3.to_variant()]);
+    assert_eq!(sum, 6.to_variant());
+
+    let sum = obj.call("sum", &[]);
+    assert_eq!(sum, 0.to_variant());
+
+    let joined = obj.call(
+        "join",
+        &["-".to_variant(), "a".to_variant(), 7.to_variant()],
+    );
+    assert_eq!(joined, "a-7".to_variant());
+
+    // Declared parameters are still required.
+    let result = obj.try_call("join", &[]);
+    assert!(result.is_err());
+}
+
+#[itest]
+fn func_vararg_errors_report_index() {
+    let mut obj = VarargObj::new_gd();
+
+    let ok = obj.call("first_as_int", &["x".to_variant(), 5.to_variant()]);
+    assert_eq!(ok, "5".to_variant());
+
+    let wrong_type = obj.call("first_as_int", &["x".to_variant(), "five".to_variant()]);
+    let message = wrong_type.to::<GString>().to_string();
+    assert!(message.contains("VarargObj::first_as_int"), "{message}");
+    assert!(message.contains("parameter #1"), "{message}");
+
+    let missing = obj.call("first_as_int", &["x".to_variant()]);
+    let message = missing.to::<GString>().to_string();
+    assert!(message.contains("parameter #1"), "{message}");
+    assert!(message.contains("missing"), "{message}");
+}
+
+#[itest]
+fn func_vararg_registered_as_vararg() {
+    use godot::global::MethodFlags;
+
+    let method = ClassDb::singleton()
+        .class_get_method_list_ex(&VarargObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .find(|method| method.get_or_nil("name") == "join".to_variant())
+        .expect("join() registered");
+
+    let flags = method.get_or_nil("flags").to::<u64>();
+    assert!(MethodFlags::from_ord(flags).is_set(MethodFlags::VARARG));
+    assert!(MethodFlags::from_ord(flags).is_set(MethodFlags::STATIC));
+
+    // Only declared parameters are listed.
+    let args = method.get_or_nil("args").to::<VariantArray>();
+    assert_eq!(args.len(), 1);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AvailabilityObj;
+
+#[godot_api]
+impl AvailabilityObj {
+    #[func]
+    fn always(&self) {}
+
+    #[func(editor_only)]
+    fn editor_tool(&self) {}
+
+    #[func(debug_only)]
+    fn debug_tool(&self) {}
+}
+
+#[itest]
+fn func_editor_only_and_debug_only() {
+    use godot::classes::{Engine, Os};
+
+    // Integration tests run headless, without editor; the engine binary may be a debug or release build.
+    let is_editor = Engine::singleton().is_editor_hint();
+    let is_debug = Os::singleton().is_debug_build();
+
+    assert!(class_has_method::<AvailabilityObj>("always"));
+    assert_eq!(
+        class_has_method::<AvailabilityObj>("editor_tool"),
+        is_editor
+    );
+    assert_eq!(class_has_method::<AvailabilityObj>("debug_tool"), is_debug);
+
+    // Rust-side calls are unaffected.
+    let obj = AvailabilityObj::new_gd();
+    obj.bind().editor_tool();
+    obj.bind().debug_tool();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
@@ -8,9 +8,10 @@
 // Needed for Clippy to accept #[cfg(all())]
 #![allow(clippy::non_minimal_cfg)]
 
-use crate::framework::itest;
-use godot::classes::GDScript;
+use crate::framework::{expect_panic, itest, wait_frames};
+use godot::classes::{ClassDb, Engine, GDScript};
 use godot::prelude::*;
+use godot::private::callbacks::{abstract_instance_error, abstract_instance_report_count};
 
 #[derive(GodotClass)]
 #[class(init)]
@@ -46,6 +47,23 @@ impl VirtualScriptCalls {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init, abstract)]
+struct AbstractScriptCalls {
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl AbstractScriptCalls {
+    #[func(virtual)]
+    fn damage(&self, multiplier: i64) -> i64;
+
+    #[func]
+    fn double_damage(&self) -> i64 {
+        self.damage(2)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -118,6 +136,132 @@ fn func_virtual_stateful() {
     assert_eq!(retrieved, variant);
 }
 
+#[itest]
+fn func_virtual_abstract_class() {
+    expect_panic("abstract class cannot be instantiated from Rust", || {
+        AbstractScriptCalls::new_gd();
+    });
+
+    let script = make_abstract_script(
+        r#"
+extends AbstractScriptCalls
+
+func _damage(multiplier: int) -> int:
+    return 7 * multiplier
+"#,
+    );
+
+    let object: Gd<AbstractScriptCalls> = script.clone().upcast::<Object>().call("new", &[]).to();
+    assert_eq!(object.bind().double_damage(), 14);
+    assert_eq!(object.bind().damage(3), 21);
+}
+
+#[itest]
+fn func_virtual_abstract_method_not_overridden() {
+    let script = make_abstract_script(
+        r#"
+extends AbstractScriptCalls
+"#,
+    );
+
+    let object: Gd<AbstractScriptCalls> = script.upcast::<Object>().call("new", &[]).to();
+    expect_panic("abstract method without script override", || {
+        object.bind().damage(1);
+    });
+}
+
+#[itest]
+fn func_virtual_abstract_class_db() {
+    let class_db = ClassDb::singleton();
+
+    // Virtual classes must stay constructible by Godot, which instantiates extending scripts through them.
+    assert!(class_db.can_instantiate("AbstractScriptCalls"));
+    assert!(class_db.class_exists("AbstractScriptCalls"));
+
+    // In contrast, no_init classes are registered as "abstract" and cannot be instantiated at all.
+    assert!(!class_db.can_instantiate("NoInitScriptCalls"));
+}
+
+#[itest]
+fn func_virtual_abstract_direct_instance_reported() {
+    // Godot cannot refuse the instantiation, but direct instances (without script) are detected and reported after the frame.
+    let object = ClassDb::singleton()
+        .instantiate("AbstractScriptCalls")
+        .to::<Gd<Object>>();
+
+    let error = abstract_instance_error::<AbstractScriptCalls>(&object)
+        .expect("direct instance of abstract class is detected");
+    assert!(error.contains("AbstractScriptCalls"), "error: {error}");
+
+    // Instances of extending scripts are fine.
+    let script = make_abstract_script(
+        r#"
+extends AbstractScriptCalls
+"#,
+    );
+    let scripted: Gd<Object> = script.upcast::<Object>().call("new", &[]).to();
+    assert_eq!(
+        abstract_instance_error::<AbstractScriptCalls>(&scripted),
+        None
+    );
+
+    // Non-abstract classes are never reported.
+    let concrete = ClassDb::singleton()
+        .instantiate("VirtualScriptCalls")
+        .to::<Gd<Object>>();
+    assert_eq!(
+        abstract_instance_error::<VirtualScriptCalls>(&concrete),
+        None
+    );
+}
+
+#[itest]
+async fn func_virtual_abstract_direct_instance_reported_after_frame() {
+    let reports_before = abstract_instance_report_count();
+
+    // Two direct instances in the same frame, plus one scripted instance which must not be reported.
+    let direct_a = ClassDb::singleton().instantiate("AbstractScriptCalls");
+    let direct_b = ClassDb::singleton().instantiate("AbstractScriptCalls");
+    let script = make_abstract_script(
+        r#"
+extends AbstractScriptCalls
+"#,
+    );
+    let scripted: Gd<Object> = script.upcast::<Object>().call("new", &[]).to();
+
+    // Reported only once the frame has been processed.
+    assert_eq!(abstract_instance_report_count(), reports_before);
+
+    // The reports are expected; don't spam the test output with them.
+    let mut engine = Engine::singleton();
+    engine.set_print_error_messages(false);
+    wait_frames(2).await;
+    engine.set_print_error_messages(true);
+
+    assert_eq!(abstract_instance_report_count(), reports_before + 2);
+
+    // Keep the RefCounted instances alive until they have been checked.
+    drop((direct_a, direct_b, scripted));
+}
+
+#[derive(GodotClass)]
+#[class(no_init)]
+struct NoInitScriptCalls {
+    _base: Base<RefCounted>,
+}
+
+fn make_abstract_script(code: &str) -> Gd<GDScript> {
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code);
+    script.reload();
+
+    assert_eq!(
+        script.get_instance_base_type(),
+        "AbstractScriptCalls".into()
+    );
+    script
+}
+
 fn make_script() -> Gd<GDScript> {
     let code = r#"
 extends VirtualScriptCalls
@@ -8,14 +8,18 @@
 mod constant_test;
 mod conversion_test;
 mod derive_godotconvert_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod editor_icon_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod multiple_impl_blocks_test;
 mod naming_tests;
 mod option_ffi_test;
 mod register_docs_test;
+mod registered_classes_test;
 #[cfg(feature = "codegen-full")]
 mod rpc_test;
+mod script_iter_test;
 mod var_test;
 
 #[cfg(since_api = "4.3")]
@@ -201,11 +201,77 @@ fn test_register_docs() {
     assert_eq!(include_str!("res/registered_docs.xml"), xml);
 }
 
+/// Documented in Rust.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct RuntimeDocumented {}
+
+#[godot_api]
+impl RuntimeDocumented {
+    /// Compile-time docs.
+    #[func]
+    fn documented(&self) {}
+}
+
+#[itest]
+fn test_amend_class_docs() {
+    godot::docs::amend_class_docs(RuntimeDocumented::class_name(), |docs| {
+        docs.append_description("Generated <from> schema.")
+            .method("documented", "Runtime docs.")
+            .method("dynamic_method", "Only exists at runtime.")
+            .property("dynamic_property", "A [b]property[/b].");
+    });
+
+    let xml = find_class_docs("RuntimeDocumented");
+
+    assert!(xml.contains(r#"inherits="RefCounted""#), "{xml}");
+    assert!(
+        xml.contains(
+            "<description>Documented in Rust.[br]Generated &lt;from&gt; schema.</description>"
+        ),
+        "{xml}"
+    );
+
+    // Existing method keeps signature, but gets new description.
+    assert!(!xml.contains("Compile-time docs."), "{xml}");
+    assert!(xml.contains("Runtime docs."), "{xml}");
+    assert!(xml.contains(r#"<return type="()" />"#), "{xml}");
+
+    assert!(xml.contains(r#"<method name="dynamic_method">"#), "{xml}");
+    assert!(
+        xml.contains(r#"<member name="dynamic_property">A [b]property[/b].</member>"#),
+        "{xml}"
+    );
+}
+
+/// Documented in Rust.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct RuntimeDocumentedNested {}
+
+#[itest]
+fn test_amend_class_docs_reentrant() {
+    // Calling back into amend_class_docs() from the closure must not deadlock.
+    godot::docs::amend_class_docs(RuntimeDocumentedNested::class_name(), |docs| {
+        docs.method("outer", "Outer docs.");
+
+        godot::docs::amend_class_docs(RuntimeDocumented::class_name(), |docs| {
+            docs.method("nested", "Nested docs.");
+        });
+    });
+
+    let xml = find_class_docs("RuntimeDocumentedNested");
+    assert!(xml.contains("Outer docs."), "{xml}");
+
+    let xml = find_class_docs("RuntimeDocumented");
+    assert!(xml.contains("Nested docs."), "{xml}");
+}
+
 fn find_class_docs(class_name: &str) -> String {
     let mut count = 0;
     for xml in godot::docs::gather_xml_docs() {
         count += 1;
-        if xml.contains(class_name) {
+        if xml.contains(&format!("<class name=\"{class_name}\"")) {
             return xml;
         }
     }
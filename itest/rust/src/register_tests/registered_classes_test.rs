@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::init::InitLevel;
+use godot::meta::ClassName;
+use godot::obj::GodotClass;
+use godot::register::{registered_classes, ClassMetadata, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, tool, base=Node)]
+struct RegisteredToolNode {}
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, internal, base=RefCounted)]
+struct RegisteredInternalObj {}
+
+// Names are chosen so that alphabetical order contradicts the declared order.
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct RegisteredOrderZ {}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, after = RegisteredOrderZ)]
+struct RegisteredOrderY {}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, after = [RegisteredOrderY, RegisteredOrderZ])]
+struct RegisteredOrderA {}
+
+fn find_class<T: GodotClass>() -> ClassMetadata {
+    registered_classes()
+        .into_iter()
+        .find(|class| class.class_name() == T::class_name())
+        .unwrap_or_else(|| panic!("class {} is registered", T::class_name()))
+}
+
+#[itest]
+fn registered_classes_metadata() {
+    let tool = find_class::<RegisteredToolNode>();
+    assert_eq!(tool.base_class_name().to_string(), "Node");
+    assert_eq!(tool.init_level(), InitLevel::Scene);
+    assert!(tool.is_tool());
+    assert!(!tool.is_internal());
+    assert!(!tool.is_editor_plugin());
+    assert_eq!(tool.icon(), None);
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn registered_classes_internal() {
+    let internal = find_class::<RegisteredInternalObj>();
+    assert_eq!(internal.base_class_name().to_string(), "RefCounted");
+    assert!(!internal.is_tool());
+    assert!(internal.is_internal());
+}
+
+#[itest]
+fn registered_classes_sorted() {
+    let names: Vec<String> = registered_classes()
+        .iter()
+        .map(|class| class.class_name().to_string())
+        .collect();
+
+    let mut sorted = names.clone();
+    sorted.sort();
+    assert_eq!(names, sorted);
+}
+
+#[itest]
+fn registered_classes_after() {
+    let order = godot::private::loaded_classes_in_registration_order(InitLevel::Scene);
+    let position_of = |class_name: ClassName| {
+        order
+            .iter()
+            .position(|name| *name == class_name)
+            .unwrap_or_else(|| panic!("class {class_name} is registered at Scene level"))
+    };
+
+    let a = position_of(RegisteredOrderA::class_name());
+    let y = position_of(RegisteredOrderY::class_name());
+    let z = position_of(RegisteredOrderZ::class_name());
+
+    // Alphabetical order would be A, Y, Z -- `after` must win.
+    assert!(z < y, "Z registered before Y: {order:?}");
+    assert!(y < a, "Y registered before A: {order:?}");
+}
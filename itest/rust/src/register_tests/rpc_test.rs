@@ -8,6 +8,7 @@
 use godot::classes::multiplayer_api::RpcMode;
 use godot::classes::multiplayer_peer::TransferMode;
 use godot::classes::{Engine, MultiplayerApi};
+use godot::meta::RpcPayload;
 use godot::prelude::*;
 use godot::register::RpcConfig;
 use godot::test::itest;
@@ -15,9 +16,43 @@ use godot::test::itest;
 #[derive(GodotClass)]
 #[class(init, base = Node2D)]
 pub struct RpcTest {
+    last_state: Option<PlayerState>,
     base: Base<Node2D>,
 }
 
+#[derive(RpcPayload, Clone, PartialEq, Debug)]
+#[rpc_payload(version = 3)]
+pub struct PlayerState {
+    position: Vector2,
+    health: u16,
+    name: GString,
+    inventory: Vec<ItemSlot>,
+    target: Option<i64>,
+}
+
+#[derive(RpcPayload, Clone, PartialEq, Debug)]
+pub enum ItemSlot {
+    Empty,
+    Item(StringName, u8),
+    Custom { data: PackedByteArray },
+}
+
+fn sample_state() -> PlayerState {
+    PlayerState {
+        position: Vector2::new(1.5, -2.0),
+        health: 80,
+        name: "Player One".into(),
+        inventory: vec![
+            ItemSlot::Item("sword".into(), 1),
+            ItemSlot::Empty,
+            ItemSlot::Custom {
+                data: PackedByteArray::from(&[1u8, 2, 3]),
+            },
+        ],
+        target: Some(-7),
+    }
+}
+
 const CACHED_CFG: RpcConfig = RpcConfig {
     rpc_mode: RpcMode::AUTHORITY,
     transfer_mode: TransferMode::RELIABLE,
@@ -78,6 +113,11 @@ impl RpcTest {
 
     #[rpc(config = provide_cfg())]
     pub fn arg_config_fn(&mut self) {}
+
+    #[rpc(any_peer, call_local)]
+    pub fn sync_state(&mut self, state: PlayerState) {
+        self.last_state = Some(state);
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -101,3 +141,55 @@ fn node_enters_tree() {
     root.remove_child(&node);
     node.free();
 }
+
+#[itest]
+fn rpc_payload_roundtrip() {
+    let state = sample_state();
+
+    let bytes = state.to_payload_bytes();
+    assert_eq!(bytes.as_slice()[0], 3, "version byte");
+
+    let decoded = PlayerState::from_payload_bytes(&bytes).expect("decode payload");
+    assert_eq!(decoded, state);
+
+    // Also through Variant, as used for RPC arguments.
+    let variant = state.to_variant();
+    assert_eq!(variant.get_type(), VariantType::PACKED_BYTE_ARRAY);
+    assert_eq!(variant.to::<PlayerState>(), state);
+}
+
+#[itest]
+fn rpc_payload_errors() {
+    let bytes = sample_state().to_payload_bytes();
+    let bytes = bytes.as_slice();
+
+    // Wrong version.
+    let mut wrong_version = bytes.to_vec();
+    wrong_version[0] = 2;
+    assert!(PlayerState::from_payload_bytes(&PackedByteArray::from(wrong_version)).is_err());
+
+    // Truncated.
+    let truncated = PackedByteArray::from(&bytes[..bytes.len() - 1]);
+    assert!(PlayerState::from_payload_bytes(&truncated).is_err());
+
+    // Trailing bytes.
+    let mut trailing = bytes.to_vec();
+    trailing.push(0);
+    assert!(PlayerState::from_payload_bytes(&PackedByteArray::from(trailing)).is_err());
+
+    // Invalid enum variant index.
+    let mut invalid_variant = vec![0];
+    invalid_variant.push(9);
+    assert!(ItemSlot::from_payload_bytes(&PackedByteArray::from(invalid_variant)).is_err());
+}
+
+#[itest]
+fn rpc_payload_as_func_param() {
+    let mut node = RpcTest::new_alloc();
+    let state = sample_state();
+
+    node.call("sync_state", &[state.to_variant()]);
+    assert_eq!(node.bind().last_state, Some(state));
+
+    node.free();
+}
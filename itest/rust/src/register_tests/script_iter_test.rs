@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{varray, GString, Variant};
+use godot::meta::ToGodot;
+use godot::obj::{NewGd, ScriptIterable};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+/// Also iterated over from GDScript, see `ManualFfiTests.gd`.
+#[derive(GodotClass)]
+#[class(init)]
+pub struct IterableWords {
+    words: Vec<GString>,
+}
+
+#[godot_api(iterable)]
+impl IterableWords {
+    #[func]
+    fn push(&mut self, word: GString) {
+        self.words.push(word);
+    }
+}
+
+impl ScriptIterable for IterableWords {
+    type Item = GString;
+
+    fn script_iter_len(&self) -> usize {
+        self.words.len()
+    }
+
+    fn script_iter_get(&self, index: usize) -> GString {
+        self.words[index].clone()
+    }
+}
+
+#[itest]
+fn script_iter_protocol() {
+    let mut obj = IterableWords::new_gd();
+    obj.bind_mut().words = vec!["a".into(), "b".into()];
+
+    // Emulates what GDScript does in `for word in obj`.
+    let iter = varray![Variant::nil()];
+    let mut collected = vec![];
+
+    let mut valid = obj.call("_iter_init", &[iter.to_variant()]);
+    while valid.to::<bool>() {
+        let word = obj.call("_iter_get", &[iter.at(0)]);
+        collected.push(word.to::<GString>());
+
+        valid = obj.call("_iter_next", &[iter.to_variant()]);
+    }
+
+    assert_eq!(collected, vec![GString::from("a"), GString::from("b")]);
+}
+
+#[itest]
+fn script_iter_empty() {
+    let mut obj = IterableWords::new_gd();
+
+    let iter = varray![Variant::nil()];
+    let valid = obj.call("_iter_init", &[iter.to_variant()]);
+
+    assert!(!valid.to::<bool>());
+}